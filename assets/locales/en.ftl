@@ -0,0 +1,872 @@
+## Terminal Arcade's English text, which every other translation falls back
+## to. Key names like [Enter] are left as they are on the keyboard.
+
+## Controls popup
+
+controls-title = Controls
+controls-shortcut = Shortcut
+controls-function = Function
+controls-control = Control
+controls-close = Closes this screen and returns to the previous one
+controls-quit = Quits the application
+controls-report-bug = Saves a bug report
+controls-screenshot = Saves a screenshot of the screen as text
+controls-record-session = Starts or stops recording the session
+controls-record-macro = Starts or stops recording an input macro
+controls-navigate = Navigate this controls list
+
+## Actions
+
+action-quit = Quits Terminal Arcade
+action-record-macro = Starts or stops recording an input macro
+action-play-macro = Plays input macro { $number }
+action-report-bug = Saves a bug report
+action-screenshot = Saves a screenshot of the screen as text
+action-record-session = Starts or stops recording the session
+action-dev-tools = Opens the developer tools
+action-up = Moves or scrolls up
+action-down = Moves or scrolls down
+action-left = Moves left
+action-right = Moves right
+action-page-up = Scrolls up by a page
+action-page-down = Scrolls down by a page
+action-first = Goes to the first item or the top
+action-last = Goes to the last item or the bottom
+action-confirm = Confirms or picks the selected item
+action-back = Closes the screen
+action-help = Opens the screen's help page
+action-controls = Opens the screen's controls popup
+action-pause = Pauses the game, opening its pause menu
+action-restart = Plays the game again from the start, with the same settings
+action-undo = Takes the game's latest move back
+action-redo = Makes the game's latest move undone again
+action-dev-console = Opens or closes the game's developer console
+quit-confirm = 🚪 Press { $key } again to quit
+
+## Keybindings cheat sheet
+
+cheat-sheet-title = Terminal Arcade keybindings
+cheat-sheet-global = On every screen
+cheat-sheet-navigation = On the active screen
+cheat-sheet-keys = Keys
+
+## Terminal size
+
+terminal-too-small = The terminal is too small for this screen: it's { $width }×{ $height }, but it needs at least { $min_width }×{ $min_height }. Make it bigger, or zoom out!
+terminal-cramped = ↔ Best at { $width }×{ $height }
+
+## Welcome screen
+
+welcome-tagline = Arcade games, right in your terminal.
+welcome-play = 🎮 Hop into a game and play!
+welcome-surprise = 🎰 Surprise me!
+welcome-settings = 🗜️ View your settings...
+welcome-about = ℹ️ About Terminal Arcade...
+welcome-quit = 🛑 Quit the application...
+welcome-dismiss-tips = Dismisses the tips in the footer
+welcome-digest = Shows your week in the arcade
+welcome-leaderboard = Shows the leaderboard
+welcome-time = ⏰ Time: { $time }
+welcome-version = 🎮 Terminal Arcade { $version }, on 🎋 { $branch }, commit hash { $commit }; at remote { $remote }
+welcome-unknown-branch = of an unknown tree
+welcome-work-in-progress = 🏗️ Terminal Arcade is a work-in-progress! If you would like to contribute, please do!
+welcome-tip = 💡 Tip: { $tip }
+welcome-update-available = ✨ A new version, { $version }, is available!
+goodbye = See you next time! 👋
+
+## Settings screen
+
+settings-title = Settings
+settings-select = Selects a setting
+settings-toggle = Toggles the selected setting
+settings-check-for-updates = 🔔 Check for new versions on startup
+settings-check-for-updates-unavailable = 🔔 Check for new versions on startup (unavailable in this build)
+settings-show-tips = 💡 Show tips on the welcome screen
+settings-large-text = 🔍 Large text for menus and game info
+settings-reduced-motion = 🐢 Reduce motion, showing text whole instead of typing it out
+settings-attract-mode = 📺 Play game demos on the welcome screen when left idle
+settings-autosave-on-leave = 💾 Save games in progress when leaving them
+settings-resume-games = ⏯️ Resume saved games in progress
+settings-coalesce-inputs = 🕹️ Merge held arrow keys, so that games keep up
+settings-exit-summary = 📋 Sum up the session when quitting
+settings-confirm-quit = 🚪 Press a quit key twice to quit
+settings-screenshot-ansi = 📸 Also save screenshots with their colors, as ANSI codes
+settings-on = ✅ On
+settings-off = ❌ Off
+settings-backups = Opens the backups of the save data
+settings-data = Opens the save data of each game
+settings-macros = Opens the input macros
+settings-profile = Edits your name and avatar
+settings-reset = Resets the settings, keybindings or save data
+settings-encryption = Sets up or removes the encryption of the save data
+settings-export = Exports your statistics as CSV and JSON files
+settings-export-done = 📊 Statistics exported to { $path }
+settings-export-failed = 📊 Couldn't export statistics: { $error }
+settings-cheat-sheet = Writes a cheat sheet of your keybindings as Markdown and text files
+settings-cheat-sheet-done = ⌨️ Keybindings cheat sheet written to { $path }
+settings-cheat-sheet-failed = ⌨️ Couldn't write the keybindings cheat sheet: { $error }
+settings-files = Files
+settings-data-dir = 📁 Data: { $path } ({ $source })
+settings-config-dir = 🗜️ Configuration: { $path } ({ $source })
+settings-sync-off = 🔄 Sync: off (set sync_repository in the configuration file to turn it on)
+settings-served = 🔒 These settings are the server's, and can't be changed from a connection.
+export-done = Statistics exported to { $path }
+scores-exported = Scores exported to { $path }
+scores-checksum-mismatch = The scores don't match the file's checksum; they may have been edited
+scores-imported = Imported the scores of { $player } in { $games } { $games ->
+        [one] game
+       *[other] games
+    }: { $improved } { $improved ->
+        [one] best beats
+       *[other] bests beat
+    } yours
+serve-listening = Serving the arcade on { $address }, until interrupted
+serve-full = The arcade is full, try again later!
+files-source-default = default
+files-source-argument = command line
+files-source-portable = portable mode
+
+## Weekly digest
+
+digest-title = Your week in the arcade
+digest-export = Exports the digest as a Markdown file
+digest-summary = Summary
+digest-period = 📅 From { $start } to { $end }
+digest-plays = 🎮 { $count } { $count ->
+        [one] play
+       *[other] plays
+    } this week
+digest-no-plays = 💤 You haven't played this week
+digest-most-played = 🏆 Most played: { $game }, { $count } { $count ->
+        [one] time
+       *[other] times
+    }
+digest-streak = 🔥 Streak: { $days } { $days ->
+        [one] day
+       *[other] days
+    } in a row (longest: { $longest })
+digest-games = Games played
+digest-game-plays = { $game }: { $count }
+digest-records = New records
+digest-record = 🥇 { $game }: { $score }
+digest-no-records = No new records this week
+digest-exported = 📝 Digest exported to { $path }
+digest-export-failed = 📝 Couldn't export the digest: { $error }
+stats-today = ⏱ { $playtime } · 🎮 { $plays } today
+summary-session = ⏱ Played for { $duration } · 🎮 { $plays } { $plays ->
+        [one] game
+       *[other] games
+    }
+summary-score = 🥇 New record in { $game }: { $score }
+summary-mode-score = 🥇 New record in { $game } ({ $mode }): { $score }
+summary-time = ⏱ New best time in { $game }: { $time }
+
+## Leaderboard
+
+leaderboard-title = Leaderboard
+leaderboard-local = 🏠 Your bests
+leaderboard-global = 🌍 Global
+leaderboard-tab-control = Switches between your bests and the global rankings
+leaderboard-game-control = Picks the game
+leaderboard-submit-control = Submits your bests to the leaderboard server
+leaderboard-refresh-control = Fetches the global rankings again
+leaderboard-no-games = No games are available
+leaderboard-best-time = Best time
+leaderboard-no-scores = No bests in this game yet
+leaderboard-no-server = 🌍 No leaderboard server is set up: set leaderboard_url in the configuration file to share your bests with other players
+leaderboard-loading = Fetching the rankings...
+leaderboard-failed = Couldn't fetch the rankings: { $error }
+leaderboard-no-rankings = Nobody is ranked in this game yet
+leaderboard-rank = #
+leaderboard-player = Player
+leaderboard-score = Score
+leaderboard-time = Time
+leaderboard-submitting = 🌍 Submitting your bests...
+leaderboard-submitted = 🌍 Your bests in { $games } { $games ->
+        [one] game
+       *[other] games
+    } were submitted
+leaderboard-submit-failed = 🌍 Couldn't submit your bests: { $error }
+leaderboard-server-missing = No leaderboard server is set up
+leaderboard-not-built = Terminal Arcade was built without the `global-leaderboard` feature
+
+## Autosaves
+
+autosave-saved = 💾 Saved
+autosave-failed = 💾 Couldn't save the game
+
+## Save data encryption
+
+passphrase-title = Passphrase
+passphrase-field = Passphrase
+passphrase-control = Submits the passphrase
+passphrase-unlock = 🔒 Your save data is encrypted. Type in its passphrase and press [Enter] to unlock it, or press [Esc] to play without it this time.
+passphrase-enable = 🔐 Choose a passphrase to encrypt your save data with, then press [Enter]. It will be asked for on every startup, and can't be recovered if lost!
+passphrase-confirm = 🔐 Type in the passphrase again to confirm it, then press [Enter].
+passphrase-disable = 🔓 Type in the passphrase and press [Enter] to decrypt your save data for good.
+passphrase-mismatch = The passphrases don't match
+passphrase-failed = ⚠ { $error }. Try again, or press [Esc] to go back.
+encryption-key-failed = Could not derive the key: { $error }
+encryption-encrypt-failed = Could not encrypt the save data
+encryption-cut-short = The encrypted save data is cut short
+encryption-decrypt-failed = Could not decrypt the save data, which may be damaged
+encryption-locked = The save data is locked
+encryption-wrong-passphrase = Wrong passphrase
+encryption-already-enabled = The save data is already encrypted
+encryption-not-enabled = The save data isn't encrypted
+
+## Save data sync
+
+sync-syncing = 🔄 Syncing the save data…
+sync-synced = 🔄 Sync: last synced at { $time }
+sync-failed = 🔄 Sync: failed at { $time }: { $error }
+sync-conflict-title = Sync conflict
+sync-conflict-local = ⚠ Your save data was changed both on this computer and elsewhere since the last sync. This computer's, being the newest, was kept and synced.
+sync-conflict-remote = ⚠ Your save data was changed both on this computer and elsewhere since the last sync. The other computer's, being the newest, was kept. This computer's was backed up first, and can be restored from the settings.
+sync-conflict-close = Press [Enter] to continue.
+
+## Backups
+
+backups-title = Backups
+backups-select = Selects a backup
+backups-restore-control = Restores the selected backup
+backups-create-control = Backs up the save data now
+backups-nothing = There is no save data to back up yet
+backups-hint = Press [Enter] to restore the selected backup, or [N] to back up now. The current save data is backed up before restoring.
+backups-none = 🗃️ No backups yet!
+backups-list-failed = ⚠ Could not list the backups: { $error }
+backups-restored = ✅ Restored the backup from { $date }.
+backups-restore-failed = ⚠ Could not restore the backup: { $error }
+backups-created = ✅ Backed up the save data on { $date }.
+backups-create-failed = ⚠ Could not back up the save data: { $error }
+
+## About screen
+
+about-title = About Terminal Arcade
+about-block = About
+about-changelog = Changelog
+about-scroll = Scrolls the changelog
+about-page-scroll = Scrolls the changelog by a few lines
+about-repository-control = Shows the repository's URL as a QR code
+about-bug-control = Shows the bug report URL as a QR code
+about-diagnostics-control = Checks the terminal and your files
+about-repository = Repository
+about-report-bug = Report a bug
+about-game-credits-block = Game credits
+about-game-credits = 🎮 { $name }: { $authors }
+about-unknown-authors = unknown authors
+about-text =
+    🎮 Terminal Arcade { $version } - { $description }
+    🔗 Repository: { $repository } ([R] for a QR code, [B] to report a bug)
+    📜 License: { $license }
+    👷 Credits: { $authors }
+
+## Diagnostics
+
+diagnostics-title = Diagnostics
+diagnostics-checks = Checks
+diagnostics-rerun = Runs the checks again
+doctor-failed = Some checks failed
+doctor-not-a-terminal = Not run in a terminal
+doctor-colors = Colors
+doctor-colors-true = 24-bit colors
+doctor-colors-256 = 256 colors: the color scheme is approximated
+doctor-colors-basic = Basic colors only: set COLORTERM=truecolor if the terminal supports more
+doctor-unicode = Unicode
+doctor-unicode-ok = UTF-8 locale ({ $locale })
+doctor-unicode-windows = Windows console
+doctor-unicode-missing = The locale ({ $locale }) isn't UTF-8: cards, pieces and borders may not display
+doctor-size = Terminal size
+doctor-size-ok = { $width }×{ $height }
+doctor-size-small = { $width }×{ $height }, smaller than the { $min_width }×{ $min_height } most screens need
+doctor-size-unknown = Couldn't read the terminal's size: { $error }
+doctor-mouse = Mouse
+doctor-mouse-ok = { $term } reports the mouse
+doctor-mouse-unlikely = "{ $term }" likely doesn't report the mouse
+doctor-keyboard = Kitty keyboard protocol
+doctor-keyboard-ok = Supported
+doctor-keyboard-missing = Not supported: some key combinations can't be told apart
+doctor-keyboard-unknown = The terminal didn't answer: { $error }
+doctor-keyboard-skipped = Only checked by the `terminal-arcade doctor` command
+doctor-graphics = Graphics
+doctor-graphics-protocol = Images drawn with { $protocol }
+doctor-config = Configuration
+doctor-config-ok = { $path } is valid
+doctor-config-missing = { $path } doesn't exist yet: the defaults are used
+doctor-config-invalid = [{ $code }] { $error }. The defaults are used.
+doctor-saves = Save files
+doctor-saves-ok = { $count } { $count ->
+        [one] file is
+       *[other] files are
+    } valid
+doctor-saves-encrypted = , { $count } encrypted { $count ->
+        [one] one
+       *[other] ones
+    } left unchecked
+doctor-saves-invalid = { $count } of { $total } files are invalid: { $files }
+doctor-data-dir = Data directory
+doctor-config-dir = Configuration directory
+doctor-dir-ok = { $path } is writable
+doctor-dir-missing = { $path } doesn't exist yet, and will be created
+doctor-dir-read-only = { $path } isn't writable: { $error }
+
+## Game data
+
+data-title = Game data
+data-select = Selects a game
+data-mark-control = Marks the selected game, to act on several at once
+data-delete-control = Deletes the data of the marked or selected games
+data-reset-control = Resets the statistics of the marked or selected games
+data-export-control = Exports the files of the marked or selected games
+data-open-control = Opens the save folder
+data-sort-control = Sorts the games by the next column
+data-hint = Mark games with [Space] to act on several at once, or act on the selected one.
+data-none = 💾 No games yet!
+data-game = Game
+data-files = Files
+data-size = Size
+data-modified = Last changed
+data-confirm-delete = Delete all save data of { $games }? Their statistics, autosaves and scripts' data will be gone.
+data-confirm-reset = Reset the statistics of { $games }? Their play counts and best scores will be gone.
+data-deleted = ✅ Deleted the data of { $games }.
+data-reset = ✅ Reset the statistics of { $games }.
+data-exported = ✅ Exported the files of { $games } to the exports folder.
+data-failed = ⚠ Could not finish: { $error }
+data-backup-failed = ⚠ Could not back up the save data first, so nothing was changed: { $error }
+data-open-failed = ⚠ Could not open the save folder: { $error }
+
+## Profile
+
+profile-title = Profile
+profile-name = Name
+profile-avatar = Avatar
+profile-avatar-control = Picks another avatar
+profile-save-control = Saves the profile
+profile-hint = Type your name, pick an avatar, then press [Enter] to save. Your profile is shown at the top of every screen, and to other players online.
+profile-preview = Shown as: { $badge }
+profile-name-blank = ⚠ The name can't be blank.
+profile-saved = ✅ Profile saved: { $badge }
+profile-save-failed = ⚠ Could not save the profile: { $error }
+
+## Reset
+
+reset-title = Reset
+reset-select = Selects what to reset
+reset-control = Resets the selection to its defaults
+reset-hint = Press [Enter] to reset the selection. Whatever is wiped is backed up first, and can be restored from the backups.
+reset-settings = ⚙️ Settings
+reset-keybindings = ⌨️ Keybindings
+reset-game = 🎮 Save data of { $game }
+reset-everything = 💣 Everything
+reset-settings-confirm = Reset the settings to their defaults?
+reset-keybindings-confirm = Reset the keybindings to their defaults? They take effect on the next start.
+reset-game-confirm = Delete all save data of { $game }? Its statistics, autosaves and scripts' data will be gone.
+reset-everything-confirm = Reset the settings, the keybindings and all save data? Everything but the backups will be gone.
+reset-done = ✅ Reset: { $scope }.
+reset-done-backed-up = ✅ Reset: { $scope }. { $count } backups were taken first, in the backups folder.
+reset-failed = ⚠ Could not reset: { $error }
+
+## Input macros
+
+macros-title = Input macros
+macros-select = Selects a macro
+macros-rename-control = Renames the selected macro
+macros-remove-control = Removes the selected macro
+macros-record-control = Starts or stops recording a macro, on any screen
+macros-hint = Press [F9] on any screen to start recording the keys you press as a macro, and again to stop. [Alt]+[1] to [Alt]+[9] play the macros back.
+macros-none = ⌨️ No macros yet!
+macros-sort-control = Sorts the macros by the next column
+macros-hotkey = Hotkey
+macros-name = Name
+macros-inputs = Inputs
+macros-default-name = Macro { $number }
+macros-renaming = ✏️ New name: { $name }█ ([Enter] to rename)
+macros-renamed = ✅ Renamed the macro to { $name }.
+macros-removed = ✅ Removed { $name }.
+macros-recording = ⏺ Recording a macro, [F9] to stop
+macros-saved = ✅ Saved { $name }, played with { $hotkey }
+macros-save-failed = ⚠ Could not save the macros: { $error }
+macros-empty = Nothing was recorded
+macros-full = ⚠ There are already { $max } macros
+macros-playing = ▶ Playing { $name }
+macros-play-failed = ⚠ Could not play the macro: { $error }
+
+## Setup presets
+presets-save-control = Saves the answers as a preset
+presets-pick-control = Picks the next preset
+presets-remove-control = Removes the picked preset
+presets-none = No presets yet. Press [S] to save these answers as one.
+presets-list = Presets: { $presets }
+presets-naming = ✏️ Name of the preset: { $name }█ ([Enter] to save)
+presets-saved = ✅ Saved the preset { $name }.
+presets-picked = ✅ Picked the preset { $name }.
+presets-removed = ✅ Removed the preset { $name }.
+presets-save-failed = ⚠ Could not save the presets: { $error }
+
+## Errors
+
+error-title = Error
+error-title-code = Error { $code }
+error-close = Press [Enter] to continue.
+error-config-parse = { $path } is invalid at line { $line }, column { $column }: { $message }
+error-config-parse-hint = Fix that line, or delete the file to go back to the default settings.
+error-save-parse = The save file { $path } is invalid at line { $line }, column { $column }: { $message }
+error-save-parse-hint = Restore a backup from the settings, or delete the file to reset that game's data.
+error-save-moved = The broken save file was moved to { $path }
+error-save-encrypted = { $path } is encrypted, and can't be read or overwritten without the passphrase
+error-save-encrypted-hint = Unlock the save data with its passphrase, in a build with encryption.
+error-file-access = Couldn't access { $path }: { $error }
+error-file-access-hint = Run `terminal-arcade doctor` to check that the save and configuration folders can be written to.
+error-screen-panicked = The "{ $screen }" screen crashed and was closed: { $message }. A bug report was saved to { $path }.
+error-screen-panicked-unsaved = The "{ $screen }" screen crashed and was closed: { $message }. Its bug report couldn't be saved.
+error-screen-panicked-hint = The rest of the session can go on. Please attach the bug report to an issue, so the crash gets fixed.
+error-render-stalled = No frame was drawn for { $seconds } seconds (terminal: { $terminal }). The terminal will be reinitialized once drawing goes on.
+error-render-stalled-hint = If the screen stays frozen, check the connection to the terminal. Resizing it also redraws the screen.
+error-unknown-hint = If this keeps happening, press [F12] to save a bug report.
+
+## Bug reports
+
+bug-report-saved =
+    ✅ Saved a bug report to { $path }.
+    Please attach it to a new issue, opened at:
+bug-report-failed =
+    ⚠ Could not save a bug report: { $error }
+    Please describe the bug in a new issue, opened at:
+bug-report-hint =
+    Press [F12] on the screen the bug happened on to save a bug report, then
+    attach it to a new issue, opened at:
+panic-message =
+    Sorry, something happened! 🫤
+    If you believe this was a bug, please send an issue to https://github.com/developer-ramen/terminal-arcade to get it squashed as soon as possible!
+
+## Screenshots
+
+screenshot-saved = 📸 Screenshot saved to { $path }
+screenshot-failed = 📸 Couldn't save the screenshot: { $error }
+
+## Session recordings
+
+recording-title = Terminal Arcade
+recording-on = ⏺ Recording the session
+recording-saved = ⏺ Recording saved to { $path }
+recording-failed = ⏺ Couldn't save the recording: { $error }
+
+## Render stats (developer tools)
+
+render-slow = 🐢 { $screen } took { $ms } ms to draw, over its { $budget } ms budget
+input-slow = ⌛ Input waited { $ms } ms to be played, with { $waiting } queued
+
+## Event replay (developer tools)
+
+event-replay-title = Event replay
+event-replay-events = Last events
+event-replay-preview = Replay
+event-replay-select = Steps through the events
+event-replay-jump = Goes to the oldest or newest event
+event-replay-mark = Marks the selected event as the start of the range replayed
+event-replay-target = Picks the screen the events are replayed against
+event-replay-control = Replays the range of events against a fresh screen
+event-replay-hint = Press [Enter] to replay the events up to the selected one, from the one marked with [Space] or the oldest one, against a fresh welcome screen. [Tab] picks another screen.
+event-replay-target-picked = Replaying against: { $screen }
+event-replay-done = ✅ Replayed { $count } event(s) against: { $screen }
+event-replay-failed = ⚠ The replay failed: { $error }
+event-replay-none = Nothing replayed yet.
+event-replay-closed = Every screen was closed
+
+## Developer console
+
+dev-console-title = Developer console - [F10] closes it
+dev-console-help = Lists the commands
+dev-console-unknown = Unknown command: { $name }. Type "help" to list the commands.
+dev-console-usage = Usage: { $usage }
+
+## Help and QR code popups
+
+help-title = Help
+help-scroll = Scrolls the help page
+help-page-scroll = Scrolls the help page by a few lines
+help-jump = Jumps to the top or bottom of the help page
+qr-title = QR code
+qr-too-long = ⚠ This is too long for a QR code!
+
+## Game selection screen
+
+search-title = Search for a game!
+search-preview = Preview
+search-back = ⏪ Back
+search-placeholder = Search...
+search-placeholder-recent = Search... (↑ recent: { $term })
+search-results = Finished in { $seconds } seconds, found { $count } { $count ->
+        [one] result
+       *[other] results
+    }, displaying { $per_page } results at once.
+search-kind-setting = ⚙️ Setting
+search-kind-help = 📖 Help
+search-setting-hint = Opens the settings at this option
+search-difficulty = Difficulty
+search-filter-control = Filter by difficulty
+search-sort-control = Sort by difficulty
+search-launch-control = Open the numbered result
+search-created = 👷 Created at: { $created }
+search-difficulty-stars = 💪 Difficulty: { $difficulty }
+search-best-scores = 🏅 Best scores
+search-best-score = { $mode }: { $score }
+search-no-best-score = { $mode }: —
+search-best-time = ⏱ Best time: { $time }
+difficulty-all = All
+difficulty-unsorted = unsorted
+difficulty-easiest-first = easiest first
+difficulty-hardest-first = hardest first
+difficulty-easy = Easy
+difficulty-medium = Medium
+difficulty-hard = Hard
+difficulty-expert = Expert
+game-created = v{ $version }
+game-created-by = v{ $version } by { $authors }
+game-entry =
+    📄 Description: { $description }
+    👷 Created at: { $created }, 💪 difficulty: { $difficulty }
+    { $status }
+game-played = 🕹️ Played { $count } { $count ->
+        [one] time
+       *[other] times
+    }, 🌗 last played at { $date }{ $rating }
+game-rated = , ⭐ rated { $rating }
+game-high-scores = , 🏅 best { $scores }
+game-best-time = , ⏱ best { $time }
+game-wins = , 🏆 won { $count }
+result-new-best-score = 🏅 New best score!
+result-best-score = Best score: { $score }
+result-new-best-time = ⏱ New best time!
+result-best-time = Best time: { $time }
+results-won = YOU WIN
+results-over = GAME OVER
+results-score = ⭐ Score: { $score }
+results-seed = 🎲 Seed: { $seed }
+results-seed-copy = [C] Copy the seed | [S] Play this seed again
+results-seed-copied = 📋 Seed copied | [S] Play this seed again
+badge-new = ✨ New!
+badge-updated = 🔄 Updated
+game-never-played = 🆕 Never played before!
+roulette-title = 🎰 Surprise!
+
+## Game modes
+
+mode-select-title = Choose a mode
+mode-select-control = Plays the game in the selected mode
+mode-standard = Standard
+mode-standard-description = The game as designed
+mode-time-trial = Time trial
+mode-time-trial-description = Score as much as you can in 2 minutes
+mode-endless = Endless
+mode-endless-description = Losing starts a new round, keeping your score
+mode-hardcore = Hardcore
+mode-hardcore-description = No saving, no undoing, no reloading
+mode-countdown = ⏱️ { $time }
+start-countdown-go = Go!
+
+## Save data migration
+
+migration-title = Move your save data
+migration-intro = 📦 Terminal Arcade now keeps its files in your system's usual folders, but your save data is still in the old folder, { $from }. It can be moved to:
+migration-data-dir = 📁 Data: { $path }
+migration-config-dir = 🗜️ Configuration: { $path }
+migration-prompt = Press [Enter] to move it, or [Esc] to skip for now and start afresh. You will be asked again next time.
+migration-done = ✅ Moved { $count } { $count ->
+        [one] file
+       *[other] files
+    }, and removed the old folder. Press [Enter] to continue.
+migration-failed = ⚠ Could not move the save data: { $error }. The old folder was left as it was. Press [Enter] to continue.
+migration-files = Files to move
+migration-list-failed = ⚠ Could not list the files: { $error }
+migration-control = Moves the save data, then continues
+
+## Tutorials
+
+tutorial-step = 🎓 Tutorial - step { $step }/{ $steps }
+tutorial-continue = Press [Enter] to continue.
+
+## Demos
+
+demo-watching = 👀 Demo - press any key to stop watching
+
+## Pause menu
+
+pause-menu-title = Paused
+confirm-title = Are you sure?
+confirm-answers = [Y] Yes | [N] No
+pause-menu-control = Pauses the game
+quick-restart-control = Restarts the game with the same settings
+quick-restart-confirm = Restart the game? Your progress in this one will be lost.
+pause-menu-resume = ▶️ Resume
+pause-menu-hint = 💡 Get a hint (costs points or time)
+pause-menu-help = 📖 Read the manual
+pause-menu-leave = 🚪 Leave the game
+pause-menu-no-hint = 🤷 No hint to give right now.
+
+## Minesweeper
+
+minesweeper-description = A tile-based game of looking for mines and avoiding responsibilities.
+minesweeper-setup-title = Mine your field!
+minesweeper-tutorial-control = Starts the interactive tutorial
+minesweeper-help-control = Opens Minesweeper's help page
+minesweeper-tutorial-hint = 🎓 New to Minesweeper? Press [T] for an interactive tutorial, [D] to watch a demo game, or [F1] for the manual.
+minesweeper-demo-control = Watches a demo game
+minesweeper-setup-select = Selects a question
+minesweeper-setup-change = Changes the answer
+minesweeper-width = Width
+minesweeper-height = Height
+minesweeper-mines = Mines
+minesweeper-demo-title = Minesweeper demo
+minesweeper-demo-cleared = 🏆 Board cleared! Another game starts in a moment...
+minesweeper-demo-exploded = 💥 Boom! Another game starts in a moment...
+minesweeper-tutorial-title = Minesweeper tutorial
+minesweeper-tutorial-welcome = Welcome to Minesweeper! This tutorial walks you through the basics of the game.
+minesweeper-tutorial-board = This is the board. Every tile starts covered, and some of them hide mines. Uncover every tile without a mine to win!
+minesweeper-tutorial-uncover = Your cursor is on the highlighted tile. Press [Space] to uncover it - the first tile you uncover is never a mine.
+minesweeper-tutorial-numbers = Uncovered tiles show how many of their neighbors hide a mine. Blank tiles have none, so their neighbors were uncovered for you.
+minesweeper-tutorial-deduce = This 1 touches only one covered tile, the one to its right. That tile must hide a mine!
+minesweeper-tutorial-move = Move your cursor onto the mine with the arrow keys.
+minesweeper-tutorial-flag = Press [F] to flag the mine, so that you don't uncover it by accident.
+minesweeper-tutorial-safe = This 1 already has its mine flagged, so its other covered neighbor must be safe.
+minesweeper-tutorial-finish = Move onto the last covered tile and press [Space] to uncover it.
+minesweeper-tutorial-done = You cleared the board - that's all there is to it! Press [F1] in the game to read the manual at any time.
+
+## Tron
+
+tron-description = A networked race of light cycles, where the last one riding wins.
+tron-setup-title = Ride your light cycle!
+tron-select = Selects an option
+tron-start = Hosts or joins a game
+tron-help-control = Opens Tron's help page
+tron-host = 🖥️ Host a game for another player to join
+tron-join-local = 📡 Join a game hosted on your local network
+tron-join = 🔌 Join a game by its address
+tron-watch-demo = 📺 Watch a demo game
+tron-demo-title = Tron demo
+tron-demo-won = 🏆 Player { $player } won the round!
+tron-demo-draw = 🤝 It's a draw!
+tron-turn = Turns your cycle
+tron-restart-control = Starts another round once over (host only)
+tron-zoom-control = Zooms the arena in, or back out to 1×1 after 3×2
+tron-chat-control = Opens or closes the chat
+tron-guest = Guest
+tron-ping = 📶 Ping: { $ms } ms
+tron-ping-unknown = 📶 Ping: ...
+tron-peer-left = 🔌 { $name } left the game.
+tron-peer-paused = ⏸️ { $name } paused the game.
+tron-restart-host = Press [R] for another round.
+tron-restart-wait = Waiting for the host to start another round...
+tron-won = 🏆 You won the round! { $hint }
+tron-crashed = 💥 You crashed! { $hint }
+tron-other-won = 🏆 { $name } won the round! { $hint }
+tron-draw = 🤝 It's a draw! { $hint }
+tron-ride = 🏍️ Ride!
+tron-spectating = 👀 Spectating
+tron-watching = 👀 { $count } watching
+
+## 2048
+
+twenty-forty-eight-description = A sliding puzzle of merging tiles, all the way up to 2048.
+twenty-forty-eight-setup-title = Size your grid!
+twenty-forty-eight-setup-select = Selects a question
+twenty-forty-eight-setup-change = Changes the answer
+twenty-forty-eight-size = Grid size
+twenty-forty-eight-start = Starts the game
+twenty-forty-eight-help-control = Opens 2048's help page
+twenty-forty-eight-title = 2048
+twenty-forty-eight-slide = Slides the tiles
+twenty-forty-eight-restart-control = Plays again once the game is over
+twenty-forty-eight-undo-control = Takes the last slide back
+twenty-forty-eight-redo-control = Makes the last slide taken back again
+twenty-forty-eight-status = ⭐ Score: { $score } | 🏆 Best: { $best }
+twenty-forty-eight-hints = 💡 Hints: { $hints }
+twenty-forty-eight-hints-used = 💡 { $count } { $count ->
+        [one] hint
+       *[other] hints
+    } used, for { $points } points
+twenty-forty-eight-hint-up = Slide the tiles up.
+twenty-forty-eight-hint-down = Slide the tiles down.
+twenty-forty-eight-hint-left = Slide the tiles left.
+twenty-forty-eight-hint-right = Slide the tiles right.
+twenty-forty-eight-command-score = Adds points to the score
+twenty-forty-eight-command-score-done = Score: { $score }
+twenty-forty-eight-command-tile = Places a tile, or empties the cell with 0
+
+## Sudoku
+
+sudoku-description = A grid of digits to fill in, without repeating one in any row, column or box.
+sudoku-setup-title = Pick your puzzle!
+sudoku-setup-select = Selects a question
+sudoku-setup-change = Changes the answer
+sudoku-difficulty = Difficulty
+sudoku-start = Generates the puzzle and starts the game
+sudoku-help-control = Opens Sudoku's help page
+sudoku-undo-control = Takes the last digit or mark back
+sudoku-redo-control = Makes the last digit or mark taken back again
+sudoku-title = Sudoku
+sudoku-move = Moves the cursor
+sudoku-digit-control = Fills the cell in, or pencils the digit in
+sudoku-clear-control = Empties the cell
+sudoku-pencil-control = Switches between filling cells in and pencilling digits in
+sudoku-new-control = Starts a new puzzle once solved
+sudoku-time = ⏱️ { $time }
+sudoku-pencil-on = ✏️ Pencil
+sudoku-marks = Marks: { $marks }
+sudoku-solved-in = Solved in { $time }
+sudoku-hints = 💡 Hints: { $hints }
+sudoku-hints-used = 💡 { $count } { $count ->
+        [one] hint
+       *[other] hints
+    } used, adding { $time }
+sudoku-command-reveal = Fills every cell in with the solution
+sudoku-command-fill = Fills a cell in with its solution
+
+## Chess
+
+chess-description = The game of kings, against the computer or a friend on the same keyboard.
+chess-setup-title = Set up your board!
+chess-setup-select = Selects a question
+chess-setup-change = Changes the answer
+chess-opponent = Opponent
+chess-opponent-computer = Computer
+chess-opponent-human = Human
+chess-level = Computer level
+chess-start = Starts the game
+chess-help-control = Opens Chess's help page
+chess-title = Chess
+chess-move = Moves the cursor
+chess-select-control = Picks a piece up, or moves it to the square
+chess-new-control = Plays again once the game is over
+chess-white = White
+chess-black = Black
+chess-turn = ♟️ { $side } to move
+chess-thinking = 🤔 The computer is thinking…
+chess-check = ⚠️ Check!
+chess-last-move = Last move: { $from } → { $to }
+chess-checkmate = Checkmate! { $winner } wins
+chess-stalemate = Stalemate: a draw
+chess-fifty-moves = Fifty moves without a capture or a pawn move: a draw
+chess-insufficient-material = Neither side can checkmate anymore: a draw
+chess-rating = ⭐ Your rating is now { $rating }
+
+## Klondike
+
+klondike-description = The classic solitaire, turning one or three cards from the stock at once.
+klondike-setup-title = Shuffle the deck!
+klondike-setup-select = Selects a question
+klondike-setup-change = Changes the answer
+klondike-draw = Cards turned
+klondike-draw-count = { $count }
+klondike-auto-move = Auto-move
+klondike-auto-move-on = On
+klondike-auto-move-off = Off
+klondike-start = Deals the cards
+klondike-help-control = Opens Klondike's help page
+klondike-title = Klondike
+klondike-move = Moves the cursor
+klondike-select-control = Turns the stock, picks cards up, or puts them on the pile
+klondike-undo-control = Takes the last move back
+klondike-redo-control = Makes the last move taken back again
+klondike-foundation-control = Moves the card to a foundation
+klondike-new-control = Deals a new game once won
+klondike-status = 🃏 Stock: { $stock } | Moves: { $moves } | ⏱️ { $time }
+klondike-won-in = Won in { $time }
+klondike-hints = 💡 Hints: { $hints }
+klondike-hints-used = 💡 { $count } { $count ->
+        [one] hint
+       *[other] hints
+    } used, adding { $time }
+klondike-hint-stock = Turn the stock.
+klondike-hint-foundation = Move the { $card } to a foundation.
+klondike-hint-move = Move the { $card } onto the { $target }.
+klondike-hint-empty = Move the { $card } to an empty column.
+klondike-wins = 🏆 { $count } { $count ->
+        [one] game
+       *[other] games
+    } won
+
+## Scripted games
+
+scripted-description = A game scripted in { $path }.
+scripted-title = Scripted game
+scripted-any-key = Any key
+scripted-key-control = Passed to the script
+scripted-restart-control = Plays again once the game is over
+scripted-reload-control = Reloads the script from its file
+scripted-undo-control = Takes the last move back, in games that allow it
+scripted-redo-control = Makes the last move taken back again
+scripted-score = ⭐ Score: { $score }
+scripted-paused = ⏸️ Paused
+scripted-hints = 💡 Hints: { $hints }
+scripted-over = 🏁 Game over! [Enter] to play again
+scripted-error = 💥 The script stopped: { $error }
+scripted-error-title = Error (Ctrl + R reloads the script)
+
+## Multiplayer
+
+hotseat-player = Player { $number }
+pass-title = Next turn
+pass-start-control = Starts your turn
+pass-keyboard = ⌨️ Pass the keyboard to
+pass-ready = Press [Enter] when you're ready to play.
+host-title = Host a game
+host-lobby = Lobby
+host-failed = ⚠ Could not host the game: { $error }
+host-unknown-address = this computer's address
+host-waiting =
+    🖥️ Hosting a game of { $game } on port { $port }.
+
+    Other players can join with the address: { $address }{ $discoverable ->
+        [yes] {" "}or find it in their local games
+       *[no] {""}
+    }
+
+    ⏳ Waiting for a player to join...
+join-title = Join a game
+join-control = Joins the game at the address typed in
+join-switch-control = Switches between playing and watching the game
+join-focus-control = Moves between the address and the mode
+join-address = Address
+join-mode = Mode
+join-mode-playing = 🎮 Playing
+join-mode-watching = 👀 Watching
+join-connecting = ⏳ Connecting to { $address }...
+join-failed = ⚠ Could not { $mode ->
+        [watch] watch
+       *[play] join
+    } the game: { $error }
+join-instructions = Type in the address shown on the host's screen, then press [Enter] to { $mode ->
+        [watch] watch
+       *[play] join
+    } their game of { $game }. The port defaults to { $port }. Press [Tab] to go to the mode, then [Space] to switch between playing and watching.
+local-title = Join a local game
+local-select = Selects a game
+local-join-control = Joins the selected game
+local-browse-failed = ⚠ Could not browse the local network: { $error }
+local-joining = ⏳ Joining the game...
+local-join-failed = ⚠ Could not join the game: { $error }
+local-looking = 📡 Looking for games of { $game } on your local network...
+local-press-enter = Press [Enter] to join the selected game.
+net-disconnected = The other player disconnected
+net-no-answer = The other player did not answer
+net-fell-behind = The other player fell too far behind, and was disconnected
+net-no-hello-peer = The peer did not introduce itself
+net-no-hello-host = The host did not introduce itself
+net-rejected-version = You are playing a different version of Terminal Arcade
+net-rejected-game = This game of { $game } isn't a game of { $requested_game }
+net-rejected-nothing-to-watch = Nobody has joined this game yet, so there's nothing to watch
+net-rejected-full = This game already has two players, but you can watch it
+net-address-not-found = Could not find { $address }
+
+## Chat
+
+chat-title = Chat
+chat-collapsed = 💬 { $unread ->
+        [0] Chat
+        [one] { $unread } new message
+       *[other] { $unread } new messages
+    } [Tab]
+chat-toggle = Expands or collapses the chat
+chat-send = Sends the message typed in
+chat-scroll = Scrolls through the chat
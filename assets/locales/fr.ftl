@@ -0,0 +1,878 @@
+## Traduction française de Terminal Arcade. Les noms de touches comme [Enter]
+## restent tels qu'ils sont sur le clavier.
+
+## Fenêtre des contrôles
+
+controls-title = Contrôles
+controls-shortcut = Raccourci
+controls-function = Fonction
+controls-control = Contrôle
+controls-close = Ferme cet écran et revient au précédent
+controls-quit = Quitte l'application
+controls-report-bug = Enregistre un rapport de bug
+controls-screenshot = Enregistre une capture de l'écran en texte
+controls-record-session = Commence ou arrête l'enregistrement de la session
+controls-record-macro = Commence ou arrête l'enregistrement d'une macro de touches
+controls-navigate = Parcourt cette liste de contrôles
+
+## Actions
+
+action-quit = Quitte Terminal Arcade
+action-record-macro = Commence ou arrête l'enregistrement d'une macro de touches
+action-play-macro = Joue la macro de touches { $number }
+action-report-bug = Enregistre un rapport de bug
+action-screenshot = Enregistre une capture de l'écran en texte
+action-record-session = Commence ou arrête l'enregistrement de la session
+action-dev-tools = Ouvre les outils de développement
+action-up = Monte ou défile vers le haut
+action-down = Descend ou défile vers le bas
+action-left = Va à gauche
+action-right = Va à droite
+action-page-up = Remonte d'une page
+action-page-down = Descend d'une page
+action-first = Va au premier élément ou tout en haut
+action-last = Va au dernier élément ou tout en bas
+action-confirm = Confirme ou choisit l'élément sélectionné
+action-back = Ferme l'écran
+action-help = Ouvre la page d'aide de l'écran
+action-controls = Ouvre la fenêtre des contrôles de l'écran
+action-pause = Met le jeu en pause, en ouvrant son menu de pause
+action-restart = Rejoue la partie depuis le début, avec les mêmes réglages
+action-undo = Annule le dernier coup de la partie
+action-redo = Rejoue le dernier coup annulé de la partie
+action-dev-console = Ouvre ou ferme la console de développement du jeu
+quit-confirm = 🚪 Appuie encore sur { $key } pour quitter
+
+## Aide-mémoire des touches
+
+cheat-sheet-title = Touches de Terminal Arcade
+cheat-sheet-global = Sur tous les écrans
+cheat-sheet-navigation = Sur l'écran actif
+cheat-sheet-keys = Touches
+
+## Taille du terminal
+
+terminal-too-small = Le terminal est trop petit pour cet écran : il fait { $width }×{ $height }, mais il lui faut au moins { $min_width }×{ $min_height }. Agrandis-le, ou dézoome !
+terminal-cramped = ↔ Idéal en { $width }×{ $height }
+
+## Écran d'accueil
+
+welcome-tagline = Des jeux d'arcade, directement dans ton terminal.
+welcome-play = 🎮 Lance-toi dans une partie !
+welcome-surprise = 🎰 Surprends-moi !
+welcome-settings = 🗜️ Voir tes paramètres...
+welcome-about = ℹ️ À propos de Terminal Arcade...
+welcome-quit = 🛑 Quitter l'application...
+welcome-dismiss-tips = Masque les astuces du bas de l'écran
+welcome-digest = Affiche ta semaine dans l'arcade
+welcome-leaderboard = Affiche le classement
+welcome-time = ⏰ Heure : { $time }
+welcome-version = 🎮 Terminal Arcade { $version }, sur 🎋 { $branch }, commit { $commit } ; dépôt distant { $remote }
+welcome-unknown-branch = une branche inconnue
+welcome-work-in-progress = 🏗️ Terminal Arcade est en cours de développement ! Si tu veux y contribuer, n'hésite pas !
+welcome-tip = 💡 Astuce : { $tip }
+welcome-update-available = ✨ Une nouvelle version, { $version }, est disponible !
+goodbye = À la prochaine ! 👋
+
+## Écran des paramètres
+
+settings-title = Paramètres
+settings-select = Sélectionne un paramètre
+settings-toggle = Active ou désactive le paramètre sélectionné
+settings-check-for-updates = 🔔 Chercher de nouvelles versions au démarrage
+settings-check-for-updates-unavailable = 🔔 Chercher de nouvelles versions au démarrage (indisponible dans cette version)
+settings-show-tips = 💡 Afficher des astuces sur l'écran d'accueil
+settings-large-text = 🔍 Grand texte pour les menus et les infos de jeu
+settings-reduced-motion = 🐢 Réduire les animations, en affichant le texte d'un coup
+settings-attract-mode = 📺 Jouer des démos sur l'écran d'accueil quand il reste inactif
+settings-autosave-on-leave = 💾 Sauvegarder les parties en cours en les quittant
+settings-resume-games = ⏯️ Reprendre les parties en cours sauvegardées
+settings-coalesce-inputs = 🕹️ Fusionner les flèches maintenues, pour que les jeux suivent
+settings-exit-summary = 📋 Résumer la session en quittant
+settings-confirm-quit = 🚪 Appuyer deux fois sur une touche pour quitter
+settings-screenshot-ansi = 📸 Enregistrer aussi les captures avec leurs couleurs, en codes ANSI
+settings-on = ✅ Activé
+settings-off = ❌ Désactivé
+settings-backups = Ouvre les sauvegardes des données
+settings-data = Ouvre les données de chaque jeu
+settings-macros = Ouvre les macros de touches
+settings-profile = Modifie ton nom et ton avatar
+settings-reset = Réinitialise les paramètres, raccourcis clavier ou données
+settings-encryption = Active ou retire le chiffrement des données
+settings-export = Exporte tes statistiques en fichiers CSV et JSON
+settings-export-done = 📊 Statistiques exportées dans { $path }
+settings-export-failed = 📊 Impossible d'exporter les statistiques : { $error }
+settings-cheat-sheet = Écrit un aide-mémoire de tes touches en fichiers Markdown et texte
+settings-cheat-sheet-done = ⌨️ Aide-mémoire des touches écrit dans { $path }
+settings-cheat-sheet-failed = ⌨️ Impossible d'écrire l'aide-mémoire des touches : { $error }
+settings-files = Fichiers
+settings-data-dir = 📁 Données : { $path } ({ $source })
+settings-config-dir = 🗜️ Configuration : { $path } ({ $source })
+settings-sync-off = 🔄 Synchronisation : désactivée (renseigne sync_repository dans le fichier de configuration pour l'activer)
+settings-served = 🔒 Ces paramètres sont ceux du serveur, et ne peuvent pas être modifiés depuis une connexion.
+export-done = Statistiques exportées dans { $path }
+scores-exported = Scores exportés dans { $path }
+scores-checksum-mismatch = Les scores ne correspondent pas à la somme de contrôle du fichier ; ils ont peut-être été modifiés
+scores-imported = Scores de { $player } importés pour { $games } { $games ->
+        [one] jeu
+       *[other] jeux
+    } : { $improved } { $improved ->
+        [one] record bat
+       *[other] records battent
+    } les tiens
+serve-listening = L'arcade est servie sur { $address }, jusqu'à interruption
+serve-full = L'arcade est pleine, réessaie plus tard !
+files-source-default = par défaut
+files-source-argument = ligne de commande
+files-source-portable = mode portable
+
+## Résumé de la semaine
+
+digest-title = Ta semaine dans l'arcade
+digest-export = Exporte le résumé en fichier Markdown
+digest-summary = Résumé
+digest-period = 📅 Du { $start } au { $end }
+digest-plays = 🎮 { $count } { $count ->
+        [one] partie
+       *[other] parties
+    } cette semaine
+digest-no-plays = 💤 Tu n'as pas joué cette semaine
+digest-most-played = 🏆 Jeu le plus joué : { $game }, { $count } { $count ->
+        [one] fois
+       *[other] fois
+    }
+digest-streak = 🔥 Série : { $days } { $days ->
+        [one] jour
+       *[other] jours
+    } d'affilée (record : { $longest })
+digest-games = Jeux joués
+digest-game-plays = { $game } : { $count }
+digest-records = Nouveaux records
+digest-record = 🥇 { $game } : { $score }
+digest-no-records = Aucun nouveau record cette semaine
+digest-exported = 📝 Résumé exporté dans { $path }
+digest-export-failed = 📝 Impossible d'exporter le résumé : { $error }
+stats-today = ⏱ { $playtime } · 🎮 { $plays } aujourd'hui
+summary-session = ⏱ Temps de jeu : { $duration } · 🎮 { $plays } { $plays ->
+        [one] partie
+       *[other] parties
+    }
+summary-score = 🥇 Nouveau record à { $game } : { $score }
+summary-mode-score = 🥇 Nouveau record à { $game } ({ $mode }) : { $score }
+summary-time = ⏱ Nouveau meilleur temps à { $game } : { $time }
+
+## Classement
+
+leaderboard-title = Classement
+leaderboard-local = 🏠 Tes records
+leaderboard-global = 🌍 Mondial
+leaderboard-tab-control = Alterne entre tes records et le classement mondial
+leaderboard-game-control = Sélectionne le jeu
+leaderboard-submit-control = Envoie tes records au serveur de classement
+leaderboard-refresh-control = Récupère à nouveau le classement mondial
+leaderboard-no-games = Aucun jeu n'est disponible
+leaderboard-best-time = Meilleur temps
+leaderboard-no-scores = Pas encore de record dans ce jeu
+leaderboard-no-server = 🌍 Aucun serveur de classement n'est configuré : renseigne leaderboard_url dans le fichier de configuration pour partager tes records avec d'autres joueurs
+leaderboard-loading = Récupération du classement...
+leaderboard-failed = Impossible de récupérer le classement : { $error }
+leaderboard-no-rankings = Personne n'est encore classé dans ce jeu
+leaderboard-rank = #
+leaderboard-player = Joueur
+leaderboard-score = Score
+leaderboard-time = Temps
+leaderboard-submitting = 🌍 Envoi de tes records...
+leaderboard-submitted = 🌍 Tes records dans { $games } { $games ->
+        [one] jeu
+       *[other] jeux
+    } ont été envoyés
+leaderboard-submit-failed = 🌍 Impossible d'envoyer tes records : { $error }
+leaderboard-server-missing = Aucun serveur de classement n'est configuré
+leaderboard-not-built = Terminal Arcade a été compilé sans la fonctionnalité `global-leaderboard`
+
+## Sauvegardes automatiques
+
+autosave-saved = 💾 Sauvegardé
+autosave-failed = 💾 Impossible de sauvegarder la partie
+
+## Chiffrement des données
+
+passphrase-title = Phrase secrète
+passphrase-field = Phrase secrète
+passphrase-control = Valide la phrase secrète
+passphrase-unlock = 🔒 Tes données sont chiffrées. Saisis leur phrase secrète et appuie sur [Enter] pour les déverrouiller, ou appuie sur [Esc] pour jouer sans elles cette fois.
+passphrase-enable = 🔐 Choisis une phrase secrète pour chiffrer tes données, puis appuie sur [Enter]. Elle te sera demandée à chaque démarrage, et ne pourra pas être retrouvée si tu l'oublies !
+passphrase-confirm = 🔐 Saisis la phrase secrète une seconde fois pour la confirmer, puis appuie sur [Enter].
+passphrase-disable = 🔓 Saisis la phrase secrète et appuie sur [Enter] pour déchiffrer tes données pour de bon.
+passphrase-mismatch = Les phrases secrètes ne correspondent pas
+passphrase-failed = ⚠ { $error }. Réessaie, ou appuie sur [Esc] pour revenir en arrière.
+encryption-key-failed = Impossible de dériver la clé : { $error }
+encryption-encrypt-failed = Impossible de chiffrer les données
+encryption-cut-short = Les données chiffrées sont tronquées
+encryption-decrypt-failed = Impossible de déchiffrer les données, qui sont peut-être endommagées
+encryption-locked = Les données sont verrouillées
+encryption-wrong-passphrase = Mauvaise phrase secrète
+encryption-already-enabled = Les données sont déjà chiffrées
+encryption-not-enabled = Les données ne sont pas chiffrées
+
+## Synchronisation des données
+
+sync-syncing = 🔄 Synchronisation des données de sauvegarde…
+sync-synced = 🔄 Synchronisation : dernière à { $time }
+sync-failed = 🔄 Synchronisation : échec à { $time } : { $error }
+sync-conflict-title = Conflit de synchronisation
+sync-conflict-local = ⚠ Tes données ont changé à la fois sur cet ordinateur et ailleurs depuis la dernière synchronisation. Celles de cet ordinateur, les plus récentes, ont été gardées et synchronisées.
+sync-conflict-remote = ⚠ Tes données ont changé à la fois sur cet ordinateur et ailleurs depuis la dernière synchronisation. Celles de l'autre ordinateur, les plus récentes, ont été gardées. Celles de cet ordinateur ont d'abord été sauvegardées, et peuvent être restaurées depuis les paramètres.
+sync-conflict-close = Appuie sur [Enter] pour continuer.
+
+## Sauvegardes
+
+backups-title = Sauvegardes
+backups-select = Sélectionne une sauvegarde
+backups-restore-control = Restaure la sauvegarde sélectionnée
+backups-create-control = Sauvegarde les données maintenant
+backups-nothing = Il n'y a pas encore de données à sauvegarder
+backups-hint = Appuie sur [Enter] pour restaurer la sauvegarde sélectionnée, ou sur [N] pour sauvegarder maintenant. Les données actuelles sont sauvegardées avant la restauration.
+backups-none = 🗃️ Aucune sauvegarde pour l'instant !
+backups-list-failed = ⚠ Impossible de lister les sauvegardes : { $error }
+backups-restored = ✅ Sauvegarde du { $date } restaurée.
+backups-restore-failed = ⚠ Impossible de restaurer la sauvegarde : { $error }
+backups-created = ✅ Données sauvegardées le { $date }.
+backups-create-failed = ⚠ Impossible de sauvegarder les données : { $error }
+
+## Écran « À propos »
+
+about-title = À propos de Terminal Arcade
+about-block = À propos
+about-changelog = Historique des versions
+about-scroll = Fait défiler l'historique des versions
+about-page-scroll = Fait défiler l'historique de quelques lignes
+about-repository-control = Affiche l'URL du dépôt en QR code
+about-bug-control = Affiche l'URL de signalement de bugs en QR code
+about-diagnostics-control = Vérifie le terminal et tes fichiers
+about-repository = Dépôt
+about-report-bug = Signaler un bug
+about-game-credits-block = Crédits des jeux
+about-game-credits = 🎮 { $name } : { $authors }
+about-unknown-authors = auteurs inconnus
+about-text =
+    🎮 Terminal Arcade { $version } - { $description }
+    🔗 Dépôt : { $repository } ([R] pour un QR code, [B] pour signaler un bug)
+    📜 Licence : { $license }
+    👷 Crédits : { $authors }
+
+## Diagnostic
+
+diagnostics-title = Diagnostic
+diagnostics-checks = Vérifications
+diagnostics-rerun = Relance les vérifications
+doctor-failed = Des vérifications ont échoué
+doctor-not-a-terminal = Pas lancé dans un terminal
+doctor-colors = Couleurs
+doctor-colors-true = Couleurs 24 bits
+doctor-colors-256 = 256 couleurs : le jeu de couleurs est approché
+doctor-colors-basic = Couleurs de base seulement : renseigne COLORTERM=truecolor si le terminal en gère plus
+doctor-unicode = Unicode
+doctor-unicode-ok = Locale UTF-8 ({ $locale })
+doctor-unicode-windows = Console Windows
+doctor-unicode-missing = La locale ({ $locale }) n'est pas en UTF-8 : les cartes, pièces et bordures risquent de mal s'afficher
+doctor-size = Taille du terminal
+doctor-size-ok = { $width }×{ $height }
+doctor-size-small = { $width }×{ $height }, plus petit que les { $min_width }×{ $min_height } dont la plupart des écrans ont besoin
+doctor-size-unknown = Impossible de lire la taille du terminal : { $error }
+doctor-mouse = Souris
+doctor-mouse-ok = { $term } signale la souris
+doctor-mouse-unlikely = « { $term } » ne signale probablement pas la souris
+doctor-keyboard = Protocole clavier de kitty
+doctor-keyboard-ok = Pris en charge
+doctor-keyboard-missing = Non pris en charge : certaines combinaisons de touches ne se distinguent pas
+doctor-keyboard-unknown = Le terminal n'a pas répondu : { $error }
+doctor-keyboard-skipped = Vérifié seulement par la commande `terminal-arcade doctor`
+doctor-graphics = Graphismes
+doctor-graphics-protocol = Images dessinées avec { $protocol }
+doctor-config = Configuration
+doctor-config-ok = { $path } est valide
+doctor-config-missing = { $path } n'existe pas encore : les valeurs par défaut sont utilisées
+doctor-config-invalid = [{ $code }] { $error }. Les valeurs par défaut sont utilisées.
+doctor-saves = Fichiers de sauvegarde
+doctor-saves-ok = { $count } { $count ->
+        [one] fichier valide
+       *[other] fichiers valides
+    }
+doctor-saves-encrypted = , { $count } { $count ->
+        [one] chiffré non vérifié
+       *[other] chiffrés non vérifiés
+    }
+doctor-saves-invalid = { $count } fichiers sur { $total } sont invalides : { $files }
+doctor-data-dir = Dossier des données
+doctor-config-dir = Dossier de configuration
+doctor-dir-ok = { $path } est accessible en écriture
+doctor-dir-missing = { $path } n'existe pas encore, et sera créé
+doctor-dir-read-only = { $path } n'est pas accessible en écriture : { $error }
+
+## Données des jeux
+
+data-title = Données des jeux
+data-select = Sélectionne un jeu
+data-mark-control = Marque le jeu sélectionné, pour agir sur plusieurs à la fois
+data-delete-control = Supprime les données des jeux marqués ou sélectionnés
+data-reset-control = Réinitialise les statistiques des jeux marqués ou sélectionnés
+data-export-control = Exporte les fichiers des jeux marqués ou sélectionnés
+data-open-control = Ouvre le dossier de sauvegarde
+data-sort-control = Trie les jeux par la colonne suivante
+data-hint = Marque des jeux avec [Space] pour agir sur plusieurs à la fois, ou agis sur celui sélectionné.
+data-none = 💾 Aucun jeu pour l'instant !
+data-game = Jeu
+data-files = Fichiers
+data-size = Taille
+data-modified = Modifié le
+data-confirm-delete = Supprimer toutes les données de { $games } ? Leurs statistiques, sauvegardes automatiques et données de scripts seront perdues.
+data-confirm-reset = Réinitialiser les statistiques de { $games } ? Leurs nombres de parties et meilleurs scores seront perdus.
+data-deleted = ✅ Données de { $games } supprimées.
+data-reset = ✅ Statistiques de { $games } réinitialisées.
+data-exported = ✅ Fichiers de { $games } exportés dans le dossier des exports.
+data-failed = ⚠ Impossible de terminer : { $error }
+data-backup-failed = ⚠ Impossible de sauvegarder les données avant, donc rien n'a changé : { $error }
+data-open-failed = ⚠ Impossible d'ouvrir le dossier de sauvegarde : { $error }
+
+## Profil
+
+profile-title = Profil
+profile-name = Nom
+profile-avatar = Avatar
+profile-avatar-control = Choisit un autre avatar
+profile-save-control = Enregistre le profil
+profile-hint = Tape ton nom, choisis un avatar, puis appuie sur [Enter] pour enregistrer. Ton profil est affiché en haut de chaque écran, et aux autres joueurs et joueuses en ligne.
+profile-preview = Affiché comme : { $badge }
+profile-name-blank = ⚠ Le nom ne peut pas être vide.
+profile-saved = ✅ Profil enregistré : { $badge }
+profile-save-failed = ⚠ Impossible d'enregistrer le profil : { $error }
+
+## Réinitialisation
+
+reset-title = Réinitialisation
+reset-select = Sélectionne ce qui sera réinitialisé
+reset-control = Rétablit les valeurs par défaut de la sélection
+reset-hint = Appuie sur [Enter] pour réinitialiser la sélection. Tout ce qui est effacé est sauvegardé avant, et peut être restauré depuis les sauvegardes.
+reset-settings = ⚙️ Paramètres
+reset-keybindings = ⌨️ Raccourcis clavier
+reset-game = 🎮 Données de { $game }
+reset-everything = 💣 Tout
+reset-settings-confirm = Rétablir les paramètres par défaut ?
+reset-keybindings-confirm = Rétablir les raccourcis clavier par défaut ? Ils prendront effet au prochain démarrage.
+reset-game-confirm = Supprimer toutes les données de { $game } ? Ses statistiques, sauvegardes automatiques et données de scripts seront perdues.
+reset-everything-confirm = Réinitialiser les paramètres, les raccourcis clavier et toutes les données ? Tout sauf les sauvegardes sera perdu.
+reset-done = ✅ Réinitialisé : { $scope }.
+reset-done-backed-up = ✅ Réinitialisé : { $scope }. { $count } sauvegardes ont été faites avant, dans le dossier des sauvegardes.
+reset-failed = ⚠ Impossible de réinitialiser : { $error }
+
+## Macros de touches
+
+macros-title = Macros de touches
+macros-select = Sélectionne une macro
+macros-rename-control = Renomme la macro sélectionnée
+macros-remove-control = Supprime la macro sélectionnée
+macros-record-control = Commence ou arrête l'enregistrement d'une macro, sur n'importe quel écran
+macros-hint = Appuie sur [F9] sur n'importe quel écran pour enregistrer les touches que tu presses dans une macro, et à nouveau pour arrêter. [Alt]+[1] à [Alt]+[9] rejouent les macros.
+macros-none = ⌨️ Pas encore de macros !
+macros-sort-control = Trie les macros par la colonne suivante
+macros-hotkey = Raccourci
+macros-name = Nom
+macros-inputs = Touches
+macros-default-name = Macro { $number }
+macros-renaming = ✏️ Nouveau nom : { $name }█ ([Enter] pour renommer)
+macros-renamed = ✅ Macro renommée en { $name }.
+macros-removed = ✅ { $name } supprimée.
+macros-recording = ⏺ Enregistrement d'une macro, [F9] pour arrêter
+macros-saved = ✅ { $name } enregistrée, rejouée avec { $hotkey }
+macros-save-failed = ⚠ Impossible d'enregistrer les macros : { $error }
+macros-empty = Rien n'a été enregistré
+macros-full = ⚠ Il y a déjà { $max } macros
+macros-playing = ▶ { $name } rejouée
+macros-play-failed = ⚠ Impossible de rejouer la macro : { $error }
+
+## Préréglages
+presets-save-control = Enregistre les réponses comme préréglage
+presets-pick-control = Choisit le préréglage suivant
+presets-remove-control = Supprime le préréglage choisi
+presets-none = Pas encore de préréglages. Appuie sur [S] pour enregistrer ces réponses.
+presets-list = Préréglages : { $presets }
+presets-naming = ✏️ Nom du préréglage : { $name }█ ([Enter] pour enregistrer)
+presets-saved = ✅ Préréglage { $name } enregistré.
+presets-picked = ✅ Préréglage { $name } choisi.
+presets-removed = ✅ Préréglage { $name } supprimé.
+presets-save-failed = ⚠ Impossible d'enregistrer les préréglages : { $error }
+
+## Erreurs
+
+error-title = Erreur
+error-title-code = Erreur { $code }
+error-close = Appuie sur [Enter] pour continuer.
+error-config-parse = { $path } est invalide à la ligne { $line }, colonne { $column } : { $message }
+error-config-parse-hint = Corrige cette ligne, ou supprime le fichier pour revenir aux paramètres par défaut.
+error-save-parse = Le fichier de sauvegarde { $path } est invalide à la ligne { $line }, colonne { $column } : { $message }
+error-save-parse-hint = Restaure une sauvegarde depuis les paramètres, ou supprime le fichier pour réinitialiser les données de ce jeu.
+error-save-moved = Le fichier de sauvegarde cassé a été déplacé vers { $path }
+error-save-encrypted = { $path } est chiffré, et ne peut être ni lu ni écrasé sans la phrase secrète
+error-save-encrypted-hint = Déverrouille les données avec leur phrase secrète, dans une version avec le chiffrement.
+error-file-access = Impossible d'accéder à { $path } : { $error }
+error-file-access-hint = Lance `terminal-arcade doctor` pour vérifier que les dossiers de sauvegarde et de configuration sont accessibles en écriture.
+error-screen-panicked = L'écran « { $screen } » a planté et a été fermé : { $message }. Un rapport de bug a été enregistré dans { $path }.
+error-screen-panicked-unsaved = L'écran « { $screen } » a planté et a été fermé : { $message }. Son rapport de bug n'a pas pu être enregistré.
+error-screen-panicked-hint = Le reste de la session peut continuer. Joins le rapport de bug à un ticket, pour que le plantage soit corrigé.
+error-render-stalled = Aucune image n'a été dessinée pendant { $seconds } secondes (terminal : { $terminal }). Le terminal sera réinitialisé dès que le dessin reprendra.
+error-render-stalled-hint = Si l'écran reste figé, vérifie la connexion au terminal. Le redimensionner redessine aussi l'écran.
+error-unknown-hint = Si ça se reproduit, appuie sur [F12] pour enregistrer un rapport de bug.
+
+## Rapports de bug
+
+bug-report-saved =
+    ✅ Rapport de bug enregistré dans { $path }.
+    Joins-le à un nouveau ticket, ouvert à :
+bug-report-failed =
+    ⚠ Impossible d'enregistrer un rapport de bug : { $error }
+    Décris le bug dans un nouveau ticket, ouvert à :
+bug-report-hint =
+    Appuie sur [F12] sur l'écran où le bug est arrivé pour enregistrer un
+    rapport de bug, puis joins-le à un nouveau ticket, ouvert à :
+panic-message =
+    Désolé, quelque chose s'est mal passé ! 🫤
+    Si tu penses que c'est un bug, ouvre un ticket sur https://github.com/developer-ramen/terminal-arcade pour qu'il soit corrigé au plus vite !
+
+## Captures d'écran
+
+screenshot-saved = 📸 Capture enregistrée dans { $path }
+screenshot-failed = 📸 Impossible d'enregistrer la capture : { $error }
+
+## Enregistrements de session
+
+recording-title = Terminal Arcade
+recording-on = ⏺ Enregistrement de la session
+recording-saved = ⏺ Enregistrement sauvegardé dans { $path }
+recording-failed = ⏺ Impossible de sauvegarder l'enregistrement : { $error }
+
+## Statistiques d'affichage (outils de développement)
+
+render-slow = 🐢 { $screen } a mis { $ms } ms à s'afficher, au-delà de son budget de { $budget } ms
+input-slow = ⌛ Une entrée a attendu { $ms } ms avant d'être jouée, avec { $waiting } en attente
+
+## Rejeu des événements (outils de développement)
+
+event-replay-title = Rejeu des événements
+event-replay-events = Derniers événements
+event-replay-preview = Rejeu
+event-replay-select = Parcourt les événements
+event-replay-jump = Va au plus ancien ou au plus récent événement
+event-replay-mark = Marque l'événement sélectionné comme début de la plage rejouée
+event-replay-target = Choisit l'écran sur lequel rejouer les événements
+event-replay-control = Rejoue la plage d'événements sur un nouvel écran
+event-replay-hint = Appuie sur [Enter] pour rejouer les événements jusqu'à celui sélectionné, depuis celui marqué avec [Space] ou le plus ancien, sur un nouvel écran d'accueil. [Tab] choisit un autre écran.
+event-replay-target-picked = Rejoué sur : { $screen }
+event-replay-done = ✅ { $count } événement(s) rejoué(s) sur : { $screen }
+event-replay-failed = ⚠ Le rejeu a échoué : { $error }
+event-replay-none = Rien de rejoué pour l'instant.
+event-replay-closed = Tous les écrans ont été fermés
+
+## Console de développement
+
+dev-console-title = Console de développement - [F10] la ferme
+dev-console-help = Liste les commandes
+dev-console-unknown = Commande inconnue : { $name }. Tape « help » pour lister les commandes.
+dev-console-usage = Utilisation : { $usage }
+
+## Fenêtres d'aide et de QR code
+
+help-title = Aide
+help-scroll = Fait défiler la page d'aide
+help-page-scroll = Fait défiler la page d'aide de quelques lignes
+help-jump = Va au début ou à la fin de la page d'aide
+qr-title = QR code
+qr-too-long = ⚠ C'est trop long pour un QR code !
+
+## Écran de sélection des jeux
+
+search-title = Cherche un jeu !
+search-preview = Aperçu
+search-back = ⏪ Retour
+search-placeholder = Rechercher...
+search-placeholder-recent = Rechercher... (↑ récent : { $term })
+search-results = Terminé en { $seconds } secondes, { $count } { $count ->
+        [one] résultat trouvé
+       *[other] résultats trouvés
+    }, { $per_page } résultats affichés à la fois.
+search-kind-setting = ⚙️ Paramètre
+search-kind-help = 📖 Aide
+search-setting-hint = Ouvre les paramètres sur cette option
+search-difficulty = Difficulté
+search-filter-control = Filtrer par difficulté
+search-sort-control = Trier par difficulté
+search-launch-control = Ouvrir le résultat numéroté
+search-created = 👷 Créé en : { $created }
+search-difficulty-stars = 💪 Difficulté : { $difficulty }
+search-best-scores = 🏅 Records
+search-best-score = { $mode } : { $score }
+search-no-best-score = { $mode } : —
+search-best-time = ⏱ Meilleur temps : { $time }
+difficulty-all = Toutes
+difficulty-unsorted = non trié
+difficulty-easiest-first = plus faciles d'abord
+difficulty-hardest-first = plus durs d'abord
+difficulty-easy = Facile
+difficulty-medium = Moyen
+difficulty-hard = Difficile
+difficulty-expert = Expert
+game-created = v{ $version }
+game-created-by = v{ $version } par { $authors }
+game-entry =
+    📄 Description : { $description }
+    👷 Créé en : { $created }, 💪 difficulté : { $difficulty }
+    { $status }
+game-played = 🕹️ Joué { $count } { $count ->
+        [one] fois
+       *[other] fois
+    }, 🌗 dernière partie le { $date }{ $rating }
+game-rated = , ⭐ classement { $rating }
+game-high-scores = , 🏅 record { $scores }
+game-best-time = , ⏱ meilleur temps { $time }
+game-wins = , 🏆 { $count } { $count ->
+        [one] victoire
+       *[other] victoires
+    }
+result-new-best-score = 🏅 Nouveau meilleur score !
+result-best-score = Meilleur score : { $score }
+result-new-best-time = ⏱ Nouveau meilleur temps !
+result-best-time = Meilleur temps : { $time }
+results-won = VICTOIRE
+results-over = FIN DE PARTIE
+results-score = ⭐ Score : { $score }
+results-seed = 🎲 Graine : { $seed }
+results-seed-copy = [C] Copier la graine | [S] Rejouer cette graine
+results-seed-copied = 📋 Graine copiée | [S] Rejouer cette graine
+badge-new = ✨ Nouveau !
+badge-updated = 🔄 Mis à jour
+game-never-played = 🆕 Jamais joué !
+roulette-title = 🎰 Surprise !
+
+## Modes de jeu
+
+mode-select-title = Choisis un mode
+mode-select-control = Lance le jeu dans le mode sélectionné
+mode-standard = Standard
+mode-standard-description = Le jeu tel qu'il a été conçu
+mode-time-trial = Contre-la-montre
+mode-time-trial-description = Marque un maximum de points en 2 minutes
+mode-endless = Sans fin
+mode-endless-description = Perdre lance une nouvelle manche, score conservé
+mode-hardcore = Hardcore
+mode-hardcore-description = Pas de sauvegarde, pas d'annulation, pas de rechargement
+mode-countdown = ⏱️ { $time }
+start-countdown-go = Partez !
+
+## Migration des données
+
+migration-title = Déplace tes données
+migration-intro = 📦 Terminal Arcade range maintenant ses fichiers dans les dossiers habituels de ton système, mais tes données sont encore dans l'ancien dossier, { $from }. Elles peuvent être déplacées vers :
+migration-data-dir = 📁 Données : { $path }
+migration-config-dir = 🗜️ Configuration : { $path }
+migration-prompt = Appuie sur [Enter] pour les déplacer, ou sur [Esc] pour passer pour l'instant et repartir de zéro. La question te sera reposée la prochaine fois.
+migration-done = ✅ { $count } { $count ->
+        [one] fichier déplacé
+       *[other] fichiers déplacés
+    }, et l'ancien dossier a été supprimé. Appuie sur [Enter] pour continuer.
+migration-failed = ⚠ Impossible de déplacer les données : { $error }. L'ancien dossier n'a pas été modifié. Appuie sur [Enter] pour continuer.
+migration-files = Fichiers à déplacer
+migration-list-failed = ⚠ Impossible de lister les fichiers : { $error }
+migration-control = Déplace les données, puis continue
+
+## Tutoriels
+
+tutorial-step = 🎓 Tutoriel - étape { $step }/{ $steps }
+tutorial-continue = Appuie sur [Enter] pour continuer.
+
+## Démos
+
+demo-watching = 👀 Démo - appuie sur une touche pour arrêter de regarder
+
+## Menu de pause
+
+pause-menu-title = Pause
+confirm-title = Confirmer ?
+confirm-answers = [Y] Oui | [N] Non
+pause-menu-control = Met le jeu en pause
+quick-restart-control = Recommence la partie avec les mêmes réglages
+quick-restart-confirm = Recommencer la partie ? Ta progression dans celle-ci sera perdue.
+pause-menu-resume = ▶️ Reprendre
+pause-menu-hint = 💡 Obtenir un indice (coûte des points ou du temps)
+pause-menu-help = 📖 Lire le manuel
+pause-menu-leave = 🚪 Quitter la partie
+pause-menu-no-hint = 🤷 Pas d'indice à donner pour le moment.
+
+## Démineur
+
+minesweeper-description = Un jeu de cases où l'on cherche des mines en fuyant ses responsabilités.
+minesweeper-setup-title = Mine ton terrain !
+minesweeper-tutorial-control = Lance le tutoriel interactif
+minesweeper-help-control = Ouvre la page d'aide du Démineur
+minesweeper-tutorial-hint = 🎓 Nouveau au Démineur ? Appuie sur [T] pour un tutoriel interactif, [D] pour regarder une partie de démo, ou [F1] pour le manuel.
+minesweeper-demo-control = Regarde une partie de démo
+minesweeper-setup-select = Sélectionne une question
+minesweeper-setup-change = Change la réponse
+minesweeper-width = Largeur
+minesweeper-height = Hauteur
+minesweeper-mines = Mines
+minesweeper-demo-title = Démo du Démineur
+minesweeper-demo-cleared = 🏆 Plateau nettoyé ! Une autre partie commence dans un instant...
+minesweeper-demo-exploded = 💥 Boum ! Une autre partie commence dans un instant...
+minesweeper-tutorial-title = Tutoriel du Démineur
+minesweeper-tutorial-welcome = Bienvenue au Démineur ! Ce tutoriel t'explique les bases du jeu.
+minesweeper-tutorial-board = Voici le plateau. Toutes les cases sont couvertes au départ, et certaines cachent des mines. Découvre toutes les cases sans mine pour gagner !
+minesweeper-tutorial-uncover = Ton curseur est sur la case en surbrillance. Appuie sur [Space] pour la découvrir - la première case découverte n'est jamais une mine.
+minesweeper-tutorial-numbers = Les cases découvertes indiquent combien de leurs voisines cachent une mine. Les cases vides n'en ont aucune, alors leurs voisines ont été découvertes pour toi.
+minesweeper-tutorial-deduce = Ce 1 ne touche qu'une seule case couverte, celle à sa droite. Cette case cache forcément une mine !
+minesweeper-tutorial-move = Déplace ton curseur sur la mine avec les flèches.
+minesweeper-tutorial-flag = Appuie sur [F] pour marquer la mine d'un drapeau, afin de ne pas la découvrir par accident.
+minesweeper-tutorial-safe = Ce 1 a déjà sa mine marquée, donc son autre voisine couverte est sans danger.
+minesweeper-tutorial-finish = Va sur la dernière case couverte et appuie sur [Space] pour la découvrir.
+minesweeper-tutorial-done = Tu as déminé le plateau - c'est tout ce qu'il y a à savoir ! Appuie sur [F1] en jeu pour lire le manuel à tout moment.
+
+## Tron
+
+tron-description = Une course de motos de lumière en réseau, où le dernier en piste gagne.
+tron-setup-title = Enfourche ta moto de lumière !
+tron-select = Sélectionne une option
+tron-start = Héberge ou rejoint une partie
+tron-help-control = Ouvre la page d'aide de Tron
+tron-host = 🖥️ Héberger une partie qu'un autre joueur peut rejoindre
+tron-join-local = 📡 Rejoindre une partie sur ton réseau local
+tron-join = 🔌 Rejoindre une partie par son adresse
+tron-watch-demo = 📺 Regarder une partie de démo
+tron-demo-title = Démo de Tron
+tron-demo-won = 🏆 Le joueur { $player } a gagné la manche !
+tron-demo-draw = 🤝 Match nul !
+tron-turn = Fait tourner ta moto
+tron-restart-control = Lance une autre manche une fois finie (hôte uniquement)
+tron-zoom-control = Agrandit l'arène, ou la ramène à 1×1 après 3×2
+tron-chat-control = Ouvre ou ferme le chat
+tron-guest = Invité
+tron-ping = 📶 Ping : { $ms } ms
+tron-ping-unknown = 📶 Ping : ...
+tron-peer-left = 🔌 { $name } a quitté la partie.
+tron-peer-paused = ⏸️ { $name } a mis la partie en pause.
+tron-restart-host = Appuie sur [R] pour une autre manche.
+tron-restart-wait = En attente d'une nouvelle manche lancée par l'hôte...
+tron-won = 🏆 Tu as gagné la manche ! { $hint }
+tron-crashed = 💥 Tu t'es écrasé ! { $hint }
+tron-other-won = 🏆 { $name } a gagné la manche ! { $hint }
+tron-draw = 🤝 Match nul ! { $hint }
+tron-ride = 🏍️ Roule !
+tron-spectating = 👀 Spectateur
+tron-watching = 👀 { $count } { $count ->
+        [one] spectateur
+       *[other] spectateurs
+    }
+
+## 2048
+
+twenty-forty-eight-description = Un casse-tête de tuiles qui glissent et fusionnent, jusqu'à 2048.
+twenty-forty-eight-setup-title = Choisis ta grille !
+twenty-forty-eight-setup-select = Sélectionne une question
+twenty-forty-eight-setup-change = Change la réponse
+twenty-forty-eight-size = Taille de la grille
+twenty-forty-eight-start = Lance la partie
+twenty-forty-eight-help-control = Ouvre la page d'aide de 2048
+twenty-forty-eight-title = 2048
+twenty-forty-eight-slide = Fait glisser les tuiles
+twenty-forty-eight-restart-control = Rejoue une fois la partie terminée
+twenty-forty-eight-undo-control = Annule le dernier glissement
+twenty-forty-eight-redo-control = Refait le dernier glissement annulé
+twenty-forty-eight-status = ⭐ Score : { $score } | 🏆 Record : { $best }
+twenty-forty-eight-hints = 💡 Indices : { $hints }
+twenty-forty-eight-hints-used = 💡 { $count } { $count ->
+        [one] indice utilisé
+       *[other] indices utilisés
+    }, pour { $points } points
+twenty-forty-eight-hint-up = Faites glisser les tuiles vers le haut.
+twenty-forty-eight-hint-down = Faites glisser les tuiles vers le bas.
+twenty-forty-eight-hint-left = Faites glisser les tuiles vers la gauche.
+twenty-forty-eight-hint-right = Faites glisser les tuiles vers la droite.
+twenty-forty-eight-command-score = Ajoute des points au score
+twenty-forty-eight-command-score-done = Score : { $score }
+twenty-forty-eight-command-tile = Place une tuile, ou vide la case avec 0
+
+## Sudoku
+
+sudoku-description = Une grille de chiffres à remplir, sans en répéter un dans une ligne, une colonne ou un carré.
+sudoku-setup-title = Choisis ta grille !
+sudoku-setup-select = Sélectionne une question
+sudoku-setup-change = Change la réponse
+sudoku-difficulty = Difficulté
+sudoku-start = Génère la grille et lance la partie
+sudoku-help-control = Ouvre la page d'aide du Sudoku
+sudoku-undo-control = Annule le dernier chiffre ou la dernière marque
+sudoku-redo-control = Refait le dernier chiffre ou la dernière marque annulés
+sudoku-title = Sudoku
+sudoku-move = Déplace le curseur
+sudoku-digit-control = Remplit la case, ou y note le chiffre au crayon
+sudoku-clear-control = Vide la case
+sudoku-pencil-control = Alterne entre remplir les cases et y noter des chiffres au crayon
+sudoku-new-control = Lance une nouvelle grille une fois résolue
+sudoku-time = ⏱️ { $time }
+sudoku-pencil-on = ✏️ Crayon
+sudoku-marks = Notes : { $marks }
+sudoku-solved-in = Résolue en { $time }
+sudoku-hints = 💡 Indices : { $hints }
+sudoku-hints-used = 💡 { $count } { $count ->
+        [one] indice utilisé
+       *[other] indices utilisés
+    }, soit { $time } de plus
+sudoku-command-reveal = Remplit toutes les cases avec la solution
+sudoku-command-fill = Remplit une case avec sa solution
+
+## Échecs
+
+chess-description = Le jeu des rois, contre l'ordinateur ou un ami sur le même clavier.
+chess-setup-title = Prépare ton échiquier !
+chess-setup-select = Sélectionne une question
+chess-setup-change = Change la réponse
+chess-opponent = Adversaire
+chess-opponent-computer = Ordinateur
+chess-opponent-human = Humain
+chess-level = Niveau de l'ordinateur
+chess-start = Lance la partie
+chess-help-control = Ouvre la page d'aide des Échecs
+chess-title = Échecs
+chess-move = Déplace le curseur
+chess-select-control = Prend une pièce, ou la déplace sur la case
+chess-new-control = Rejoue une fois la partie finie
+chess-white = Blancs
+chess-black = Noirs
+chess-turn = ♟️ Aux { $side } de jouer
+chess-thinking = 🤔 L'ordinateur réfléchit…
+chess-check = ⚠️ Échec !
+chess-last-move = Dernier coup : { $from } → { $to }
+chess-checkmate = Échec et mat ! Les { $winner } gagnent
+chess-stalemate = Pat : match nul
+chess-fifty-moves = Cinquante coups sans prise ni coup de pion : match nul
+chess-insufficient-material = Aucun camp ne peut plus mater : match nul
+chess-rating = ⭐ Ton classement est maintenant de { $rating }
+
+## Klondike
+
+klondike-description = La réussite classique, en retournant une ou trois cartes de la pioche à la fois.
+klondike-setup-title = Bats les cartes !
+klondike-setup-select = Sélectionne une question
+klondike-setup-change = Change la réponse
+klondike-draw = Cartes retournées
+klondike-draw-count = { $count }
+klondike-auto-move = Placement automatique
+klondike-auto-move-on = Activé
+klondike-auto-move-off = Désactivé
+klondike-start = Distribue les cartes
+klondike-help-control = Ouvre la page d'aide du Klondike
+klondike-title = Klondike
+klondike-move = Déplace le curseur
+klondike-select-control = Retourne la pioche, prend des cartes, ou les pose sur la pile
+klondike-undo-control = Annule le dernier coup
+klondike-redo-control = Rejoue le dernier coup annulé
+klondike-foundation-control = Envoie la carte sur une fondation
+klondike-new-control = Distribue une nouvelle partie une fois gagnée
+klondike-status = 🃏 Pioche : { $stock } | Coups : { $moves } | ⏱️ { $time }
+klondike-won-in = Gagnée en { $time }
+klondike-hints = 💡 Indices : { $hints }
+klondike-hints-used = 💡 { $count } { $count ->
+        [one] indice utilisé
+       *[other] indices utilisés
+    }, soit { $time } de plus
+klondike-hint-stock = Retournez la pioche.
+klondike-hint-foundation = Déplacez le { $card } sur une fondation.
+klondike-hint-move = Déplacez le { $card } sur le { $target }.
+klondike-hint-empty = Déplacez le { $card } sur une colonne vide.
+klondike-wins = 🏆 { $count } { $count ->
+        [one] partie gagnée
+       *[other] parties gagnées
+    }
+
+## Jeux scriptés
+
+scripted-description = Un jeu scripté dans { $path }.
+scripted-title = Jeu scripté
+scripted-any-key = Toute touche
+scripted-key-control = Transmise au script
+scripted-restart-control = Rejoue une fois la partie terminée
+scripted-reload-control = Recharge le script depuis son fichier
+scripted-undo-control = Annule le dernier coup, dans les jeux qui le permettent
+scripted-redo-control = Rejoue le dernier coup annulé
+scripted-score = ⭐ Score : { $score }
+scripted-paused = ⏸️ En pause
+scripted-hints = 💡 Indices : { $hints }
+scripted-over = 🏁 Partie terminée ! [Enter] pour rejouer
+scripted-error = 💥 Le script s'est arrêté : { $error }
+scripted-error-title = Erreur (Ctrl + R recharge le script)
+
+## Multijoueur
+
+hotseat-player = Joueur { $number }
+pass-title = Tour suivant
+pass-start-control = Commence ton tour
+pass-keyboard = ⌨️ Passe le clavier à
+pass-ready = Appuie sur [Enter] quand tu es prêt à jouer.
+host-title = Héberger une partie
+host-lobby = Salon
+host-failed = ⚠ Impossible d'héberger la partie : { $error }
+host-unknown-address = l'adresse de cet ordinateur
+host-waiting =
+    🖥️ Partie de { $game } hébergée sur le port { $port }.
+
+    Les autres joueurs peuvent la rejoindre avec l'adresse : { $address }{ $discoverable ->
+        [yes] {" "}ou la trouver dans leurs parties locales
+       *[no] {""}
+    }
+
+    ⏳ En attente d'un joueur...
+join-title = Rejoindre une partie
+join-control = Rejoint la partie à l'adresse saisie
+join-switch-control = Bascule entre jouer et regarder la partie
+join-focus-control = Passe de l'adresse au mode, et inversement
+join-address = Adresse
+join-mode = Mode
+join-mode-playing = 🎮 Jouer
+join-mode-watching = 👀 Regarder
+join-connecting = ⏳ Connexion à { $address }...
+join-failed = ⚠ Impossible de { $mode ->
+        [watch] regarder
+       *[play] rejoindre
+    } la partie : { $error }
+join-instructions = Saisis l'adresse affichée sur l'écran de l'hôte, puis appuie sur [Enter] pour { $mode ->
+        [watch] regarder
+       *[play] rejoindre
+    } sa partie de { $game }. Le port par défaut est { $port }. Appuie sur [Tab] pour passer au mode, puis sur [Space] pour basculer entre jouer et regarder.
+local-title = Rejoindre une partie locale
+local-select = Sélectionne une partie
+local-join-control = Rejoint la partie sélectionnée
+local-browse-failed = ⚠ Impossible de parcourir le réseau local : { $error }
+local-joining = ⏳ Connexion à la partie...
+local-join-failed = ⚠ Impossible de rejoindre la partie : { $error }
+local-looking = 📡 Recherche de parties de { $game } sur ton réseau local...
+local-press-enter = Appuie sur [Enter] pour rejoindre la partie sélectionnée.
+net-disconnected = L'autre joueur s'est déconnecté
+net-no-answer = L'autre joueur n'a pas répondu
+net-fell-behind = L'autre joueur avait trop de retard, et a été déconnecté
+net-no-hello-peer = L'autre joueur ne s'est pas présenté
+net-no-hello-host = L'hôte ne s'est pas présenté
+net-rejected-version = Tu joues à une autre version de Terminal Arcade
+net-rejected-game = Cette partie de { $game } n'est pas une partie de { $requested_game }
+net-rejected-nothing-to-watch = Personne n'a encore rejoint cette partie, il n'y a donc rien à regarder
+net-rejected-full = Cette partie a déjà deux joueurs, mais tu peux la regarder
+net-address-not-found = Impossible de trouver { $address }
+
+## Chat
+
+chat-title = Chat
+chat-collapsed = 💬 { $unread ->
+        [0] Chat
+        [one] { $unread } nouveau message
+       *[other] { $unread } nouveaux messages
+    } [Tab]
+chat-toggle = Déplie ou replie le chat
+chat-send = Envoie le message saisi
+chat-scroll = Fait défiler le chat
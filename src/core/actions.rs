@@ -0,0 +1,576 @@
+//! Actions, like moving up or quitting, resolved from input events against the
+//! keybindings of a [context](ActionContext), so that keys can be remapped and
+//! other input, like macros, goes through the same path as the keyboard.
+//!
+//! The [handler](crate::core::handler) resolves [global](ActionContext::Global)
+//! actions first, like quitting or reporting a bug. Screens then get the
+//! [navigation](ActionContext::Navigation) actions in
+//! [`Screen::handle_action`](crate::ui::Screen::handle_action), and the raw
+//! events they don't handle as actions in
+//! [`Screen::handle_event`](crate::ui::Screen::handle_event).
+//!
+//! Only keys no action stands for are left to raw events: typed text and
+//! digits, like the game search or Sudoku's digits, letter shortcuts to a
+//! screen's own commands, like the settings' exports or Klondike's
+//! foundations, Tron's WASD steering, and the keys scripted games get by name.
+//!
+//! Keybindings are read from the [configuration directory](get_config_dir)'s
+//! [`KEYBINDINGS_FILE`], with keys written the way
+//! [scripts](crate::core::script) write them:
+//!
+//! ```toml
+//! [navigation]
+//! up = "up"
+//! k = "up"
+//! down = "down"
+//! j = "down"
+//! ```
+//!
+//! A context listed in the file replaces its default keybindings, which
+//! contexts left out keep. Quitting can't be unbound: if no key is bound to
+//! it, its default keys are bound again.
+//!
+//! With [`Config::confirm_quit`] on, quitting takes [pressing](confirm_quit)
+//! a quit key twice in a row.
+
+use std::{
+	collections::{
+		BTreeMap,
+		HashSet,
+	},
+	path::PathBuf,
+	sync::{
+		LazyLock,
+		Mutex,
+		OnceLock,
+	},
+	time::Duration,
+};
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+use crate::{
+	core::{
+		config::Config,
+		events::{
+			Event,
+			KeyEventKind,
+		},
+		get_config_dir,
+		macros::MAX_MACROS,
+		script::{
+			format_key,
+			parse_key,
+		},
+		time::Instant,
+	},
+	t,
+};
+
+/// Name of the file keybindings are read from, in the [configuration
+/// directory](get_config_dir).
+pub const KEYBINDINGS_FILE: &str = "keybindings.toml";
+
+/// Keys bound to quitting by default.
+const DEFAULT_QUIT_KEYS: [&str; 3] = ["ctrl+q", "ctrl+c", "alt+f4"];
+
+/// How long after a first press of a quit key a second one quits, when
+/// [confirming](confirm_quit) is on.
+const QUIT_CONFIRM_WINDOW: Duration = Duration::from_secs(2);
+
+/// The keybindings, read on first use.
+static KEYBINDINGS: OnceLock<Keybindings> = OnceLock::new();
+
+/// Labels made by [`keys_label`], kept for the rest of the run so that they
+/// can be handed out as `&'static str`.
+static KEYS_LABELS: LazyLock<Mutex<HashSet<&'static str>>> = LazyLock::new(Mutex::default);
+
+/// When a quit key was last pressed without quitting, and its label.
+static QUIT_PRESSED: Mutex<Option<(Instant, String)>> = Mutex::new(None);
+
+/// Gets the path of the keybindings file.
+#[must_use]
+pub fn keybindings_file_path() -> PathBuf {
+	get_config_dir().join(KEYBINDINGS_FILE)
+}
+
+/// Where an action applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionContext {
+	/// On every screen, before the screen gets the input.
+	Global,
+
+	/// On the active screen.
+	Navigation,
+}
+
+/// Something the player asks for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+	/// Quits Terminal Arcade.
+	Quit,
+
+	/// Starts or stops recording an [input macro](crate::core::macros).
+	RecordMacro,
+
+	/// Plays an [input macro](crate::core::macros), by its number from 1.
+	PlayMacro(usize),
+
+	/// Saves a [bug report](crate::core::bug_report).
+	ReportBug,
+
+	/// Saves a [screenshot](crate::core::screenshot) of the screens as text.
+	Screenshot,
+
+	/// Starts or stops [recording](crate::core::recording) the session.
+	RecordSession,
+
+	/// Opens the developer tools, if they're enabled.
+	DevTools,
+
+	/// Moves or scrolls up.
+	Up,
+
+	/// Moves or scrolls down.
+	Down,
+
+	/// Moves left.
+	Left,
+
+	/// Moves right.
+	Right,
+
+	/// Scrolls up by a page.
+	PageUp,
+
+	/// Scrolls down by a page.
+	PageDown,
+
+	/// Goes to the first item or the top.
+	First,
+
+	/// Goes to the last item or the bottom.
+	Last,
+
+	/// Confirms or picks the selected item.
+	Confirm,
+
+	/// Closes the screen.
+	Back,
+
+	/// Opens the screen's help page.
+	Help,
+
+	/// Opens the screen's controls popup.
+	Controls,
+
+	/// Opens or closes a game's [pause
+	/// menu](crate::ui::components::pause_menu).
+	Pause,
+
+	/// Plays a game again from the start, with the same settings and
+	/// [seed](crate::services::rng).
+	Restart,
+
+	/// Takes a game's latest move back, in games that [allow
+	/// it](crate::games::modes::GameMode::allows_undo).
+	Undo,
+
+	/// Makes a game's latest move [undone](Self::Undo) again.
+	Redo,
+
+	/// Opens or closes a game's [developer
+	/// console](crate::ui::components::dev_console).
+	#[cfg(feature = "dev-console")]
+	DevConsole,
+}
+
+impl Action {
+	/// Returns what the action does, like "Quits Terminal Arcade".
+	#[must_use]
+	pub fn label(self) -> String {
+		match self {
+			Self::Quit => t!("action-quit"),
+			Self::RecordMacro => t!("action-record-macro"),
+			Self::PlayMacro(number) => return t!("action-play-macro", number = number),
+			Self::ReportBug => t!("action-report-bug"),
+			Self::Screenshot => t!("action-screenshot"),
+			Self::RecordSession => t!("action-record-session"),
+			Self::DevTools => t!("action-dev-tools"),
+			Self::Up => t!("action-up"),
+			Self::Down => t!("action-down"),
+			Self::Left => t!("action-left"),
+			Self::Right => t!("action-right"),
+			Self::PageUp => t!("action-page-up"),
+			Self::PageDown => t!("action-page-down"),
+			Self::First => t!("action-first"),
+			Self::Last => t!("action-last"),
+			Self::Confirm => t!("action-confirm"),
+			Self::Back => t!("action-back"),
+			Self::Help => t!("action-help"),
+			Self::Controls => t!("action-controls"),
+			Self::Pause => t!("action-pause"),
+			Self::Restart => t!("action-restart"),
+			Self::Undo => t!("action-undo"),
+			Self::Redo => t!("action-redo"),
+			#[cfg(feature = "dev-console")]
+			Self::DevConsole => t!("action-dev-console"),
+		}
+		.to_string()
+	}
+}
+
+/// Actions bound to keys, by context. See the [module](self) documentation
+/// for more information.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[must_use]
+pub struct Keybindings {
+	/// Actions bound on every screen.
+	pub global: BTreeMap<String, Action>,
+
+	/// Actions bound on the active screen.
+	pub navigation: BTreeMap<String, Action>,
+}
+
+impl Default for Keybindings {
+	fn default() -> Self {
+		let mut global = DEFAULT_QUIT_KEYS
+			.into_iter()
+			.map(|key| (key, Action::Quit))
+			.chain([
+				("f7", Action::RecordSession),
+				("f8", Action::Screenshot),
+				("f9", Action::RecordMacro),
+				("f11", Action::DevTools),
+				("f12", Action::ReportBug),
+			])
+			.map(|(key, action)| (key.to_string(), action))
+			.collect::<BTreeMap<_, _>>();
+		global.extend(
+			(1..=MAX_MACROS).map(|number| (format!("alt+{number}"), Action::PlayMacro(number))),
+		);
+		let navigation = BTreeMap::from([
+			("up", Action::Up),
+			("down", Action::Down),
+			("left", Action::Left),
+			("right", Action::Right),
+			("pageup", Action::PageUp),
+			("pagedown", Action::PageDown),
+			("home", Action::First),
+			("end", Action::Last),
+			("enter", Action::Confirm),
+			("esc", Action::Back),
+			("f1", Action::Help),
+			("ctrl+h", Action::Controls),
+			("f2", Action::Pause),
+			("f5", Action::Restart),
+			("ctrl+z", Action::Undo),
+			("ctrl+y", Action::Redo),
+			#[cfg(feature = "dev-console")]
+			("f10", Action::DevConsole),
+		])
+		.into_iter()
+		.map(|(key, action)| (key.to_string(), action))
+		.collect();
+		Self { global, navigation }
+	}
+}
+
+/// Keybindings as read from the keybindings file, where contexts can be left
+/// out.
+#[derive(Debug, Default, Deserialize)]
+struct KeybindingsFile {
+	/// Actions bound on every screen, if set.
+	global: Option<BTreeMap<String, Action>>,
+
+	/// Actions bound on the active screen, if set.
+	navigation: Option<BTreeMap<String, Action>>,
+}
+
+impl Keybindings {
+	/// Loads the keybindings, keeping the defaults of the contexts left out.
+	/// Keys are written back the way scripts write them, so that `Ctrl+Q`
+	/// and `ctrl+q` match the same key.
+	pub fn load() -> anyhow::Result<Self> {
+		let file =
+			toml::from_str::<KeybindingsFile>(&std::fs::read_to_string(keybindings_file_path())?)?;
+		let normalize = |bindings: BTreeMap<String, Action>| -> anyhow::Result<_> {
+			bindings
+				.into_iter()
+				.map(|(key, action)| {
+					let key = parse_key(&key)?;
+					Ok((format_key(&key).unwrap_or_default(), action))
+				})
+				.collect()
+		};
+		let defaults = Self::default();
+		let mut keybindings = Self {
+			global: file.global.map(normalize).transpose()?.unwrap_or(defaults.global),
+			navigation: file.navigation.map(normalize).transpose()?.unwrap_or(defaults.navigation),
+		};
+		keybindings.keep_quit_bound();
+		Ok(keybindings)
+	}
+
+	/// Binds the default quit keys that aren't bound to anything else again,
+	/// if no key is bound to quitting, so that Terminal Arcade can always be
+	/// quit.
+	pub fn keep_quit_bound(&mut self) {
+		if self.global.values().any(|action| *action == Action::Quit) {
+			return;
+		}
+		for key in DEFAULT_QUIT_KEYS {
+			self.global.entry(key.to_string()).or_insert(Action::Quit);
+		}
+		if !self.global.values().any(|action| *action == Action::Quit) {
+			self.global.insert(DEFAULT_QUIT_KEYS[0].to_string(), Action::Quit);
+		}
+	}
+
+	/// Loads the keybindings, or returns the default keybindings if they could
+	/// not be loaded.
+	pub fn load_or_default() -> Self {
+		Self::load().unwrap_or_default()
+	}
+
+	/// Returns the keybindings, loading them on first use.
+	pub fn get() -> &'static Self {
+		KEYBINDINGS.get_or_init(Self::load_or_default)
+	}
+
+	/// Returns the actions bound in a context.
+	#[must_use]
+	pub fn context(&self, context: ActionContext) -> &BTreeMap<String, Action> {
+		match context {
+			ActionContext::Global => &self.global,
+			ActionContext::Navigation => &self.navigation,
+		}
+	}
+
+	/// Returns the action an event is bound to in a context, if any. Only
+	/// key presses are bound.
+	#[must_use]
+	pub fn resolve(&self, event: &Event, context: ActionContext) -> Option<Action> {
+		let Event::Key(key) = event else {
+			return None;
+		};
+		if key.kind != KeyEventKind::Press {
+			return None;
+		}
+		self.context(context).get(&format_key(key)?).copied()
+	}
+
+	/// Returns the actions bound in a context with the keys bound to each, in
+	/// the order of their first key.
+	#[must_use]
+	pub fn keys_by_action(&self, context: ActionContext) -> Vec<(Action, Vec<&str>)> {
+		let mut actions: Vec<(Action, Vec<&str>)> = Vec::new();
+		for (key, &action) in self.context(context) {
+			match actions.iter_mut().find(|(bound, _)| *bound == action) {
+				Some((_, keys)) => keys.push(key),
+				None => actions.push((action, vec![key])),
+			}
+		}
+		actions
+	}
+
+	/// Returns the keys bound to actions in a context as shown to the player,
+	/// each action's [keys](key_label) separated by slashes and actions by
+	/// spaces, like `↑ ↓` or `Alt+F4 / Ctrl+Q`, if any are bound.
+	#[must_use]
+	pub fn keys_label(&self, actions: &[Action], context: ActionContext) -> Option<String> {
+		let keys_by_action = self.keys_by_action(context);
+		let labels = actions
+			.iter()
+			.filter_map(|action| {
+				let (_, keys) = keys_by_action.iter().find(|(bound, _)| bound == action)?;
+				Some(keys.iter().map(|key| key_label(key)).collect::<Vec<_>>().join(" / "))
+			})
+			.collect::<Vec<_>>();
+		(!labels.is_empty()).then(|| labels.join(" "))
+	}
+
+	/// Returns the first key bound to an action in a context, like `ctrl+q`,
+	/// if any.
+	#[must_use]
+	pub fn key_for(&self, action: Action, context: ActionContext) -> Option<&str> {
+		self.context(context)
+			.iter()
+			.find_map(|(key, bound)| (*bound == action).then_some(key.as_str()))
+	}
+}
+
+/// Returns a key written the way scripts write them, like `ctrl+q` or
+/// `pageup`, as shown to the player, like `Ctrl+Q` or `PgUp`.
+#[must_use]
+pub fn key_label(key: &str) -> String {
+	let mut label = String::new();
+	let mut name = key;
+	for (prefix, modifier) in [("ctrl+", "Ctrl+"), ("alt+", "Alt+"), ("shift+", "Shift+")] {
+		if let Some(rest) = name.strip_prefix(prefix) {
+			label.push_str(modifier);
+			name = rest;
+		}
+	}
+	match name {
+		"up" => label.push('↑'),
+		"down" => label.push('↓'),
+		"left" => label.push('←'),
+		"right" => label.push('→'),
+		"pageup" => label.push_str("PgUp"),
+		"pagedown" => label.push_str("PgDn"),
+		_ => {
+			let mut chars = name.chars();
+			label.extend(chars.next().into_iter().flat_map(char::to_uppercase));
+			label.extend(chars);
+		},
+	}
+	label
+}
+
+/// Returns [`Keybindings::keys_label`] with the player's
+/// [keybindings](Keybindings::get), for [controls
+/// entries](crate::ui::screens::ControlsEntry).
+///
+/// # Panics
+///
+/// This function panics if the labels' cache was poisoned.
+#[must_use]
+pub fn keys_label(actions: &[Action], context: ActionContext) -> Option<&'static str> {
+	let label = Keybindings::get().keys_label(actions, context)?;
+	let mut labels = KEYS_LABELS.lock().expect("The keys labels' cache was poisoned");
+	if let Some(&label) = labels.get(label.as_str()) {
+		return Some(label);
+	}
+	let label: &'static str = Box::leak(label.into_boxed_str());
+	labels.insert(label);
+	Some(label)
+}
+
+/// Returns whether a press of a quit key should quit. With
+/// [`Config::confirm_quit`] on, only a second press within a moment of the
+/// first one does, and the first one shows a [notice](quit_indicator).
+#[must_use]
+pub fn confirm_quit(event: &Event) -> bool {
+	if !Config::load_or_default().confirm_quit {
+		return true;
+	}
+	let Ok(mut pressed) = QUIT_PRESSED.lock() else {
+		return true;
+	};
+	if pressed.as_ref().is_some_and(|(time, _)| time.elapsed() < QUIT_CONFIRM_WINDOW) {
+		*pressed = None;
+		return true;
+	}
+	let key = match event {
+		Event::Key(key) => format_key(key).map(|key| key_label(&key)),
+		_ => None,
+	};
+	*pressed = Some((Instant::now(), key.unwrap_or_default()));
+	false
+}
+
+/// Returns the text shown in the screen's border for a moment after a first
+/// press of a quit key, if quitting is [confirmed](confirm_quit).
+#[must_use]
+pub fn quit_indicator() -> Option<String> {
+	let pressed = QUIT_PRESSED.lock().ok()?;
+	let (time, key) = pressed.as_ref()?;
+	(time.elapsed() < QUIT_CONFIRM_WINDOW).then(|| t!("quit-confirm", key = key.as_str()))
+}
+
+/// Returns the action an event is bound to in a context, if any, with the
+/// player's [keybindings](Keybindings::get).
+#[must_use]
+pub fn resolve(event: &Event, context: ActionContext) -> Option<Action> {
+	Keybindings::get().resolve(event, context)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		Action,
+		ActionContext,
+		Keybindings,
+	};
+	use crate::core::events::{
+		Event,
+		KeyCode,
+		KeyEvent,
+		KeyModifiers,
+	};
+
+	#[test]
+	fn keys_resolve_to_actions_by_context() {
+		let keybindings = Keybindings::default();
+		let key = |code, modifiers| Event::Key(KeyEvent::new(code, modifiers));
+		let resolve = |event: &Event, context| keybindings.resolve(event, context);
+		let ctrl_q = key(KeyCode::Char('q'), KeyModifiers::CONTROL);
+		assert_eq!(resolve(&ctrl_q, ActionContext::Global), Some(Action::Quit));
+		assert_eq!(resolve(&ctrl_q, ActionContext::Navigation), None);
+		let alt_3 = key(KeyCode::Char('3'), KeyModifiers::ALT);
+		assert_eq!(
+			resolve(&alt_3, ActionContext::Global),
+			Some(Action::PlayMacro(3))
+		);
+		let up = key(KeyCode::Up, KeyModifiers::NONE);
+		assert_eq!(resolve(&up, ActionContext::Navigation), Some(Action::Up));
+		assert_eq!(
+			resolve(&Event::FocusGained, ActionContext::Navigation),
+			None
+		);
+		assert_eq!(
+			keybindings.key_for(Action::Back, ActionContext::Navigation),
+			Some("esc")
+		);
+	}
+
+	#[test]
+	fn keys_are_labeled_as_shown() {
+		let mut keybindings = Keybindings::default();
+		keybindings.navigation.insert("k".to_string(), Action::Up);
+		let label = |actions: &[Action], context| keybindings.keys_label(actions, context);
+		assert_eq!(
+			label(&[Action::Up, Action::Down], ActionContext::Navigation).as_deref(),
+			Some("K / ↑ ↓")
+		);
+		assert_eq!(
+			label(&[Action::Quit], ActionContext::Global).as_deref(),
+			Some("Alt+F4 / Ctrl+C / Ctrl+Q")
+		);
+		assert_eq!(
+			label(&[Action::PageUp], ActionContext::Navigation).as_deref(),
+			Some("PgUp")
+		);
+		assert_eq!(label(&[Action::Quit], ActionContext::Navigation), None);
+	}
+
+	#[test]
+	fn quitting_stays_bound() {
+		let mut keybindings = Keybindings::default();
+		keybindings.global.retain(|_, action| *action != Action::Quit);
+		keybindings.global.insert("ctrl+q".to_string(), Action::ReportBug);
+		keybindings.keep_quit_bound();
+		assert_eq!(
+			keybindings.keys_label(&[Action::Quit], ActionContext::Global).as_deref(),
+			Some("Alt+F4 / Ctrl+C")
+		);
+		assert_eq!(
+			keybindings.key_for(Action::ReportBug, ActionContext::Global),
+			Some("ctrl+q")
+		);
+
+		for key in ["ctrl+c", "alt+f4"] {
+			keybindings.global.insert(key.to_string(), Action::DevTools);
+		}
+		keybindings.keep_quit_bound();
+		assert_eq!(
+			keybindings.key_for(Action::Quit, ActionContext::Global),
+			Some("ctrl+q")
+		);
+	}
+}
@@ -0,0 +1,102 @@
+//! Bug reports, saved as zip archives in the save directory's
+//! [`BUG_REPORT_DIR`] folder with \[F12\] from any screen, to attach to an
+//! [issue][`BUG_REPORT_URL`].
+//!
+//! A report holds:
+//! - `report.txt`: the version of Terminal Arcade, the platform it runs on and
+//!   the screens open, the active one last.
+//! - `events.txt`: the [last inputs played](EventHistory), as a script that
+//!   `--script` plays back to reproduce the bug.
+//! - `config.toml`: the [configuration](Config::redacted), without private
+//!   data.
+//! - `render.txt`: how long screens took to draw, from the [render
+//!   stats](render_stats).
+//! - `errors.log`: the [errors](error) the player ran into, if any.
+//! - `panic.txt`: the [panic](ScreenPanic) that closed a screen, for reports
+//!   saved when one does.
+//!
+//! [`BUG_REPORT_URL`]: crate::ui::screens::about::BUG_REPORT_URL
+
+use std::{
+	fs::{
+		self,
+		File,
+	},
+	io::Write,
+	path::PathBuf,
+};
+
+use zip::{
+	write::SimpleFileOptions,
+	ZipWriter,
+};
+
+use crate::{
+	core::{
+		config::Config,
+		error::{
+			self,
+			error_log_path,
+		},
+		event_history::EventHistory,
+		get_save_dir,
+		panics::ScreenPanic,
+		render_stats,
+	},
+	games::get_unix_time_as_secs,
+	ui::util::get_crate_version,
+};
+
+/// Name of the folder of the save directory that bug reports are saved in.
+pub const BUG_REPORT_DIR: &str = "bug-reports";
+
+/// Gets the directory bug reports are saved in.
+#[must_use]
+pub fn bug_report_dir() -> PathBuf {
+	get_save_dir().join(BUG_REPORT_DIR)
+}
+
+/// Saves a bug report, given the last inputs played, the titles of the
+/// screens open and the panic that closed a screen if any, and returns its
+/// path.
+pub fn create(
+	history: &EventHistory,
+	screens: &[&str],
+	panic: Option<&ScreenPanic>,
+) -> anyhow::Result<PathBuf> {
+	let dir = bug_report_dir();
+	fs::create_dir_all(&dir)?;
+	let created = get_unix_time_as_secs();
+	let path = dir.join(format!("bug-report-{created}.zip"));
+
+	let report = format!(
+		"Terminal Arcade {version}\nPlatform: {os} ({arch})\nCreated: {created}\n\nScreens, the \
+		 active one last:\n{screens}\n",
+		version = get_crate_version(),
+		os = std::env::consts::OS,
+		arch = std::env::consts::ARCH,
+		screens = screens.iter().map(|title| format!("- {title}")).collect::<Vec<_>>().join("\n"),
+	);
+	let config = toml::to_string_pretty(&Config::load_or_default().redacted())?;
+	let mut files = vec![
+		("report.txt", report),
+		("events.txt", history.to_script()),
+		("config.toml", config),
+		("render.txt", render_stats::to_text()),
+		(
+			error::ERROR_LOG_FILE,
+			fs::read_to_string(error_log_path()).unwrap_or_default(),
+		),
+	];
+	if let Some(panic) = panic {
+		files.push(("panic.txt", panic.to_text()));
+	}
+
+	let mut zip = ZipWriter::new(File::create(&path)?);
+	for (name, contents) in files {
+		zip.start_file(name, SimpleFileOptions::default())?;
+		zip.write_all(contents.as_bytes())?;
+	}
+	zip.finish()?.sync_all()?;
+	Ok(path)
+}
@@ -0,0 +1,227 @@
+//! Printable cheat sheets of the keybindings in effect: the
+//! [remappable](crate::core::actions) global and navigation actions, then the
+//! controls of each screen, as Markdown or plain text. Written from the
+//! settings to the save directory's `exports` folder.
+//!
+//! Only screens that can be opened on their own are listed, so the controls
+//! of screens opened with something to show, like a game's board, are left
+//! out.
+
+use std::{
+	fs,
+	path::Path,
+};
+
+use crate::{
+	core::actions::{
+		ActionContext,
+		Keybindings,
+	},
+	t,
+	ui::{
+		screens::{
+			AboutScreen,
+			BackupsScreen,
+			ConfigScreen,
+			DiagnosticsScreen,
+			DigestScreen,
+			GameSearchScreen,
+			LeaderboardScreen,
+			MacrosScreen,
+			Screens,
+			WelcomeScreen,
+		},
+		text,
+		Screen,
+	},
+};
+
+/// Format of a cheat sheet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheatSheetFormat {
+	/// A Markdown document, a table per section.
+	Markdown,
+
+	/// Plain text, keys lined up in a column.
+	Text,
+}
+
+impl CheatSheetFormat {
+	/// Every format, in the order they're written.
+	pub const ALL: [Self; 2] = [Self::Markdown, Self::Text];
+
+	/// Returns the format's file extension.
+	#[must_use]
+	pub fn extension(self) -> &'static str {
+		match self {
+			Self::Markdown => "md",
+			Self::Text => "txt",
+		}
+	}
+}
+
+/// Keys and what they do somewhere, like on every screen or on the settings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheatSheetSection {
+	/// Where the keys apply.
+	pub title: String,
+
+	/// Keys, and what they do.
+	pub entries: Vec<(String, String)>,
+}
+
+/// A cheat sheet of the keybindings. See the [module](self) documentation for
+/// more information.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct CheatSheet {
+	/// The sections of the cheat sheet, global actions first.
+	pub sections: Vec<CheatSheetSection>,
+}
+
+/// Returns the screens whose controls are listed, in the order they're listed.
+fn listed_screens() -> Vec<Screens> {
+	vec![
+		WelcomeScreen::default().into(),
+		GameSearchScreen::default().into(),
+		DigestScreen::default().into(),
+		LeaderboardScreen::default().into(),
+		ConfigScreen::default().into(),
+		BackupsScreen::default().into(),
+		MacrosScreen::default().into(),
+		AboutScreen::default().into(),
+		DiagnosticsScreen::default().into(),
+		#[cfg(feature = "minesweeper")]
+		crate::ui::screens::MinesweeperTutorialScreen::default().into(),
+		#[cfg(feature = "tron")]
+		crate::ui::screens::TronSetupScreen::default().into(),
+	]
+}
+
+impl CheatSheet {
+	/// Creates a cheat sheet of keybindings and of the controls of screens.
+	pub fn new(keybindings: &Keybindings, screens: &[Screens]) -> Self {
+		let actions = |context| {
+			keybindings
+				.keys_by_action(context)
+				.into_iter()
+				.map(|(action, keys)| (keys.join(", "), action.label()))
+				.collect()
+		};
+		let mut sections = vec![
+			CheatSheetSection {
+				title: t!("cheat-sheet-global").to_string(),
+				entries: actions(ActionContext::Global),
+			},
+			CheatSheetSection {
+				title: t!("cheat-sheet-navigation").to_string(),
+				entries: actions(ActionContext::Navigation),
+			},
+		];
+		sections.extend(screens.iter().filter_map(|screen| {
+			let state = screen.initial_state();
+			let entries = state.controls_entries_with_actions()?;
+			Some(CheatSheetSection {
+				title: state.title.to_string(),
+				entries: entries
+					.into_iter()
+					.map(|(keys, function)| (keys.to_string(), function.to_string()))
+					.collect(),
+			})
+		}));
+		Self { sections }
+	}
+
+	/// Creates a cheat sheet of the player's keybindings.
+	pub fn collect() -> Self {
+		Self::new(Keybindings::get(), &listed_screens())
+	}
+
+	/// Formats the cheat sheet as Markdown.
+	#[must_use]
+	pub fn to_markdown(&self) -> String {
+		let escape = |cell: &str| cell.replace('|', "\\|");
+		let mut markdown = vec![format!("# {}", t!("cheat-sheet-title"))];
+		for section in &self.sections {
+			markdown.extend([
+				String::new(),
+				format!("## {}", section.title),
+				String::new(),
+				format!(
+					"| {} | {} |",
+					t!("cheat-sheet-keys"),
+					t!("controls-function")
+				),
+				"| --- | --- |".to_string(),
+			]);
+			markdown.extend(
+				section.entries.iter().map(|(keys, function)| {
+					format!("| `{}` | {} |", escape(keys), escape(function))
+				}),
+			);
+		}
+		markdown.join("\n") + "\n"
+	}
+
+	/// Formats the cheat sheet as plain text.
+	#[must_use]
+	pub fn to_text(&self) -> String {
+		let title = t!("cheat-sheet-title");
+		let mut lines = vec![title.to_string(), "=".repeat(text::width(title))];
+		for section in &self.sections {
+			let width =
+				section.entries.iter().map(|(keys, _)| text::width(keys)).max().unwrap_or(0);
+			lines.extend([
+				String::new(),
+				section.title.clone(),
+				"-".repeat(text::width(&section.title)),
+			]);
+			lines.extend(
+				section
+					.entries
+					.iter()
+					.map(|(keys, function)| format!("{}  {function}", text::pad(keys, width))),
+			);
+		}
+		lines.join("\n") + "\n"
+	}
+
+	/// Formats the cheat sheet in a format.
+	#[must_use]
+	pub fn format(&self, format: CheatSheetFormat) -> String {
+		match format {
+			CheatSheetFormat::Markdown => self.to_markdown(),
+			CheatSheetFormat::Text => self.to_text(),
+		}
+	}
+}
+
+/// Writes a cheat sheet of the player's keybindings to a file.
+pub fn export(
+	cheat_sheet: &CheatSheet,
+	path: &Path,
+	format: CheatSheetFormat,
+) -> anyhow::Result<()> {
+	if let Some(parent) = path.parent() {
+		fs::create_dir_all(parent)?;
+	}
+	Ok(fs::write(path, cheat_sheet.format(format))?)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::CheatSheet;
+	use crate::core::actions::Keybindings;
+
+	#[test]
+	fn keys_of_an_action_share_a_line() {
+		let cheat_sheet = CheatSheet::new(&Keybindings::default(), &[]);
+		let text = cheat_sheet.to_text();
+		assert!(text.contains("alt+f4, ctrl+c, ctrl+q  Quits Terminal Arcade\n"));
+		assert!(text
+			.lines()
+			.any(|line| line.starts_with("esc") && line.ends_with("Closes the screen")));
+		let markdown = cheat_sheet.to_markdown();
+		assert!(markdown.contains("| `f9` | Starts or stops recording an input macro |\n"));
+	}
+}
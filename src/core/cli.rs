@@ -0,0 +1,188 @@
+//! Command-line arguments of Terminal Arcade. See [`Cli`] for the arguments
+//! themselves.
+
+#[cfg(feature = "telnet")]
+use std::net::IpAddr;
+use std::{
+	io::Write,
+	path::PathBuf,
+};
+
+use clap::{
+	CommandFactory,
+	Parser,
+	Subcommand,
+};
+use clap_complete::Shell;
+
+use crate::core::export::ExportFormat;
+
+/// Terminal-based arcade-style games for when you're bored out of your mind.
+#[derive(Debug, Parser)]
+#[command(version, about)]
+pub struct Cli {
+	/// Runs a command instead of the arcade.
+	#[command(subcommand)]
+	pub command: Option<Command>,
+
+	/// Listens for commands from other programs on a Unix socket at this path,
+	/// one JSON object per line. See the `services::ipc` module for the
+	/// commands.
+	#[cfg(unix)]
+	#[arg(long, value_name = "PATH")]
+	pub ipc_socket: Option<PathBuf>,
+
+	/// Plays back the input in this file as if typed on the keyboard. See the
+	/// `core::script` module for the format.
+	#[arg(long, value_name = "FILE")]
+	pub script: Option<PathBuf>,
+
+	/// Saves data, like game metadata and scripted games, in this directory
+	/// instead of the default one.
+	#[arg(long, value_name = "DIR")]
+	pub data_dir: Option<PathBuf>,
+
+	/// Reads and saves the configuration in this directory instead of the
+	/// default one.
+	#[arg(long, value_name = "DIR")]
+	pub config_dir: Option<PathBuf>,
+
+	/// Keeps all files in a folder next to the executable, like on a USB
+	/// stick. Also enabled by a file named `portable` next to the executable.
+	#[arg(long)]
+	pub portable: bool,
+
+	/// Enables developer tools: \[F11\] opens a screen replaying the last
+	/// events played. See the `ui::screens::event_replay` module.
+	#[arg(long)]
+	pub dev_tools: bool,
+
+	/// Seeds the random number generator, so that games come out the same as
+	/// in other runs with the same seed.
+	#[arg(long)]
+	pub seed: Option<u64>,
+}
+
+/// Commands run instead of the arcade.
+#[derive(Debug, Subcommand)]
+pub enum Command {
+	/// Exports your play statistics and high scores in every game to a file.
+	Export {
+		/// The file to write. Defaults to a new file in the save directory's
+		/// `exports` folder.
+		path: Option<PathBuf>,
+
+		/// Format of the file. Defaults to the one matching the file's
+		/// extension, or CSV.
+		#[arg(long, value_enum)]
+		format: Option<ExportFormat>,
+	},
+
+	/// Exports your best scores and times in every game to a score file, to
+	/// share or import into another profile.
+	ExportScores {
+		/// The file to write. Defaults to a new file in the save directory's
+		/// `exports` folder.
+		path: Option<PathBuf>,
+	},
+
+	/// Imports the best scores and times of a score file, keeping the ones
+	/// that beat your own. Files edited by hand are refused.
+	ImportScores {
+		/// The score file to read.
+		path: PathBuf,
+	},
+
+	/// Serves the arcade over telnet, each connection playing its own instance
+	/// from the welcome screen. Every player's results are saved in the same
+	/// save directory, sharing the games' best scores and times.
+	#[cfg(feature = "telnet")]
+	Serve {
+		/// Address to listen on. Only this machine can connect by default;
+		/// `0.0.0.0` lets anyone reaching it connect.
+		#[arg(long, default_value = "127.0.0.1")]
+		address: IpAddr,
+
+		/// Port to listen on.
+		#[arg(long, default_value_t = crate::services::telnet::DEFAULT_PORT)]
+		port: u16,
+
+		/// Talks raw TCP, for clients like `nc`, instead of negotiating the
+		/// window's size and echo with telnet clients.
+		#[arg(long)]
+		raw: bool,
+
+		/// Most players connected at once. Others are turned away.
+		#[arg(long, default_value_t = 16)]
+		max_connections: usize,
+	},
+
+	/// Plays the arcade for one connection of the `serve` command, over the
+	/// standard input and output, which the server runs in its own process.
+	#[cfg(feature = "telnet")]
+	#[command(hide = true)]
+	ServeConnection {
+		/// Talks raw TCP instead of telnet.
+		#[arg(long)]
+		raw: bool,
+	},
+
+	/// Checks what the terminal supports, whether the configuration and save
+	/// files can be read, and whether their directories can be written to,
+	/// then prints a report. Fails if any check does.
+	Doctor,
+
+	/// Prints a script completing Terminal Arcade's arguments in a shell, to
+	/// be sourced from the shell's configuration.
+	Completions {
+		/// The shell to complete arguments in.
+		#[arg(value_enum)]
+		shell: Shell,
+	},
+
+	/// Prints Terminal Arcade's man page, in roff, to be installed in a
+	/// `man1` directory as `terminal-arcade.1`.
+	Manpage,
+}
+
+/// Writes the script completing the arguments in a shell.
+pub fn write_completions(shell: Shell, out: &mut impl Write) -> anyhow::Result<()> {
+	let mut command = Cli::command();
+	let name = command.get_name().to_string();
+	// Generated in memory first, since generating panics on write errors.
+	let mut script = Vec::new();
+	clap_complete::generate(shell, &mut command, name, &mut script);
+	out.write_all(&script)?;
+	Ok(())
+}
+
+/// Writes the man page.
+pub fn write_manpage(out: &mut impl Write) -> anyhow::Result<()> {
+	clap_mangen::Man::new(Cli::command()).render(out)?;
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use clap::CommandFactory;
+	use clap_complete::Shell;
+
+	use super::{
+		write_completions,
+		write_manpage,
+		Cli,
+	};
+
+	#[test]
+	fn completions_and_man_page_cover_the_commands() {
+		Cli::command().debug_assert();
+		let mut completions = Vec::new();
+		write_completions(Shell::Bash, &mut completions).unwrap();
+		let completions = String::from_utf8(completions).unwrap();
+		assert!(completions.contains("export-scores") && completions.contains("--data-dir"));
+		let mut manpage = Vec::new();
+		write_manpage(&mut manpage).unwrap();
+		let manpage = String::from_utf8(manpage).unwrap();
+		assert!(manpage.contains(".TH") && manpage.contains("completions"));
+	}
+}
@@ -0,0 +1,35 @@
+//! Copying text to the clipboard through the terminal, with the OSC 52 escape
+//! sequence, so that it also works over SSH. Terminals that don't support it
+//! ignore it.
+//!
+//! Screens ask for text to be copied with
+//! [`ScreenEvent::Copy`](crate::ui::screens::ScreenEvent::Copy).
+
+use std::io::Write;
+
+use base64::{
+	engine::general_purpose::STANDARD,
+	Engine,
+};
+
+/// Returns the escape sequence copying text to the clipboard.
+#[must_use]
+pub fn sequence(text: &str) -> String {
+	format!("\x1b]52;c;{}\x07", STANDARD.encode(text))
+}
+
+/// Copies text to the clipboard of the terminal a writer writes to.
+pub fn copy(writer: &mut impl Write, text: &str) -> std::io::Result<()> {
+	writer.write_all(sequence(text).as_bytes())?;
+	writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::sequence;
+
+	#[test]
+	fn encodes_text_in_the_sequence() {
+		assert_eq!(sequence("42"), "\x1b]52;c;NDI=\x07");
+	}
+}
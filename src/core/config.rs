@@ -0,0 +1,162 @@
+//! Terminal Arcade's user configuration, saved as a TOML file in the save
+//! directory.
+
+use std::path::PathBuf;
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+use crate::core::{
+	error::AppError,
+	get_config_dir,
+};
+
+/// Name of the configuration file, in the [configuration
+/// directory](get_config_dir).
+pub const CONFIG_FILE: &str = "config.toml";
+
+/// Gets the path of the configuration file.
+#[must_use]
+pub fn config_file_path() -> PathBuf {
+	get_config_dir().join(CONFIG_FILE)
+}
+
+/// Terminal Arcade's user configuration. Missing fields are filled with their
+/// default values when loading, so that older configuration files keep
+/// working.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+#[must_use]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Config {
+	/// Whether to check for new releases of Terminal Arcade on startup.
+	pub check_for_updates: bool,
+
+	/// Whether to show tips on the welcome screen.
+	pub show_tips: bool,
+
+	/// Whether to draw menus and key game info in large block letters, for
+	/// low-vision players.
+	pub large_text: bool,
+
+	/// Whether text is shown whole instead of being typed out, for players
+	/// bothered by motion.
+	pub reduced_motion: bool,
+
+	/// Whether the welcome screen plays [demos](crate::games::demo) of the
+	/// games when left idle for a while.
+	pub attract_mode: bool,
+
+	/// How often the save directory is [backed up](crate::services::backup),
+	/// in hours. Backups are disabled when this is 0.
+	pub backup_interval_hours: u64,
+
+	/// How many backups are kept before the oldest are removed.
+	pub backups_kept: usize,
+
+	/// URL of a git repository to [sync](crate::services::sync) the save data
+	/// with, if any.
+	pub sync_repository: Option<String>,
+
+	/// URL of a [leaderboard server](crate::services::leaderboard) shared with
+	/// other players, if any.
+	pub leaderboard_url: Option<String>,
+
+	/// How often games in progress are
+	/// [autosaved](crate::services::autosave), in seconds. Games are only
+	/// autosaved when left when this is 0.
+	pub autosave_interval_seconds: u64,
+
+	/// Whether to autosave games in progress when leaving them.
+	pub autosave_on_leave: bool,
+
+	/// Whether to resume autosaved games in progress when opening them, rather
+	/// than starting them over.
+	pub resume_games: bool,
+
+	/// Whether an arrow key repeated in a row within a frame is only played
+	/// once, so that auto-repeat doesn't flood games. See
+	/// [`input`](crate::core::input).
+	pub coalesce_inputs: bool,
+
+	/// How soon an arrow key can be played again after itself, in
+	/// milliseconds. Repeats are not limited when this is 0.
+	pub key_repeat_limit_ms: u64,
+
+	/// Whether a [summary](crate::services::stats::SessionSummary) of the
+	/// session is printed when Terminal Arcade quits.
+	pub exit_summary: bool,
+
+	/// Whether quitting takes pressing a quit key twice in a row. See
+	/// [`actions::confirm_quit`](crate::core::actions::confirm_quit).
+	pub confirm_quit: bool,
+
+	/// Whether [screenshots](crate::core::screenshot) are also saved with
+	/// their colors, as ANSI escape codes.
+	pub screenshot_ansi: bool,
+
+	/// Language of the interface, like `fr`. Defaults to the one of the
+	/// environment. See [`i18n`](crate::core::i18n).
+	pub language: Option<String>,
+}
+
+impl Default for Config {
+	fn default() -> Self {
+		Self {
+			check_for_updates: false,
+			show_tips: true,
+			large_text: false,
+			reduced_motion: false,
+			attract_mode: true,
+			backup_interval_hours: 24,
+			backups_kept: 5,
+			sync_repository: None,
+			leaderboard_url: None,
+			autosave_interval_seconds: 60,
+			autosave_on_leave: true,
+			resume_games: true,
+			coalesce_inputs: true,
+			key_repeat_limit_ms: 0,
+			exit_summary: true,
+			confirm_quit: false,
+			screenshot_ansi: false,
+			language: None,
+		}
+	}
+}
+
+impl Config {
+	/// Loads the configuration.
+	pub fn load() -> anyhow::Result<Self> {
+		let path = config_file_path();
+		let config_file =
+			std::fs::read_to_string(&path).map_err(|error| AppError::file_access(&path, error))?;
+		Ok(toml::from_str::<Self>(&config_file)
+			.map_err(|error| AppError::config_parse(&path, &config_file, &error))?)
+	}
+
+	/// Saves the configuration, in TOML format.
+	pub fn save(&self) -> anyhow::Result<()> {
+		let toml_string = toml::to_string_pretty(self)?;
+		std::fs::create_dir_all(get_config_dir())?;
+		Ok(std::fs::write(config_file_path(), toml_string)?)
+	}
+
+	/// Loads the configuration, or returns the default configuration if it
+	/// could not be loaded.
+	pub fn load_or_default() -> Self {
+		Self::load().unwrap_or_default()
+	}
+
+	/// Returns the configuration without private data, like the sync
+	/// repository's URL, which may hold credentials.
+	pub fn redacted(&self) -> Self {
+		Self {
+			sync_repository: self.sync_repository.as_ref().map(|_| "<redacted>".to_string()),
+			leaderboard_url: self.leaderboard_url.as_ref().map(|_| "<redacted>".to_string()),
+			..self.clone()
+		}
+	}
+}
@@ -0,0 +1,413 @@
+//! Diagnostics of the environment Terminal Arcade runs in: what the terminal
+//! supports, whether the configuration and save files can be read, and
+//! whether their directories can be written to. The checks are run by the
+//! `doctor` command, which prints a [report](Report), and shown on the
+//! [diagnostics screen](crate::ui::screens::DiagnosticsScreen).
+
+use std::{
+	env,
+	fs,
+	io::IsTerminal,
+	path::Path,
+};
+
+use crate::{
+	core::{
+		config::{
+			config_file_path,
+			Config,
+		},
+		error::ErrorReport,
+		files::app_files,
+		save_file,
+	},
+	games::GameDynamicInfo,
+	t,
+};
+
+/// Smallest terminal size most screens are laid out for.
+pub const RECOMMENDED_SIZE: (u16, u16) = (80, 24);
+
+/// Name of the file written to check that a directory is writable, removed
+/// right after.
+const PROBE_FILE: &str = ".doctor-probe";
+
+/// How a check went.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+	/// Everything works.
+	Passed,
+
+	/// Terminal Arcade works, but not as well as it could.
+	Warning,
+
+	/// Something is broken.
+	Failed,
+
+	/// The check couldn't be run here.
+	Skipped,
+}
+
+impl CheckStatus {
+	/// Returns the symbol the status is shown with.
+	#[must_use]
+	pub fn symbol(self) -> &'static str {
+		match self {
+			Self::Passed => "✅",
+			Self::Warning => "⚠️",
+			Self::Failed => "❌",
+			Self::Skipped => "➖",
+		}
+	}
+}
+
+/// The outcome of a check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+	/// What was checked.
+	pub name: &'static str,
+
+	/// How it went.
+	pub status: CheckStatus,
+
+	/// What was found.
+	pub details: String,
+}
+
+impl Check {
+	/// Returns a check's outcome.
+	fn new(name: &'static str, status: CheckStatus, details: impl Into<String>) -> Self {
+		Self {
+			name,
+			status,
+			details: details.into(),
+		}
+	}
+}
+
+/// The outcomes of every check, in the order they were run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct Report {
+	/// The checks' outcomes.
+	pub checks: Vec<Check>,
+}
+
+impl Report {
+	/// Runs every check. Querying the terminal for the kitty keyboard protocol
+	/// reads its answer from the input, so it's only done with
+	/// `probe_keyboard`, away from the arcade's event loop.
+	#[cfg_attr(target_arch = "wasm32", allow(unused_variables))]
+	pub fn run(probe_keyboard: bool) -> Self {
+		let files = app_files();
+		let mut checks = vec![check_colors(), check_unicode()];
+		// A browser's terminal can't be asked about itself.
+		#[cfg(not(target_arch = "wasm32"))]
+		checks.extend([check_size(), check_mouse(), check_keyboard(probe_keyboard)]);
+		#[cfg(feature = "images")]
+		checks.push(Check::new(
+			t!("doctor-graphics"),
+			CheckStatus::Passed,
+			t!(
+				"doctor-graphics-protocol",
+				protocol = format!("{:?}", *crate::ui::widgets::image::GRAPHICS_PROTOCOL)
+			),
+		));
+		checks.extend([
+			check_config(),
+			check_save_files(),
+			check_dir(t!("doctor-data-dir"), &files.data_dir),
+			check_dir(t!("doctor-config-dir"), &files.config_dir),
+		]);
+		Self { checks }
+	}
+
+	/// Returns whether any check failed.
+	#[must_use]
+	pub fn failed(&self) -> bool {
+		self.checks.iter().any(|check| check.status == CheckStatus::Failed)
+	}
+
+	/// Returns the report as text, one check per line.
+	#[must_use]
+	pub fn to_text(&self) -> String {
+		self.checks
+			.iter()
+			.map(|check| {
+				format!(
+					"{} {}: {}",
+					check.status.symbol(),
+					check.name,
+					check.details
+				)
+			})
+			.collect::<Vec<_>>()
+			.join("\n")
+	}
+}
+
+/// Returns an environment variable, or an empty string if it's not set.
+fn var(name: &str) -> String {
+	env::var(name).unwrap_or_default()
+}
+
+/// Checks how many colors the terminal advertises.
+fn check_colors() -> Check {
+	let name = t!("doctor-colors");
+	let (colorterm, term) = (var("COLORTERM"), var("TERM"));
+	if matches!(colorterm.as_str(), "truecolor" | "24bit") || cfg!(windows) {
+		Check::new(name, CheckStatus::Passed, t!("doctor-colors-true"))
+	} else if term.contains("256color") {
+		Check::new(name, CheckStatus::Warning, t!("doctor-colors-256"))
+	} else {
+		Check::new(name, CheckStatus::Warning, t!("doctor-colors-basic"))
+	}
+}
+
+/// Checks that the locale encodes text in UTF-8, which the cards, pieces and
+/// borders are drawn with.
+fn check_unicode() -> Check {
+	let name = t!("doctor-unicode");
+	if cfg!(windows) {
+		return Check::new(name, CheckStatus::Passed, t!("doctor-unicode-windows"));
+	}
+	let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+		.into_iter()
+		.map(var)
+		.find(|value| !value.is_empty())
+		.unwrap_or_else(|| "C".to_string());
+	let lowercase = locale.to_lowercase();
+	if lowercase.contains("utf-8") || lowercase.contains("utf8") {
+		Check::new(
+			name,
+			CheckStatus::Passed,
+			t!("doctor-unicode-ok", locale = locale),
+		)
+	} else {
+		Check::new(
+			name,
+			CheckStatus::Warning,
+			t!("doctor-unicode-missing", locale = locale),
+		)
+	}
+}
+
+/// Checks that the terminal is at least the [recommended
+/// size](RECOMMENDED_SIZE).
+#[cfg(not(target_arch = "wasm32"))]
+fn check_size() -> Check {
+	let name = t!("doctor-size");
+	let (min_width, min_height) = RECOMMENDED_SIZE;
+	match crossterm::terminal::size() {
+		Ok((width, height)) if width >= min_width && height >= min_height => Check::new(
+			name,
+			CheckStatus::Passed,
+			t!("doctor-size-ok", width = width, height = height),
+		),
+		Ok((width, height)) => Check::new(
+			name,
+			CheckStatus::Warning,
+			t!(
+				"doctor-size-small",
+				width = width,
+				height = height,
+				min_width = min_width,
+				min_height = min_height
+			),
+		),
+		Err(error) => Check::new(
+			name,
+			CheckStatus::Failed,
+			t!("doctor-size-unknown", error = error.to_string()),
+		),
+	}
+}
+
+/// Checks whether the terminal is one that reports the mouse, going by its
+/// name, since asking would take clicking.
+#[cfg(not(target_arch = "wasm32"))]
+fn check_mouse() -> Check {
+	let name = t!("doctor-mouse");
+	let term = var("TERM");
+	if cfg!(windows) {
+		Check::new(
+			name,
+			CheckStatus::Passed,
+			t!("doctor-mouse-ok", term = "Windows"),
+		)
+	} else if term.is_empty() || term == "dumb" || term == "linux" {
+		Check::new(
+			name,
+			CheckStatus::Warning,
+			t!("doctor-mouse-unlikely", term = term),
+		)
+	} else {
+		Check::new(
+			name,
+			CheckStatus::Passed,
+			t!("doctor-mouse-ok", term = term),
+		)
+	}
+}
+
+/// Checks whether the terminal supports the kitty keyboard protocol, which
+/// tells more key combinations apart, by asking it.
+#[cfg(not(target_arch = "wasm32"))]
+fn check_keyboard(probe: bool) -> Check {
+	let name = t!("doctor-keyboard");
+	if !probe {
+		return Check::new(name, CheckStatus::Skipped, t!("doctor-keyboard-skipped"));
+	}
+	if !std::io::stdout().is_terminal() {
+		return Check::new(name, CheckStatus::Skipped, t!("doctor-not-a-terminal"));
+	}
+	match crossterm::terminal::supports_keyboard_enhancement() {
+		Ok(true) => Check::new(name, CheckStatus::Passed, t!("doctor-keyboard-ok")),
+		Ok(false) => Check::new(name, CheckStatus::Warning, t!("doctor-keyboard-missing")),
+		Err(error) => Check::new(
+			name,
+			CheckStatus::Warning,
+			t!("doctor-keyboard-unknown", error = error.to_string()),
+		),
+	}
+}
+
+/// Checks that the configuration file, if any, can be read.
+fn check_config() -> Check {
+	let name = t!("doctor-config");
+	let path = config_file_path();
+	let display = path.display().to_string();
+	if !path.exists() {
+		return Check::new(
+			name,
+			CheckStatus::Passed,
+			t!("doctor-config-missing", path = display),
+		);
+	}
+	match Config::load() {
+		Ok(_) => Check::new(
+			name,
+			CheckStatus::Passed,
+			t!("doctor-config-ok", path = display),
+		),
+		Err(error) => {
+			let report = ErrorReport::new(&error);
+			Check::new(
+				name,
+				CheckStatus::Failed,
+				t!(
+					"doctor-config-invalid",
+					code = report.code,
+					error = report.message
+				),
+			)
+		},
+	}
+}
+
+/// Checks that a save file can be read and parsed.
+fn read_save_file(path: &Path) -> anyhow::Result<()> {
+	let contents = save_file::read(path)?;
+	let path_text = path.to_string_lossy();
+	if path_text.ends_with(".meta.toml") {
+		let _: GameDynamicInfo = toml::from_str(&contents)?;
+	} else if path_text.ends_with(".toml") {
+		toml::from_str::<toml::Value>(&contents)?;
+	} else {
+		serde_json::from_str::<serde_json::Value>(&contents)?;
+	}
+	Ok(())
+}
+
+/// Checks that every save file can be read, leaving out encrypted ones while
+/// the save data is locked.
+fn check_save_files() -> Check {
+	let name = t!("doctor-saves");
+	let files = match save_file::list() {
+		Ok(files) => files,
+		Err(error) => return Check::new(name, CheckStatus::Failed, error.to_string()),
+	};
+	let locked = save_file::is_locked() || cfg!(not(feature = "encryption"));
+	let (encrypted, readable): (Vec<_>, Vec<_>) =
+		files.iter().partition(|path| locked && save_file::is_encrypted(path));
+	let invalid: Vec<_> = readable
+		.iter()
+		.filter(|path| read_save_file(path).is_err())
+		.filter_map(|path| Some(path.file_name()?.to_string_lossy().to_string()))
+		.collect();
+	if !invalid.is_empty() {
+		return Check::new(
+			name,
+			CheckStatus::Failed,
+			t!(
+				"doctor-saves-invalid",
+				count = invalid.len(),
+				total = files.len(),
+				files = invalid.join(", ")
+			),
+		);
+	}
+	let mut details = t!("doctor-saves-ok", count = readable.len());
+	if !encrypted.is_empty() {
+		details += &t!("doctor-saves-encrypted", count = encrypted.len());
+	}
+	Check::new(name, CheckStatus::Passed, details)
+}
+
+/// Checks that a directory can be written to, by writing a file in it.
+fn check_dir(name: &'static str, dir: &Path) -> Check {
+	let display = dir.display().to_string();
+	if !dir.is_dir() {
+		return Check::new(
+			name,
+			CheckStatus::Warning,
+			t!("doctor-dir-missing", path = display),
+		);
+	}
+	let probe = dir.join(PROBE_FILE);
+	match fs::write(&probe, "").and_then(|()| fs::remove_file(&probe)) {
+		Ok(()) => Check::new(
+			name,
+			CheckStatus::Passed,
+			t!("doctor-dir-ok", path = display),
+		),
+		Err(error) => Check::new(
+			name,
+			CheckStatus::Failed,
+			t!(
+				"doctor-dir-read-only",
+				path = display,
+				error = error.to_string()
+			),
+		),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		check_dir,
+		Check,
+		CheckStatus,
+		Report,
+	};
+
+	#[test]
+	fn reports_checks_and_writable_directories() {
+		let dir = std::env::temp_dir().join(format!("doctor-test-{}", std::process::id()));
+		assert_eq!(check_dir("Data", &dir).status, CheckStatus::Warning);
+		std::fs::create_dir_all(&dir).unwrap();
+		assert_eq!(check_dir("Data", &dir).status, CheckStatus::Passed);
+		assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+		std::fs::remove_dir(&dir).unwrap();
+
+		let report = Report {
+			checks: vec![
+				Check::new("Colors", CheckStatus::Passed, "24-bit"),
+				Check::new("Save files", CheckStatus::Failed, "broken"),
+			],
+		};
+		assert!(report.failed());
+		assert_eq!(report.to_text(), "✅ Colors: 24-bit\n❌ Save files: broken");
+	}
+}
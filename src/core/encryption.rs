@@ -0,0 +1,252 @@
+//! Encryption of the player's [save files](crate::core::save_file) with a
+//! passphrase, for players sharing a computer. Only compiled in with the
+//! `encryption` cargo feature.
+//!
+//! Files are encrypted with ChaCha20-Poly1305, with a key derived from the
+//! passphrase with Argon2. The key's salt is kept in the [key
+//! file](key_file_path), along with a known text encrypted with the key, so
+//! that a wrong passphrase is noticed before anything is decrypted. The key
+//! itself is only ever kept in memory, once [unlocked](unlock) on startup.
+
+use std::{
+	fs,
+	path::PathBuf,
+	sync::Mutex,
+};
+
+use anyhow::{
+	anyhow,
+	bail,
+};
+use argon2::Argon2;
+use chacha20poly1305::{
+	aead::Aead,
+	ChaCha20Poly1305,
+	Key,
+	KeyInit,
+	Nonce,
+};
+use rand::{
+	rngs::OsRng,
+	RngCore,
+};
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+use crate::{
+	core::{
+		get_save_dir,
+		save_file,
+	},
+	t,
+};
+
+/// Name of the key file, in the save directory.
+pub const KEY_FILE: &str = "encryption.toml";
+
+/// Text encrypted in the key file to check passphrases against.
+const CHECK_TEXT: &[u8] = b"terminal-arcade";
+
+/// Length of the key's salt, in bytes.
+const SALT_LENGTH: usize = 16;
+
+/// Length of a nonce, in bytes.
+const NONCE_LENGTH: usize = 12;
+
+/// The key unlocked with the passphrase, if it was.
+static KEY: Mutex<Option<Key>> = Mutex::new(None);
+
+/// Gets the path of the key file.
+#[must_use]
+pub fn key_file_path() -> PathBuf {
+	get_save_dir().join(KEY_FILE)
+}
+
+/// Contents of the key file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct KeyFile {
+	/// Salt the key is derived with.
+	salt: Vec<u8>,
+
+	/// [`CHECK_TEXT`], encrypted with the key.
+	check: Vec<u8>,
+}
+
+impl KeyFile {
+	/// Loads the key file.
+	fn load() -> anyhow::Result<Self> {
+		Ok(toml::from_str(&fs::read_to_string(key_file_path())?)?)
+	}
+
+	/// Saves the key file, in TOML format.
+	fn save(&self) -> anyhow::Result<()> {
+		fs::create_dir_all(get_save_dir())?;
+		Ok(fs::write(key_file_path(), toml::to_string_pretty(self)?)?)
+	}
+}
+
+/// Returns whether the save data is encrypted.
+#[must_use]
+pub fn is_enabled() -> bool {
+	key_file_path().exists()
+}
+
+/// Returns whether the save data is encrypted but wasn't [unlocked](unlock).
+#[must_use]
+pub fn is_locked() -> bool {
+	is_enabled() && current_key().is_none()
+}
+
+/// Returns the key unlocked, if any.
+fn current_key() -> Option<Key> {
+	*KEY.lock().expect("The encryption key was poisoned")
+}
+
+/// Sets the key unlocked.
+fn set_key(key: Option<Key>) {
+	*KEY.lock().expect("The encryption key was poisoned") = key;
+}
+
+/// Derives a key from a passphrase.
+fn derive_key(passphrase: &str, salt: &[u8]) -> anyhow::Result<Key> {
+	let mut key = Key::default();
+	Argon2::default()
+		.hash_password_into(passphrase.as_bytes(), salt, &mut key)
+		.map_err(|error| anyhow!(t!("encryption-key-failed", error = error.to_string())))?;
+	Ok(key)
+}
+
+/// Encrypts bytes with a key, prefixing them with a random nonce.
+fn encrypt_with(key: &Key, plaintext: &[u8]) -> anyhow::Result<Vec<u8>> {
+	let mut nonce = [0; NONCE_LENGTH];
+	OsRng.fill_bytes(&mut nonce);
+	let ciphertext = ChaCha20Poly1305::new(key)
+		.encrypt(Nonce::from_slice(&nonce), plaintext)
+		.map_err(|_| anyhow!(t!("encryption-encrypt-failed")))?;
+	Ok([nonce.as_slice(), &ciphertext].concat())
+}
+
+/// Decrypts bytes encrypted by [`encrypt_with`].
+fn decrypt_with(key: &Key, encrypted: &[u8]) -> anyhow::Result<Vec<u8>> {
+	if encrypted.len() < NONCE_LENGTH {
+		bail!(t!("encryption-cut-short"));
+	}
+	let (nonce, ciphertext) = encrypted.split_at(NONCE_LENGTH);
+	ChaCha20Poly1305::new(key)
+		.decrypt(Nonce::from_slice(nonce), ciphertext)
+		.map_err(|_| anyhow!(t!("encryption-decrypt-failed")))
+}
+
+/// Encrypts bytes with the key unlocked.
+pub fn encrypt(plaintext: &[u8]) -> anyhow::Result<Vec<u8>> {
+	let Some(key) = current_key() else {
+		bail!(t!("encryption-locked"));
+	};
+	encrypt_with(&key, plaintext)
+}
+
+/// Decrypts bytes with the key unlocked.
+pub fn decrypt(encrypted: &[u8]) -> anyhow::Result<Vec<u8>> {
+	let Some(key) = current_key() else {
+		bail!(t!("encryption-locked"));
+	};
+	decrypt_with(&key, encrypted)
+}
+
+/// Unlocks the save data with its passphrase.
+pub fn unlock(passphrase: &str) -> anyhow::Result<()> {
+	let key_file = KeyFile::load()?;
+	let key = derive_key(passphrase, &key_file.salt)?;
+	match decrypt_with(&key, &key_file.check) {
+		Ok(check) if check == CHECK_TEXT => {
+			set_key(Some(key));
+			Ok(())
+		},
+		_ => bail!(t!("encryption-wrong-passphrase")),
+	}
+}
+
+/// Encrypts the save data with a new passphrase, which unlocks it.
+pub fn enable(passphrase: &str) -> anyhow::Result<()> {
+	if is_enabled() {
+		bail!(t!("encryption-already-enabled"));
+	}
+	let files = save_file::list()?
+		.into_iter()
+		.map(|path| Ok((save_file::read(&path)?, path)))
+		.collect::<anyhow::Result<Vec<_>>>()?;
+	let mut salt = [0; SALT_LENGTH];
+	OsRng.fill_bytes(&mut salt);
+	let key = derive_key(passphrase, &salt)?;
+	KeyFile {
+		salt: salt.to_vec(),
+		check: encrypt_with(&key, CHECK_TEXT)?,
+	}
+	.save()?;
+	set_key(Some(key));
+	for (contents, path) in files {
+		save_file::write(&path, &contents)?;
+	}
+	Ok(())
+}
+
+/// Decrypts the save data for good, once unlocked.
+pub fn disable() -> anyhow::Result<()> {
+	if !is_enabled() {
+		bail!(t!("encryption-not-enabled"));
+	}
+	if is_locked() {
+		bail!(t!("encryption-locked"));
+	}
+	let files = save_file::list()?
+		.into_iter()
+		.map(|path| Ok((save_file::read(&path)?, path)))
+		.collect::<anyhow::Result<Vec<_>>>()?;
+	for (contents, path) in files {
+		fs::write(path, contents)?;
+	}
+	fs::remove_file(key_file_path())?;
+	set_key(None);
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		disable,
+		enable,
+	};
+	use crate::{
+		core::{
+			files::isolate_test_files,
+			save_file,
+		},
+		services::autosave,
+	};
+
+	/// Writes an autosave like [`autosave::save`], without showing the
+	/// autosave indicator other tests' snapshots would catch.
+	fn write_autosave(game: &str, json: &str) {
+		let path = autosave::autosave_path(game);
+		std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+		save_file::write(&path, json).unwrap();
+	}
+
+	#[test]
+	fn round_trips_autosaves() {
+		isolate_test_files();
+		write_autosave("before", "[1,2,3]");
+		enable("passphrase").unwrap();
+		write_autosave("after", "[4,5,6]");
+		for game in ["before", "after"] {
+			assert!(save_file::is_encrypted(&autosave::autosave_path(game)));
+		}
+
+		disable().unwrap();
+		assert!(!save_file::is_encrypted(&autosave::autosave_path("after")));
+		assert_eq!(autosave::resume::<Vec<u8>>("before"), Some(vec![1, 2, 3]));
+		assert_eq!(autosave::resume::<Vec<u8>>("after"), Some(vec![4, 5, 6]));
+	}
+}
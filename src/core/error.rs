@@ -0,0 +1,353 @@
+//! Errors the player can do something about, each with a stable code to look
+//! up or mention in an issue, and a hint on how to recover from it. Any error
+//! is turned into a [report](ErrorReport) for the error popup and the [error
+//! log](error_log_path), falling back on [`UNKNOWN_CODE`] for errors that
+//! aren't [`AppError`]s.
+
+use std::{
+	fs::{
+		self,
+		OpenOptions,
+	},
+	io::{
+		self,
+		Write,
+	},
+	path::{
+		Path,
+		PathBuf,
+	},
+	sync::Mutex,
+};
+
+use crate::{
+	core::get_save_dir,
+	games::get_unix_time_as_secs,
+	t,
+};
+
+/// Name of the file of the save directory that errors are logged to.
+pub const ERROR_LOG_FILE: &str = "errors.log";
+
+/// Code of errors that aren't [`AppError`]s.
+pub const UNKNOWN_CODE: &str = "E000";
+
+/// Reports of errors that happened away from any screen, waiting to be shown
+/// in a popup.
+static PENDING: Mutex<Vec<ErrorReport>> = Mutex::new(Vec::new());
+
+/// Gets the path of the error log.
+#[must_use]
+pub fn error_log_path() -> PathBuf {
+	get_save_dir().join(ERROR_LOG_FILE)
+}
+
+/// Returns the line and column, both counted from 1, of a byte offset in a
+/// text.
+fn line_and_column(text: &str, offset: usize) -> (usize, usize) {
+	let before = text.get(..offset).unwrap_or(text);
+	let line = before.matches('\n').count() + 1;
+	let column = before.rsplit('\n').next().unwrap_or_default().chars().count() + 1;
+	(line, column)
+}
+
+/// An error the player can recover from. Codes never change once given out,
+/// so they can be searched for across versions.
+#[derive(Debug, thiserror::Error)]
+pub enum AppError {
+	/// The configuration file isn't valid TOML, or holds an invalid setting.
+	#[error(
+		"{}",
+		t!(
+			"error-config-parse",
+			path = path.display().to_string(),
+			line = *line,
+			column = *column,
+			message = message.clone()
+		)
+	)]
+	ConfigParse {
+		/// The configuration file.
+		path: PathBuf,
+
+		/// Line the error is on, counted from 1.
+		line: usize,
+
+		/// Column the error is on, counted from 1.
+		column: usize,
+
+		/// What's wrong.
+		message: String,
+	},
+
+	/// A save file isn't valid TOML, or holds invalid data.
+	#[error(
+		"{}",
+		t!(
+			"error-save-parse",
+			path = path.display().to_string(),
+			line = *line,
+			column = *column,
+			message = message.clone()
+		)
+	)]
+	SaveFileParse {
+		/// The save file.
+		path: PathBuf,
+
+		/// Line the error is on, counted from 1.
+		line: usize,
+
+		/// Column the error is on, counted from 1.
+		column: usize,
+
+		/// What's wrong.
+		message: String,
+	},
+
+	/// A save file is encrypted, but this build can't decrypt it or can't
+	/// overwrite it.
+	#[error("{}", t!("error-save-encrypted", path = path.display().to_string()))]
+	SaveFileEncrypted {
+		/// The save file.
+		path: PathBuf,
+	},
+
+	/// A file couldn't be read or written.
+	#[error(
+		"{}",
+		t!(
+			"error-file-access",
+			path = path.display().to_string(),
+			error = error.to_string()
+		)
+	)]
+	FileAccess {
+		/// The file.
+		path: PathBuf,
+
+		/// Why it couldn't be.
+		error: io::Error,
+	},
+
+	/// A screen panicked, and was closed.
+	#[error(
+		"{}",
+		if let Some(path) = report {
+			t!(
+				"error-screen-panicked",
+				screen = *screen,
+				message = message.clone(),
+				path = path.display().to_string()
+			)
+		} else {
+			t!(
+				"error-screen-panicked-unsaved",
+				screen = *screen,
+				message = message.clone()
+			)
+		}
+	)]
+	ScreenPanicked {
+		/// Title of the screen.
+		screen: &'static str,
+
+		/// What the panic said, and where.
+		message: String,
+
+		/// The bug report saved about it, if it could be.
+		report: Option<PathBuf>,
+	},
+
+	/// No frame was drawn for a while, as noticed by the
+	/// [watchdog](crate::core::watchdog).
+	#[error(
+		"{}",
+		t!(
+			"error-render-stalled",
+			seconds = *seconds,
+			terminal = terminal.clone()
+		)
+	)]
+	RenderStalled {
+		/// How long no frame was drawn for.
+		seconds: u64,
+
+		/// The terminal's size, or why it couldn't be read.
+		terminal: String,
+	},
+}
+
+impl AppError {
+	/// Returns the error of the configuration file failing to parse.
+	#[must_use]
+	pub fn config_parse(path: &Path, contents: &str, error: &toml::de::Error) -> Self {
+		let (line, column) = line_and_column(contents, error.span().map_or(0, |span| span.start));
+		Self::ConfigParse {
+			path: path.to_path_buf(),
+			line,
+			column,
+			message: error.message().to_string(),
+		}
+	}
+
+	/// Returns the error of a save file failing to parse.
+	#[must_use]
+	pub fn save_file_parse(path: &Path, contents: &str, error: &toml::de::Error) -> Self {
+		let (line, column) = line_and_column(contents, error.span().map_or(0, |span| span.start));
+		Self::SaveFileParse {
+			path: path.to_path_buf(),
+			line,
+			column,
+			message: error.message().to_string(),
+		}
+	}
+
+	/// Returns the error of a file failing to be read or written.
+	#[must_use]
+	pub fn file_access(path: &Path, error: io::Error) -> Self {
+		Self::FileAccess {
+			path: path.to_path_buf(),
+			error,
+		}
+	}
+
+	/// Returns whether an error is about a file that doesn't exist, like a
+	/// save file that wasn't created yet, rather than one that's broken.
+	#[must_use]
+	pub fn is_missing_file(error: &anyhow::Error) -> bool {
+		error.chain().any(|cause| {
+			matches!(
+				cause.downcast_ref::<Self>(),
+				Some(Self::FileAccess { error, .. }) if matches!(
+					error.kind(),
+					// Browsers have no file system, so no files at all.
+					io::ErrorKind::NotFound | io::ErrorKind::Unsupported
+				)
+			)
+		})
+	}
+
+	/// Returns the error's stable code.
+	#[must_use]
+	pub fn code(&self) -> &'static str {
+		match self {
+			Self::ConfigParse { .. } => "E101",
+			Self::SaveFileParse { .. } => "E201",
+			Self::SaveFileEncrypted { .. } => "E202",
+			Self::FileAccess { .. } => "E301",
+			Self::ScreenPanicked { .. } => "E901",
+			Self::RenderStalled { .. } => "E902",
+		}
+	}
+
+	/// Returns a hint on how to recover from the error.
+	#[must_use]
+	pub fn hint(&self) -> &'static str {
+		match self {
+			Self::ConfigParse { .. } => t!("error-config-parse-hint"),
+			Self::SaveFileParse { .. } => t!("error-save-parse-hint"),
+			Self::SaveFileEncrypted { .. } => t!("error-save-encrypted-hint"),
+			Self::FileAccess { .. } => t!("error-file-access-hint"),
+			Self::ScreenPanicked { .. } => t!("error-screen-panicked-hint"),
+			Self::RenderStalled { .. } => t!("error-render-stalled-hint"),
+		}
+	}
+}
+
+/// What the player is told about an error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct ErrorReport {
+	/// The error's [code](AppError::code).
+	pub code: &'static str,
+
+	/// What went wrong, with its causes.
+	pub message: String,
+
+	/// How to recover from it.
+	pub hint: &'static str,
+}
+
+impl ErrorReport {
+	/// Reports an error, going by the first [`AppError`] among it and its
+	/// causes.
+	pub fn new(error: &anyhow::Error) -> Self {
+		let app_error = error.chain().find_map(|cause| cause.downcast_ref::<AppError>());
+		Self {
+			code: app_error.map_or(UNKNOWN_CODE, AppError::code),
+			message: format!("{error:#}"),
+			hint: app_error.map_or_else(|| t!("error-unknown-hint"), AppError::hint),
+		}
+	}
+
+	/// Returns the report as text: the code and what went wrong on the first
+	/// line, and the hint on the second.
+	#[must_use]
+	pub fn to_text(&self) -> String {
+		format!("[{}] {}\n{}", self.code, self.message, self.hint)
+	}
+}
+
+/// Appends a report to the [error log](error_log_path), with when it
+/// happened.
+pub fn log(report: &ErrorReport) -> anyhow::Result<()> {
+	fs::create_dir_all(get_save_dir())?;
+	let mut log = OpenOptions::new().create(true).append(true).open(error_log_path())?;
+	writeln!(
+		log,
+		"{} [{}] {}",
+		get_unix_time_as_secs(),
+		report.code,
+		report.message
+	)?;
+	Ok(())
+}
+
+/// Logs an error that happened away from any screen, like while loading the
+/// configuration at startup, and keeps its report for the handler to show in
+/// a popup.
+pub fn report_later(error: &anyhow::Error) {
+	let report = ErrorReport::new(error);
+	// The popup still tells about the error if it couldn't be logged.
+	let _ = log(&report);
+	if let Ok(mut pending) = PENDING.lock() {
+		pending.push(report);
+	}
+}
+
+/// Takes the reports of the errors [reported later](report_later) that
+/// weren't shown yet.
+pub fn take_pending() -> Vec<ErrorReport> {
+	PENDING.lock().map(|mut pending| std::mem::take(&mut *pending)).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+	use std::path::Path;
+
+	use super::{
+		AppError,
+		ErrorReport,
+		UNKNOWN_CODE,
+	};
+
+	#[test]
+	fn reports_codes_and_locations() {
+		let contents = "show_tips = true\nlarge_text = \"yes\"\n";
+		let error = toml::from_str::<crate::core::config::Config>(contents).unwrap_err();
+		let error = AppError::config_parse(Path::new("config.toml"), contents, &error);
+		let AppError::ConfigParse { line, column, .. } = error else {
+			panic!("not a configuration error: {error:?}");
+		};
+		assert_eq!((line, column), (2, 14));
+
+		let report = ErrorReport::new(&anyhow::Error::from(error).context("Loading the settings"));
+		assert_eq!(report.code, "E101");
+		assert!(report.message.starts_with("Loading the settings: "));
+		assert_eq!(
+			ErrorReport::new(&anyhow::anyhow!("Oops")).code,
+			UNKNOWN_CODE
+		);
+	}
+}
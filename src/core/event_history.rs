@@ -0,0 +1,102 @@
+//! The last events played, kept by the [handler](crate::core::handler) so that
+//! [bug reports](crate::core::bug_report) can tell how a bug was reached, and
+//! so that developers can replay them in the
+//! [event replay screen](crate::ui::screens::event_replay).
+//!
+//! The events that [scripts](crate::core::script) can play can be written as a
+//! script, and played back with `--script`.
+
+use std::{
+	collections::VecDeque,
+	time::Duration,
+};
+
+use crate::core::{
+	events::Event,
+	script::format_event,
+	time::Instant,
+};
+
+/// How many events are kept, the oldest being dropped first.
+pub const EVENT_HISTORY_LEN: usize = 200;
+
+/// An event kept in an [`EventHistory`].
+#[derive(Debug, Clone)]
+pub struct RecordedEvent {
+	/// When the event was played.
+	pub at: Instant,
+
+	/// The event played.
+	pub event: Event,
+}
+
+impl RecordedEvent {
+	/// Returns the event as a script step's input, like `key ctrl+c`, if
+	/// scripts can play it.
+	#[must_use]
+	pub fn input(&self) -> Option<String> {
+		format_event(&self.event)
+	}
+
+	/// Describes the event: as a script step's input if scripts can play it,
+	/// or else as it's debugged.
+	#[must_use]
+	pub fn describe(&self) -> String {
+		self.input().unwrap_or_else(|| format!("{:?}", self.event))
+	}
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Debug, Clone, Default)]
+#[must_use]
+pub struct EventHistory {
+	/// The events kept, oldest first.
+	events: VecDeque<RecordedEvent>,
+}
+
+impl EventHistory {
+	/// Keeps an event, dropping the oldest one if there are already
+	/// [`EVENT_HISTORY_LEN`].
+	pub fn record(&mut self, event: &Event) {
+		if self.events.len() == EVENT_HISTORY_LEN {
+			self.events.pop_front();
+		}
+		self.events.push_back(RecordedEvent {
+			at: Instant::now(),
+			event: event.clone(),
+		});
+	}
+
+	/// Returns the events kept, oldest first.
+	pub fn events(&self) -> impl Iterator<Item = &RecordedEvent> {
+		self.events.iter()
+	}
+
+	/// Returns the number of events kept.
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.events.len()
+	}
+
+	/// Returns whether no event was kept.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.events.is_empty()
+	}
+
+	/// Writes the events kept that scripts can play as a script, waiting as
+	/// long between them as they were played apart.
+	#[must_use]
+	pub fn to_script(&self) -> String {
+		let mut last_at = None;
+		let steps = self.events.iter().filter_map(|event| {
+			let input = event.input()?;
+			let delay = last_at.map_or(Duration::ZERO, |last_at| event.at - last_at);
+			last_at = Some(event.at);
+			Some(format!("{}ms {input}\n", delay.as_millis()))
+		});
+		std::iter::once("# The last inputs played, oldest first.\n".to_string())
+			.chain(steps)
+			.collect()
+	}
+}
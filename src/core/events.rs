@@ -0,0 +1,326 @@
+//! The events screens are given: keys pressed, mouse clicks, pastes, focus
+//! changes and resizes.
+//!
+//! Native builds read them from the terminal with crossterm, and these are
+//! crossterm's own types. Crossterm doesn't build for browsers though, so
+//! [web](crate::core::web) builds get look-alikes instead, with the same
+//! names, variants and fields, which the browser's terminal is translated to.
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use crossterm::event::{
+	Event,
+	KeyCode,
+	KeyEvent,
+	KeyEventKind,
+	KeyEventState,
+	KeyModifiers,
+	MediaKeyCode,
+	ModifierKeyCode,
+	MouseButton,
+	MouseEvent,
+	MouseEventKind,
+};
+
+#[cfg(target_arch = "wasm32")]
+pub use self::web::*;
+
+/// Look-alikes of crossterm's events, for builds without crossterm. See the
+/// [module](self) documentation for more information.
+#[cfg(target_arch = "wasm32")]
+mod web {
+	use std::hash::{
+		Hash,
+		Hasher,
+	};
+
+	use bitflags::bitflags;
+
+	/// An event screens handle.
+	#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Hash)]
+	pub enum Event {
+		/// The terminal gained focus.
+		FocusGained,
+
+		/// The terminal lost focus.
+		FocusLost,
+
+		/// A key was pressed, repeated or released.
+		Key(KeyEvent),
+
+		/// The mouse was clicked, moved or scrolled.
+		Mouse(MouseEvent),
+
+		/// Text was pasted.
+		Paste(String),
+
+		/// The terminal was resized to a number of columns and rows.
+		Resize(u16, u16),
+	}
+
+	/// A mouse event, in a cell of the terminal.
+	#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+	pub struct MouseEvent {
+		/// What the mouse did.
+		pub kind: MouseEventKind,
+
+		/// The column of the cell, from 0.
+		pub column: u16,
+
+		/// The row of the cell, from 0.
+		pub row: u16,
+
+		/// The modifier keys held.
+		pub modifiers: KeyModifiers,
+	}
+
+	/// What the mouse did.
+	#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+	pub enum MouseEventKind {
+		/// A button was pressed.
+		Down(MouseButton),
+
+		/// A button was released.
+		Up(MouseButton),
+
+		/// The mouse moved with a button pressed.
+		Drag(MouseButton),
+
+		/// The mouse moved.
+		Moved,
+
+		/// The wheel was scrolled down.
+		ScrollDown,
+
+		/// The wheel was scrolled up.
+		ScrollUp,
+	}
+
+	/// A mouse button.
+	#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+	pub enum MouseButton {
+		/// The left button.
+		Left,
+
+		/// The right button.
+		Right,
+
+		/// The middle button, usually the wheel.
+		Middle,
+	}
+
+	bitflags! {
+		/// Modifier keys held with a key or mouse event.
+		#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+		pub struct KeyModifiers: u8 {
+			/// Shift.
+			const SHIFT = 0b0000_0001;
+			/// Control.
+			const CONTROL = 0b0000_0010;
+			/// Alt, or Option on macOS.
+			const ALT = 0b0000_0100;
+			/// Super, the Windows or Command key.
+			const SUPER = 0b0000_1000;
+			/// Hyper.
+			const HYPER = 0b0001_0000;
+			/// Meta.
+			const META = 0b0010_0000;
+			/// No modifier.
+			const NONE = 0b0000_0000;
+		}
+	}
+
+	/// Whether a key was pressed, repeated or released.
+	#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+	pub enum KeyEventKind {
+		/// The key was pressed.
+		Press,
+
+		/// The key is held.
+		Repeat,
+
+		/// The key was released.
+		Release,
+	}
+
+	bitflags! {
+		/// The state of the keyboard when a key was pressed.
+		#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+		pub struct KeyEventState: u8 {
+			/// The key is on the keypad.
+			const KEYPAD = 0b0000_0001;
+			/// Caps Lock was on.
+			const CAPS_LOCK = 0b0000_1000;
+			/// Num Lock was on.
+			const NUM_LOCK = 0b0000_1000;
+			/// Nothing special.
+			const NONE = 0b0000_0000;
+		}
+	}
+
+	/// A key event. Like crossterm's, uppercase letters count as pressed with
+	/// Shift, whether Shift is in their modifiers or not.
+	#[derive(Debug, PartialOrd, Clone, Copy)]
+	pub struct KeyEvent {
+		/// The key.
+		pub code: KeyCode,
+
+		/// The modifier keys held.
+		pub modifiers: KeyModifiers,
+
+		/// Whether the key was pressed, repeated or released.
+		pub kind: KeyEventKind,
+
+		/// The state of the keyboard.
+		pub state: KeyEventState,
+	}
+
+	impl KeyEvent {
+		/// Creates a key press.
+		#[must_use]
+		pub const fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+			Self::new_with_kind(code, modifiers, KeyEventKind::Press)
+		}
+
+		/// Creates a key event of a kind.
+		#[must_use]
+		pub const fn new_with_kind(
+			code: KeyCode,
+			modifiers: KeyModifiers,
+			kind: KeyEventKind,
+		) -> Self {
+			Self::new_with_kind_and_state(code, modifiers, kind, KeyEventState::empty())
+		}
+
+		/// Creates a key event of a kind, with the keyboard in a state.
+		#[must_use]
+		pub const fn new_with_kind_and_state(
+			code: KeyCode,
+			modifiers: KeyModifiers,
+			kind: KeyEventKind,
+			state: KeyEventState,
+		) -> Self {
+			Self {
+				code,
+				modifiers,
+				kind,
+				state,
+			}
+		}
+
+		/// Returns the event with Shift in its modifiers if and only if its key
+		/// is an uppercase letter.
+		fn normalize_case(mut self) -> Self {
+			let KeyCode::Char(char) = self.code else {
+				return self;
+			};
+			if char.is_ascii_uppercase() {
+				self.modifiers.insert(KeyModifiers::SHIFT);
+			} else if self.modifiers.contains(KeyModifiers::SHIFT) {
+				self.code = KeyCode::Char(char.to_ascii_uppercase());
+			}
+			self
+		}
+	}
+
+	impl From<KeyCode> for KeyEvent {
+		fn from(code: KeyCode) -> Self {
+			Self::new(code, KeyModifiers::empty())
+		}
+	}
+
+	impl PartialEq for KeyEvent {
+		fn eq(&self, other: &Self) -> bool {
+			let (lhs, rhs) = (self.normalize_case(), other.normalize_case());
+			lhs.code == rhs.code
+				&& lhs.modifiers == rhs.modifiers
+				&& lhs.kind == rhs.kind
+				&& lhs.state == rhs.state
+		}
+	}
+
+	impl Eq for KeyEvent {}
+
+	impl Hash for KeyEvent {
+		fn hash<H: Hasher>(&self, state: &mut H) {
+			let event = self.normalize_case();
+			event.code.hash(state);
+			event.modifiers.hash(state);
+			event.kind.hash(state);
+			event.state.hash(state);
+		}
+	}
+
+	/// A media key.
+	#[allow(missing_docs)]
+	#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+	pub enum MediaKeyCode {
+		Play,
+		Pause,
+		PlayPause,
+		Reverse,
+		Stop,
+		FastForward,
+		Rewind,
+		TrackNext,
+		TrackPrevious,
+		Record,
+		LowerVolume,
+		RaiseVolume,
+		MuteVolume,
+	}
+
+	/// A modifier key pressed on its own.
+	#[allow(missing_docs)]
+	#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+	pub enum ModifierKeyCode {
+		LeftShift,
+		LeftControl,
+		LeftAlt,
+		LeftSuper,
+		LeftHyper,
+		LeftMeta,
+		RightShift,
+		RightControl,
+		RightAlt,
+		RightSuper,
+		RightHyper,
+		RightMeta,
+		IsoLevel3Shift,
+		IsoLevel5Shift,
+	}
+
+	/// A key.
+	#[allow(missing_docs)]
+	#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy, Hash)]
+	pub enum KeyCode {
+		Backspace,
+		Enter,
+		Left,
+		Right,
+		Up,
+		Down,
+		Home,
+		End,
+		PageUp,
+		PageDown,
+		Tab,
+		BackTab,
+		Delete,
+		Insert,
+		/// A function key, like 1 for F1.
+		F(u8),
+		/// A character typed.
+		Char(char),
+		Null,
+		Esc,
+		CapsLock,
+		ScrollLock,
+		NumLock,
+		PrintScreen,
+		Pause,
+		Menu,
+		KeypadBegin,
+		Media(MediaKeyCode),
+		Modifier(ModifierKeyCode),
+	}
+}
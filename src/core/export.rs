@@ -0,0 +1,166 @@
+//! Exports of the player's statistics in every game, as CSV or JSON files,
+//! for analysis in other programs. Run from the settings, or with the
+//! `export` command.
+
+use std::{
+	fs,
+	path::{
+		Path,
+		PathBuf,
+	},
+};
+
+use chrono::{
+	DateTime,
+	Local,
+	Utc,
+};
+use clap::ValueEnum;
+use serde_derive::Serialize;
+
+use crate::{
+	core::get_save_dir,
+	games::{
+		Game,
+		Games,
+	},
+};
+
+/// Header of CSV exports.
+const CSV_HEADER: &str = "game,play_count,last_played,rating,high_score,best_time_secs";
+
+/// Format of an export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+	/// Comma-separated values, one game per line.
+	Csv,
+
+	/// A JSON array, one object per game.
+	Json,
+}
+
+impl ExportFormat {
+	/// Returns the format's file extension.
+	#[must_use]
+	pub fn extension(self) -> &'static str {
+		match self {
+			Self::Csv => "csv",
+			Self::Json => "json",
+		}
+	}
+
+	/// Returns the format matching a file's extension, if any.
+	#[must_use]
+	pub fn from_path(path: &Path) -> Option<Self> {
+		let extension = path.extension()?.to_str()?;
+		Self::value_variants()
+			.iter()
+			.copied()
+			.find(|format| extension.eq_ignore_ascii_case(format.extension()))
+	}
+}
+
+/// Statistics of the player in a game.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GameStats {
+	/// Name of the game.
+	pub game: String,
+
+	/// How many times the game was played.
+	pub play_count: u64,
+
+	/// When the game was last played, in RFC 3339 format.
+	pub last_played: Option<String>,
+
+	/// The player's rating in the game's versus mode.
+	pub rating: Option<u32>,
+
+	/// The player's best score.
+	pub high_score: Option<i64>,
+
+	/// The player's fastest time, in seconds.
+	pub best_time_secs: Option<u64>,
+}
+
+/// Collects the player's statistics in every game.
+#[must_use]
+pub fn collect_stats() -> Vec<GameStats> {
+	Games::all()
+		.into_iter()
+		.map(|game| {
+			let metadata = game.data().metadata;
+			let info = metadata.dynamic_info;
+			GameStats {
+				game: metadata.static_info.name,
+				play_count: info.play_count,
+				last_played: info.last_played.and_then(|secs| {
+					let secs = i64::try_from(secs).ok()?;
+					Some(DateTime::<Utc>::from_timestamp(secs, 0)?.to_rfc3339())
+				}),
+				rating: info.rating,
+				high_score: info.high_score,
+				best_time_secs: info.best_time_secs,
+			}
+		})
+		.collect()
+}
+
+/// Quotes a CSV field if needed.
+fn csv_field(field: &str) -> String {
+	if field.contains([',', '"', '\n']) {
+		format!("\"{}\"", field.replace('"', "\"\""))
+	} else {
+		field.to_string()
+	}
+}
+
+/// Formats statistics as CSV.
+#[must_use]
+pub fn to_csv(stats: &[GameStats]) -> String {
+	let optional = |value: Option<String>| value.unwrap_or_default();
+	let mut csv = vec![CSV_HEADER.to_string()];
+	csv.extend(stats.iter().map(|game| {
+		[
+			csv_field(&game.game),
+			game.play_count.to_string(),
+			optional(game.last_played.clone()),
+			optional(game.rating.map(|rating| rating.to_string())),
+			optional(game.high_score.map(|score| score.to_string())),
+			optional(game.best_time_secs.map(|secs| secs.to_string())),
+		]
+		.join(",")
+	}));
+	csv.join("\n") + "\n"
+}
+
+/// Formats statistics as a JSON array.
+pub fn to_json(stats: &[GameStats]) -> anyhow::Result<String> {
+	Ok(serde_json::to_string_pretty(stats)? + "\n")
+}
+
+/// Writes the player's statistics to a file.
+pub fn export(path: &Path, format: ExportFormat) -> anyhow::Result<()> {
+	let stats = collect_stats();
+	let contents = match format {
+		ExportFormat::Csv => to_csv(&stats),
+		ExportFormat::Json => to_json(&stats)?,
+	};
+	if let Some(parent) = path.parent() {
+		fs::create_dir_all(parent)?;
+	}
+	Ok(fs::write(path, contents)?)
+}
+
+/// Gets the path of a new export in the save directory's `exports` folder,
+/// named after what's exported and the current time.
+#[must_use]
+pub fn export_path(name: &str, extension: &str) -> PathBuf {
+	let time = Local::now().format("%Y-%m-%d_%H-%M-%S");
+	get_save_dir().join("exports").join(format!("{name}_{time}.{extension}"))
+}
+
+/// Gets the path of a new export of statistics in a format.
+#[must_use]
+pub fn default_export_path(format: ExportFormat) -> PathBuf {
+	export_path("stats", format.extension())
+}
@@ -0,0 +1,206 @@
+//! Locations of the files Terminal Arcade reads and writes. See [`AppFiles`].
+//!
+//! By default, data and configuration are saved in the platform's usual
+//! directories, like `~/.local/share` and `~/.config` on Linux, or in the
+//! legacy [`SAVE_DIR`] in the home directory if those can't be found. The
+//! `--data-dir` and `--config-dir` arguments move them elsewhere, and the
+//! portable mode, enabled with `--portable` or by a [`PORTABLE_MARKER`] file
+//! next to the executable, keeps everything beside the executable, like on a
+//! USB stick.
+
+use std::{
+	env,
+	path::{
+		Path,
+		PathBuf,
+	},
+	sync::OnceLock,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use directories::ProjectDirs;
+
+use crate::{
+	core::{
+		cli::Cli,
+		SAVE_DIR,
+	},
+	t,
+};
+
+/// Name of the file that enables the portable mode when it's next to the
+/// executable.
+pub const PORTABLE_MARKER: &str = "portable";
+
+/// Name of the folder files are saved in in the portable mode, next to the
+/// executable.
+pub const PORTABLE_DIR: &str = "terminal-arcade-data";
+
+/// The files' locations picked on startup.
+static APP_FILES: OnceLock<AppFiles> = OnceLock::new();
+
+/// Where a path in [`AppFiles`] comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathSource {
+	/// The default location, in the platform's usual directories.
+	Default,
+
+	/// An argument on the command line.
+	Argument,
+
+	/// The portable mode, next to the executable.
+	Portable,
+}
+
+impl PathSource {
+	/// Returns a short description of the source.
+	#[must_use]
+	pub fn label(self) -> &'static str {
+		match self {
+			PathSource::Default => t!("files-source-default"),
+			PathSource::Argument => t!("files-source-argument"),
+			PathSource::Portable => t!("files-source-portable"),
+		}
+	}
+}
+
+/// Locations of Terminal Arcade's files, and where they come from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppFiles {
+	/// Directory of save data, like game metadata and scripted games.
+	pub data_dir: PathBuf,
+
+	/// Where [`Self::data_dir`] comes from.
+	pub data_source: PathSource,
+
+	/// Directory of the configuration file.
+	pub config_dir: PathBuf,
+
+	/// Where [`Self::config_dir`] comes from.
+	pub config_source: PathSource,
+}
+
+impl Default for AppFiles {
+	fn default() -> Self {
+		#[cfg(not(target_arch = "wasm32"))]
+		let (data_dir, config_dir) = if let Some(dirs) = ProjectDirs::from("", "", "terminal-arcade") {
+			(
+				dirs.data_dir().to_path_buf(),
+				dirs.config_dir().to_path_buf(),
+			)
+		} else {
+			let dir = home::home_dir().unwrap().join(SAVE_DIR);
+			(dir.clone(), dir)
+		};
+		// Browsers have no file system: files there fail to be read and
+		// written, and games go on without them.
+		#[cfg(target_arch = "wasm32")]
+		let (data_dir, config_dir) = (PathBuf::from(SAVE_DIR), PathBuf::from(SAVE_DIR));
+		Self {
+			data_dir,
+			data_source: PathSource::Default,
+			config_dir,
+			config_source: PathSource::Default,
+		}
+	}
+}
+
+impl AppFiles {
+	/// Returns the files' locations in the portable mode, in [`PORTABLE_DIR`]
+	/// next to an executable.
+	#[must_use]
+	pub fn portable(executable_dir: &Path) -> Self {
+		let dir = executable_dir.join(PORTABLE_DIR);
+		Self {
+			data_dir: dir.clone(),
+			data_source: PathSource::Portable,
+			config_dir: dir,
+			config_source: PathSource::Portable,
+		}
+	}
+
+	/// Returns the files' locations picked by the command-line arguments,
+	/// which take precedence over the portable mode, which takes precedence
+	/// over the defaults.
+	#[must_use]
+	pub fn from_cli(cli: &Cli) -> Self {
+		let executable_dir =
+			env::current_exe().ok().and_then(|path| Some(path.parent()?.to_path_buf()));
+		let portable = cli.portable
+			|| executable_dir.as_ref().is_some_and(|dir| dir.join(PORTABLE_MARKER).exists());
+		let mut files = match executable_dir {
+			Some(dir) if portable => Self::portable(&dir),
+			_ => Self::default(),
+		};
+		if let Some(dir) = &cli.data_dir {
+			files.data_dir.clone_from(dir);
+			files.data_source = PathSource::Argument;
+		}
+		if let Some(dir) = &cli.config_dir {
+			files.config_dir.clone_from(dir);
+			files.config_source = PathSource::Argument;
+		}
+		files
+	}
+}
+
+/// Picks the files' locations for the rest of the run. Has no effect once
+/// they were picked, so it should be called before any file is read.
+pub fn init(files: AppFiles) -> &'static AppFiles {
+	APP_FILES.get_or_init(|| files)
+}
+
+/// Returns the files' locations picked, or the [defaults](AppFiles::default)
+/// if none were. Tests get [their own](test_files) instead.
+#[must_use]
+pub fn app_files() -> &'static AppFiles {
+	#[cfg(test)]
+	if let Some(files) = ISOLATED_FILES.get() {
+		return files;
+	}
+	#[cfg(not(test))]
+	let files = AppFiles::default;
+	#[cfg(test)]
+	let files = test_files;
+	APP_FILES.get_or_init(files)
+}
+
+/// Returns the files' locations for tests, in a temporary directory shared by
+/// every test of the run, so that tests never read or overwrite the player's
+/// files.
+#[cfg(test)]
+fn test_files() -> AppFiles {
+	static DIR: OnceLock<tempfile::TempDir> = OnceLock::new();
+	let dir = DIR
+		.get_or_init(|| tempfile::TempDir::new().expect("Failed to create the tests' directory"))
+		.path();
+	files_in(dir)
+}
+
+#[cfg(test)]
+thread_local! {
+	/// The files' locations of a test that [isolated](isolate_test_files) them.
+	static ISOLATED_FILES: std::cell::Cell<Option<&'static AppFiles>> =
+		const { std::cell::Cell::new(None) };
+}
+
+/// Gives the calling test a temporary directory of its own for the rest of
+/// it, for tests changing files other tests would notice, like [encrypting
+/// them](crate::core::encryption). Tests run on threads of their own.
+#[cfg(test)]
+pub fn isolate_test_files() {
+	let dir = tempfile::TempDir::new().expect("Failed to create the test's directory");
+	let files = Box::leak(Box::new(files_in(&dir.keep())));
+	ISOLATED_FILES.set(Some(files));
+}
+
+/// Returns the files' locations in a directory, for tests.
+#[cfg(test)]
+fn files_in(dir: &Path) -> AppFiles {
+	AppFiles {
+		data_dir: dir.join("data"),
+		data_source: PathSource::Argument,
+		config_dir: dir.join("config"),
+		config_source: PathSource::Argument,
+	}
+}
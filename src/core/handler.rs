@@ -18,6 +18,7 @@ use std::{
 
 use anyhow::bail;
 use bool_toggle::Toggler;
+#[cfg(not(target_arch = "wasm32"))]
 use crossterm::{
 	cursor::{
 		DisableBlinking,
@@ -35,10 +36,6 @@ use crossterm::{
 		EnableBracketedPaste,
 		EnableFocusChange,
 		EnableMouseCapture,
-		Event,
-		KeyCode,
-		KeyEvent,
-		KeyModifiers,
 	},
 	execute,
 	terminal::{
@@ -49,8 +46,13 @@ use crossterm::{
 	},
 };
 use derive_new::new;
+#[cfg(not(target_arch = "wasm32"))]
+use ratatui::backend::CrosstermBackend;
 use ratatui::{
-	backend::CrosstermBackend,
+	backend::{
+		Backend,
+		TestBackend,
+	},
 	layout::{
 		Constraint,
 		Layout,
@@ -59,21 +61,107 @@ use ratatui::{
 		Color,
 		Style,
 	},
+	CompletedFrame,
+};
+#[cfg(unix)]
+use serde_json::{
+	json,
+	Value,
 };
+#[cfg(unix)]
+use strum::IntoEnumIterator;
 
-use crate::ui::{
-	screens::{
-		OpenStatus,
-		ScreenAndState,
-		ScreenKind,
-		ScreenState,
-		Screens,
+#[cfg(feature = "encryption")]
+use crate::ui::screens::passphrase::{
+	PassphrasePurpose,
+	PassphraseScreen,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::{
+	core::watchdog::Watchdog,
+	services::sync,
+};
+use crate::{
+	core::{
+		actions::{
+			self,
+			Action,
+			ActionContext,
+		},
+		bug_report,
+		clipboard,
+		error::{
+			self,
+			AppError,
+			ErrorReport,
+		},
+		event_history::EventHistory,
+		events::{
+			Event,
+			KeyCode,
+			KeyEvent,
+			KeyEventKind,
+			KeyModifiers,
+		},
+		input::InputPipeline,
+		macros,
+		migration::Migration,
+		panics::{
+			self,
+			ScreenPanic,
+		},
+		recording::Recording,
+		render_stats::{
+			self,
+			FramePacer,
+		},
+		screenshot,
+		script::Script,
+		time::Instant,
+	},
+	services::{
+		autosave::AutosaveTimer,
+		stats,
+	},
+	t,
+	ui::{
+		screens::{
+			about::BUG_REPORT_URL,
+			sync_conflict::SyncConflictPopup,
+			ErrorPopup,
+			EventReplayScreen,
+			MigrationScreen,
+			OpenStatus,
+			QrCodePopup,
+			ScreenAndState,
+			ScreenEvent,
+			ScreenKind,
+			ScreenState,
+			Screens,
+		},
+		Screen,
+		WelcomeScreen,
+	},
+};
+#[cfg(unix)]
+use crate::{
+	games::{
+		registry::{
+			self,
+			GameId,
+		},
+		Game,
+		Games,
+	},
+	services::ipc::{
+		IpcCommand,
+		IpcResponse,
+		IpcServer,
 	},
-	Screen,
-	WelcomeScreen,
 };
 
 /// Kind of terminal backend used in Terminal Arcade - crossterm + stdout.
+#[cfg(not(target_arch = "wasm32"))]
 pub type Terminal = ratatui::Terminal<CrosstermBackend<Stdout>>;
 
 /// Struct to handle and manage multiple [Screen]s in Terminal Arcade.
@@ -84,10 +172,18 @@ pub struct ScreenHandler {
 	/// The screen with the highest index in this hierarchy will be the only
 	/// screen visible on the terminal.
 	screens: Vec<ScreenAndState>,
+
+	/// Text screens asked to [copy](ScreenEvent::Copy), until it's written to
+	/// the terminal.
+	copies: Vec<String>,
+
+	/// Panics [caught](panics) in screens, until they're reported.
+	panics: Vec<ScreenPanic>,
 }
 
 impl ScreenHandler {
 	/// Returns whether there are no [Screen]s to manage.
+	#[must_use]
 	pub fn is_empty(&self) -> bool {
 		self.screens.is_empty()
 	}
@@ -118,47 +214,375 @@ impl ScreenHandler {
 		drawn_screens
 	}
 
+	/// Returns whether every screen drawn is
+	/// [static](ScreenState::static_frames).
+	#[must_use]
+	pub fn is_static(&self) -> bool {
+		for screen in self.screens.iter().rev() {
+			if !screen.state.static_frames {
+				return false;
+			}
+			if screen.state.kind == ScreenKind::Normal {
+				break;
+			}
+		}
+		true
+	}
+
 	/// "Spawns" a screen. This method simply appends a
-	/// [`ScreenAndState`] object to the tail end of the screen stack.
-	fn spawn_screen(&mut self, screen: Screens) {
+	/// [`ScreenAndState`] object to the tail end of the screen stack, after
+	/// [pausing](Screen::pause) the screen it opens over.
+	pub fn spawn_screen(&mut self, screen: Screens) {
+		if let Some(active_screen) = self.get_mut_active_screen() {
+			active_screen.pause();
+		}
 		self.screens.push(ScreenAndState::new(screen));
 	}
 
 	/// Closes the active screen and returns it.
 	/// This function pops the screen from the screen hierarchy in
-	/// Terminal Arcade, and calls its [`Screen::close`] function.
+	/// Terminal Arcade, and calls its [`Screen::close`] function. The screen
+	/// under it is [resumed](Screen::resume).
 	fn close_active_screen(&mut self) -> anyhow::Result<Option<ScreenAndState>> {
-		match self.get_mut_active_screen() {
-			Some(screen) => screen.close()?,
-			None => {},
+		if let Some(screen) = self.get_mut_active_screen() {
+			screen.close()?;
+		}
+		let closed = self.screens.pop();
+		if let Some(active_screen) = self.get_mut_active_screen() {
+			active_screen.resume();
+		}
+		Ok(closed)
+	}
+
+	/// Closes a screen that [panicked](panics) along with the screens opened
+	/// over it, without running any more of their code, and keeps the panic
+	/// to report. The screen under them is [resumed](Screen::resume).
+	fn close_panicked_screen(&mut self, index: usize, panic: ScreenPanic) {
+		self.screens.truncate(index);
+		self.panics.push(panic);
+		if let Some(active_screen) = self.get_mut_active_screen() {
+			active_screen.resume();
+		}
+	}
+
+	/// Takes the panics caught in screens since the last call.
+	pub fn take_panics(&mut self) -> Vec<ScreenPanic> {
+		std::mem::take(&mut self.panics)
+	}
+
+	/// Closes every screen, from the active one down.
+	pub fn close_all_screens(&mut self) -> anyhow::Result<()> {
+		while !self.is_empty() {
+			self.close_active_screen()?;
+		}
+		Ok(())
+	}
+
+	/// Returns the titles of the screens in the stack, the active one last.
+	#[must_use]
+	pub fn titles(&self) -> Vec<&'static str> {
+		self.screens.iter().map(|screen| screen.state.title).collect()
+	}
+
+	/// [Autosaves](Screen::autosave) the games in progress on every screen.
+	pub fn autosave(&mut self) {
+		for screen in &mut self.screens {
+			// Failures are shown by the autosave indicator.
+			let _ = screen.screen.autosave();
+		}
+	}
+
+	/// [Updates](Screen::update) the active screen, after pausing or resuming
+	/// it for its [pause menu](ScreenState::pause_menu) and [start
+	/// countdown](ScreenState::start_countdown).
+	pub fn update_active_screen(&mut self) {
+		let Some(screen) = self.get_mut_active_screen() else {
+			return;
+		};
+		let updated = panics::catch(screen.state.title, || {
+			screen.update_pause_menu();
+			screen.update_countdown();
+			screen.screen.update(&mut screen.state);
+		});
+		if let Err(panic) = updated {
+			self.close_panicked_screen(self.screens.len() - 1, panic);
 		}
-		Ok(self.screens.pop())
+	}
+
+	/// Passes an event to the active screen. The active screen is
+	/// [paused](Screen::pause) while the terminal loses focus instead.
+	pub fn send_event(&mut self, event: &Event) -> anyhow::Result<()> {
+		let Some(screen) = self.get_mut_active_screen() else {
+			return Ok(());
+		};
+		let handled = panics::catch(screen.state.title, || {
+			match event {
+				Event::FocusLost => screen.pause(),
+				Event::FocusGained => screen.resume(),
+				_ => screen.screen.event(event, &mut screen.state)?,
+			}
+			anyhow::Ok(())
+		});
+		handled.unwrap_or_else(|panic| {
+			self.close_panicked_screen(self.screens.len() - 1, panic);
+			Ok(())
+		})
+	}
+
+	/// Draws the screens that need to be drawn (see
+	/// [`Self::get_drawn_screens`]) on a terminal, of any backend, timing each
+	/// for the [render stats](render_stats). Returns the frame drawn, if there
+	/// were screens to draw.
+	pub fn draw<'a, B: Backend>(
+		&mut self,
+		terminal: &'a mut ratatui::Terminal<B>,
+	) -> anyhow::Result<Option<CompletedFrame<'a>>> {
+		let screen_count = self.screens.len();
+		let drawn_screens = self.get_drawn_screens();
+		let Some(active_screen_index) = drawn_screens.len().checked_sub(1) else {
+			return Ok(None);
+		};
+		// The screens drawn are the top of the stack.
+		let first_drawn = screen_count - drawn_screens.len();
+		let mut panicked = None;
+		let frame = terminal.draw(|frame| {
+			for (index, drawn_screen) in drawn_screens.into_iter().enumerate() {
+				let started = Instant::now();
+				let rendered = panics::catch(drawn_screen.state.title, || {
+					drawn_screen.screen.render(
+						frame,
+						&mut drawn_screen.state,
+						index == active_screen_index,
+					);
+				});
+				if let Err(panic) = rendered {
+					panicked = Some((first_drawn + index, panic));
+					break;
+				}
+				let state = &drawn_screen.state;
+				render_stats::record(state.title, started.elapsed(), state.frame_budget);
+			}
+		})?;
+		if let Some((index, panic)) = panicked {
+			self.close_panicked_screen(index, panic);
+		}
+		Ok(Some(frame))
+	}
+
+	/// Handles an event a screen sent.
+	fn handle_screen_event(&mut self, event: ScreenEvent) {
+		match event {
+			ScreenEvent::Create(screen) => self.spawn_screen(*screen),
+			ScreenEvent::Copy(text) => self.copies.push(text),
+		}
+	}
+
+	/// Takes the text screens asked to [copy](ScreenEvent::Copy) since the
+	/// last call.
+	pub fn take_copies(&mut self) -> Vec<String> {
+		std::mem::take(&mut self.copies)
+	}
+
+	/// Closes the active screen if it was closed, and handles the [events it
+	/// sent](ScreenState::screen_events) if any, also returning whether the
+	/// screen stack changed.
+	pub fn handle_active_screen(&mut self) -> anyhow::Result<bool> {
+		let Some(active_screen) = self.get_mut_active_screen() else {
+			return Ok(false);
+		};
+		let screen_events = std::mem::take(&mut active_screen.state.screen_events);
+		let closed = active_screen.state.open_status == OpenStatus::Closed;
+
+		if closed {
+			self.close_active_screen()?;
+		}
+		let changed = closed || !screen_events.is_empty();
+		for event in screen_events {
+			self.handle_screen_event(event);
+		}
+		Ok(changed)
 	}
 }
 
 /// Core struct to all inner workings in Terminal Arcade.
 /// This struct mostly handles rendering that and managing screens.
+#[cfg(not(target_arch = "wasm32"))]
 #[must_use]
 #[derive(new)]
+#[allow(clippy::struct_field_names)]
 pub struct Handler {
 	/// Terminal managed by Terminal Arcade.
 	terminal: Terminal,
 
 	/// Handler for screens.
 	screen_handler: ScreenHandler,
+
+	/// Control socket that other programs send commands to, if enabled.
+	#[cfg(unix)]
+	#[new(default)]
+	ipc_server: Option<IpcServer>,
+
+	/// Input played back as if typed on the keyboard, if any.
+	#[new(default)]
+	script: Option<Script>,
+
+	/// Timer for autosaving games in progress.
+	#[new(default)]
+	autosave_timer: AutosaveTimer,
+
+	/// Events read from the terminal, played one per frame.
+	#[new(default)]
+	input: InputPipeline,
+
+	/// The last events played, saved in bug reports and replayed by the
+	/// developer tools.
+	#[new(default)]
+	event_history: EventHistory,
+
+	/// Whether developer tools, like the [event replay
+	/// screen](EventReplayScreen), are enabled.
+	#[new(default)]
+	dev_tools: bool,
+
+	/// When the last frame started, once one did.
+	#[new(default)]
+	last_frame: Option<Instant>,
+
+	/// Decides which frames are drawn.
+	#[new(default)]
+	frame_pacer: FramePacer,
+
+	/// Recording of the session, if one is going on.
+	#[new(default)]
+	recording: Option<Recording>,
+
+	/// Notices when frames stop being drawn.
+	#[new(default)]
+	watchdog: Watchdog,
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 impl Default for Handler {
 	fn default() -> Self {
-		Self {
-			terminal: Terminal::new(CrosstermBackend::new(stdout()))
+		Self::new(
+			Terminal::new(CrosstermBackend::new(stdout()))
 				.expect("Failed to create a terminal from crossterm and stdout"),
-			screen_handler: ScreenHandler::default(),
-		}
+			ScreenHandler::default(),
+		)
 	}
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 impl Handler {
+	/// Plays back a [script](Script) while running.
+	pub fn with_script(mut self, script: Script) -> Self {
+		self.script = Some(script);
+		self
+	}
+
+	/// Enables developer tools, like the [event replay
+	/// screen](EventReplayScreen) opened with \[F11\].
+	pub fn with_dev_tools(mut self) -> Self {
+		self.dev_tools = true;
+		render_stats::show_notices(true);
+		self
+	}
+
+	/// Returns the next event, from the script being played if it has one
+	/// ready, or else from the terminal. Every event waiting in the terminal
+	/// is read at once, within a frame, and goes through the [input
+	/// pipeline](InputPipeline).
+	fn next_event(&mut self) -> anyhow::Result<Option<Event>> {
+		if let Some(event) = self.script.as_mut().and_then(Script::poll) {
+			return Ok(Some(event));
+		}
+		if self.input.is_empty() && poll(render_stats::FRAME_BUDGET)? {
+			let mut events = vec![read()?];
+			while poll(Duration::ZERO)? {
+				events.push(read()?);
+			}
+			self.input.push(events);
+		}
+		Ok(self.input.next_event())
+	}
+
+	/// Answers commands sent to an [IPC server](IpcServer) while running.
+	#[cfg(unix)]
+	pub fn with_ipc_server(mut self, server: IpcServer) -> Self {
+		self.ipc_server = Some(server);
+		self
+	}
+
+	/// Answers the commands sent to the IPC server since the last frame, also
+	/// returning whether Terminal Arcade was quit.
+	#[cfg(unix)]
+	fn handle_ipc_requests(&mut self) -> anyhow::Result<bool> {
+		let Some(server) = &self.ipc_server else {
+			return Ok(false);
+		};
+		for request in server.poll() {
+			let response = match &request.command {
+				IpcCommand::State => IpcResponse::Ok(self.ipc_state()),
+				IpcCommand::Launch { game } => self.launch_game(game),
+				IpcCommand::CloseScreen => {
+					if let Some(screen) = self.screen_handler.get_mut_active_screen() {
+						screen.state.open_status = OpenStatus::Closed;
+					}
+					IpcResponse::Ok(Value::Null)
+				},
+				IpcCommand::Quit => {
+					request.reply(IpcResponse::Ok(Value::Null));
+					self.quit()?;
+					return Ok(true);
+				},
+			};
+			request.reply(response);
+		}
+		self.handle_active_screen()
+	}
+
+	/// Returns the state reported to the IPC server's clients: the titles of
+	/// the screens open, the active one last, and each game's statistics.
+	#[cfg(unix)]
+	fn ipc_state(&self) -> Value {
+		let screens = self.screen_handler.titles();
+		let games: Vec<_> = Games::all()
+			.into_iter()
+			.map(|game| {
+				let metadata = game.data().metadata;
+				json!({
+					"id": metadata.static_info.id,
+					"name": metadata.static_info.name,
+					"play_count": metadata.dynamic_info.play_count,
+					"last_played": metadata.dynamic_info.last_played,
+					"rating": metadata.dynamic_info.rating,
+				})
+			})
+			.collect();
+		json!({
+			"screen": screens.last(),
+			"screens": screens,
+			"games": games,
+		})
+	}
+
+	/// Opens a game on top of the active screen, given its ID or name.
+	#[cfg(unix)]
+	fn launch_game(&mut self, name: &str) -> IpcResponse {
+		let game = registry::get(&GameId::from(name)).or_else(|| {
+			Games::all().into_iter().find(|game| {
+				game.data().metadata.static_info.name.eq_ignore_ascii_case(name.trim())
+			})
+		});
+		let Some(screen) = game.and_then(|game| game.data().created_screen) else {
+			return IpcResponse::Error(format!("There is no game named {name}"));
+		};
+		if let Some(active_screen) = self.screen_handler.get_mut_active_screen() {
+			active_screen.state.create_screen(screen);
+		}
+		IpcResponse::Ok(Value::Null)
+	}
+
 	/// Registers this handler's terminal reset method to the panic hook.
 	/// ([`Self::unset_global_terminal_rules`])
 	fn set_panic_hook() {
@@ -166,13 +590,22 @@ impl Handler {
 		set_hook(Box::new(move |panic_info| {
 			let _ = { Self::unset_global_terminal_rules() };
 			original_hook(panic_info);
-			println!("Sorry, something happened! 🫤\nIf you believe this was a bug, please send an issue to https://github.com/developer-ramen/terminal-arcade to get it squashed as soon as possible!");
+			println!("{}", t!("panic-message"));
 		}));
+		// Screens' panics are caught, and the session goes on.
+		panics::set_hook();
 	}
 
-	/// Runs the event loop, also returning whether the loop should break.
-	fn event_loop(&mut self, event: &Event) -> anyhow::Result<bool> {
-		Ok(self.handle_terminal_event(event)? || self.handle_active_screen()?)
+	/// Runs the event loop, also returning whether the loop should break. The
+	/// active screen is handled even without an event, since screens may close
+	/// themselves or create screens while [updating](Screen::update).
+	fn event_loop(&mut self, event: Option<&Event>) -> anyhow::Result<bool> {
+		if let Some(event) = event {
+			if self.handle_terminal_event(event)? {
+				return Ok(true);
+			}
+		}
+		self.handle_active_screen()
 	}
 
 	/// The function to be called when Terminal Arcade is done starting and
@@ -182,22 +615,87 @@ impl Handler {
 	/// shortcuts), are passed to the last screen (which is the only active
 	/// screen anyways, see the struct documentation for more information).
 	fn run(&mut self) -> anyhow::Result<()> {
-		let sixty_fps_in_ms = 16;
 		loop {
-			self.draw_screen_ui()?;
-			let poll_status = poll(Duration::from_millis(sixty_fps_in_ms))?;
-			if poll_status && self.event_loop(&read()?)? {
+			for conflict in sync::take_conflicts() {
+				self.screen_handler.spawn_screen(SyncConflictPopup::new(conflict).into());
+				self.frame_pacer.invalidate();
+			}
+			if self.autosave_timer.is_due() {
+				self.screen_handler.autosave();
+				// Failures are left for the next save to make up for.
+				let _ = stats::save_today();
+			}
+			self.tick_today();
+			self.screen_handler.update_active_screen();
+			if self.watchdog.take_stall() {
+				self.reinitialize_terminal()?;
+			}
+			self.draw_paced()?;
+			let event = self.next_event()?;
+			if event.is_some() {
+				self.frame_pacer.invalidate();
+			}
+			if self.event_loop(event.as_ref())? {
+				break;
+			}
+			#[cfg(unix)]
+			if self.handle_ipc_requests()? {
 				break;
 			}
 		}
 		Ok(())
 	}
 
+	/// Sets the global terminal rules again and redraws the whole screen
+	/// after the [watchdog](Watchdog) noticed drawing stalled, in case the
+	/// terminal lost them meanwhile. This only happens once the stalled write
+	/// returns and the loop goes on: a terminal that stays wedged is never
+	/// recovered.
+	fn reinitialize_terminal(&mut self) -> anyhow::Result<()> {
+		Self::set_global_terminal_rules()?;
+		self.terminal.clear()?;
+		self.frame_pacer.invalidate();
+		Ok(())
+	}
+
+	/// Draws the screens, unless the [frame pacer](FramePacer) skips the
+	/// frame for waiting input or to let the terminal catch up.
+	fn draw_paced(&mut self) -> anyhow::Result<()> {
+		let started = Instant::now();
+		let input_waiting = !self.input.is_empty();
+		if let Some(skip) =
+			self.frame_pacer.skip(started, input_waiting, self.screen_handler.is_static())
+		{
+			render_stats::record_skipped_frame(skip);
+			return Ok(());
+		}
+		self.draw_screen_ui()?;
+		self.frame_pacer.drawn(started, started.elapsed());
+		self.watchdog.beat();
+		Ok(())
+	}
+
+	/// Counts the time since the last frame towards [today's
+	/// playtime](stats::tick_today) if a game was being played.
+	fn tick_today(&mut self) {
+		let now = Instant::now();
+		if let Some(last_frame) = self.last_frame.replace(now) {
+			let playing = self
+				.screen_handler
+				.get_mut_active_screen()
+				.is_some_and(|screen| screen.is_playing());
+			stats::tick_today(now - last_frame, playing);
+		}
+	}
+
 	/// The function to be called when Terminal Arcade is being quitted.
 	fn quit(&mut self) -> anyhow::Result<()> {
-		while !self.screen_handler.is_empty() {
-			self.screen_handler.close_active_screen()?;
+		self.watchdog.stop();
+		self.screen_handler.close_all_screens()?;
+		if let Some(recording) = self.recording.take() {
+			recording.stop();
 		}
+		stats::save_today()?;
 		Self::unset_global_terminal_rules()?;
 		Ok(())
 	}
@@ -208,7 +706,7 @@ impl Handler {
 		Ok(execute!(
 			stdout(),
 			DisableBracketedPaste,
-			DisableFocusChange,
+			EnableFocusChange,
 			DisableBlinking,
 			EnterAlternateScreen,
 			Hide,
@@ -223,39 +721,39 @@ impl Handler {
 		Ok(execute!(
 			stdout(),
 			EnableBracketedPaste,
-			EnableFocusChange,
+			DisableFocusChange,
 			EnableBlinking,
 			LeaveAlternateScreen,
 			Show,
 		)?)
 	}
 
-	/// Checks for whether a key event matches the quit controls.
-	#[must_use]
-	fn check_quit_controls(key: &KeyEvent) -> bool {
-		let quit_controls = [
-			KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL),
-			KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL),
-			KeyEvent::new(KeyCode::F(4), KeyModifiers::ALT),
-		];
-		quit_controls.contains(key)
-	}
-
 	/// Draws the UI. This function draws not only the topmost ("active") screen
 	/// but also the parenting screens if the child(ren) screen is not of
 	/// [`ScreenKind::Normal`] variant.
 	fn draw_screen_ui(&mut self) -> anyhow::Result<()> {
-		let drawn_screens = self.screen_handler.get_drawn_screens();
-		let active_screen_index = drawn_screens.len() - 1;
-		self.terminal.draw(|frame| {
-			for (index, drawn_screen) in drawn_screens.into_iter().enumerate() {
-				drawn_screen.screen.render(
-					frame,
-					&mut drawn_screen.state,
-					index == active_screen_index,
-				);
+		let frame = self.screen_handler.draw(&mut self.terminal)?;
+		if let (Some(recording), Some(frame)) = (&mut self.recording, frame) {
+			if let Err(error) = recording.record(frame.buffer) {
+				if let Some(recording) = self.recording.take() {
+					recording.abandon(&error);
+				}
 			}
-		})?;
+		}
+		Ok(())
+	}
+
+	/// Starts [recording](Recording) the session, or stops the recording
+	/// going on.
+	fn toggle_recording(&mut self) -> anyhow::Result<()> {
+		if let Some(recording) = self.recording.take() {
+			recording.stop();
+		} else {
+			let size = self.terminal.size()?;
+			// Failures are shown by the recording indicator.
+			self.recording = Recording::start(size.width, size.height).ok();
+		}
+		self.frame_pacer.invalidate();
 		Ok(())
 	}
 
@@ -276,6 +774,17 @@ impl Handler {
 		Self::set_panic_hook();
 		Self::set_global_terminal_rules()?;
 		self.screen_handler.spawn_screen(WelcomeScreen::default().into());
+		#[cfg(feature = "encryption")]
+		if crate::core::encryption::is_locked() {
+			self.screen_handler
+				.spawn_screen(PassphraseScreen::new(PassphrasePurpose::Unlock).into());
+		}
+		if let Some(migration) = Migration::pending() {
+			self.screen_handler.spawn_screen(MigrationScreen::new(migration).into());
+		}
+		#[cfg(feature = "images")]
+		self.screen_handler.spawn_screen(crate::ui::SplashScreen::default().into());
+		self.watchdog.start();
 		self.run()?;
 		Ok(())
 	}
@@ -283,42 +792,142 @@ impl Handler {
 	/// Handles the information provided by the active screen,
 	/// also returning if the event loop calling this function should quit.
 	fn handle_active_screen(&mut self) -> anyhow::Result<bool> {
+		for panic in self.screen_handler.take_panics() {
+			self.report_panic(&panic);
+			self.frame_pacer.invalidate();
+		}
+		for report in error::take_pending() {
+			self.screen_handler.spawn_screen(ErrorPopup::new(report).into());
+			self.frame_pacer.invalidate();
+		}
 		if self.quit_when_no_screens()? {
 			return Ok(true);
 		}
 
-		let active_screen = self.screen_handler.get_mut_active_screen().unwrap();
-		let created_screen = active_screen.state.screen_created.take();
-
-		if active_screen.state.open_status == OpenStatus::Closed {
-			self.screen_handler.close_active_screen()?;
+		if self.screen_handler.handle_active_screen()? {
+			stats::refresh_today();
+			self.frame_pacer.invalidate();
+			#[cfg(feature = "images")]
+			crate::ui::widgets::image::clear_images(&mut self.terminal)?;
 		}
-		if let Some(screen) = created_screen {
-			self.screen_handler.spawn_screen(screen);
+		for text in self.screen_handler.take_copies() {
+			clipboard::copy(self.terminal.backend_mut(), &text)?;
 		}
 
 		self.quit_when_no_screens()
 	}
 
-	/// Handles an event read from the terminal.
+	/// Logs an error the active screen ran into and opens a popup telling what
+	/// went wrong and how to recover, rather than quitting over it.
+	fn show_error(&mut self, error: &anyhow::Error) {
+		let report = ErrorReport::new(error);
+		// The popup still tells about the error if it couldn't be logged.
+		let _ = error::log(&report);
+		self.screen_handler.spawn_screen(ErrorPopup::new(report).into());
+	}
+
+	/// Saves a [bug report](bug_report) about a screen that panicked and was
+	/// closed, and opens a popup telling where it was saved, so the session
+	/// can go on.
+	fn report_panic(&mut self, panic: &ScreenPanic) {
+		let report = bug_report::create(
+			&self.event_history,
+			&self.screen_handler.titles(),
+			Some(panic),
+		);
+		let error = AppError::ScreenPanicked {
+			screen: panic.screen,
+			message: panic.message.clone(),
+			report: report.ok(),
+		};
+		self.show_error(&error.into());
+	}
+
+	/// Saves a [bug report](bug_report) and opens a popup telling where it
+	/// was saved and where to report the bug.
+	fn report_bug(&mut self) {
+		let message =
+			match bug_report::create(&self.event_history, &self.screen_handler.titles(), None) {
+				Ok(path) => t!("bug-report-saved", path = path.display().to_string()),
+				Err(error) => t!("bug-report-failed", error = error.to_string()),
+			};
+		self.screen_handler.spawn_screen(
+			QrCodePopup::new(t!("about-report-bug"), BUG_REPORT_URL.to_string())
+				.with_message(message)
+				.into(),
+		);
+	}
+
+	/// Draws the screens in memory, at the size of the terminal, and saves
+	/// the frame as a [screenshot](screenshot).
+	fn take_screenshot(&mut self) -> anyhow::Result<()> {
+		let size = self.terminal.size()?;
+		let mut terminal = ratatui::Terminal::new(TestBackend::new(size.width, size.height))?;
+		self.screen_handler.draw(&mut terminal)?;
+		screenshot::take(terminal.backend().buffer());
+		self.frame_pacer.invalidate();
+		Ok(())
+	}
+
+	/// Handles an event read from the terminal, first as a
+	/// [global action](ActionContext::Global) if it's bound to one,
 	/// also returning if the event loop calling this function should quit.
 	fn handle_terminal_event(&mut self, event: &Event) -> anyhow::Result<bool> {
-		match event {
-			Event::Key(ref key) if Self::check_quit_controls(key) => {
+		match actions::resolve(event, ActionContext::Global) {
+			Some(Action::Quit) => {
+				if !actions::confirm_quit(event) {
+					self.frame_pacer.invalidate();
+					return Ok(false);
+				}
 				self.quit()?;
 				return Ok(true);
 			},
-			Event::Resize(..) => {
-				self.draw_screen_ui()?;
+			Some(Action::ReportBug) => {
+				self.report_bug();
+				return Ok(false);
+			},
+			Some(Action::Screenshot) => {
+				self.take_screenshot()?;
+				return Ok(false);
+			},
+			Some(Action::RecordSession) => {
+				self.toggle_recording()?;
+				return Ok(false);
+			},
+			Some(Action::RecordMacro) => {
+				if macros::is_recording() {
+					macros::stop_recording();
+				} else {
+					macros::start_recording();
+				}
+				return Ok(false);
+			},
+			Some(Action::PlayMacro(number)) => {
+				if let Some(script) = macros::play(number.saturating_sub(1)) {
+					self.script = Some(script);
+				}
+				return Ok(false);
+			},
+			Some(Action::DevTools) if self.dev_tools => {
+				let size = self.terminal.size()?;
+				self.screen_handler.spawn_screen(
+					EventReplayScreen::new(&self.event_history, size.width, size.height).into(),
+				);
+				return Ok(false);
 			},
 			_ => {},
 		}
-		Ok(match self.screen_handler.get_mut_active_screen() {
-			Some(screen) => {
-				screen.screen.event(event, &mut screen.state)?;
-				false
-			},
-			None => true,
-		})
+		if let Event::Resize(..) = event {
+			self.draw_screen_ui()?;
+		}
+		if self.screen_handler.is_empty() {
+			return Ok(true);
+		}
+		self.event_history.record(event);
+		macros::record(event);
+		if let Err(error) = self.screen_handler.send_event(event) {
+			self.show_error(&error);
+		}
+		Ok(false)
 	}
 }
@@ -0,0 +1,331 @@
+//! A headless harness running screens against ratatui's [`TestBackend`], for
+//! integration tests of screens and games, or against any other
+//! [backend](HarnessBackend) for frontends driving the arcade with their own
+//! events, like a terminal emulated in a browser.
+//!
+//! The harness manages a stack of screens just like the [handler](Handler)
+//! does, but never touches the real terminal: events are injected through a
+//! channel instead of read from the keyboard, and frames are drawn in memory,
+//! where they can be asserted on.
+//!
+//! ```ignore
+//! let mut harness = Harness::new(WelcomeScreen::default().into(), 100, 40);
+//! harness.press(KeyCode::Down);
+//! harness.press(KeyCode::Enter);
+//! assert_eq!(harness.active_title(), Some("Search for a game!"));
+//! assert!(harness.contains("Minesweeper"));
+//! ```
+//!
+//! Screens can also be rendered into text [snapshots](render_snapshot), to be
+//! compared with [insta](https://insta.rs)'s `assert_snapshot!`.
+//!
+//! [Handler]: crate::core::Handler
+
+use std::sync::mpsc::{
+	channel,
+	Receiver,
+	Sender,
+};
+
+use ratatui::{
+	backend::{
+		Backend,
+		TestBackend,
+	},
+	buffer::Buffer,
+	Terminal,
+};
+
+use crate::{
+	core::{
+		events::{
+			Event,
+			KeyCode,
+			KeyEvent,
+			KeyModifiers,
+		},
+		handler::ScreenHandler,
+		screenshot,
+	},
+	ui::screens::Screens,
+};
+
+/// A backend the [harness](Harness) draws on.
+pub trait HarnessBackend: Backend {
+	/// Resizes the backend when a resize event is played. Backends sized by
+	/// what they draw on, like a browser's terminal, keep their size and are
+	/// resized when drawn.
+	fn resize(&mut self, _width: u16, _height: u16) {}
+}
+
+impl HarnessBackend for TestBackend {
+	fn resize(&mut self, width: u16, height: u16) {
+		TestBackend::resize(self, width, height);
+	}
+}
+
+/// See the [module](self) documentation for more information.
+#[must_use]
+pub struct Harness<B: HarnessBackend = TestBackend> {
+	/// The terminal frames are drawn on.
+	terminal: Terminal<B>,
+
+	/// Handler for the screens run.
+	screen_handler: ScreenHandler,
+
+	/// Sends events to the screens, played one per frame.
+	events: Sender<Event>,
+
+	/// Events waiting to be played.
+	pending_events: Receiver<Event>,
+}
+
+impl Harness {
+	/// Runs a screen on an in-memory terminal of a size, drawing its first
+	/// frame.
+	///
+	/// # Panics
+	///
+	/// This function panics if the first frame can't be drawn.
+	pub fn new(screen: Screens, width: u16, height: u16) -> Self {
+		let terminal = Terminal::new(TestBackend::new(width, height))
+			.expect("Failed to create a terminal with a test backend");
+		Self::with_terminal(screen, terminal).expect("Failed to draw the first frame")
+	}
+
+	/// Returns the last frame drawn.
+	#[must_use]
+	pub fn buffer(&self) -> &Buffer {
+		self.terminal.backend().buffer()
+	}
+
+	/// Returns the text of the last frame drawn, line by line, without trailing
+	/// spaces. Styles are left out.
+	#[must_use]
+	pub fn lines(&self) -> Vec<String> {
+		screenshot::buffer_lines(self.buffer())
+	}
+
+	/// Returns the text of the last frame drawn. See [`Self::lines`].
+	#[must_use]
+	pub fn text(&self) -> String {
+		self.lines().join("\n")
+	}
+
+	/// Returns whether the last frame drawn shows some text on a single line.
+	#[must_use]
+	pub fn contains(&self, text: &str) -> bool {
+		self.lines().iter().any(|line| line.contains(text))
+	}
+}
+
+impl<B: HarnessBackend> Harness<B> {
+	/// Runs a screen on a terminal, drawing its first frame.
+	pub fn with_terminal(screen: Screens, terminal: Terminal<B>) -> anyhow::Result<Self> {
+		let mut screen_handler = ScreenHandler::default();
+		screen_handler.spawn_screen(screen);
+		let (events, pending_events) = channel();
+		let mut harness = Self {
+			terminal,
+			screen_handler,
+			events,
+			pending_events,
+		};
+		harness.draw()?;
+		Ok(harness)
+	}
+
+	/// Returns the terminal frames are drawn on.
+	#[must_use]
+	pub fn terminal(&self) -> &Terminal<B> {
+		&self.terminal
+	}
+
+	/// Returns the terminal frames are drawn on, to take what was drawn from
+	/// its backend.
+	#[must_use]
+	pub fn terminal_mut(&mut self) -> &mut Terminal<B> {
+		&mut self.terminal
+	}
+
+	/// Returns a sender of events, which are played in order once the harness
+	/// [runs](Self::run_until_idle).
+	#[must_use]
+	pub fn event_sender(&self) -> Sender<Event> {
+		self.events.clone()
+	}
+
+	/// Queues an event, played once the harness [runs](Self::run_until_idle).
+	pub fn send(&self, event: Event) {
+		self.events.send(event).expect("The harness owns its receiver");
+	}
+
+	/// Runs one frame like the handler does: updates the active screen, plays
+	/// an event if one is queued, then handles the screens closed and created
+	/// and draws the result. Returns whether an event was played.
+	pub fn tick(&mut self) -> anyhow::Result<bool> {
+		self.screen_handler.update_active_screen();
+		let event = self.pending_events.try_recv().ok();
+		if let Some(event) = &event {
+			if let Event::Resize(width, height) = event {
+				HarnessBackend::resize(self.terminal.backend_mut(), *width, *height);
+			}
+			self.screen_handler.send_event(event)?;
+		}
+		self.screen_handler.handle_active_screen()?;
+		self.draw()?;
+		// The handler reports panics and goes on, but tests should fail.
+		if let Some(panic) = self.screen_handler.take_panics().pop() {
+			anyhow::bail!("{} panicked: {}", panic.screen, panic.message);
+		}
+		Ok(event.is_some())
+	}
+
+	/// Runs frames until every queued event was played.
+	///
+	/// # Panics
+	///
+	/// This function panics if a screen fails to handle an event.
+	pub fn run_until_idle(&mut self) {
+		self.try_run_until_idle().expect("Failed to run a frame");
+	}
+
+	/// Runs frames until every queued event was played, or until a screen
+	/// fails to handle one.
+	pub fn try_run_until_idle(&mut self) -> anyhow::Result<()> {
+		while self.tick()? {}
+		Ok(())
+	}
+
+	/// Runs a number of frames, letting animations like
+	/// [typewriters](crate::ui::widgets::typewriter) play out.
+	pub fn run_frames(&mut self, count: usize) {
+		for _ in 0..count {
+			self.tick().expect("Failed to run a frame");
+		}
+	}
+
+	/// Presses a key, then [runs](Self::run_until_idle) the harness.
+	pub fn press(&mut self, code: KeyCode) {
+		self.press_with(code, KeyModifiers::NONE);
+	}
+
+	/// Presses a key with modifiers, then [runs](Self::run_until_idle) the
+	/// harness.
+	pub fn press_with(&mut self, code: KeyCode, modifiers: KeyModifiers) {
+		self.send(Event::Key(KeyEvent::new(code, modifiers)));
+		self.run_until_idle();
+	}
+
+	/// Types every character of a text, then [runs](Self::run_until_idle) the
+	/// harness.
+	pub fn type_text(&mut self, text: &str) {
+		for char in text.chars() {
+			self.send(Event::Key(KeyEvent::new(
+				KeyCode::Char(char),
+				KeyModifiers::NONE,
+			)));
+		}
+		self.run_until_idle();
+	}
+
+	/// Resizes the terminal, then [runs](Self::run_until_idle) the harness.
+	pub fn resize(&mut self, width: u16, height: u16) {
+		self.send(Event::Resize(width, height));
+		self.run_until_idle();
+	}
+
+	/// Draws a frame of the screens.
+	fn draw(&mut self) -> anyhow::Result<()> {
+		self.screen_handler.draw(&mut self.terminal)?;
+		Ok(())
+	}
+
+	/// Returns the titles of the screens open, the active one last.
+	#[must_use]
+	pub fn titles(&self) -> Vec<&'static str> {
+		self.screen_handler.titles()
+	}
+
+	/// Returns the title of the active screen, if there is one.
+	#[must_use]
+	pub fn active_title(&self) -> Option<&'static str> {
+		self.titles().last().copied()
+	}
+
+	/// Returns whether every screen was closed.
+	#[must_use]
+	pub fn is_closed(&self) -> bool {
+		self.screen_handler.is_empty()
+	}
+}
+
+/// Renders a screen's first frame on a terminal of a size, as text without
+/// styles (see [`Harness::lines`]). Screens rendering the time, the git branch
+/// or anything else that changes between runs need insta's filters, and
+/// tests should [isolate their files](crate::core::files::isolate_test_files)
+/// to render with the default configuration.
+#[must_use]
+pub fn render_snapshot(screen: Screens, width: u16, height: u16) -> String {
+	Harness::new(screen, width, height).text()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Harness;
+	use crate::{
+		core::events::{
+			KeyCode,
+			KeyModifiers,
+		},
+		ui::{
+			AboutScreen,
+			WelcomeScreen,
+		},
+	};
+
+	#[test]
+	fn welcome_screen_lists_its_options() {
+		let harness = Harness::new(WelcomeScreen::default().into(), 100, 50);
+		assert_eq!(harness.active_title(), Some("Terminal Arcade"));
+		assert!(harness.contains("Hop into a game and play!"));
+		assert!(harness.contains("Quit the application..."));
+	}
+
+	#[test]
+	#[cfg(feature = "minesweeper")]
+	fn enter_opens_the_game_search() {
+		let mut harness = Harness::new(WelcomeScreen::default().into(), 100, 50);
+		harness.press(KeyCode::Down);
+		harness.press(KeyCode::Enter);
+		assert_eq!(harness.titles(), ["Terminal Arcade", "Search for a game!"]);
+		assert!(harness.contains("Minesweeper"));
+	}
+
+	#[test]
+	fn escape_closes_the_active_screen() {
+		let mut harness = Harness::new(WelcomeScreen::default().into(), 100, 50);
+		harness.press(KeyCode::Down);
+		harness.press(KeyCode::Enter);
+		harness.press(KeyCode::Esc);
+		assert_eq!(harness.active_title(), Some("Terminal Arcade"));
+		harness.press(KeyCode::Esc);
+		assert!(harness.is_closed());
+	}
+
+	#[test]
+	fn controls_popup_opens_over_the_screen() {
+		let mut harness = Harness::new(AboutScreen::default().into(), 100, 50);
+		harness.press_with(KeyCode::Char('h'), KeyModifiers::CONTROL);
+		assert_eq!(harness.titles().len(), 2);
+		assert!(harness.contains("Closes this screen"));
+	}
+
+	#[test]
+	fn resizing_redraws_at_the_new_size() {
+		let mut harness = Harness::new(WelcomeScreen::default().into(), 100, 50);
+		harness.resize(60, 30);
+		assert_eq!(harness.lines().len(), 30);
+		assert!(harness.contains("Hop into a game and play!"));
+	}
+}
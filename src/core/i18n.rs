@@ -0,0 +1,148 @@
+//! Translations of Terminal Arcade's user-facing text, written in
+//! [Fluent](https://projectfluent.org) files in `assets/locales`.
+//!
+//! Text is looked up by its message ID with the [`t!`](crate::t) macro. The
+//! locale is [picked once](init) on startup, from the `language` setting of
+//! the [configuration](crate::core::config::Config) or from the `LC_ALL`,
+//! `LC_MESSAGES` and `LANG` environment variables. Messages missing from a
+//! translation fall back to English.
+//!
+//! To add a translation, copy `assets/locales/en.ftl` next to it as
+//! `<language>.ftl`, translate its messages and add it to [`LOCALES`].
+
+use std::{
+	collections::HashMap,
+	env,
+	sync::{
+		LazyLock,
+		Mutex,
+		OnceLock,
+	},
+};
+
+/// Arguments of a translated message, re-exported for [`t!`](crate::t).
+pub use fluent_bundle::FluentArgs;
+use fluent_bundle::{
+	concurrent::FluentBundle,
+	FluentResource,
+};
+use unic_langid::LanguageIdentifier;
+
+/// Locale used when no other one is picked, and for messages missing from a
+/// translation.
+pub const FALLBACK_LOCALE: &str = "en";
+
+/// Locales Terminal Arcade is translated into, with their Fluent files.
+pub const LOCALES: &[(&str, &str)] = &[
+	("en", include_str!("../../assets/locales/en.ftl")),
+	("fr", include_str!("../../assets/locales/fr.ftl")),
+];
+
+/// Environment variables the locale is read from, by priority.
+const LOCALE_VARIABLES: [&str; 3] = ["LC_ALL", "LC_MESSAGES", "LANG"];
+
+/// The locale picked on startup.
+static LOCALE: OnceLock<&'static str> = OnceLock::new();
+
+/// Bundles of messages of every locale.
+static BUNDLES: LazyLock<Vec<(&'static str, FluentBundle<FluentResource>)>> = LazyLock::new(|| {
+	LOCALES
+		.iter()
+		.map(|&(locale, source)| {
+			let id = locale.parse().expect("Locales have valid identifiers");
+			let mut bundle = FluentBundle::new_concurrent(vec![id]);
+			// Unicode isolation marks show up as garbage in some terminals.
+			bundle.set_use_isolating(false);
+			let resource = FluentResource::try_new(source.to_string())
+				.expect("Translations are valid Fluent files");
+			bundle.add_resource(resource).expect("Translations have unique message IDs");
+			(locale, bundle)
+		})
+		.collect()
+});
+
+/// Messages already translated by [`translate`], kept for the rest of the run
+/// so that they can be handed out as `&'static str`.
+static TRANSLATED: LazyLock<Mutex<HashMap<&'static str, &'static str>>> =
+	LazyLock::new(Mutex::default);
+
+/// Returns the supported locale matching a language tag, like `fr`, `fr-CA`
+/// or `fr_CA.UTF-8`.
+#[must_use]
+pub fn match_locale(tag: &str) -> Option<&'static str> {
+	let tag = tag.split(['.', '@']).next().unwrap_or_default().replace('_', "-");
+	let id = tag.parse::<LanguageIdentifier>().ok()?;
+	LOCALES.iter().map(|&(locale, _)| locale).find(|locale| *locale == id.language.as_str())
+}
+
+/// Picks the locale for the rest of the run: the configured language if it's
+/// supported, then the one of the environment, then [English](FALLBACK_LOCALE).
+/// Has no effect once a locale was picked, so it should be called before
+/// anything is translated.
+pub fn init(configured: Option<&str>) -> &'static str {
+	LOCALE.get_or_init(|| {
+		configured
+			.and_then(match_locale)
+			.or_else(|| {
+				LOCALE_VARIABLES
+					.iter()
+					.filter_map(|variable| env::var(variable).ok())
+					.find(|value| !value.is_empty())
+					.and_then(|value| match_locale(&value))
+			})
+			.unwrap_or(FALLBACK_LOCALE)
+	})
+}
+
+/// Returns the locale picked, or [English](FALLBACK_LOCALE) if none was.
+#[must_use]
+pub fn locale() -> &'static str {
+	LOCALE.get().copied().unwrap_or(FALLBACK_LOCALE)
+}
+
+/// Formats a message in a locale, if the locale has it.
+fn format(locale: &str, id: &str, args: Option<&FluentArgs<'_>>) -> Option<String> {
+	let (_, bundle) = BUNDLES.iter().find(|(bundle_locale, _)| *bundle_locale == locale)?;
+	let pattern = bundle.get_message(id)?.value()?;
+	let mut errors = Vec::new();
+	Some(bundle.format_pattern(pattern, args, &mut errors).into_owned())
+}
+
+/// Formats a message in the [current locale](locale), falling back to English,
+/// then to the message's ID.
+#[must_use]
+pub fn translate_with_args(id: &str, args: Option<&FluentArgs<'_>>) -> String {
+	format(locale(), id, args)
+		.or_else(|| format(FALLBACK_LOCALE, id, args))
+		.unwrap_or_else(|| id.to_string())
+}
+
+/// Translates a message without arguments. See [`translate_with_args`].
+///
+/// # Panics
+///
+/// This function panics if the translations' cache was poisoned.
+#[must_use]
+pub fn translate(id: &'static str) -> &'static str {
+	let mut translated = TRANSLATED.lock().expect("The translations' cache was poisoned");
+	translated
+		.entry(id)
+		.or_insert_with(|| Box::leak(translate_with_args(id, None).into_boxed_str()))
+}
+
+/// Translates a message of the [Fluent files](self) by its ID.
+///
+/// Without arguments, returns a `&'static str`, usable anywhere a string
+/// literal is. With arguments, like `t!("tron-ping", ms = 42)`, returns a
+/// [`String`].
+#[macro_export]
+macro_rules! t {
+	($id:literal) => {
+		$crate::core::i18n::translate($id)
+	};
+	($id:literal, $($name:ident = $value:expr),+ $(,)?) => {{
+		let mut args = $crate::core::i18n::FluentArgs::new();
+		$(args.set(stringify!($name), $value);)+
+		$crate::core::i18n::translate_with_args($id, Some(&args))
+	}};
+}
@@ -0,0 +1,324 @@
+//! The input pipeline: events read from the terminal go through a series of
+//! [transformers](InputTransformer) before screens get them, so that new input
+//! features are added as a transformer rather than by patching the
+//! [handler](crate::core::handler).
+//!
+//! The handler reads every event waiting in the terminal once per frame, and
+//! [pushes](InputPipeline::push) them into an [`InputPipeline`], which plays
+//! them one per frame. Each transformer, in the order they were registered:
+//! 1. [Reads](InputTransformer::read) each event as it's pushed, letting it
+//!    through, dropping it or replacing it with other events.
+//! 2. [Plays](InputTransformer::play) each event when its turn comes, letting
+//!    it through or dropping it, for transformers depending on when events are
+//!    played.
+//!
+//! The [default](InputPipeline::new) pipeline registers:
+//! - [`KeyNormalizer`], so that held keys repeat and released keys are ignored
+//!   on every platform.
+//! - [`MovementCoalescer`], if [`Config::coalesce_inputs`] is on, so that an
+//!   arrow key repeated in a row within the events read together is only played
+//!   once.
+//! - [`RepeatLimiter`], if [`Config::key_repeat_limit_ms`] isn't 0, so that an
+//!   arrow key played again sooner than that after itself is dropped.
+//!
+//! Only arrow keys are coalesced, since letters may be typed twice in a row.
+
+use std::{
+	collections::VecDeque,
+	fmt::Debug,
+	time::Duration,
+};
+
+use crate::core::{
+	config::Config,
+	events::{
+		Event,
+		KeyCode,
+		KeyEvent,
+		KeyEventKind,
+	},
+	render_stats,
+	time::Instant,
+};
+
+/// Returns whether a key moves something, and may be coalesced.
+fn is_movement_key(key: &KeyEvent) -> bool {
+	matches!(
+		key.code,
+		KeyCode::Up | KeyCode::Down | KeyCode::Left | KeyCode::Right
+	)
+}
+
+/// Returns the movement key an event presses, if any.
+fn movement_key(event: &Event) -> Option<&KeyEvent> {
+	match event {
+		Event::Key(key) if is_movement_key(key) => Some(key),
+		_ => None,
+	}
+}
+
+/// A step of the [input pipeline](self). Both methods let events through by
+/// default.
+pub trait InputTransformer: Debug {
+	/// Transforms an event as it's read, given the event queued right before
+	/// it if it's still waiting, into the events to queue instead: none to
+	/// drop it, or others to replace it.
+	fn read(&mut self, event: Event, _previous: Option<&Event>) -> Vec<Event> {
+		vec![event]
+	}
+
+	/// Returns whether a queued event is played when its turn comes, rather
+	/// than dropped.
+	fn play(&mut self, _event: &Event) -> bool {
+		true
+	}
+}
+
+/// Plays auto-repeated keys as presses, and drops released keys, which only
+/// some platforms report.
+#[derive(Debug, Default)]
+pub struct KeyNormalizer;
+
+impl InputTransformer for KeyNormalizer {
+	fn read(&mut self, event: Event, _previous: Option<&Event>) -> Vec<Event> {
+		match event {
+			Event::Key(key) if key.kind == KeyEventKind::Release => Vec::new(),
+			Event::Key(key) => vec![Event::Key(KeyEvent {
+				kind: KeyEventKind::Press,
+				..key
+			})],
+			event => vec![event],
+		}
+	}
+}
+
+/// Drops a movement key read right after the same one, before either is
+/// played.
+#[derive(Debug, Default)]
+pub struct MovementCoalescer;
+
+impl InputTransformer for MovementCoalescer {
+	fn read(&mut self, event: Event, previous: Option<&Event>) -> Vec<Event> {
+		let repeated = movement_key(&event).is_some() && previous == Some(&event);
+		if repeated {
+			Vec::new()
+		} else {
+			vec![event]
+		}
+	}
+}
+
+/// Drops movement keys played again sooner than a limit after themselves.
+#[derive(Debug)]
+pub struct RepeatLimiter {
+	/// How soon a movement key can be played again after itself.
+	limit: Duration,
+
+	/// The last movement key played, and when.
+	last_movement: Option<(KeyEvent, Instant)>,
+}
+
+impl RepeatLimiter {
+	/// Creates a limiter letting movement keys repeat at most once per
+	/// `limit`.
+	#[must_use]
+	pub fn new(limit: Duration) -> Self {
+		Self {
+			limit,
+			last_movement: None,
+		}
+	}
+}
+
+impl InputTransformer for RepeatLimiter {
+	fn play(&mut self, event: &Event) -> bool {
+		let Some(key) = movement_key(event).copied() else {
+			return true;
+		};
+		let too_soon = self
+			.last_movement
+			.is_some_and(|(last_key, at)| last_key == key && at.elapsed() < self.limit);
+		if !too_soon {
+			self.last_movement = Some((key, Instant::now()));
+		}
+		!too_soon
+	}
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Debug)]
+pub struct InputPipeline {
+	/// Events read but not played yet, oldest first, and when they were
+	/// read.
+	pending: VecDeque<(Event, Instant)>,
+
+	/// The transformers events go through, in order.
+	transformers: Vec<Box<dyn InputTransformer>>,
+}
+
+impl Default for InputPipeline {
+	fn default() -> Self {
+		let config = Config::load_or_default();
+		Self::new(config.coalesce_inputs, config.key_repeat_limit_ms)
+	}
+}
+
+impl InputPipeline {
+	/// Creates a pipeline letting every event through as is.
+	#[must_use]
+	pub fn empty() -> Self {
+		Self {
+			pending: VecDeque::new(),
+			transformers: Vec::new(),
+		}
+	}
+
+	/// Creates the default pipeline, normalizing keys, merging repeated
+	/// movement keys or not, and limiting how often they're played, in
+	/// milliseconds, unless it's 0.
+	#[must_use]
+	pub fn new(coalesce: bool, repeat_limit_ms: u64) -> Self {
+		let mut pipeline = Self::empty().with(KeyNormalizer);
+		if coalesce {
+			pipeline = pipeline.with(MovementCoalescer);
+		}
+		if repeat_limit_ms > 0 {
+			pipeline = pipeline.with(RepeatLimiter::new(Duration::from_millis(repeat_limit_ms)));
+		}
+		pipeline
+	}
+
+	/// Registers a transformer, after the ones already registered.
+	#[must_use]
+	pub fn with(mut self, transformer: impl InputTransformer + 'static) -> Self {
+		self.transformers.push(Box::new(transformer));
+		self
+	}
+
+	/// Queues events read together, through every transformer.
+	pub fn push(&mut self, events: impl IntoIterator<Item = Event>) {
+		let read_at = Instant::now();
+		for event in events {
+			let mut transformed = vec![event];
+			for transformer in &mut self.transformers {
+				let previous = self.pending.back().map(|(event, _)| event);
+				transformed = transformed
+					.into_iter()
+					.flat_map(|event| transformer.read(event, previous))
+					.collect();
+			}
+			self.pending.extend(transformed.into_iter().map(|event| (event, read_at)));
+		}
+	}
+
+	/// Returns whether no event is waiting to be played.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.pending.is_empty()
+	}
+
+	/// Returns the next event to play, skipping the ones a transformer drops.
+	/// How long it waited, and how many events were waiting, are recorded in
+	/// the [render stats](render_stats).
+	pub fn next_event(&mut self) -> Option<Event> {
+		let waiting = self.pending.len();
+		while let Some((event, read_at)) = self.pending.pop_front() {
+			if self.transformers.iter_mut().all(|transformer| transformer.play(&event)) {
+				render_stats::record_input(read_at.elapsed(), waiting);
+				return Some(event);
+			}
+		}
+		None
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		InputPipeline,
+		InputTransformer,
+	};
+	use crate::core::events::{
+		Event,
+		KeyCode,
+		KeyEvent,
+		KeyEventKind,
+		KeyModifiers,
+	};
+
+	fn key(code: KeyCode) -> Event {
+		Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+	}
+
+	fn drain(pipeline: &mut InputPipeline) -> Vec<Event> {
+		std::iter::from_fn(|| pipeline.next_event()).collect()
+	}
+
+	#[test]
+	fn repeated_movement_keys_are_coalesced() {
+		let events = [
+			key(KeyCode::Up),
+			key(KeyCode::Up),
+			key(KeyCode::Up),
+			key(KeyCode::Char('a')),
+			key(KeyCode::Char('a')),
+			key(KeyCode::Left),
+			key(KeyCode::Up),
+		];
+		let mut pipeline = InputPipeline::new(true, 0);
+		pipeline.push(events.clone());
+		assert_eq!(drain(&mut pipeline), [
+			key(KeyCode::Up),
+			key(KeyCode::Char('a')),
+			key(KeyCode::Char('a')),
+			key(KeyCode::Left),
+			key(KeyCode::Up),
+		]);
+
+		let mut pipeline = InputPipeline::new(false, 0);
+		pipeline.push(events.clone());
+		assert_eq!(drain(&mut pipeline), events);
+	}
+
+	#[test]
+	fn movement_keys_are_rate_limited() {
+		let mut pipeline = InputPipeline::new(false, 60_000);
+		pipeline.push([key(KeyCode::Up), key(KeyCode::Down)]);
+		pipeline.push([key(KeyCode::Down), key(KeyCode::Char('a'))]);
+		assert_eq!(drain(&mut pipeline), [
+			key(KeyCode::Up),
+			key(KeyCode::Down),
+			key(KeyCode::Char('a')),
+		]);
+	}
+
+	#[test]
+	fn keys_are_normalized_then_transformed() {
+		/// Plays [W] as the up arrow.
+		#[derive(Debug)]
+		struct Remapper;
+
+		impl InputTransformer for Remapper {
+			fn read(&mut self, event: Event, _previous: Option<&Event>) -> Vec<Event> {
+				if event == key(KeyCode::Char('w')) {
+					vec![key(KeyCode::Up)]
+				} else {
+					vec![event]
+				}
+			}
+		}
+
+		let with_kind =
+			|code, kind| Event::Key(KeyEvent::new_with_kind(code, KeyModifiers::NONE, kind));
+		let mut pipeline = InputPipeline::new(true, 0).with(Remapper);
+		pipeline.push([
+			with_kind(KeyCode::Char('w'), KeyEventKind::Repeat),
+			with_kind(KeyCode::Char('w'), KeyEventKind::Release),
+			key(KeyCode::Char('a')),
+		]);
+		assert_eq!(drain(&mut pipeline), [
+			key(KeyCode::Up),
+			key(KeyCode::Char('a'))
+		]);
+	}
+}
@@ -0,0 +1,213 @@
+//! Input macros: key inputs recorded under a name, and played back with a
+//! hotkey to skip through repetitive setup, like picking the same game and
+//! options every time.
+//!
+//! \[F9\] starts recording a macro on any screen, and stops it, saving the
+//! macro in the [configuration directory](get_config_dir)'s [`MACROS_FILE`].
+//! Up to [`MAX_MACROS`] macros are kept, played with \[Alt\]+\[1\] to
+//! \[Alt\]+\[9\] in order, unless [remapped](crate::core::actions). They're
+//! renamed and removed from the macros screen of the settings.
+//!
+//! Macros are played as [scripts](crate::core::script), so the inputs recorded
+//! are the ones scripts can play.
+
+use std::{
+	path::PathBuf,
+	sync::Mutex,
+	time::Duration,
+};
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+use crate::{
+	core::{
+		actions::{
+			Action,
+			ActionContext,
+			Keybindings,
+		},
+		events::Event,
+		get_config_dir,
+		script::{
+			format_event,
+			Script,
+		},
+		time::Instant,
+	},
+	t,
+};
+
+/// Name of the file macros are saved in, in the [configuration
+/// directory](get_config_dir).
+pub const MACROS_FILE: &str = "macros.toml";
+
+/// How many macros can be kept, one per hotkey.
+pub const MAX_MACROS: usize = 9;
+
+/// How long a notice about macros is shown after something happened to them.
+const NOTICE_DURATION: Duration = Duration::from_secs(2);
+
+/// The macro being recorded, if any.
+static RECORDING: Mutex<Option<InputMacro>> = Mutex::new(None);
+
+/// When the last notice about macros was given, and what it says.
+static NOTICE: Mutex<Option<(Instant, String)>> = Mutex::new(None);
+
+/// Gets the path of the macros file.
+#[must_use]
+pub fn macros_file_path() -> PathBuf {
+	get_config_dir().join(MACROS_FILE)
+}
+
+/// Key inputs recorded under a name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[must_use]
+pub struct InputMacro {
+	/// Name of the macro.
+	pub name: String,
+
+	/// The inputs recorded, as script steps' inputs like `key ctrl+c`.
+	pub inputs: Vec<String>,
+}
+
+impl InputMacro {
+	/// Returns a script playing the macro's inputs one after the other.
+	pub fn to_script(&self) -> anyhow::Result<Script> {
+		let steps =
+			self.inputs.iter().map(|input| format!("0ms {input}")).collect::<Vec<_>>().join("\n");
+		Script::parse(&steps)
+	}
+}
+
+/// The macros kept, in the order of their hotkeys.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+#[must_use]
+pub struct Macros {
+	/// The macros kept.
+	pub macros: Vec<InputMacro>,
+}
+
+impl Macros {
+	/// Loads the macros.
+	pub fn load() -> anyhow::Result<Self> {
+		let macros_file = std::fs::read_to_string(macros_file_path())?;
+		Ok(toml::from_str::<Self>(&macros_file)?)
+	}
+
+	/// Saves the macros, in TOML format.
+	pub fn save(&self) -> anyhow::Result<()> {
+		let toml_string = toml::to_string_pretty(self)?;
+		std::fs::create_dir_all(get_config_dir())?;
+		Ok(std::fs::write(macros_file_path(), toml_string)?)
+	}
+
+	/// Loads the macros, or returns no macros if they could not be loaded.
+	pub fn load_or_default() -> Self {
+		Self::load().unwrap_or_default()
+	}
+}
+
+/// Returns the hotkey playing the macro at an index, like `alt+1`, as
+/// [bound](Keybindings).
+#[must_use]
+pub fn hotkey_label(index: usize) -> String {
+	Keybindings::get()
+		.key_for(Action::PlayMacro(index + 1), ActionContext::Global)
+		.unwrap_or("-")
+		.to_string()
+}
+
+/// Gives a notice about macros, shown for a moment in the screen's border.
+fn notify(notice: String) {
+	if let Ok(mut last_notice) = NOTICE.lock() {
+		*last_notice = Some((Instant::now(), notice));
+	}
+}
+
+/// Returns whether a macro is being recorded.
+#[must_use]
+pub fn is_recording() -> bool {
+	RECORDING.lock().is_ok_and(|recording| recording.is_some())
+}
+
+/// Starts recording a macro, unless every hotkey is taken.
+pub fn start_recording() {
+	let count = Macros::load_or_default().macros.len();
+	if count >= MAX_MACROS {
+		notify(t!("macros-full", max = MAX_MACROS));
+		return;
+	}
+	if let Ok(mut recording) = RECORDING.lock() {
+		*recording = Some(InputMacro {
+			name: t!("macros-default-name", number = count + 1),
+			inputs: Vec::new(),
+		});
+	}
+}
+
+/// Records an input in the macro being recorded, if any and if scripts can
+/// play it.
+pub fn record(event: &Event) {
+	let Ok(mut recording) = RECORDING.lock() else {
+		return;
+	};
+	if let (Some(recording), Some(input)) = (recording.as_mut(), format_event(event)) {
+		recording.inputs.push(input);
+	}
+}
+
+/// Stops recording a macro, and saves it if anything was recorded.
+pub fn stop_recording() {
+	let Some(recorded) = RECORDING.lock().ok().and_then(|mut recording| recording.take()) else {
+		return;
+	};
+	if recorded.inputs.is_empty() {
+		notify(t!("macros-empty").to_string());
+		return;
+	}
+	let mut macros = Macros::load_or_default();
+	let name = recorded.name.clone();
+	macros.macros.push(recorded);
+	notify(match macros.save() {
+		Ok(()) => t!(
+			"macros-saved",
+			name = name,
+			hotkey = hotkey_label(macros.macros.len() - 1)
+		),
+		Err(error) => t!("macros-save-failed", error = error.to_string()),
+	});
+}
+
+/// Returns a script playing the macro at an index, if there's one and it can
+/// be played.
+#[must_use]
+pub fn play(index: usize) -> Option<Script> {
+	let macros = Macros::load_or_default();
+	let input_macro = macros.macros.get(index)?;
+	match input_macro.to_script() {
+		Ok(script) => {
+			notify(t!("macros-playing", name = input_macro.name.clone()));
+			Some(script)
+		},
+		Err(error) => {
+			notify(t!("macros-play-failed", error = error.to_string()));
+			None
+		},
+	}
+}
+
+/// Returns the text shown in the screen's border while a macro is recorded,
+/// or for a moment after something happened to macros.
+#[must_use]
+pub fn indicator() -> Option<String> {
+	if is_recording() {
+		return Some(t!("macros-recording").to_string());
+	}
+	let last_notice = NOTICE.lock().ok()?;
+	let (time, notice) = last_notice.as_ref()?;
+	(time.elapsed() < NOTICE_DURATION).then(|| notice.clone())
+}
@@ -0,0 +1,149 @@
+//! Moves save data from the [legacy save directory](legacy_save_dir), where
+//! older versions of Terminal Arcade kept everything, to the [directories
+//! used now](crate::core::files). See [`Migration`].
+
+use std::{
+	fs,
+	path::{
+		Path,
+		PathBuf,
+	},
+};
+
+use anyhow::bail;
+
+use crate::core::{
+	config::CONFIG_FILE,
+	files::{
+		app_files,
+		PathSource,
+	},
+	SAVE_DIR,
+};
+
+/// Gets the directory older versions of Terminal Arcade saved their data in.
+#[cfg(not(target_arch = "wasm32"))]
+#[must_use]
+pub fn legacy_save_dir() -> PathBuf {
+	home::home_dir().unwrap().join(SAVE_DIR)
+}
+
+/// Gets the directory older versions of Terminal Arcade saved their data in,
+/// which browsers, without a home directory, never had.
+#[cfg(target_arch = "wasm32")]
+#[must_use]
+pub fn legacy_save_dir() -> PathBuf {
+	PathBuf::from(SAVE_DIR)
+}
+
+/// A move of save data from an old directory to the data and configuration
+/// directories. The configuration file goes to the configuration directory,
+/// and every other file to the data directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Migration {
+	/// Directory the data is moved from.
+	pub from: PathBuf,
+
+	/// Directory the data is moved to.
+	pub data_dir: PathBuf,
+
+	/// Directory the configuration file is moved to.
+	pub config_dir: PathBuf,
+}
+
+impl Migration {
+	/// Returns the migration from the [legacy save directory](legacy_save_dir)
+	/// to the default directories, if there's data in the former and none in
+	/// the latter. Directories picked on the command line or by the portable
+	/// mode are never migrated to.
+	#[must_use]
+	pub fn pending() -> Option<Self> {
+		let files = app_files();
+		if files.data_source != PathSource::Default || files.config_source != PathSource::Default {
+			return None;
+		}
+		Self::between(
+			legacy_save_dir(),
+			files.data_dir.clone(),
+			files.config_dir.clone(),
+		)
+	}
+
+	/// Returns the migration between two locations, if there's data to move
+	/// from the old one and none at the new one.
+	#[must_use]
+	pub fn between(from: PathBuf, data_dir: PathBuf, config_dir: PathBuf) -> Option<Self> {
+		let needed = from.is_dir()
+			&& from != data_dir
+			&& from != config_dir
+			&& !data_dir.exists()
+			&& !config_dir.join(CONFIG_FILE).exists();
+		needed.then_some(Self {
+			from,
+			data_dir,
+			config_dir,
+		})
+	}
+
+	/// Returns the files to move, relative to the [old directory](Self::from).
+	pub fn files(&self) -> anyhow::Result<Vec<PathBuf>> {
+		let mut files = Vec::new();
+		let mut dirs = vec![self.from.clone()];
+		while let Some(dir) = dirs.pop() {
+			for entry in fs::read_dir(&dir)? {
+				let path = entry?.path();
+				if path.is_dir() {
+					dirs.push(path);
+				} else {
+					files.push(path.strip_prefix(&self.from)?.to_path_buf());
+				}
+			}
+		}
+		files.sort();
+		Ok(files)
+	}
+
+	/// Returns where a file, relative to the [old directory](Self::from), is
+	/// moved to.
+	#[must_use]
+	pub fn destination(&self, file: &Path) -> PathBuf {
+		if file == Path::new(CONFIG_FILE) {
+			self.config_dir.join(file)
+		} else {
+			self.data_dir.join(file)
+		}
+	}
+
+	/// Moves the files, returning how many were moved. Every file is copied
+	/// and checked against its original before the old directory is removed,
+	/// so that it's left untouched, and the copies removed, if anything fails.
+	pub fn run(&self) -> anyhow::Result<usize> {
+		let files = self.files()?;
+		let mut copied = Vec::new();
+		let result = files.iter().try_for_each(|file| {
+			let destination = self.destination(file);
+			copied.push(destination.clone());
+			Self::copy_verified(&self.from.join(file), &destination)
+		});
+		if let Err(error) = result {
+			for file in copied {
+				let _ = fs::remove_file(file);
+			}
+			return Err(error);
+		}
+		fs::remove_dir_all(&self.from)?;
+		Ok(files.len())
+	}
+
+	/// Copies a file, checking that the copy has the same content.
+	fn copy_verified(source: &Path, destination: &Path) -> anyhow::Result<()> {
+		if let Some(parent) = destination.parent() {
+			fs::create_dir_all(parent)?;
+		}
+		fs::copy(source, destination)?;
+		if fs::read(source)? != fs::read(destination)? {
+			bail!("The copy of {} differs from the original", source.display());
+		}
+		Ok(())
+	}
+}
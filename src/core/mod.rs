@@ -12,36 +12,6 @@ use std::{
 };
 
 use bool_toggle::Toggler;
-use crossterm::{
-	cursor::{
-		DisableBlinking,
-		EnableBlinking,
-		Hide,
-		MoveTo,
-		Show,
-	},
-	event::{
-		poll,
-		read,
-		DisableBracketedPaste,
-		DisableFocusChange,
-		DisableMouseCapture,
-		EnableBracketedPaste,
-		EnableFocusChange,
-		EnableMouseCapture,
-		Event,
-		KeyCode,
-		KeyEvent,
-		KeyModifiers,
-	},
-	execute,
-	terminal::{
-		disable_raw_mode,
-		enable_raw_mode,
-		EnterAlternateScreen,
-		LeaveAlternateScreen,
-	},
-};
 use ratatui::{
 	layout::{
 		Constraint,
@@ -53,18 +23,64 @@ use ratatui::{
 	},
 };
 
+pub mod actions;
+pub mod bug_report;
+pub mod cheat_sheet;
+pub mod cli;
+pub mod clipboard;
+pub mod config;
+pub mod doctor;
+#[cfg(feature = "encryption")]
+pub mod encryption;
+pub mod error;
+pub mod event_history;
+pub mod events;
+pub mod export;
+pub mod files;
 pub mod handler;
+pub mod harness;
+pub mod i18n;
+pub mod input;
+pub mod macros;
+pub mod migration;
+pub mod panics;
+pub mod presets;
+pub mod profile;
+pub mod recording;
+pub mod render_stats;
+pub mod save_file;
+pub mod score_file;
+pub mod screenshot;
+pub mod script;
+pub mod search_history;
+pub mod time;
+pub mod updates;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod watchdog;
+#[cfg(target_arch = "wasm32")]
+pub mod web;
 
-/// The directory where Terminal Arcade saves all of its data.
+/// The directory in the home directory where Terminal Arcade used to save all
+/// of its data, before using the platform's usual directories. Still used when
+/// those can't be found.
 /// NOT TO BE USED DIRECTLY. This path does not include the home dir.
 /// Use [`get_save_dir`] for this instead.
 pub const SAVE_DIR: &str = ".terminal-arcade";
 
-/// Gets the save directory of Terminal Arcade.
+/// Gets the save directory of Terminal Arcade, which may be moved by the
+/// [command-line arguments](files::AppFiles::from_cli).
 /// Always use this function over the constant [`SAVE_DIR`].
 #[must_use]
 pub fn get_save_dir() -> PathBuf {
-	home::home_dir().unwrap().as_path().to_owned().join(SAVE_DIR)
+	files::app_files().data_dir.clone()
+}
+
+/// Gets the directory of Terminal Arcade's configuration file, which may also
+/// be moved by the command-line arguments.
+#[must_use]
+pub fn get_config_dir() -> PathBuf {
+	files::app_files().config_dir.clone()
 }
 
+#[cfg(not(target_arch = "wasm32"))]
 pub use handler::Handler;
@@ -0,0 +1,128 @@
+//! Panics in screens, caught where the [screen
+//! handler](crate::core::handler::ScreenHandler) runs their code so that only
+//! the screen that panicked is closed, and the rest of the session goes on.
+//!
+//! The panic hook [records](record) the panic's message and backtrace while
+//! a screen's code is [run](catch), instead of restoring the terminal and
+//! printing them, since the arcade keeps running.
+
+use std::{
+	any::Any,
+	backtrace::Backtrace,
+	cell::{
+		Cell,
+		RefCell,
+	},
+	panic::{
+		self,
+		AssertUnwindSafe,
+		PanicHookInfo,
+	},
+};
+
+thread_local! {
+	/// Whether a panic on this thread would be caught by [`catch`].
+	static CATCHING: Cell<bool> = const { Cell::new(false) };
+
+	/// The message and backtrace of the last panic [recorded](record) on this
+	/// thread.
+	static RECORDED: RefCell<Option<(String, String)>> = const { RefCell::new(None) };
+}
+
+/// A panic caught in a screen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScreenPanic {
+	/// Title of the screen that panicked.
+	pub screen: &'static str,
+
+	/// What the panic said, and where.
+	pub message: String,
+
+	/// The stack when it panicked, if it was recorded.
+	pub backtrace: String,
+}
+
+impl ScreenPanic {
+	/// Returns the panic as text, for a bug report.
+	#[must_use]
+	pub fn to_text(&self) -> String {
+		format!(
+			"Screen: {}\nPanic: {}\n\n{}",
+			self.screen, self.message, self.backtrace
+		)
+	}
+}
+
+/// Returns what a panic's payload says.
+fn payload_text(payload: &(dyn Any + Send)) -> String {
+	payload
+		.downcast_ref::<&str>()
+		.map(ToString::to_string)
+		.or_else(|| payload.downcast_ref::<String>().cloned())
+		.unwrap_or_else(|| "Box<dyn Any>".to_string())
+}
+
+/// Returns whether a panic on this thread would be caught, in which case the
+/// panic hook should only [record](record) it.
+#[must_use]
+pub fn is_catching() -> bool {
+	CATCHING.get()
+}
+
+/// Sets a panic hook [recording](record) the panics [caught](catch) in
+/// screens, and leaving the others to the hook set before.
+pub fn set_hook() {
+	let original_hook = panic::take_hook();
+	panic::set_hook(Box::new(move |panic_info| {
+		if is_catching() {
+			record(panic_info);
+		} else {
+			original_hook(panic_info);
+		}
+	}));
+}
+
+/// Records a panic's message and backtrace, for [`catch`] to report.
+pub fn record(info: &PanicHookInfo<'_>) {
+	let mut message = payload_text(info.payload());
+	if let Some(location) = info.location() {
+		message = format!("{message} at {location}");
+	}
+	let backtrace = Backtrace::force_capture().to_string();
+	RECORDED.set(Some((message, backtrace)));
+}
+
+/// Runs a screen's code, returning the panic it caused instead of unwinding
+/// further. Screens are left as they were when they panicked, so the one that
+/// panicked shouldn't be run again.
+pub fn catch<T>(screen: &'static str, code: impl FnOnce() -> T) -> Result<T, ScreenPanic> {
+	let was_catching = CATCHING.replace(true);
+	let result = panic::catch_unwind(AssertUnwindSafe(code));
+	CATCHING.set(was_catching);
+	result.map_err(|payload| {
+		let (message, backtrace) =
+			RECORDED.take().unwrap_or_else(|| (payload_text(payload.as_ref()), String::new()));
+		ScreenPanic {
+			screen,
+			message,
+			backtrace,
+		}
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		catch,
+		is_catching,
+	};
+
+	#[test]
+	fn catches_panics() {
+		assert_eq!(catch("Test", || 1 + 1), Ok(2));
+		let panic = catch("Test", || -> u8 { panic!("Oops") }).unwrap_err();
+		assert_eq!(panic.screen, "Test");
+		assert!(panic.message.starts_with("Oops"));
+		assert!(!is_catching());
+	}
+}
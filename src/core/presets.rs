@@ -0,0 +1,126 @@
+//! Presets: answers to a game's setup screen saved under a name, like "my
+//! expert board", to be picked again later instead of answering every
+//! question.
+//!
+//! Each game's presets are kept in a file of the [configuration
+//! directory](get_config_dir)'s [`PRESETS_DIR`] folder, named after the game's
+//! [ID](GameId).
+
+use std::path::PathBuf;
+
+use serde::de::DeserializeOwned;
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+use crate::{
+	core::get_config_dir,
+	games::registry::GameId,
+};
+
+/// Name of the folder of the configuration directory that presets are saved
+/// in.
+pub const PRESETS_DIR: &str = "presets";
+
+/// Gets the path of the file a game's presets are saved in.
+#[must_use]
+pub fn presets_file_path(game: &GameId) -> PathBuf {
+	get_config_dir().join(PRESETS_DIR).join(format!("{game}.toml"))
+}
+
+/// Answers to a game's setup screen, saved under a name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[must_use]
+pub struct Preset<T> {
+	/// Name of the preset.
+	pub name: String,
+
+	/// The answers.
+	pub answers: T,
+}
+
+/// A game's presets, in the order they were first saved.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[must_use]
+pub struct Presets<T> {
+	/// The presets kept.
+	#[serde(default = "Vec::new")]
+	pub presets: Vec<Preset<T>>,
+}
+
+impl<T> Default for Presets<T> {
+	fn default() -> Self {
+		Self {
+			presets: Vec::new(),
+		}
+	}
+}
+
+impl<T> Presets<T> {
+	/// Saves answers under a name, replacing the preset with the same name if
+	/// there's one. Returns the index of the preset.
+	pub fn insert(&mut self, name: &str, answers: T) -> usize {
+		let name = name.trim();
+		if let Some(index) =
+			self.presets.iter().position(|preset| preset.name.eq_ignore_ascii_case(name))
+		{
+			self.presets[index].answers = answers;
+			index
+		} else {
+			self.presets.push(Preset {
+				name: name.to_string(),
+				answers,
+			});
+			self.presets.len() - 1
+		}
+	}
+
+	/// Returns the names of the presets.
+	#[must_use]
+	pub fn names(&self) -> Vec<&str> {
+		self.presets.iter().map(|preset| preset.name.as_str()).collect()
+	}
+}
+
+impl<T: serde::Serialize + DeserializeOwned> Presets<T> {
+	/// Loads a game's presets.
+	pub fn load(game: &GameId) -> anyhow::Result<Self> {
+		let presets_file = std::fs::read_to_string(presets_file_path(game))?;
+		Ok(toml::from_str::<Self>(&presets_file)?)
+	}
+
+	/// Saves a game's presets, in TOML format.
+	pub fn save(&self, game: &GameId) -> anyhow::Result<()> {
+		let toml_string = toml::to_string_pretty(self)?;
+		std::fs::create_dir_all(get_config_dir().join(PRESETS_DIR))?;
+		Ok(std::fs::write(presets_file_path(game), toml_string)?)
+	}
+
+	/// Loads a game's presets, or returns no presets if they could not be
+	/// loaded.
+	pub fn load_or_default(game: &GameId) -> Self {
+		Self::load(game).unwrap_or_default()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Presets;
+
+	#[test]
+	fn presets_are_replaced_by_name() {
+		let mut presets = Presets::default();
+		assert_eq!(presets.insert("Expert", 99), 0);
+		assert_eq!(presets.insert("Quick", 10), 1);
+		assert_eq!(presets.insert(" expert ", 120), 0);
+		assert_eq!(presets.names(), ["Expert", "Quick"]);
+		assert_eq!(presets.presets[0].answers, 120);
+
+		let toml_string = toml::to_string_pretty(&presets).unwrap();
+		assert_eq!(
+			toml::from_str::<Presets<u32>>(&toml_string).unwrap(),
+			presets
+		);
+	}
+}
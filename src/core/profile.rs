@@ -0,0 +1,193 @@
+//! The player's profile: a display name and a small avatar, edited from the
+//! [profile screen](crate::ui::screens::ProfileScreen) opened from the
+//! settings.
+//!
+//! Once saved, in the [save directory](get_save_dir)'s [`PROFILE_FILE`], the
+//! profile is shown in the top border of every screen, and other players see
+//! it in games played over the [network](crate::services::net). Until then,
+//! the name defaults to the user's account name.
+
+use std::{
+	env,
+	path::PathBuf,
+	sync::Mutex,
+};
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+use crate::core::{
+	get_save_dir,
+	save_file,
+};
+
+/// Name of the file the profile is saved in, in the [save
+/// directory](get_save_dir).
+pub const PROFILE_FILE: &str = "profile.toml";
+
+/// How many characters a display name can have at most.
+pub const MAX_NAME_LENGTH: usize = 16;
+
+/// Avatars to pick from.
+pub const AVATARS: [&str; 12] = [
+	"🙂", "😎", "🤖", "👾", "👻", "🐱", "🦊", "🐸", "🐧", "🦄", "🚀", "⭐",
+];
+
+/// The saved profile, as last looked up.
+static SAVED_PROFILE: Mutex<SavedProfile> = Mutex::new(SavedProfile::Unknown);
+
+/// What's known of the saved profile.
+enum SavedProfile {
+	/// It hasn't been looked up yet.
+	Unknown,
+
+	/// It's been looked up, and there was one if it's `Some`.
+	Known(Option<Profile>),
+}
+
+/// Gets the path of the profile file.
+#[must_use]
+pub fn profile_path() -> PathBuf {
+	get_save_dir().join(PROFILE_FILE)
+}
+
+/// Returns the user's account name, or a generic name if it's unknown.
+fn account_name() -> String {
+	env::var("USER").or_else(|_| env::var("USERNAME")).unwrap_or_else(|_| "Player".to_string())
+}
+
+/// Returns a name typed in without the spaces around it and cut to
+/// [`MAX_NAME_LENGTH`] characters, or `None` if it's blank.
+#[must_use]
+pub fn clean_name(name: &str) -> Option<String> {
+	let name: String = name.trim().chars().take(MAX_NAME_LENGTH).collect();
+	let name = name.trim_end();
+	(!name.is_empty()).then(|| name.to_string())
+}
+
+/// Returns the text shown in the screen's border for the saved profile, if
+/// there is one.
+#[must_use]
+pub fn indicator() -> Option<String> {
+	Profile::saved().map(|profile| profile.badge())
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+#[must_use]
+pub struct Profile {
+	/// Name shown to the player and to other players.
+	pub name: String,
+
+	/// A glyph or emoji shown before the name, usually one of [`AVATARS`].
+	pub avatar: String,
+}
+
+impl Default for Profile {
+	fn default() -> Self {
+		Self {
+			name: account_name(),
+			avatar: AVATARS[0].to_string(),
+		}
+	}
+}
+
+impl Profile {
+	/// Loads the profile.
+	pub fn load() -> anyhow::Result<Self> {
+		let profile_file = save_file::read(&profile_path())?;
+		Ok(toml::from_str::<Self>(&profile_file)?)
+	}
+
+	/// Saves the profile, in TOML format.
+	pub fn save(&self) -> anyhow::Result<()> {
+		let toml_string = toml::to_string_pretty(self)?;
+		std::fs::create_dir_all(get_save_dir())?;
+		save_file::write(&profile_path(), &toml_string)?;
+		if let Ok(mut saved) = SAVED_PROFILE.lock() {
+			*saved = SavedProfile::Known(Some(self.clone()));
+		}
+		Ok(())
+	}
+
+	/// Returns the saved profile, if there is one. It's only read from its
+	/// file once, until [forgotten](Self::forget).
+	pub fn saved() -> Option<Self> {
+		let mut saved = SAVED_PROFILE.lock().ok()?;
+		if let SavedProfile::Unknown = *saved {
+			*saved = SavedProfile::Known(Self::load().ok());
+		}
+		match &*saved {
+			SavedProfile::Known(profile) => profile.clone(),
+			SavedProfile::Unknown => None,
+		}
+	}
+
+	/// Returns the saved profile, or the default one if there's none saved.
+	pub fn current() -> Self {
+		Self::saved().unwrap_or_default()
+	}
+
+	/// Forgets the saved profile, so that it's read from its file again, like
+	/// after the save directory changed.
+	pub fn forget() {
+		if let Ok(mut saved) = SAVED_PROFILE.lock() {
+			*saved = SavedProfile::Unknown;
+		}
+	}
+
+	/// Returns the avatar followed by the name.
+	#[must_use]
+	pub fn badge(&self) -> String {
+		format!("{} {}", self.avatar, self.name)
+	}
+
+	/// Switches to another of the [`AVATARS`], going back or forward.
+	/// Avatars that aren't among them switch to the first one.
+	pub fn cycle_avatar(&mut self, forward: bool) {
+		let next = match AVATARS.iter().position(|avatar| *avatar == self.avatar) {
+			Some(index) if forward => (index + 1) % AVATARS.len(),
+			Some(index) => (index + AVATARS.len() - 1) % AVATARS.len(),
+			None => 0,
+		};
+		self.avatar = AVATARS[next].to_string();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		clean_name,
+		Profile,
+		AVATARS,
+	};
+
+	#[test]
+	fn cleans_names_and_cycles_avatars() {
+		assert_eq!(clean_name("  Ada  "), Some("Ada".to_string()));
+		assert_eq!(clean_name("   "), None);
+		assert_eq!(
+			clean_name("Ada Lovelace the Countess"),
+			Some("Ada Lovelace the".to_string())
+		);
+		assert_eq!(
+			clean_name("Ada Lovelace    x"),
+			Some("Ada Lovelace".to_string())
+		);
+
+		let mut profile = Profile {
+			name: "Ada".to_string(),
+			avatar: "?".to_string(),
+		};
+		profile.cycle_avatar(true);
+		assert_eq!(profile.avatar, AVATARS[0]);
+		profile.cycle_avatar(false);
+		assert_eq!(profile.avatar, AVATARS[AVATARS.len() - 1]);
+		profile.cycle_avatar(true);
+		assert_eq!(profile.avatar, AVATARS[0]);
+		assert_eq!(profile.badge(), format!("{} Ada", AVATARS[0]));
+	}
+}
@@ -0,0 +1,221 @@
+//! Recordings of whole sessions, started and stopped with \[F7\] from any
+//! screen, saved as [asciicasts](https://docs.asciinema.org/manual/asciicast/v2/)
+//! in the save directory's [`RECORDING_DIR`] folder to make demos of
+//! Terminal Arcade.
+//!
+//! Every frame drawn while recording that differs from the last one is
+//! written whole, with its colors as ANSI escape codes, so that `asciinema
+//! play` shows exactly what was on screen. Frames the [frame
+//! pacer](crate::core::render_stats::FramePacer) skips aren't recorded.
+
+use std::{
+	fs::{
+		self,
+		File,
+	},
+	io::{
+		BufWriter,
+		Write,
+	},
+	path::PathBuf,
+	sync::Mutex,
+	time::Duration,
+};
+
+use ratatui::buffer::Buffer;
+use serde_json::json;
+
+use crate::{
+	core::{
+		get_save_dir,
+		screenshot::buffer_ansi_lines,
+		time::{
+			Instant,
+			SystemTime,
+			UNIX_EPOCH,
+		},
+	},
+	t,
+};
+
+/// Name of the folder of the save directory that recordings are saved in.
+pub const RECORDING_DIR: &str = "recordings";
+
+/// How long the notice of a saved recording is shown after it's saved.
+const NOTICE_DURATION: Duration = Duration::from_secs(4);
+
+/// What happened to recordings lately: whether one is going on, or when the
+/// last one stopped and what it came to.
+static STATUS: Mutex<Option<RecordingStatus>> = Mutex::new(None);
+
+/// See [`STATUS`].
+enum RecordingStatus {
+	/// A recording is going on.
+	Recording,
+
+	/// A recording stopped at a time, saved at a path or failed with an
+	/// error.
+	Stopped(Instant, Result<PathBuf, String>),
+}
+
+/// Gets the directory recordings are saved in.
+#[must_use]
+pub fn recording_dir() -> PathBuf {
+	get_save_dir().join(RECORDING_DIR)
+}
+
+/// Returns the text shown in the screen's border while a session is
+/// recorded, or for a moment after a recording stopped.
+#[must_use]
+pub fn indicator() -> Option<String> {
+	match STATUS.lock().ok()?.as_ref()? {
+		RecordingStatus::Recording => Some(t!("recording-on").to_string()),
+		RecordingStatus::Stopped(time, result) => {
+			(time.elapsed() < NOTICE_DURATION).then(|| match result {
+				Ok(path) => t!("recording-saved", path = path.display().to_string()),
+				Err(error) => t!("recording-failed", error = error.as_str()),
+			})
+		},
+	}
+}
+
+/// Sets what happened to recordings lately.
+fn set_status(status: RecordingStatus) {
+	if let Ok(mut last_status) = STATUS.lock() {
+		*last_status = Some(status);
+	}
+}
+
+/// See the [module](self) documentation for more information.
+#[must_use]
+pub struct Recording<W: Write = BufWriter<File>> {
+	/// Where the asciicast is written.
+	writer: W,
+
+	/// Where the asciicast is saved, if it's saved to a file.
+	path: Option<PathBuf>,
+
+	/// When the recording started.
+	started: Instant,
+
+	/// The last frame recorded, if one was.
+	last_frame: Option<Buffer>,
+}
+
+impl Recording {
+	/// Starts recording a session of a terminal's size to a new file.
+	pub fn start(width: u16, height: u16) -> anyhow::Result<Self> {
+		let dir = recording_dir();
+		fs::create_dir_all(&dir)?;
+		let started = SystemTime::now().duration_since(UNIX_EPOCH)?;
+		let path = dir.join(format!("session-{}.cast", started.as_millis()));
+		let writer = BufWriter::new(File::create(&path)?);
+		let mut recording = Self::new(writer, width, height, started.as_secs())?;
+		recording.path = Some(path);
+		set_status(RecordingStatus::Recording);
+		Ok(recording)
+	}
+
+	/// Stops recording, saving the file.
+	pub fn stop(mut self) {
+		let result = self.writer.flush().map_err(|error| error.to_string());
+		let path = self.path.take().unwrap_or_default();
+		set_status(RecordingStatus::Stopped(
+			Instant::now(),
+			result.map(|()| path),
+		));
+	}
+
+	/// Stops recording after an error writing it.
+	pub fn abandon(self, error: &anyhow::Error) {
+		set_status(RecordingStatus::Stopped(
+			Instant::now(),
+			Err(error.to_string()),
+		));
+	}
+}
+
+impl<W: Write> Recording<W> {
+	/// Starts recording a session of a terminal's size, at a UNIX timestamp,
+	/// to a writer.
+	pub fn new(mut writer: W, width: u16, height: u16, timestamp: u64) -> anyhow::Result<Self> {
+		let header = json!({
+			"version": 2,
+			"width": width,
+			"height": height,
+			"timestamp": timestamp,
+			"title": t!("recording-title"),
+		});
+		writeln!(writer, "{header}")?;
+		Ok(Self {
+			writer,
+			path: None,
+			started: Instant::now(),
+			last_frame: None,
+		})
+	}
+
+	/// Writes an event of the asciicast, like output or a resize.
+	fn write_event(&mut self, code: &str, data: &str) -> anyhow::Result<()> {
+		let time = self.started.elapsed().as_secs_f64();
+		writeln!(self.writer, "{}", json!([time, code, data]))?;
+		Ok(())
+	}
+
+	/// Records a frame drawn, unless it's the same as the last one.
+	pub fn record(&mut self, frame: &Buffer) -> anyhow::Result<()> {
+		if self.last_frame.as_ref() == Some(frame) {
+			return Ok(());
+		}
+		let resized = self.last_frame.as_ref().is_some_and(|last| last.area != frame.area);
+		if resized {
+			self.write_event("r", &format!("{}x{}", frame.area.width, frame.area.height))?;
+		}
+		let clear = if self.last_frame.is_none() || resized { "\x1b[2J" } else { "" };
+		let output = format!("{clear}\x1b[H{}", buffer_ansi_lines(frame).join("\r\n"));
+		self.write_event("o", &output)?;
+		self.last_frame = Some(frame.clone());
+		Ok(())
+	}
+
+	/// Returns the writer the asciicast is written to.
+	pub fn into_writer(self) -> W {
+		self.writer
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use ratatui::{
+		buffer::Buffer,
+		layout::Rect,
+		style::Style,
+	};
+	use serde_json::Value;
+
+	use super::Recording;
+
+	#[test]
+	fn records_changed_frames_as_an_asciicast() {
+		let mut recording = Recording::new(Vec::new(), 4, 1, 1_700_000_000).unwrap();
+		let mut frame = Buffer::empty(Rect::new(0, 0, 4, 1));
+		frame.set_string(0, 0, "ab", Style::new());
+		recording.record(&frame).unwrap();
+		recording.record(&frame).unwrap();
+		let mut resized = Buffer::empty(Rect::new(0, 0, 2, 1));
+		resized.set_string(0, 0, "cd", Style::new());
+		recording.record(&resized).unwrap();
+
+		let cast = String::from_utf8(recording.into_writer()).unwrap();
+		let lines: Vec<Value> =
+			cast.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+		assert_eq!(lines.len(), 4);
+		assert_eq!(lines[0]["version"], 2);
+		assert_eq!(lines[0]["width"], 4);
+		assert_eq!(lines[1][1], "o");
+		assert_eq!(lines[1][2], "\x1b[2J\x1b[H\x1b[0mab  \x1b[0m");
+		assert_eq!(lines[2][1], "r");
+		assert_eq!(lines[2][2], "2x1");
+		assert_eq!(lines[3][2], "\x1b[2J\x1b[H\x1b[0mcd\x1b[0m");
+	}
+}
@@ -0,0 +1,446 @@
+//! How long screens take to draw, for performance work. Every frame drawn is
+//! timed by the [screen handler](crate::core::handler::ScreenHandler) and
+//! counted against the screen's [frame budget](ScreenState::frame_budget).
+//!
+//! Frames are [paced](FramePacer), so that drawing keeps up with the terminal:
+//! - Input comes first: while input is waiting to be played, frames are skipped
+//!   until a frame budget has passed since the last one, so that slow frames
+//!   never hold input back for long. How long input waited, how much of it
+//!   queued up and how many frames were skipped are kept as the [input
+//!   stats](input_stats).
+//! - Under load, like on a slow terminal or over SSH, a frame over budget is
+//!   followed by as much time without drawing, so that screens keep updating
+//!   and the next frame drawn shows their latest state, rather than drawing
+//!   falling further and further behind.
+//! - [Static screens](crate::ui::screens::ScreenState::static_frames), like
+//!   menus without animations, are only drawn again after something happened,
+//!   like input or another screen opening, or every [`STATIC_REDRAW_INTERVAL`]
+//!   for the indicators in their border, so that they barely use the CPU while
+//!   idle.
+//!
+//! The [stats] are saved in bug reports. With the developer tools on, frames
+//! over budget and input waiting longer than a frame budget are also pointed
+//! out in the screen's border for a moment.
+//!
+//! [ScreenState::frame_budget]: crate::ui::screens::ScreenState::frame_budget
+
+use std::{
+	collections::BTreeMap,
+	sync::{
+		atomic::{
+			AtomicBool,
+			AtomicU64,
+			Ordering,
+		},
+		Mutex,
+	},
+	time::Duration,
+};
+
+use crate::{
+	core::time::Instant,
+	t,
+};
+
+/// How long a frame should take at most, so that the app keeps up with 60
+/// frames per second.
+pub const FRAME_BUDGET: Duration = Duration::from_millis(16);
+
+/// How often [static screens](FramePacer) are drawn again when nothing
+/// happened.
+pub const STATIC_REDRAW_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How long a notice about a slow frame is shown.
+const NOTICE_DURATION: Duration = Duration::from_secs(2);
+
+/// Render stats by screen title.
+static STATS: Mutex<BTreeMap<&'static str, RenderStats>> = Mutex::new(BTreeMap::new());
+
+/// When the last frame over budget was drawn, and the notice about it.
+static NOTICE: Mutex<Option<(Instant, String)>> = Mutex::new(None);
+
+/// Whether frames over budget are pointed out in the screen's border.
+static SHOW_NOTICES: AtomicBool = AtomicBool::new(false);
+
+/// How input waited to be played.
+static INPUT_STATS: Mutex<InputStats> = Mutex::new(InputStats::new());
+
+/// How many frames weren't drawn under load.
+static DROPPED_FRAMES: AtomicU64 = AtomicU64::new(0);
+
+/// How long frames of a screen took to draw.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[must_use]
+pub struct RenderStats {
+	/// How many frames were drawn.
+	pub frames: u64,
+
+	/// How long every frame took, together.
+	pub total: Duration,
+
+	/// How long the slowest frame took.
+	pub slowest: Duration,
+
+	/// How many frames went over budget.
+	pub slow_frames: u64,
+}
+
+impl RenderStats {
+	/// Counts a frame that took a duration to draw, returning whether it went
+	/// over budget.
+	pub fn record(&mut self, duration: Duration, budget: Duration) -> bool {
+		self.frames += 1;
+		self.total += duration;
+		self.slowest = self.slowest.max(duration);
+		let slow = duration > budget;
+		if slow {
+			self.slow_frames += 1;
+		}
+		slow
+	}
+
+	/// Returns how long frames took on average.
+	#[must_use]
+	pub fn average(&self) -> Duration {
+		u32::try_from(self.frames)
+			.ok()
+			.filter(|frames| *frames > 0)
+			.map_or(Duration::ZERO, |frames| self.total / frames)
+	}
+}
+
+/// How input waited to be played, behind other input and frames drawn.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[must_use]
+pub struct InputStats {
+	/// How many events were played.
+	pub played: u64,
+
+	/// How long every event waited between being read and played, together.
+	pub total_wait: Duration,
+
+	/// How long the event that waited the most waited.
+	pub longest_wait: Duration,
+
+	/// Most events waiting to be played at once.
+	pub deepest_queue: usize,
+
+	/// How many frames weren't drawn so that waiting input was played first.
+	pub skipped_frames: u64,
+}
+
+impl InputStats {
+	/// Creates empty input stats.
+	const fn new() -> Self {
+		Self {
+			played: 0,
+			total_wait: Duration::ZERO,
+			longest_wait: Duration::ZERO,
+			deepest_queue: 0,
+			skipped_frames: 0,
+		}
+	}
+
+	/// Counts an event played after waiting a duration, with a number of
+	/// events waiting, itself included. Returns whether it waited longer
+	/// than a frame budget.
+	pub fn record(&mut self, wait: Duration, waiting: usize) -> bool {
+		self.played += 1;
+		self.total_wait += wait;
+		self.longest_wait = self.longest_wait.max(wait);
+		self.deepest_queue = self.deepest_queue.max(waiting);
+		wait > FRAME_BUDGET
+	}
+
+	/// Returns how long events waited on average.
+	#[must_use]
+	pub fn average_wait(&self) -> Duration {
+		u32::try_from(self.played)
+			.ok()
+			.filter(|played| *played > 0)
+			.map_or(Duration::ZERO, |played| self.total_wait / played)
+	}
+}
+
+/// Why a frame isn't drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameSkip {
+	/// Input is waiting to be played first.
+	Input,
+
+	/// The last frame went over budget, and the terminal is given time to
+	/// catch up.
+	Load,
+
+	/// The screens are static, and nothing happened since the last frame.
+	Idle,
+}
+
+/// Decides which frames are drawn. See the [module](self) documentation for
+/// more information.
+#[derive(Debug, Clone, Copy, Default)]
+#[must_use]
+pub struct FramePacer {
+	/// When the last frame drawn started, once one was.
+	last_draw: Option<Instant>,
+
+	/// How long the last frame drawn took.
+	last_draw_time: Duration,
+
+	/// Whether something happened since the last frame drawn.
+	invalidated: bool,
+}
+
+impl FramePacer {
+	/// Returns why the frame starting at `now` isn't drawn, or `None` if it's
+	/// drawn, given whether input is waiting and whether the screens drawn are
+	/// static.
+	#[must_use]
+	pub fn skip(
+		&self,
+		now: Instant,
+		input_waiting: bool,
+		static_frames: bool,
+	) -> Option<FrameSkip> {
+		let since_last_draw = now.saturating_duration_since(self.last_draw?);
+		if input_waiting && since_last_draw < FRAME_BUDGET {
+			Some(FrameSkip::Input)
+		} else if self.last_draw_time > FRAME_BUDGET && since_last_draw < self.last_draw_time * 2 {
+			Some(FrameSkip::Load)
+		} else if static_frames && !self.invalidated && since_last_draw < STATIC_REDRAW_INTERVAL {
+			Some(FrameSkip::Idle)
+		} else {
+			None
+		}
+	}
+
+	/// Remembers a frame drawn, starting at `started` and taking `took`.
+	pub fn drawn(&mut self, started: Instant, took: Duration) {
+		self.last_draw = Some(started);
+		self.last_draw_time = took;
+		self.invalidated = false;
+	}
+
+	/// Remembers that something happened since the last frame drawn, so that
+	/// static screens are drawn again.
+	pub fn invalidate(&mut self) {
+		self.invalidated = true;
+	}
+}
+
+/// Points out frames over budget in the screen's border from now on, or stops
+/// to.
+pub fn show_notices(show: bool) {
+	SHOW_NOTICES.store(show, Ordering::Relaxed);
+}
+
+/// Counts a frame of a screen that took a duration to draw, against the
+/// screen's frame budget.
+pub fn record(screen: &'static str, duration: Duration, budget: Duration) {
+	let Ok(mut stats) = STATS.lock() else {
+		return;
+	};
+	if stats.entry(screen).or_default().record(duration, budget) {
+		if let Ok(mut notice) = NOTICE.lock() {
+			*notice = Some((
+				Instant::now(),
+				t!(
+					"render-slow",
+					screen = screen,
+					ms = duration.as_millis().to_string(),
+					budget = budget.as_millis().to_string()
+				),
+			));
+		}
+	}
+}
+
+/// Counts an input event played after waiting a duration, with a number of
+/// events waiting, itself included.
+pub fn record_input(wait: Duration, waiting: usize) {
+	let Ok(mut stats) = INPUT_STATS.lock() else {
+		return;
+	};
+	if stats.record(wait, waiting) {
+		if let Ok(mut notice) = NOTICE.lock() {
+			*notice = Some((
+				Instant::now(),
+				t!(
+					"input-slow",
+					ms = wait.as_millis().to_string(),
+					waiting = waiting
+				),
+			));
+		}
+	}
+}
+
+/// Counts a frame that wasn't drawn.
+pub fn record_skipped_frame(skip: FrameSkip) {
+	match skip {
+		FrameSkip::Input => {
+			if let Ok(mut stats) = INPUT_STATS.lock() {
+				stats.skipped_frames += 1;
+			}
+		},
+		FrameSkip::Load => {
+			DROPPED_FRAMES.fetch_add(1, Ordering::Relaxed);
+		},
+		// Idle frames aren't worth counting.
+		FrameSkip::Idle => {},
+	}
+}
+
+/// Returns how input waited to be played so far.
+pub fn input_stats() -> InputStats {
+	INPUT_STATS.lock().map(|stats| *stats).unwrap_or_default()
+}
+
+/// Returns the render stats of every screen drawn so far, by title.
+#[must_use]
+pub fn stats() -> BTreeMap<&'static str, RenderStats> {
+	STATS.lock().map(|stats| stats.clone()).unwrap_or_default()
+}
+
+/// Returns the render stats of every screen drawn so far as text, a screen
+/// per line, then the input stats and the frames dropped under load, for bug
+/// reports.
+#[must_use]
+pub fn to_text() -> String {
+	let input = input_stats();
+	let input = format!(
+		"Input: {played} events, {average:?} waited on average, {longest:?} at most, {deepest} \
+		 queued at most, {skipped} frames skipped",
+		played = input.played,
+		average = input.average_wait(),
+		longest = input.longest_wait,
+		deepest = input.deepest_queue,
+		skipped = input.skipped_frames,
+	);
+	stats()
+		.into_iter()
+		.map(|(screen, stats)| {
+			format!(
+				"{screen}: {frames} frames, {average:?} on average, {slowest:?} at most, {slow} \
+				 over budget",
+				frames = stats.frames,
+				average = stats.average(),
+				slowest = stats.slowest,
+				slow = stats.slow_frames,
+			)
+		})
+		.chain([
+			input,
+			format!(
+				"Load: {} frames dropped",
+				DROPPED_FRAMES.load(Ordering::Relaxed)
+			),
+		])
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+/// Returns the text shown in the screen's border for a moment after a frame
+/// went over budget, if [shown](show_notices).
+#[must_use]
+pub fn indicator() -> Option<String> {
+	if !SHOW_NOTICES.load(Ordering::Relaxed) {
+		return None;
+	}
+	let notice = NOTICE.lock().ok()?;
+	let (time, notice) = notice.as_ref()?;
+	(time.elapsed() < NOTICE_DURATION).then(|| notice.clone())
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::{
+		Duration,
+		Instant,
+	};
+
+	use super::{
+		FramePacer,
+		FrameSkip,
+		InputStats,
+		RenderStats,
+		FRAME_BUDGET,
+		STATIC_REDRAW_INTERVAL,
+	};
+
+	#[test]
+	fn frames_over_budget_are_counted() {
+		let budget = Duration::from_millis(16);
+		let mut stats = RenderStats::default();
+		assert_eq!(stats.average(), Duration::ZERO);
+		assert!(!stats.record(Duration::from_millis(4), budget));
+		assert!(stats.record(Duration::from_millis(20), budget));
+		assert!(!stats.record(Duration::from_millis(16), budget));
+		assert_eq!(stats.frames, 3);
+		assert_eq!(stats.slow_frames, 1);
+		assert_eq!(stats.slowest, Duration::from_millis(20));
+		assert_eq!(stats.average(), Duration::from_millis(40) / 3);
+	}
+
+	#[test]
+	fn input_waiting_over_budget_is_counted() {
+		let mut stats = InputStats::default();
+		assert_eq!(stats.average_wait(), Duration::ZERO);
+		assert!(!stats.record(Duration::from_millis(2), 3));
+		assert!(stats.record(Duration::from_millis(30), 1));
+		assert_eq!(stats.played, 2);
+		assert_eq!(stats.deepest_queue, 3);
+		assert_eq!(stats.longest_wait, Duration::from_millis(30));
+		assert_eq!(stats.average_wait(), Duration::from_millis(16));
+	}
+
+	#[test]
+	fn frames_are_skipped_for_input_and_under_load() {
+		let start = Instant::now();
+		let mut pacer = FramePacer::default();
+		assert_eq!(pacer.skip(start, true, false), None);
+
+		pacer.drawn(start, Duration::from_millis(4));
+		assert_eq!(
+			pacer.skip(start + Duration::from_millis(8), true, false),
+			Some(FrameSkip::Input)
+		);
+		assert_eq!(
+			pacer.skip(start + Duration::from_millis(8), false, false),
+			None
+		);
+		assert_eq!(pacer.skip(start + FRAME_BUDGET, true, false), None);
+
+		pacer.drawn(start, Duration::from_millis(50));
+		assert_eq!(
+			pacer.skip(start + Duration::from_millis(60), false, false),
+			Some(FrameSkip::Load)
+		);
+		assert_eq!(
+			pacer.skip(start + Duration::from_millis(100), false, false),
+			None
+		);
+	}
+
+	#[test]
+	fn static_frames_are_drawn_on_demand() {
+		let start = Instant::now();
+		let mut pacer = FramePacer::default();
+		pacer.drawn(start, Duration::from_millis(4));
+		let later = start + Duration::from_millis(100);
+		assert_eq!(pacer.skip(later, false, true), Some(FrameSkip::Idle));
+		assert_eq!(pacer.skip(later, false, false), None);
+		assert_eq!(
+			pacer.skip(start + STATIC_REDRAW_INTERVAL, false, true),
+			None
+		);
+
+		pacer.invalidate();
+		assert_eq!(pacer.skip(later, false, true), None);
+		pacer.drawn(later, Duration::from_millis(4));
+		assert_eq!(
+			pacer.skip(later + FRAME_BUDGET, false, true),
+			Some(FrameSkip::Idle)
+		);
+	}
+}
@@ -0,0 +1,183 @@
+//! Reading and writing of the player's save data, like game metadata, which
+//! is [encrypted](crate::core::encryption) when set up with the `encryption`
+//! feature.
+
+use std::{
+	fs::{
+		self,
+		File,
+		OpenOptions,
+	},
+	path::{
+		Component,
+		Path,
+		PathBuf,
+	},
+	sync::atomic::{
+		AtomicUsize,
+		Ordering,
+	},
+};
+
+use anyhow::bail;
+
+#[cfg(feature = "encryption")]
+use crate::core::encryption;
+use crate::{
+	core::{
+		error::AppError,
+		get_save_dir,
+		profile::PROFILE_FILE,
+	},
+	services::{
+		autosave::AUTOSAVE_DIR,
+		stats::PLAYTIME_FILE,
+	},
+};
+
+/// Folders of the save directory that hold save files, with the suffixes of
+/// their names: games' metadata and scripted games' data, the
+/// [profile](crate::core::profile), today's [playtime](crate::services::stats)
+/// and [autosaves](crate::services::autosave).
+const SAVE_FILE_DIRS: [(&str, &[&str]); 3] = [
+	("", &[
+		".meta.toml",
+		".save.json",
+		PROFILE_FILE,
+		PLAYTIME_FILE,
+	]),
+	("games", &[".meta.toml", ".save.json"]),
+	(AUTOSAVE_DIR, &[".json"]),
+];
+
+/// Bytes encrypted save files start with.
+pub const ENCRYPTED_MAGIC: &[u8] = b"TAENC1";
+
+/// Suffix of the files next to save files that [lock](lock) them.
+pub const LOCK_SUFFIX: &str = ".lock";
+
+/// Suffix of the files save files are written to before being renamed into
+/// place.
+const TEMPORARY_SUFFIX: &str = ".tmp";
+
+/// Lists the save files, in the save directory and its `games` and
+/// [autosaves](AUTOSAVE_DIR) folders.
+pub fn list() -> anyhow::Result<Vec<PathBuf>> {
+	let mut files = Vec::new();
+	for (dir, suffixes) in SAVE_FILE_DIRS {
+		let Ok(entries) = fs::read_dir(get_save_dir().join(dir)) else {
+			continue;
+		};
+		for entry in entries {
+			let path = entry?.path();
+			let name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+			if suffixes.iter().any(|suffix| name.ends_with(suffix)) {
+				files.push(path);
+			}
+		}
+	}
+	files.sort();
+	Ok(files)
+}
+
+/// Returns whether a path, relative to the save directory, is one [list]
+/// would find. Paths that are absolute or leave the save directory never are.
+#[must_use]
+pub fn is_save_file(path: &Path) -> bool {
+	if !path.components().all(|component| matches!(component, Component::Normal(_))) {
+		return false;
+	}
+	let dir = path.parent().unwrap_or(Path::new(""));
+	let name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+	SAVE_FILE_DIRS.iter().any(|(save_dir, suffixes)| {
+		dir == Path::new(save_dir) && suffixes.iter().any(|suffix| name.ends_with(suffix))
+	})
+}
+
+/// Returns whether a file is an encrypted save file.
+#[must_use]
+pub fn is_encrypted(path: &Path) -> bool {
+	fs::read(path).is_ok_and(|bytes| bytes.starts_with(ENCRYPTED_MAGIC))
+}
+
+/// Returns whether the save data is encrypted but wasn't unlocked with its
+/// passphrase, in which case it can't be read or written. Browsers have no
+/// file system, so the save data is always locked in [web](crate::core::web)
+/// builds.
+#[must_use]
+pub fn is_locked() -> bool {
+	#[cfg(target_arch = "wasm32")]
+	return true;
+	#[cfg(all(feature = "encryption", not(target_arch = "wasm32")))]
+	return encryption::is_locked();
+	#[cfg(not(any(feature = "encryption", target_arch = "wasm32")))]
+	false
+}
+
+/// Reads a save file, decrypting it if it's encrypted.
+pub fn read(path: &Path) -> anyhow::Result<String> {
+	let bytes = fs::read(path).map_err(|error| AppError::file_access(path, error))?;
+	#[cfg(feature = "encryption")]
+	if let Some(encrypted) = bytes.strip_prefix(ENCRYPTED_MAGIC) {
+		return Ok(String::from_utf8(encryption::decrypt(encrypted)?)?);
+	}
+	if bytes.starts_with(ENCRYPTED_MAGIC) {
+		bail!(AppError::SaveFileEncrypted {
+			path: path.to_path_buf(),
+		});
+	}
+	Ok(String::from_utf8(bytes)?)
+}
+
+/// Writes a save file, encrypting it if encryption is set up. Encrypted files
+/// are never overwritten in plain text.
+pub fn write(path: &Path, contents: &str) -> anyhow::Result<()> {
+	#[cfg(feature = "encryption")]
+	if encryption::is_enabled() {
+		let mut bytes = ENCRYPTED_MAGIC.to_vec();
+		bytes.extend(encryption::encrypt(contents.as_bytes())?);
+		return write_whole(path, &bytes);
+	}
+	if is_encrypted(path) {
+		bail!(AppError::SaveFileEncrypted {
+			path: path.to_path_buf(),
+		});
+	}
+	write_whole(path, contents.as_bytes())
+}
+
+/// Writes a file to a temporary file next to it, then renames it into place,
+/// so that other processes reading it never find it half-written.
+fn write_whole(path: &Path, contents: &[u8]) -> anyhow::Result<()> {
+	static WRITES: AtomicUsize = AtomicUsize::new(0);
+	let mut temporary_path = path.as_os_str().to_owned();
+	temporary_path.push(format!(
+		".{}-{}{TEMPORARY_SUFFIX}",
+		std::process::id(),
+		WRITES.fetch_add(1, Ordering::Relaxed)
+	));
+	fs::write(&temporary_path, contents).map_err(|error| AppError::file_access(path, error))?;
+	fs::rename(&temporary_path, path).map_err(|error| {
+		let _ = fs::remove_file(&temporary_path);
+		AppError::file_access(path, error)
+	})?;
+	Ok(())
+}
+
+/// Locks a save file against other processes, like the connections of the
+/// [telnet server](crate::services::telnet), until the lock returned is
+/// dropped. The lock is taken on a [`LOCK_SUFFIX`] file next to the save file,
+/// waiting for other processes to release it.
+pub fn lock(path: &Path) -> anyhow::Result<File> {
+	let mut lock_path = path.as_os_str().to_owned();
+	lock_path.push(LOCK_SUFFIX);
+	let lock_path = PathBuf::from(lock_path);
+	let file = OpenOptions::new()
+		.create(true)
+		.truncate(false)
+		.write(true)
+		.open(&lock_path)
+		.map_err(|error| AppError::file_access(&lock_path, error))?;
+	file.lock().map_err(|error| AppError::file_access(&lock_path, error))?;
+	Ok(file)
+}
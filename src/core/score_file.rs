@@ -0,0 +1,272 @@
+//! Score files, sharing the player's bests in every game with other players or
+//! carrying them to another profile, written with the `export-scores` command
+//! and read with `import-scores`.
+//!
+//! A score file is JSON, holding each game's high scores and best time along
+//! with a [checksum](checksum) of them, keyed with [`CHECKSUM_KEY`]. Files
+//! whose scores were edited by hand no longer match their checksum, and
+//! aren't imported. The key ships with Terminal Arcade, so this only keeps
+//! honest players honest.
+
+use std::{
+	collections::BTreeMap,
+	fs,
+	path::{
+		Path,
+		PathBuf,
+	},
+};
+
+use anyhow::bail;
+use base64::{
+	engine::general_purpose::STANDARD,
+	Engine,
+};
+use blake2::{
+	digest::{
+		consts::U32,
+		Mac,
+	},
+	Blake2bMac,
+};
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+use crate::{
+	core::{
+		export::export_path,
+		profile::Profile,
+	},
+	games::{
+		get_unix_time_as_secs,
+		modes::GameMode,
+		registry::{
+			self,
+			GameId,
+		},
+		Game,
+		GameDynamicInfo,
+		Games,
+		ScoreRecord,
+	},
+	t,
+};
+
+/// Extension of score files.
+pub const SCORE_FILE_EXTENSION: &str = "scores.json";
+
+/// Key the checksums of score files are computed with.
+const CHECKSUM_KEY: &[u8] = b"terminal-arcade score file v1";
+
+/// A game's bests in a [score file](ScoreFile).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameScores {
+	/// [ID](GameId) of the game.
+	pub game: GameId,
+
+	/// The best score in each mode the game was played in.
+	pub high_scores: BTreeMap<GameMode, i64>,
+
+	/// The fastest time, in seconds.
+	pub best_time_secs: Option<u64>,
+}
+
+impl GameScores {
+	/// Collects a game's bests from its metadata, if it has any.
+	#[must_use]
+	pub fn from_info(game: &GameId, info: &GameDynamicInfo) -> Option<Self> {
+		let high_scores: BTreeMap<_, _> = info
+			.high_score
+			.map(|score| (GameMode::Standard, score))
+			.into_iter()
+			.chain(info.mode_high_scores.iter().map(|(&mode, &score)| (mode, score)))
+			.collect();
+		(!high_scores.is_empty() || info.best_time_secs.is_some()).then(|| Self {
+			game: game.clone(),
+			high_scores,
+			best_time_secs: info.best_time_secs,
+		})
+	}
+
+	/// Merges these bests into a game's metadata, as set at a UNIX timestamp,
+	/// returning how many of them beat the game's own.
+	pub fn merge_into(&self, info: &mut GameDynamicInfo, time: u64) -> usize {
+		let mut improved = 0;
+		for (&mode, &score) in &self.high_scores {
+			if info.high_score_in(mode).is_some_and(|high_score| high_score >= score) {
+				continue;
+			}
+			match mode {
+				GameMode::Standard => info.high_score = Some(score),
+				_ => {
+					info.mode_high_scores.insert(mode, score);
+				},
+			}
+			info.score_history.push(ScoreRecord { time, score, mode });
+			improved += 1;
+		}
+		if let Some(secs) = self.best_time_secs {
+			if info.best_time_secs.is_none_or(|best| secs < best) {
+				info.best_time_secs = Some(secs);
+				improved += 1;
+			}
+		}
+		improved
+	}
+}
+
+/// The scores of a score file, that its checksum is computed from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[must_use]
+pub struct ScoreFile {
+	/// The [profile](Profile) of the player who set the scores.
+	pub player: String,
+
+	/// When the file was written, as a UNIX timestamp.
+	pub exported: u64,
+
+	/// Each game's bests.
+	pub games: Vec<GameScores>,
+}
+
+/// A score file as written, with its checksum.
+#[derive(Debug, Serialize, Deserialize)]
+struct SignedScoreFile {
+	/// The scores.
+	#[serde(flatten)]
+	scores: ScoreFile,
+
+	/// The checksum of the scores, in base64.
+	checksum: String,
+}
+
+/// Returns the checksum of some scores, keyed with [`CHECKSUM_KEY`].
+fn checksum(scores: &ScoreFile) -> anyhow::Result<String> {
+	let mut mac = Blake2bMac::<U32>::new_from_slice(CHECKSUM_KEY)?;
+	mac.update(serde_json::to_string(scores)?.as_bytes());
+	Ok(STANDARD.encode(mac.finalize().into_bytes()))
+}
+
+impl ScoreFile {
+	/// Collects the player's bests in every game.
+	pub fn collect() -> Self {
+		Self {
+			player: Profile::current().badge(),
+			exported: get_unix_time_as_secs(),
+			games: Games::all()
+				.into_iter()
+				.filter_map(|game| {
+					let metadata = game.data().metadata;
+					GameScores::from_info(&metadata.static_info.id, &metadata.dynamic_info)
+				})
+				.collect(),
+		}
+	}
+
+	/// Returns the scores as a score file, with their checksum.
+	pub fn to_json(&self) -> anyhow::Result<String> {
+		let signed = SignedScoreFile {
+			scores: self.clone(),
+			checksum: checksum(self)?,
+		};
+		Ok(serde_json::to_string_pretty(&signed)? + "\n")
+	}
+
+	/// Reads the scores of a score file, checking them against its checksum.
+	///
+	/// # Errors
+	///
+	/// This function returns an error if the file isn't a score file, or if its
+	/// scores don't match its checksum.
+	pub fn from_json(json: &str) -> anyhow::Result<Self> {
+		let signed = serde_json::from_str::<SignedScoreFile>(json)?;
+		if checksum(&signed.scores)? != signed.checksum {
+			bail!(t!("scores-checksum-mismatch"));
+		}
+		Ok(signed.scores)
+	}
+
+	/// Merges the scores into every game's metadata, returning how many bests
+	/// they beat. Games that aren't installed are left out.
+	pub fn import(&self) -> anyhow::Result<usize> {
+		let mut improved = 0;
+		for scores in self.games.iter().filter(|scores| registry::get(&scores.game).is_some()) {
+			improved += GameDynamicInfo::update(&scores.game, |info| {
+				scores.merge_into(info, self.exported)
+			})?;
+		}
+		Ok(improved)
+	}
+}
+
+/// Gets the path of a new score file in the save directory's `exports` folder.
+#[must_use]
+pub fn default_score_file_path() -> PathBuf {
+	export_path("scores", SCORE_FILE_EXTENSION)
+}
+
+/// Writes the player's bests in every game to a score file.
+pub fn export(path: &Path) -> anyhow::Result<()> {
+	let json = ScoreFile::collect().to_json()?;
+	if let Some(parent) = path.parent() {
+		fs::create_dir_all(parent)?;
+	}
+	Ok(fs::write(path, json)?)
+}
+
+/// Reads a score file and merges its scores into every game's metadata,
+/// returning its scores and how many bests they beat.
+pub fn import(path: &Path) -> anyhow::Result<(ScoreFile, usize)> {
+	let scores = ScoreFile::from_json(&fs::read_to_string(path)?)?;
+	let improved = scores.import()?;
+	Ok((scores, improved))
+}
+
+#[cfg(test)]
+mod tests {
+	use std::collections::BTreeMap;
+
+	use super::{
+		GameScores,
+		ScoreFile,
+	};
+	use crate::games::{
+		modes::GameMode,
+		registry::GameId,
+		GameDynamicInfo,
+	};
+
+	#[test]
+	fn checks_and_merges_score_files() {
+		let scores = ScoreFile {
+			player: "🙂 Ada".to_string(),
+			exported: 1_700_000_000,
+			games: vec![GameScores {
+				game: GameId::from("tron"),
+				high_scores: BTreeMap::from([(GameMode::Standard, 40), (GameMode::Hardcore, 5)]),
+				best_time_secs: Some(90),
+			}],
+		};
+		let json = scores.to_json().unwrap();
+		assert_eq!(ScoreFile::from_json(&json).unwrap(), scores);
+		assert!(
+			ScoreFile::from_json(&json.replace("\"standard\": 40", "\"standard\": 4000")).is_err()
+		);
+
+		let mut info = GameDynamicInfo {
+			high_score: Some(50),
+			best_time_secs: Some(100),
+			..Default::default()
+		};
+		assert_eq!(scores.games[0].merge_into(&mut info, scores.exported), 2);
+		assert_eq!(info.high_score, Some(50));
+		assert_eq!(info.high_score_in(GameMode::Hardcore), Some(5));
+		assert_eq!(info.best_time_secs, Some(90));
+		assert_eq!(
+			GameScores::from_info(&GameId::from("tron"), &info).unwrap().high_scores,
+			BTreeMap::from([(GameMode::Standard, 50), (GameMode::Hardcore, 5)])
+		);
+	}
+}
@@ -0,0 +1,231 @@
+//! Screenshots of the screens as text, saved in the save directory's
+//! [`SCREENSHOT_DIR`] folder with \[F8\] from any screen, to share a board or
+//! attach to a bug report.
+//!
+//! Each screenshot is saved as plain text, and also as text colored with ANSI
+//! escape codes, that `cat` shows in color, if
+//! [`Config::screenshot_ansi`] is on. A notice in the border of screens
+//! [tells](indicator) where it was saved.
+
+use std::{
+	fs,
+	path::PathBuf,
+	sync::Mutex,
+	time::Duration,
+};
+
+use ratatui::{
+	buffer::{
+		Buffer,
+		Cell,
+	},
+	style::{
+		Color,
+		Modifier,
+	},
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+	core::{
+		config::Config,
+		get_save_dir,
+		time::{
+			Instant,
+			SystemTime,
+			UNIX_EPOCH,
+		},
+	},
+	t,
+};
+
+/// Name of the folder of the save directory that screenshots are saved in.
+pub const SCREENSHOT_DIR: &str = "screenshots";
+
+/// How long the notice of a screenshot is shown after it's taken.
+const INDICATOR_DURATION: Duration = Duration::from_secs(4);
+
+/// Modifiers written in colored screenshots, with their ANSI codes.
+const MODIFIER_CODES: [(Modifier, &str); 6] = [
+	(Modifier::BOLD, "1"),
+	(Modifier::DIM, "2"),
+	(Modifier::ITALIC, "3"),
+	(Modifier::UNDERLINED, "4"),
+	(Modifier::REVERSED, "7"),
+	(Modifier::CROSSED_OUT, "9"),
+];
+
+/// When the last screenshot was taken, and where it was saved or why it
+/// couldn't be.
+static LAST_SCREENSHOT: Mutex<Option<(Instant, anyhow::Result<PathBuf>)>> = Mutex::new(None);
+
+/// Gets the directory screenshots are saved in.
+#[must_use]
+pub fn screenshot_dir() -> PathBuf {
+	get_save_dir().join(SCREENSHOT_DIR)
+}
+
+/// Returns the rows of a buffer, each cell with its symbol. Cells hidden by
+/// the wide symbol before them are left out.
+fn rows(buffer: &Buffer) -> impl Iterator<Item = Vec<&Cell>> {
+	buffer.content.chunks(usize::from(buffer.area.width).max(1)).map(|row| {
+		let mut cells = Vec::new();
+		let mut skipped = 0;
+		for cell in row {
+			if skipped > 0 {
+				skipped -= 1;
+				continue;
+			}
+			skipped = cell.symbol().width().saturating_sub(1);
+			cells.push(cell);
+		}
+		cells
+	})
+}
+
+/// Returns the text of a buffer, line by line, without trailing spaces.
+/// Styles are left out.
+#[must_use]
+pub fn buffer_lines(buffer: &Buffer) -> Vec<String> {
+	rows(buffer)
+		.map(|cells| {
+			cells.iter().map(|cell| cell.symbol()).collect::<String>().trim_end().to_string()
+		})
+		.collect()
+}
+
+/// Returns the ANSI code of a color, in the foreground or the background,
+/// or `None` for the terminal's default one.
+fn color_code(color: Color, foreground: bool) -> Option<String> {
+	let (normal, bright, extended) = if foreground { (30, 90, 38) } else { (40, 100, 48) };
+	let code = match color {
+		Color::Reset => return None,
+		Color::Black => normal,
+		Color::Red => normal + 1,
+		Color::Green => normal + 2,
+		Color::Yellow => normal + 3,
+		Color::Blue => normal + 4,
+		Color::Magenta => normal + 5,
+		Color::Cyan => normal + 6,
+		Color::Gray => normal + 7,
+		Color::DarkGray => bright,
+		Color::LightRed => bright + 1,
+		Color::LightGreen => bright + 2,
+		Color::LightYellow => bright + 3,
+		Color::LightBlue => bright + 4,
+		Color::LightMagenta => bright + 5,
+		Color::LightCyan => bright + 6,
+		Color::White => bright + 7,
+		Color::Indexed(index) => return Some(format!("{extended};5;{index}")),
+		Color::Rgb(red, green, blue) => return Some(format!("{extended};2;{red};{green};{blue}")),
+	};
+	Some(code.to_string())
+}
+
+/// Returns the ANSI escape code switching to a cell's style.
+#[must_use]
+pub fn style_code(cell: &Cell) -> String {
+	let codes = std::iter::once("0".to_string())
+		.chain(color_code(cell.fg, true))
+		.chain(color_code(cell.bg, false))
+		.chain(
+			MODIFIER_CODES
+				.into_iter()
+				.filter(|(modifier, _)| cell.modifier.contains(*modifier))
+				.map(|(_, code)| code.to_string()),
+		)
+		.collect::<Vec<_>>();
+	format!("\x1b[{}m", codes.join(";"))
+}
+
+/// Returns the lines of a buffer with their colors and modifiers as ANSI
+/// escape codes, each ending with them reset.
+#[must_use]
+pub fn buffer_ansi_lines(buffer: &Buffer) -> Vec<String> {
+	rows(buffer)
+		.map(|cells| {
+			let mut line = String::new();
+			let mut style = None;
+			for cell in cells {
+				let cell_style = (cell.fg, cell.bg, cell.modifier);
+				if style != Some(cell_style) {
+					line.push_str(&style_code(cell));
+					style = Some(cell_style);
+				}
+				line.push_str(cell.symbol());
+			}
+			line + "\x1b[0m"
+		})
+		.collect()
+}
+
+/// Returns the text of a buffer with its colors and modifiers as ANSI escape
+/// codes. See [`buffer_ansi_lines`].
+#[must_use]
+pub fn buffer_ansi(buffer: &Buffer) -> String {
+	buffer_ansi_lines(buffer).into_iter().map(|line| line + "\n").collect()
+}
+
+/// Saves a screenshot of a buffer, and returns the path of its plain text.
+fn save(buffer: &Buffer) -> anyhow::Result<PathBuf> {
+	let dir = screenshot_dir();
+	fs::create_dir_all(&dir)?;
+	let taken = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
+	let path = dir.join(format!("screenshot-{taken}.txt"));
+	fs::write(&path, buffer_lines(buffer).join("\n") + "\n")?;
+	if Config::load_or_default().screenshot_ansi {
+		fs::write(path.with_extension("ans"), buffer_ansi(buffer))?;
+	}
+	Ok(path)
+}
+
+/// Saves a screenshot of a buffer, to be [shown](indicator) in the border of
+/// screens.
+pub fn take(buffer: &Buffer) {
+	let result = save(buffer);
+	if let Ok(mut last_screenshot) = LAST_SCREENSHOT.lock() {
+		*last_screenshot = Some((Instant::now(), result));
+	}
+}
+
+/// Returns the text shown in the screen's border for a moment after a
+/// screenshot, if one was just taken.
+#[must_use]
+pub fn indicator() -> Option<String> {
+	let last_screenshot = LAST_SCREENSHOT.lock().ok()?;
+	let (time, result) = last_screenshot.as_ref()?;
+	(time.elapsed() < INDICATOR_DURATION).then(|| match result {
+		Ok(path) => t!("screenshot-saved", path = path.display().to_string()),
+		Err(error) => t!("screenshot-failed", error = error.to_string()),
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use ratatui::{
+		buffer::Buffer,
+		layout::Rect,
+		style::{
+			Color,
+			Style,
+			Stylize,
+		},
+	};
+
+	use super::{
+		buffer_ansi,
+		buffer_lines,
+	};
+
+	#[test]
+	fn buffers_are_written_as_text() {
+		let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+		buffer.set_string(0, 0, "🙂 Ada", Style::new().fg(Color::Red).bold());
+		buffer.set_string(0, 1, "ok", Style::new());
+		assert_eq!(buffer_lines(&buffer), ["🙂 Ada", "ok"]);
+		assert_eq!(
+			buffer_ansi(&buffer),
+			"\x1b[0;31;1m🙂 Ada\x1b[0m\n\x1b[0mok    \x1b[0m\n"
+		);
+	}
+}
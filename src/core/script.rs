@@ -0,0 +1,276 @@
+//! Scripted input, played back with the `--script <FILE>` argument as if typed
+//! on the keyboard. Useful for demos, reproducing bugs and end-to-end tests.
+//!
+//! A script has one step per line: how long to wait after the previous step,
+//! then the input to play. Empty lines and lines starting with `#` are ignored.
+//!
+//! ```text
+//! # Opens the game selection screen and picks the second game.
+//! 1s key enter
+//! 200ms key down
+//! 200ms key enter
+//! 500ms type hello
+//! 100ms key ctrl+c
+//! ```
+//!
+//! The inputs are:
+//! - `key <KEY>`: presses a key, like `a`, `enter`, `esc`, `tab`, `backtab`,
+//!   `backspace`, `delete`, `insert`, `space`, `up`, `down`, `left`, `right`,
+//!   `home`, `end`, `pageup`, `pagedown` or `f1` to `f12`, optionally with
+//!   `ctrl+`, `alt+` and `shift+` in front.
+//! - `type <TEXT>`: types every character of the text at once.
+//! - `paste <TEXT>`: pastes the text.
+//! - `resize <COLUMNS> <ROWS>`: resizes the terminal.
+//!
+//! Keyboard input still works while a script plays, and once it's done. Input
+//! can also be written back as a script with [`format_event`], which is how
+//! [bug reports](crate::core::bug_report) record the last inputs played.
+
+use std::{
+	collections::VecDeque,
+	fs,
+	path::Path,
+	time::Duration,
+};
+
+use anyhow::{
+	anyhow,
+	bail,
+	Context,
+};
+
+use crate::core::{
+	events::{
+		Event,
+		KeyCode,
+		KeyEvent,
+		KeyEventKind,
+		KeyModifiers,
+	},
+	time::Instant,
+};
+
+/// A step of a [script](Script).
+#[derive(Debug, Clone)]
+pub struct ScriptStep {
+	/// How long to wait after the previous step.
+	pub delay: Duration,
+
+	/// The input played.
+	pub event: Event,
+}
+
+/// Input played back over time. See the [module](self) documentation for more
+/// information.
+#[derive(Debug, Clone, Default)]
+#[must_use]
+pub struct Script {
+	/// The steps left to play.
+	steps: VecDeque<ScriptStep>,
+
+	/// When the last step was played, or when the script started.
+	last_step_at: Option<Instant>,
+}
+
+impl Script {
+	/// Loads a script from a file.
+	pub fn load(path: &Path) -> anyhow::Result<Self> {
+		let contents = fs::read_to_string(path)
+			.with_context(|| format!("Couldn't read the script at {}", path.display()))?;
+		Self::parse(&contents).with_context(|| format!("Invalid script at {}", path.display()))
+	}
+
+	/// Parses a script.
+	pub fn parse(script: &str) -> anyhow::Result<Self> {
+		let mut steps = VecDeque::new();
+		for (index, line) in script.lines().enumerate() {
+			let line = line.trim();
+			if line.is_empty() || line.starts_with('#') {
+				continue;
+			}
+			steps.extend(Self::parse_line(line).with_context(|| format!("On line {}", index + 1))?);
+		}
+		Ok(Self {
+			steps,
+			last_step_at: None,
+		})
+	}
+
+	/// Parses a line into the steps it plays.
+	fn parse_line(line: &str) -> anyhow::Result<Vec<ScriptStep>> {
+		let (delay, input) = line.split_once(' ').ok_or_else(|| anyhow!("Missing input"))?;
+		let delay = humantime::parse_duration(delay)?;
+		let (kind, argument) = input.trim_start().split_once(' ').unwrap_or((input, ""));
+		let events = match kind {
+			"key" => vec![Event::Key(parse_key(argument.trim())?)],
+			"type" => argument
+				.chars()
+				.map(|char| Event::Key(KeyEvent::new(KeyCode::Char(char), KeyModifiers::NONE)))
+				.collect(),
+			"paste" => vec![Event::Paste(argument.to_string())],
+			"resize" => {
+				let size =
+					argument.split_whitespace().map(str::parse).collect::<Result<Vec<u16>, _>>()?;
+				let [columns, rows] = size[..] else {
+					bail!("Resizing needs a number of columns and rows");
+				};
+				vec![Event::Resize(columns, rows)]
+			},
+			_ => bail!("Unknown input {kind}"),
+		};
+		Ok(events
+			.into_iter()
+			.enumerate()
+			.map(|(index, event)| ScriptStep {
+				delay: if index == 0 { delay } else { Duration::ZERO },
+				event,
+			})
+			.collect())
+	}
+
+	/// Returns the next input once it's time to play it.
+	pub fn poll(&mut self) -> Option<Event> {
+		let last_step_at = *self.last_step_at.get_or_insert_with(Instant::now);
+		if last_step_at.elapsed() < self.steps.front()?.delay {
+			return None;
+		}
+		self.last_step_at = Some(Instant::now());
+		self.steps.pop_front().map(|step| step.event)
+	}
+
+	/// Returns whether every step was played.
+	#[must_use]
+	pub fn is_finished(&self) -> bool {
+		self.steps.is_empty()
+	}
+}
+
+/// Parses a key, like `q`, `enter` or `ctrl+c`.
+pub fn parse_key(key: &str) -> anyhow::Result<KeyEvent> {
+	let mut modifiers = KeyModifiers::NONE;
+	let mut name = key;
+	while let Some((modifier, rest)) = name.split_once('+').filter(|(_, rest)| !rest.is_empty()) {
+		modifiers |= match modifier.to_lowercase().as_str() {
+			"ctrl" | "control" => KeyModifiers::CONTROL,
+			"alt" => KeyModifiers::ALT,
+			"shift" => KeyModifiers::SHIFT,
+			_ => bail!("Unknown modifier {modifier}"),
+		};
+		name = rest;
+	}
+	let mut chars = name.chars();
+	let code = match (chars.next(), chars.next()) {
+		(Some(char), None) => KeyCode::Char(char),
+		_ => match name.to_lowercase().as_str() {
+			"enter" | "return" => KeyCode::Enter,
+			"esc" | "escape" => KeyCode::Esc,
+			"tab" => KeyCode::Tab,
+			"backtab" => KeyCode::BackTab,
+			"backspace" => KeyCode::Backspace,
+			"delete" | "del" => KeyCode::Delete,
+			"insert" => KeyCode::Insert,
+			"space" => KeyCode::Char(' '),
+			"up" => KeyCode::Up,
+			"down" => KeyCode::Down,
+			"left" => KeyCode::Left,
+			"right" => KeyCode::Right,
+			"home" => KeyCode::Home,
+			"end" => KeyCode::End,
+			"pageup" => KeyCode::PageUp,
+			"pagedown" => KeyCode::PageDown,
+			lower => match lower.strip_prefix('f').and_then(|number| number.parse().ok()) {
+				Some(number @ 1..=12) => KeyCode::F(number),
+				_ => bail!("Unknown key {name}"),
+			},
+		},
+	};
+	Ok(KeyEvent::new(code, modifiers))
+}
+
+/// Writes an event as the input of a script step, like `key ctrl+c`, or
+/// returns [`None`] if scripts can't play it, like mouse events or key
+/// releases.
+#[must_use]
+pub fn format_event(event: &Event) -> Option<String> {
+	match event {
+		Event::Key(key) if key.kind == KeyEventKind::Press => {
+			Some(format!("key {}", format_key(key)?))
+		},
+		Event::Paste(text) if !text.contains('\n') => Some(format!("paste {text}")),
+		Event::Resize(columns, rows) => Some(format!("resize {columns} {rows}")),
+		_ => None,
+	}
+}
+
+/// Writes a key the way [`parse_key`] reads it, or returns [`None`] if it has
+/// no name in scripts.
+#[must_use]
+pub fn format_key(key: &KeyEvent) -> Option<String> {
+	let name = match key.code {
+		KeyCode::Char(' ') => "space".to_string(),
+		KeyCode::Char(char) => char.to_string(),
+		KeyCode::Enter => "enter".to_string(),
+		KeyCode::Esc => "esc".to_string(),
+		KeyCode::Tab => "tab".to_string(),
+		KeyCode::BackTab => "backtab".to_string(),
+		KeyCode::Backspace => "backspace".to_string(),
+		KeyCode::Delete => "delete".to_string(),
+		KeyCode::Insert => "insert".to_string(),
+		KeyCode::Up => "up".to_string(),
+		KeyCode::Down => "down".to_string(),
+		KeyCode::Left => "left".to_string(),
+		KeyCode::Right => "right".to_string(),
+		KeyCode::Home => "home".to_string(),
+		KeyCode::End => "end".to_string(),
+		KeyCode::PageUp => "pageup".to_string(),
+		KeyCode::PageDown => "pagedown".to_string(),
+		KeyCode::F(number @ 1..=12) => format!("f{number}"),
+		_ => return None,
+	};
+	let modifiers = [
+		(KeyModifiers::CONTROL, "ctrl+"),
+		(KeyModifiers::ALT, "alt+"),
+		(KeyModifiers::SHIFT, "shift+"),
+	]
+	.into_iter()
+	.filter(|(modifier, _)| key.modifiers.contains(*modifier))
+	.map(|(_, prefix)| prefix)
+	.collect::<String>();
+	Some(format!("{modifiers}{name}"))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		format_event,
+		parse_key,
+	};
+	use crate::core::events::{
+		Event,
+		KeyCode,
+		KeyEvent,
+		KeyModifiers,
+	};
+
+	#[test]
+	fn formatted_keys_parse_back() {
+		let keys = [
+			KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE),
+			KeyEvent::new(KeyCode::Char('+'), KeyModifiers::CONTROL),
+			KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE),
+			KeyEvent::new(KeyCode::Char('A'), KeyModifiers::SHIFT),
+			KeyEvent::new(KeyCode::Enter, KeyModifiers::CONTROL | KeyModifiers::ALT),
+			KeyEvent::new(KeyCode::F(12), KeyModifiers::NONE),
+		];
+		for key in keys {
+			let input = format_event(&Event::Key(key)).unwrap();
+			let parsed = parse_key(input.strip_prefix("key ").unwrap()).unwrap();
+			assert_eq!(parsed, key, "{input}");
+		}
+		assert_eq!(
+			format_event(&Event::Resize(80, 24)).as_deref(),
+			Some("resize 80 24")
+		);
+		assert_eq!(format_event(&Event::FocusGained), None);
+	}
+}
@@ -0,0 +1,104 @@
+//! Recent searches of the [game selection
+//! screen](crate::ui::screens::GameSearchScreen), recalled with the up arrow
+//! while the search bar is empty, like a shell's history.
+//!
+//! A search is remembered once a result it found is opened, in the [save
+//! directory](get_save_dir)'s [`SEARCH_HISTORY_FILE`]. Up to
+//! [`MAX_RECENT_SEARCHES`] are kept, searching again for a term moving it back
+//! to the top.
+
+use std::path::PathBuf;
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+use crate::core::get_save_dir;
+
+/// Name of the file recent searches are saved in, in the [save
+/// directory](get_save_dir).
+pub const SEARCH_HISTORY_FILE: &str = "search_history.toml";
+
+/// How many recent searches are kept, the oldest being dropped first.
+pub const MAX_RECENT_SEARCHES: usize = 10;
+
+/// Gets the path of the search history file.
+#[must_use]
+pub fn search_history_path() -> PathBuf {
+	get_save_dir().join(SEARCH_HISTORY_FILE)
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+#[must_use]
+pub struct SearchHistory {
+	/// The terms searched, most recent first.
+	terms: Vec<String>,
+}
+
+impl SearchHistory {
+	/// Loads the search history.
+	pub fn load() -> anyhow::Result<Self> {
+		let history_file = std::fs::read_to_string(search_history_path())?;
+		Ok(toml::from_str::<Self>(&history_file)?)
+	}
+
+	/// Saves the search history, in TOML format.
+	pub fn save(&self) -> anyhow::Result<()> {
+		let toml_string = toml::to_string_pretty(self)?;
+		std::fs::create_dir_all(get_save_dir())?;
+		Ok(std::fs::write(search_history_path(), toml_string)?)
+	}
+
+	/// Loads the search history, or returns an empty one if it could not be
+	/// loaded.
+	pub fn load_or_default() -> Self {
+		Self::load().unwrap_or_default()
+	}
+
+	/// Remembers a term searched, as the most recent one. Blank terms aren't
+	/// remembered, and terms differing only by case are remembered once.
+	pub fn record(&mut self, term: &str) {
+		let term = term.trim();
+		if term.is_empty() {
+			return;
+		}
+		let lowercase = term.to_lowercase();
+		self.terms.retain(|recent| recent.to_lowercase() != lowercase);
+		self.terms.insert(0, term.to_string());
+		self.terms.truncate(MAX_RECENT_SEARCHES);
+	}
+
+	/// Returns a recent term, counting from the most recent one.
+	#[must_use]
+	pub fn get(&self, index: usize) -> Option<&str> {
+		self.terms.get(index).map(String::as_str)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		SearchHistory,
+		MAX_RECENT_SEARCHES,
+	};
+
+	#[test]
+	fn keeps_recent_terms_once() {
+		let mut history = SearchHistory::default();
+		history.record("tron");
+		history.record(" mines ");
+		history.record("   ");
+		history.record("Tron");
+		assert_eq!(history.get(0), Some("Tron"));
+		assert_eq!(history.get(1), Some("mines"));
+		assert_eq!(history.get(2), None);
+		for number in 0..MAX_RECENT_SEARCHES {
+			history.record(&number.to_string());
+		}
+		assert_eq!(history.get(0), Some("9"));
+		assert_eq!(history.get(MAX_RECENT_SEARCHES), None);
+	}
+}
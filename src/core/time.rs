@@ -0,0 +1,17 @@
+//! The clock. Native builds use std's, which panics when asked the time in
+//! browsers, so [web](crate::core::web) builds use [`web_time`]'s, which asks
+//! the browser instead.
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use std::time::{
+	Instant,
+	SystemTime,
+	UNIX_EPOCH,
+};
+
+#[cfg(target_arch = "wasm32")]
+pub use web_time::{
+	Instant,
+	SystemTime,
+	UNIX_EPOCH,
+};
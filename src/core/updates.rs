@@ -0,0 +1,169 @@
+//! Checks for new releases of Terminal Arcade on GitHub.
+//!
+//! The check is opt-in through [`Config::check_for_updates`], and only compiled
+//! in with the `update-check` cargo feature. It runs on a background thread so
+//! that it never blocks the UI, and its result is cached in the save directory
+//! for a day so that GitHub isn't asked on every startup.
+
+use std::{
+	path::PathBuf,
+	sync::{
+		Arc,
+		Mutex,
+	},
+};
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+#[cfg(feature = "update-check")]
+use crate::{
+	core::config::Config,
+	games::get_unix_time_as_secs,
+};
+use crate::{
+	core::get_save_dir,
+	t,
+};
+
+/// The GitHub API endpoint for Terminal Arcade's latest release.
+pub const LATEST_RELEASE_URL: &str =
+	"https://api.github.com/repos/developer-ramen/terminal-arcade/releases/latest";
+
+/// How long a cached update check stays fresh, in seconds.
+pub const CACHE_LIFETIME_SECS: u64 = 24 * 60 * 60;
+
+/// Gets the path of the update check cache file.
+#[must_use]
+pub fn cache_file_path() -> PathBuf {
+	get_save_dir().join("update_check.toml")
+}
+
+/// Parses a version string such as `v1.2.3` into its numeric components.
+#[must_use]
+pub fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+	let mut parts = version.trim().trim_start_matches('v').split('.');
+	let mut next_part = || parts.next()?.parse::<u64>().ok();
+	Some((next_part()?, next_part()?, next_part().unwrap_or(0)))
+}
+
+/// Returns whether `version` is newer than the version of this build.
+#[must_use]
+pub fn is_newer_than_current(version: &str) -> bool {
+	match (
+		parse_version(version),
+		parse_version(env!("CARGO_PKG_VERSION")),
+	) {
+		(Some(version), Some(current)) => version > current,
+		_ => false,
+	}
+}
+
+/// The cached result of the last update check.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[must_use]
+pub struct UpdateCheckCache {
+	/// UNIX timestamp of the last check.
+	pub last_checked: u64,
+
+	/// The latest released version found during the last check.
+	pub latest_version: Option<String>,
+}
+
+impl UpdateCheckCache {
+	/// Loads the cache.
+	pub fn load() -> anyhow::Result<Self> {
+		let cache_file = std::fs::read_to_string(cache_file_path())?;
+		Ok(toml::from_str::<Self>(&cache_file)?)
+	}
+
+	/// Saves the cache, in TOML format.
+	pub fn save(&self) -> anyhow::Result<()> {
+		let toml_string = toml::to_string_pretty(self)?;
+		std::fs::create_dir_all(get_save_dir())?;
+		Ok(std::fs::write(cache_file_path(), toml_string)?)
+	}
+}
+
+/// A notice of a newer version, filled in by a background update check.
+/// Cloning this struct shares the underlying notice.
+#[derive(Debug, Clone, Default)]
+#[must_use]
+pub struct UpdateNotice(Arc<Mutex<Option<String>>>);
+
+impl UpdateNotice {
+	/// Spawns a background update check if enabled in the configuration,
+	/// returning the notice that it fills in once a newer version is found.
+	pub fn spawn_check() -> Self {
+		let notice = Self::default();
+		#[cfg(feature = "update-check")]
+		if Config::load_or_default().check_for_updates {
+			let shared_notice = notice.clone();
+			std::thread::spawn(move || {
+				if let Ok(Some(version)) = fetch_latest_version_cached() {
+					if is_newer_than_current(&version) {
+						shared_notice.set(version);
+					}
+				}
+			});
+		}
+		notice
+	}
+
+	/// Sets the newer version found.
+	pub fn set(&self, version: String) {
+		if let Ok(mut notice) = self.0.lock() {
+			*notice = Some(version);
+		}
+	}
+
+	/// Returns the notice text to be displayed, if a newer version was found.
+	#[must_use]
+	pub fn get_text(&self) -> Option<String> {
+		let version = self.0.lock().ok()?.clone()?;
+		Some(t!("welcome-update-available", version = version))
+	}
+}
+
+/// A release, as returned by the GitHub API. Only the needed fields are
+/// included.
+#[cfg(feature = "update-check")]
+#[derive(Deserialize)]
+struct Release {
+	/// The release's tag, which is its version.
+	tag_name: String,
+}
+
+/// Fetches the latest released version from GitHub.
+#[cfg(feature = "update-check")]
+fn fetch_latest_version() -> anyhow::Result<String> {
+	let release: Release = ureq::get(LATEST_RELEASE_URL)
+		.set(
+			"User-Agent",
+			concat!("terminal-arcade/", env!("CARGO_PKG_VERSION")),
+		)
+		.call()?
+		.into_json()?;
+	Ok(release.tag_name)
+}
+
+/// Returns the latest released version, from the cache if it is fresh enough
+/// or from GitHub otherwise (updating the cache).
+#[cfg(feature = "update-check")]
+fn fetch_latest_version_cached() -> anyhow::Result<Option<String>> {
+	let now = get_unix_time_as_secs();
+	if let Ok(cache) = UpdateCheckCache::load() {
+		if now.saturating_sub(cache.last_checked) < CACHE_LIFETIME_SECS {
+			return Ok(cache.latest_version);
+		}
+	}
+	let latest_version = fetch_latest_version()?;
+	UpdateCheckCache {
+		last_checked: now,
+		latest_version: Some(latest_version.clone()),
+	}
+	.save()?;
+	Ok(Some(latest_version))
+}
@@ -0,0 +1,162 @@
+//! A watchdog noticing when frames stop being drawn while the arcade runs,
+//! like when a terminal is wedged over a flaky SSH connection and writing to
+//! it blocks.
+//!
+//! The [handler](crate::core::Handler) [beats](Watchdog::beat) after every
+//! frame it draws. Static screens are still drawn every
+//! [`STATIC_REDRAW_INTERVAL`], so a running arcade never goes
+//! [`STALL_TIMEOUT`] without beating. When it does, the watchdog's thread logs
+//! what it can find out about the terminal to the [error log](error), and asks
+//! the handler to reinitialize the terminal. The watchdog can't unblock the
+//! write drawing is stuck in, so this only happens once that write returns:
+//! the terminal rules are then set again and the whole screen is redrawn, in
+//! case the terminal lost them meanwhile. A terminal that stays wedged is
+//! never recovered.
+//!
+//! [`STATIC_REDRAW_INTERVAL`]: crate::core::render_stats::STATIC_REDRAW_INTERVAL
+
+use std::{
+	io::IsTerminal,
+	sync::{
+		atomic::{
+			AtomicBool,
+			Ordering,
+		},
+		Arc,
+		Mutex,
+		Weak,
+	},
+	thread,
+	time::Duration,
+};
+
+use crate::{
+	core::{
+		error::{
+			self,
+			AppError,
+			ErrorReport,
+		},
+		time::Instant,
+	},
+	t,
+};
+
+/// How long frames can go without being drawn before drawing counts as
+/// stalled.
+pub const STALL_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How often the watchdog's thread checks on the frames.
+const CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// What the handler and the watchdog's thread share.
+#[derive(Debug, Default)]
+struct WatchdogState {
+	/// When the last frame was drawn, while the arcade runs.
+	last_beat: Mutex<Option<Instant>>,
+
+	/// Whether drawing stalled since the terminal was last reinitialized.
+	stalled: AtomicBool,
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Debug, Clone, Default)]
+pub struct Watchdog {
+	/// What's shared with the watchdog's thread.
+	state: Arc<WatchdogState>,
+}
+
+impl Watchdog {
+	/// Starts watching the frames, from a thread that stops once every handle
+	/// to the watchdog is dropped.
+	pub fn start(&self) {
+		self.beat();
+		let state = Arc::downgrade(&self.state);
+		thread::spawn(move || {
+			while let Some(state) = Weak::upgrade(&state) {
+				let watchdog = Self { state };
+				if let Some(stalled_for) = watchdog.check(Instant::now()) {
+					report_stall(stalled_for);
+				}
+				drop(watchdog);
+				thread::sleep(CHECK_INTERVAL);
+			}
+		});
+	}
+
+	/// Stops watching the frames, like when the arcade quits.
+	pub fn stop(&self) {
+		*self.state.last_beat.lock().expect("The watchdog never panics") = None;
+	}
+
+	/// Tells the watchdog a frame was drawn.
+	pub fn beat(&self) {
+		*self.state.last_beat.lock().expect("The watchdog never panics") = Some(Instant::now());
+	}
+
+	/// Returns whether drawing stalled since the last call, in which case the
+	/// terminal should be reinitialized.
+	#[must_use]
+	pub fn take_stall(&self) -> bool {
+		self.state.stalled.swap(false, Ordering::Relaxed)
+	}
+
+	/// Returns how long no frame was drawn for if drawing just stalled, as of
+	/// some instant. A stall is only noticed once until the next
+	/// [`take_stall`](Self::take_stall).
+	fn check(&self, now: Instant) -> Option<Duration> {
+		let last_beat = (*self.state.last_beat.lock().expect("The watchdog never panics"))?;
+		let stalled_for = now.saturating_duration_since(last_beat);
+		if stalled_for < STALL_TIMEOUT || self.state.stalled.swap(true, Ordering::Relaxed) {
+			return None;
+		}
+		Some(stalled_for)
+	}
+}
+
+/// Logs what can be found out about the terminal once drawing stalled,
+/// without writing to it, since that may be what blocks.
+fn report_stall(stalled_for: Duration) {
+	let size = match crossterm::terminal::size() {
+		Ok((width, height)) => format!("{width}×{height}"),
+		Err(error) => error.to_string(),
+	};
+	let terminal = if std::io::stdout().is_terminal() {
+		size
+	} else {
+		t!("doctor-not-a-terminal").to_string()
+	};
+	let error = AppError::RenderStalled {
+		seconds: stalled_for.as_secs(),
+		terminal,
+	};
+	// There's nowhere else to tell about it.
+	let _ = error::log(&ErrorReport::new(&error.into()));
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::Instant;
+
+	use super::{
+		Watchdog,
+		STALL_TIMEOUT,
+	};
+
+	#[test]
+	fn notices_stalls_once() {
+		let watchdog = Watchdog::default();
+		let later = Instant::now() + STALL_TIMEOUT * 2;
+		assert_eq!(watchdog.check(later), None);
+
+		watchdog.beat();
+		assert!(watchdog.check(Instant::now()).is_none());
+		assert!(watchdog.check(later).is_some());
+		assert!(watchdog.check(later).is_none());
+		assert!(watchdog.take_stall());
+		assert!(!watchdog.take_stall());
+
+		watchdog.stop();
+		assert!(watchdog.check(later).is_none());
+	}
+}
@@ -0,0 +1,317 @@
+//! The arcade in a browser, built for the wasm32 target. Screens run in the
+//! [harness](Harness) like in tests, drawing on an [xterm.js](https://xtermjs.org)
+//! terminal through an [`XtermBackend`], and the page feeds them its keys and
+//! asks for a frame every time the browser paints one. The page, with the
+//! JavaScript glue, is in the repository's `web` folder, and expects the
+//! arcade built into its `pkg` folder with:
+//!
+//! ```sh
+//! cargo rustc --lib --release --target wasm32-unknown-unknown --crate-type cdylib \
+//!     --no-default-features --features minesweeper,twenty-forty-eight,sudoku,chess,klondike
+//! wasm-bindgen --target web --out-dir web/pkg \
+//!     target/wasm32-unknown-unknown/release/terminal_arcade.wasm
+//! ```
+//!
+//! Browsers have no terminal to set up, no file system, no threads and no
+//! sockets, so this build leaves out what needs them: the terminal
+//! [handler](crate::core::handler), the IPC socket, telnet, syncing,
+//! scheduled backups, update checks, networked games like Tron, the global
+//! leaderboard, scripted games and images. The save data is always
+//! [locked](crate::core::save_file::is_locked), so nothing is saved and games
+//! start afresh with every page load.
+
+use std::{
+	fmt::Write,
+	io,
+	panic,
+};
+
+use ratatui::{
+	backend::{
+		Backend,
+		ClearType,
+		WindowSize,
+	},
+	buffer::Cell,
+	layout::{
+		Rect,
+		Size,
+	},
+	Terminal,
+};
+use wasm_bindgen::prelude::*;
+
+use crate::{
+	core::{
+		events::{
+			Event,
+			KeyCode,
+			KeyEvent,
+			KeyModifiers,
+		},
+		harness::{
+			Harness,
+			HarnessBackend,
+		},
+		i18n,
+		screenshot,
+	},
+	services::stats,
+	ui::WelcomeScreen,
+};
+
+#[cfg(any(
+	feature = "networking",
+	feature = "telnet",
+	feature = "update-check",
+	feature = "global-leaderboard"
+))]
+compile_error!(
+	"Browsers can't open sockets: build for them without the `networking`, `telnet`, \
+	 `update-check` and `global-leaderboard` features"
+);
+
+#[cfg(any(feature = "scripting", feature = "images"))]
+compile_error!(
+	"Browsers have no file system to load scripted games from, and their terminal can't be asked \
+	 about graphics: build for them without the `scripting` and `images` features"
+);
+
+#[wasm_bindgen]
+extern "C" {
+	/// Logs an error to the browser's console.
+	#[wasm_bindgen(js_namespace = console)]
+	fn error(message: &str);
+}
+
+/// A ratatui backend writing what it draws as the escape codes an xterm.js
+/// terminal understands, for the page to [write](WebArcade::tick) to it.
+#[derive(Debug, Default)]
+pub struct XtermBackend {
+	/// The terminal's size, in columns and rows.
+	size: (u16, u16),
+
+	/// The cursor's position.
+	cursor: (u16, u16),
+
+	/// Escape codes and text not written to the terminal yet.
+	output: String,
+}
+
+impl XtermBackend {
+	/// Creates a backend for a terminal of a size.
+	#[must_use]
+	pub fn new(width: u16, height: u16) -> Self {
+		Self {
+			size: (width, height),
+			..Self::default()
+		}
+	}
+
+	/// Takes what was drawn since the last time, to be written to the
+	/// terminal.
+	pub fn take_output(&mut self) -> String {
+		std::mem::take(&mut self.output)
+	}
+
+	/// Moves the cursor to a cell.
+	fn move_to(&mut self, x: u16, y: u16) {
+		let _ = write!(self.output, "\x1b[{};{}H", y + 1, x + 1);
+		self.cursor = (x, y);
+	}
+}
+
+impl Backend for XtermBackend {
+	fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+	where
+		I: Iterator<Item = (u16, u16, &'a Cell)>,
+	{
+		let mut last = None;
+		let mut style = None;
+		for (x, y, cell) in content {
+			if last != Some((x.wrapping_sub(1), y)) {
+				self.move_to(x, y);
+			}
+			last = Some((x, y));
+			let cell_style = (cell.fg, cell.bg, cell.modifier);
+			if style != Some(cell_style) {
+				self.output.push_str(&screenshot::style_code(cell));
+				style = Some(cell_style);
+			}
+			self.output.push_str(cell.symbol());
+		}
+		self.output.push_str("\x1b[0m");
+		Ok(())
+	}
+
+	fn hide_cursor(&mut self) -> io::Result<()> {
+		self.output.push_str("\x1b[?25l");
+		Ok(())
+	}
+
+	fn show_cursor(&mut self) -> io::Result<()> {
+		self.output.push_str("\x1b[?25h");
+		Ok(())
+	}
+
+	fn get_cursor(&mut self) -> io::Result<(u16, u16)> {
+		Ok(self.cursor)
+	}
+
+	fn set_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
+		self.move_to(x, y);
+		Ok(())
+	}
+
+	fn clear(&mut self) -> io::Result<()> {
+		self.output.push_str("\x1b[0m\x1b[2J");
+		Ok(())
+	}
+
+	fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
+		let code = match clear_type {
+			ClearType::All => "2J",
+			ClearType::AfterCursor => "0J",
+			ClearType::BeforeCursor => "1J",
+			ClearType::CurrentLine => "2K",
+			ClearType::UntilNewLine => "0K",
+		};
+		let _ = write!(self.output, "\x1b[0m\x1b[{code}");
+		Ok(())
+	}
+
+	fn size(&self) -> io::Result<Rect> {
+		Ok(Rect::new(0, 0, self.size.0, self.size.1))
+	}
+
+	fn window_size(&mut self) -> io::Result<WindowSize> {
+		// The page knows the size of its cells, but screens don't need it.
+		Ok(WindowSize {
+			columns_rows: self.size.into(),
+			pixels: Size::default(),
+		})
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		Ok(())
+	}
+}
+
+impl HarnessBackend for XtermBackend {
+	fn resize(&mut self, width: u16, height: u16) {
+		self.size = (width, height);
+	}
+}
+
+/// Returns the key of a [`KeyboardEvent.key`] value, if it's one screens know.
+///
+/// [`KeyboardEvent.key`]: https://developer.mozilla.org/en-US/docs/Web/API/UI_Events/Keyboard_event_key_values
+fn key_code(key: &str) -> Option<KeyCode> {
+	let code = match key {
+		"Enter" => KeyCode::Enter,
+		"Escape" => KeyCode::Esc,
+		"Backspace" => KeyCode::Backspace,
+		"Tab" => KeyCode::Tab,
+		"Delete" => KeyCode::Delete,
+		"Insert" => KeyCode::Insert,
+		"Home" => KeyCode::Home,
+		"End" => KeyCode::End,
+		"PageUp" => KeyCode::PageUp,
+		"PageDown" => KeyCode::PageDown,
+		"ArrowUp" => KeyCode::Up,
+		"ArrowDown" => KeyCode::Down,
+		"ArrowLeft" => KeyCode::Left,
+		"ArrowRight" => KeyCode::Right,
+		_ => {
+			if let Some(number) = key.strip_prefix('F').and_then(|number| number.parse().ok()) {
+				return Some(KeyCode::F(number));
+			}
+			let mut chars = key.chars();
+			let (Some(char), None) = (chars.next(), chars.next()) else {
+				return None;
+			};
+			KeyCode::Char(char)
+		},
+	};
+	Some(code)
+}
+
+/// The arcade running in a page. See the [module](self) documentation for
+/// more information.
+#[wasm_bindgen]
+pub struct WebArcade {
+	/// The harness running the screens.
+	harness: Harness<XtermBackend>,
+}
+
+#[wasm_bindgen]
+impl WebArcade {
+	/// Starts the arcade on the welcome screen, on a terminal of a size,
+	/// speaking the browser's language if it's supported.
+	#[wasm_bindgen(constructor)]
+	#[allow(clippy::needless_pass_by_value)]
+	pub fn new(width: u16, height: u16, language: Option<String>) -> Result<WebArcade, JsError> {
+		let original_hook = panic::take_hook();
+		panic::set_hook(Box::new(move |info| {
+			// Panics abort in browsers, so this is the only trace left of them.
+			error(&info.to_string());
+			original_hook(info);
+		}));
+		i18n::init(language.as_deref());
+		stats::start_session();
+		let terminal = Terminal::new(XtermBackend::new(width, height))?;
+		let harness = Harness::with_terminal(WelcomeScreen::default().into(), terminal)
+			.map_err(|error| JsError::new(&error.to_string()))?;
+		Ok(Self { harness })
+	}
+
+	/// Queues a key pressed in the page, given as its [`KeyboardEvent.key`]
+	/// with the modifiers held. Returns whether screens know the key, so that
+	/// the page leaves the others to the browser.
+	///
+	/// [`KeyboardEvent.key`]: https://developer.mozilla.org/en-US/docs/Web/API/UI_Events/Keyboard_event_key_values
+	pub fn key(&mut self, key: &str, ctrl: bool, alt: bool, shift: bool) -> bool {
+		let Some(mut code) = key_code(key) else {
+			return false;
+		};
+		let mut modifiers = KeyModifiers::NONE;
+		modifiers.set(KeyModifiers::CONTROL, ctrl);
+		modifiers.set(KeyModifiers::ALT, alt);
+		// Like in terminals, Shift only shows in the characters typed, apart
+		// from uppercase letters.
+		let shifted = match code {
+			KeyCode::Char(char) => char.is_ascii_uppercase(),
+			_ => shift,
+		};
+		modifiers.set(KeyModifiers::SHIFT, shifted);
+		if code == KeyCode::Tab && shift {
+			code = KeyCode::BackTab;
+		}
+		self.harness.send(Event::Key(KeyEvent::new(code, modifiers)));
+		true
+	}
+
+	/// Queues text pasted in the page.
+	pub fn paste(&mut self, text: String) {
+		self.harness.send(Event::Paste(text));
+	}
+
+	/// Queues a resize of the terminal to a number of columns and rows.
+	pub fn resize(&mut self, width: u16, height: u16) {
+		self.harness.send(Event::Resize(width, height));
+	}
+
+	/// Runs a frame, playing a key if one is queued, and returns what to write
+	/// to the terminal.
+	pub fn tick(&mut self) -> Result<String, JsError> {
+		self.harness.tick().map_err(|error| JsError::new(&error.to_string()))?;
+		Ok(self.harness.terminal_mut().backend_mut().take_output())
+	}
+
+	/// Returns whether every screen was closed, once the player quit.
+	#[wasm_bindgen(js_name = isClosed)]
+	#[must_use]
+	pub fn is_closed(&self) -> bool {
+		self.harness.is_closed()
+	}
+}
@@ -0,0 +1,223 @@
+//! The computer opponent: a [minimax](https://en.wikipedia.org/wiki/Minimax)
+//! search with alpha-beta pruning, looking a few moves ahead and weighing
+//! positions by their material and how central and advanced their pieces are.
+//! [Searches](Search) run on threads of their own, so that frames keep being
+//! drawn while the computer thinks.
+
+use std::{
+	sync::{
+		Arc,
+		OnceLock,
+	},
+	thread,
+};
+
+use rand::{
+	seq::SliceRandom,
+	Rng,
+};
+
+use crate::{
+	games::chess::board::{
+		Board,
+		Move,
+		PieceKind,
+		Side,
+		SIZE,
+	},
+	services::rng,
+};
+
+/// Score of a checkmate, above any material difference.
+const MATE: i32 = 100_000;
+
+/// Returns a piece's worth, in hundredths of a pawn.
+fn value(kind: PieceKind) -> i32 {
+	match kind {
+		PieceKind::Pawn => 100,
+		PieceKind::Knight => 320,
+		PieceKind::Bishop => 330,
+		PieceKind::Rook => 500,
+		PieceKind::Queen => 900,
+		PieceKind::King => 0,
+	}
+}
+
+/// Returns a bonus for a piece's square: pawns gain by advancing, and knights
+/// and bishops by standing near the center.
+fn placement(kind: PieceKind, side: Side, (file, rank): (usize, usize)) -> i32 {
+	let center_distance = |coordinate: usize| (2 * coordinate).abs_diff(SIZE - 1) as i32;
+	match kind {
+		PieceKind::Pawn => {
+			let advance = match side {
+				Side::White => rank,
+				Side::Black => SIZE - 1 - rank,
+			};
+			advance as i32 * 5
+		},
+		PieceKind::Knight | PieceKind::Bishop => {
+			20 - 2 * (center_distance(file) + center_distance(rank))
+		},
+		_ => 0,
+	}
+}
+
+/// Returns how good a position is for the side to move.
+fn evaluate(board: &Board) -> i32 {
+	board
+		.pieces()
+		.map(|(square, piece)| {
+			let score = value(piece.kind) + placement(piece.kind, piece.side, square);
+			if piece.side == board.turn() {
+				score
+			} else {
+				-score
+			}
+		})
+		.sum()
+}
+
+/// Returns a board's legal moves, captures of the most valuable pieces
+/// first so that the search prunes more.
+fn ordered_moves(board: &Board) -> Vec<Move> {
+	let mut moves = board.legal_moves();
+	moves.sort_by_key(|mv| {
+		let captured = board.get(mv.to).map_or(0, |piece| value(piece.kind));
+		-captured - mv.promotion.map_or(0, value)
+	});
+	moves
+}
+
+/// Returns how good a position is for the side to move, looking a number of
+/// moves ahead. Scores outside of `alpha..beta` don't matter to the caller.
+fn negamax(board: &Board, depth: u32, mut alpha: i32, beta: i32) -> i32 {
+	let moves = ordered_moves(board);
+	if moves.is_empty() {
+		// Mates sooner, with more depth left, weigh more.
+		return if board.is_in_check(board.turn()) { -MATE - depth as i32 } else { 0 };
+	}
+	if depth == 0 {
+		return evaluate(board);
+	}
+	for mv in moves {
+		let mut next = board.clone();
+		next.apply(mv);
+		let score = -negamax(&next, depth - 1, -beta, -alpha);
+		if score >= beta {
+			return beta;
+		}
+		alpha = alpha.max(score);
+	}
+	alpha
+}
+
+/// Returns the best move for the side to move, looking a number of moves
+/// ahead, or [`None`] if the game is over. Moves as good as each other are
+/// picked between at random.
+#[must_use]
+pub fn best_move(board: &Board, depth: u32, rng: &mut impl Rng) -> Option<Move> {
+	let mut moves = board.legal_moves();
+	moves.shuffle(rng);
+	let mut best = None;
+	let mut alpha = -MATE * 2;
+	for mv in moves {
+		let mut next = board.clone();
+		next.apply(mv);
+		let score = -negamax(&next, depth.saturating_sub(1), -MATE * 2, -alpha);
+		if best.is_none() || score > alpha {
+			alpha = score;
+			best = Some(mv);
+		}
+	}
+	best
+}
+
+/// A search for the [best move](best_move) running on a thread of its own,
+/// with its own random number generator seeded from the game's, so that
+/// seeded games play the same.
+#[derive(Debug, Clone)]
+pub struct Search {
+	/// The move found, once the search is over.
+	result: Arc<OnceLock<Option<Move>>>,
+}
+
+impl Search {
+	/// Starts searching for the best move on a board.
+	#[must_use]
+	#[cfg_attr(target_arch = "wasm32", allow(clippy::needless_pass_by_value))]
+	pub fn start(board: Board, depth: u32) -> Self {
+		let seed: u64 = rng::with_rng(Rng::gen);
+		let result = Arc::new(OnceLock::new());
+		let sender = Arc::clone(&result);
+		#[cfg(not(target_arch = "wasm32"))]
+		thread::spawn(move || {
+			let _ = sender.set(best_move(&board, depth, &mut rng::seeded(seed)));
+		});
+		// Browsers can't spawn threads, so the frame waits for the search there.
+		#[cfg(target_arch = "wasm32")]
+		let _ = sender.set(best_move(&board, depth, &mut rng::seeded(seed)));
+		Self { result }
+	}
+
+	/// Returns the move found, or [`None`] while the search goes on. The
+	/// move found is [`None`] itself if the game is over.
+	#[must_use]
+	pub fn poll(&self) -> Option<Option<Move>> {
+		self.result.get().copied()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		thread,
+		time::Duration,
+	};
+
+	use super::{
+		best_move,
+		Search,
+	};
+	use crate::{
+		games::chess::board::{
+			Board,
+			Move,
+			Piece,
+			PieceKind,
+			Side,
+		},
+		services::rng,
+	};
+
+	#[test]
+	fn mates_and_captures_are_found() {
+		let piece = |kind, side| Some(Piece { kind, side });
+		let mut board = Board::empty();
+		board.set((6, 0), piece(PieceKind::King, Side::White));
+		board.set((0, 6), piece(PieceKind::Rook, Side::White));
+		board.set((1, 2), piece(PieceKind::Rook, Side::White));
+		board.set((6, 7), piece(PieceKind::King, Side::Black));
+		let mate = Move {
+			from: (1, 2),
+			to: (1, 7),
+			promotion: None,
+		};
+		assert_eq!(best_move(&board, 2, &mut rng::seeded(0)), Some(mate));
+
+		let mut board = Board::empty();
+		board.set((0, 0), piece(PieceKind::King, Side::White));
+		board.set((3, 3), piece(PieceKind::Knight, Side::White));
+		board.set((4, 5), piece(PieceKind::Queen, Side::Black));
+		board.set((7, 7), piece(PieceKind::King, Side::Black));
+		assert_eq!(
+			best_move(&board, 1, &mut rng::seeded(0)).map(|mv| mv.to),
+			Some((4, 5))
+		);
+
+		let search = Search::start(board, 1);
+		while search.poll().is_none() {
+			thread::sleep(Duration::from_millis(1));
+		}
+		assert_eq!(search.poll().flatten().map(|mv| mv.to), Some((4, 5)));
+	}
+}
@@ -0,0 +1,642 @@
+//! A chess board, with the rules of chess: every legal move, including
+//! castling, en passant and promotions, and how games end.
+//!
+//! Squares are `(file, rank)`, from `(0, 0)` for a1, White's queen side
+//! corner, to `(7, 7)` for h8.
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+/// Number of files and ranks of the board.
+pub const SIZE: usize = 8;
+
+/// A square of the board, as `(file, rank)`.
+pub type Square = (usize, usize);
+
+/// Steps a knight can take.
+const KNIGHT_STEPS: [(isize, isize); 8] = [
+	(1, 2),
+	(2, 1),
+	(2, -1),
+	(1, -2),
+	(-1, -2),
+	(-2, -1),
+	(-2, 1),
+	(-1, 2),
+];
+
+/// Directions rooks slide in.
+const ROOK_DIRECTIONS: [(isize, isize); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+
+/// Directions bishops slide in.
+const BISHOP_DIRECTIONS: [(isize, isize); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+/// Pieces a pawn can be promoted to, the likeliest first.
+const PROMOTIONS: [PieceKind; 4] = [
+	PieceKind::Queen,
+	PieceKind::Knight,
+	PieceKind::Rook,
+	PieceKind::Bishop,
+];
+
+/// Halfmoves without a capture or a pawn move after which the game is drawn.
+const FIFTY_MOVES: u32 = 100;
+
+/// Returns the name of a square, like `e4`.
+#[must_use]
+pub fn square_name((file, rank): Square) -> String {
+	format!("{}{}", char::from(b'a' + file as u8), rank + 1)
+}
+
+/// Returns the square a step away from another, if it's on the board.
+fn offset((file, rank): Square, (file_step, rank_step): (isize, isize)) -> Option<Square> {
+	let file = file.checked_add_signed(file_step).filter(|&file| file < SIZE)?;
+	let rank = rank.checked_add_signed(rank_step).filter(|&rank| rank < SIZE)?;
+	Some((file, rank))
+}
+
+/// A side of the game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Side {
+	/// The side moving first, from ranks 1 and 2.
+	White,
+
+	/// The side moving second, from ranks 7 and 8.
+	Black,
+}
+
+impl Side {
+	/// Returns the other side.
+	#[must_use]
+	pub fn opponent(self) -> Self {
+		match self {
+			Self::White => Self::Black,
+			Self::Black => Self::White,
+		}
+	}
+
+	/// Returns the direction the side's pawns move in, along ranks.
+	fn forward(self) -> isize {
+		match self {
+			Self::White => 1,
+			Self::Black => -1,
+		}
+	}
+
+	/// Returns the rank the side's pieces start on.
+	fn back_rank(self) -> usize {
+		match self {
+			Self::White => 0,
+			Self::Black => SIZE - 1,
+		}
+	}
+}
+
+/// A kind of piece.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PieceKind {
+	/// Moves forward a square, or two from its starting square, and captures
+	/// diagonally.
+	Pawn,
+
+	/// Jumps in an L shape.
+	Knight,
+
+	/// Slides diagonally.
+	Bishop,
+
+	/// Slides along files and ranks.
+	Rook,
+
+	/// Slides in any direction.
+	Queen,
+
+	/// Steps in any direction, and must never be left in check.
+	King,
+}
+
+/// A piece on the board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Piece {
+	/// What kind of piece it is.
+	pub kind: PieceKind,
+
+	/// Whose piece it is.
+	pub side: Side,
+}
+
+impl Piece {
+	/// Returns the piece's symbol, outlined for White's pieces and filled for
+	/// Black's, like `♘` or `♞`.
+	#[must_use]
+	pub fn symbol(self) -> char {
+		let symbols = match self.side {
+			Side::White => ['♙', '♘', '♗', '♖', '♕', '♔'],
+			Side::Black => ['♟', '♞', '♝', '♜', '♛', '♚'],
+		};
+		symbols[self.kind as usize]
+	}
+}
+
+/// A move of a piece from a square to another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Move {
+	/// The square the piece moves from.
+	pub from: Square,
+
+	/// The square the piece moves to.
+	pub to: Square,
+
+	/// What the piece is promoted to, for pawns reaching the last rank.
+	pub promotion: Option<PieceKind>,
+}
+
+impl Move {
+	/// Returns the move's name, like `e2e4` or `e7e8q`.
+	#[must_use]
+	pub fn name(self) -> String {
+		let promotion = match self.promotion {
+			Some(PieceKind::Knight) => "n",
+			Some(PieceKind::Bishop) => "b",
+			Some(PieceKind::Rook) => "r",
+			Some(PieceKind::Queen) => "q",
+			_ => "",
+		};
+		format!(
+			"{}{}{promotion}",
+			square_name(self.from),
+			square_name(self.to)
+		)
+	}
+}
+
+/// How a game stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+	/// The side to move has moves to play.
+	Playing,
+
+	/// The side to move is in check without any move to get out of it, and
+	/// the other side wins.
+	Checkmate(Side),
+
+	/// The side to move isn't in check but has no move to play: a draw.
+	Stalemate,
+
+	/// Fifty moves went by without a capture or a pawn move: a draw.
+	FiftyMoves,
+
+	/// Neither side has the pieces left to checkmate: a draw.
+	InsufficientMaterial,
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct Board {
+	/// The pieces, rank by rank from rank 1.
+	squares: [Option<Piece>; SIZE * SIZE],
+
+	/// The side to move.
+	turn: Side,
+
+	/// Whether each side can still castle, by side, on the king's side then
+	/// on the queen's side.
+	castling: [[bool; 2]; 2],
+
+	/// The square a pawn that just moved two squares skipped, where it can be
+	/// captured en passant.
+	en_passant: Option<Square>,
+
+	/// Halfmoves since the last capture or pawn move.
+	halfmoves: u32,
+}
+
+impl Default for Board {
+	fn default() -> Self {
+		let back_rank = [
+			PieceKind::Rook,
+			PieceKind::Knight,
+			PieceKind::Bishop,
+			PieceKind::Queen,
+			PieceKind::King,
+			PieceKind::Bishop,
+			PieceKind::Knight,
+			PieceKind::Rook,
+		];
+		let mut board = Self::empty();
+		for (file, kind) in back_rank.into_iter().enumerate() {
+			for side in [Side::White, Side::Black] {
+				let pawn_rank = side.back_rank().saturating_add_signed(side.forward());
+				board.set((file, side.back_rank()), Some(Piece { kind, side }));
+				board.set(
+					(file, pawn_rank),
+					Some(Piece {
+						kind: PieceKind::Pawn,
+						side,
+					}),
+				);
+			}
+		}
+		board.castling = [[true; 2]; 2];
+		board
+	}
+}
+
+impl Board {
+	/// Creates a board without any piece, White to move.
+	pub fn empty() -> Self {
+		Self {
+			squares: [None; SIZE * SIZE],
+			turn: Side::White,
+			castling: [[false; 2]; 2],
+			en_passant: None,
+			halfmoves: 0,
+		}
+	}
+
+	/// Returns the piece on a square, if any.
+	#[must_use]
+	pub fn get(&self, (file, rank): Square) -> Option<Piece> {
+		self.squares[rank * SIZE + file]
+	}
+
+	/// Places a piece on a square, or empties it.
+	pub fn set(&mut self, (file, rank): Square, piece: Option<Piece>) {
+		self.squares[rank * SIZE + file] = piece;
+	}
+
+	/// Returns the side to move.
+	#[must_use]
+	pub fn turn(&self) -> Side {
+		self.turn
+	}
+
+	/// Returns the squares with a piece on them, with their piece.
+	pub fn pieces(&self) -> impl Iterator<Item = (Square, Piece)> + '_ {
+		self.squares
+			.iter()
+			.enumerate()
+			.filter_map(|(index, piece)| Some(((index % SIZE, index / SIZE), (*piece)?)))
+	}
+
+	/// Returns whether a side's piece attacks a square.
+	#[must_use]
+	pub fn is_attacked(&self, square: Square, by: Side) -> bool {
+		let is = |square: Option<Square>, kinds: &[PieceKind]| {
+			square
+				.and_then(|square| self.get(square))
+				.is_some_and(|piece| piece.side == by && kinds.contains(&piece.kind))
+		};
+		let pawn_step = -by.forward();
+		if [-1, 1]
+			.into_iter()
+			.any(|file_step| is(offset(square, (file_step, pawn_step)), &[PieceKind::Pawn]))
+		{
+			return true;
+		}
+		if KNIGHT_STEPS.into_iter().any(|step| is(offset(square, step), &[PieceKind::Knight])) {
+			return true;
+		}
+		let king_steps = ROOK_DIRECTIONS.into_iter().chain(BISHOP_DIRECTIONS);
+		if king_steps.into_iter().any(|step| is(offset(square, step), &[PieceKind::King])) {
+			return true;
+		}
+		let slides = [
+			(ROOK_DIRECTIONS, PieceKind::Rook),
+			(BISHOP_DIRECTIONS, PieceKind::Bishop),
+		];
+		slides.into_iter().any(|(directions, kind)| {
+			directions.into_iter().any(|direction| {
+				let blocker = std::iter::successors(offset(square, direction), |&square| {
+					offset(square, direction)
+				})
+				.find(|&square| self.get(square).is_some());
+				is(blocker, &[kind, PieceKind::Queen])
+			})
+		})
+	}
+
+	/// Returns whether a side's king is attacked.
+	#[must_use]
+	pub fn is_in_check(&self, side: Side) -> bool {
+		self.pieces()
+			.find(|(_, piece)| {
+				*piece
+					== Piece {
+						kind: PieceKind::King,
+						side,
+					}
+			})
+			.is_some_and(|(square, _)| self.is_attacked(square, side.opponent()))
+	}
+
+	/// Adds the moves of the piece on a square to a list, whether or not they
+	/// leave its king in check.
+	fn pseudo_legal_moves(&self, from: Square, moves: &mut Vec<Move>) {
+		let Some(piece) = self.get(from) else {
+			return;
+		};
+		let side = piece.side;
+		let mut add = |to: Square| {
+			moves.push(Move {
+				from,
+				to,
+				promotion: None,
+			});
+		};
+		let can_land = |to: Square| self.get(to).is_none_or(|other| other.side != side);
+		match piece.kind {
+			PieceKind::Pawn => {
+				let last_rank = side.opponent().back_rank();
+				let mut targets = Vec::new();
+				if let Some(to) = offset(from, (0, side.forward())) {
+					if self.get(to).is_none() {
+						targets.push(to);
+						let start_rank = side.back_rank().saturating_add_signed(side.forward());
+						let double = offset(to, (0, side.forward()));
+						if let Some(double) = double.filter(|_| from.1 == start_rank) {
+							if self.get(double).is_none() {
+								targets.push(double);
+							}
+						}
+					}
+				}
+				for file_step in [-1, 1] {
+					if let Some(to) = offset(from, (file_step, side.forward())) {
+						let enemy = self.get(to).is_some_and(|other| other.side != side);
+						if enemy || self.en_passant == Some(to) {
+							targets.push(to);
+						}
+					}
+				}
+				for to in targets {
+					if to.1 == last_rank {
+						moves.extend(PROMOTIONS.map(|kind| Move {
+							from,
+							to,
+							promotion: Some(kind),
+						}));
+					} else {
+						moves.push(Move {
+							from,
+							to,
+							promotion: None,
+						});
+					}
+				}
+			},
+			PieceKind::Knight | PieceKind::King => {
+				let steps: Vec<_> = if piece.kind == PieceKind::Knight {
+					KNIGHT_STEPS.to_vec()
+				} else {
+					ROOK_DIRECTIONS.into_iter().chain(BISHOP_DIRECTIONS).collect()
+				};
+				for to in steps.into_iter().filter_map(|step| offset(from, step)) {
+					if can_land(to) {
+						add(to);
+					}
+				}
+				if piece.kind == PieceKind::King {
+					for to in self.castling_targets(side) {
+						add(to);
+					}
+				}
+			},
+			PieceKind::Bishop | PieceKind::Rook | PieceKind::Queen => {
+				let directions = match piece.kind {
+					PieceKind::Bishop => BISHOP_DIRECTIONS.to_vec(),
+					PieceKind::Rook => ROOK_DIRECTIONS.to_vec(),
+					_ => ROOK_DIRECTIONS.into_iter().chain(BISHOP_DIRECTIONS).collect(),
+				};
+				for direction in directions {
+					let mut square = from;
+					while let Some(to) = offset(square, direction) {
+						if can_land(to) {
+							add(to);
+						}
+						if self.get(to).is_some() {
+							break;
+						}
+						square = to;
+					}
+				}
+			},
+		}
+	}
+
+	/// Returns the squares a side's king can castle to: it can't castle out
+	/// of, through or into check, nor past another piece.
+	fn castling_targets(&self, side: Side) -> Vec<Square> {
+		let rank = side.back_rank();
+		let opponent = side.opponent();
+		if self.get((4, rank))
+			!= Some(Piece {
+				kind: PieceKind::King,
+				side,
+			}) || self.is_attacked((4, rank), opponent)
+		{
+			return Vec::new();
+		}
+		let rook = Some(Piece {
+			kind: PieceKind::Rook,
+			side,
+		});
+		let [king_side, queen_side] = self.castling[side as usize];
+		let mut targets = Vec::new();
+		if king_side
+			&& self.get((7, rank)) == rook
+			&& [5, 6].iter().all(|&file| {
+				self.get((file, rank)).is_none() && !self.is_attacked((file, rank), opponent)
+			}) {
+			targets.push((6, rank));
+		}
+		if queen_side
+			&& self.get((0, rank)) == rook
+			&& [1, 2, 3].iter().all(|&file| self.get((file, rank)).is_none())
+			&& [2, 3].iter().all(|&file| !self.is_attacked((file, rank), opponent))
+		{
+			targets.push((2, rank));
+		}
+		targets
+	}
+
+	/// Returns every legal move of the side to move.
+	#[must_use]
+	pub fn legal_moves(&self) -> Vec<Move> {
+		let mut moves = Vec::new();
+		for (square, piece) in self.pieces() {
+			if piece.side == self.turn {
+				self.pseudo_legal_moves(square, &mut moves);
+			}
+		}
+		moves.retain(|&mv| {
+			let mut next = self.clone();
+			next.apply(mv);
+			!next.is_in_check(self.turn)
+		});
+		moves
+	}
+
+	/// Returns the legal moves of the piece on a square.
+	#[must_use]
+	pub fn legal_moves_from(&self, from: Square) -> Vec<Move> {
+		self.legal_moves().into_iter().filter(|mv| mv.from == from).collect()
+	}
+
+	/// Plays a move without checking that it's legal.
+	pub fn apply(&mut self, mv: Move) {
+		let Some(piece) = self.get(mv.from) else {
+			return;
+		};
+		let side = piece.side;
+		let captured = self.get(mv.to).is_some();
+		self.set(mv.from, None);
+		if piece.kind == PieceKind::Pawn && Some(mv.to) == self.en_passant && !captured {
+			self.set((mv.to.0, mv.from.1), None);
+		}
+		if piece.kind == PieceKind::King && mv.from.0.abs_diff(mv.to.0) == 2 {
+			let rook_file = if mv.to.0 > mv.from.0 { SIZE - 1 } else { 0 };
+			let rook = self.get((rook_file, mv.from.1));
+			self.set((rook_file, mv.from.1), None);
+			self.set((usize::midpoint(mv.from.0, mv.to.0), mv.from.1), rook);
+		}
+		self.en_passant = (piece.kind == PieceKind::Pawn && mv.from.1.abs_diff(mv.to.1) == 2)
+			.then_some((mv.from.0, usize::midpoint(mv.from.1, mv.to.1)));
+		if piece.kind == PieceKind::King {
+			self.castling[side as usize] = [false; 2];
+		}
+		// A rook leaving its corner, or captured in it, can't castle anymore.
+		for square in [mv.from, mv.to] {
+			for corner_side in [Side::White, Side::Black] {
+				if square.1 == corner_side.back_rank() {
+					match square.0 {
+						0 => self.castling[corner_side as usize][1] = false,
+						7 => self.castling[corner_side as usize][0] = false,
+						_ => {},
+					}
+				}
+			}
+		}
+		self.halfmoves =
+			if piece.kind == PieceKind::Pawn || captured { 0 } else { self.halfmoves + 1 };
+		let kind = mv.promotion.unwrap_or(piece.kind);
+		self.set(mv.to, Some(Piece { kind, side }));
+		self.turn = side.opponent();
+	}
+
+	/// Plays a move if it's legal, returning whether it was.
+	pub fn play(&mut self, mv: Move) -> bool {
+		let legal = self.legal_moves().contains(&mv);
+		if legal {
+			self.apply(mv);
+		}
+		legal
+	}
+
+	/// Returns whether neither side has the pieces left to checkmate: only
+	/// kings, with at most one knight or bishop between them.
+	fn is_insufficient_material(&self) -> bool {
+		let mut minor_pieces = 0;
+		for (_, piece) in self.pieces() {
+			match piece.kind {
+				PieceKind::King => {},
+				PieceKind::Knight | PieceKind::Bishop => minor_pieces += 1,
+				_ => return false,
+			}
+		}
+		minor_pieces <= 1
+	}
+
+	/// Returns how the game stands.
+	#[must_use]
+	pub fn status(&self) -> Status {
+		if self.legal_moves().is_empty() {
+			if self.is_in_check(self.turn) {
+				Status::Checkmate(self.turn.opponent())
+			} else {
+				Status::Stalemate
+			}
+		} else if self.halfmoves >= FIFTY_MOVES {
+			Status::FiftyMoves
+		} else if self.is_insufficient_material() {
+			Status::InsufficientMaterial
+		} else {
+			Status::Playing
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		Board,
+		Move,
+		Piece,
+		PieceKind,
+		Side,
+		Status,
+	};
+
+	/// Counts the positions reached after a number of moves.
+	fn perft(board: &Board, depth: u32) -> usize {
+		if depth == 0 {
+			return 1;
+		}
+		board
+			.legal_moves()
+			.into_iter()
+			.map(|mv| {
+				let mut next = board.clone();
+				next.apply(mv);
+				perft(&next, depth - 1)
+			})
+			.sum()
+	}
+
+	/// Returns a move without promotion between squares named like `e2`.
+	fn mv(from: &str, to: &str) -> Move {
+		let square = |name: &str| {
+			let bytes = name.as_bytes();
+			(usize::from(bytes[0] - b'a'), usize::from(bytes[1] - b'1'))
+		};
+		Move {
+			from: square(from),
+			to: square(to),
+			promotion: None,
+		}
+	}
+
+	#[test]
+	fn moves_from_the_start_are_counted() {
+		let board = Board::default();
+		assert_eq!(board.legal_moves().len(), 20);
+		assert_eq!(perft(&board, 3), 8902);
+	}
+
+	#[test]
+	fn special_moves_and_mates_are_found() {
+		let mut board = Board::default();
+		for (from, to) in [("f2", "f3"), ("e7", "e5"), ("g2", "g4"), ("d8", "h4")] {
+			assert!(board.play(mv(from, to)), "{from}{to} should be legal");
+		}
+		assert_eq!(board.status(), Status::Checkmate(Side::Black));
+
+		let mut board = Board::empty();
+		let piece = |kind, side| Some(Piece { kind, side });
+		board.set((4, 0), piece(PieceKind::King, Side::White));
+		board.set((7, 0), piece(PieceKind::Rook, Side::White));
+		board.set((4, 7), piece(PieceKind::King, Side::Black));
+		board.set((3, 6), piece(PieceKind::Pawn, Side::Black));
+		board.set((4, 4), piece(PieceKind::Pawn, Side::White));
+		board.castling = [[true, false], [false, false]];
+		assert!(board.play(mv("e1", "g1")));
+		assert_eq!(board.get((5, 0)), piece(PieceKind::Rook, Side::White));
+		assert!(board.play(mv("d7", "d5")));
+		assert!(board.play(mv("e5", "d6")));
+		assert_eq!(board.get((3, 4)), None);
+		assert_eq!(board.status(), Status::Playing);
+	}
+}
@@ -0,0 +1,85 @@
+//! Implementation for the game Chess, played against the computer or another
+//! player on the same keyboard.
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+use crate::{
+	core::events::Event,
+	games::{
+		difficulty::DifficultyRating,
+		preview::GamePreview,
+		registry::{
+			GameId,
+			GameRegistry,
+		},
+		Game,
+		GameMetadata,
+		GameState,
+		GameStaticInfo,
+	},
+	t,
+	ui::{
+		util::get_crate_authors,
+		ChessSetupScreen,
+	},
+};
+
+pub mod ai;
+pub mod board;
+pub mod setup;
+
+/// Chess's [ID](GameId).
+pub const GAME_ID: &str = "chess";
+
+/// Chess's manual.
+pub const HELP_PAGE: &str = include_str!("../../../assets/help/chess.md");
+
+/// Chess's preview, Black's side of the board after a few opening moves.
+const PREVIEW: &str = "\
+8 ♜ ♞ ♝ ♛ ♚ ♝ · ♜
+7 ♟ ♟ ♟ ♟ · ♟ ♟ ♟
+6 · · · · · ♞ · ·
+5 · · · · ♟ · · ·
+4 · · · · ♙ · · ·
+  a b c d e f g h";
+
+/// Registers Chess in a [registry](GameRegistry).
+pub fn register(registry: &mut GameRegistry) {
+	registry.register(Chess.into());
+}
+
+/// The game [Chess](https://en.wikipedia.org/wiki/Chess).
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct Chess;
+
+impl Game for Chess {
+	fn data(&self) -> GameState {
+		GameState::new(
+			GameMetadata::new(
+				GameStaticInfo::new(
+					self.clone().into(),
+					GameId::from(GAME_ID),
+					"Chess".to_string(),
+					t!("chess-description").to_string(),
+					"0.0.1".to_string(),
+					get_crate_authors(),
+					DifficultyRating::Hard,
+				)
+				.with_preview(Some(GamePreview::still(PREVIEW))),
+			)
+			.unwrap(),
+			Some(ChessSetupScreen::default().into()),
+		)
+	}
+
+	fn event(&mut self, _event: &Event) -> anyhow::Result<()> {
+		Ok(())
+	}
+
+	fn help_page(&self) -> Option<&'static str> {
+		Some(HELP_PAGE)
+	}
+}
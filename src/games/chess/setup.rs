@@ -0,0 +1,117 @@
+//! The answers to Chess's setup screen: who plays Black, and how far ahead the
+//! computer looks.
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+use strum::{
+	Display,
+	EnumIter,
+};
+
+use crate::{
+	t,
+	ui::components::games::setup::SetupAnswers,
+};
+
+/// Fewest moves the computer looks ahead.
+pub const MIN_LEVEL: u32 = 1;
+
+/// Most moves the computer looks ahead.
+pub const MAX_LEVEL: u32 = 3;
+
+/// [Rating](crate::services::rating) the computer plays at for each level it
+/// looks ahead, counted against the player's own after each game.
+pub const COMPUTER_RATING_PER_LEVEL: u32 = 400;
+
+/// Who plays Black.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Opponent {
+	/// The computer plays Black, with the player as White.
+	#[default]
+	Computer,
+
+	/// Another player takes turns on the same keyboard.
+	Human,
+}
+
+/// A question of the setup screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, EnumIter)]
+pub enum ChessField {
+	/// Who plays Black.
+	Opponent,
+
+	/// How far ahead the computer looks.
+	Level,
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[must_use]
+pub struct ChessSetup {
+	/// Who plays Black.
+	pub opponent: Opponent,
+
+	/// How many moves ahead the computer looks, from [`MIN_LEVEL`] to
+	/// [`MAX_LEVEL`].
+	pub level: u32,
+}
+
+impl Default for ChessSetup {
+	fn default() -> Self {
+		Self {
+			opponent: Opponent::default(),
+			level: 2,
+		}
+	}
+}
+
+impl ChessSetup {
+	/// Returns the [rating](crate::services::rating) of the computer at this
+	/// setup's level.
+	#[must_use]
+	pub fn computer_rating(self) -> u32 {
+		COMPUTER_RATING_PER_LEVEL * (self.level + 1)
+	}
+}
+
+impl SetupAnswers for ChessSetup {
+	type Question = ChessField;
+
+	fn label(question: ChessField) -> &'static str {
+		match question {
+			ChessField::Opponent => t!("chess-opponent"),
+			ChessField::Level => t!("chess-level"),
+		}
+	}
+
+	fn answer(&self, question: ChessField) -> usize {
+		match question {
+			ChessField::Opponent => self.opponent as usize,
+			ChessField::Level => self.level as usize,
+		}
+	}
+
+	fn answer_text(&self, question: ChessField) -> String {
+		match question {
+			ChessField::Opponent => match self.opponent {
+				Opponent::Computer => t!("chess-opponent-computer").to_string(),
+				Opponent::Human => t!("chess-opponent-human").to_string(),
+			},
+			ChessField::Level => self.level.to_string(),
+		}
+	}
+
+	fn adjust(&mut self, question: ChessField, step: isize) {
+		match question {
+			ChessField::Opponent => {
+				self.opponent = if step < 0 { Opponent::Computer } else { Opponent::Human };
+			},
+			ChessField::Level => {
+				let level = self.level.saturating_add_signed(step as i32);
+				self.level = level.clamp(MIN_LEVEL, MAX_LEVEL);
+			},
+		}
+	}
+}
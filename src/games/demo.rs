@@ -0,0 +1,122 @@
+//! Games playing themselves, watched from their setup screens and in attract
+//! mode, when the welcome screen is left idle.
+//!
+//! Games opt in by implementing [`DemoPlayable`] for their state, picking
+//! moves with a simple policy, and by returning a screen showing a
+//! [`DemoPlayer`] of it from
+//! [`Game::demo_screen`](crate::games::Game::demo_screen).
+
+use std::time::Duration;
+
+use crate::core::time::Instant;
+
+/// How long a finished demo stays on screen before another one starts.
+pub const RESTART_DELAY: Duration = Duration::from_secs(3);
+
+/// A game state that can play itself, following a policy. Policies don't have
+/// to play well, only believably.
+pub trait DemoPlayable: Sized {
+	/// A move made by the policy.
+	type Move;
+
+	/// How long the policy waits between moves.
+	const MOVE_INTERVAL: Duration;
+
+	/// Creates a new game for the policy to play.
+	fn new_demo() -> Self;
+
+	/// Returns the policy's next move, or [`None`] once the game is over.
+	fn demo_move(&self) -> Option<Self::Move>;
+
+	/// Plays a move made by the policy.
+	fn play_demo_move(&mut self, demo_move: Self::Move);
+}
+
+/// Plays a [demo](DemoPlayable) at its pace, starting another game a while
+/// after one ends.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct DemoPlayer<T> {
+	/// The game being played.
+	game: T,
+
+	/// When the last move was made.
+	last_move: Instant,
+
+	/// When the game ended, if it did.
+	ended: Option<Instant>,
+}
+
+impl<T: DemoPlayable> Default for DemoPlayer<T> {
+	fn default() -> Self {
+		Self {
+			game: T::new_demo(),
+			last_move: Instant::now(),
+			ended: None,
+		}
+	}
+}
+
+impl<T: DemoPlayable> DemoPlayer<T> {
+	/// Returns the game being played.
+	pub fn game(&self) -> &T {
+		&self.game
+	}
+
+	/// Returns whether the game being played is over, before another starts.
+	#[must_use]
+	pub fn is_over(&self) -> bool {
+		self.ended.is_some()
+	}
+
+	/// Makes the policy's next move, returning whether it made one.
+	pub fn step(&mut self) -> bool {
+		self.last_move = Instant::now();
+		let Some(demo_move) = self.game.demo_move() else {
+			self.ended.get_or_insert_with(Instant::now);
+			return false;
+		};
+		self.game.play_demo_move(demo_move);
+		true
+	}
+
+	/// Makes the next move when it's time to, or starts another game once the
+	/// last one has been over for a while.
+	pub fn update(&mut self) {
+		if let Some(ended) = self.ended {
+			if ended.elapsed() >= RESTART_DELAY {
+				*self = Self::default();
+			}
+		} else if self.last_move.elapsed() >= T::MOVE_INTERVAL {
+			self.step();
+		}
+	}
+}
+
+#[cfg(all(test, feature = "minesweeper", feature = "tron"))]
+mod tests {
+	use super::{
+		DemoPlayable,
+		DemoPlayer,
+	};
+	use crate::games::{
+		minesweeper::round::Round,
+		tron::arena::Arena,
+	};
+
+	/// Plays a demo until it's over, returning how many moves it took.
+	fn play_out<T: DemoPlayable>() -> usize {
+		let mut player = DemoPlayer::<T>::default();
+		let moves = (0..10_000).take_while(|_| player.step()).count();
+		assert!(player.is_over(), "the demo never ended");
+		moves
+	}
+
+	#[test]
+	fn demos_play_to_the_end() {
+		for _ in 0..20 {
+			assert!(play_out::<Arena>() > 0);
+			assert!(play_out::<Round>() > 0);
+		}
+	}
+}
@@ -0,0 +1,164 @@
+//! How hard games are, shown as stars in the game selection screen, which can
+//! filter and sort games by it.
+
+use std::{
+	cmp::Reverse,
+	fmt::{
+		Display,
+		Formatter,
+	},
+};
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+use strum::{
+	EnumIter,
+	IntoEnumIterator,
+};
+
+use crate::{
+	games::{
+		Game,
+		Games,
+	},
+	t,
+};
+
+/// How hard a game is, from one to four stars.
+#[derive(
+	Debug,
+	Clone,
+	Copy,
+	Default,
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+	Hash,
+	EnumIter,
+	Serialize,
+	Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum DifficultyRating {
+	/// Picked up in a minute.
+	Easy,
+
+	/// Takes a few games to get good at.
+	#[default]
+	Medium,
+
+	/// Takes practice to get good at.
+	Hard,
+
+	/// For players who've mastered the rest.
+	Expert,
+}
+
+impl DifficultyRating {
+	/// Returns the rating's identifier, as used in scripts.
+	#[must_use]
+	pub fn id(self) -> &'static str {
+		match self {
+			Self::Easy => "easy",
+			Self::Medium => "medium",
+			Self::Hard => "hard",
+			Self::Expert => "expert",
+		}
+	}
+
+	/// Returns the rating with an identifier, if any.
+	#[must_use]
+	pub fn from_id(id: &str) -> Option<Self> {
+		Self::iter().find(|rating| rating.id() == id)
+	}
+
+	/// Returns the rating's name.
+	#[must_use]
+	pub fn label(self) -> &'static str {
+		match self {
+			Self::Easy => t!("difficulty-easy"),
+			Self::Medium => t!("difficulty-medium"),
+			Self::Hard => t!("difficulty-hard"),
+			Self::Expert => t!("difficulty-expert"),
+		}
+	}
+
+	/// Returns the rating's number of stars.
+	#[must_use]
+	pub fn stars(self) -> usize {
+		self as usize + 1
+	}
+
+	/// Returns the rating as filled stars out of four, like `★★☆☆`.
+	#[must_use]
+	pub fn stars_text(self) -> String {
+		let total = Self::iter().count();
+		format!(
+			"{}{}",
+			"★".repeat(self.stars()),
+			"☆".repeat(total - self.stars())
+		)
+	}
+
+	/// Returns the next harder rating, or [`None`] past the hardest.
+	#[must_use]
+	pub fn harder(self) -> Option<Self> {
+		Self::iter().find(|rating| *rating > self)
+	}
+}
+
+impl Display for DifficultyRating {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		f.write_str(self.label())
+	}
+}
+
+/// How games are sorted by difficulty in the game selection screen.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DifficultySort {
+	/// Left in the order they're listed in, built-in games first.
+	#[default]
+	Unsorted,
+
+	/// Easiest games first.
+	Easiest,
+
+	/// Hardest games first.
+	Hardest,
+}
+
+impl DifficultySort {
+	/// Returns the sort picked after this one.
+	#[must_use]
+	pub fn next(self) -> Self {
+		match self {
+			Self::Unsorted => Self::Easiest,
+			Self::Easiest => Self::Hardest,
+			Self::Hardest => Self::Unsorted,
+		}
+	}
+
+	/// Returns the sort's name.
+	#[must_use]
+	pub fn label(self) -> &'static str {
+		match self {
+			Self::Unsorted => t!("difficulty-unsorted"),
+			Self::Easiest => t!("difficulty-easiest-first"),
+			Self::Hardest => t!("difficulty-hardest-first"),
+		}
+	}
+
+	/// Sorts games by their difficulty, keeping the order of games as hard as
+	/// each other.
+	pub fn sort(self, games: &mut [Games]) {
+		let difficulty = |game: &Games| game.data().metadata.static_info.difficulty;
+		match self {
+			Self::Unsorted => {},
+			Self::Easiest => games.sort_by_key(difficulty),
+			Self::Hardest => games.sort_by_key(|game| Reverse(difficulty(game))),
+		}
+	}
+}
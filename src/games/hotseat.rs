@@ -0,0 +1,150 @@
+//! Hotseat multiplayer, where players take turns on the same keyboard.
+//!
+//! Turn-based games keep a [Hotseat] with their players, and
+//! [end the turn](Hotseat::end_turn) whenever a player is done. Between turns,
+//! a [screen](crate::ui::PassKeyboardScreen) hides the game until the next
+//! player is ready, so that they don't see what they shouldn't (like the other
+//! player's fleet in Battleship).
+
+use ratatui::style::Color;
+
+use crate::{
+	t,
+	ui::{
+		color_scheme::{
+			FRENCH_VIOLET,
+			GHOST_WHITE,
+			MEDIUM_SLATE_BLUE,
+			SUNGLOW,
+			THISTLE,
+			TROPICAL_INDIGO,
+		},
+		screens::ScreenState,
+		PassKeyboardScreen,
+	},
+};
+
+/// Colors given to players by default, in turn order.
+pub const PLAYER_COLORS: [Color; 6] = [
+	SUNGLOW,
+	TROPICAL_INDIGO,
+	THISTLE,
+	FRENCH_VIOLET,
+	MEDIUM_SLATE_BLUE,
+	GHOST_WHITE,
+];
+
+/// A player taking turns on the keyboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HotseatPlayer {
+	/// The player's name.
+	pub name: String,
+
+	/// The color the player's pieces and name are shown in.
+	pub color: Color,
+}
+
+/// Players taking turns on the same keyboard, in order. See the
+/// [module](self) documentation for more information.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct Hotseat {
+	/// The players, in turn order.
+	players: Vec<HotseatPlayer>,
+
+	/// Index of the player whose turn it is.
+	current: usize,
+
+	/// The current round, starting at 1. A round ends once every player has
+	/// played.
+	round: u32,
+}
+
+impl Hotseat {
+	/// Creates a hotseat of players, the first one starting.
+	///
+	/// # Panics
+	///
+	/// This function panics when there are no players.
+	pub fn new(players: Vec<HotseatPlayer>) -> Self {
+		assert!(!players.is_empty(), "A hotseat needs at least one player");
+		Self {
+			players,
+			current: 0,
+			round: 1,
+		}
+	}
+
+	/// Creates a hotseat of players named "Player 1", "Player 2" and so on,
+	/// with the [default colors](PLAYER_COLORS).
+	pub fn with_player_count(count: usize) -> Self {
+		Self::new(
+			(0..count)
+				.map(|index| HotseatPlayer {
+					name: t!("hotseat-player", number = index + 1),
+					color: PLAYER_COLORS[index % PLAYER_COLORS.len()],
+				})
+				.collect(),
+		)
+	}
+
+	/// Returns the players, in turn order.
+	#[must_use]
+	pub fn players(&self) -> &[HotseatPlayer] {
+		&self.players
+	}
+
+	/// Renames a player.
+	///
+	/// # Panics
+	///
+	/// This function panics when the index is outside of the players.
+	pub fn rename(&mut self, index: usize, name: String) {
+		self.players[index].name = name;
+	}
+
+	/// Returns the index of the player whose turn it is.
+	#[must_use]
+	pub fn current_index(&self) -> usize {
+		self.current
+	}
+
+	/// Returns the player whose turn it is.
+	#[must_use]
+	pub fn current(&self) -> &HotseatPlayer {
+		&self.players[self.current]
+	}
+
+	/// Returns the current round, starting at 1.
+	#[must_use]
+	pub fn round(&self) -> u32 {
+		self.round
+	}
+
+	/// Gives the turn to the next player, without passing the keyboard.
+	/// Returns whether a new round started.
+	pub fn advance(&mut self) -> bool {
+		self.current = (self.current + 1) % self.players.len();
+		if self.current == 0 {
+			self.round += 1;
+		}
+		self.current == 0
+	}
+
+	/// Ends the current player's turn, asking them to pass the keyboard to the
+	/// next player if there is more than one. Returns whether a new round
+	/// started.
+	pub fn end_turn(&mut self, state: &mut ScreenState) -> bool {
+		let new_round = self.advance();
+		if self.players.len() > 1 {
+			state.create_screen(PassKeyboardScreen::new(self.current().clone()).into());
+		}
+		new_round
+	}
+
+	/// Starts over from the first player and round.
+	pub fn reset(&mut self) {
+		self.current = 0;
+		self.round = 1;
+	}
+}
@@ -0,0 +1,104 @@
+//! Playing cards, and the shuffled deck Klondike is dealt from.
+
+use rand::{
+	seq::SliceRandom,
+	Rng,
+};
+use strum::{
+	EnumIter,
+	IntoEnumIterator,
+};
+
+/// Rank of aces, the lowest cards.
+pub const ACE: u8 = 1;
+
+/// Rank of kings, the highest cards.
+pub const KING: u8 = 13;
+
+/// A card's suit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter)]
+pub enum Suit {
+	/// ♠, a black suit.
+	Spades,
+
+	/// ♥, a red suit.
+	Hearts,
+
+	/// ♣, a black suit.
+	Clubs,
+
+	/// ♦, a red suit.
+	Diamonds,
+}
+
+impl Suit {
+	/// Returns the suit's symbol.
+	#[must_use]
+	pub fn symbol(self) -> char {
+		match self {
+			Self::Spades => '♠',
+			Self::Hearts => '♥',
+			Self::Clubs => '♣',
+			Self::Diamonds => '♦',
+		}
+	}
+
+	/// Returns whether the suit is red, rather than black.
+	#[must_use]
+	pub fn is_red(self) -> bool {
+		matches!(self, Self::Hearts | Self::Diamonds)
+	}
+}
+
+/// A playing card, face up or down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Card {
+	/// The card's rank, from [`ACE`] to [`KING`].
+	pub rank: u8,
+
+	/// The card's suit.
+	pub suit: Suit,
+
+	/// Whether the card is turned face up.
+	pub face_up: bool,
+}
+
+impl Card {
+	/// Returns a card turned face down.
+	#[must_use]
+	pub fn new(rank: u8, suit: Suit) -> Self {
+		Self {
+			rank,
+			suit,
+			face_up: false,
+		}
+	}
+
+	/// Returns the card's name, like `10♥` or `K♠`.
+	#[must_use]
+	pub fn name(self) -> String {
+		let rank = match self.rank {
+			ACE => "A".to_string(),
+			11 => "J".to_string(),
+			12 => "Q".to_string(),
+			KING => "K".to_string(),
+			rank => rank.to_string(),
+		};
+		format!("{rank}{}", self.suit.symbol())
+	}
+
+	/// Returns whether the card's suit is red, rather than black.
+	#[must_use]
+	pub fn is_red(self) -> bool {
+		self.suit.is_red()
+	}
+}
+
+/// Returns the 52 cards of a deck face down, shuffled.
+#[must_use]
+pub fn shuffled_deck(rng: &mut impl Rng) -> Vec<Card> {
+	let mut deck: Vec<_> =
+		Suit::iter().flat_map(|suit| (ACE..=KING).map(move |rank| Card::new(rank, suit))).collect();
+	deck.shuffle(rng);
+	deck
+}
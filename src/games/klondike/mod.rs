@@ -0,0 +1,85 @@
+//! Implementation for the game Klondike, the classic solitaire, turning one or
+//! three cards from the stock at once.
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+use crate::{
+	core::events::Event,
+	games::{
+		difficulty::DifficultyRating,
+		preview::GamePreview,
+		registry::{
+			GameId,
+			GameRegistry,
+		},
+		Game,
+		GameMetadata,
+		GameState,
+		GameStaticInfo,
+	},
+	t,
+	ui::{
+		util::get_crate_authors,
+		KlondikeSetupScreen,
+	},
+};
+
+pub mod cards;
+pub mod setup;
+pub mod table;
+
+/// Klondike's [ID](GameId).
+pub const GAME_ID: &str = "klondike";
+
+/// Klondike's manual.
+pub const HELP_PAGE: &str = include_str!("../../../assets/help/klondike.md");
+
+/// Klondike's preview, a few moves into a game.
+const PREVIEW: &str = "\
+ ░░  7♦      A♠   ·   ·   ·
+ K♣  ░░  ░░  ░░  ░░  ░░  ░░
+ Q♥  9♠  ░░  ░░  ░░  ░░  ░░
+     8♦  4♠  ░░  ░░  ░░  ░░
+         3♥ 10♣  ░░  ░░  ░░
+                 J♠  6♦  Q♣";
+
+/// Registers Klondike in a [registry](GameRegistry).
+pub fn register(registry: &mut GameRegistry) {
+	registry.register(Klondike.into());
+}
+
+/// The game [Klondike](https://en.wikipedia.org/wiki/Klondike_(solitaire)).
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct Klondike;
+
+impl Game for Klondike {
+	fn data(&self) -> GameState {
+		GameState::new(
+			GameMetadata::new(
+				GameStaticInfo::new(
+					self.clone().into(),
+					GameId::from(GAME_ID),
+					"Klondike".to_string(),
+					t!("klondike-description").to_string(),
+					"0.0.1".to_string(),
+					get_crate_authors(),
+					DifficultyRating::Medium,
+				)
+				.with_preview(Some(GamePreview::still(PREVIEW))),
+			)
+			.unwrap(),
+			Some(KlondikeSetupScreen::default().into()),
+		)
+	}
+
+	fn event(&mut self, _event: &Event) -> anyhow::Result<()> {
+		Ok(())
+	}
+
+	fn help_page(&self) -> Option<&'static str> {
+		Some(HELP_PAGE)
+	}
+}
@@ -0,0 +1,106 @@
+//! The answers to Klondike's setup screen: how many cards are turned from the
+//! stock at once, and whether safe cards move to the foundations on their own.
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+use strum::{
+	Display,
+	EnumIter,
+};
+
+use crate::{
+	t,
+	ui::components::games::setup::SetupAnswers,
+};
+
+/// How many cards are turned from the stock at once.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Draw {
+	/// One card at a time, the easier game.
+	#[default]
+	One,
+
+	/// Three cards at a time, only the last of which is playable.
+	Three,
+}
+
+impl Draw {
+	/// Returns how many cards are turned at once.
+	#[must_use]
+	pub fn count(self) -> usize {
+		match self {
+			Self::One => 1,
+			Self::Three => 3,
+		}
+	}
+}
+
+/// A question of the setup screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, EnumIter)]
+pub enum KlondikeField {
+	/// How many cards are turned from the stock at once.
+	Draw,
+
+	/// Whether safe cards move to the foundations on their own.
+	AutoMove,
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[must_use]
+pub struct KlondikeSetup {
+	/// How many cards are turned from the stock at once.
+	pub draw: Draw,
+
+	/// Whether cards that no other card could be built on move to the
+	/// foundations on their own after each move.
+	pub auto_move: bool,
+}
+
+impl Default for KlondikeSetup {
+	fn default() -> Self {
+		Self {
+			draw: Draw::default(),
+			auto_move: true,
+		}
+	}
+}
+
+impl SetupAnswers for KlondikeSetup {
+	type Question = KlondikeField;
+
+	fn label(question: KlondikeField) -> &'static str {
+		match question {
+			KlondikeField::Draw => t!("klondike-draw"),
+			KlondikeField::AutoMove => t!("klondike-auto-move"),
+		}
+	}
+
+	fn answer(&self, question: KlondikeField) -> usize {
+		match question {
+			KlondikeField::Draw => self.draw as usize,
+			KlondikeField::AutoMove => usize::from(self.auto_move),
+		}
+	}
+
+	fn answer_text(&self, question: KlondikeField) -> String {
+		match question {
+			KlondikeField::Draw => t!("klondike-draw-count", count = self.draw.count()),
+			KlondikeField::AutoMove => if self.auto_move {
+				t!("klondike-auto-move-on")
+			} else {
+				t!("klondike-auto-move-off")
+			}
+			.to_string(),
+		}
+	}
+
+	fn adjust(&mut self, question: KlondikeField, step: isize) {
+		match question {
+			KlondikeField::Draw => self.draw = if step < 0 { Draw::One } else { Draw::Three },
+			KlondikeField::AutoMove => self.auto_move = step > 0,
+		}
+	}
+}
@@ -0,0 +1,411 @@
+//! A game of Klondike laid out on the table: the stock dealt from, the waste
+//! it's turned onto, the four foundations built up by suit from aces to
+//! kings, and the seven tableau columns built down in alternating colors.
+
+use rand::Rng;
+use strum::IntoEnumIterator;
+
+use super::{
+	cards::{
+		shuffled_deck,
+		Card,
+		Suit,
+		ACE,
+		KING,
+	},
+	setup::Draw,
+};
+
+/// Number of foundations, one per suit.
+pub const FOUNDATIONS: usize = 4;
+
+/// Number of tableau columns.
+pub const COLUMNS: usize = 7;
+
+/// A pile of cards on the table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pile {
+	/// The face-down cards left to deal.
+	Stock,
+
+	/// The cards turned from the stock, the top one playable.
+	Waste,
+
+	/// A foundation, by index.
+	Foundation(usize),
+
+	/// A tableau column, by index from the left.
+	Tableau(usize),
+}
+
+/// A move [suggested](Table::suggest_move) to the player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Move {
+	/// Turning the stock.
+	TurnStock,
+
+	/// Moving a number of cards from the top of a pile to another.
+	Cards {
+		/// The pile the cards are moved from.
+		from: Pile,
+
+		/// How many cards are moved.
+		count: usize,
+
+		/// The pile the cards are moved to.
+		to: Pile,
+	},
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct Table {
+	/// How many cards are turned from the stock at once.
+	draw: Draw,
+
+	/// The stock, its top card last.
+	stock: Vec<Card>,
+
+	/// The waste, its top card last.
+	waste: Vec<Card>,
+
+	/// The foundations, their top card last.
+	foundations: [Vec<Card>; FOUNDATIONS],
+
+	/// The tableau columns, their top card last.
+	tableau: [Vec<Card>; COLUMNS],
+}
+
+impl Table {
+	/// Deals a shuffled deck: one card to the first column, two to the
+	/// second, and so on, with only the top card of each face up, and the
+	/// rest to the stock.
+	pub fn deal(draw: Draw, rng: &mut impl Rng) -> Self {
+		let mut stock = shuffled_deck(rng);
+		let tableau = std::array::from_fn(|column| {
+			let mut cards = stock.split_off(stock.len() - column - 1);
+			if let Some(top) = cards.last_mut() {
+				top.face_up = true;
+			}
+			cards
+		});
+		Self {
+			draw,
+			stock,
+			waste: Vec::new(),
+			foundations: Default::default(),
+			tableau,
+		}
+	}
+
+	/// Returns the cards of a pile, its top card last.
+	#[must_use]
+	pub fn pile(&self, pile: Pile) -> &[Card] {
+		match pile {
+			Pile::Stock => &self.stock,
+			Pile::Waste => &self.waste,
+			Pile::Foundation(index) => &self.foundations[index],
+			Pile::Tableau(column) => &self.tableau[column],
+		}
+	}
+
+	/// Returns the cards of a pile to change them.
+	fn pile_mut(&mut self, pile: Pile) -> &mut Vec<Card> {
+		match pile {
+			Pile::Stock => &mut self.stock,
+			Pile::Waste => &mut self.waste,
+			Pile::Foundation(index) => &mut self.foundations[index],
+			Pile::Tableau(column) => &mut self.tableau[column],
+		}
+	}
+
+	/// Returns how many cards of a pile are face up.
+	#[must_use]
+	pub fn face_up(&self, pile: Pile) -> usize {
+		self.pile(pile).iter().rev().take_while(|card| card.face_up).count()
+	}
+
+	/// Turns cards from the stock onto the waste, or turns the waste back over
+	/// into the stock once it's empty. Returns whether any card was moved.
+	pub fn turn_stock(&mut self) -> bool {
+		if self.stock.is_empty() {
+			self.stock = self
+				.waste
+				.drain(..)
+				.rev()
+				.map(|card| Card {
+					face_up: false,
+					..card
+				})
+				.collect();
+			return !self.stock.is_empty();
+		}
+		for _ in 0..self.draw.count() {
+			let Some(card) = self.stock.pop() else {
+				break;
+			};
+			self.waste.push(Card {
+				face_up: true,
+				..card
+			});
+		}
+		true
+	}
+
+	/// Returns whether a number of cards can be picked up from the top of a
+	/// pile: tableau columns let go of face-up runs built down in alternating
+	/// colors, the waste and foundations of their top card only.
+	#[must_use]
+	pub fn can_pick_up(&self, pile: Pile, count: usize) -> bool {
+		match pile {
+			Pile::Stock => false,
+			Pile::Waste | Pile::Foundation(_) => count == 1 && !self.pile(pile).is_empty(),
+			Pile::Tableau(_) => {
+				let cards = self.pile(pile);
+				count >= 1
+					&& count <= self.face_up(pile)
+					&& cards[cards.len() - count..]
+						.windows(2)
+						.all(|pair| builds_down(pair[0], pair[1]))
+			},
+		}
+	}
+
+	/// Returns whether cards can be placed on a pile: foundations take the
+	/// next card of their suit, starting from an ace, and tableau columns a
+	/// run starting one rank below their top card in the other color, or with
+	/// a king when empty.
+	#[must_use]
+	pub fn can_place(&self, cards: &[Card], pile: Pile) -> bool {
+		let Some(&first) = cards.first() else {
+			return false;
+		};
+		let top = self.pile(pile).last().copied();
+		match pile {
+			Pile::Stock | Pile::Waste => false,
+			Pile::Foundation(_) => {
+				cards.len() == 1
+					&& match top {
+						None => first.rank == ACE,
+						Some(top) => top.suit == first.suit && top.rank + 1 == first.rank,
+					}
+			},
+			Pile::Tableau(_) => match top {
+				None => first.rank == KING,
+				Some(top) => top.face_up && builds_down(top, first),
+			},
+		}
+	}
+
+	/// Moves a number of cards from the top of a pile to another if the rules
+	/// allow it, turning the card left on top of a tableau column face up.
+	/// Returns whether they were moved.
+	pub fn move_cards(&mut self, from: Pile, count: usize, to: Pile) -> bool {
+		if from == to || !self.can_pick_up(from, count) {
+			return false;
+		}
+		let source = self.pile(from);
+		if !self.can_place(&source[source.len() - count..], to) {
+			return false;
+		}
+		let source = self.pile_mut(from);
+		let cards = source.split_off(source.len() - count);
+		if let (Pile::Tableau(_), Some(top)) = (from, source.last_mut()) {
+			top.face_up = true;
+		}
+		self.pile_mut(to).extend(cards);
+		true
+	}
+
+	/// Returns the foundation the top card of a pile can be moved to, if any.
+	#[must_use]
+	pub fn foundation_for(&self, pile: Pile) -> Option<Pile> {
+		if !self.can_pick_up(pile, 1) || matches!(pile, Pile::Foundation(_)) {
+			return None;
+		}
+		let card = self.pile(pile).last()?;
+		(0..FOUNDATIONS)
+			.map(Pile::Foundation)
+			.find(|&foundation| self.can_place(&[*card], foundation))
+	}
+
+	/// Moves the top card of a pile to a foundation, if it can go on one.
+	/// Returns whether it was moved.
+	pub fn send_to_foundation(&mut self, pile: Pile) -> bool {
+		self.foundation_for(pile).is_some_and(|foundation| self.move_cards(pile, 1, foundation))
+	}
+
+	/// Returns the rank that both suits of a color are built up to on the
+	/// foundations, 0 for suits without a foundation yet.
+	fn foundation_rank(&self, red: bool) -> u8 {
+		Suit::iter()
+			.filter(|suit| suit.is_red() == red)
+			.map(|suit| {
+				self.foundations
+					.iter()
+					.find(|foundation| foundation.first().is_some_and(|card| card.suit == suit))
+					.map_or(0, |foundation| foundation.len() as u8)
+			})
+			.min()
+			.unwrap_or(0)
+	}
+
+	/// Moves every card that's safe to move to the foundations there, until
+	/// there's none left: aces and twos, and cards that no card of the other
+	/// color left could be built on. Returns how many were moved.
+	pub fn auto_move(&mut self) -> usize {
+		let mut moved = 0;
+		loop {
+			let safe =
+				std::iter::once(Pile::Waste).chain((0..COLUMNS).map(Pile::Tableau)).find(|&pile| {
+					let Some(card) = self.pile(pile).last() else {
+						return false;
+					};
+					let safe =
+						card.rank <= 2 || self.foundation_rank(!card.is_red()) + 1 >= card.rank;
+					safe && self.foundation_for(pile).is_some()
+				});
+			match safe {
+				Some(pile) if self.send_to_foundation(pile) => moved += 1,
+				_ => return moved,
+			}
+		}
+	}
+
+	/// Suggests a move that gets the game further, if there's any: a card to
+	/// a foundation, a column's face-up cards to another column, uncovering
+	/// the card under them, the waste's top card to a column, or else
+	/// turning the stock.
+	#[must_use]
+	pub fn suggest_move(&self) -> Option<Move> {
+		let columns = || (0..COLUMNS).map(Pile::Tableau);
+		let to_column = |from: Pile, count: usize| {
+			if !self.can_pick_up(from, count) {
+				return None;
+			}
+			let cards = &self.pile(from)[self.pile(from).len() - count..];
+			let to = columns().find(|&to| to != from && self.can_place(cards, to))?;
+			Some(Move::Cards { from, count, to })
+		};
+		let to_foundation = std::iter::once(Pile::Waste).chain(columns()).find_map(|from| {
+			let to = self.foundation_for(from)?;
+			Some(Move::Cards { from, count: 1, to })
+		});
+		let uncovering = columns().find_map(|from| {
+			let count = self.face_up(from);
+			(count < self.pile(from).len()).then(|| to_column(from, count)).flatten()
+		});
+		let turn_stock =
+			(!self.stock.is_empty() || !self.waste.is_empty()).then_some(Move::TurnStock);
+		to_foundation.or(uncovering).or_else(|| to_column(Pile::Waste, 1)).or(turn_stock)
+	}
+
+	/// Returns whether every card was built on the foundations.
+	#[must_use]
+	pub fn is_won(&self) -> bool {
+		self.foundations.iter().all(|foundation| foundation.len() == KING as usize)
+	}
+}
+
+/// Returns whether a card can be placed on another in the tableau: one rank
+/// below it, in the other color.
+fn builds_down(below: Card, above: Card) -> bool {
+	below.is_red() != above.is_red() && below.rank == above.rank + 1
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		Move,
+		Pile,
+		Table,
+		COLUMNS,
+	};
+	use crate::{
+		games::klondike::{
+			cards::{
+				Card,
+				Suit,
+				KING,
+			},
+			setup::Draw,
+		},
+		services::rng,
+	};
+
+	#[test]
+	fn deals_and_plays_by_the_rules() {
+		let mut table = Table::deal(Draw::Three, &mut rng::seeded(7));
+		for column in 0..COLUMNS {
+			assert_eq!(table.pile(Pile::Tableau(column)).len(), column + 1);
+			assert_eq!(table.face_up(Pile::Tableau(column)), 1);
+		}
+		assert_eq!(table.pile(Pile::Stock).len(), 24);
+		assert!(table.turn_stock());
+		assert_eq!(table.pile(Pile::Waste).len(), 3);
+		while !table.pile(Pile::Stock).is_empty() {
+			table.turn_stock();
+		}
+		assert!(table.turn_stock());
+		assert_eq!(table.pile(Pile::Stock).len(), 24);
+
+		let face_up = |rank, suit| Card {
+			face_up: true,
+			..Card::new(rank, suit)
+		};
+		table.tableau[0] = vec![face_up(KING, Suit::Spades)];
+		table.tableau[1] = vec![face_up(2, Suit::Clubs), face_up(12, Suit::Hearts)];
+		assert!(table.move_cards(Pile::Tableau(1), 1, Pile::Tableau(0)));
+		assert!(table.pile(Pile::Tableau(1))[0].face_up);
+		assert!(!table.move_cards(Pile::Tableau(1), 1, Pile::Tableau(0)));
+
+		table.waste = vec![face_up(1, Suit::Clubs)];
+		for column in 2..COLUMNS {
+			table.tableau[column].clear();
+		}
+		assert_eq!(table.auto_move(), 2);
+		assert_eq!(table.pile(Pile::Foundation(0)).len(), 2);
+		assert!(table.pile(Pile::Tableau(1)).is_empty());
+		assert!(!table.is_won());
+	}
+
+	#[test]
+	fn suggests_moves_that_get_the_game_further() {
+		let mut table = Table::deal(Draw::One, &mut rng::seeded(7));
+		let face_up = |rank, suit| Card {
+			face_up: true,
+			..Card::new(rank, suit)
+		};
+		for column in 0..COLUMNS {
+			table.tableau[column].clear();
+		}
+		table.tableau[0] = vec![face_up(KING, Suit::Spades)];
+		table.tableau[1] = vec![face_up(12, Suit::Hearts)];
+		table.tableau[2] = vec![Card::new(3, Suit::Clubs), face_up(12, Suit::Diamonds)];
+		assert_eq!(
+			table.suggest_move(),
+			Some(Move::Cards {
+				from: Pile::Tableau(2),
+				count: 1,
+				to: Pile::Tableau(0),
+			})
+		);
+
+		table.waste = vec![face_up(1, Suit::Hearts)];
+		assert_eq!(
+			table.suggest_move(),
+			Some(Move::Cards {
+				from: Pile::Waste,
+				count: 1,
+				to: Pile::Foundation(0),
+			})
+		);
+
+		table.waste.clear();
+		table.tableau[2].clear();
+		assert_eq!(table.suggest_move(), Some(Move::TurnStock));
+		table.stock.clear();
+		assert_eq!(table.suggest_move(), None);
+	}
+}
@@ -0,0 +1,436 @@
+//! A Minesweeper board and the rules for uncovering and flagging its tiles.
+
+use rand::{
+	seq::SliceRandom,
+	Rng,
+};
+
+use crate::services::rng;
+
+/// A position on the board, as `(x, y)`.
+pub type Position = (usize, usize);
+
+/// State of a tile, as seen by the player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum TileState {
+	Covered,
+	Flagged,
+	Uncovered,
+}
+
+/// A tile on the board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[must_use]
+pub struct Tile {
+	/// Whether the tile hides a mine.
+	pub mine: bool,
+
+	/// State of the tile.
+	pub state: TileState,
+
+	/// Number of mines in the tile's neighbors.
+	pub neighboring_mines: u8,
+}
+
+impl Default for Tile {
+	fn default() -> Self {
+		Self {
+			mine: false,
+			state: TileState::Covered,
+			neighboring_mines: 0,
+		}
+	}
+}
+
+/// The outcome of uncovering a tile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum UncoverOutcome {
+	Nothing,
+	Safe,
+	Mine,
+}
+
+/// A Minesweeper board.
+///
+/// Mines are placed when the first tile is uncovered, so that the first tile
+/// and its neighbors are never mines, unless the board was created with fixed
+/// mines through [`Self::with_mines`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct Board {
+	/// Width of the board, in tiles.
+	width: usize,
+
+	/// Height of the board, in tiles.
+	height: usize,
+
+	/// Number of mines on the board.
+	mine_count: usize,
+
+	/// Tiles of the board, row by row.
+	tiles: Vec<Tile>,
+
+	/// Whether the mines have been placed.
+	mines_placed: bool,
+
+	/// Whether a mine has been uncovered.
+	exploded: bool,
+}
+
+impl Board {
+	/// Creates a new board, without placing mines yet. The mine count is
+	/// capped so that the first uncovered tile and its neighbors can always be
+	/// kept free of mines.
+	pub fn new(width: usize, height: usize, mine_count: usize) -> Self {
+		Self {
+			width,
+			height,
+			mine_count: mine_count.min((width * height).saturating_sub(9)),
+			tiles: vec![Tile::default(); width * height],
+			mines_placed: false,
+			exploded: false,
+		}
+	}
+
+	/// Creates a new board with mines at fixed positions.
+	pub fn with_mines(width: usize, height: usize, mines: &[Position]) -> Self {
+		let mut board = Self::new(width, height, 0);
+		board.mine_count = mines.len();
+		for &position in mines {
+			let index = board.index(position);
+			board.tiles[index].mine = true;
+		}
+		board.finish_placing_mines();
+		board
+	}
+
+	/// Returns the width of the board, in tiles.
+	#[must_use]
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	/// Returns the height of the board, in tiles.
+	#[must_use]
+	pub fn height(&self) -> usize {
+		self.height
+	}
+
+	/// Returns the number of mines on the board.
+	#[must_use]
+	pub fn mine_count(&self) -> usize {
+		self.mine_count
+	}
+
+	/// Returns the number of flagged tiles.
+	#[must_use]
+	pub fn flag_count(&self) -> usize {
+		self.tiles.iter().filter(|tile| tile.state == TileState::Flagged).count()
+	}
+
+	/// Returns the tile at a position.
+	///
+	/// # Panics
+	///
+	/// This function panics when the position is outside of the board.
+	pub fn get(&self, position: Position) -> Tile {
+		self.tiles[self.index(position)]
+	}
+
+	/// Returns the index of a position in [`Self::tiles`].
+	fn index(&self, (x, y): Position) -> usize {
+		assert!(
+			x < self.width && y < self.height,
+			"({x}, {y}) is outside of the board"
+		);
+		y * self.width + x
+	}
+
+	/// Returns the neighbors of a position (up to eight of them).
+	#[must_use]
+	pub fn neighbors(&self, (x, y): Position) -> Vec<Position> {
+		let mut neighbors = Vec::with_capacity(8);
+		for neighbor_y in y.saturating_sub(1)..=(y + 1).min(self.height - 1) {
+			for neighbor_x in x.saturating_sub(1)..=(x + 1).min(self.width - 1) {
+				if (neighbor_x, neighbor_y) != (x, y) {
+					neighbors.push((neighbor_x, neighbor_y));
+				}
+			}
+		}
+		neighbors
+	}
+
+	/// Creates a new board with mines placed at random, keeping a position and
+	/// its neighbors free as if it had been uncovered first. The same generator
+	/// state always gives the same board.
+	///
+	/// # Panics
+	///
+	/// This function panics when the position is outside of the board.
+	pub fn generate(
+		width: usize,
+		height: usize,
+		mine_count: usize,
+		safe_position: Position,
+		rng: &mut impl Rng,
+	) -> Self {
+		let mut board = Self::new(width, height, mine_count);
+		board.place_mines_with(safe_position, rng);
+		board
+	}
+
+	/// Places mines randomly with the [shared generator](rng), keeping a
+	/// position and its neighbors free.
+	fn place_mines(&mut self, safe_position: Position) {
+		rng::with_rng(|rng| self.place_mines_with(safe_position, rng));
+	}
+
+	/// Places mines randomly with a generator, keeping a position and its
+	/// neighbors free.
+	fn place_mines_with(&mut self, safe_position: Position, rng: &mut impl Rng) {
+		let mut safe_positions = self.neighbors(safe_position);
+		safe_positions.push(safe_position);
+		let mut candidates: Vec<usize> = (0..self.tiles.len())
+			.filter(|&index| !safe_positions.contains(&(index % self.width, index / self.width)))
+			.collect();
+		candidates.shuffle(rng);
+		for index in candidates.into_iter().take(self.mine_count) {
+			self.tiles[index].mine = true;
+		}
+		self.finish_placing_mines();
+	}
+
+	/// Counts neighboring mines for every tile once mines are placed.
+	fn finish_placing_mines(&mut self) {
+		for index in 0..self.tiles.len() {
+			let position = (index % self.width, index / self.width);
+			let count = self
+				.neighbors(position)
+				.into_iter()
+				.filter(|&neighbor| self.get(neighbor).mine)
+				.count();
+			self.tiles[index].neighboring_mines = count as u8;
+		}
+		self.mines_placed = true;
+	}
+
+	/// Uncovers a tile. Uncovering a tile without neighboring mines also
+	/// uncovers its neighbors, and uncovering an already uncovered tile
+	/// [chords](Self::chord) it.
+	pub fn uncover(&mut self, position: Position) -> UncoverOutcome {
+		if self.is_over() {
+			return UncoverOutcome::Nothing;
+		}
+		if !self.mines_placed {
+			self.place_mines(position);
+		}
+		match self.get(position).state {
+			TileState::Flagged => UncoverOutcome::Nothing,
+			TileState::Uncovered => self.chord(position),
+			TileState::Covered => self.flood_uncover(position),
+		}
+	}
+
+	/// Uncovers a covered tile, spreading to the neighbors of tiles without
+	/// neighboring mines.
+	fn flood_uncover(&mut self, position: Position) -> UncoverOutcome {
+		let mut stack = vec![position];
+		while let Some(current) = stack.pop() {
+			let index = self.index(current);
+			let tile = &mut self.tiles[index];
+			if tile.state != TileState::Covered {
+				continue;
+			}
+			tile.state = TileState::Uncovered;
+			if tile.mine {
+				self.exploded = true;
+				return UncoverOutcome::Mine;
+			}
+			if tile.neighboring_mines == 0 {
+				stack.extend(self.neighbors(current));
+			}
+		}
+		UncoverOutcome::Safe
+	}
+
+	/// Uncovers the covered neighbors of an uncovered tile, if as many of its
+	/// neighbors are flagged as it has neighboring mines.
+	pub fn chord(&mut self, position: Position) -> UncoverOutcome {
+		let tile = self.get(position);
+		let neighbors = self.neighbors(position);
+		let flags = neighbors
+			.iter()
+			.filter(|&&neighbor| self.get(neighbor).state == TileState::Flagged)
+			.count();
+		if tile.state != TileState::Uncovered || flags != usize::from(tile.neighboring_mines) {
+			return UncoverOutcome::Nothing;
+		}
+		let mut outcome = UncoverOutcome::Nothing;
+		for neighbor in neighbors {
+			if self.get(neighbor).state == TileState::Covered {
+				outcome = self.flood_uncover(neighbor);
+				if outcome == UncoverOutcome::Mine {
+					return outcome;
+				}
+			}
+		}
+		outcome
+	}
+
+	/// Flags or unflags a covered tile.
+	pub fn toggle_flag(&mut self, position: Position) {
+		if self.is_over() {
+			return;
+		}
+		let index = self.index(position);
+		let tile = &mut self.tiles[index];
+		tile.state = match tile.state {
+			TileState::Covered => TileState::Flagged,
+			TileState::Flagged => TileState::Covered,
+			TileState::Uncovered => TileState::Uncovered,
+		};
+	}
+
+	/// Returns whether a mine has been uncovered.
+	#[must_use]
+	pub fn is_exploded(&self) -> bool {
+		self.exploded
+	}
+
+	/// Returns whether every tile without a mine has been uncovered.
+	#[must_use]
+	pub fn is_cleared(&self) -> bool {
+		self.mines_placed
+			&& self.tiles.iter().all(|tile| tile.mine || tile.state == TileState::Uncovered)
+	}
+
+	/// Returns whether the game on this board is over, won or lost.
+	#[must_use]
+	pub fn is_over(&self) -> bool {
+		self.exploded || self.is_cleared()
+	}
+
+	/// Returns a position moved one tile in a direction, staying on the board.
+	#[must_use]
+	pub fn move_position(&self, (x, y): Position, dx: isize, dy: isize) -> Position {
+		(
+			x.saturating_add_signed(dx).min(self.width - 1),
+			y.saturating_add_signed(dy).min(self.height - 1),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use proptest::prelude::*;
+
+	use super::{
+		Board,
+		Position,
+		TileState,
+		UncoverOutcome,
+	};
+	use crate::services::rng;
+
+	/// Generates a board's size, mine count, first uncovered position and seed.
+	fn board_parameters() -> impl Strategy<Value = (usize, usize, usize, Position, u64)> {
+		(1..30_usize, 1..30_usize).prop_flat_map(|(width, height)| {
+			(
+				Just(width),
+				Just(height),
+				0..=width * height,
+				(0..width, 0..height),
+				any::<u64>(),
+			)
+		})
+	}
+
+	/// Returns every position of a board.
+	fn positions(board: &Board) -> impl Iterator<Item = Position> + '_ {
+		(0..board.height()).flat_map(|y| (0..board.width()).map(move |x| (x, y)))
+	}
+
+	proptest! {
+		#[test]
+		fn generates_the_requested_mine_count(
+			(width, height, mine_count, safe_position, seed) in board_parameters()
+		) {
+			let board =
+				Board::generate(width, height, mine_count, safe_position, &mut rng::seeded(seed));
+			let mines = positions(&board).filter(|&position| board.get(position).mine).count();
+			prop_assert_eq!(mines, board.mine_count());
+			prop_assert!(board.mine_count() <= mine_count);
+			prop_assert_eq!(board.mine_count(), mine_count.min((width * height).saturating_sub(9)));
+		}
+
+		#[test]
+		fn keeps_the_first_position_and_its_neighbors_free(
+			(width, height, mine_count, safe_position, seed) in board_parameters()
+		) {
+			let board =
+				Board::generate(width, height, mine_count, safe_position, &mut rng::seeded(seed));
+			prop_assert!(!board.get(safe_position).mine);
+			for neighbor in board.neighbors(safe_position) {
+				prop_assert!(!board.get(neighbor).mine);
+			}
+			prop_assert_eq!(board.get(safe_position).neighboring_mines, 0);
+		}
+
+		#[test]
+		fn counts_neighboring_mines(
+			(width, height, mine_count, safe_position, seed) in board_parameters()
+		) {
+			let board =
+				Board::generate(width, height, mine_count, safe_position, &mut rng::seeded(seed));
+			for position in positions(&board) {
+				let count =
+					board.neighbors(position).into_iter().filter(|&n| board.get(n).mine).count();
+				prop_assert_eq!(usize::from(board.get(position).neighboring_mines), count);
+			}
+		}
+
+		#[test]
+		fn generates_the_same_board_from_the_same_seed(
+			(width, height, mine_count, safe_position, seed) in board_parameters()
+		) {
+			let first =
+				Board::generate(width, height, mine_count, safe_position, &mut rng::seeded(seed));
+			let second =
+				Board::generate(width, height, mine_count, safe_position, &mut rng::seeded(seed));
+			prop_assert!(positions(&first).all(|position| first.get(position) == second.get(position)));
+		}
+
+		#[test]
+		fn first_uncover_is_safe_and_never_uncovers_mines(
+			(width, height, mine_count, safe_position, seed) in board_parameters()
+		) {
+			let mut board =
+				Board::generate(width, height, mine_count, safe_position, &mut rng::seeded(seed));
+			prop_assert_eq!(board.uncover(safe_position), UncoverOutcome::Safe);
+			prop_assert!(!board.is_exploded());
+			for position in positions(&board) {
+				let tile = board.get(position);
+				prop_assert!(!(tile.mine && tile.state == TileState::Uncovered));
+			}
+		}
+
+		#[test]
+		fn uncovering_every_safe_tile_clears_the_board(
+			(width, height, mine_count, safe_position, seed) in board_parameters()
+		) {
+			let mut board =
+				Board::generate(width, height, mine_count, safe_position, &mut rng::seeded(seed));
+			let safe_tiles: Vec<_> =
+				positions(&board).filter(|&position| !board.get(position).mine).collect();
+			for position in safe_tiles {
+				if board.get(position).state == TileState::Covered {
+					prop_assert_eq!(board.uncover(position), UncoverOutcome::Safe);
+				}
+			}
+			prop_assert!(board.is_cleared());
+			prop_assert!(!board.is_exploded());
+		}
+	}
+}
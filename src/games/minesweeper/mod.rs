@@ -1,26 +1,64 @@
 //! Implementation for the game Minesweeper.
 
-use crossterm::event::Event;
 use serde_derive::{
 	Deserialize,
 	Serialize,
 };
 
 use crate::{
+	core::events::Event,
 	games::{
+		difficulty::DifficultyRating,
+		preview::GamePreview,
+		registry::{
+			GameId,
+			GameRegistry,
+		},
 		Game,
 		GameMetadata,
 		GameState,
 		GameStaticInfo,
 		Games,
 	},
+	t,
 	ui::{
-		games::minesweeper::board_setup::MinesweeperSetupScreen,
+		games::minesweeper::{
+			board_setup::MinesweeperSetupScreen,
+			demo::MinesweeperDemoScreen,
+		},
 		screens::Screens,
+		util::get_crate_authors,
 		Screen,
 	},
 };
 
+pub mod board;
+pub mod round;
+pub mod setup;
+
+/// Minesweeper's [ID](GameId).
+pub const GAME_ID: &str = "minesweeper";
+
+/// Minesweeper's manual.
+pub const HELP_PAGE: &str = include_str!("../../../assets/help/minesweeper.md");
+
+/// Minesweeper's thumbnail.
+pub const THUMBNAIL: &[u8] = include_bytes!("../../../assets/images/minesweeper.png");
+
+/// Minesweeper's preview, a board being cleared.
+const PREVIEW: &str = "\
+■ ■ ■ 1 · · · ·
+■ ■ 2 1 · · · ·
+■ 2 1 · · 1 1 1
+■ 1 · · · 1 ⚑ ■
+1 1 · 1 1 2 ■ ■
+⚑ 1 · 1 ■ ■ ■ ■";
+
+/// Registers Minesweeper in a [registry](GameRegistry).
+pub fn register(registry: &mut GameRegistry) {
+	registry.register(Minesweeper.into());
+}
+
 /// The game [Minesweeper](https://en.wikipedia.org/wiki/Minesweeper_(video_game)).
 #[derive(Clone, Default, Serialize, Deserialize)]
 pub struct Minesweeper;
@@ -28,18 +66,36 @@ pub struct Minesweeper;
 impl Game for Minesweeper {
 	fn data(&self) -> GameState {
 		GameState::new(
-			GameMetadata::new(GameStaticInfo::new(
-				self.clone().into(),
-				"Minesweeper".to_string(),
-				"A tile-based game of looking for mines and avoiding responsibilities.".to_string(),
-				"0.0.1".to_string(),
-			))
+			GameMetadata::new(
+				GameStaticInfo::new(
+					self.clone().into(),
+					GameId::from(GAME_ID),
+					"Minesweeper".to_string(),
+					t!("minesweeper-description").to_string(),
+					"0.0.1".to_string(),
+					get_crate_authors(),
+					DifficultyRating::Medium,
+				)
+				.with_preview(Some(GamePreview::still(PREVIEW))),
+			)
 			.unwrap(),
-			Some(MinesweeperSetupScreen::new().into()),
+			Some(MinesweeperSetupScreen::default().into()),
 		)
 	}
 
 	fn event(&mut self, _event: &Event) -> anyhow::Result<()> {
 		Ok(())
 	}
+
+	fn help_page(&self) -> Option<&'static str> {
+		Some(HELP_PAGE)
+	}
+
+	fn thumbnail(&self) -> Option<&'static [u8]> {
+		Some(THUMBNAIL)
+	}
+
+	fn demo_screen(&self) -> Option<Screens> {
+		Some(MinesweeperDemoScreen::default().into())
+	}
 }
@@ -0,0 +1,141 @@
+//! A round of Minesweeper: a [board](Board) and the player's cursor on it.
+
+use std::time::Duration;
+
+use crate::{
+	core::events::KeyCode,
+	games::{
+		demo::DemoPlayable,
+		minesweeper::board::{
+			Board,
+			Position,
+			TileState,
+			UncoverOutcome,
+		},
+	},
+};
+
+/// Width of the boards played in [demos](DemoPlayable), in tiles.
+const DEMO_WIDTH: usize = 16;
+
+/// Height of the boards played in demos, in tiles.
+const DEMO_HEIGHT: usize = 10;
+
+/// Number of mines on the boards played in demos.
+const DEMO_MINES: usize = 22;
+
+/// A round of Minesweeper being played.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct Round {
+	/// The board being played on.
+	pub board: Board,
+
+	/// Position of the player's cursor.
+	pub cursor: Position,
+}
+
+impl Round {
+	/// Creates a new round on a board, with the cursor at the top left tile.
+	pub fn new(board: Board) -> Self {
+		Self {
+			board,
+			cursor: (0, 0),
+		}
+	}
+
+	/// Handles a key press according to the game's controls, returning the
+	/// outcome of any tile uncovered.
+	pub fn handle_key(&mut self, key: KeyCode) -> UncoverOutcome {
+		let (dx, dy) = match key {
+			KeyCode::Up => (0, -1),
+			KeyCode::Down => (0, 1),
+			KeyCode::Left => (-1, 0),
+			KeyCode::Right => (1, 0),
+			KeyCode::Char(' ') | KeyCode::Enter => return self.board.uncover(self.cursor),
+			KeyCode::Char('f' | 'F') => {
+				self.board.toggle_flag(self.cursor);
+				return UncoverOutcome::Nothing;
+			},
+			_ => return UncoverOutcome::Nothing,
+		};
+		self.cursor = self.board.move_position(self.cursor, dx, dy);
+		UncoverOutcome::Nothing
+	}
+
+	/// Returns the tile a demo goes for next and the key played on it. Tiles
+	/// that are certainly mines are flagged and tiles that are certainly safe
+	/// are uncovered, the closest first, and without any, the closest covered
+	/// tile is uncovered as a guess.
+	fn demo_target(&self) -> Option<(Position, KeyCode)> {
+		let board = &self.board;
+		let distance = |(x, y): Position| self.cursor.0.abs_diff(x) + self.cursor.1.abs_diff(y);
+		let positions = (0..board.height()).flat_map(|y| (0..board.width()).map(move |x| (x, y)));
+		let mut deductions = Vec::new();
+		for position in positions.clone() {
+			let tile = board.get(position);
+			if tile.state != TileState::Uncovered || tile.neighboring_mines == 0 {
+				continue;
+			}
+			let neighbors = board.neighbors(position);
+			let with_state = |state| {
+				neighbors.iter().filter(move |&&neighbor| board.get(neighbor).state == state)
+			};
+			let flagged = with_state(TileState::Flagged).count();
+			let covered: Vec<_> = with_state(TileState::Covered).copied().collect();
+			let mines = usize::from(tile.neighboring_mines);
+			if flagged == mines {
+				deductions
+					.extend(covered.into_iter().map(|neighbor| (neighbor, KeyCode::Char(' '))));
+			} else if flagged + covered.len() == mines {
+				deductions
+					.extend(covered.into_iter().map(|neighbor| (neighbor, KeyCode::Char('f'))));
+			}
+		}
+		let guesses = positions
+			.filter(|&position| board.get(position).state == TileState::Covered)
+			.map(|position| (position, KeyCode::Char(' ')));
+		let closest =
+			|moves: Vec<_>| moves.into_iter().min_by_key(|&(position, _)| distance(position));
+		closest(deductions).or_else(|| closest(guesses.collect()))
+	}
+}
+
+impl DemoPlayable for Round {
+	type Move = KeyCode;
+
+	const MOVE_INTERVAL: Duration = Duration::from_millis(150);
+
+	fn new_demo() -> Self {
+		let mut round = Self::new(Board::new(DEMO_WIDTH, DEMO_HEIGHT, DEMO_MINES));
+		round.cursor = (DEMO_WIDTH / 2, DEMO_HEIGHT / 2);
+		round
+	}
+
+	/// Moves the cursor a tile towards the [tile gone for](Self::demo_target),
+	/// or plays the key on it once there.
+	fn demo_move(&self) -> Option<Self::Move> {
+		if self.board.is_over() {
+			return None;
+		}
+		let ((x, y), key) = self.demo_target()?;
+		let (cursor_x, cursor_y) = self.cursor;
+		Some(
+			if x < cursor_x {
+				KeyCode::Left
+			} else if x > cursor_x {
+				KeyCode::Right
+			} else if y < cursor_y {
+				KeyCode::Up
+			} else if y > cursor_y {
+				KeyCode::Down
+			} else {
+				key
+			},
+		)
+	}
+
+	fn play_demo_move(&mut self, key: Self::Move) {
+		self.handle_key(key);
+	}
+}
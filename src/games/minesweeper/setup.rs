@@ -0,0 +1,137 @@
+//! The answers to Minesweeper's setup screen: the size of the board and how
+//! many mines hide in it.
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+use strum::{
+	Display,
+	EnumIter,
+};
+
+use crate::{
+	t,
+	ui::components::games::setup::SetupAnswers,
+};
+
+/// Smallest width or height of a board, in tiles.
+pub const MIN_SIZE: usize = 5;
+
+/// Largest width of a board, in tiles.
+pub const MAX_WIDTH: usize = 30;
+
+/// Largest height of a board, in tiles.
+pub const MAX_HEIGHT: usize = 24;
+
+/// A question of the setup screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, EnumIter)]
+pub enum BoardField {
+	/// The width of the board.
+	Width,
+
+	/// The height of the board.
+	Height,
+
+	/// How many mines hide in the board.
+	Mines,
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[must_use]
+pub struct BoardSetup {
+	/// Width of the board, in tiles.
+	pub width: usize,
+
+	/// Height of the board, in tiles.
+	pub height: usize,
+
+	/// How many mines hide in the board.
+	pub mines: usize,
+}
+
+impl Default for BoardSetup {
+	fn default() -> Self {
+		Self {
+			width: 16,
+			height: 16,
+			mines: 40,
+		}
+	}
+}
+
+impl BoardSetup {
+	/// Returns the most mines a [board](super::board::Board) of this size can
+	/// hide, keeping the first tile uncovered and its neighbors free of them.
+	#[must_use]
+	pub fn max_mines(&self) -> usize {
+		(self.width * self.height).saturating_sub(9).max(1)
+	}
+
+	/// Keeps every answer in its bounds, like after loading them from a
+	/// preset edited by hand.
+	pub fn clamped(mut self) -> Self {
+		self.width = self.width.clamp(MIN_SIZE, MAX_WIDTH);
+		self.height = self.height.clamp(MIN_SIZE, MAX_HEIGHT);
+		self.mines = self.mines.clamp(1, self.max_mines());
+		self
+	}
+}
+
+impl SetupAnswers for BoardSetup {
+	type Question = BoardField;
+
+	fn label(question: BoardField) -> &'static str {
+		match question {
+			BoardField::Width => t!("minesweeper-width"),
+			BoardField::Height => t!("minesweeper-height"),
+			BoardField::Mines => t!("minesweeper-mines"),
+		}
+	}
+
+	fn answer(&self, question: BoardField) -> usize {
+		match question {
+			BoardField::Width => self.width,
+			BoardField::Height => self.height,
+			BoardField::Mines => self.mines,
+		}
+	}
+
+	fn adjust(&mut self, field: BoardField, step: isize) {
+		let (value, max) = match field {
+			BoardField::Width => (&mut self.width, MAX_WIDTH),
+			BoardField::Height => (&mut self.height, MAX_HEIGHT),
+			BoardField::Mines => {
+				let max = self.max_mines();
+				(&mut self.mines, max)
+			},
+		};
+		let min = if field == BoardField::Mines { 1 } else { MIN_SIZE };
+		*value = value.saturating_add_signed(step).clamp(min, max);
+		self.mines = self.mines.min(self.max_mines());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		BoardField,
+		BoardSetup,
+		MAX_WIDTH,
+		MIN_SIZE,
+	};
+	use crate::ui::components::games::setup::SetupAnswers;
+
+	#[test]
+	fn answers_stay_in_bounds() {
+		let mut setup = BoardSetup::default();
+		setup.adjust(BoardField::Width, 100);
+		assert_eq!(setup.width, MAX_WIDTH);
+		setup.adjust(BoardField::Mines, 1000);
+		assert_eq!(setup.mines, setup.max_mines());
+		setup.adjust(BoardField::Height, -100);
+		assert_eq!(setup.height, MIN_SIZE);
+		assert_eq!(setup.mines, MAX_WIDTH * MIN_SIZE - 9);
+	}
+}
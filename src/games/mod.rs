@@ -6,16 +6,13 @@
 //! [`crate::ui::screens::games`] module.
 
 use std::{
+	collections::BTreeMap,
 	fmt::{
 		Display,
 		Formatter,
 	},
 	path::PathBuf,
-	time::{
-		Duration,
-		SystemTime,
-		UNIX_EPOCH,
-	},
+	time::Duration,
 };
 
 use chrono::{
@@ -23,31 +20,89 @@ use chrono::{
 	Local,
 	Utc,
 };
-use crossterm::event::Event;
 use derive_new::new;
 use enum_dispatch::enum_dispatch;
-use pluralizer::pluralize;
 use serde_derive::{
 	Deserialize,
 	Serialize,
 };
-use strum::{
-	Display,
-	EnumIter,
-	IntoEnumIterator,
-};
+use strum::Display;
 
 use crate::{
-	core::get_save_dir,
-	games::minesweeper::Minesweeper,
+	core::{
+		error::{
+			self,
+			AppError,
+		},
+		events::Event,
+		get_save_dir,
+		save_file,
+		time::{
+			SystemTime,
+			UNIX_EPOCH,
+		},
+	},
+	games::{
+		difficulty::DifficultyRating,
+		modes::GameMode,
+		preview::GamePreview,
+		registry::GameId,
+	},
+	services::{
+		autosave,
+		badges,
+		rating::{
+			updated_rating,
+			MatchResult,
+			DEFAULT_RATING,
+		},
+		stats::in_history,
+	},
+	t,
 	ui::{
-		screens::Screens,
+		screens::{
+			ModeSelectPopup,
+			Screens,
+		},
 		widgets::scrollable_list::ListItem,
 		Screen,
 	},
 };
 
+#[cfg(feature = "chess")]
+pub mod chess;
+pub mod demo;
+pub mod difficulty;
+pub mod hotseat;
+#[cfg(feature = "klondike")]
+pub mod klondike;
+#[cfg(feature = "minesweeper")]
 pub mod minesweeper;
+pub mod modes;
+pub mod preview;
+pub mod registry;
+#[cfg(feature = "scripting")]
+pub mod scripted;
+#[cfg(feature = "sudoku")]
+pub mod sudoku;
+#[cfg(feature = "tron")]
+pub mod tron;
+#[cfg(feature = "twenty-forty-eight")]
+pub mod twenty_forty_eight;
+
+#[cfg(not(any(
+	feature = "minesweeper",
+	feature = "tron",
+	feature = "twenty-forty-eight",
+	feature = "sudoku",
+	feature = "chess",
+	feature = "klondike",
+	feature = "scripting"
+)))]
+compile_error!(
+	"Terminal Arcade needs at least one game: enable the `minesweeper`, `tron`, \
+	 `twenty-forty-eight`, `sudoku`, `chess`, `klondike` or `scripting` feature"
+);
 
 /// State for a [Game].
 #[derive(Clone, new)]
@@ -72,15 +127,67 @@ pub trait Game {
 
 	/// Called when an event is passed to the game.
 	fn event(&mut self, event: &Event) -> anyhow::Result<()>;
+
+	/// The game's manual (rules, controls, scoring), bundled from the
+	/// `assets/help` directory. Opened with \[F1\] from within the game.
+	fn help_page(&self) -> Option<&'static str> {
+		None
+	}
+
+	/// The game's thumbnail as PNG data, bundled from the `assets/images`
+	/// directory. Shown in the game selection screen with the `images`
+	/// feature.
+	fn thumbnail(&self) -> Option<&'static [u8]> {
+		None
+	}
+
+	/// The [modes](GameMode) the game can be played in. Games with more than
+	/// the standard mode are launched from a mode selector.
+	fn modes(&self) -> Vec<GameMode> {
+		vec![GameMode::Standard]
+	}
+
+	/// Creates the game's screen for a mode. Games opting into other
+	/// [modes](Self::modes) should override this.
+	fn mode_screen(&self, _mode: GameMode) -> Option<Screens> {
+		self.data().created_screen
+	}
+
+	/// The files the game's save data is kept in, whether they exist or not:
+	/// [common](common_save_files) ones by default. Games keeping more should
+	/// override this.
+	fn save_files(&self) -> Vec<PathBuf> {
+		common_save_files(&self.data().metadata.static_info.id, &self.modes())
+	}
+
+	/// Creates a screen where the game [plays itself](demo), shown in attract
+	/// mode and from the game's setup screen. Games with a
+	/// [policy](demo::DemoPlayable) should override this.
+	fn demo_screen(&self) -> Option<Screens> {
+		None
+	}
 }
 
 /// All games implemented in Terminal Arcade.
 #[must_use]
 #[enum_dispatch(Game)]
-#[derive(EnumIter, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 #[allow(missing_docs)]
 pub enum Games {
-	Minesweeper(Minesweeper),
+	#[cfg(feature = "minesweeper")]
+	Minesweeper(minesweeper::Minesweeper),
+	#[cfg(feature = "tron")]
+	Tron(tron::Tron),
+	#[cfg(feature = "twenty-forty-eight")]
+	TwentyFortyEight(twenty_forty_eight::TwentyFortyEight),
+	#[cfg(feature = "sudoku")]
+	Sudoku(sudoku::Sudoku),
+	#[cfg(feature = "chess")]
+	Chess(chess::Chess),
+	#[cfg(feature = "klondike")]
+	Klondike(klondike::Klondike),
+	#[cfg(feature = "scripting")]
+	Scripted(scripted::ScriptedGame),
 }
 
 impl Display for Games {
@@ -90,10 +197,36 @@ impl Display for Games {
 }
 
 impl Games {
+	/// Returns every game in the [registry](registry): the ones built into
+	/// Terminal Arcade with their features, then the [scripted](scripted) ones
+	/// with the `scripting` feature. It may be empty in builds without any.
+	#[must_use]
+	pub fn all() -> Vec<Games> {
+		registry::all()
+	}
+
+	/// Returns the game's [ID](GameId).
+	#[must_use]
+	pub fn id(&self) -> GameId {
+		self.data().metadata.static_info.id
+	}
+
+	/// Returns the screen launching the game: the mode selector if it has
+	/// several [modes](Game::modes), or else the game's own screen.
+	#[must_use]
+	pub fn launch_screen(&self) -> Option<Screens> {
+		if self.modes().len() > 1 {
+			Some(ModeSelectPopup::new(self.clone()).into())
+		} else {
+			self.data().created_screen
+		}
+	}
+
 	/// Returns a list of games that match the keyword in their name.
 	#[must_use]
 	pub fn get_by_keyword(keyword: &str) -> Vec<Games> {
-		Self::iter()
+		Self::all()
+			.into_iter()
 			.filter(|game| game.data().metadata.static_info.matches_keyword(keyword))
 			.collect()
 	}
@@ -106,7 +239,7 @@ impl Games {
 		if let Some(ref term) = term {
 			Self::get_by_keyword(term)
 		} else {
-			Games::iter().collect()
+			Games::all()
 		}
 	}
 }
@@ -117,10 +250,35 @@ pub fn get_unix_time_as_secs() -> u64 {
 	SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()
 }
 
-/// Gets the metadata file path, according to the game's name.
+/// Suffix added to the name of a broken metadata file when it's moved aside.
+pub const BROKEN_SUFFIX: &str = ".broken";
+
+/// Gets the metadata file path, according to the game's [ID](GameId).
+#[must_use]
+pub fn meta_file_path(id: &GameId) -> PathBuf {
+	get_save_dir().join(format!("{id}.meta.toml"))
+}
+
+/// Renames a game's metadata file from the one older versions of Terminal
+/// Arcade kept, after the game's name in lowercase, to the one after its
+/// [ID](GameId), unless the latter already exists.
+pub fn migrate_meta_file(id: &GameId, name: &str) -> anyhow::Result<()> {
+	let legacy_path = get_save_dir().join(format!("{}.meta.toml", name.to_lowercase()));
+	let path = meta_file_path(id);
+	if legacy_path != path && legacy_path.is_file() && !path.exists() {
+		std::fs::rename(legacy_path, path)?;
+	}
+	Ok(())
+}
+
+/// Gets the paths of the save files every game keeps: its metadata, and its
+/// [autosave](autosave) in each of its modes.
 #[must_use]
-pub fn meta_file_path(name: &str) -> PathBuf {
-	get_save_dir().join(format!("{}.meta.toml", name.to_lowercase()))
+pub fn common_save_files(id: &GameId, modes: &[GameMode]) -> Vec<PathBuf> {
+	let autosaves = modes
+		.iter()
+		.map(|&mode| autosave::autosave_path(&autosave::autosave_name(id.as_str(), mode)));
+	std::iter::once(meta_file_path(id)).chain(autosaves).collect()
 }
 
 /// A [Game]'s metadata. Note that this does not include the game's settings.
@@ -136,12 +294,16 @@ pub struct GameMetadata {
 }
 
 impl<'a> GameMetadata {
-	/// Creates a new game metadata object.
+	/// Creates a new game metadata object, [migrating](migrate_meta_file) the
+	/// game's metadata file if it's still named after the game.
 	pub fn new(static_info: GameStaticInfo) -> anyhow::Result<Self> {
-		let name = static_info.name.clone();
+		if !save_file::is_locked() {
+			migrate_meta_file(&static_info.id, &static_info.name)?;
+		}
+		let dynamic_info = GameDynamicInfo::load_or_default(&static_info.id)?;
 		Ok(Self {
 			static_info,
-			dynamic_info: GameDynamicInfo::load_or_default(&name)?,
+			dynamic_info,
 		})
 	}
 
@@ -153,11 +315,12 @@ impl<'a> GameMetadata {
 	/// Returns an entry string that contains all of the metadata properties.
 	#[must_use]
 	pub fn get_entry_text(&self) -> String {
-		format!(
-			"📄 Description: {}\n👷 Created at: v{}\n{}",
-			self.static_info.description,
-			self.static_info.version_created,
-			self.dynamic_info.get_status_text(),
+		t!(
+			"game-entry",
+			description = self.static_info.description.as_str(),
+			created = self.static_info.created_text(),
+			difficulty = self.static_info.difficulty.stars_text(),
+			status = self.dynamic_info.get_status_text(),
 		)
 	}
 
@@ -165,17 +328,21 @@ impl<'a> GameMetadata {
 	/// [`crate::ui::components::scrollable_list::ScrollableList`] widget.
 	pub fn get_list_entry(&self) -> ListItem<Games> {
 		ListItem::new(
-			Some(self.static_info.name.to_string()),
+			Some(self.static_info.name.clone()),
 			self.static_info.game.clone(),
 			Some(self.get_entry_text()),
 		)
+		.with_badge(badges::badge(self).map(|badge| badge.label().to_string()))
 	}
 
 	/// Adds 1 play count and updates the last playtime, while also saving the
 	/// metadata.
 	pub fn play(&mut self) -> anyhow::Result<()> {
-		self.dynamic_info.play();
-		self.dynamic_info.save(&self.static_info.name)
+		self.dynamic_info = GameDynamicInfo::update(&self.static_info.id, |info| {
+			info.play();
+			info.clone()
+		})?;
+		Ok(())
 	}
 
 	/// Returns whether this game has been played.
@@ -193,6 +360,10 @@ pub struct GameStaticInfo {
 	/// [Games] enum variant for this game.
 	pub game: Games,
 
+	/// ID of the game, which its save data is kept under. Unlike its name, it
+	/// never changes.
+	pub id: GameId,
+
 	/// Name of the game
 	pub name: String,
 
@@ -201,17 +372,64 @@ pub struct GameStaticInfo {
 
 	/// Version that the game was created on.
 	pub version_created: String,
+
+	/// People who made the game, with optional email addresses like
+	/// `Name <email>`.
+	#[serde(default)]
+	pub authors: Vec<String>,
+
+	/// How hard the game is.
+	#[serde(default)]
+	pub difficulty: DifficultyRating,
+
+	/// The game's preview in the game selection screen, if it has one.
+	#[new(default)]
+	#[serde(skip)]
+	pub preview: Option<GamePreview>,
 }
 
 impl GameStaticInfo {
+	/// Sets the game's [preview](GamePreview).
+	pub fn with_preview(mut self, preview: Option<GamePreview>) -> Self {
+		self.preview = preview;
+		self
+	}
+
 	/// Returns whether the game's metadata matches a certain term.
 	#[must_use]
 	pub fn matches_keyword(&self, keyword: &str) -> bool {
 		let keyword = keyword.trim().to_lowercase();
 		[&self.name, &self.description, &self.version_created]
 			.into_iter()
+			.chain(&self.authors)
 			.any(|field| field.to_lowercase().contains(&keyword))
 	}
+
+	/// Returns the authors' names, without their email addresses, separated
+	/// by commas.
+	#[must_use]
+	pub fn authors_string(&self) -> String {
+		self.authors
+			.iter()
+			.map(|author| author.split('<').next().unwrap_or_default().trim())
+			.collect::<Vec<_>>()
+			.join(", ")
+	}
+
+	/// Returns the version the game was created on, and by whom if known.
+	#[must_use]
+	pub fn created_text(&self) -> String {
+		let version = self.version_created.as_str();
+		if self.authors.is_empty() {
+			t!("game-created", version = version)
+		} else {
+			t!(
+				"game-created-by",
+				version = version,
+				authors = self.authors_string()
+			)
+		}
+	}
 }
 
 /// A [Game]'s dynamic info, such as the game's play count, or the last played
@@ -224,61 +442,376 @@ pub struct GameDynamicInfo {
 
 	/// The game's [Option]al last-played UNIX timestamp.
 	pub last_played: Option<u64>,
+
+	/// The player's [rating](crate::services::rating) in the game's versus
+	/// mode, once they've played a rated match.
+	#[serde(default)]
+	pub rating: Option<u32>,
+
+	/// The player's best score, in games that keep a score.
+	#[serde(default)]
+	pub high_score: Option<i64>,
+
+	/// The player's best score in each [mode](GameMode) other than the
+	/// standard one.
+	#[serde(default)]
+	pub mode_high_scores: BTreeMap<GameMode, i64>,
+
+	/// The player's fastest time in the standard mode, in seconds, in games
+	/// that are played against the clock.
+	#[serde(default)]
+	pub best_time_secs: Option<u64>,
+
+	/// How many games the player won, in games that are won rather than
+	/// scored, like solitaires.
+	#[serde(default)]
+	pub wins: u64,
+
+	/// UNIX timestamps of the game's plays, oldest first, for the last
+	/// [`HISTORY_DAYS`](crate::services::stats::HISTORY_DAYS) days.
+	#[serde(default)]
+	pub play_history: Vec<u64>,
+
+	/// High scores set in the game, oldest first, for the last
+	/// [`HISTORY_DAYS`](crate::services::stats::HISTORY_DAYS) days.
+	#[serde(default)]
+	pub score_history: Vec<ScoreRecord>,
+}
+
+/// A high score set in a game.
+#[derive(new, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoreRecord {
+	/// UNIX timestamp of when the score was set.
+	pub time: u64,
+
+	/// The score.
+	pub score: i64,
+
+	/// The mode the score was set in.
+	#[new(default)]
+	#[serde(default)]
+	pub mode: GameMode,
+}
+
+/// How a game went, reported by its screen when it ends and
+/// [recorded](GameDynamicInfo::record_result) in the game's metadata.
+#[derive(new, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameResult {
+	/// The mode the game was played in.
+	pub mode: GameMode,
+
+	/// The player's score, in games that keep a score.
+	pub score: Option<i64>,
+
+	/// How long the game took, in games played against the clock.
+	pub time: Option<Duration>,
+
+	/// The [seed](crate::services::rng::seed_game) the game started from, in
+	/// games drawing from the shared generator, to play it again.
+	#[new(default)]
+	pub seed: Option<u64>,
+}
+
+impl GameResult {
+	/// Sets the seed the game started from.
+	#[must_use]
+	pub fn with_seed(mut self, seed: Option<u64>) -> Self {
+		self.seed = seed;
+		self
+	}
+}
+
+/// How a [result](GameResult) compares to the player's previous bests, shown
+/// when the game ends.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[must_use]
+pub struct ResultComparison {
+	/// The best score before the game, if any.
+	pub previous_best_score: Option<i64>,
+
+	/// Whether the game set a new best score.
+	pub new_best_score: bool,
+
+	/// The best time before the game, in seconds, if any.
+	pub previous_best_time_secs: Option<u64>,
+
+	/// Whether the game set a new best time.
+	pub new_best_time: bool,
+}
+
+impl ResultComparison {
+	/// Returns the text comparing the result to the previous bests, or an
+	/// empty string if there's nothing to compare against.
+	#[must_use]
+	pub fn text(&self) -> String {
+		let mut parts = Vec::new();
+		if self.new_best_score {
+			parts.push(t!("result-new-best-score").to_string());
+		} else if let Some(score) = self.previous_best_score {
+			parts.push(t!("result-best-score", score = score));
+		}
+		if self.new_best_time {
+			parts.push(t!("result-new-best-time").to_string());
+		} else if let Some(secs) = self.previous_best_time_secs {
+			parts.push(t!("result-best-time", time = clock_text(secs)));
+		}
+		parts.join(" | ")
+	}
+}
+
+/// Formats a number of seconds as minutes and seconds, like `01:23`.
+#[must_use]
+pub fn clock_text(secs: u64) -> String {
+	format!("{:02}:{:02}", secs / 60, secs % 60)
 }
 
 impl GameDynamicInfo {
 	/// Formats dynamic game metadata into a human-readable string,
 	#[must_use]
-	#[allow(clippy::cast_possible_truncation)]
-	#[allow(clippy::cast_possible_wrap)]
 	pub fn get_status_text(&self) -> String {
+		self.status_text(
+			&(self.high_scores_text() + self.best_time_text().as_str() + self.wins_text().as_str()),
+		)
+	}
+
+	/// Formats how much and when the game was played, leaving the player's
+	/// bests out for them to be listed separately.
+	#[must_use]
+	pub fn play_text(&self) -> String {
+		self.status_text("")
+	}
+
+	/// Formats how much and when the game was played, followed by the
+	/// player's bests.
+	fn status_text(&self, bests: &str) -> String {
 		let play_count = self.play_count;
 		let last_played = self.last_played;
 		if self.played() {
-			let system_time = UNIX_EPOCH + Duration::from_secs(last_played.unwrap());
-			let datetime = DateTime::<Local>::from(system_time);
+			let datetime = DateTime::from_timestamp(last_played.unwrap() as i64, 0)
+				.unwrap_or_default()
+				.with_timezone(&Local);
 			let date_str = datetime.format("%d/%m/%Y");
 
-			format!(
-				"🕹️ Played {} {}, 🌗 last played at {}",
-				play_count,
-				pluralize("time", play_count as isize, false),
-				date_str,
+			let rating =
+				self.rating.map(|rating| t!("game-rated", rating = rating)).unwrap_or_default()
+					+ bests;
+			t!(
+				"game-played",
+				count = play_count,
+				date = date_str.to_string(),
+				rating = rating,
 			)
 		} else {
-			"🆕 Never played before!".to_string()
+			t!("game-never-played").to_string()
 		}
 	}
 
 	/// Loads the game metadata.
-	pub fn load(name: &str) -> anyhow::Result<Self> {
-		let metadata_file = std::fs::read_to_string(meta_file_path(name))?;
-		Ok(toml::from_str::<Self>(&metadata_file)?)
+	pub fn load(id: &GameId) -> anyhow::Result<Self> {
+		let path = meta_file_path(id);
+		let metadata_file = save_file::read(&path)?;
+		Ok(toml::from_str::<Self>(&metadata_file)
+			.map_err(|error| AppError::save_file_parse(&path, &metadata_file, &error))?)
 	}
 
 	/// Saves the current configuration, in TOML format.
-	pub fn save(&self, name: &str) -> anyhow::Result<()> {
+	pub fn save(&self, id: &GameId) -> anyhow::Result<()> {
 		let toml_string = toml::to_string_pretty(self)?;
-		Ok(std::fs::write(meta_file_path(name), toml_string)?)
+		save_file::write(&meta_file_path(id), &toml_string)
 	}
 
-	/// Loads this struct from the specified location, or creates a default.
-	pub fn load_or_default(name: &str) -> anyhow::Result<Self> {
-		let load_results = Self::load(name);
-		Ok(if let Ok(info) = load_results {
-			info
-		} else {
-			let new = Self::default();
+	/// Loads the game metadata, or creates a default if it wasn't saved yet.
+	/// A broken metadata file is [reported](error::report_later) and moved
+	/// aside, to a [`BROKEN_SUFFIX`] file, rather than overwritten. Other
+	/// errors, like locked save data, are returned without touching the file.
+	pub fn load_or_default(id: &GameId) -> anyhow::Result<Self> {
+		if let Some(info) = Self::load_or_move_aside(id)? {
+			return Ok(info);
+		}
+		let new = Self::default();
+		// Locked save data can't be overwritten, and shouldn't be.
+		if !save_file::is_locked() {
 			std::fs::create_dir_all(get_save_dir())?;
-			new.save(name)?; // So that this else branch wouldn't happen again
-			new
-		})
+			let _lock = save_file::lock(&meta_file_path(id))?;
+			// Another process may have saved it in the meantime.
+			if !meta_file_path(id).exists() {
+				new.save(id)?; // So that the metadata loads next time
+			}
+		}
+		Ok(new)
 	}
 
-	/// Adds 1 play count and updates the last playtime.
+	/// Loads the game metadata, updates it and saves it, returning what the
+	/// update returned. The metadata is [locked](save_file::lock) meanwhile, so
+	/// that processes sharing the save directory, like the connections of the
+	/// [telnet server](crate::services::telnet), update it one after another
+	/// rather than overwriting each other's results.
+	pub fn update<T>(id: &GameId, update: impl FnOnce(&mut Self) -> T) -> anyhow::Result<T> {
+		std::fs::create_dir_all(get_save_dir())?;
+		let _lock = save_file::lock(&meta_file_path(id))?;
+		let mut info = Self::load_or_move_aside(id)?.unwrap_or_default();
+		let value = update(&mut info);
+		info.save(id)?;
+		Ok(value)
+	}
+
+	/// Loads the game metadata, or returns [`None`] if it wasn't saved yet or
+	/// was broken, in which case it's [reported](error::report_later) and
+	/// moved aside to a [`BROKEN_SUFFIX`] file.
+	fn load_or_move_aside(id: &GameId) -> anyhow::Result<Option<Self>> {
+		let error = match Self::load(id) {
+			Ok(info) => return Ok(Some(info)),
+			Err(error) => error,
+		};
+		let is_broken = error.chain().any(|cause| {
+			matches!(
+				cause.downcast_ref::<AppError>(),
+				Some(AppError::SaveFileParse { .. })
+			)
+		});
+		if is_broken {
+			let path = meta_file_path(id);
+			let mut broken_path = path.clone().into_os_string();
+			broken_path.push(BROKEN_SUFFIX);
+			std::fs::rename(&path, &broken_path)
+				.map_err(|rename_error| AppError::file_access(&path, rename_error))?;
+			error::report_later(&error.context(t!(
+				"error-save-moved",
+				path = PathBuf::from(broken_path).display().to_string()
+			)));
+		} else if !AppError::is_missing_file(&error) {
+			return Err(error);
+		}
+		Ok(None)
+	}
+
+	/// Adds 1 play count and updates the last playtime and the play history.
 	pub fn play(&mut self) {
+		let now = get_unix_time_as_secs();
 		self.play_count += 1;
-		self.last_played = Some(get_unix_time_as_secs());
+		self.last_played = Some(now);
+		self.play_history.retain(|&time| in_history(time, now));
+		self.play_history.push(now);
+	}
+
+	/// Returns the player's rating, or the [default](DEFAULT_RATING) one if
+	/// they've never played a rated match.
+	#[must_use]
+	pub fn rating_or_default(&self) -> u32 {
+		self.rating.unwrap_or(DEFAULT_RATING)
+	}
+
+	/// Updates the player's rating after a match against an opponent, returning
+	/// the new rating.
+	pub fn record_match(&mut self, opponent_rating: u32, result: MatchResult) -> u32 {
+		let rating = updated_rating(self.rating_or_default(), opponent_rating, result);
+		self.rating = Some(rating);
+		rating
+	}
+
+	/// Returns the player's best score in a mode.
+	#[must_use]
+	pub fn high_score_in(&self, mode: GameMode) -> Option<i64> {
+		match mode {
+			GameMode::Standard => self.high_score,
+			_ => self.mode_high_scores.get(&mode).copied(),
+		}
+	}
+
+	/// Records a score set in a mode, returning whether it's a new high score
+	/// in that mode.
+	pub fn record_score(&mut self, mode: GameMode, score: i64) -> bool {
+		let new_high_score = self.high_score_in(mode).is_none_or(|high_score| score > high_score);
+		if new_high_score {
+			let now = get_unix_time_as_secs();
+			match mode {
+				GameMode::Standard => self.high_score = Some(score),
+				_ => {
+					self.mode_high_scores.insert(mode, score);
+				},
+			}
+			self.score_history.retain(|record| in_history(record.time, now));
+			self.score_history.push(ScoreRecord {
+				time: now,
+				score,
+				mode,
+			});
+		}
+		new_high_score
+	}
+
+	/// Records how a game went, updating the best score in its mode and, in the
+	/// standard mode, the best time. Returns how it compares to the previous
+	/// bests.
+	pub fn record_result(&mut self, result: &GameResult) -> ResultComparison {
+		let mut comparison = ResultComparison {
+			previous_best_score: self.high_score_in(result.mode),
+			previous_best_time_secs: self.best_time_secs,
+			..ResultComparison::default()
+		};
+		if let Some(score) = result.score {
+			comparison.new_best_score = self.record_score(result.mode, score);
+		}
+		if let Some(time) = result.time.filter(|_| result.mode == GameMode::Standard) {
+			let secs = time.as_secs();
+			comparison.new_best_time = self.best_time_secs.is_none_or(|best| secs < best);
+			if comparison.new_best_time {
+				self.best_time_secs = Some(secs);
+			}
+		} else {
+			comparison.previous_best_time_secs = None;
+		}
+		comparison
+	}
+
+	/// Returns the text listing the player's best score in each mode, or an
+	/// empty string if they have none.
+	#[must_use]
+	pub fn high_scores_text(&self) -> String {
+		let scores: Vec<_> = self
+			.high_score
+			.map(|score| (GameMode::Standard, score))
+			.into_iter()
+			.chain(self.mode_high_scores.iter().map(|(&mode, &score)| (mode, score)))
+			.map(|(mode, score)| {
+				if mode == GameMode::Standard {
+					score.to_string()
+				} else {
+					format!("{} {score}", mode.label())
+				}
+			})
+			.collect();
+		if scores.is_empty() {
+			String::new()
+		} else {
+			t!("game-high-scores", scores = scores.join(", "))
+		}
+	}
+
+	/// Returns the text showing the player's best time, or an empty string if
+	/// they have none.
+	#[must_use]
+	pub fn best_time_text(&self) -> String {
+		self.best_time_secs
+			.map(|secs| t!("game-best-time", time = clock_text(secs)))
+			.unwrap_or_default()
+	}
+
+	/// Counts a win, returning how many games the player won.
+	pub fn record_win(&mut self) -> u64 {
+		self.wins += 1;
+		self.wins
+	}
+
+	/// Returns the text showing how many games the player won, or an empty
+	/// string if they never won.
+	#[must_use]
+	pub fn wins_text(&self) -> String {
+		if self.wins == 0 {
+			String::new()
+		} else {
+			t!("game-wins", count = self.wins)
+		}
 	}
 
 	/// Checks if the game has ever been played.
@@ -287,3 +820,104 @@ impl GameDynamicInfo {
 		self.play_count > 0
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use std::time::Duration;
+
+	use super::{
+		meta_file_path,
+		GameDynamicInfo,
+		GameResult,
+		BROKEN_SUFFIX,
+	};
+	use crate::{
+		core::{
+			error,
+			files,
+			get_save_dir,
+			save_file::{
+				self,
+				LOCK_SUFFIX,
+			},
+		},
+		games::{
+			modes::GameMode,
+			registry::GameId,
+		},
+	};
+
+	#[test]
+	fn results_are_compared_to_previous_bests() {
+		let mut info = GameDynamicInfo::default();
+		let first = info.record_result(&GameResult::new(
+			GameMode::Standard,
+			Some(10),
+			Some(Duration::from_secs(83)),
+		));
+		assert!(first.new_best_score && first.new_best_time);
+		assert_eq!(info.best_time_text(), ", ⏱ best 01:23");
+
+		let slower = info.record_result(&GameResult::new(
+			GameMode::Standard,
+			Some(12),
+			Some(Duration::from_secs(90)),
+		));
+		assert!(slower.new_best_score && !slower.new_best_time);
+		assert_eq!(slower.previous_best_score, Some(10));
+		assert_eq!(slower.previous_best_time_secs, Some(83));
+		assert_eq!(info.best_time_secs, Some(83));
+
+		let time_trial = info.record_result(&GameResult::new(
+			GameMode::TimeTrial,
+			Some(5),
+			Some(Duration::from_secs(1)),
+		));
+		assert!(time_trial.new_best_score && !time_trial.new_best_time);
+		assert_eq!(info.high_score, Some(12));
+		assert_eq!(info.best_time_secs, Some(83));
+	}
+
+	#[test]
+	fn broken_metadata_is_moved_aside() {
+		files::isolate_test_files();
+		let id = GameId::from("broken");
+		let path = meta_file_path(&id);
+		std::fs::create_dir_all(get_save_dir()).unwrap();
+		std::fs::write(&path, "play_count = [").unwrap();
+
+		let info = GameDynamicInfo::load_or_default(&id).unwrap();
+		assert_eq!(info.play_count, 0);
+		let broken = std::fs::read_to_string(format!("{}{BROKEN_SUFFIX}", path.display()));
+		assert_eq!(broken.unwrap(), "play_count = [");
+		assert!(GameDynamicInfo::load(&id).is_ok());
+		assert!(error::take_pending().iter().any(|report| report.code == "E201"));
+	}
+
+	#[test]
+	fn updates_are_saved_under_a_lock() {
+		files::isolate_test_files();
+		let id = GameId::from("updated");
+		for _ in 0..2 {
+			GameDynamicInfo::update(&id, GameDynamicInfo::play).unwrap();
+		}
+		assert_eq!(GameDynamicInfo::load(&id).unwrap().play_count, 2);
+		let saved: Vec<_> = std::fs::read_dir(get_save_dir())
+			.unwrap()
+			.map(|entry| entry.unwrap().file_name())
+			.collect();
+		assert_eq!(
+			saved.len(),
+			2,
+			"only the metadata and its lock are left: {saved:?}"
+		);
+
+		let path = meta_file_path(&id);
+		let other_process =
+			std::fs::File::open(format!("{}{LOCK_SUFFIX}", path.display())).unwrap();
+		let lock = save_file::lock(&path).unwrap();
+		assert!(other_process.try_lock().is_err());
+		drop(lock);
+		assert!(other_process.try_lock().is_ok());
+	}
+}
@@ -0,0 +1,170 @@
+//! Modes that games can be played in, on top of their standard rules. Games
+//! opt into modes with [`Game::modes`](super::Game::modes), and are launched in
+//! the one picked in the mode selector.
+
+use std::{
+	fmt::{
+		Display,
+		Formatter,
+	},
+	time::Duration,
+};
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+use strum::EnumIter;
+
+use crate::{
+	core::time::Instant,
+	t,
+};
+
+/// How long a time trial lasts.
+pub const TIME_TRIAL_DURATION: Duration = Duration::from_mins(2);
+
+/// A mode a game can be played in. Each mode has its own high score.
+#[derive(
+	Debug,
+	Clone,
+	Copy,
+	Default,
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+	Hash,
+	EnumIter,
+	Serialize,
+	Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum GameMode {
+	/// The game as designed.
+	#[default]
+	Standard,
+
+	/// Scoring as much as possible before a [countdown](TIME_TRIAL_DURATION)
+	/// ends the game.
+	TimeTrial,
+
+	/// Losing starts a new round, with the score carried over, until the
+	/// player leaves.
+	Endless,
+
+	/// No saving and no undoing.
+	Hardcore,
+}
+
+impl GameMode {
+	/// Returns the mode's identifier, as used in scripts and file names.
+	#[must_use]
+	pub fn id(self) -> &'static str {
+		match self {
+			Self::Standard => "standard",
+			Self::TimeTrial => "time_trial",
+			Self::Endless => "endless",
+			Self::Hardcore => "hardcore",
+		}
+	}
+
+	/// Returns the mode with an identifier, if any.
+	#[must_use]
+	pub fn from_id(id: &str) -> Option<Self> {
+		<Self as strum::IntoEnumIterator>::iter().find(|mode| mode.id() == id)
+	}
+
+	/// Returns the mode's name.
+	#[must_use]
+	pub fn label(self) -> &'static str {
+		match self {
+			Self::Standard => t!("mode-standard"),
+			Self::TimeTrial => t!("mode-time-trial"),
+			Self::Endless => t!("mode-endless"),
+			Self::Hardcore => t!("mode-hardcore"),
+		}
+	}
+
+	/// Returns what the mode changes.
+	#[must_use]
+	pub fn description(self) -> &'static str {
+		match self {
+			Self::Standard => t!("mode-standard-description"),
+			Self::TimeTrial => t!("mode-time-trial-description"),
+			Self::Endless => t!("mode-endless-description"),
+			Self::Hardcore => t!("mode-hardcore-description"),
+		}
+	}
+
+	/// Returns whether games in progress can be
+	/// [autosaved](crate::services::autosave) and resumed. Time trials can't,
+	/// since their countdown isn't saved.
+	#[must_use]
+	pub fn allows_saves(self) -> bool {
+		matches!(self, Self::Standard | Self::Endless)
+	}
+
+	/// Returns whether moves can be undone, or games reloaded.
+	#[must_use]
+	pub fn allows_undo(self) -> bool {
+		self != Self::Hardcore
+	}
+
+	/// Returns how long games last in this mode, if they have a time limit.
+	#[must_use]
+	pub fn time_limit(self) -> Option<Duration> {
+		(self == Self::TimeTrial).then_some(TIME_TRIAL_DURATION)
+	}
+}
+
+impl Display for GameMode {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		f.write_str(self.label())
+	}
+}
+
+/// A countdown to the end of a game.
+#[derive(Debug, Clone, Copy)]
+#[must_use]
+pub struct Countdown {
+	/// When the countdown ends.
+	end: Instant,
+}
+
+impl Countdown {
+	/// Starts a countdown lasting a duration.
+	pub fn start(duration: Duration) -> Self {
+		Self {
+			end: Instant::now() + duration,
+		}
+	}
+
+	/// Returns the time left.
+	#[must_use]
+	pub fn remaining(&self) -> Duration {
+		self.end.saturating_duration_since(Instant::now())
+	}
+
+	/// Pushes the end of the countdown back, like by the time a game was
+	/// paused.
+	pub fn delay(&mut self, duration: Duration) {
+		self.end += duration;
+	}
+
+	/// Returns whether the countdown ended.
+	#[must_use]
+	pub fn is_over(&self) -> bool {
+		self.remaining().is_zero()
+	}
+
+	/// Returns the time left as minutes and seconds, like `1:05`.
+	#[must_use]
+	pub fn text(&self) -> String {
+		let seconds = self.remaining().as_millis().div_ceil(1000) as u64;
+		t!(
+			"mode-countdown",
+			time = format!("{}:{:02}", seconds / 60, seconds % 60)
+		)
+	}
+}
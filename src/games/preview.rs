@@ -0,0 +1,85 @@
+//! Previews of games, shown next to the selected game in the game selection
+//! screen: a bit of text art, like a mock of the game's board, either still or
+//! animated.
+//!
+//! Games register theirs in their [static
+//! info](crate::games::GameStaticInfo::with_preview). With the `images`
+//! feature, a game's [thumbnail](crate::games::Game::thumbnail) is shown
+//! instead when it has one.
+
+use std::time::Duration;
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+/// How long each frame of an animated preview is shown, unless set otherwise.
+pub const DEFAULT_FRAME_DURATION: Duration = Duration::from_millis(500);
+
+/// See the [module](self) documentation for more information.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[must_use]
+pub struct GamePreview {
+	/// The preview's frames, shown in a loop. Still previews have a single
+	/// one.
+	frames: Vec<String>,
+
+	/// How long each frame is shown.
+	frame_duration: Duration,
+}
+
+impl GamePreview {
+	/// Creates a still preview.
+	pub fn still(art: impl Into<String>) -> Self {
+		Self {
+			frames: vec![art.into()],
+			frame_duration: DEFAULT_FRAME_DURATION,
+		}
+	}
+
+	/// Creates an animated preview, showing each frame for a while in a loop.
+	/// Without frames, the preview is left blank.
+	pub fn animated(frames: impl IntoIterator<Item = String>, frame_duration: Duration) -> Self {
+		let frames: Vec<_> = frames.into_iter().collect();
+		Self {
+			frames: if frames.is_empty() { vec![String::new()] } else { frames },
+			frame_duration: frame_duration.max(Duration::from_millis(1)),
+		}
+	}
+
+	/// Returns whether the preview is animated.
+	#[must_use]
+	pub fn is_animated(&self) -> bool {
+		self.frames.len() > 1
+	}
+
+	/// Returns the frame shown a while into the preview.
+	#[must_use]
+	pub fn frame_at(&self, elapsed: Duration) -> &str {
+		let index = elapsed.as_millis() / self.frame_duration.as_millis();
+		&self.frames[(index % self.frames.len() as u128) as usize]
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::Duration;
+
+	use super::GamePreview;
+
+	#[test]
+	fn loops_through_its_frames() {
+		let preview = GamePreview::animated(
+			["a", "b", "c"].map(String::from),
+			Duration::from_millis(100),
+		);
+		let frame = |millis| preview.frame_at(Duration::from_millis(millis));
+		assert_eq!(frame(0), "a");
+		assert_eq!(frame(199), "b");
+		assert_eq!(frame(300), "a");
+		let still = GamePreview::still("art");
+		assert!(!still.is_animated());
+		assert_eq!(still.frame_at(Duration::from_mins(1)), "art");
+	}
+}
@@ -0,0 +1,197 @@
+//! The registry of the games Terminal Arcade knows of, keyed by their
+//! [`GameId`]. Game selection, search, statistics and everything else listing
+//! games go through it, so adding a game only takes registering it.
+//!
+//! Games built into Terminal Arcade register themselves with their features,
+//! from their module's `register` function. Games found while Terminal Arcade
+//! runs, like [scripted](super::scripted) ones, register a
+//! [provider](Provider) instead, asked for its games every time they're
+//! listed.
+
+use std::{
+	fmt::{
+		Display,
+		Formatter,
+	},
+	sync::{
+		LazyLock,
+		RwLock,
+	},
+};
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+use crate::games::{
+	Game,
+	Games,
+};
+
+/// The games registered, with the built-in ones to start with.
+static REGISTRY: LazyLock<RwLock<GameRegistry>> =
+	LazyLock::new(|| RwLock::new(GameRegistry::builtin()));
+
+/// Returns the games found while Terminal Arcade runs, as they are at the
+/// time.
+pub type Provider = fn() -> Vec<Games>;
+
+/// Identifies a game, keying its save data. Unlike the game's name, which
+/// can be translated or changed between versions, it stays the same.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct GameId(String);
+
+impl GameId {
+	/// Returns the ID as a string.
+	#[must_use]
+	pub fn as_str(&self) -> &str {
+		&self.0
+	}
+}
+
+impl From<&str> for GameId {
+	fn from(name: &str) -> Self {
+		Self(name.trim().to_lowercase())
+	}
+}
+
+impl Display for GameId {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		f.write_str(&self.0)
+	}
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Default)]
+pub struct GameRegistry {
+	/// Games registered, in the order they were.
+	games: Vec<(GameId, Games)>,
+
+	/// Providers registered, in the order they were.
+	providers: Vec<Provider>,
+}
+
+impl GameRegistry {
+	/// Creates a registry with the games built into Terminal Arcade with their
+	/// features.
+	#[must_use]
+	pub fn builtin() -> Self {
+		let mut registry = Self::default();
+		#[cfg(feature = "minesweeper")]
+		super::minesweeper::register(&mut registry);
+		#[cfg(feature = "tron")]
+		super::tron::register(&mut registry);
+		#[cfg(feature = "twenty-forty-eight")]
+		super::twenty_forty_eight::register(&mut registry);
+		#[cfg(feature = "sudoku")]
+		super::sudoku::register(&mut registry);
+		#[cfg(feature = "chess")]
+		super::chess::register(&mut registry);
+		#[cfg(feature = "klondike")]
+		super::klondike::register(&mut registry);
+		#[cfg(feature = "scripting")]
+		super::scripted::register(&mut registry);
+		registry
+	}
+
+	/// Registers a game, replacing the one registered with the same ID if
+	/// there was one.
+	pub fn register(&mut self, game: Games) {
+		let id = game.id();
+		if let Some(registered) =
+			self.games.iter_mut().find(|(registered_id, _)| *registered_id == id)
+		{
+			registered.1 = game;
+		} else {
+			self.games.push((id, game));
+		}
+	}
+
+	/// Registers a provider of games.
+	pub fn register_provider(&mut self, provider: Provider) {
+		self.providers.push(provider);
+	}
+
+	/// Returns every game: the ones registered, then the ones of each
+	/// provider. Games with the ID of one listed before them are left out.
+	#[must_use]
+	pub fn games(&self) -> Vec<Games> {
+		let mut ids: Vec<_> = self.games.iter().map(|(id, _)| id.clone()).collect();
+		let mut games: Vec<_> = self.games.iter().map(|(_, game)| game.clone()).collect();
+		for game in self.providers.iter().flat_map(|provider| provider()) {
+			let id = game.id();
+			if !ids.contains(&id) {
+				ids.push(id);
+				games.push(game);
+			}
+		}
+		games
+	}
+
+	/// Returns the game with an ID, if there is one.
+	#[must_use]
+	pub fn get(&self, id: &GameId) -> Option<Games> {
+		self.games
+			.iter()
+			.find_map(|(registered_id, game)| (registered_id == id).then(|| game.clone()))
+			.or_else(|| {
+				self.providers.iter().flat_map(|provider| provider()).find(|game| game.id() == *id)
+			})
+	}
+}
+
+/// Registers a game for the rest of the session. See
+/// [`GameRegistry::register`].
+pub fn register(game: impl Into<Games>) {
+	if let Ok(mut registry) = REGISTRY.write() {
+		registry.register(game.into());
+	}
+}
+
+/// Registers a provider of games for the rest of the session. See
+/// [`GameRegistry::register_provider`].
+pub fn register_provider(provider: Provider) {
+	if let Ok(mut registry) = REGISTRY.write() {
+		registry.register_provider(provider);
+	}
+}
+
+/// Returns every game registered. See [`GameRegistry::games`].
+#[must_use]
+pub fn all() -> Vec<Games> {
+	REGISTRY.read().map(|registry| registry.games()).unwrap_or_default()
+}
+
+/// Returns the game registered with an ID, if there is one.
+#[must_use]
+pub fn get(id: &GameId) -> Option<Games> {
+	REGISTRY.read().ok()?.get(id)
+}
+
+#[cfg(all(test, feature = "tron"))]
+mod tests {
+	use super::{
+		GameId,
+		GameRegistry,
+	};
+	use crate::games::{
+		tron::Tron,
+		Games,
+	};
+
+	fn provide_tron() -> Vec<Games> {
+		vec![Tron.into()]
+	}
+
+	#[test]
+	fn games_are_listed_once_by_id() {
+		let mut registry = GameRegistry::default();
+		assert!(registry.get(&GameId::from("tron")).is_none());
+		registry.register_provider(provide_tron);
+		registry.register(Tron.into());
+		registry.register(Tron.into());
+		assert_eq!(registry.games().len(), 1);
+		assert!(registry.get(&GameId::from(" TRON ")).is_some());
+	}
+}
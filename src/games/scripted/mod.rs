@@ -0,0 +1,211 @@
+//! Games written by players in [Rhai](https://rhai.rs), loaded from the `games`
+//! folder of the [save directory](get_save_dir) with the `scripting` feature.
+//!
+//! Every `.rhai` file in the folder shows up as a game in the game selection
+//! screen, named after its `NAME` constant (or its file name). The scripting
+//! API, from drawing on the grid to saving data between runs, is documented in
+//! [the scripting manual](HELP_PAGE), which scripted games open with \[F1\].
+
+use std::{
+	fs,
+	path::{
+		Path,
+		PathBuf,
+	},
+};
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+use crate::{
+	core::{
+		events::Event,
+		get_save_dir,
+	},
+	games::{
+		common_save_files,
+		difficulty::DifficultyRating,
+		modes::GameMode,
+		preview::GamePreview,
+		registry::{
+			GameId,
+			GameRegistry,
+		},
+		Game,
+		GameMetadata,
+		GameState,
+		GameStaticInfo,
+		Games,
+	},
+	t,
+	ui::{
+		screens::Screens,
+		ScriptedGameScreen,
+	},
+};
+
+pub mod runtime;
+
+/// The scripting manual, shared by every scripted game.
+pub const HELP_PAGE: &str = include_str!("../../../assets/help/scripting.md");
+
+/// Extension of scripted games' files.
+pub const SCRIPT_EXTENSION: &str = "rhai";
+
+/// Returns the folder scripted games are loaded from.
+#[must_use]
+pub fn get_scripts_dir() -> PathBuf {
+	get_save_dir().join("games")
+}
+
+/// Returns the scripted games in the [scripts folder](get_scripts_dir), sorted
+/// by name. Scripts that fail to compile are still listed, so that their errors
+/// show up when they're opened.
+#[must_use]
+pub fn discover() -> Vec<ScriptedGame> {
+	let Ok(entries) = fs::read_dir(get_scripts_dir()) else {
+		return Vec::new();
+	};
+	let mut games: Vec<_> = entries
+		.flatten()
+		.map(|entry| entry.path())
+		.filter(|path| path.extension().is_some_and(|extension| extension == SCRIPT_EXTENSION))
+		.map(|path| ScriptedGame::load(&path))
+		.collect();
+	games.sort_by(|a, b| a.name.cmp(&b.name));
+	games
+}
+
+/// Registers the scripted games in a [registry](GameRegistry), discovered
+/// every time they're listed so that new scripts show up without restarting.
+pub fn register(registry: &mut GameRegistry) {
+	registry.register_provider(|| discover().into_iter().map(Games::Scripted).collect());
+}
+
+/// A game written in Rhai. See the [module](self) documentation for more
+/// information.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScriptedGame {
+	/// Path of the script.
+	pub path: PathBuf,
+
+	/// Name of the game.
+	pub name: String,
+
+	/// Description of the game.
+	pub description: String,
+
+	/// Version of the game, which badges it as updated in the game selection
+	/// screen when it changes.
+	#[serde(default)]
+	pub version: String,
+
+	/// Modes the game opts into, besides the standard one.
+	#[serde(default)]
+	pub modes: Vec<GameMode>,
+
+	/// Whether the game is played against the clock, keeping the player's
+	/// best time.
+	#[serde(default)]
+	pub timed: bool,
+
+	/// People who wrote the script.
+	#[serde(default)]
+	pub authors: Vec<String>,
+
+	/// How hard the game is.
+	#[serde(default)]
+	pub difficulty: DifficultyRating,
+
+	/// The game's preview in the game selection screen.
+	#[serde(default)]
+	pub preview: Option<GamePreview>,
+}
+
+impl ScriptedGame {
+	/// Reads a game's info from its script's constants, falling back to its
+	/// file name for the name. See [`runtime::ScriptInfo`].
+	#[must_use]
+	pub fn load(path: &Path) -> Self {
+		let file_name = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+		let info = runtime::read_info(path).unwrap_or_default();
+		Self {
+			path: path.to_path_buf(),
+			name: info.name.unwrap_or(file_name),
+			description: info
+				.description
+				.unwrap_or_else(|| t!("scripted-description", path = path.display().to_string())),
+			version: info.version.unwrap_or_else(|| env!("CARGO_PKG_VERSION").to_string()),
+			modes: info.modes,
+			authors: info.authors,
+			timed: info.timed,
+			difficulty: info.difficulty.unwrap_or_default(),
+			preview: info.preview,
+		}
+	}
+
+	/// Returns the game's [ID](GameId), after its script's file name so that
+	/// renaming the game keeps its save data.
+	#[must_use]
+	pub fn id(&self) -> GameId {
+		let stem = self.path.file_stem().unwrap_or_default().to_string_lossy();
+		GameId::from(format!("scripted-{stem}").as_str())
+	}
+
+	/// Returns the path where the script's persistent data is saved.
+	#[must_use]
+	pub fn data_path(&self) -> PathBuf {
+		self.path.with_extension("save.json")
+	}
+}
+
+impl Game for ScriptedGame {
+	fn data(&self) -> GameState {
+		GameState::new(
+			GameMetadata::new(
+				GameStaticInfo::new(
+					self.clone().into(),
+					self.id(),
+					self.name.clone(),
+					self.description.clone(),
+					self.version.clone(),
+					self.authors.clone(),
+					self.difficulty,
+				)
+				.with_preview(self.preview.clone()),
+			)
+			.unwrap(),
+			Some(ScriptedGameScreen::new(self.clone(), GameMode::Standard).into()),
+		)
+	}
+
+	fn event(&mut self, _event: &Event) -> anyhow::Result<()> {
+		Ok(())
+	}
+
+	fn help_page(&self) -> Option<&'static str> {
+		Some(HELP_PAGE)
+	}
+
+	fn modes(&self) -> Vec<GameMode> {
+		let mut modes = vec![GameMode::Standard];
+		for &mode in &self.modes {
+			if !modes.contains(&mode) {
+				modes.push(mode);
+			}
+		}
+		modes
+	}
+
+	fn mode_screen(&self, mode: GameMode) -> Option<Screens> {
+		Some(ScriptedGameScreen::new(self.clone(), mode).into())
+	}
+
+	fn save_files(&self) -> Vec<PathBuf> {
+		let mut files = common_save_files(&self.id(), &self.modes());
+		files.push(self.data_path());
+		files
+	}
+}
@@ -0,0 +1,694 @@
+//! Runs a [scripted game](super::ScriptedGame): compiles its script, exposes
+//! the [canvas](Canvas) to it as `this`, and calls its `init`, `on_key`,
+//! `on_tick` and `hint` functions. Scripts setting `UNDO` get their key presses
+//! [undone](crate::services::undo) and redone.
+
+use std::{
+	fs,
+	path::Path,
+	str::FromStr,
+	time::Duration,
+};
+
+use anyhow::anyhow;
+use rand::Rng;
+use ratatui::style::Color;
+use rhai::{
+	Array,
+	CallFnOptions,
+	Dynamic,
+	Engine,
+	EvalAltResult,
+	ImmutableString,
+	Map,
+	Scope,
+	AST,
+	INT,
+};
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+use crate::{
+	core::save_file,
+	games::{
+		difficulty::DifficultyRating,
+		modes::GameMode,
+		preview::{
+			GamePreview,
+			DEFAULT_FRAME_DURATION,
+		},
+		scripted::ScriptedGame,
+	},
+	services::{
+		rng,
+		undo::{
+			Command,
+			UndoStack,
+		},
+	},
+	ui::{
+		color_scheme::GHOST_WHITE,
+		components::pause_menu::Hint,
+	},
+};
+
+/// Size of the canvas, unless the script sets `WIDTH` and `HEIGHT`.
+pub const DEFAULT_CANVAS_SIZE: (usize, usize) = (20, 10);
+
+/// Largest size of the canvas.
+pub const MAX_CANVAS_SIZE: (usize, usize) = (200, 100);
+
+/// How many operations a script may run per call, so that an endless loop
+/// doesn't freeze Terminal Arcade.
+pub const MAX_OPERATIONS: u64 = 1_000_000;
+
+/// Points taken off the score for each hint, unless the script sets
+/// `HINT_COST`.
+pub const DEFAULT_HINT_COST: INT = 10;
+
+/// A cell of the [canvas](Canvas).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CanvasCell {
+	/// Character drawn in the cell.
+	pub symbol: char,
+
+	/// Color of the character.
+	pub color: Color,
+}
+
+impl Default for CanvasCell {
+	fn default() -> Self {
+		Self {
+			symbol: ' ',
+			color: GHOST_WHITE,
+		}
+	}
+}
+
+/// What scripts draw on and keep their state in, bound to `this` in their
+/// functions.
+#[derive(Debug, Clone, Default)]
+#[must_use]
+pub struct Canvas {
+	/// Width of the canvas, in cells.
+	width: usize,
+
+	/// Height of the canvas, in cells.
+	height: usize,
+
+	/// Cells of the canvas, row by row.
+	cells: Vec<CanvasCell>,
+
+	/// Text shown below the canvas.
+	pub status: String,
+
+	/// The player's score.
+	pub score: INT,
+
+	/// Data kept between runs of the game.
+	pub data: Map,
+
+	/// Whether the game is over.
+	pub over: bool,
+
+	/// Whether the game ended with the player winning.
+	pub won: bool,
+
+	/// How many hints the player was given.
+	pub hints: INT,
+
+	/// The mode the game is played in.
+	mode: GameMode,
+}
+
+impl Canvas {
+	/// Creates an empty canvas of a size.
+	pub fn new(width: usize, height: usize, data: Map, mode: GameMode) -> Self {
+		Self {
+			width,
+			height,
+			cells: vec![CanvasCell::default(); width * height],
+			data,
+			mode,
+			..Self::default()
+		}
+	}
+
+	/// Returns the width of the canvas, in cells.
+	#[must_use]
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	/// Returns the height of the canvas, in cells.
+	#[must_use]
+	pub fn height(&self) -> usize {
+		self.height
+	}
+
+	/// Returns the rows of cells.
+	pub fn rows(&self) -> impl Iterator<Item = &[CanvasCell]> {
+		self.cells.chunks(self.width.max(1))
+	}
+
+	/// Returns the index of a position in [`Self::cells`], if it's on the
+	/// canvas.
+	fn index(&self, x: INT, y: INT) -> Option<usize> {
+		let x = usize::try_from(x).ok().filter(|&x| x < self.width)?;
+		let y = usize::try_from(y).ok().filter(|&y| y < self.height)?;
+		Some(y * self.width + x)
+	}
+
+	/// Writes text from a position rightwards, cut off at the canvas' edge.
+	fn print(&mut self, x: INT, y: INT, text: &str, color: Color) {
+		for (offset, symbol) in (0..).zip(text.chars()) {
+			if let Some(index) = self.index(x + offset, y) {
+				self.cells[index] = CanvasCell { symbol, color };
+			}
+		}
+	}
+
+	/// Returns the character at a position, or an empty string outside of the
+	/// canvas.
+	fn symbol_at(&self, x: INT, y: INT) -> String {
+		self.index(x, y).map(|index| self.cells[index].symbol.to_string()).unwrap_or_default()
+	}
+
+	/// Clears every cell.
+	fn clear(&mut self) {
+		self.cells.fill(CanvasCell::default());
+	}
+
+	/// Returns the symbols and colors of the cells, row by row, as saved.
+	fn saved_cells(&self) -> Vec<(char, String)> {
+		self.cells.iter().map(|cell| (cell.symbol, cell.color.to_string())).collect()
+	}
+
+	/// Restores saved cells, unless the canvas' size changed since.
+	fn restore_cells(&mut self, cells: &[(char, String)]) {
+		if cells.len() != self.cells.len() {
+			return;
+		}
+		for (cell, (symbol, color)) in self.cells.iter_mut().zip(cells) {
+			*cell = CanvasCell {
+				symbol: *symbol,
+				color: Color::from_str(color).unwrap_or(GHOST_WHITE),
+			};
+		}
+	}
+}
+
+/// What a key press can change on the canvas, kept to take it back.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CanvasState {
+	/// Symbols and colors of the canvas' cells, row by row.
+	cells: Vec<(char, String)>,
+
+	/// Text shown below the canvas.
+	status: String,
+
+	/// The player's score.
+	score: INT,
+
+	/// The script's data, where it keeps the game's state.
+	data: Map,
+}
+
+impl CanvasState {
+	/// Takes the state of a canvas.
+	fn of(canvas: &Canvas) -> Self {
+		Self {
+			cells: canvas.saved_cells(),
+			status: canvas.status.clone(),
+			score: canvas.score,
+			data: canvas.data.clone(),
+		}
+	}
+
+	/// Puts a canvas back in this state.
+	fn restore(&self, canvas: &mut Canvas) {
+		canvas.restore_cells(&self.cells);
+		canvas.status.clone_from(&self.status);
+		canvas.score = self.score;
+		canvas.data.clone_from(&self.data);
+	}
+
+	/// Returns whether two states are the same, comparing data by its JSON
+	/// since script values can't be compared directly.
+	fn same_as(&self, other: &Self) -> bool {
+		self.cells == other.cells
+			&& self.status == other.status
+			&& self.score == other.score
+			&& serde_json::to_string(&self.data).ok() == serde_json::to_string(&other.data).ok()
+	}
+}
+
+/// A key press that changed the game, undone by putting the canvas back the
+/// way it was before.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyPress {
+	/// The canvas before the key press.
+	before: CanvasState,
+
+	/// The canvas after the key press.
+	after: CanvasState,
+}
+
+impl Command for KeyPress {
+	type Target = Canvas;
+
+	fn apply(&self, canvas: &mut Canvas) {
+		self.after.restore(canvas);
+	}
+
+	fn revert(&self, canvas: &mut Canvas) {
+		self.before.restore(canvas);
+	}
+}
+
+/// Parses a color name (like `red`), hex code (like `#ff8800`) or ANSI index.
+fn parse_color(color: &str) -> Result<Color, Box<EvalAltResult>> {
+	Color::from_str(color).map_err(|_| format!("Unknown color {color}").into())
+}
+
+/// Creates an engine with the scripting API registered.
+fn create_engine() -> Engine {
+	let mut engine = Engine::new();
+	engine.set_max_operations(MAX_OPERATIONS);
+	engine
+		.register_type_with_name::<Canvas>("Canvas")
+		.register_get("width", |canvas: &mut Canvas| canvas.width as INT)
+		.register_get("height", |canvas: &mut Canvas| canvas.height as INT)
+		.register_get_set(
+			"status",
+			|canvas: &mut Canvas| canvas.status.clone(),
+			|canvas: &mut Canvas, status: String| canvas.status = status,
+		)
+		.register_get_set(
+			"score",
+			|canvas: &mut Canvas| canvas.score,
+			|canvas: &mut Canvas, score: INT| canvas.score = score,
+		)
+		.register_get_set(
+			"data",
+			|canvas: &mut Canvas| canvas.data.clone(),
+			|canvas: &mut Canvas, data: Map| canvas.data = data,
+		)
+		.register_get("over", |canvas: &mut Canvas| canvas.over)
+		.register_get("hints", |canvas: &mut Canvas| canvas.hints)
+		.register_get("mode", |canvas: &mut Canvas| canvas.mode.id().to_string())
+		.register_fn("end", |canvas: &mut Canvas| canvas.over = true)
+		.register_fn("win", |canvas: &mut Canvas| {
+			canvas.over = true;
+			canvas.won = true;
+		})
+		.register_fn("clear", Canvas::clear)
+		.register_fn("get", |canvas: &mut Canvas, x: INT, y: INT| {
+			canvas.symbol_at(x, y)
+		})
+		.register_fn("set", |canvas: &mut Canvas, x: INT, y: INT, text: &str| {
+			canvas.print(x, y, text, GHOST_WHITE);
+		})
+		.register_fn(
+			"set",
+			|canvas: &mut Canvas,
+			 x: INT,
+			 y: INT,
+			 text: &str,
+			 color: &str|
+			 -> Result<(), Box<EvalAltResult>> {
+				canvas.print(x, y, text, parse_color(color)?);
+				Ok(())
+			},
+		)
+		.register_fn("random", |min: INT, max: INT| {
+			rng::with_rng(|rng| rng.gen_range(min.min(max)..=max.max(min)))
+		});
+	engine
+}
+
+/// Converts a script error into an [`anyhow::Error`].
+#[allow(clippy::needless_pass_by_value)]
+fn script_error(error: Box<EvalAltResult>) -> anyhow::Error {
+	anyhow!("{error}")
+}
+
+/// A game's info, declared as constants at the top of its script.
+#[derive(Debug, Clone, Default)]
+pub struct ScriptInfo {
+	/// The `NAME` constant.
+	pub name: Option<String>,
+
+	/// The `DESCRIPTION` constant.
+	pub description: Option<String>,
+
+	/// The `VERSION` constant.
+	pub version: Option<String>,
+
+	/// The [modes](GameMode) in the `MODES` array, by
+	/// [identifier](GameMode::id). Unknown ones are left out.
+	pub modes: Vec<GameMode>,
+
+	/// The `AUTHORS` constant, either an array of names or a single one.
+	pub authors: Vec<String>,
+
+	/// The `TIMED` constant, set by games played against the clock.
+	pub timed: bool,
+
+	/// The [difficulty](DifficultyRating) in the `DIFFICULTY` constant, by
+	/// [identifier](DifficultyRating::id).
+	pub difficulty: Option<DifficultyRating>,
+
+	/// The [preview](GamePreview) in the `PREVIEW` constant, either a string
+	/// or an array of frames shown every `PREVIEW_MS` milliseconds.
+	pub preview: Option<GamePreview>,
+}
+
+/// Reads the `AUTHORS` constant of a script, which is either an array of names
+/// or a single one.
+fn read_authors(scope: &Scope<'_>) -> Vec<String> {
+	if let Some(author) = scope.get_value::<ImmutableString>("AUTHORS") {
+		return vec![author.to_string()];
+	}
+	scope
+		.get_value::<Array>("AUTHORS")
+		.unwrap_or_default()
+		.into_iter()
+		.filter_map(|author| author.into_string().ok())
+		.collect()
+}
+
+/// Reads the `PREVIEW` constant of a script, which is either a string or an
+/// array of frames.
+fn read_preview(scope: &Scope<'_>) -> Option<GamePreview> {
+	if let Some(art) = scope.get_value::<ImmutableString>("PREVIEW") {
+		return Some(GamePreview::still(art.to_string()));
+	}
+	let frames = scope.get_value::<Array>("PREVIEW")?;
+	let frame_duration = scope
+		.get_value::<INT>("PREVIEW_MS")
+		.and_then(|ms| u64::try_from(ms).ok())
+		.map_or(DEFAULT_FRAME_DURATION, Duration::from_millis);
+	Some(GamePreview::animated(
+		frames.into_iter().filter_map(|frame| frame.into_string().ok()),
+		frame_duration,
+	))
+}
+
+/// Reads the info constants of a script.
+pub fn read_info(path: &Path) -> anyhow::Result<ScriptInfo> {
+	let engine = create_engine();
+	let ast = engine.compile_file(path.to_path_buf()).map_err(script_error)?;
+	let mut scope = Scope::new();
+	engine.run_ast_with_scope(&mut scope, &ast).map_err(script_error)?;
+	let read = |name| scope.get_value::<ImmutableString>(name).map(|value| value.to_string());
+	let modes = scope
+		.get_value::<Array>("MODES")
+		.unwrap_or_default()
+		.into_iter()
+		.filter_map(|mode| GameMode::from_id(&mode.into_string().ok()?))
+		.collect();
+	Ok(ScriptInfo {
+		name: read("NAME"),
+		description: read("DESCRIPTION"),
+		version: read("VERSION"),
+		modes,
+		authors: read_authors(&scope),
+		timed: scope.get_value::<bool>("TIMED").unwrap_or_default(),
+		difficulty: read("DIFFICULTY").and_then(|id| DifficultyRating::from_id(&id)),
+		preview: read_preview(&scope),
+	})
+}
+
+/// A game in progress, as [autosaved](crate::services::autosave): what's on
+/// the canvas, and the key presses to undo. The rest of the game's state is in
+/// the canvas' data, which is saved on its own.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuntimeSnapshot {
+	/// Symbols and colors of the canvas' cells, row by row.
+	cells: Vec<(char, String)>,
+
+	/// Text shown below the canvas.
+	status: String,
+
+	/// The player's score.
+	score: INT,
+
+	/// How many hints the player was given.
+	#[serde(default)]
+	hints: INT,
+
+	/// Key presses to undo and redo.
+	#[serde(default)]
+	history: UndoStack<KeyPress>,
+}
+
+/// A running scripted game.
+#[must_use]
+pub struct ScriptRuntime {
+	/// The game run.
+	game: ScriptedGame,
+
+	/// Engine running the script.
+	engine: Engine,
+
+	/// The compiled script.
+	ast: AST,
+
+	/// Variables and constants declared at the script's top level.
+	scope: Scope<'static>,
+
+	/// The canvas the script draws on.
+	canvas: Canvas,
+
+	/// Key presses to undo and redo, if the script sets `UNDO`.
+	history: UndoStack<KeyPress>,
+}
+
+impl ScriptRuntime {
+	/// Compiles a game's script, runs its top level, then resumes the game
+	/// from a snapshot if given one, or else calls its `init` function.
+	pub fn load(
+		game: &ScriptedGame,
+		mode: GameMode,
+		snapshot: Option<RuntimeSnapshot>,
+	) -> anyhow::Result<Self> {
+		let engine = create_engine();
+		let ast = engine.compile_file(game.path.clone()).map_err(script_error)?;
+		let mut scope = Scope::new();
+		engine.run_ast_with_scope(&mut scope, &ast).map_err(script_error)?;
+		let size = |name, default: usize, max: usize| {
+			scope
+				.get_value::<INT>(name)
+				.and_then(|size| usize::try_from(size).ok())
+				.unwrap_or(default)
+				.clamp(1, max)
+		};
+		let width = size("WIDTH", DEFAULT_CANVAS_SIZE.0, MAX_CANVAS_SIZE.0);
+		let height = size("HEIGHT", DEFAULT_CANVAS_SIZE.1, MAX_CANVAS_SIZE.1);
+		let data = save_file::read(&game.data_path())
+			.ok()
+			.and_then(|data| serde_json::from_str(&data).ok())
+			.unwrap_or_default();
+		let mut runtime = Self {
+			game: game.clone(),
+			engine,
+			ast,
+			scope,
+			canvas: Canvas::new(width, height, data, mode),
+			history: UndoStack::default(),
+		};
+		match snapshot {
+			Some(snapshot) => runtime.restore(snapshot),
+			None => runtime.call("init", ())?,
+		}
+		Ok(runtime)
+	}
+
+	/// Returns how often the script's `on_tick` function is called, in
+	/// milliseconds, if the script sets `TICK_MS`.
+	#[must_use]
+	pub fn tick_interval_ms(&self) -> Option<u64> {
+		self.scope.get_value::<INT>("TICK_MS").and_then(|ms| u64::try_from(ms).ok())
+	}
+
+	/// Returns the canvas.
+	pub fn canvas(&self) -> &Canvas {
+		&self.canvas
+	}
+
+	/// Returns whether the script has a function.
+	#[must_use]
+	pub fn has_function(&self, name: &str) -> bool {
+		self.ast.iter_functions().any(|function| function.name == name)
+	}
+
+	/// Calls a function of the script with the canvas bound to `this`, if the
+	/// script has it.
+	fn call(&mut self, name: &str, args: impl rhai::FuncArgs) -> anyhow::Result<()> {
+		if !self.has_function(name) {
+			return Ok(());
+		}
+		self.call_for_value(name, args).map(|_| ())
+	}
+
+	/// Calls a function of the script with the canvas bound to `this`,
+	/// returning what it returned.
+	fn call_for_value(&mut self, name: &str, args: impl rhai::FuncArgs) -> anyhow::Result<Dynamic> {
+		let mut this = Dynamic::from(std::mem::take(&mut self.canvas));
+		let options = CallFnOptions::new().eval_ast(false).bind_this_ptr(&mut this);
+		let result = self
+			.engine
+			.call_fn_with_options::<Dynamic>(options, &mut self.scope, &self.ast, name, args)
+			.map_err(script_error);
+		self.canvas = this.cast();
+		result
+	}
+
+	/// Returns whether key presses can be undone: the script sets `UNDO` and
+	/// the game's mode [allows it](GameMode::allows_undo).
+	#[must_use]
+	pub fn undoable(&self) -> bool {
+		self.scope.get_value::<bool>("UNDO").unwrap_or_default() && self.canvas.mode.allows_undo()
+	}
+
+	/// Passes a key press to the script's `on_key` function, remembering it to
+	/// be undone if it changed anything.
+	pub fn key(&mut self, key: &str) -> anyhow::Result<()> {
+		if self.canvas.over {
+			return Ok(());
+		}
+		if !self.undoable() {
+			return self.call("on_key", (key.to_string(),));
+		}
+		let before = CanvasState::of(&self.canvas);
+		self.call("on_key", (key.to_string(),))?;
+		let after = CanvasState::of(&self.canvas);
+		if !after.same_as(&before) {
+			self.history.push(KeyPress { before, after });
+		}
+		Ok(())
+	}
+
+	/// Takes the last key press back, returning whether there was one.
+	pub fn undo(&mut self) -> bool {
+		self.undoable() && !self.canvas.over && self.history.undo(&mut self.canvas)
+	}
+
+	/// Makes the last key press taken back again, returning whether there was
+	/// one.
+	pub fn redo(&mut self) -> bool {
+		self.undoable() && !self.canvas.over && self.history.redo(&mut self.canvas)
+	}
+
+	/// Calls the script's `on_tick` function.
+	pub fn tick(&mut self) -> anyhow::Result<()> {
+		if self.canvas.over {
+			return Ok(());
+		}
+		self.call("on_tick", ())
+	}
+
+	/// Asks the script's `hint` function for a hint, taking `HINT_COST` points
+	/// off the score if it gives one. Hints are either text, or a map with `x`
+	/// and `y` keys pointing at a cell; `()` means there's no hint to give.
+	pub fn hint(&mut self) -> anyhow::Result<Option<Hint>> {
+		if self.canvas.over || !self.has_function("hint") {
+			return Ok(None);
+		}
+		let value = self.call_for_value("hint", ())?;
+		let hint = if value.is_unit() {
+			None
+		} else if let Some(text) = value.clone().try_cast::<ImmutableString>() {
+			Some(Hint::Text(text.to_string()))
+		} else if let Some(map) = value.try_cast::<Map>() {
+			let coordinate = |key: &str| {
+				map.get(key)
+					.and_then(|value| value.as_int().ok())
+					.and_then(|value| usize::try_from(value).ok())
+			};
+			match (coordinate("x"), coordinate("y")) {
+				(Some(x), Some(y)) if x < self.canvas.width && y < self.canvas.height => {
+					Some(Hint::Position(x, y))
+				},
+				_ => return Err(anyhow!("hint() returned a map without a cell's x and y")),
+			}
+		} else {
+			return Err(anyhow!(
+				"hint() must return a string, a map with x and y, or ()"
+			));
+		};
+		if hint.is_some() {
+			self.canvas.hints += 1;
+			self.canvas.score -= self.hint_cost();
+		}
+		Ok(hint)
+	}
+
+	/// Returns how many points a hint costs, which is `HINT_COST` if the script
+	/// sets it.
+	#[must_use]
+	fn hint_cost(&self) -> INT {
+		self.scope.get_value::<INT>("HINT_COST").unwrap_or(DEFAULT_HINT_COST)
+	}
+
+	/// Starts the game over with a blank canvas, keeping its saved data and
+	/// forgetting the key presses to undo.
+	pub fn restart(&mut self) -> anyhow::Result<()> {
+		self.history.clear();
+		self.canvas = Canvas::new(
+			self.canvas.width,
+			self.canvas.height,
+			std::mem::take(&mut self.canvas.data),
+			self.canvas.mode,
+		);
+		self.call("init", ())
+	}
+
+	/// Starts a new round of an endless game, carrying the score and hints
+	/// over.
+	pub fn next_round(&mut self) -> anyhow::Result<()> {
+		let (score, hints) = (self.canvas.score, self.canvas.hints);
+		self.restart()?;
+		self.canvas.score += score;
+		self.canvas.hints += hints;
+		Ok(())
+	}
+
+	/// Ends the game, like when a time trial's countdown runs out.
+	pub fn end(&mut self) -> anyhow::Result<()> {
+		self.canvas.over = true;
+		Ok(())
+	}
+
+	/// Takes a snapshot of the game in progress.
+	pub fn snapshot(&self) -> RuntimeSnapshot {
+		RuntimeSnapshot {
+			cells: self.canvas.saved_cells(),
+			status: self.canvas.status.clone(),
+			score: self.canvas.score,
+			hints: self.canvas.hints,
+			history: self.history.clone(),
+		}
+	}
+
+	/// Restores a game in progress from a snapshot. The canvas is left blank
+	/// if its size changed since.
+	fn restore(&mut self, snapshot: RuntimeSnapshot) {
+		self.canvas.restore_cells(&snapshot.cells);
+		self.canvas.status = snapshot.status;
+		self.canvas.score = snapshot.score;
+		self.canvas.hints = snapshot.hints;
+		self.history = snapshot.history;
+	}
+
+	/// Saves the script's `data` for its next runs.
+	pub fn save(&self) -> anyhow::Result<()> {
+		save_file::write(
+			&self.game.data_path(),
+			&serde_json::to_string_pretty(&self.canvas.data)?,
+		)
+	}
+}
@@ -0,0 +1,83 @@
+//! Implementation for the game Sudoku, where a grid is filled with digits so
+//! that no row, column or box repeats one.
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+use crate::{
+	core::events::Event,
+	games::{
+		difficulty::DifficultyRating,
+		preview::GamePreview,
+		registry::{
+			GameId,
+			GameRegistry,
+		},
+		Game,
+		GameMetadata,
+		GameState,
+		GameStaticInfo,
+	},
+	t,
+	ui::{
+		util::get_crate_authors,
+		SudokuSetupScreen,
+	},
+};
+
+pub mod puzzle;
+pub mod setup;
+
+/// Sudoku's [ID](GameId).
+pub const GAME_ID: &str = "sudoku";
+
+/// Sudoku's manual.
+pub const HELP_PAGE: &str = include_str!("../../../assets/help/sudoku.md");
+
+/// Sudoku's preview, the top of a puzzle being solved.
+const PREVIEW: &str = "\
+5 3 · │ · 7 · │ · · ·
+6 · · │ 1 9 5 │ · · ·
+· 9 8 │ · · · │ · 6 ·
+──────┼───────┼──────
+8 · · │ · 6 · │ · · 3";
+
+/// Registers Sudoku in a [registry](GameRegistry).
+pub fn register(registry: &mut GameRegistry) {
+	registry.register(Sudoku.into());
+}
+
+/// The game [Sudoku](https://en.wikipedia.org/wiki/Sudoku).
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct Sudoku;
+
+impl Game for Sudoku {
+	fn data(&self) -> GameState {
+		GameState::new(
+			GameMetadata::new(
+				GameStaticInfo::new(
+					self.clone().into(),
+					GameId::from(GAME_ID),
+					"Sudoku".to_string(),
+					t!("sudoku-description").to_string(),
+					"0.0.1".to_string(),
+					get_crate_authors(),
+					DifficultyRating::Medium,
+				)
+				.with_preview(Some(GamePreview::still(PREVIEW))),
+			)
+			.unwrap(),
+			Some(SudokuSetupScreen::default().into()),
+		)
+	}
+
+	fn event(&mut self, _event: &Event) -> anyhow::Result<()> {
+		Ok(())
+	}
+
+	fn help_page(&self) -> Option<&'static str> {
+		Some(HELP_PAGE)
+	}
+}
@@ -0,0 +1,345 @@
+//! A Sudoku puzzle: a 9×9 grid split into 3×3 boxes, filled with the digits 1
+//! to 9 so that no row, column or box holds the same digit twice.
+//!
+//! Puzzles are generated from a grid filled at random, then emptied one cell
+//! at a time for as long as the puzzle keeps a single solution, down to the
+//! number of [givens](givens) of its difficulty.
+
+use rand::{
+	seq::SliceRandom,
+	Rng,
+};
+
+use crate::{
+	games::difficulty::DifficultyRating,
+	services::rng,
+};
+
+/// Width and height of the grid, in cells.
+pub const SIZE: usize = 9;
+
+/// Width and height of a box, in cells.
+pub const BOX_SIZE: usize = 3;
+
+/// Number of cells in the grid.
+const CELLS: usize = SIZE * SIZE;
+
+/// Bits of the digits 1 to 9 in a set of digits, where bit `d` is digit `d`.
+const ALL_DIGITS: u16 = 0b11_1111_1110;
+
+/// Digits of a grid, row by row. Empty cells hold 0.
+pub type Digits = [u8; CELLS];
+
+/// Returns how many cells a puzzle of a difficulty starts with filled in.
+/// Harder puzzles may keep a few more, when emptying any other cell would give
+/// them more than one solution.
+#[must_use]
+pub fn givens(difficulty: DifficultyRating) -> usize {
+	match difficulty {
+		DifficultyRating::Easy => 40,
+		DifficultyRating::Medium => 34,
+		DifficultyRating::Hard => 28,
+		DifficultyRating::Expert => 24,
+	}
+}
+
+/// Returns the indices of the cells sharing a row, a column or a box with a
+/// cell, itself included, some of them more than once.
+fn peers(index: usize) -> impl Iterator<Item = usize> {
+	let (x, y) = (index % SIZE, index / SIZE);
+	let (box_x, box_y) = (x / BOX_SIZE * BOX_SIZE, y / BOX_SIZE * BOX_SIZE);
+	(0..SIZE).flat_map(move |step| {
+		[
+			y * SIZE + step,
+			step * SIZE + x,
+			(box_y + step / BOX_SIZE) * SIZE + box_x + step % BOX_SIZE,
+		]
+	})
+}
+
+/// Returns the set of digits that an empty cell can hold without repeating
+/// one of its peers'.
+fn candidates(digits: &Digits, index: usize) -> u16 {
+	let used = peers(index).fold(0, |used, peer| used | 1 << digits[peer]);
+	!used & ALL_DIGITS
+}
+
+/// Fills the empty cells of a grid at random, returning whether it could be
+/// filled.
+fn fill(digits: &mut Digits, rng: &mut impl Rng) -> bool {
+	let Some(index) = digits.iter().position(|&digit| digit == 0) else {
+		return true;
+	};
+	let candidates = candidates(digits, index);
+	let mut options: Vec<u8> = (1..=9).filter(|digit| candidates & 1 << digit != 0).collect();
+	options.shuffle(rng);
+	for digit in options {
+		digits[index] = digit;
+		if fill(digits, rng) {
+			return true;
+		}
+	}
+	digits[index] = 0;
+	false
+}
+
+/// Counts the solutions of a grid, stopping at a limit. The grid is left as
+/// it was.
+fn count_solutions(digits: &mut Digits, limit: usize) -> usize {
+	// The cell with the fewest candidates is tried first, which keeps the
+	// search short.
+	let mut fewest: Option<(usize, u16)> = None;
+	for index in (0..CELLS).filter(|&index| digits[index] == 0) {
+		let candidates = candidates(digits, index);
+		if fewest.is_none_or(|(_, fewest)| candidates.count_ones() < fewest.count_ones()) {
+			fewest = Some((index, candidates));
+			if candidates.count_ones() <= 1 {
+				break;
+			}
+		}
+	}
+	let Some((index, candidates)) = fewest else {
+		return 1;
+	};
+	let mut count = 0;
+	for digit in (1..=9).filter(|digit| candidates & 1 << digit != 0) {
+		digits[index] = digit;
+		count += count_solutions(digits, limit - count);
+		if count >= limit {
+			break;
+		}
+	}
+	digits[index] = 0;
+	count
+}
+
+/// A puzzle being solved, with the digits and pencil marks filled in so far.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct Puzzle {
+	/// The digits the puzzle started with, which can't be changed.
+	givens: Digits,
+
+	/// The digits filled in, givens included.
+	digits: Digits,
+
+	/// Pencil marks of the empty cells, as sets of digits.
+	marks: [u16; CELLS],
+}
+
+impl Puzzle {
+	/// Creates a puzzle starting with digits.
+	pub fn new(givens: Digits) -> Self {
+		Self {
+			givens,
+			digits: givens,
+			marks: [0; CELLS],
+		}
+	}
+
+	/// Generates a puzzle of a difficulty with a single solution, with the
+	/// [shared generator](rng).
+	pub fn generate(difficulty: DifficultyRating) -> Self {
+		rng::with_rng(|rng| Self::generate_with(difficulty, rng))
+	}
+
+	/// Generates a puzzle of a difficulty with a single solution, with a
+	/// generator.
+	fn generate_with(difficulty: DifficultyRating, rng: &mut impl Rng) -> Self {
+		let target = givens(difficulty);
+		let mut digits = [0; CELLS];
+		fill(&mut digits, rng);
+		let mut order: Vec<_> = (0..CELLS).collect();
+		order.shuffle(rng);
+		let mut givens = CELLS;
+		for index in order {
+			if givens <= target {
+				break;
+			}
+			let digit = std::mem::take(&mut digits[index]);
+			if count_solutions(&mut digits, 2) == 1 {
+				givens -= 1;
+			} else {
+				digits[index] = digit;
+			}
+		}
+		Self::new(digits)
+	}
+
+	/// Returns the digit of a cell, 0 if it's empty.
+	#[must_use]
+	pub fn get(&self, x: usize, y: usize) -> u8 {
+		self.digits[y * SIZE + x]
+	}
+
+	/// Returns whether a cell's digit was given, and can't be changed.
+	#[must_use]
+	pub fn is_given(&self, x: usize, y: usize) -> bool {
+		self.givens[y * SIZE + x] != 0
+	}
+
+	/// Fills a cell in with a digit, or empties it with 0, clearing its pencil
+	/// marks. Returns whether the cell could be changed, which given cells
+	/// can't.
+	pub fn set(&mut self, x: usize, y: usize, digit: u8) -> bool {
+		if self.is_given(x, y) {
+			return false;
+		}
+		let index = y * SIZE + x;
+		self.digits[index] = digit;
+		self.marks[index] = 0;
+		true
+	}
+
+	/// Pencils a digit in an empty cell as a candidate, or rubs it out if it
+	/// was. Returns whether the cell could be marked, which filled cells
+	/// can't.
+	pub fn toggle_mark(&mut self, x: usize, y: usize, digit: u8) -> bool {
+		let index = y * SIZE + x;
+		if self.digits[index] != 0 {
+			return false;
+		}
+		self.marks[index] ^= 1 << digit;
+		true
+	}
+
+	/// Returns the digits pencilled in a cell, in order.
+	#[must_use]
+	pub fn marks(&self, x: usize, y: usize) -> Vec<u8> {
+		let marks = self.marks[y * SIZE + x];
+		(1..=9).filter(|digit| marks & 1 << digit != 0).collect()
+	}
+
+	/// Returns whether a cell's digit is also in its row, column or box.
+	#[must_use]
+	pub fn is_conflicting(&self, x: usize, y: usize) -> bool {
+		let index = y * SIZE + x;
+		let digit = self.digits[index];
+		digit != 0 && peers(index).any(|peer| peer != index && self.digits[peer] == digit)
+	}
+
+	/// Returns whether every cell is filled in without any conflict.
+	#[must_use]
+	pub fn is_solved(&self) -> bool {
+		(0..CELLS).all(|index| {
+			self.digits[index] != 0 && !self.is_conflicting(index % SIZE, index / SIZE)
+		})
+	}
+
+	/// Returns the puzzle's solution.
+	#[must_use]
+	pub fn solution(&self) -> Digits {
+		// Puzzles have a single solution, found whatever the order digits are
+		// tried in.
+		let mut solution = self.givens;
+		fill(&mut solution, &mut rng::seeded(0));
+		solution
+	}
+
+	/// Empties every cell that wasn't given, with their pencil marks.
+	pub fn reset(&mut self) {
+		*self = Self::new(self.givens);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use proptest::prelude::*;
+
+	use super::{
+		count_solutions,
+		fill,
+		givens,
+		peers,
+		Digits,
+		Puzzle,
+		CELLS,
+	};
+	use crate::{
+		games::difficulty::DifficultyRating,
+		services::rng,
+	};
+
+	/// Generates a difficulty.
+	fn difficulty() -> impl Strategy<Value = DifficultyRating> {
+		prop_oneof![
+			Just(DifficultyRating::Easy),
+			Just(DifficultyRating::Medium),
+			Just(DifficultyRating::Hard),
+			Just(DifficultyRating::Expert),
+		]
+	}
+
+	/// Returns a puzzle's solution.
+	fn solve(puzzle: &Puzzle) -> Digits {
+		let mut solution = puzzle.givens;
+		assert!(fill(&mut solution, &mut rng::seeded(0)));
+		solution
+	}
+
+	#[test]
+	fn generated_puzzles_have_one_solution() {
+		let puzzle = Puzzle::generate(DifficultyRating::Easy);
+		let given = puzzle.givens.iter().filter(|&&digit| digit != 0).count();
+		assert_eq!(given, givens(DifficultyRating::Easy));
+		assert_eq!(count_solutions(&mut puzzle.givens.clone(), 2), 1);
+	}
+
+	#[test]
+	fn conflicts_are_highlighted_until_solved() {
+		let mut solution = [0; CELLS];
+		assert!(fill(&mut solution, &mut rng::seeded(7)));
+		let mut givens = solution;
+		givens[0] = 0;
+		givens[CELLS - 1] = 0;
+		let mut puzzle = Puzzle::new(givens);
+		assert!(!puzzle.is_solved());
+		assert!(!puzzle.set(1, 0, 0));
+
+		assert!(puzzle.toggle_mark(0, 0, 4));
+		assert_eq!(puzzle.marks(0, 0), [4]);
+		assert!(puzzle.set(0, 0, solution[1]));
+		assert!(puzzle.marks(0, 0).is_empty());
+		assert!(puzzle.is_conflicting(0, 0));
+		assert!(puzzle.is_conflicting(1, 0));
+
+		puzzle.set(0, 0, solution[0]);
+		puzzle.set(8, 8, solution[CELLS - 1]);
+		assert!(!puzzle.is_conflicting(0, 0));
+		assert!(puzzle.is_solved());
+	}
+
+	proptest! {
+		#![proptest_config(ProptestConfig::with_cases(32))]
+
+		#[test]
+		fn generates_puzzles_with_one_solution(difficulty in difficulty(), seed in any::<u64>()) {
+			let puzzle = Puzzle::generate_with(difficulty, &mut rng::seeded(seed));
+			prop_assert_eq!(count_solutions(&mut puzzle.givens.clone(), 2), 1);
+			let given = puzzle.givens.iter().filter(|&&digit| digit != 0).count();
+			prop_assert!(given >= givens(difficulty));
+		}
+
+		#[test]
+		fn keeps_givens_in_the_solution(difficulty in difficulty(), seed in any::<u64>()) {
+			let puzzle = Puzzle::generate_with(difficulty, &mut rng::seeded(seed));
+			let solution = solve(&puzzle);
+			for (given, digit) in puzzle.givens.into_iter().zip(solution) {
+				prop_assert!(given == 0 || given == digit);
+			}
+		}
+
+		#[test]
+		fn solves_into_a_valid_grid(difficulty in difficulty(), seed in any::<u64>()) {
+			let puzzle = Puzzle::generate_with(difficulty, &mut rng::seeded(seed));
+			let solution = solve(&puzzle);
+			for index in 0..CELLS {
+				prop_assert!((1..=9).contains(&solution[index]));
+				for peer in peers(index).filter(|&peer| peer != index) {
+					prop_assert_ne!(solution[peer], solution[index]);
+				}
+			}
+			prop_assert!(Puzzle::new(solution).is_solved());
+		}
+	}
+}
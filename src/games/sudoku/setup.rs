@@ -0,0 +1,64 @@
+//! The answers to Sudoku's setup screen: how hard the puzzle is.
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+use strum::{
+	Display,
+	EnumIter,
+	IntoEnumIterator,
+};
+
+use crate::{
+	games::difficulty::DifficultyRating,
+	t,
+	ui::components::games::setup::SetupAnswers,
+};
+
+/// A question of the setup screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, EnumIter)]
+pub enum PuzzleField {
+	/// How hard the puzzle is.
+	Difficulty,
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[must_use]
+pub struct PuzzleSetup {
+	/// How hard the puzzle is.
+	pub difficulty: DifficultyRating,
+}
+
+impl SetupAnswers for PuzzleSetup {
+	type Question = PuzzleField;
+
+	fn label(question: PuzzleField) -> &'static str {
+		match question {
+			PuzzleField::Difficulty => t!("sudoku-difficulty"),
+		}
+	}
+
+	fn answer(&self, question: PuzzleField) -> usize {
+		match question {
+			PuzzleField::Difficulty => self.difficulty as usize,
+		}
+	}
+
+	fn answer_text(&self, question: PuzzleField) -> String {
+		match question {
+			PuzzleField::Difficulty => self.difficulty.to_string(),
+		}
+	}
+
+	fn adjust(&mut self, question: PuzzleField, step: isize) {
+		match question {
+			PuzzleField::Difficulty => {
+				let ratings: Vec<_> = DifficultyRating::iter().collect();
+				let index = (self.difficulty as usize).saturating_add_signed(step);
+				self.difficulty = ratings[index.min(ratings.len() - 1)];
+			},
+		}
+	}
+}
@@ -0,0 +1,299 @@
+//! The arena light cycles race in, leaving trails behind them.
+
+use std::time::Duration;
+
+use rand::{
+	seq::SliceRandom,
+	Rng,
+};
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+use crate::{
+	games::demo::DemoPlayable,
+	services::rng,
+	ui::screens::games::tron::tron_game::{
+		ARENA_HEIGHT,
+		ARENA_WIDTH,
+		TICK_INTERVAL,
+	},
+};
+
+/// Position of a cell in the arena, as (x, y).
+pub type Position = (usize, usize);
+
+/// Number of players in the arena.
+pub const PLAYER_COUNT: usize = 2;
+
+/// How many cells ahead [demo](DemoPlayable) cycles look for room to ride in.
+const DEMO_LOOKAHEAD: usize = 4;
+
+/// How often demo cycles turn while there's still room ahead, as one in this
+/// many steps.
+const DEMO_WANDER_ODDS: u32 = 12;
+
+/// A direction light cycles can head in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub enum Heading {
+	Up,
+	Down,
+	Left,
+	Right,
+}
+
+impl Heading {
+	/// Returns the opposite heading.
+	#[must_use]
+	pub fn opposite(self) -> Self {
+		match self {
+			Self::Up => Self::Down,
+			Self::Down => Self::Up,
+			Self::Left => Self::Right,
+			Self::Right => Self::Left,
+		}
+	}
+
+	/// Returns every heading.
+	#[must_use]
+	pub fn all() -> [Self; 4] {
+		[Self::Up, Self::Down, Self::Left, Self::Right]
+	}
+
+	/// Returns the change in position when moving one cell in this heading.
+	#[must_use]
+	pub fn delta(self) -> (isize, isize) {
+		match self {
+			Self::Up => (0, -1),
+			Self::Down => (0, 1),
+			Self::Left => (-1, 0),
+			Self::Right => (1, 0),
+		}
+	}
+}
+
+/// A player's light cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cycle {
+	/// Position of the cycle.
+	pub position: Position,
+
+	/// Where the cycle is heading.
+	pub heading: Heading,
+
+	/// Whether the cycle hasn't crashed yet.
+	pub alive: bool,
+}
+
+/// How a round ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+	/// A player outlived the other.
+	Winner(usize),
+
+	/// Every cycle crashed at once.
+	Draw,
+}
+
+/// An arena with a cycle per player. Every cell a cycle has been on is part of
+/// its trail, which crashes any cycle riding into it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[must_use]
+pub struct Arena {
+	/// Width of the arena.
+	width: usize,
+
+	/// Height of the arena.
+	height: usize,
+
+	/// The cycles, by player.
+	cycles: [Cycle; PLAYER_COUNT],
+
+	/// Which player's trail is on each cell, row by row.
+	trails: Vec<Option<usize>>,
+}
+
+impl Arena {
+	/// Creates an arena with the cycles facing each other from its sides.
+	pub fn new(width: usize, height: usize) -> Self {
+		let cycles = [
+			Cycle {
+				position: (width / 4, height / 2),
+				heading: Heading::Right,
+				alive: true,
+			},
+			Cycle {
+				position: (width - 1 - width / 4, height / 2),
+				heading: Heading::Left,
+				alive: true,
+			},
+		];
+		let mut arena = Self {
+			width,
+			height,
+			cycles,
+			trails: vec![None; width * height],
+		};
+		arena.mark_trails();
+		arena
+	}
+
+	/// Returns the width of the arena.
+	#[must_use]
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	/// Returns the height of the arena.
+	#[must_use]
+	pub fn height(&self) -> usize {
+		self.height
+	}
+
+	/// Returns the cycles, by player.
+	#[must_use]
+	pub fn cycles(&self) -> &[Cycle; PLAYER_COUNT] {
+		&self.cycles
+	}
+
+	/// Returns which player's trail is on a cell, if any.
+	#[must_use]
+	pub fn trail(&self, (x, y): Position) -> Option<usize> {
+		self.trails.get(y * self.width + x).copied().flatten()
+	}
+
+	/// Turns a player's cycle. Cycles can't turn back on themselves.
+	pub fn turn(&mut self, player: usize, heading: Heading) {
+		let cycle = &mut self.cycles[player];
+		if heading != cycle.heading.opposite() {
+			cycle.heading = heading;
+		}
+	}
+
+	/// Moves every cycle one cell forward, crashing those riding into a wall, a
+	/// trail or each other.
+	pub fn step(&mut self) {
+		if self.outcome().is_some() {
+			return;
+		}
+		let targets = self.cycles.map(|cycle| self.target(cycle));
+		for (player, target) in targets.iter().enumerate() {
+			let cycle = &mut self.cycles[player];
+			if !cycle.alive {
+				continue;
+			}
+			let collides_with_other = targets
+				.iter()
+				.enumerate()
+				.any(|(other, other_target)| other != player && other_target == target);
+			match target {
+				Some(position) if self.trails[position.1 * self.width + position.0].is_none() => {
+					cycle.position = *position;
+					cycle.alive = !collides_with_other;
+				},
+				_ => cycle.alive = false,
+			}
+		}
+		self.mark_trails();
+	}
+
+	/// Returns where a cycle moves next, or [`None`] if it rides into a wall.
+	fn target(&self, cycle: Cycle) -> Option<Position> {
+		self.neighbor(cycle.position, cycle.heading)
+	}
+
+	/// Returns the cell next to a position in a heading, or [`None`] past the
+	/// walls.
+	fn neighbor(&self, (x, y): Position, heading: Heading) -> Option<Position> {
+		let (dx, dy) = heading.delta();
+		let x = x.checked_add_signed(dx).filter(|x| *x < self.width)?;
+		let y = y.checked_add_signed(dy).filter(|y| *y < self.height)?;
+		Some((x, y))
+	}
+
+	/// Returns how many free cells there are in a row from a position in a
+	/// heading, up to [`DEMO_LOOKAHEAD`].
+	fn free_run(&self, mut position: Position, heading: Heading) -> usize {
+		let mut run = 0;
+		while run < DEMO_LOOKAHEAD {
+			match self.neighbor(position, heading) {
+				Some(next) if self.trail(next).is_none() => position = next,
+				_ => break,
+			}
+			run += 1;
+		}
+		run
+	}
+
+	/// Returns where a player's cycle heads next in a demo: on while there's
+	/// room ahead, and otherwise towards the most room, now and then turning
+	/// at random.
+	fn demo_heading(&self, player: usize) -> Heading {
+		let cycle = self.cycles[player];
+		let wander = rng::with_rng(|rng| rng.gen_ratio(1, DEMO_WANDER_ODDS));
+		if !wander && self.free_run(cycle.position, cycle.heading) == DEMO_LOOKAHEAD {
+			return cycle.heading;
+		}
+		let runs: Vec<_> = Heading::all()
+			.into_iter()
+			.filter(|&heading| heading != cycle.heading.opposite())
+			.map(|heading| (heading, self.free_run(cycle.position, heading)))
+			.collect();
+		let longest = runs.iter().map(|&(_, run)| run).max().unwrap_or_default();
+		let headings: Vec<_> = runs
+			.into_iter()
+			.filter(|&(_, run)| run == longest)
+			.map(|(heading, _)| heading)
+			.collect();
+		rng::with_rng(|rng| headings.choose(rng).copied()).unwrap_or(cycle.heading)
+	}
+
+	/// Replaces the cycles with ones received from the simulating peer, leaving
+	/// trails where they are.
+	pub fn apply(&mut self, cycles: [Cycle; PLAYER_COUNT]) {
+		self.cycles = cycles;
+		self.mark_trails();
+	}
+
+	/// Marks the cells under the cycles as part of their trails.
+	fn mark_trails(&mut self) {
+		for (player, cycle) in self.cycles.iter().enumerate() {
+			let index = cycle.position.1 * self.width + cycle.position.0;
+			self.trails[index].get_or_insert(player);
+		}
+	}
+
+	/// Returns how the round ended, or [`None`] if it's still going.
+	#[must_use]
+	pub fn outcome(&self) -> Option<Outcome> {
+		let mut alive = self.cycles.iter().enumerate().filter(|(_, cycle)| cycle.alive);
+		match (alive.next(), alive.next()) {
+			(None, _) => Some(Outcome::Draw),
+			(Some((player, _)), None) => Some(Outcome::Winner(player)),
+			_ => None,
+		}
+	}
+}
+
+impl DemoPlayable for Arena {
+	type Move = [Heading; PLAYER_COUNT];
+
+	const MOVE_INTERVAL: Duration = TICK_INTERVAL;
+
+	fn new_demo() -> Self {
+		Self::new(ARENA_WIDTH, ARENA_HEIGHT)
+	}
+
+	fn demo_move(&self) -> Option<Self::Move> {
+		self.outcome().is_none().then(|| std::array::from_fn(|player| self.demo_heading(player)))
+	}
+
+	fn play_demo_move(&mut self, headings: Self::Move) {
+		for (player, heading) in headings.into_iter().enumerate() {
+			self.turn(player, heading);
+		}
+		self.step();
+	}
+}
@@ -0,0 +1,142 @@
+//! Implementation for the game Tron, a two-player light cycle race played over
+//! the network.
+
+use std::time::Duration;
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+use crate::{
+	core::events::Event,
+	games::{
+		difficulty::DifficultyRating,
+		preview::GamePreview,
+		registry::{
+			GameId,
+			GameRegistry,
+		},
+		tron::arena::{
+			Arena,
+			Cycle,
+			Heading,
+			PLAYER_COUNT,
+		},
+		Game,
+		GameMetadata,
+		GameState,
+		GameStaticInfo,
+	},
+	t,
+	ui::{
+		screens::Screens,
+		util::get_crate_authors,
+		TronDemoScreen,
+		TronSetupScreen,
+	},
+};
+
+pub mod arena;
+
+/// Tron's manual.
+pub const HELP_PAGE: &str = include_str!("../../../assets/help/tron.md");
+
+/// Name of the game, used to match players in a session.
+pub const GAME_NAME: &str = "Tron";
+
+/// Tron's [ID](GameId).
+pub const GAME_ID: &str = "tron";
+
+/// Width of the track in Tron's preview, in columns.
+const PREVIEW_WIDTH: usize = 24;
+
+/// Returns Tron's preview, two light cycles racing past each other.
+fn preview() -> GamePreview {
+	let frames = (0..PREVIEW_WIDTH).map(|step| {
+		let first = format!("{}▶", "━".repeat(step));
+		let second = format!("◀{}", "━".repeat(step));
+		format!("\n{first}\n\n{second:>PREVIEW_WIDTH$}")
+	});
+	GamePreview::animated(frames, Duration::from_millis(120))
+}
+
+/// An event exchanged between the players of a session. The host runs the
+/// simulation and the guest only sends where it turns, while spectators only
+/// receive the host's events.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TronEvent {
+	/// The guest turned its cycle.
+	Turn(Heading),
+
+	/// The cycles after a step of the simulation.
+	Tick([Cycle; PLAYER_COUNT]),
+
+	/// The host started a new round.
+	Restart,
+
+	/// A player paused the game, or resumed it. The round only goes on while
+	/// neither player has it paused.
+	Paused(bool),
+
+	/// A player's [rating](crate::services::rating), sent by both players
+	/// when the game starts.
+	Rating(u32),
+
+	/// Catches spectators up with the game when they start watching.
+	Sync {
+		/// The arena as it is.
+		arena: Arena,
+
+		/// Names of the players, by player.
+		names: [String; PLAYER_COUNT],
+
+		/// Rounds won, by player.
+		wins: [u32; PLAYER_COUNT],
+
+		/// Ratings, by player.
+		ratings: [u32; PLAYER_COUNT],
+	},
+}
+
+/// Registers Tron in a [registry](GameRegistry).
+pub fn register(registry: &mut GameRegistry) {
+	registry.register(Tron.into());
+}
+
+/// The game [Tron](https://en.wikipedia.org/wiki/Tron_(video_game)).
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct Tron;
+
+impl Game for Tron {
+	fn data(&self) -> GameState {
+		GameState::new(
+			GameMetadata::new(
+				GameStaticInfo::new(
+					self.clone().into(),
+					GameId::from(GAME_ID),
+					GAME_NAME.to_string(),
+					t!("tron-description").to_string(),
+					"0.0.1".to_string(),
+					get_crate_authors(),
+					DifficultyRating::Hard,
+				)
+				.with_preview(Some(preview())),
+			)
+			.unwrap(),
+			Some(TronSetupScreen::default().into()),
+		)
+	}
+
+	fn event(&mut self, _event: &Event) -> anyhow::Result<()> {
+		Ok(())
+	}
+
+	fn help_page(&self) -> Option<&'static str> {
+		Some(HELP_PAGE)
+	}
+
+	fn demo_screen(&self) -> Option<Screens> {
+		Some(TronDemoScreen::default().into())
+	}
+}
@@ -0,0 +1,319 @@
+//! The grid of a game of 2048: tiles slid all at once in a direction, tiles of
+//! the same value merging as they meet.
+
+use std::cmp::Reverse;
+
+use rand::Rng;
+
+use crate::services::rng;
+
+/// Value of the tile that wins the game.
+pub const WINNING_TILE: u32 = 2048;
+
+/// Chance for a new tile to be a 4 rather than a 2.
+const FOUR_CHANCE: f64 = 0.1;
+
+/// A direction tiles are slid in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Slide {
+	/// Towards the top row.
+	Up,
+
+	/// Towards the bottom row.
+	Down,
+
+	/// Towards the left column.
+	Left,
+
+	/// Towards the right column.
+	Right,
+}
+
+/// A grid of 2048. Empty cells hold 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct Grid {
+	/// Width and height of the grid, in cells.
+	size: usize,
+
+	/// Values of the cells, row by row.
+	cells: Vec<u32>,
+
+	/// Sum of the tiles merged so far.
+	score: i64,
+}
+
+impl Grid {
+	/// Creates a grid of a size with two tiles placed at random with the
+	/// [shared generator](rng).
+	pub fn new(size: usize) -> Self {
+		let mut grid = Self::with_cells(size, vec![0; size * size]);
+		rng::with_rng(|rng| {
+			grid.spawn(rng);
+			grid.spawn(rng);
+		});
+		grid
+	}
+
+	/// Creates a grid with cells of fixed values, row by row.
+	///
+	/// # Panics
+	///
+	/// This function panics when there aren't `size * size` cells.
+	pub fn with_cells(size: usize, cells: Vec<u32>) -> Self {
+		assert_eq!(
+			cells.len(),
+			size * size,
+			"a grid of {size} needs {} cells",
+			size * size
+		);
+		Self {
+			size,
+			cells,
+			score: 0,
+		}
+	}
+
+	/// Returns the width and height of the grid, in cells.
+	#[must_use]
+	pub fn size(&self) -> usize {
+		self.size
+	}
+
+	/// Returns the value of the cell at a position, 0 if it's empty.
+	#[must_use]
+	pub fn get(&self, x: usize, y: usize) -> u32 {
+		self.cells[y * self.size + x]
+	}
+
+	/// Returns the sum of the tiles merged so far.
+	#[must_use]
+	pub fn score(&self) -> i64 {
+		self.score
+	}
+
+	/// Places a tile of a value at a position, or empties it with 0.
+	#[cfg(feature = "dev-console")]
+	pub fn set(&mut self, x: usize, y: usize, value: u32) {
+		self.cells[y * self.size + x] = value;
+	}
+
+	/// Adds points to the score.
+	#[cfg(feature = "dev-console")]
+	pub fn add_score(&mut self, points: i64) {
+		self.score += points;
+	}
+
+	/// Returns whether a tile reached the [winning value](WINNING_TILE).
+	#[must_use]
+	pub fn is_won(&self) -> bool {
+		self.cells.iter().any(|&cell| cell >= WINNING_TILE)
+	}
+
+	/// Returns whether no slide would move a tile anymore.
+	#[must_use]
+	pub fn is_over(&self) -> bool {
+		[Slide::Up, Slide::Down, Slide::Left, Slide::Right]
+			.into_iter()
+			.all(|slide| !self.clone().shift(slide))
+	}
+
+	/// Returns the slide leaving the most empty cells, merging the most points
+	/// on a tie, or [`None`] if no slide moves a tile. Of slides as good as
+	/// each other, the first of up, down, left and right is returned.
+	#[must_use]
+	pub fn best_slide(&self) -> Option<Slide> {
+		[Slide::Up, Slide::Down, Slide::Left, Slide::Right]
+			.into_iter()
+			.filter_map(|slide| {
+				let mut grid = self.clone();
+				let empty = grid
+					.shift(slide)
+					.then(|| grid.cells.iter().filter(|&&cell| cell == 0).count())?;
+				Some(((empty, grid.score), slide))
+			})
+			.min_by_key(|&(rank, _)| Reverse(rank))
+			.map(|(_, slide)| slide)
+	}
+
+	/// Returns the indices of the cells of each line slid along, each from the
+	/// edge tiles are slid towards.
+	fn lines(&self, slide: Slide) -> Vec<Vec<usize>> {
+		let size = self.size;
+		(0..size)
+			.map(|line| {
+				(0..size)
+					.map(|step| match slide {
+						Slide::Up => step * size + line,
+						Slide::Down => (size - 1 - step) * size + line,
+						Slide::Left => line * size + step,
+						Slide::Right => line * size + size - 1 - step,
+					})
+					.collect()
+			})
+			.collect()
+	}
+
+	/// Slides the tiles without placing a new one, returning whether any
+	/// moved.
+	pub fn shift(&mut self, slide: Slide) -> bool {
+		let mut moved = false;
+		for line in self.lines(slide) {
+			let tiles: Vec<_> =
+				line.iter().map(|&index| self.cells[index]).filter(|&cell| cell > 0).collect();
+			let mut merged = Vec::with_capacity(line.len());
+			let mut tiles = tiles.into_iter().peekable();
+			while let Some(tile) = tiles.next() {
+				if tiles.next_if_eq(&tile).is_some() {
+					merged.push(tile * 2);
+					self.score += i64::from(tile * 2);
+				} else {
+					merged.push(tile);
+				}
+			}
+			merged.resize(line.len(), 0);
+			for (&index, value) in line.iter().zip(merged) {
+				moved |= self.cells[index] != value;
+				self.cells[index] = value;
+			}
+		}
+		moved
+	}
+
+	/// Slides the tiles, placing a new one with the [shared generator](rng) if
+	/// any moved. Returns whether any did.
+	pub fn slide(&mut self, slide: Slide) -> bool {
+		let moved = self.shift(slide);
+		if moved {
+			rng::with_rng(|rng| self.spawn(rng));
+		}
+		moved
+	}
+
+	/// Places a 2, or sometimes a 4, in an empty cell picked at random.
+	fn spawn(&mut self, rng: &mut impl Rng) {
+		let empty: Vec<_> = (0..self.cells.len()).filter(|&index| self.cells[index] == 0).collect();
+		if empty.is_empty() {
+			return;
+		}
+		let index = empty[rng.gen_range(0..empty.len())];
+		self.cells[index] = if rng.gen_bool(FOUR_CHANCE) { 4 } else { 2 };
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use proptest::prelude::*;
+
+	use super::{
+		Grid,
+		Slide,
+	};
+
+	/// Generates a grid of 2 to 8 cells a side, with tiles up to 128.
+	fn grid() -> impl Strategy<Value = Grid> {
+		(2..=8_usize).prop_flat_map(|size| {
+			prop::collection::vec(0..8_u32, size * size).prop_map(move |powers| {
+				let cells = powers.into_iter().map(|power| (1 << power) & !1).collect();
+				Grid::with_cells(size, cells)
+			})
+		})
+	}
+
+	/// Generates a slide.
+	fn slide() -> impl Strategy<Value = Slide> {
+		prop_oneof![
+			Just(Slide::Up),
+			Just(Slide::Down),
+			Just(Slide::Left),
+			Just(Slide::Right),
+		]
+	}
+
+	/// Returns the tiles of each line slid along, from the edge tiles are slid
+	/// towards.
+	fn line_tiles(grid: &Grid, slide: Slide) -> Vec<Vec<u32>> {
+		grid.lines(slide)
+			.into_iter()
+			.map(|line| {
+				line.into_iter().map(|index| grid.cells[index]).filter(|&cell| cell > 0).collect()
+			})
+			.collect()
+	}
+
+	#[test]
+	fn tiles_merge_once_per_slide() {
+		#[rustfmt::skip]
+		let mut grid = Grid::with_cells(4, vec![
+			2, 2, 2, 2,
+			4, 0, 4, 8,
+			0, 0, 0, 0,
+			2, 0, 0, 2,
+		]);
+		assert!(grid.shift(Slide::Left));
+		#[rustfmt::skip]
+		assert_eq!(grid, Grid { score: 4 + 4 + 8 + 4, ..Grid::with_cells(4, vec![
+			4, 4, 0, 0,
+			8, 8, 0, 0,
+			0, 0, 0, 0,
+			4, 0, 0, 0,
+		]) });
+		assert!(grid.shift(Slide::Down));
+		assert_eq!([grid.get(0, 1), grid.get(0, 2), grid.get(0, 3)], [4, 8, 4]);
+		assert_eq!([grid.get(1, 2), grid.get(1, 3)], [4, 8]);
+		assert!(!grid.is_over());
+		assert!(Grid::with_cells(2, vec![2, 4, 4, 2]).is_over());
+	}
+
+	#[test]
+	fn best_slides_merge_the_most_tiles() {
+		#[rustfmt::skip]
+		let grid = Grid::with_cells(3, vec![
+			2, 2, 4,
+			8, 4, 8,
+			4, 8, 16,
+		]);
+		assert_eq!(grid.best_slide(), Some(Slide::Left));
+		#[rustfmt::skip]
+		let grid = Grid::with_cells(3, vec![
+			2, 4, 0,
+			2, 8, 0,
+			4, 2, 2,
+		]);
+		assert_eq!(grid.best_slide(), Some(Slide::Up));
+		assert_eq!(Grid::with_cells(2, vec![2, 4, 4, 2]).best_slide(), None);
+	}
+
+	proptest! {
+		#[test]
+		fn slides_keep_the_sum_of_tiles(mut grid in grid(), slide in slide()) {
+			let sum: u32 = grid.cells.iter().sum();
+			grid.shift(slide);
+			prop_assert_eq!(grid.cells.iter().sum::<u32>(), sum);
+		}
+
+		#[test]
+		fn tiles_merge_at_most_once(mut grid in grid(), slide in slide()) {
+			let before = line_tiles(&grid, slide);
+			let score = grid.score;
+			grid.shift(slide);
+			let mut merged_points = 0;
+			for (before, after) in before.into_iter().zip(line_tiles(&grid, slide)) {
+				// Each tile after the slide is either a tile from before it, or
+				// two equal tiles next to each other that merged, in order.
+				let mut before = before.into_iter().peekable();
+				for tile in after {
+					let first = before.next();
+					if first == Some(tile) {
+						continue;
+					}
+					prop_assert_eq!(first.map(|first| first * 2), Some(tile));
+					prop_assert_eq!(before.next(), first);
+					merged_points += i64::from(tile);
+				}
+				prop_assert!(before.next().is_none());
+			}
+			prop_assert_eq!(grid.score - score, merged_points);
+		}
+	}
+}
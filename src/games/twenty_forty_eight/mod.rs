@@ -0,0 +1,92 @@
+//! Implementation for the game 2048, where tiles of the same value merge as
+//! they're slid across a grid, until one reaches 2048.
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+use crate::{
+	core::events::Event,
+	games::{
+		difficulty::DifficultyRating,
+		modes::GameMode,
+		preview::GamePreview,
+		registry::{
+			GameId,
+			GameRegistry,
+		},
+		Game,
+		GameMetadata,
+		GameState,
+		GameStaticInfo,
+	},
+	t,
+	ui::{
+		screens::Screens,
+		util::get_crate_authors,
+		TwentyFortyEightSetupScreen,
+	},
+};
+
+pub mod grid;
+pub mod setup;
+
+/// 2048's [ID](GameId).
+pub const GAME_ID: &str = "2048";
+
+/// 2048's manual.
+pub const HELP_PAGE: &str = include_str!("../../../assets/help/2048.md");
+
+/// 2048's preview, a grid halfway to the winning tile.
+const PREVIEW: &str = "\
+   2    ·    ·    4
+   ·    8    2   16
+   4   32   64    2
+ 128  256  512    8";
+
+/// Registers 2048 in a [registry](GameRegistry).
+pub fn register(registry: &mut GameRegistry) {
+	registry.register(TwentyFortyEight.into());
+}
+
+/// The game [2048](https://en.wikipedia.org/wiki/2048_(video_game)).
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct TwentyFortyEight;
+
+impl Game for TwentyFortyEight {
+	fn data(&self) -> GameState {
+		GameState::new(
+			GameMetadata::new(
+				GameStaticInfo::new(
+					self.clone().into(),
+					GameId::from(GAME_ID),
+					"2048".to_string(),
+					t!("twenty-forty-eight-description").to_string(),
+					"0.0.1".to_string(),
+					get_crate_authors(),
+					DifficultyRating::Easy,
+				)
+				.with_preview(Some(GamePreview::still(PREVIEW))),
+			)
+			.unwrap(),
+			Some(TwentyFortyEightSetupScreen::default().into()),
+		)
+	}
+
+	fn event(&mut self, _event: &Event) -> anyhow::Result<()> {
+		Ok(())
+	}
+
+	fn help_page(&self) -> Option<&'static str> {
+		Some(HELP_PAGE)
+	}
+
+	fn modes(&self) -> Vec<GameMode> {
+		vec![GameMode::Standard, GameMode::Hardcore]
+	}
+
+	fn mode_screen(&self, mode: GameMode) -> Option<Screens> {
+		Some(TwentyFortyEightSetupScreen::new(mode).into())
+	}
+}
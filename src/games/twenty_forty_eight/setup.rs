@@ -0,0 +1,66 @@
+//! The answers to 2048's setup screen: the size of the grid.
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+use strum::{
+	Display,
+	EnumIter,
+};
+
+use crate::{
+	t,
+	ui::components::games::setup::SetupAnswers,
+};
+
+/// Smallest width and height of a grid, in cells.
+pub const MIN_SIZE: usize = 3;
+
+/// Largest width and height of a grid, in cells.
+pub const MAX_SIZE: usize = 8;
+
+/// A question of the setup screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, EnumIter)]
+pub enum GridField {
+	/// The width and height of the grid.
+	Size,
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[must_use]
+pub struct GridSetup {
+	/// Width and height of the grid, in cells.
+	pub size: usize,
+}
+
+impl Default for GridSetup {
+	fn default() -> Self {
+		Self { size: 4 }
+	}
+}
+
+impl SetupAnswers for GridSetup {
+	type Question = GridField;
+
+	fn label(question: GridField) -> &'static str {
+		match question {
+			GridField::Size => t!("twenty-forty-eight-size"),
+		}
+	}
+
+	fn answer(&self, question: GridField) -> usize {
+		match question {
+			GridField::Size => self.size,
+		}
+	}
+
+	fn adjust(&mut self, question: GridField, step: isize) {
+		match question {
+			GridField::Size => {
+				self.size = self.size.saturating_add_signed(step).clamp(MIN_SIZE, MAX_SIZE);
+			},
+		}
+	}
+}
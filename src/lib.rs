@@ -0,0 +1,51 @@
+//! # Terminal Arcade
+//!
+//! Terminal Arcade is an arcade machine
+//! replica-concept-reinvention-do-it-myself thingymajig of the arcade
+//! machine! That's really it.
+//!
+//! This crate contains an interface for extending and building more games, as
+//! well as a (hopefully) lot other pre-built games as well. The
+//! `terminal-arcade` binary is a thin wrapper around it, and other crates can
+//! use it to embed its games or to build another frontend for them.
+//!
+//! ## Where to start
+//!
+//! - [Games](games::Game) are listed in the [registry](games::registry), and
+//!   each opens a [screen](ui::Screen) of its own: the screen draws the game
+//!   with [ratatui] and handles the player's input as [actions](core::actions).
+//! - The [handler](core::Handler) runs screens in the terminal, while the
+//!   [harness](core::harness) runs them against any ratatui backend, feeding
+//!   them events from elsewhere, which is the place to start for another
+//!   frontend.
+//! - Pieces shared between games:
+//!   - [Seeded randomness](services::rng), so that a game can be played again
+//!     from its seed.
+//!   - [Turns](games::hotseat) between players on the same keyboard.
+//!   - [Computer players](games::demo) watched in demos.
+//!   - [Replays](core::event_history) of the events played.
+//!   - [Scoring](games::GameDynamicInfo::record_result), with best scores and
+//!     times kept in the save data.
+//!   - [Widgets](ui::widgets) and [components](ui::components), like lists,
+//!     game boards and results banners.
+//!
+//! Terminal Arcade keeps its files in the directories set up with
+//! [`core::files::init`], and its text is translated once
+//! [`core::i18n::init`] picked the language: both should be called before
+//! anything else.
+
+#![deny(unused_must_use, unused_imports, rust_2018_idioms)]
+#![warn(clippy::all, clippy::pedantic, missing_docs)]
+#![allow(
+	clippy::missing_errors_doc,
+	clippy::missing_panics_doc,
+	clippy::module_name_repetitions,
+	clippy::cast_possible_truncation,
+	clippy::cast_possible_wrap,
+	unused_imports
+)]
+
+pub mod core;
+pub mod games;
+pub mod services;
+pub mod ui;
@@ -1,40 +1,183 @@
-//! # Terminal Arcade
-//!
-//! Terminal Arcade is an arcade machine
-//! replica-concept-reinvention-do-it-myself thingymajig of the arcade
-//! machine! That's really it.
-//!
-//! This crate contains an interface for extending and building more games, as
-//! well as a (hopefully) lot other pre-built games as well.
-
-#![deny(unused_must_use, unused_imports, rust_2018_idioms)]
-#![warn(clippy::all, clippy::pedantic, missing_docs)]
-#![allow(
-	clippy::missing_errors_doc,
-	clippy::missing_panics_doc,
-	clippy::module_name_repetitions,
-	clippy::cast_possible_truncation,
-	clippy::cast_possible_wrap,
-	unused_imports
-)]
-
-use std::{
-	panic::{
-		set_hook,
-		take_hook,
+//! The `terminal-arcade` binary: parses the command line, then runs the
+//! arcade or the command asked for with the [library](terminal_arcade).
+
+#![deny(unused_must_use, rust_2018_idioms)]
+#![warn(clippy::all, clippy::pedantic)]
+
+#[cfg(feature = "telnet")]
+use std::net::SocketAddr;
+use std::process::ExitCode;
+
+use clap::Parser;
+use terminal_arcade::{
+	core::{
+		self,
+		cli::{
+			Cli,
+			Command,
+		},
+		config::Config,
+		error::{
+			AppError,
+			ErrorReport,
+		},
+		export::ExportFormat,
+		files::AppFiles,
+		script::Script,
+		Handler,
 	},
-	time::Duration,
+	services,
+	t,
 };
 
-use crate::core::Handler;
+fn main() -> ExitCode {
+	match run(Cli::parse()) {
+		Ok(code) => code,
+		Err(error) => {
+			let report = ErrorReport::new(&error);
+			// Printed below even if it couldn't be logged.
+			let _ = core::error::log(&report);
+			eprintln!("{}", report.to_text());
+			ExitCode::FAILURE
+		},
+	}
+}
 
-pub mod core;
-pub mod games;
-pub mod ui;
+/// Speaks the language of the settings. Missing settings are the defaults,
+/// but broken ones are [reported](core::error::report_later) once, before
+/// falling back on the defaults.
+fn init_language() {
+	let config = Config::load();
+	core::i18n::init(config.as_ref().ok().and_then(|config| config.language.as_deref()));
+	if let Err(error) = &config {
+		if !AppError::is_missing_file(error) {
+			core::error::report_later(error);
+		}
+	}
+}
 
-fn main() -> anyhow::Result<()> {
+/// Serves the arcade over telnet, or raw TCP, until the process ends.
+#[cfg(feature = "telnet")]
+fn serve(address: SocketAddr, raw: bool, max_connections: usize) -> anyhow::Result<ExitCode> {
+	let server = services::telnet::TelnetServer::bind(address, raw, max_connections)?;
+	let address = server.local_addr()?.to_string();
+	println!("{}", t!("serve-listening", address = address));
+	server.serve();
+	Ok(ExitCode::SUCCESS)
+}
+
+/// Runs the arcade, or the command asked for, returning how the process
+/// should exit.
+fn run(cli: Cli) -> anyhow::Result<ExitCode> {
+	// Generated when packaging, without touching the player's files.
+	match cli.command {
+		Some(Command::Completions { shell }) => {
+			core::cli::write_completions(shell, &mut std::io::stdout())?;
+			return Ok(ExitCode::SUCCESS);
+		},
+		Some(Command::Manpage) => {
+			core::cli::write_manpage(&mut std::io::stdout())?;
+			return Ok(ExitCode::SUCCESS);
+		},
+		_ => {},
+	}
 	let _ = color_eyre::install();
-	Handler::default().startup()?;
-	println!("See you next time! 👋");
-	Ok(())
+	core::files::init(AppFiles::from_cli(&cli));
+	init_language();
+	match cli.command {
+		Some(Command::Export { path, format }) => {
+			let format = format
+				.or_else(|| path.as_deref().and_then(ExportFormat::from_path))
+				.unwrap_or(ExportFormat::Csv);
+			let path = path.unwrap_or_else(|| core::export::default_export_path(format));
+			core::export::export(&path, format)?;
+			println!("{}", t!("export-done", path = path.display().to_string()));
+			return Ok(ExitCode::SUCCESS);
+		},
+		Some(Command::ExportScores { path }) => {
+			let path = path.unwrap_or_else(core::score_file::default_score_file_path);
+			core::score_file::export(&path)?;
+			println!(
+				"{}",
+				t!("scores-exported", path = path.display().to_string())
+			);
+			return Ok(ExitCode::SUCCESS);
+		},
+		Some(Command::ImportScores { path }) => {
+			let (scores, improved) = core::score_file::import(&path)?;
+			println!(
+				"{}",
+				t!(
+					"scores-imported",
+					player = scores.player,
+					games = scores.games.len(),
+					improved = improved
+				)
+			);
+			return Ok(ExitCode::SUCCESS);
+		},
+		#[cfg(feature = "telnet")]
+		Some(Command::Serve {
+			address,
+			port,
+			raw,
+			max_connections,
+		}) => {
+			return serve((address, port).into(), raw, max_connections);
+		},
+		#[cfg(feature = "telnet")]
+		Some(Command::ServeConnection { raw }) => {
+			services::telnet::play_stdio(raw)?;
+			return Ok(ExitCode::SUCCESS);
+		},
+		Some(Command::Doctor) => {
+			let report = core::doctor::Report::run(true);
+			println!("{}", report.to_text());
+			if report.failed() {
+				eprintln!("{}", t!("doctor-failed"));
+				return Ok(ExitCode::FAILURE);
+			}
+			return Ok(ExitCode::SUCCESS);
+		},
+		Some(Command::Completions { .. } | Command::Manpage) | None => {},
+	}
+	services::sync::sync_now();
+	let _ = services::badges::init();
+	services::backup::spawn_scheduler();
+	if let Some(seed) = cli.seed {
+		services::rng::seed(seed);
+	}
+	services::stats::start_session();
+	let mut handler = Handler::default();
+	#[cfg(unix)]
+	if let Some(path) = &cli.ipc_socket {
+		handler = handler.with_ipc_server(services::ipc::IpcServer::bind(path)?);
+	}
+	if let Some(path) = &cli.script {
+		handler = handler.with_script(Script::load(path)?);
+	}
+	if cli.dev_tools {
+		handler = handler.with_dev_tools();
+	}
+	handler.startup()?;
+	say_goodbye();
+	Ok(ExitCode::SUCCESS)
+}
+
+/// Syncs the save data once the arcade is closed, then says goodbye with a
+/// summary of the session if set to.
+fn say_goodbye() {
+	services::sync::sync_now();
+	for conflict in services::sync::take_conflicts() {
+		println!("{conflict}");
+	}
+	println!("{}", t!("goodbye"));
+	if Config::load_or_default().exit_summary {
+		if let Some(summary) = services::stats::SessionSummary::compute() {
+			println!();
+			for line in summary.lines() {
+				println!("{line}");
+			}
+		}
+	}
 }
@@ -0,0 +1,133 @@
+//! Autosaves of games in progress, so that they can be resumed after leaving
+//! them or after a crash.
+//!
+//! Screens of games that support it save their game with [`save`] in
+//! [`Screen::autosave`](crate::ui::Screen::autosave), which is called on every
+//! open screen every [`Config::autosave_interval_seconds`], and resume it with
+//! [`resume`]. Autosaves are kept in the save directory's [`AUTOSAVE_DIR`]
+//! folder, as [save files](crate::core::save_file) that are
+//! [listed](crate::core::save_file::list) with the rest of the save data, so
+//! that they're encrypted and decrypted along with it.
+
+use std::{
+	fs,
+	path::PathBuf,
+	sync::Mutex,
+	time::Duration,
+};
+
+use serde::{
+	de::DeserializeOwned,
+	Serialize,
+};
+
+use crate::{
+	core::{
+		config::Config,
+		get_save_dir,
+		save_file,
+		time::Instant,
+	},
+	games::modes::GameMode,
+	t,
+};
+
+/// Name of the folder of the save directory that autosaves are kept in.
+pub const AUTOSAVE_DIR: &str = "autosaves";
+
+/// How long the autosave indicator is shown after a save.
+const INDICATOR_DURATION: Duration = Duration::from_secs(2);
+
+/// When the last autosave happened, and whether it succeeded.
+static LAST_AUTOSAVE: Mutex<Option<(Instant, bool)>> = Mutex::new(None);
+
+/// Returns the name a game in progress is autosaved under, which differs
+/// between modes so that each has its own autosave.
+#[must_use]
+pub fn autosave_name(game: &str, mode: GameMode) -> String {
+	match mode {
+		GameMode::Standard => game.to_string(),
+		mode => format!("{game}.{}", mode.id()),
+	}
+}
+
+/// Gets the path of a game's autosave.
+#[must_use]
+pub fn autosave_path(game: &str) -> PathBuf {
+	get_save_dir().join(AUTOSAVE_DIR).join(format!("{}.json", game.to_lowercase()))
+}
+
+/// Saves a game in progress.
+pub fn save<T: Serialize>(game: &str, progress: &T) -> anyhow::Result<()> {
+	let path = autosave_path(game);
+	let result = serde_json::to_string(progress).map_err(anyhow::Error::from).and_then(|json| {
+		fs::create_dir_all(get_save_dir().join(AUTOSAVE_DIR))?;
+		save_file::write(&path, &json)
+	});
+	if let Ok(mut last_autosave) = LAST_AUTOSAVE.lock() {
+		*last_autosave = Some((Instant::now(), result.is_ok()));
+	}
+	result
+}
+
+/// Returns a game's autosave, if there's one and resuming games is turned on
+/// in the [configuration](Config::resume_games).
+#[must_use]
+pub fn resume<T: DeserializeOwned>(game: &str) -> Option<T> {
+	if !Config::load_or_default().resume_games {
+		return None;
+	}
+	let json = save_file::read(&autosave_path(game)).ok()?;
+	serde_json::from_str(&json).ok()
+}
+
+/// Removes a game's autosave, once the game is over or started over.
+pub fn discard(game: &str) {
+	let _ = fs::remove_file(autosave_path(game));
+}
+
+/// Returns the text shown in the screen's border for a moment after an
+/// autosave, if one just happened.
+#[must_use]
+pub fn indicator() -> Option<&'static str> {
+	let (time, succeeded) = (*LAST_AUTOSAVE.lock().ok()?)?;
+	(time.elapsed() < INDICATOR_DURATION).then(|| {
+		if succeeded {
+			t!("autosave-saved")
+		} else {
+			t!("autosave-failed")
+		}
+	})
+}
+
+/// Keeps track of when games in progress should be autosaved.
+#[derive(Debug)]
+pub struct AutosaveTimer {
+	/// How often games are autosaved, if they are.
+	interval: Option<Duration>,
+
+	/// When games were last autosaved.
+	last_autosave: Instant,
+}
+
+impl Default for AutosaveTimer {
+	fn default() -> Self {
+		let seconds = Config::load_or_default().autosave_interval_seconds;
+		Self {
+			interval: (seconds > 0).then(|| Duration::from_secs(seconds)),
+			last_autosave: Instant::now(),
+		}
+	}
+}
+
+impl AutosaveTimer {
+	/// Returns whether games should be autosaved now, restarting the timer if
+	/// so.
+	pub fn is_due(&mut self) -> bool {
+		let due = self.interval.is_some_and(|interval| self.last_autosave.elapsed() >= interval);
+		if due {
+			self.last_autosave = Instant::now();
+		}
+		due
+	}
+}
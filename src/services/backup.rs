@@ -0,0 +1,364 @@
+//! Backups of the [save directory](get_save_dir), kept as timestamped tar
+//! archives in its [`BACKUP_DIR`] folder. The [sync](crate::services::sync)
+//! repository's and [bug reports](crate::core::bug_report)' folders are left
+//! out.
+//!
+//! Backups are taken on a background thread every
+//! [`Config::backup_interval_hours`], and the oldest are removed once there are
+//! more than [`Config::backups_kept`]. They can be restored from the settings.
+//! Files kept outside of the save directory, like the configuration, are
+//! [copied](back_up_file) to the same folder before being
+//! [reset](crate::services::reset), keeping as many copies of each.
+//!
+//! Archives and copies are named after the second they were made in, numbered
+//! when others were made in the same second, and never overwrite each other.
+
+use std::{
+	cmp::Reverse,
+	fs::{
+		self,
+		File,
+	},
+	io::ErrorKind,
+	path::{
+		Path,
+		PathBuf,
+	},
+	sync::atomic::{
+		AtomicUsize,
+		Ordering,
+	},
+	thread,
+	time::{
+		Duration,
+		UNIX_EPOCH,
+	},
+};
+
+use anyhow::bail;
+use chrono::{
+	DateTime,
+	Local,
+};
+use tar::{
+	Archive,
+	Builder,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::services::sync::SYNC_DIR;
+use crate::{
+	core::{
+		bug_report::BUG_REPORT_DIR,
+		config::Config,
+		get_save_dir,
+		profile::Profile,
+	},
+	games::get_unix_time_as_secs,
+	t,
+};
+
+/// Name of the folder of the save directory that backups are kept in. It's
+/// left out of the backups themselves.
+pub const BACKUP_DIR: &str = "backups";
+
+/// Folders of the save directory left out of backups, and left alone when
+/// restoring them.
+#[cfg(not(target_arch = "wasm32"))]
+const SKIPPED_DIRS: [&str; 3] = [BACKUP_DIR, BUG_REPORT_DIR, SYNC_DIR];
+
+/// Folders of the save directory left out of backups, and left alone when
+/// restoring them. Browsers have no sync repository.
+#[cfg(target_arch = "wasm32")]
+const SKIPPED_DIRS: [&str; 2] = [BACKUP_DIR, BUG_REPORT_DIR];
+
+/// Prefix of backups' file names, followed by their creation time.
+const FILE_PREFIX: &str = "backup-";
+
+/// Extension of backups' file names.
+const FILE_EXTENSION: &str = ".tar";
+
+/// Extension of the files backups and copies are written to before being
+/// moved into place.
+const PARTIAL_EXTENSION: &str = ".part";
+
+/// How long the scheduler waits at least between two checks, so that it
+/// doesn't spin when backups keep failing.
+#[cfg(not(target_arch = "wasm32"))]
+const MIN_CHECK_INTERVAL: Duration = Duration::from_mins(1);
+
+/// Gets the directory backups are kept in.
+#[must_use]
+pub fn backup_dir() -> PathBuf {
+	get_save_dir().join(BACKUP_DIR)
+}
+
+/// Parses the name of a file in the backups' folder made of a prefix, the UNIX
+/// timestamp it was made at, a number telling apart the files made in the same
+/// second if there were others, and a suffix. Returns the timestamp and the
+/// number, which is 1 for the first file made in a second.
+fn parse_name(name: &str, prefix: &str, suffix: &str) -> Option<(u64, u32)> {
+	let stamp = name.strip_prefix(prefix)?.strip_suffix(suffix)?;
+	let (created, number) = stamp.split_once('-').unwrap_or((stamp, "1"));
+	Some((created.parse().ok()?, number.parse().ok()?))
+}
+
+/// Lists the files of the backups' folder [named](parse_name) with a prefix
+/// and a suffix, with the UNIX timestamp they were made at and their number,
+/// newest first.
+fn list_named(prefix: &str, suffix: &str) -> anyhow::Result<Vec<(PathBuf, u64, u32)>> {
+	let dir = backup_dir();
+	if !dir.exists() {
+		return Ok(Vec::new());
+	}
+	let mut files = Vec::new();
+	for entry in fs::read_dir(dir)? {
+		let path = entry?.path();
+		let name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+		if let Some((created, number)) = parse_name(name, prefix, suffix) {
+			files.push((path, created, number));
+		}
+	}
+	files.sort_by_key(|&(_, created, number)| Reverse((created, number)));
+	Ok(files)
+}
+
+/// Returns a path in the backups' folder to write a file to before
+/// [moving it in](move_in), unique to this write.
+fn partial_path(prefix: &str) -> PathBuf {
+	static WRITES: AtomicUsize = AtomicUsize::new(0);
+	backup_dir().join(format!(
+		"{prefix}{}-{}{PARTIAL_EXTENSION}",
+		std::process::id(),
+		WRITES.fetch_add(1, Ordering::Relaxed)
+	))
+}
+
+/// Moves a file into the backups' folder, [named](parse_name) with a prefix,
+/// the current time and a suffix, returning its path and the time. Files
+/// already there are never overwritten: the file is numbered after the others
+/// made in the same second instead, so that it's listed as the newest.
+fn move_in(file: &Path, prefix: &str, suffix: &str) -> anyhow::Result<(PathBuf, u64)> {
+	let created = get_unix_time_as_secs();
+	let mut number = list_named(prefix, suffix)?
+		.into_iter()
+		.filter(|&(_, other_created, _)| other_created == created)
+		.map(|(_, _, number)| number + 1)
+		.max()
+		.unwrap_or(1);
+	loop {
+		let name = if number == 1 {
+			format!("{prefix}{created}{suffix}")
+		} else {
+			format!("{prefix}{created}-{number}{suffix}")
+		};
+		let path = backup_dir().join(name);
+		// Unlike renaming, linking fails rather than replacing a file.
+		match fs::hard_link(file, &path) {
+			Ok(()) => {
+				fs::remove_file(file)?;
+				return Ok((path, created));
+			},
+			Err(error) if error.kind() == ErrorKind::AlreadyExists => number += 1,
+			Err(error) => {
+				let _ = fs::remove_file(file);
+				return Err(error.into());
+			},
+		}
+	}
+}
+
+/// A backup of the save directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct Backup {
+	/// Path of the backup's archive.
+	pub path: PathBuf,
+
+	/// UNIX timestamp of when the backup was taken.
+	pub created: u64,
+}
+
+impl Backup {
+	/// Lists the backups, newest first.
+	pub fn list() -> anyhow::Result<Vec<Self>> {
+		Ok(list_named(FILE_PREFIX, FILE_EXTENSION)?
+			.into_iter()
+			.map(|(path, created, _)| Self { path, created })
+			.collect())
+	}
+
+	/// Backs up the save directory now.
+	pub fn create() -> anyhow::Result<Self> {
+		let save_dir = get_save_dir();
+		if !save_dir.is_dir() {
+			bail!(t!("backups-nothing"));
+		}
+		fs::create_dir_all(backup_dir())?;
+		// Written under another name first, so that an interrupted backup is
+		// never listed.
+		let partial_path = partial_path(FILE_PREFIX);
+		let mut builder = Builder::new(File::create(&partial_path)?);
+		for entry in fs::read_dir(&save_dir)? {
+			let entry = entry?;
+			if SKIPPED_DIRS.iter().any(|dir| entry.file_name() == *dir) {
+				continue;
+			}
+			if entry.file_type()?.is_dir() {
+				builder.append_dir_all(entry.file_name(), entry.path())?;
+			} else {
+				builder.append_path_with_name(entry.path(), entry.file_name())?;
+			}
+		}
+		builder.into_inner()?.sync_all()?;
+		let (path, created) = move_in(&partial_path, FILE_PREFIX, FILE_EXTENSION)?;
+		Ok(Self { path, created })
+	}
+
+	/// Replaces the save data with the backup's. The current save data is
+	/// backed up first, so that restoring can be undone.
+	pub fn restore(&self) -> anyhow::Result<()> {
+		let _ = Self::create()?;
+		clear_save_dir()?;
+		Archive::new(File::open(&self.path)?).unpack(get_save_dir())?;
+		Ok(())
+	}
+
+	/// Returns the local date and time the backup was taken at.
+	#[must_use]
+	pub fn label(&self) -> String {
+		let datetime = DateTime::<Local>::from(UNIX_EPOCH + Duration::from_secs(self.created));
+		datetime.format("%d/%m/%Y %H:%M:%S").to_string()
+	}
+}
+
+/// Removes everything from the save directory that backups hold, leaving
+/// the backups themselves alone.
+pub fn clear_save_dir() -> anyhow::Result<()> {
+	Profile::forget();
+	let save_dir = get_save_dir();
+	if !save_dir.is_dir() {
+		return Ok(());
+	}
+	for entry in fs::read_dir(&save_dir)? {
+		let entry = entry?;
+		if SKIPPED_DIRS.iter().any(|dir| entry.file_name() == *dir) {
+			continue;
+		}
+		if entry.file_type()?.is_dir() {
+			fs::remove_dir_all(entry.path())?;
+		} else {
+			fs::remove_file(entry.path())?;
+		}
+	}
+	Ok(())
+}
+
+/// Copies a file outside of the save directory, like the configuration, to
+/// the backups' folder under its name and the current time, returning the
+/// copy's path. Files that don't exist aren't copied. The oldest copies of the
+/// file are removed once there are more than [`Config::backups_kept`], keeping
+/// the new one at least.
+pub fn back_up_file(path: &Path) -> anyhow::Result<Option<PathBuf>> {
+	if !path.is_file() {
+		return Ok(None);
+	}
+	let stem = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or("file");
+	let extension = path.extension().and_then(|extension| extension.to_str()).unwrap_or("bak");
+	let (prefix, suffix) = (format!("{stem}-"), format!(".{extension}"));
+	fs::create_dir_all(backup_dir())?;
+	let partial_path = partial_path(&prefix);
+	fs::copy(path, &partial_path)?;
+	let (copy, _) = move_in(&partial_path, &prefix, &suffix)?;
+	let kept = Config::load_or_default().backups_kept.max(1);
+	for (old_copy, ..) in list_named(&prefix, &suffix)?.into_iter().skip(kept) {
+		fs::remove_file(old_copy)?;
+	}
+	Ok(Some(copy))
+}
+
+/// Removes the oldest backups, keeping a number of them.
+pub fn prune(kept: usize) -> anyhow::Result<()> {
+	for backup in Backup::list()?.into_iter().skip(kept) {
+		fs::remove_file(backup.path)?;
+	}
+	Ok(())
+}
+
+/// Spawns a background thread taking a backup whenever the last one is older
+/// than the configured interval, if backups are enabled. Browsers can't spawn
+/// threads, and have nothing to back up.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn spawn_scheduler() {
+	let config = Config::load_or_default();
+	if config.backup_interval_hours == 0 {
+		return;
+	}
+	let interval = config.backup_interval_hours * 60 * 60;
+	thread::spawn(move || loop {
+		let last = Backup::list().ok().and_then(|backups| Some(backups.first()?.created));
+		let now = get_unix_time_as_secs();
+		let next = match last {
+			Some(last) if now < last + interval => last + interval,
+			_ => {
+				if Backup::create().is_ok() {
+					let _ = prune(config.backups_kept);
+				}
+				now + interval
+			},
+		};
+		let wait = Duration::from_secs(next.saturating_sub(get_unix_time_as_secs()));
+		thread::sleep(wait.max(MIN_CHECK_INTERVAL));
+	});
+}
+
+#[cfg(test)]
+mod tests {
+	use std::fs;
+
+	use super::{
+		back_up_file,
+		backup_dir,
+		Backup,
+	};
+	use crate::core::{
+		config::Config,
+		files,
+		get_save_dir,
+	};
+
+	#[test]
+	fn backups_made_in_the_same_second_are_kept_apart() {
+		files::isolate_test_files();
+		let file = get_save_dir().join("tron.meta.toml");
+		fs::create_dir_all(get_save_dir()).unwrap();
+		fs::write(&file, "play_count = 1").unwrap();
+		let backup = Backup::create().unwrap();
+
+		fs::write(&file, "play_count = 2").unwrap();
+		backup.restore().unwrap();
+		assert_eq!(fs::read_to_string(&file).unwrap(), "play_count = 1");
+		let backups = Backup::list().unwrap();
+		assert_eq!(backups.len(), 2);
+		assert_eq!(backups[1], backup);
+	}
+
+	#[test]
+	fn copies_of_files_are_pruned() {
+		files::isolate_test_files();
+		let kept = Config::default().backups_kept;
+		let file = get_save_dir().join("settings.toml");
+		fs::create_dir_all(get_save_dir()).unwrap();
+		for index in 0..kept + 2 {
+			fs::write(&file, index.to_string()).unwrap();
+			back_up_file(&file).unwrap();
+		}
+		let mut copies: Vec<_> = fs::read_dir(backup_dir())
+			.unwrap()
+			.map(|entry| fs::read_to_string(entry.unwrap().path()).unwrap())
+			.collect();
+		copies.sort();
+		let newest: Vec<_> = (2..kept + 2).map(|index| index.to_string()).collect();
+		assert_eq!(copies, newest);
+	}
+}
@@ -0,0 +1,120 @@
+//! Badges on the game selection screen's entries, for games that are new to the
+//! player and games that changed since the last run.
+//!
+//! The version of every game is kept in the save directory's
+//! [`SEEN_GAMES_FILE`] when Terminal Arcade starts, and compared against the
+//! next time with [`init`]. The first run has nothing to compare against, so
+//! no game counts as added or updated then.
+
+use std::{
+	collections::BTreeMap,
+	sync::OnceLock,
+};
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+use crate::{
+	core::{
+		get_save_dir,
+		save_file,
+	},
+	games::{
+		Game,
+		GameMetadata,
+		Games,
+	},
+	t,
+};
+
+/// Name of the file of the save directory that the games seen on the last run
+/// are kept in.
+pub const SEEN_GAMES_FILE: &str = "seen_games.toml";
+
+/// Games added or updated since the last run, by name.
+static CHANGES: OnceLock<BTreeMap<String, GameBadge>> = OnceLock::new();
+
+/// A badge on a game's entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameBadge {
+	/// The game was added since the last run, or was never played.
+	New,
+
+	/// The game's version changed since the last run.
+	Updated,
+}
+
+impl GameBadge {
+	/// Returns the badge's text.
+	#[must_use]
+	pub fn label(self) -> &'static str {
+		match self {
+			Self::New => t!("badge-new"),
+			Self::Updated => t!("badge-updated"),
+		}
+	}
+}
+
+/// Versions of the games seen on a run, by name.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct SeenGames {
+	/// The version each game was created on, by name.
+	games: BTreeMap<String, String>,
+}
+
+/// Compares the games to the ones seen on the last run, then keeps them for
+/// the next one. Only the first call does anything.
+pub fn init() -> anyhow::Result<()> {
+	if CHANGES.get().is_some() {
+		return Ok(());
+	}
+	let path = get_save_dir().join(SEEN_GAMES_FILE);
+	let last_run = save_file::read(&path)
+		.ok()
+		.and_then(|contents| toml::from_str::<SeenGames>(&contents).ok());
+	let current = SeenGames {
+		games: Games::all()
+			.into_iter()
+			.map(|game| {
+				let info = game.data().metadata.static_info;
+				(info.name, info.version_created)
+			})
+			.collect(),
+	};
+	let changes = last_run.map_or_else(BTreeMap::new, |last_run| {
+		current
+			.games
+			.iter()
+			.filter_map(|(name, version)| {
+				let badge = match last_run.games.get(name) {
+					None => GameBadge::New,
+					Some(last_version) if last_version != version => GameBadge::Updated,
+					Some(_) => return None,
+				};
+				Some((name.clone(), badge))
+			})
+			.collect()
+	});
+	let _ = CHANGES.set(changes);
+	// Locked save data can't be overwritten, and shouldn't be.
+	if !save_file::is_locked() {
+		std::fs::create_dir_all(get_save_dir())?;
+		save_file::write(&path, &toml::to_string_pretty(&current)?)?;
+	}
+	Ok(())
+}
+
+/// Returns the badge on a game's entry, if any: [new](GameBadge::New) if it was
+/// never played or added since the last run, or
+/// [updated](GameBadge::Updated) if its version changed since.
+#[must_use]
+pub fn badge(metadata: &GameMetadata) -> Option<GameBadge> {
+	let change = CHANGES.get().and_then(|changes| changes.get(&metadata.static_info.name));
+	if metadata.played() {
+		change.copied()
+	} else {
+		Some(GameBadge::New)
+	}
+}
@@ -0,0 +1,176 @@
+//! Each game's save data, as listed in the [data
+//! screen](crate::ui::screens::GameDataScreen) opened from the settings: the
+//! [files](crate::games::Game::save_files) it's kept in, how big they are and
+//! when they last changed. From there, a game's data can be deleted, its
+//! statistics reset, or its files exported to an archive in the save
+//! directory's `exports` folder.
+
+use std::{
+	fs::{
+		self,
+		File,
+	},
+	path::{
+		Path,
+		PathBuf,
+	},
+	process::{
+		Command,
+		Stdio,
+	},
+	thread,
+	time::{
+		Duration,
+		UNIX_EPOCH,
+	},
+};
+
+use chrono::{
+	DateTime,
+	Local,
+};
+use tar::Builder;
+
+use crate::{
+	core::export::export_path,
+	games::{
+		registry::GameId,
+		Game,
+		GameDynamicInfo,
+		Games,
+	},
+};
+
+/// Units file sizes are shown in, each 1024 times bigger than the last.
+const SIZE_UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+/// Formats a file size in bytes, in the biggest unit it's at least one of,
+/// down to a tenth of it.
+#[must_use]
+pub fn size_text(bytes: u64) -> String {
+	let unit_size = |unit: usize| 1_u128 << (10 * unit);
+	let unit = (1..SIZE_UNITS.len()).rev().find(|&unit| u128::from(bytes) >= unit_size(unit));
+	let Some(unit) = unit else {
+		return format!("{bytes} {}", SIZE_UNITS[0]);
+	};
+	let tenths = u128::from(bytes) * 10 / unit_size(unit);
+	format!("{}.{} {}", tenths / 10, tenths % 10, SIZE_UNITS[unit])
+}
+
+/// Opens a folder in the system's file manager, without waiting for it.
+pub fn open_folder(path: &Path) -> anyhow::Result<()> {
+	let opener = if cfg!(target_os = "windows") {
+		"explorer"
+	} else if cfg!(target_os = "macos") {
+		"open"
+	} else {
+		"xdg-open"
+	};
+	let mut child =
+		Command::new(opener).arg(path).stdout(Stdio::null()).stderr(Stdio::null()).spawn()?;
+	thread::spawn(move || child.wait());
+	Ok(())
+}
+
+/// A game's save data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct GameData {
+	/// ID of the game.
+	pub id: GameId,
+
+	/// Name of the game.
+	pub name: String,
+
+	/// The game's save files that exist.
+	pub files: Vec<PathBuf>,
+
+	/// Size of the files altogether, in bytes.
+	pub size: u64,
+
+	/// UNIX timestamp of when a file last changed, if known.
+	pub modified: Option<u64>,
+}
+
+impl GameData {
+	/// Looks up a game's save files.
+	pub fn of(game: &Games) -> Self {
+		let files: Vec<_> = game.save_files().into_iter().filter(|file| file.is_file()).collect();
+		let metadata: Vec<_> = files.iter().filter_map(|file| fs::metadata(file).ok()).collect();
+		let info = game.data().metadata.static_info;
+		Self {
+			id: info.id,
+			name: info.name,
+			size: metadata.iter().map(fs::Metadata::len).sum(),
+			modified: metadata
+				.iter()
+				.filter_map(|metadata| metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok())
+				.map(|since_epoch| since_epoch.as_secs())
+				.max(),
+			files,
+		}
+	}
+
+	/// Looks up every game's save files.
+	#[must_use]
+	pub fn list() -> Vec<Self> {
+		Games::all().iter().map(Self::of).collect()
+	}
+
+	/// Returns the local date and time a file last changed at, or a dash if
+	/// unknown.
+	#[must_use]
+	pub fn modified_text(&self) -> String {
+		self.modified.map_or_else(
+			|| "-".to_string(),
+			|modified| {
+				let datetime = DateTime::<Local>::from(UNIX_EPOCH + Duration::from_secs(modified));
+				datetime.format("%d/%m/%Y %H:%M").to_string()
+			},
+		)
+	}
+
+	/// Deletes the game's save files.
+	pub fn delete(&self) -> anyhow::Result<()> {
+		for file in &self.files {
+			fs::remove_file(file)?;
+		}
+		Ok(())
+	}
+
+	/// Resets the game's statistics, like its play count and best scores,
+	/// leaving its other save files alone.
+	pub fn reset_stats(&self) -> anyhow::Result<()> {
+		GameDynamicInfo::default().save(&self.id)
+	}
+
+	/// Exports the game's save files to a TAR archive, returning its path.
+	pub fn export(&self) -> anyhow::Result<PathBuf> {
+		let path = export_path(self.id.as_str(), "tar");
+		if let Some(parent) = path.parent() {
+			fs::create_dir_all(parent)?;
+		}
+		let mut builder = Builder::new(File::create(&path)?);
+		for file in &self.files {
+			if let Some(file_name) = file.file_name() {
+				builder.append_path_with_name(file, file_name)?;
+			}
+		}
+		builder.into_inner()?.sync_all()?;
+		Ok(path)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::size_text;
+
+	#[test]
+	fn formats_sizes_in_the_biggest_unit() {
+		assert_eq!(size_text(0), "0 B");
+		assert_eq!(size_text(1023), "1023 B");
+		assert_eq!(size_text(1536), "1.5 KiB");
+		assert_eq!(size_text(5 * 1024 * 1024), "5.0 MiB");
+		assert_eq!(size_text(u64::MAX), "17179869183.9 GiB");
+	}
+}
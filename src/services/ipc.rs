@@ -0,0 +1,172 @@
+//! A control socket for other programs, such as scripts and integration tests,
+//! to query and drive a running instance of Terminal Arcade.
+//!
+//! The [server](IpcServer) listens on a Unix socket, enabled with the
+//! `--ipc-socket <PATH>` argument. Clients send one [command](IpcCommand) per
+//! line as a JSON object, and get one [response](IpcResponse) per line back,
+//! in order. For example, with `socat`:
+//!
+//! ```text
+//! $ echo '{"command": "launch", "game": "Minesweeper"}' | socat - UNIX-CONNECT:/tmp/arcade.sock
+//! {"ok":null}
+//! ```
+//!
+//! Each client has its own thread waiting for its commands, which are handed to
+//! the [handler](crate::core::Handler) and answered between frames.
+
+use std::{
+	fs,
+	io::{
+		BufRead,
+		BufReader,
+		Write,
+	},
+	os::unix::net::{
+		UnixListener,
+		UnixStream,
+	},
+	path::{
+		Path,
+		PathBuf,
+	},
+	sync::mpsc::{
+		channel,
+		Receiver,
+		Sender,
+	},
+	thread,
+};
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+use serde_json::Value;
+
+/// A command sent by a client.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum IpcCommand {
+	/// Queries the screens open and the games' statistics.
+	State,
+
+	/// Launches a game by its name, ignoring case.
+	Launch {
+		/// Name of the game.
+		game: String,
+	},
+
+	/// Closes the active screen.
+	CloseScreen,
+
+	/// Quits Terminal Arcade.
+	Quit,
+}
+
+/// The response to a [command](IpcCommand).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IpcResponse {
+	/// The command succeeded, with data for queries.
+	Ok(Value),
+
+	/// The command failed, with the reason why.
+	Error(String),
+}
+
+/// A command waiting to be answered.
+#[derive(Debug)]
+pub struct IpcRequest {
+	/// The command sent.
+	pub command: IpcCommand,
+
+	/// Sends the response back to the client.
+	reply: Sender<IpcResponse>,
+}
+
+impl IpcRequest {
+	/// Answers the command.
+	pub fn reply(self, response: IpcResponse) {
+		let _ = self.reply.send(response);
+	}
+}
+
+/// The control socket's server. See the [module](self) documentation for more
+/// information.
+#[must_use]
+pub struct IpcServer {
+	/// Path of the socket, removed once the server is dropped.
+	path: PathBuf,
+
+	/// Commands received from every client.
+	requests: Receiver<IpcRequest>,
+}
+
+impl IpcServer {
+	/// Listens on a socket at a path, replacing any socket left there.
+	pub fn bind(path: &Path) -> anyhow::Result<Self> {
+		if path.exists() {
+			fs::remove_file(path)?;
+		}
+		let listener = UnixListener::bind(path)?;
+		let (sender, receiver) = channel();
+		thread::spawn(move || {
+			for stream in listener.incoming().flatten() {
+				let sender = sender.clone();
+				thread::spawn(move || Self::serve_client(stream, &sender));
+			}
+		});
+		Ok(Self {
+			path: path.to_path_buf(),
+			requests: receiver,
+		})
+	}
+
+	/// Reads a client's commands line by line, answering each one before
+	/// reading the next.
+	fn serve_client(stream: UnixStream, requests: &Sender<IpcRequest>) {
+		let Ok(reader) = stream.try_clone().map(BufReader::new) else {
+			return;
+		};
+		let mut writer = stream;
+		for line in reader.lines() {
+			let Ok(line) = line else {
+				break;
+			};
+			if line.trim().is_empty() {
+				continue;
+			}
+			let response = match serde_json::from_str(&line) {
+				Ok(command) => {
+					let (reply, response) = channel();
+					if requests.send(IpcRequest { command, reply }).is_err() {
+						break;
+					}
+					response.recv().unwrap_or_else(|_| {
+						IpcResponse::Error("Terminal Arcade is shutting down".to_string())
+					})
+				},
+				Err(err) => IpcResponse::Error(format!("Invalid command: {err}")),
+			};
+			let Ok(mut line) = serde_json::to_string(&response) else {
+				break;
+			};
+			line.push('\n');
+			if writer.write_all(line.as_bytes()).is_err() {
+				break;
+			}
+		}
+	}
+
+	/// Returns the commands received since the last poll.
+	#[must_use]
+	pub fn poll(&self) -> Vec<IpcRequest> {
+		self.requests.try_iter().collect()
+	}
+}
+
+impl Drop for IpcServer {
+	fn drop(&mut self) {
+		let _ = fs::remove_file(&self.path);
+	}
+}
@@ -0,0 +1,186 @@
+//! Client of a self-hosted leaderboard server, shared by every player set up
+//! with it: the player's bests are submitted to it, and each game's rankings
+//! fetched from it, from the [leaderboard
+//! screen](crate::ui::screens::LeaderboardScreen)'s "Global" tab.
+//!
+//! The server's URL is set with
+//! [`Config::leaderboard_url`](crate::core::config::Config::leaderboard_url),
+//! and requests are only sent in builds with the `global-leaderboard` feature.
+//!
+//! # API
+//!
+//! Servers answer two requests, relative to their URL, in JSON:
+//!
+//! - `POST /scores` submits the player's bests. Its body is a [score
+//!   file](crate::core::score_file) of every game's bests, as written by the
+//!   `export-scores` command, with the player's name and a checksum. Servers
+//!   keep each player's bests, and answer with any success status.
+//! - `GET /rankings/<game>?mode=<mode>` fetches a game's rankings in a
+//!   [mode](GameMode::id), best first, as [ranked by the server](Rankings):
+//!
+//! ```json
+//! {
+//!   "rankings": [
+//!     { "player": "🙂 Ada", "score": 2048, "time_secs": null },
+//!     { "player": "🦊 Grace", "score": 1024, "time_secs": null }
+//!   ]
+//! }
+//! ```
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+#[cfg(feature = "global-leaderboard")]
+use crate::core::score_file::ScoreFile;
+use crate::{
+	core::config::Config,
+	games::{
+		modes::GameMode,
+		registry::GameId,
+	},
+	t,
+};
+
+/// A player's place in a game's rankings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[must_use]
+pub struct Ranking {
+	/// The player's [profile](crate::core::profile::Profile) badge.
+	pub player: String,
+
+	/// The player's best score, in games that keep a score.
+	#[serde(default)]
+	pub score: Option<i64>,
+
+	/// The player's fastest time, in seconds, in games played against the
+	/// clock.
+	#[serde(default)]
+	pub time_secs: Option<u64>,
+}
+
+/// A game's rankings, as answered by the server. The server ranks them, best
+/// first, usually by highest score then by fastest time.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[must_use]
+pub struct Rankings {
+	/// The players' rankings, best first.
+	pub rankings: Vec<Ranking>,
+}
+
+/// Returns the URL of the leaderboard server set up, without trailing
+/// slashes, if any.
+#[must_use]
+pub fn server_url() -> Option<String> {
+	let url = Config::load_or_default().leaderboard_url?;
+	let url = url.trim().trim_end_matches('/');
+	(!url.is_empty()).then(|| url.to_string())
+}
+
+/// Escapes a part of a URL's path or query, keeping only the characters that
+/// never need escaping.
+fn escape(part: &str) -> String {
+	part.bytes()
+		.map(|byte| {
+			if byte.is_ascii_alphanumeric() || b"-_.~".contains(&byte) {
+				char::from(byte).to_string()
+			} else {
+				format!("%{byte:02X}")
+			}
+		})
+		.collect::<Vec<_>>()
+		.concat()
+}
+
+/// Returns the URL of a game's rankings in a mode, on a server.
+#[must_use]
+pub fn rankings_url(server: &str, game: &GameId, mode: GameMode) -> String {
+	format!(
+		"{server}/rankings/{}?mode={}",
+		escape(game.as_str()),
+		escape(mode.id())
+	)
+}
+
+/// Returns the URL that scores are submitted to, on a server.
+#[must_use]
+pub fn scores_url(server: &str) -> String {
+	format!("{server}/scores")
+}
+
+/// User agent the server is sent requests with.
+#[cfg(feature = "global-leaderboard")]
+const USER_AGENT: &str = concat!("terminal-arcade/", env!("CARGO_PKG_VERSION"));
+
+/// Returns the server set up, or fails if there's none.
+#[cfg(feature = "global-leaderboard")]
+fn require_server() -> anyhow::Result<String> {
+	server_url().ok_or_else(|| anyhow::anyhow!(t!("leaderboard-server-missing")))
+}
+
+/// Fetches a game's rankings in a mode from the server, best first.
+#[cfg(feature = "global-leaderboard")]
+pub fn fetch_rankings(game: &GameId, mode: GameMode) -> anyhow::Result<Vec<Ranking>> {
+	let rankings: Rankings = ureq::get(&rankings_url(&require_server()?, game, mode))
+		.set("User-Agent", USER_AGENT)
+		.call()?
+		.into_json()?;
+	Ok(rankings.rankings)
+}
+
+/// Fetches a game's rankings in a mode from the server, best first.
+#[cfg(not(feature = "global-leaderboard"))]
+pub fn fetch_rankings(_game: &GameId, _mode: GameMode) -> anyhow::Result<Vec<Ranking>> {
+	anyhow::bail!(t!("leaderboard-not-built"))
+}
+
+/// Submits the player's bests in every game to the server, returning how many
+/// games they were submitted in.
+#[cfg(feature = "global-leaderboard")]
+pub fn submit_scores() -> anyhow::Result<usize> {
+	let server = require_server()?;
+	let scores = ScoreFile::collect();
+	ureq::post(&scores_url(&server))
+		.set("User-Agent", USER_AGENT)
+		.set("Content-Type", "application/json")
+		.send_string(&scores.to_json()?)?;
+	Ok(scores.games.len())
+}
+
+/// Submits the player's bests in every game to the server, returning how many
+/// games they were submitted in.
+#[cfg(not(feature = "global-leaderboard"))]
+pub fn submit_scores() -> anyhow::Result<usize> {
+	anyhow::bail!(t!("leaderboard-not-built"))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		rankings_url,
+		Rankings,
+	};
+	use crate::games::{
+		modes::GameMode,
+		registry::GameId,
+	};
+
+	#[test]
+	fn urls_are_escaped_and_rankings_parsed() {
+		assert_eq!(
+			rankings_url(
+				"https://scores.example",
+				&GameId::from("my game/2"),
+				GameMode::TimeTrial
+			),
+			"https://scores.example/rankings/my%20game%2F2?mode=time_trial"
+		);
+		let rankings: Rankings = serde_json::from_str(
+			r#"{"rankings": [{"player": "🙂 Ada", "score": 2048}, {"player": "🦊 Grace", "time_secs": 83}]}"#,
+		)
+		.unwrap();
+		assert_eq!(rankings.rankings[0].score, Some(2048));
+		assert_eq!(rankings.rankings[1].time_secs, Some(83));
+	}
+}
@@ -0,0 +1,23 @@
+//! # The `services` module
+//!
+//! This module contains subsystems that run alongside the UI, usually on their
+//! own threads, and that screens and games talk to.
+
+pub mod autosave;
+pub mod backup;
+pub mod badges;
+pub mod game_data;
+#[cfg(unix)]
+pub mod ipc;
+pub mod leaderboard;
+#[cfg(feature = "networking")]
+pub mod net;
+pub mod rating;
+pub mod reset;
+pub mod rng;
+pub mod stats;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod sync;
+#[cfg(feature = "telnet")]
+pub mod telnet;
+pub mod undo;
@@ -0,0 +1,186 @@
+//! Discovery of games hosted on the local network over mDNS (multicast DNS).
+//!
+//! Hosts [advertise](Advertisement) their game as a service on the local
+//! network, which other players [browse](LocalGameBrowser) for, so that they
+//! can join without typing in the host's address.
+
+use std::{
+	net::SocketAddr,
+	process,
+};
+
+use anyhow::anyhow;
+use mdns_sd::{
+	Receiver,
+	ServiceDaemon,
+	ServiceEvent,
+	ServiceInfo,
+};
+
+use crate::services::net::{
+	local_address,
+	protocol::PROTOCOL_VERSION,
+};
+
+/// Type of the mDNS service that games are advertised as.
+pub const SERVICE_TYPE: &str = "_terminal-arcade._tcp.local.";
+
+/// Returns a name usable as an mDNS label, keeping only ASCII letters, digits
+/// and hyphens.
+fn to_label(name: &str) -> String {
+	let label: String = name
+		.chars()
+		.map(|character| if character.is_ascii_alphanumeric() { character } else { '-' })
+		.collect();
+	if label.is_empty() {
+		"player".to_string()
+	} else {
+		label
+	}
+}
+
+/// A game advertised on the local network while its host waits for a guest.
+#[derive(Clone)]
+#[must_use]
+pub struct Advertisement {
+	/// The mDNS daemon answering queries for the game.
+	daemon: ServiceDaemon,
+
+	/// Full name of the advertised service.
+	fullname: String,
+}
+
+impl Advertisement {
+	/// Advertises a game hosted by a player on a port.
+	pub fn start(game: &str, name: &str, port: u16) -> anyhow::Result<Self> {
+		let address =
+			local_address().ok_or_else(|| anyhow!("This computer isn't on a local network"))?;
+		let instance = format!("{}-{}", to_label(name), process::id());
+		let properties = [
+			("game", game.to_string()),
+			("name", name.to_string()),
+			("version", PROTOCOL_VERSION.to_string()),
+		];
+		let service = ServiceInfo::new(
+			SERVICE_TYPE,
+			&instance,
+			&format!("{instance}.local."),
+			address,
+			port,
+			&properties[..],
+		)?;
+		let fullname = service.get_fullname().to_string();
+		let daemon = ServiceDaemon::new()?;
+		daemon.register(service)?;
+		Ok(Self { daemon, fullname })
+	}
+
+	/// Stops advertising the game.
+	pub fn stop(&self) {
+		let _ = self.daemon.unregister(&self.fullname);
+		let _ = self.daemon.shutdown();
+	}
+}
+
+/// A game found on the local network.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalGame {
+	/// Full name of the game's service, identifying it on the network.
+	pub fullname: String,
+
+	/// Name of the player hosting the game.
+	pub host_name: String,
+
+	/// Address to join the game at.
+	pub address: SocketAddr,
+}
+
+/// Browses the local network for hosts of a game.
+#[derive(Clone)]
+#[must_use]
+pub struct LocalGameBrowser {
+	/// The mDNS daemon sending queries for the game.
+	daemon: ServiceDaemon,
+
+	/// Receives services appearing and disappearing on the network.
+	events: Receiver<ServiceEvent>,
+
+	/// Name of the game browsed for.
+	game: String,
+
+	/// Games found so far.
+	games: Vec<LocalGame>,
+}
+
+impl LocalGameBrowser {
+	/// Starts browsing for hosts of a game. Hosts speaking another protocol
+	/// version are left out.
+	pub fn browse(game: &str) -> anyhow::Result<Self> {
+		let daemon = ServiceDaemon::new()?;
+		let events = daemon.browse(SERVICE_TYPE)?;
+		Ok(Self {
+			daemon,
+			events,
+			game: game.to_string(),
+			games: Vec::new(),
+		})
+	}
+
+	/// Returns the games found so far.
+	#[must_use]
+	pub fn games(&self) -> &[LocalGame] {
+		&self.games
+	}
+
+	/// Updates the games found with what happened on the network since last
+	/// polled, returning whether they changed.
+	pub fn poll(&mut self) -> bool {
+		let mut changed = false;
+		while let Ok(event) = self.events.try_recv() {
+			match event {
+				ServiceEvent::ServiceResolved(service) => {
+					let Some(game) = self.to_local_game(&service) else {
+						continue;
+					};
+					self.games.retain(|found| found.fullname != game.fullname);
+					self.games.push(game);
+					changed = true;
+				},
+				ServiceEvent::ServiceRemoved(_, fullname) => {
+					self.games.retain(|found| found.fullname != fullname);
+					changed = true;
+				},
+				_ => {},
+			}
+		}
+		changed
+	}
+
+	/// Returns the game a service advertises, if it's a game of the one
+	/// browsed for.
+	fn to_local_game(&self, service: &ServiceInfo) -> Option<LocalGame> {
+		let version = PROTOCOL_VERSION.to_string();
+		if service.get_property_val_str("game") != Some(self.game.as_str())
+			|| service.get_property_val_str("version") != Some(version.as_str())
+		{
+			return None;
+		}
+		let addresses = service.get_addresses();
+		let ip = addresses
+			.iter()
+			.find(|ip| ip.is_ipv4())
+			.or_else(|| addresses.iter().next())
+			.copied()?;
+		Some(LocalGame {
+			fullname: service.get_fullname().to_string(),
+			host_name: service.get_property_val_str("name").unwrap_or("Player").to_string(),
+			address: SocketAddr::new(ip, service.get_port()),
+		})
+	}
+
+	/// Stops browsing.
+	pub fn stop(&self) {
+		let _ = self.daemon.stop_browse(SERVICE_TYPE);
+		let _ = self.daemon.shutdown();
+	}
+}
@@ -0,0 +1,824 @@
+//! Networked multiplayer over TCP.
+//!
+//! A player [hosts](PendingSession::host) a game and another one
+//! [joins](PendingSession::join) it. Once the peers have introduced themselves,
+//! they get a [Session] through which games exchange their own events, while
+//! the session keeps track of the [latency](Session::latency) between them.
+//! Others can [spectate](PendingSession::spectate) a game being played, and are
+//! sent the host's events without being able to send their own.
+//! Every connection has its own threads reading messages from the peer and
+//! writing messages to it, so that screens can poll and send without blocking,
+//! and peers that stop reading fall behind rather than freezing the game.
+//! Hosts introduce themselves to each peer connecting on its own thread too.
+//! Hosts are also [advertised](discovery) on the local network, so that guests
+//! can find them.
+
+use std::{
+	env,
+	io::{
+		BufRead,
+		BufReader,
+		ErrorKind,
+		Read,
+		Write,
+	},
+	net::{
+		IpAddr,
+		Shutdown,
+		SocketAddr,
+		TcpListener,
+		TcpStream,
+		ToSocketAddrs,
+		UdpSocket,
+	},
+	sync::{
+		atomic::{
+			AtomicBool,
+			Ordering,
+		},
+		mpsc::{
+			channel,
+			sync_channel,
+			Receiver,
+			Sender,
+			SyncSender,
+			TryRecvError,
+			TrySendError,
+		},
+		Arc,
+		Mutex,
+	},
+	thread,
+	time::Duration,
+};
+
+use anyhow::{
+	anyhow,
+	bail,
+};
+use serde::{
+	de::DeserializeOwned,
+	Serialize,
+};
+
+use crate::{
+	core::{
+		profile::Profile,
+		time::{
+			Instant,
+			SystemTime,
+			UNIX_EPOCH,
+		},
+	},
+	services::net::protocol::{
+		Message,
+		PROTOCOL_VERSION,
+	},
+	t,
+};
+
+pub mod discovery;
+pub mod protocol;
+
+/// Port that games are hosted on by default.
+pub const DEFAULT_PORT: u16 = 7676;
+
+/// How often peers measure the latency between them.
+pub const PING_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How long to wait for a peer to connect or to introduce itself.
+pub const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Longest message a peer may send, in bytes, newline included. Peers sending
+/// longer ones are disconnected, rather than read into memory endlessly.
+pub const MAX_MESSAGE_LEN: usize = 64 * 1024;
+
+/// How long writing a message to a peer may take before the peer is
+/// disconnected.
+pub const WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Most messages waiting to be written to a peer. Peers reading too slowly to
+/// keep up are disconnected once that many are waiting.
+pub const OUTGOING_QUEUE_LEN: usize = 256;
+
+/// How often a host checks for new guests while waiting for one.
+const ACCEPT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Returns the player's name as other players see it: their
+/// [profile](crate::core::profile)'s avatar and name.
+#[must_use]
+pub fn default_player_name() -> String {
+	Profile::current().badge()
+}
+
+/// Returns the address of this machine on the local network, if it has one.
+/// No packets are sent to find it out.
+#[must_use]
+pub fn local_address() -> Option<IpAddr> {
+	let socket = UdpSocket::bind("0.0.0.0:0").ok()?;
+	socket.connect("192.0.2.1:9").ok()?;
+	Some(socket.local_addr().ok()?.ip())
+}
+
+/// Returns the current UNIX time in milliseconds.
+fn unix_time_millis() -> u64 {
+	SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64
+}
+
+/// Something for a connection's writer thread to do.
+enum Outgoing {
+	/// Write a line to the peer.
+	Line(String),
+
+	/// Close the connection, once the lines before are written.
+	Close,
+}
+
+/// A connection to a peer, exchanging [messages](Message).
+#[derive(Clone)]
+pub struct Connection {
+	/// The stream, to close it.
+	stream: Arc<TcpStream>,
+
+	/// Lines waiting to be written to the peer by the connection's writer
+	/// thread.
+	outgoing: SyncSender<Outgoing>,
+
+	/// Messages read from the peer by the connection's reader thread.
+	incoming: Arc<Mutex<Receiver<Message>>>,
+}
+
+impl Connection {
+	/// Creates a connection from a stream, spawning a thread that reads the
+	/// peer's messages until the stream closes and one that writes the
+	/// messages sent.
+	pub fn new(stream: TcpStream) -> anyhow::Result<Self> {
+		stream.set_nodelay(true)?;
+		stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+		let reader = BufReader::new(stream.try_clone()?);
+		let (sender, receiver) = channel();
+		thread::spawn(move || Self::read_messages(reader, &sender));
+		let writer = stream.try_clone()?;
+		let (outgoing, outgoing_receiver) = sync_channel(OUTGOING_QUEUE_LEN);
+		thread::spawn(move || Self::write_messages(writer, &outgoing_receiver));
+		Ok(Self {
+			stream: Arc::new(stream),
+			outgoing,
+			incoming: Arc::new(Mutex::new(receiver)),
+		})
+	}
+
+	/// Writes the lines sent, until the connection is closed or dropped, or a
+	/// write fails or times out, closing the stream then.
+	fn write_messages(mut writer: TcpStream, outgoing: &Receiver<Outgoing>) {
+		while let Ok(Outgoing::Line(line)) = outgoing.recv() {
+			if writer.write_all(line.as_bytes()).is_err() {
+				break;
+			}
+		}
+		let _ = writer.shutdown(Shutdown::Both);
+	}
+
+	/// Reads messages line by line, until the stream closes or a malformed
+	/// message is received. Peers sending messages longer than
+	/// [`MAX_MESSAGE_LEN`] are disconnected.
+	fn read_messages(mut reader: BufReader<TcpStream>, sender: &Sender<Message>) {
+		let mut line = Vec::new();
+		loop {
+			line.clear();
+			let limit = MAX_MESSAGE_LEN as u64;
+			let Ok(read) = (&mut reader).take(limit).read_until(b'\n', &mut line) else {
+				break;
+			};
+			if read == 0 {
+				break;
+			}
+			if line.last() != Some(&b'\n') {
+				if read == MAX_MESSAGE_LEN {
+					let _ = reader.get_ref().shutdown(Shutdown::Both);
+				}
+				break;
+			}
+			let Ok(message) = serde_json::from_slice::<Message>(&line) else {
+				break;
+			};
+			if sender.send(message).is_err() {
+				break;
+			}
+		}
+	}
+
+	/// Sends a message to the peer, without waiting for it to be written.
+	/// Peers with [`OUTGOING_QUEUE_LEN`] messages waiting already fell behind,
+	/// and are disconnected.
+	pub fn send(&self, message: &Message) -> anyhow::Result<()> {
+		let mut line = serde_json::to_string(message)?;
+		line.push('\n');
+		match self.outgoing.try_send(Outgoing::Line(line)) {
+			Ok(()) => Ok(()),
+			Err(TrySendError::Full(_)) => {
+				let _ = self.stream.shutdown(Shutdown::Both);
+				bail!(t!("net-fell-behind"))
+			},
+			Err(TrySendError::Disconnected(_)) => bail!(t!("net-disconnected")),
+		}
+	}
+
+	/// Returns the next message received, or [`None`] if there is none yet.
+	/// Fails if the peer is disconnected.
+	pub fn try_receive(&self) -> anyhow::Result<Option<Message>> {
+		let incoming = self.incoming.lock().map_err(|_| anyhow!("Connection is poisoned"))?;
+		match incoming.try_recv() {
+			Ok(message) => Ok(Some(message)),
+			Err(TryRecvError::Empty) => Ok(None),
+			Err(TryRecvError::Disconnected) => bail!(t!("net-disconnected")),
+		}
+	}
+
+	/// Waits for the next message, up to a timeout.
+	fn receive_timeout(&self, timeout: Duration) -> anyhow::Result<Message> {
+		let incoming = self.incoming.lock().map_err(|_| anyhow!("Connection is poisoned"))?;
+		incoming.recv_timeout(timeout).map_err(|_| anyhow!(t!("net-no-answer")))
+	}
+
+	/// Returns whether this is the same connection as another one.
+	fn is(&self, other: &Self) -> bool {
+		Arc::ptr_eq(&self.stream, &other.stream)
+	}
+
+	/// Closes the connection once the messages sent are written, or right away
+	/// if the peer fell behind.
+	fn close(&self) {
+		if self.outgoing.try_send(Outgoing::Close).is_err() {
+			let _ = self.stream.shutdown(Shutdown::Both);
+		}
+	}
+}
+
+/// Which side of a session a player is on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+	/// The player hosting the game, who usually runs the game's simulation.
+	Host,
+
+	/// The player who joined the game.
+	Guest,
+
+	/// Someone watching the game without playing, who only receives events
+	/// from the host.
+	Spectator,
+}
+
+/// Something that happened in a [Session].
+#[derive(Debug, Clone)]
+pub enum SessionEvent<E> {
+	/// The peer sent a game event.
+	Game(E),
+
+	/// A spectator started watching the game. Hosts should send them what
+	/// they need to catch up with the game.
+	SpectatorJoined,
+
+	/// A peer sent a chat message.
+	Chat {
+		/// Name of the player who sent the message.
+		author: String,
+
+		/// What the message says.
+		text: String,
+	},
+
+	/// The peer disconnected.
+	Disconnected,
+}
+
+/// A session between two players, established once they've introduced
+/// themselves. Hosts also send their events to the session's spectators.
+#[derive(Clone)]
+#[must_use]
+pub struct Session {
+	/// This player's side of the session.
+	role: Role,
+
+	/// This player's name.
+	name: String,
+
+	/// The other player's name, or the host's for spectators.
+	peer_name: String,
+
+	/// The connection to the other player, or to the host for spectators.
+	connection: Connection,
+
+	/// Connections to the spectators, accepted in the background by the host.
+	spectators: Arc<Mutex<Vec<Connection>>>,
+
+	/// The number of spectators watching, as last known.
+	spectator_count: usize,
+
+	/// Whether the session was left, which stops the host from accepting
+	/// spectators.
+	left: Arc<AtomicBool>,
+
+	/// The last latency measured.
+	latency: Option<Duration>,
+
+	/// When the latency was last measured.
+	last_ping: Option<Instant>,
+
+	/// Whether the other player disconnected.
+	disconnected: bool,
+}
+
+impl Session {
+	/// Returns this player's side of the session.
+	#[must_use]
+	pub fn role(&self) -> Role {
+		self.role
+	}
+
+	/// Returns the other player's name, or the host's for spectators.
+	#[must_use]
+	pub fn peer_name(&self) -> &str {
+		&self.peer_name
+	}
+
+	/// Returns the round-trip time to the other player, once measured.
+	#[must_use]
+	pub fn latency(&self) -> Option<Duration> {
+		self.latency
+	}
+
+	/// Returns the number of spectators watching the game.
+	#[must_use]
+	pub fn spectator_count(&self) -> usize {
+		self.spectator_count
+	}
+
+	/// Returns whether the other player disconnected.
+	#[must_use]
+	pub fn is_disconnected(&self) -> bool {
+		self.disconnected
+	}
+
+	/// Sends a game event to the other player, and to the spectators if
+	/// hosting. Events sent after the other player disconnected are dropped.
+	pub fn send<E: Serialize>(&mut self, event: &E) -> anyhow::Result<()> {
+		let message = Message::Game(serde_json::to_string(event)?);
+		self.send_message(&message);
+		self.send_to_spectators_raw(&message, None);
+		Ok(())
+	}
+
+	/// Sends a chat message to everyone in the session.
+	pub fn send_chat(&mut self, text: &str) {
+		let message = Message::Chat {
+			author: self.name.clone(),
+			text: text.to_string(),
+		};
+		self.send_message(&message);
+		self.send_to_spectators_raw(&message, None);
+	}
+
+	/// Sends a game event to the spectators only, if hosting.
+	pub fn send_to_spectators<E: Serialize>(&self, event: &E) -> anyhow::Result<()> {
+		self.send_to_spectators_raw(&Message::Game(serde_json::to_string(event)?), None);
+		Ok(())
+	}
+
+	/// Sends a message to the spectators, if hosting, except to the one who
+	/// sent it if any. Spectators that fell behind are dropped, and those that
+	/// can't be reached are dropped when polling.
+	fn send_to_spectators_raw(&self, message: &Message, except: Option<&Connection>) {
+		if let Ok(mut spectators) = self.spectators.lock() {
+			spectators.retain(|spectator| {
+				except.is_some_and(|sender| sender.is(spectator)) || spectator.send(message).is_ok()
+			});
+		}
+	}
+
+	/// Sends a message, marking the session as disconnected if it fails.
+	fn send_message(&mut self, message: &Message) {
+		if !self.disconnected && self.connection.send(message).is_err() {
+			self.disconnected = true;
+		}
+	}
+
+	/// Returns the events received since the last poll, answering and sending
+	/// pings along the way. This should be called regularly, such as in
+	/// [`crate::ui::Screen::update`].
+	pub fn poll<E: DeserializeOwned>(&mut self) -> Vec<SessionEvent<E>> {
+		let mut events = Vec::new();
+		self.poll_spectators(&mut events);
+		if self.disconnected {
+			return events;
+		}
+		if self.last_ping.is_none_or(|last_ping| last_ping.elapsed() >= PING_INTERVAL) {
+			self.last_ping = Some(Instant::now());
+			self.send_message(&Message::Ping(unix_time_millis()));
+		}
+		loop {
+			match self.connection.try_receive() {
+				Ok(Some(Message::Ping(sent_at))) => self.send_message(&Message::Pong(sent_at)),
+				Ok(Some(Message::Pong(sent_at))) => {
+					self.latency = Some(Duration::from_millis(
+						unix_time_millis().saturating_sub(sent_at),
+					));
+				},
+				Ok(Some(Message::Spectators(count))) => self.spectator_count = count,
+				Ok(Some(Message::Chat { author, text })) => {
+					events.push(SessionEvent::Chat {
+						author: author.clone(),
+						text: text.clone(),
+					});
+					self.send_to_spectators_raw(&Message::Chat { author, text }, None);
+				},
+				Ok(Some(Message::Game(event))) => {
+					if let Ok(event) = serde_json::from_str(&event) {
+						events.push(SessionEvent::Game(event));
+					}
+				},
+				Ok(Some(_)) => {},
+				Ok(None) => break,
+				Err(_) => {
+					self.disconnected = true;
+					events.push(SessionEvent::Disconnected);
+					break;
+				},
+			}
+		}
+		events
+	}
+
+	/// Answers the spectators' pings, passes on their chat messages and drops
+	/// those who left, telling everyone when the number of spectators changes.
+	/// Only hosts have spectators to poll.
+	fn poll_spectators<E>(&mut self, events: &mut Vec<SessionEvent<E>>) {
+		let mut chat = Vec::new();
+		let count = {
+			let Ok(mut spectators) = self.spectators.lock() else {
+				return;
+			};
+			spectators.retain(|spectator| loop {
+				match spectator.try_receive() {
+					Ok(Some(Message::Ping(sent_at))) => {
+						let _ = spectator.send(&Message::Pong(sent_at));
+					},
+					Ok(Some(Message::Chat { author, text })) => {
+						chat.push((spectator.clone(), author, text));
+					},
+					Ok(Some(_)) => {},
+					Ok(None) => break true,
+					Err(_) => break false,
+				}
+			});
+			spectators.len()
+		};
+		for (sender, author, text) in chat {
+			events.push(SessionEvent::Chat {
+				author: author.clone(),
+				text: text.clone(),
+			});
+			let message = Message::Chat { author, text };
+			self.send_message(&message);
+			self.send_to_spectators_raw(&message, Some(&sender));
+		}
+		if count == self.spectator_count {
+			return;
+		}
+		if count > self.spectator_count {
+			events.push(SessionEvent::SpectatorJoined);
+		}
+		self.spectator_count = count;
+		self.send_message(&Message::Spectators(count));
+		self.send_to_spectators_raw(&Message::Spectators(count), None);
+	}
+
+	/// Leaves the session, disconnecting from the other player and the
+	/// spectators.
+	pub fn leave(&mut self) {
+		self.disconnected = true;
+		self.left.store(true, Ordering::Relaxed);
+		self.connection.close();
+		if let Ok(spectators) = self.spectators.lock() {
+			for spectator in spectators.iter() {
+				spectator.close();
+			}
+		}
+	}
+}
+
+/// A session being established in the background, while a host waits for a
+/// guest or a guest connects to a host.
+#[derive(Clone)]
+#[must_use]
+pub struct PendingSession {
+	/// Receives the session once established, or why it couldn't be.
+	result: Arc<Mutex<Receiver<anyhow::Result<Session>>>>,
+
+	/// Whether establishing the session was cancelled.
+	cancelled: Arc<AtomicBool>,
+}
+
+impl PendingSession {
+	/// Creates a pending session from the channel its thread sends the result
+	/// to.
+	fn new(result: Receiver<anyhow::Result<Session>>, cancelled: Arc<AtomicBool>) -> Self {
+		Self {
+			result: Arc::new(Mutex::new(result)),
+			cancelled,
+		}
+	}
+
+	/// Hosts a game on a port, waiting for a guest in the background. Guests
+	/// playing another game or speaking another protocol version are rejected.
+	/// Once a guest joined, spectators are accepted until the session is left.
+	/// Each peer connecting is introduced on its own thread, so that one that
+	/// stays silent doesn't keep others from joining.
+	pub fn host(game: &str, name: String, port: u16) -> anyhow::Result<Self> {
+		let listener = TcpListener::bind(("0.0.0.0", port))?;
+		listener.set_nonblocking(true)?;
+		let (sender, receiver) = channel();
+		let cancelled = Arc::new(AtomicBool::new(false));
+		let lobby = Lobby {
+			game: game.to_string(),
+			name,
+			playing: Arc::new(AtomicBool::new(false)),
+			spectators: Arc::new(Mutex::new(Vec::new())),
+			left: Arc::new(AtomicBool::new(false)),
+			sessions: sender,
+		};
+		let thread_cancelled = Arc::clone(&cancelled);
+		thread::spawn(move || {
+			while !lobby.is_closed(&thread_cancelled) {
+				match listener.accept() {
+					Ok((stream, _)) => {
+						let lobby = lobby.clone();
+						thread::spawn(move || lobby.welcome(stream));
+					},
+					Err(err) if err.kind() == ErrorKind::WouldBlock => {
+						thread::sleep(ACCEPT_POLL_INTERVAL);
+					},
+					Err(err) => {
+						let _ = lobby.sessions.send(Err(err.into()));
+						return;
+					},
+				}
+			}
+		});
+		Ok(Self::new(receiver, cancelled))
+	}
+
+	/// Joins a game hosted at an address, connecting in the background. The
+	/// [default port](DEFAULT_PORT) is used if the address has none.
+	pub fn join(game: &str, name: String, address: &str) -> Self {
+		Self::connect(game, name, address, Role::Guest)
+	}
+
+	/// Watches a game hosted at an address as a spectator, connecting in the
+	/// background. The [default port](DEFAULT_PORT) is used if the address has
+	/// none.
+	pub fn spectate(game: &str, name: String, address: &str) -> Self {
+		Self::connect(game, name, address, Role::Spectator)
+	}
+
+	/// Connects to a game hosted at an address in the background, as a guest
+	/// or a spectator.
+	fn connect(game: &str, name: String, address: &str, role: Role) -> Self {
+		let address = if address.contains(':') {
+			address.to_string()
+		} else {
+			format!("{address}:{DEFAULT_PORT}")
+		};
+		let (sender, receiver) = channel();
+		let game = game.to_string();
+		thread::spawn(move || {
+			let _ = sender.send(Self::connect_to_host(&address, &game, name, role));
+		});
+		Self::new(receiver, Arc::new(AtomicBool::new(false)))
+	}
+
+	/// Connects and introduces a guest or spectator to a host.
+	fn connect_to_host(
+		address: &str,
+		game: &str,
+		name: String,
+		role: Role,
+	) -> anyhow::Result<Session> {
+		let address: SocketAddr = address
+			.to_socket_addrs()?
+			.next()
+			.ok_or_else(|| anyhow!(t!("net-address-not-found", address = address.to_string())))?;
+		let connection = Connection::new(TcpStream::connect_timeout(&address, HANDSHAKE_TIMEOUT)?)?;
+		connection.send(&Message::Hello {
+			protocol_version: PROTOCOL_VERSION,
+			game: game.to_string(),
+			name: name.clone(),
+			spectator: role == Role::Spectator,
+		})?;
+		match connection.receive_timeout(HANDSHAKE_TIMEOUT)? {
+			Message::Hello {
+				name: peer_name, ..
+			} => Ok(Self::session(role, name, peer_name, connection)),
+			Message::Rejected(reason) => bail!(reason),
+			_ => bail!(t!("net-no-hello-host")),
+		}
+	}
+
+	/// Creates a session from an established connection.
+	fn session(role: Role, name: String, peer_name: String, connection: Connection) -> Session {
+		Session {
+			role,
+			name,
+			peer_name,
+			connection,
+			spectators: Arc::new(Mutex::new(Vec::new())),
+			spectator_count: 0,
+			left: Arc::new(AtomicBool::new(false)),
+			latency: None,
+			last_ping: None,
+			disconnected: false,
+		}
+	}
+
+	/// Returns the session once established, or why it couldn't be.
+	#[must_use]
+	pub fn poll(&self) -> Option<anyhow::Result<Session>> {
+		self.result.lock().ok()?.try_recv().ok()
+	}
+
+	/// Stops waiting for the session. Has no effect once it's established.
+	pub fn cancel(&self) {
+		self.cancelled.store(true, Ordering::Relaxed);
+	}
+}
+
+/// What a host shares between the threads introducing the peers connecting.
+#[derive(Clone)]
+struct Lobby {
+	/// The game hosted.
+	game: String,
+
+	/// The host's name.
+	name: String,
+
+	/// Whether a guest joined, after which only spectators are accepted.
+	playing: Arc<AtomicBool>,
+
+	/// Connections to the spectators.
+	spectators: Arc<Mutex<Vec<Connection>>>,
+
+	/// Whether the session was left, which stops accepting spectators.
+	left: Arc<AtomicBool>,
+
+	/// Sends the session once a guest joined.
+	sessions: Sender<anyhow::Result<Session>>,
+}
+
+impl Lobby {
+	/// Returns whether peers stopped being accepted: once the session is left,
+	/// or before a guest joined if hosting was cancelled.
+	fn is_closed(&self, cancelled: &AtomicBool) -> bool {
+		if self.playing.load(Ordering::Relaxed) { &self.left } else { cancelled }
+			.load(Ordering::Relaxed)
+	}
+
+	/// Introduces the host to a peer that just connected, adding them to the
+	/// spectators or starting the session with them.
+	fn welcome(&self, stream: TcpStream) {
+		let Ok((peer_name, spectator, connection)) = self.accept_peer(stream) else {
+			return;
+		};
+		if spectator {
+			if let Ok(mut spectators) = self.spectators.lock() {
+				spectators.push(connection);
+			}
+			return;
+		}
+		let mut session =
+			PendingSession::session(Role::Host, self.name.clone(), peer_name, connection);
+		session.spectators = Arc::clone(&self.spectators);
+		session.left = Arc::clone(&self.left);
+		let _ = self.sessions.send(Ok(session));
+	}
+
+	/// Introduces the host to a guest or spectator that just connected,
+	/// returning their name and whether they only watch. Spectators are only
+	/// accepted once the game is being played, and a single guest before.
+	fn accept_peer(&self, stream: TcpStream) -> anyhow::Result<(String, bool, Connection)> {
+		stream.set_nonblocking(false)?;
+		let connection = Connection::new(stream)?;
+		let Message::Hello {
+			protocol_version,
+			game: requested_game,
+			name: peer_name,
+			spectator,
+		} = connection.receive_timeout(HANDSHAKE_TIMEOUT)?
+		else {
+			bail!(t!("net-no-hello-peer"));
+		};
+		let rejection = if protocol_version != PROTOCOL_VERSION {
+			Some(t!("net-rejected-version").to_string())
+		} else if requested_game != self.game {
+			Some(t!(
+				"net-rejected-game",
+				game = self.game.clone(),
+				requested_game = requested_game.clone()
+			))
+		} else if spectator && !self.playing.load(Ordering::Relaxed) {
+			Some(t!("net-rejected-nothing-to-watch").to_string())
+		} else if !spectator && self.playing.swap(true, Ordering::Relaxed) {
+			Some(t!("net-rejected-full").to_string())
+		} else {
+			None
+		};
+		if let Some(reason) = rejection {
+			connection.send(&Message::Rejected(reason.clone()))?;
+			connection.close();
+			bail!(reason);
+		}
+		let hello = connection.send(&Message::Hello {
+			protocol_version: PROTOCOL_VERSION,
+			game: self.game.clone(),
+			name: self.name.clone(),
+			spectator: false,
+		});
+		if hello.is_err() && !spectator {
+			// Another guest may join instead.
+			self.playing.store(false, Ordering::Relaxed);
+		}
+		hello?;
+		Ok((peer_name, spectator, connection))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		io::Write,
+		net::{
+			TcpListener,
+			TcpStream,
+		},
+		thread,
+		time::Duration,
+	};
+
+	use super::{
+		protocol::Message,
+		Connection,
+		PendingSession,
+		HANDSHAKE_TIMEOUT,
+		MAX_MESSAGE_LEN,
+		OUTGOING_QUEUE_LEN,
+		WRITE_TIMEOUT,
+	};
+	use crate::core::time::Instant;
+
+	#[test]
+	fn drops_peers_sending_overlong_messages() {
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let mut peer = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+		let connection = Connection::new(listener.accept().unwrap().0).unwrap();
+
+		peer.write_all(b"{\"Ping\":1}\n").unwrap();
+		peer.write_all(&vec![b' '; MAX_MESSAGE_LEN + 1]).unwrap();
+		thread::sleep(Duration::from_millis(100));
+		assert!(connection.try_receive().unwrap().is_some());
+		assert!(connection.try_receive().is_err());
+	}
+
+	#[test]
+	fn peers_that_stop_reading_fall_behind_without_blocking() {
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let _peer = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+		let connection = Connection::new(listener.accept().unwrap().0).unwrap();
+
+		let message = Message::Game("x".repeat(MAX_MESSAGE_LEN / 2));
+		let started = Instant::now();
+		assert!((0..OUTGOING_QUEUE_LEN * 4).any(|_| connection.send(&message).is_err()));
+		// Sending never waited for the peer, which would have taken as long as
+		// the write timeout.
+		assert!(started.elapsed() < WRITE_TIMEOUT);
+	}
+
+	#[test]
+	fn silent_peers_dont_keep_guests_from_joining() {
+		let port = TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+		let host = PendingSession::host("tron", "Host".to_string(), port).unwrap();
+		let _silent = TcpStream::connect(("127.0.0.1", port)).unwrap();
+		thread::sleep(Duration::from_millis(100));
+		let guest = PendingSession::join("tron", "Guest".to_string(), &format!("127.0.0.1:{port}"));
+
+		let started = Instant::now();
+		let (mut hosted, mut joined) = (None, None);
+		while (hosted.is_none() || joined.is_none()) && started.elapsed() < HANDSHAKE_TIMEOUT {
+			hosted = hosted.or_else(|| host.poll());
+			joined = joined.or_else(|| guest.poll());
+			thread::sleep(Duration::from_millis(10));
+		}
+		assert_eq!(hosted.unwrap().unwrap().peer_name(), "Guest");
+		assert_eq!(joined.unwrap().unwrap().peer_name(), "Host");
+		assert!(started.elapsed() < HANDSHAKE_TIMEOUT / 2);
+		host.cancel();
+	}
+}
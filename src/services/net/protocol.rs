@@ -0,0 +1,57 @@
+//! The session protocol: [messages](Message) exchanged between peers as JSON,
+//! one message per line.
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+/// Version of the protocol. Peers with different versions refuse to play
+/// together.
+pub const PROTOCOL_VERSION: u32 = 2;
+
+/// A message exchanged between peers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Message {
+	/// Introduces a peer. The guest or spectator sends it first, and the host
+	/// replies with its own if it accepts them.
+	Hello {
+		/// Version of the protocol spoken by the peer.
+		protocol_version: u32,
+
+		/// Name of the game played in the session.
+		game: String,
+
+		/// The player's name.
+		name: String,
+
+		/// Whether the peer only watches the game.
+		spectator: bool,
+	},
+
+	/// Refuses a guest or spectator, with the reason why.
+	Rejected(String),
+
+	/// Tells peers how many spectators are watching, sent by the host whenever
+	/// it changes.
+	Spectators(usize),
+
+	/// Measures the latency, sent with the sender's time in milliseconds.
+	Ping(u64),
+
+	/// Answers a [ping](Self::Ping) with the time it was sent with.
+	Pong(u64),
+
+	/// A game event, serialized as JSON by the game.
+	Game(String),
+
+	/// A chat message. Hosts pass on the messages they receive to every other
+	/// peer.
+	Chat {
+		/// Name of the player who sent the message.
+		author: String,
+
+		/// What the message says.
+		text: String,
+	},
+}
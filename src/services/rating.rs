@@ -0,0 +1,105 @@
+//! [Elo ratings](https://en.wikipedia.org/wiki/Elo_rating_system) for games
+//! played against an opponent. Each game keeps the player's rating in its
+//! [dynamic info](crate::games::GameDynamicInfo), updated after every match.
+
+/// Rating of a player who has never played a rated match.
+pub const DEFAULT_RATING: u32 = 1200;
+
+/// How much a single match can move a rating.
+pub const K_FACTOR: f64 = 32.0;
+
+/// How a match ended for a player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchResult {
+	/// The player won.
+	Win,
+
+	/// The player lost.
+	Loss,
+
+	/// Nobody won.
+	Draw,
+}
+
+impl MatchResult {
+	/// Returns how the match ended for the opponent.
+	#[must_use]
+	pub fn opposite(self) -> Self {
+		match self {
+			Self::Win => Self::Loss,
+			Self::Loss => Self::Win,
+			Self::Draw => Self::Draw,
+		}
+	}
+
+	/// Returns the score the result is worth: 1 for a win, 0.5 for a draw and
+	/// 0 for a loss.
+	#[must_use]
+	pub fn score(self) -> f64 {
+		match self {
+			Self::Win => 1.0,
+			Self::Loss => 0.0,
+			Self::Draw => 0.5,
+		}
+	}
+}
+
+/// Returns the score a player is expected to get against an opponent, between
+/// 0 and 1.
+#[must_use]
+pub fn expected_score(rating: u32, opponent_rating: u32) -> f64 {
+	let difference = f64::from(opponent_rating) - f64::from(rating);
+	1.0 / (1.0 + 10_f64.powf(difference / 400.0))
+}
+
+/// Returns a player's rating after a match against an opponent.
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn updated_rating(rating: u32, opponent_rating: u32, result: MatchResult) -> u32 {
+	let change = K_FACTOR * (result.score() - expected_score(rating, opponent_rating));
+	(f64::from(rating) + change).round().max(0.0) as u32
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		expected_score,
+		updated_rating,
+		MatchResult,
+		DEFAULT_RATING,
+	};
+
+	#[test]
+	fn expected_scores_favor_the_higher_rating() {
+		assert!((expected_score(1200, 1200) - 0.5).abs() < 1e-9);
+		assert!((expected_score(1600, 1200) - 10.0 / 11.0).abs() < 1e-9);
+		assert!((expected_score(1200, 1600) - 1.0 / 11.0).abs() < 1e-9);
+		assert!((expected_score(1400, 1000) + expected_score(1000, 1400) - 1.0).abs() < 1e-9);
+	}
+
+	#[test]
+	fn ratings_move_by_at_most_the_k_factor() {
+		assert_eq!(
+			updated_rating(DEFAULT_RATING, DEFAULT_RATING, MatchResult::Win),
+			1216
+		);
+		assert_eq!(
+			updated_rating(DEFAULT_RATING, DEFAULT_RATING, MatchResult::Loss),
+			1184
+		);
+		assert_eq!(updated_rating(1600, 1200, MatchResult::Win), 1603);
+		assert_eq!(updated_rating(1200, 1600, MatchResult::Win), 1229);
+		assert_eq!(updated_rating(1200, 1600, MatchResult::Loss), 1197);
+		assert_eq!(updated_rating(10, 3000, MatchResult::Loss), 10);
+		assert_eq!(updated_rating(0, 0, MatchResult::Loss), 0);
+	}
+
+	#[test]
+	fn draws_move_ratings_toward_each_other() {
+		assert_eq!(updated_rating(1200, 1200, MatchResult::Draw), 1200);
+		assert_eq!(updated_rating(1600, 1200, MatchResult::Draw), 1587);
+		assert_eq!(updated_rating(1200, 1600, MatchResult::Draw), 1213);
+		assert_eq!(MatchResult::Draw.opposite(), MatchResult::Draw);
+		assert_eq!(MatchResult::Win.opposite(), MatchResult::Loss);
+	}
+}
@@ -0,0 +1,126 @@
+//! Resetting parts of Terminal Arcade to their defaults, from the [reset
+//! screen](crate::ui::screens::ResetScreen) opened from the settings: the
+//! configuration, the keybindings, a game's save data, or everything at once.
+//!
+//! Whatever is wiped is [backed up](crate::services::backup) first: the save
+//! directory as a whole, and the files kept outside of it one by one, so that
+//! every reset can be undone.
+
+use std::{
+	fmt::{
+		Display,
+		Formatter,
+	},
+	fs,
+	path::PathBuf,
+};
+
+use crate::{
+	core::{
+		actions::keybindings_file_path,
+		config::config_file_path,
+		get_save_dir,
+	},
+	games::{
+		Game,
+		Games,
+	},
+	services::{
+		backup::{
+			self,
+			Backup,
+		},
+		game_data::GameData,
+	},
+	t,
+};
+
+/// What a reset wipes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResetScope {
+	/// The configuration.
+	Settings,
+
+	/// The keybindings, which take effect again on the next start.
+	Keybindings,
+
+	/// A game's save files, by the game's name.
+	Game(String),
+
+	/// The configuration, the keybindings and the whole save directory.
+	Everything,
+}
+
+impl Display for ResetScope {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		f.write_str(&self.label())
+	}
+}
+
+impl ResetScope {
+	/// Returns every scope, with one per game.
+	#[must_use]
+	pub fn all() -> Vec<Self> {
+		let games =
+			Games::all().into_iter().map(|game| Self::Game(game.data().metadata.static_info.name));
+		[Self::Settings, Self::Keybindings]
+			.into_iter()
+			.chain(games)
+			.chain([Self::Everything])
+			.collect()
+	}
+
+	/// Returns what the scope wipes.
+	#[must_use]
+	pub fn label(&self) -> String {
+		match self {
+			Self::Settings => t!("reset-settings").to_string(),
+			Self::Keybindings => t!("reset-keybindings").to_string(),
+			Self::Game(name) => t!("reset-game", game = name.as_str()),
+			Self::Everything => t!("reset-everything").to_string(),
+		}
+	}
+
+	/// Returns the question confirming the reset.
+	#[must_use]
+	pub fn confirmation(&self) -> String {
+		match self {
+			Self::Settings => t!("reset-settings-confirm").to_string(),
+			Self::Keybindings => t!("reset-keybindings-confirm").to_string(),
+			Self::Game(name) => t!("reset-game-confirm", game = name.as_str()),
+			Self::Everything => t!("reset-everything-confirm").to_string(),
+		}
+	}
+
+	/// Backs up what the scope wipes, then wipes it, returning where the
+	/// backups are. Save data is backed up as a whole, and the files kept
+	/// outside of it one by one.
+	pub fn reset(&self) -> anyhow::Result<Vec<PathBuf>> {
+		let mut backups = Vec::new();
+		if matches!(self, Self::Game(_) | Self::Everything) && get_save_dir().is_dir() {
+			backups.push(Backup::create()?.path);
+		}
+		let outside_files = match self {
+			Self::Settings => vec![config_file_path()],
+			Self::Keybindings => vec![keybindings_file_path()],
+			Self::Game(_) => Vec::new(),
+			Self::Everything => vec![config_file_path(), keybindings_file_path()],
+		};
+		for file in outside_files {
+			backups.extend(backup::back_up_file(&file)?);
+			if file.is_file() {
+				fs::remove_file(file)?;
+			}
+		}
+		match self {
+			Self::Game(name) => {
+				for data in GameData::list().into_iter().filter(|data| data.name == *name) {
+					data.delete()?;
+				}
+			},
+			Self::Everything => backup::clear_save_dir()?,
+			Self::Settings | Self::Keybindings => {},
+		}
+		Ok(backups)
+	}
+}
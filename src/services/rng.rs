@@ -0,0 +1,78 @@
+//! The random number generator shared by games and screens.
+//!
+//! Everything random in Terminal Arcade, from mine placements to picking a
+//! random game, draws from a single seedable generator instead of
+//! [`rand::thread_rng`]. Seeding it, with the `--seed <SEED>` argument or
+//! [`seed`], makes a run reproducible: the same seed and the same input give
+//! the same games, which is what daily challenges and replays rely on.
+//!
+//! Unless seeded, the generator starts from a random seed, which is still
+//! available through [`current_seed`] to reproduce the run later.
+//!
+//! Games start from a seed of their own with [`seed_game`], shown when they're
+//! over so that the same game can be played again.
+
+use std::sync::{
+	LazyLock,
+	Mutex,
+};
+
+use chrono::{
+	Datelike,
+	Utc,
+};
+use rand::{
+	Rng,
+	SeedableRng,
+};
+use rand_chacha::ChaCha8Rng;
+
+/// The generator, with the seed it started from.
+static RNG: LazyLock<Mutex<(u64, ChaCha8Rng)>> = LazyLock::new(|| {
+	let seed = rand::thread_rng().gen();
+	Mutex::new((seed, ChaCha8Rng::seed_from_u64(seed)))
+});
+
+/// Restarts the shared generator from a seed.
+pub fn seed(seed: u64) {
+	*RNG.lock().expect("The random number generator was poisoned") =
+		(seed, ChaCha8Rng::seed_from_u64(seed));
+}
+
+/// Returns the seed the shared generator last started from.
+#[must_use]
+pub fn current_seed() -> u64 {
+	RNG.lock().expect("The random number generator was poisoned").0
+}
+
+/// Restarts the shared generator from a new seed for a game starting, and
+/// returns it. The seed is drawn from the generator itself, so seeded runs
+/// stay reproducible.
+#[must_use]
+pub fn seed_game() -> u64 {
+	let game_seed = with_rng(Rng::gen);
+	seed(game_seed);
+	game_seed
+}
+
+/// Runs a function with the shared generator.
+pub fn with_rng<T>(function: impl FnOnce(&mut ChaCha8Rng) -> T) -> T {
+	function(&mut RNG.lock().expect("The random number generator was poisoned").1)
+}
+
+/// Returns a generator of its own started from a seed, which other random
+/// draws don't affect.
+#[must_use]
+pub fn seeded(seed: u64) -> ChaCha8Rng {
+	ChaCha8Rng::seed_from_u64(seed)
+}
+
+/// Returns the seed of today's daily challenge, the same for every player on
+/// the same (UTC) day.
+#[must_use]
+pub fn daily_seed() -> u64 {
+	let today = Utc::now().date_naive();
+	// Hashes the date, so that seeds of consecutive days aren't consecutive.
+	let days = u64::from(today.num_days_from_ce().unsigned_abs());
+	days.wrapping_mul(0x9E37_79B9_7F4A_7C15)
+}
@@ -0,0 +1,593 @@
+//! Statistics computed from the player's history in every game, kept in each
+//! game's [dynamic info](crate::games::GameDynamicInfo), like the
+//! [weekly digest](WeeklyDigest).
+//!
+//! The [session summary](SessionSummary) compares every game's metadata from
+//! when Terminal Arcade [started](start_session) with when it quits.
+//!
+//! Today's playtime and plays are also counted live, and shown in the top
+//! border of every screen once the player played. Playtime is counted by the
+//! [handler](crate::core::handler)'s loop while a game is on screen and not
+//! paused, and kept in the save directory's [`PLAYTIME_FILE`].
+
+use std::{
+	collections::{
+		BTreeSet,
+		HashMap,
+	},
+	sync::{
+		Mutex,
+		OnceLock,
+	},
+	time::{
+		Duration,
+		UNIX_EPOCH,
+	},
+};
+
+use chrono::{
+	DateTime,
+	Local,
+	NaiveDate,
+};
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+use crate::{
+	core::{
+		get_save_dir,
+		save_file,
+	},
+	games::{
+		clock_text,
+		get_unix_time_as_secs,
+		modes::GameMode,
+		Game,
+		GameDynamicInfo,
+		Games,
+	},
+	t,
+};
+
+/// Seconds in a day.
+pub const DAY_SECS: u64 = 24 * 60 * 60;
+
+/// How many days of history are kept for each game.
+pub const HISTORY_DAYS: u64 = 365;
+
+/// Name of the file today's playtime is kept in, in the [save
+/// directory](get_save_dir).
+pub const PLAYTIME_FILE: &str = "playtime.toml";
+
+/// When the session started, and every game's name and metadata then.
+static SESSION_START: OnceLock<(u64, Vec<(String, GameDynamicInfo)>)> = OnceLock::new();
+
+/// Today's counters, once they've been loaded by the first [tick](tick_today).
+static TODAY: Mutex<Option<Today>> = Mutex::new(None);
+
+/// Returns the local date of a UNIX timestamp.
+fn local_date(timestamp: u64) -> NaiveDate {
+	DateTime::<Local>::from(UNIX_EPOCH + Duration::from_secs(timestamp)).date_naive()
+}
+
+/// Returns whether a UNIX timestamp is recent enough to be kept in the
+/// history, as of `now`.
+#[must_use]
+pub fn in_history(timestamp: u64, now: u64) -> bool {
+	timestamp + HISTORY_DAYS * DAY_SECS >= now
+}
+
+/// Summary of the player's last seven days in the arcade.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeeklyDigest {
+	/// Date the week started on.
+	pub start: NaiveDate,
+
+	/// Date the week ends on, today.
+	pub end: NaiveDate,
+
+	/// Plays of each game played this week, most played first.
+	pub plays: Vec<(String, usize)>,
+
+	/// Best high score set this week in each game's standard mode, if any were
+	/// set.
+	pub records: Vec<(String, i64)>,
+
+	/// Days in a row, up to today or yesterday, the player played on.
+	pub streak: usize,
+
+	/// Most days in a row the player played on, in the whole history.
+	pub longest_streak: usize,
+}
+
+impl WeeklyDigest {
+	/// Computes the digest of the player's week from every game's metadata.
+	#[must_use]
+	pub fn compute() -> Self {
+		Self::from_games(&games_info(), get_unix_time_as_secs())
+	}
+
+	/// Computes the digest of the week up to `now` from games' names and
+	/// metadata.
+	#[must_use]
+	pub fn from_games(games: &[(String, GameDynamicInfo)], now: u64) -> Self {
+		let week_start = now.saturating_sub(7 * DAY_SECS);
+		let mut plays: Vec<_> = games
+			.iter()
+			.map(|(name, info)| {
+				let count = info.play_history.iter().filter(|&&time| time > week_start).count();
+				(name.clone(), count)
+			})
+			.filter(|(_, count)| *count > 0)
+			.collect();
+		plays.sort_by(|(name_a, count_a), (name_b, count_b)| {
+			count_b.cmp(count_a).then_with(|| name_a.cmp(name_b))
+		});
+		let records = games
+			.iter()
+			.filter_map(|(name, info)| {
+				let best = info
+					.score_history
+					.iter()
+					.filter(|record| record.time > week_start && record.mode == GameMode::Standard)
+					.map(|record| record.score)
+					.max()?;
+				Some((name.clone(), best))
+			})
+			.collect();
+
+		let days: BTreeSet<_> = games
+			.iter()
+			.flat_map(|(_, info)| info.play_history.iter().map(|&time| local_date(time)))
+			.collect();
+		let today = local_date(now);
+		let mut day = if days.contains(&today) { Some(today) } else { today.pred_opt() };
+		let mut streak = 0;
+		while let Some(current) = day.filter(|day| days.contains(day)) {
+			streak += 1;
+			day = current.pred_opt();
+		}
+		let mut runs: HashMap<NaiveDate, usize> = HashMap::new();
+		for day in &days {
+			let run = day.pred_opt().and_then(|previous| runs.get(&previous)).unwrap_or(&0) + 1;
+			runs.insert(*day, run);
+		}
+
+		Self {
+			start: local_date(week_start + DAY_SECS),
+			end: today,
+			plays,
+			records,
+			streak,
+			longest_streak: runs.into_values().max().unwrap_or_default(),
+		}
+	}
+
+	/// Returns the total plays of every game this week.
+	#[must_use]
+	pub fn total_plays(&self) -> usize {
+		self.plays.iter().map(|(_, count)| count).sum()
+	}
+
+	/// Returns the lines summing up the week: plays, most played game and
+	/// streak.
+	#[must_use]
+	pub fn summary_lines(&self) -> Vec<String> {
+		let mut lines = vec![t!(
+			"digest-period",
+			start = self.start.format("%d/%m/%Y").to_string(),
+			end = self.end.format("%d/%m/%Y").to_string()
+		)];
+		if let Some((game, count)) = self.plays.first() {
+			lines.push(t!("digest-plays", count = self.total_plays()));
+			lines.push(t!(
+				"digest-most-played",
+				game = game.as_str(),
+				count = *count
+			));
+		} else {
+			lines.push(t!("digest-no-plays").to_string());
+		}
+		lines.push(t!(
+			"digest-streak",
+			days = self.streak,
+			longest = self.longest_streak
+		));
+		lines
+	}
+
+	/// Returns a line for each game played this week.
+	#[must_use]
+	pub fn play_lines(&self) -> Vec<String> {
+		self.plays
+			.iter()
+			.map(|(game, count)| t!("digest-game-plays", game = game.as_str(), count = *count))
+			.collect()
+	}
+
+	/// Returns a line for each record set this week.
+	#[must_use]
+	pub fn record_lines(&self) -> Vec<String> {
+		if self.records.is_empty() {
+			return vec![t!("digest-no-records").to_string()];
+		}
+		self.records
+			.iter()
+			.map(|(game, score)| t!("digest-record", game = game.as_str(), score = *score))
+			.collect()
+	}
+
+	/// Formats the digest as a Markdown document.
+	#[must_use]
+	pub fn to_markdown(&self) -> String {
+		let list = |lines: Vec<String>| {
+			lines.into_iter().fold(String::new(), |list, line| {
+				list + "- " + line.as_str() + "\n"
+			})
+		};
+		format!(
+			"# {}\n\n{}\n## {}\n\n{}\n## {}\n\n{}",
+			t!("digest-title"),
+			list(self.summary_lines()),
+			t!("digest-games"),
+			list(self.play_lines()),
+			t!("digest-records"),
+			list(self.record_lines()),
+		)
+	}
+}
+
+/// Returns every game's name and metadata.
+fn games_info() -> Vec<(String, GameDynamicInfo)> {
+	Games::all()
+		.into_iter()
+		.map(|game| {
+			let metadata = game.data().metadata;
+			(metadata.static_info.name, metadata.dynamic_info)
+		})
+		.collect()
+}
+
+/// Remembers when the session started and every game's metadata then, for
+/// its [summary](SessionSummary).
+pub fn start_session() {
+	let _ = SESSION_START.set((get_unix_time_as_secs(), games_info()));
+}
+
+/// A best set during a session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SessionRecord {
+	/// A high score, in a mode.
+	Score(GameMode, i64),
+
+	/// A fastest time, in seconds.
+	Time(u64),
+}
+
+/// What the player did during a session, shown when Terminal Arcade quits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionSummary {
+	/// How long the session lasted.
+	pub duration: Duration,
+
+	/// Plays of every game during the session.
+	pub plays: usize,
+
+	/// Bests set during the session, by game: each mode's highest score, then
+	/// the fastest time.
+	pub records: Vec<(String, SessionRecord)>,
+}
+
+impl SessionSummary {
+	/// Computes the summary of the session [started](start_session) so far,
+	/// if it was.
+	#[must_use]
+	pub fn compute() -> Option<Self> {
+		let (start, before) = SESSION_START.get()?;
+		Some(Self::from_games(
+			before,
+			&games_info(),
+			*start,
+			get_unix_time_as_secs(),
+		))
+	}
+
+	/// Computes the summary of a session from `start` to `now`, from games'
+	/// names and metadata from before and after it.
+	#[must_use]
+	pub fn from_games(
+		before: &[(String, GameDynamicInfo)],
+		after: &[(String, GameDynamicInfo)],
+		start: u64,
+		now: u64,
+	) -> Self {
+		let mut plays = 0;
+		let mut records = Vec::new();
+		for (name, info) in after {
+			plays += info.play_history.iter().filter(|&&time| time >= start).count();
+			let mut scores: Vec<(GameMode, i64)> = Vec::new();
+			for record in info.score_history.iter().filter(|record| record.time >= start) {
+				match scores.iter_mut().find(|(mode, _)| *mode == record.mode) {
+					Some((_, best)) => *best = (*best).max(record.score),
+					None => scores.push((record.mode, record.score)),
+				}
+			}
+			records.extend(
+				scores
+					.into_iter()
+					.map(|(mode, score)| (name.clone(), SessionRecord::Score(mode, score))),
+			);
+			let best_time_before = before
+				.iter()
+				.find(|(other, _)| other == name)
+				.and_then(|(_, info)| info.best_time_secs);
+			if let Some(secs) =
+				info.best_time_secs.filter(|&secs| best_time_before.is_none_or(|best| secs < best))
+			{
+				records.push((name.clone(), SessionRecord::Time(secs)));
+			}
+		}
+		Self {
+			duration: Duration::from_secs(now.saturating_sub(start)),
+			plays,
+			records,
+		}
+	}
+
+	/// Returns the lines of the summary.
+	#[must_use]
+	pub fn lines(&self) -> Vec<String> {
+		let mut lines = vec![t!(
+			"summary-session",
+			duration = playtime_text(self.duration),
+			plays = self.plays
+		)];
+		lines.extend(self.records.iter().map(|(game, record)| match record {
+			SessionRecord::Score(GameMode::Standard, score) => {
+				t!("summary-score", game = game.as_str(), score = *score)
+			},
+			SessionRecord::Score(mode, score) => t!(
+				"summary-mode-score",
+				game = game.as_str(),
+				mode = mode.label(),
+				score = *score
+			),
+			SessionRecord::Time(secs) => {
+				t!(
+					"summary-time",
+					game = game.as_str(),
+					time = clock_text(*secs)
+				)
+			},
+		}));
+		lines
+	}
+}
+
+/// Returns how many plays of every game happened on the same local day as
+/// `now`.
+#[must_use]
+pub fn plays_on_day(games: &[GameDynamicInfo], now: u64) -> usize {
+	let today = local_date(now);
+	games
+		.iter()
+		.flat_map(|info| &info.play_history)
+		.filter(|&&time| local_date(time) == today)
+		.count()
+}
+
+/// Formats a playtime compactly, in hours and minutes, like `1h05` or `12m`.
+#[must_use]
+pub fn playtime_text(playtime: Duration) -> String {
+	let minutes = playtime.as_secs() / 60;
+	if minutes < 60 {
+		format!("{minutes}m")
+	} else {
+		format!("{}h{:02}", minutes / 60, minutes % 60)
+	}
+}
+
+/// The player's playtime and plays on a day.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct Today {
+	/// Local date the counters are for, like `2024-05-17`.
+	date: String,
+
+	/// Time spent playing games on that day, in milliseconds.
+	playtime_ms: u64,
+
+	/// Plays of every game on that day. They're counted from the games' play
+	/// histories, so they aren't saved.
+	#[serde(skip)]
+	plays: usize,
+}
+
+impl Today {
+	/// Loads today's counters, starting them over if they're for another day.
+	fn load(date: String) -> Self {
+		let saved = save_file::read(&get_save_dir().join(PLAYTIME_FILE))
+			.ok()
+			.and_then(|file| toml::from_str::<Self>(&file).ok())
+			.filter(|saved| saved.date == date);
+		let mut today = saved.unwrap_or(Self {
+			date,
+			..Self::default()
+		});
+		today.count_plays();
+		today
+	}
+
+	/// Counts today's plays again from every game's play history.
+	fn count_plays(&mut self) {
+		let games: Vec<_> =
+			Games::all().into_iter().map(|game| game.data().metadata.dynamic_info).collect();
+		self.plays = plays_on_day(&games, get_unix_time_as_secs());
+	}
+}
+
+/// Updates today's counters after a frame, adding its time to the playtime
+/// if a game was being played. The counters start over at midnight.
+pub fn tick_today(elapsed: Duration, playing: bool) {
+	let Ok(mut today) = TODAY.lock() else {
+		return;
+	};
+	let date = local_date(get_unix_time_as_secs()).to_string();
+	if today.as_ref().is_none_or(|today| today.date != date) {
+		*today = Some(Today::load(date));
+	}
+	if let Some(today) = today.as_mut().filter(|_| playing) {
+		let elapsed_ms = u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX);
+		today.playtime_ms = today.playtime_ms.saturating_add(elapsed_ms);
+	}
+}
+
+/// Counts today's plays again, like after a game started or ended.
+pub fn refresh_today() {
+	if let Some(today) = TODAY.lock().ok().as_deref_mut().and_then(Option::as_mut) {
+		today.count_plays();
+	}
+}
+
+/// Saves today's playtime, if it's been counted and the save data isn't
+/// locked.
+pub fn save_today() -> anyhow::Result<()> {
+	let Some(today) = TODAY.lock().ok().and_then(|today| today.clone()) else {
+		return Ok(());
+	};
+	// Locked save data can't be overwritten, and shouldn't be.
+	if save_file::is_locked() {
+		return Ok(());
+	}
+	let toml_string = toml::to_string_pretty(&today)?;
+	std::fs::create_dir_all(get_save_dir())?;
+	save_file::write(&get_save_dir().join(PLAYTIME_FILE), &toml_string)
+}
+
+/// Returns the text shown in the screen's border with today's playtime and
+/// plays, once the player played today.
+#[must_use]
+pub fn today_indicator() -> Option<String> {
+	let today = TODAY.lock().ok()?.clone()?;
+	let playtime = Duration::from_millis(today.playtime_ms);
+	(today.plays > 0 || playtime.as_secs() >= 60).then(|| {
+		t!(
+			"stats-today",
+			playtime = playtime_text(playtime),
+			plays = today.plays
+		)
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::Duration;
+
+	use super::{
+		plays_on_day,
+		playtime_text,
+		SessionRecord,
+		SessionSummary,
+		WeeklyDigest,
+		DAY_SECS,
+	};
+	use crate::games::{
+		modes::GameMode,
+		GameDynamicInfo,
+		ScoreRecord,
+	};
+
+	const NOW: u64 = 1_700_000_000;
+
+	fn game(name: &str, days_ago: &[u64], records: &[(u64, i64)]) -> (String, GameDynamicInfo) {
+		let info = GameDynamicInfo {
+			play_history: days_ago.iter().map(|days| NOW - days * DAY_SECS).collect(),
+			score_history: records
+				.iter()
+				.map(|&(days, score)| ScoreRecord::new(NOW - days * DAY_SECS, score))
+				.collect(),
+			..Default::default()
+		};
+		(name.to_string(), info)
+	}
+
+	#[test]
+	fn counts_this_weeks_plays_and_records() {
+		let digest = WeeklyDigest::from_games(
+			&[
+				game("Tron", &[0, 1, 1, 10], &[(12, 30)]),
+				game("Minesweeper", &[2, 3, 4], &[(3, 10), (2, 20)]),
+				game("Snake", &[9], &[]),
+			],
+			NOW,
+		);
+		assert_eq!(digest.plays, [
+			("Minesweeper".to_string(), 3),
+			("Tron".to_string(), 3)
+		]);
+		assert_eq!(digest.total_plays(), 6);
+		assert_eq!(digest.records, [("Minesweeper".to_string(), 20)]);
+	}
+
+	#[test]
+	fn counts_streaks() {
+		let digest = WeeklyDigest::from_games(
+			&[
+				game("Tron", &[1, 2, 20, 21, 22, 23], &[]),
+				game("Minesweeper", &[3], &[]),
+			],
+			NOW,
+		);
+		assert_eq!(digest.streak, 3);
+		assert_eq!(digest.longest_streak, 4);
+
+		let broken = WeeklyDigest::from_games(&[game("Tron", &[2, 3], &[])], NOW);
+		assert_eq!(broken.streak, 0);
+		assert_eq!(broken.longest_streak, 2);
+	}
+
+	#[test]
+	fn counts_todays_plays_and_formats_playtime() {
+		let games = [
+			game("Tron", &[0, 0, 1], &[]).1,
+			game("Snake", &[0, 7], &[]).1,
+		];
+		assert_eq!(plays_on_day(&games, NOW), 3);
+		assert_eq!(playtime_text(Duration::from_secs(59)), "0m");
+		assert_eq!(playtime_text(Duration::from_secs(12 * 60 + 30)), "12m");
+		assert_eq!(playtime_text(Duration::from_secs(3_930)), "1h05");
+	}
+
+	#[test]
+	fn sums_up_the_session() {
+		let start = NOW - DAY_SECS / 2;
+		let (name, mut before) = game("Tron", &[1], &[(1, 50)]);
+		before.best_time_secs = Some(90);
+		let mut after = before.clone();
+		after.play_history.extend([NOW - 60, NOW - 30]);
+		after.score_history.extend([
+			ScoreRecord::new(NOW - 60, 60),
+			ScoreRecord::new(NOW - 30, 70),
+			ScoreRecord {
+				mode: GameMode::Hardcore,
+				..ScoreRecord::new(NOW - 30, 20)
+			},
+		]);
+		after.best_time_secs = Some(80);
+		let summary = SessionSummary::from_games(
+			&[(name.clone(), before)],
+			&[(name.clone(), after), game("Snake", &[1], &[])],
+			start,
+			NOW,
+		);
+		assert_eq!(summary.plays, 2);
+		assert_eq!(summary.duration.as_secs(), DAY_SECS / 2);
+		assert_eq!(summary.records, [
+			(name.clone(), SessionRecord::Score(GameMode::Standard, 70)),
+			(name.clone(), SessionRecord::Score(GameMode::Hardcore, 20)),
+			(name, SessionRecord::Time(80)),
+		]);
+	}
+}
@@ -0,0 +1,532 @@
+//! Syncing of the player's [save files](crate::core::save_file) with a git
+//! repository of their own, for players on several computers. Opt-in through
+//! [`Config::sync_repository`].
+//!
+//! The repository is cloned in the save directory's [`SYNC_DIR`] folder, and
+//! [synced](sync_now) on startup, before any save file is read, and on exit,
+//! after the last one is written, so that syncing never races the arcade's
+//! own saves. Save files changed on this computer since the last sync are
+//! pushed, and those changed on the remote are pulled, keeping the time they
+//! were pushed at as their modification time. When both changed, the newest
+//! side wins, and the conflict is reported for the UI to
+//! [show](take_conflicts). Save files are synced as they are on disk, so
+//! encrypted ones stay encrypted.
+//!
+//! Save files deleted on this computer are deleted from the repository, and
+//! listed in its [`TOMBSTONES_FILE`] so that other computers delete them too
+//! rather than pushing them back.
+
+use std::{
+	collections::BTreeSet,
+	fs::{
+		self,
+		File,
+	},
+	path::{
+		Path,
+		PathBuf,
+	},
+	process::Command,
+	sync::Mutex,
+	time::Duration,
+};
+
+use anyhow::bail;
+use chrono::{
+	DateTime,
+	Local,
+};
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+use crate::{
+	core::{
+		config::Config,
+		get_save_dir,
+		profile::Profile,
+		save_file,
+		time::{
+			SystemTime,
+			UNIX_EPOCH,
+		},
+	},
+	services::backup::Backup,
+	t,
+};
+
+/// Name of the folder of the save directory that the repository is cloned in.
+pub const SYNC_DIR: &str = "sync";
+
+/// Name of the file of the repository listing the save files deleted.
+pub const TOMBSTONES_FILE: &str = "tombstones.toml";
+
+/// Branch save files are synced on.
+const BRANCH: &str = "main";
+
+/// Outcome of the last sync, shown in the settings.
+static STATUS: Mutex<Option<String>> = Mutex::new(None);
+
+/// Conflicts found while syncing, not shown yet.
+static CONFLICTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Gets the directory the repository is cloned in.
+#[must_use]
+pub fn sync_dir() -> PathBuf {
+	get_save_dir().join(SYNC_DIR)
+}
+
+/// Gets the path of the file keeping track of the last sync.
+#[must_use]
+pub fn state_file_path() -> PathBuf {
+	get_save_dir().join("sync_state.toml")
+}
+
+/// What's remembered of the last sync.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct SyncState {
+	/// The commit the save files were last synced with.
+	last_commit: Option<String>,
+}
+
+impl SyncState {
+	/// Loads the state, or returns a blank one if it could not be loaded.
+	fn load_or_default() -> Self {
+		fs::read_to_string(state_file_path())
+			.ok()
+			.and_then(|state| toml::from_str(&state).ok())
+			.unwrap_or_default()
+	}
+
+	/// Saves the state, in TOML format.
+	fn save(&self) -> anyhow::Result<()> {
+		Ok(fs::write(state_file_path(), toml::to_string_pretty(self)?)?)
+	}
+}
+
+/// Save files deleted on a computer, for the others to delete rather than
+/// push back. Save files written again are taken off the list. The list comes
+/// from the remote, so anything on it that isn't a
+/// [save file](save_file::is_save_file) is ignored.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct Tombstones {
+	/// Paths of the save files deleted, relative to the save directory.
+	deleted: BTreeSet<PathBuf>,
+}
+
+impl Tombstones {
+	/// Loads the list from the repository's working tree, without what isn't
+	/// a save file, or returns an empty one if it could not be loaded.
+	fn load_or_default() -> Self {
+		let mut tombstones: Self = fs::read_to_string(sync_dir().join(TOMBSTONES_FILE))
+			.ok()
+			.and_then(|tombstones| toml::from_str(&tombstones).ok())
+			.unwrap_or_default();
+		tombstones.deleted.retain(|file| save_file::is_save_file(file));
+		tombstones
+	}
+
+	/// Saves the list in the repository's working tree, in TOML format.
+	fn save(&self) -> anyhow::Result<()> {
+		Ok(fs::write(
+			sync_dir().join(TOMBSTONES_FILE),
+			toml::to_string_pretty(self)?,
+		)?)
+	}
+}
+
+/// What a sync did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncOutcome {
+	/// Nothing changed on either side.
+	UpToDate,
+
+	/// Changes made on this computer were pushed.
+	Pushed,
+
+	/// Changes made on the remote were pulled.
+	Pulled,
+
+	/// Both sides changed, and this computer's save files, being newer, were
+	/// pushed.
+	KeptLocal,
+
+	/// Both sides changed, and the remote's save files, being newer, were
+	/// pulled. This computer's were backed up first.
+	KeptRemote,
+}
+
+/// Runs git in the repository's directory, returning its output.
+fn git(args: &[&str]) -> anyhow::Result<String> {
+	let output = Command::new("git")
+		.arg("-C")
+		.arg(sync_dir())
+		.args(args)
+		.env("GIT_TERMINAL_PROMPT", "0")
+		.output()?;
+	if !output.status.success() {
+		bail!(
+			"git {} failed: {}",
+			args.first().unwrap_or(&""),
+			String::from_utf8_lossy(&output.stderr).trim()
+		);
+	}
+	Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Returns the save files' paths relative to the save directory.
+fn relative_save_files() -> anyhow::Result<Vec<PathBuf>> {
+	let save_dir = get_save_dir();
+	save_file::list()?
+		.into_iter()
+		.map(|path| Ok(path.strip_prefix(&save_dir)?.to_path_buf()))
+		.collect()
+}
+
+/// Copies files, relative to two directories, from one to the other.
+fn copy_files(files: &[PathBuf], from: &Path, to: &Path) -> anyhow::Result<()> {
+	for file in files {
+		let destination = to.join(file);
+		if let Some(parent) = destination.parent() {
+			fs::create_dir_all(parent)?;
+		}
+		fs::copy(from.join(file), destination)?;
+	}
+	Ok(())
+}
+
+/// Lists the save files in the repository's working tree. Anything else
+/// committed there, which no [save file](save_file::is_save_file) can be, is
+/// ignored.
+fn repository_files() -> anyhow::Result<Vec<PathBuf>> {
+	Ok(git(&["ls-files"])?
+		.lines()
+		.map(PathBuf::from)
+		.filter(|file| save_file::is_save_file(file))
+		.collect())
+}
+
+/// Lists the save files in the repository's working tree that aren't on this
+/// computer anymore.
+fn deleted_files(files: &[PathBuf]) -> anyhow::Result<Vec<PathBuf>> {
+	Ok(repository_files()?.into_iter().filter(|file| !files.contains(file)).collect())
+}
+
+/// Copies the save files of the repository's working tree to this computer,
+/// with the time they were last pushed at, and deletes those deleted on
+/// another computer.
+fn pull_files() -> anyhow::Result<()> {
+	let files = repository_files()?;
+	copy_files(&files, &sync_dir(), &get_save_dir())?;
+	for file in &files {
+		let pushed: u64 =
+			git(&["log", "-1", "--format=%ct", "--", &file.to_string_lossy()])?.parse()?;
+		File::options()
+			.write(true)
+			.open(get_save_dir().join(file))?
+			.set_modified(UNIX_EPOCH + Duration::from_secs(pushed))?;
+	}
+	for file in Tombstones::load_or_default().deleted {
+		let path = get_save_dir().join(file);
+		if path.exists() {
+			fs::remove_file(path)?;
+		}
+	}
+	// The profile may have been read before it was pulled.
+	Profile::forget();
+	Ok(())
+}
+
+/// Returns the UNIX timestamp of the last change to the save files on this
+/// computer.
+fn last_local_change(files: &[PathBuf]) -> u64 {
+	files
+		.iter()
+		.filter_map(|file| fs::metadata(get_save_dir().join(file)).ok()?.modified().ok())
+		.filter_map(|time| time.duration_since(UNIX_EPOCH).ok())
+		.map(|duration| duration.as_secs())
+		.max()
+		.unwrap_or_default()
+}
+
+/// Copies the save files of this computer to the repository's working tree,
+/// deleting those deleted since the last sync and listing them in the
+/// [tombstones](TOMBSTONES_FILE).
+fn stage_files(files: &[PathBuf], deleted: &[PathBuf]) -> anyhow::Result<()> {
+	copy_files(files, &get_save_dir(), &sync_dir())?;
+	let mut tombstones = Tombstones::load_or_default();
+	for file in deleted {
+		let path = sync_dir().join(file);
+		if path.exists() {
+			fs::remove_file(path)?;
+		}
+		tombstones.deleted.insert(file.clone());
+	}
+	tombstones.deleted.retain(|file| !files.contains(file));
+	if !tombstones.deleted.is_empty() || sync_dir().join(TOMBSTONES_FILE).exists() {
+		tombstones.save()?;
+	}
+	Ok(())
+}
+
+/// Commits the save files of this computer, with those deleted since the last
+/// sync, and pushes them.
+fn push(files: &[PathBuf], deleted: &[PathBuf]) -> anyhow::Result<()> {
+	stage_files(files, deleted)?;
+	git(&["add", "--all"])?;
+	if !git(&["status", "--porcelain"])?.is_empty() {
+		let message = format!("Sync from {}", Profile::current().name);
+		git(&["commit", "--quiet", "-m", &message])?;
+	}
+	git(&["push", "--quiet", "origin", &format!("HEAD:{BRANCH}")])?;
+	Ok(())
+}
+
+/// Syncs the save files with a repository once.
+pub fn sync(repository: &str) -> anyhow::Result<SyncOutcome> {
+	let dir = sync_dir();
+	if !dir.join(".git").exists() {
+		fs::create_dir_all(&dir)?;
+		git(&["init", "--quiet"])?;
+		git(&["remote", "add", "origin", repository])?;
+		// Commits are made by Terminal Arcade, whether or not git knows who
+		// the player is.
+		git(&["config", "user.name", "Terminal Arcade"])?;
+		git(&["config", "user.email", "terminal-arcade@localhost"])?;
+	}
+	git(&["remote", "set-url", "origin", repository])?;
+	let remote_commit = git(&["fetch", "--quiet", "origin", BRANCH])
+		.and_then(|_| git(&["rev-parse", "FETCH_HEAD"]))
+		.ok();
+	let mut state = SyncState::load_or_default();
+	let files = relative_save_files()?;
+
+	// Compares this computer's save files with those of the last sync.
+	let mut deleted = Vec::new();
+	let local_changed = if let Some(last_commit) = &state.last_commit {
+		git(&["checkout", "--quiet", "--force", last_commit])?;
+		deleted = deleted_files(&files)?;
+		stage_files(&files, &deleted)?;
+		!git(&["status", "--porcelain"])?.is_empty()
+	} else {
+		// On the first sync, this computer's save files are only pushed if
+		// the remote has none, since they're likely fresh ones.
+		remote_commit.is_none() && !files.is_empty()
+	};
+	let remote_changed = remote_commit.is_some() && remote_commit != state.last_commit;
+	if let Some(commit) = &remote_commit {
+		git(&["checkout", "--quiet", "--force", "-B", BRANCH, commit])?;
+	}
+
+	let first_sync = state.last_commit.is_none();
+	let pull = |backup_first: bool| -> anyhow::Result<()> {
+		if backup_first {
+			let _ = Backup::create()?;
+		}
+		pull_files()
+	};
+	let outcome = match (local_changed, remote_changed) {
+		(false, false) => SyncOutcome::UpToDate,
+		(true, false) => {
+			push(&files, &deleted)?;
+			SyncOutcome::Pushed
+		},
+		(false, true) => {
+			pull(first_sync && !files.is_empty())?;
+			SyncOutcome::Pulled
+		},
+		(true, true) => {
+			let remote_time: u64 = git(&["log", "-1", "--format=%ct"])?.parse()?;
+			if last_local_change(&files) >= remote_time {
+				push(&files, &deleted)?;
+				SyncOutcome::KeptLocal
+			} else {
+				pull(true)?;
+				SyncOutcome::KeptRemote
+			}
+		},
+	};
+	state.last_commit = git(&["rev-parse", "HEAD"]).ok();
+	state.save()?;
+	Ok(outcome)
+}
+
+/// Returns the outcome of the last sync, if any.
+#[must_use]
+pub fn status() -> Option<String> {
+	STATUS.lock().ok()?.clone()
+}
+
+/// Takes the conflicts found while syncing, to be shown to the player.
+#[must_use]
+pub fn take_conflicts() -> Vec<String> {
+	CONFLICTS.lock().map(|mut conflicts| std::mem::take(&mut *conflicts)).unwrap_or_default()
+}
+
+/// Records the outcome of a sync.
+fn record(result: anyhow::Result<SyncOutcome>) {
+	let time = DateTime::<Local>::from(SystemTime::now()).format("%H:%M").to_string();
+	let status = match result {
+		Ok(SyncOutcome::KeptLocal) => {
+			record_conflict(t!("sync-conflict-local").to_string());
+			t!("sync-synced", time = time)
+		},
+		Ok(SyncOutcome::KeptRemote) => {
+			record_conflict(t!("sync-conflict-remote").to_string());
+			t!("sync-synced", time = time)
+		},
+		Ok(_) => t!("sync-synced", time = time),
+		Err(error) => t!("sync-failed", time = time, error = error.to_string()),
+	};
+	if let Ok(mut current) = STATUS.lock() {
+		*current = Some(status);
+	}
+}
+
+/// Records a conflict to be shown.
+fn record_conflict(conflict: String) {
+	if let Ok(mut conflicts) = CONFLICTS.lock() {
+		conflicts.push(conflict);
+	}
+}
+
+/// Syncs the save files once if syncing is set up, printing the outcome.
+/// Run on startup and on exit, while the arcade isn't reading or writing
+/// save files.
+pub fn sync_now() {
+	let Some(repository) = Config::load_or_default().sync_repository else {
+		return;
+	};
+	println!("{}", t!("sync-syncing"));
+	record(sync(&repository));
+	if let Some(status) = status() {
+		println!("{status}");
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		fs,
+		path::PathBuf,
+		process::Command,
+		sync::mpsc,
+		thread,
+		time::UNIX_EPOCH,
+	};
+
+	use super::{
+		git,
+		pull_files,
+		sync,
+		sync_dir,
+		SyncOutcome,
+		Tombstones,
+	};
+	use crate::core::{
+		files,
+		get_save_dir,
+	};
+
+	/// A save file synced, relative to the save directory.
+	const FILE: &str = "games/snake.meta.toml";
+
+	#[test]
+	fn syncs_changes_and_deletions() {
+		let remote = tempfile::TempDir::new().unwrap();
+		let status =
+			Command::new("git").args(["init", "--quiet", "--bare"]).arg(remote.path()).status();
+		assert!(status.unwrap().success());
+		let repository = remote.path().to_string_lossy().to_string();
+
+		// The other computer syncs on its own thread, with its own files, and
+		// tells when the save file was last modified, if it's there.
+		let (sync_other, other_syncs) = mpsc::channel::<()>();
+		let (synced, other_synced) = mpsc::channel();
+		let other_repository = repository.clone();
+		let other = thread::spawn(move || {
+			files::isolate_test_files();
+			for () in other_syncs {
+				let outcome = sync(&other_repository).unwrap();
+				let modified =
+					fs::metadata(get_save_dir().join(FILE)).and_then(|file| file.modified());
+				let modified =
+					modified.ok().map(|time| time.duration_since(UNIX_EPOCH).unwrap().as_secs());
+				synced.send((outcome, modified)).unwrap();
+			}
+		});
+
+		files::isolate_test_files();
+		let path = get_save_dir().join(FILE);
+		fs::create_dir_all(path.parent().unwrap()).unwrap();
+		fs::write(&path, "play_count = 1").unwrap();
+		assert_eq!(sync(&repository).unwrap(), SyncOutcome::Pushed);
+		let pushed: u64 = git(&["log", "-1", "--format=%ct"]).unwrap().parse().unwrap();
+		sync_other.send(()).unwrap();
+		assert_eq!(
+			other_synced.recv().unwrap(),
+			(SyncOutcome::Pulled, Some(pushed))
+		);
+
+		fs::remove_file(&path).unwrap();
+		assert_eq!(sync(&repository).unwrap(), SyncOutcome::Pushed);
+		sync_other.send(()).unwrap();
+		assert_eq!(other_synced.recv().unwrap(), (SyncOutcome::Pulled, None));
+		assert_eq!(sync(&repository).unwrap(), SyncOutcome::UpToDate);
+
+		drop(sync_other);
+		other.join().unwrap();
+	}
+
+	#[test]
+	fn ignores_tombstones_outside_the_save_directory() {
+		files::isolate_test_files();
+		let save_dir = get_save_dir();
+		let outside = save_dir.parent().unwrap().join("outside.meta.toml");
+		let absolute = save_dir.parent().unwrap().join("absolute.meta.toml");
+		let deleted = save_dir.join(FILE);
+		fs::create_dir_all(deleted.parent().unwrap()).unwrap();
+		for path in [&outside, &absolute, &deleted] {
+			fs::write(path, "play_count = 1").unwrap();
+		}
+
+		fs::create_dir_all(sync_dir()).unwrap();
+		git(&["init", "--quiet"]).unwrap();
+		Tombstones {
+			deleted: [
+				PathBuf::from("../outside.meta.toml"),
+				PathBuf::from("games/../../outside.meta.toml"),
+				absolute.clone(),
+				PathBuf::from(FILE),
+			]
+			.into(),
+		}
+		.save()
+		.unwrap();
+		pull_files().unwrap();
+
+		assert!(outside.exists());
+		assert!(absolute.exists());
+		assert!(!deleted.exists());
+	}
+
+	#[test]
+	fn pulls_only_save_files() {
+		files::isolate_test_files();
+		fs::create_dir_all(sync_dir().join("games")).unwrap();
+		git(&["init", "--quiet"]).unwrap();
+		git(&["config", "user.name", "Terminal Arcade"]).unwrap();
+		git(&["config", "user.email", "terminal-arcade@localhost"]).unwrap();
+		for file in [FILE, "config.toml", "games/snake.sh"] {
+			fs::write(sync_dir().join(file), "play_count = 1").unwrap();
+		}
+		git(&["add", "--all"]).unwrap();
+		git(&["commit", "--quiet", "-m", "Sync"]).unwrap();
+		pull_files().unwrap();
+
+		assert!(get_save_dir().join(FILE).exists());
+		assert!(!get_save_dir().join("config.toml").exists());
+		assert!(!get_save_dir().join("games/snake.sh").exists());
+	}
+}
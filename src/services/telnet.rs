@@ -0,0 +1,555 @@
+//! Serves the arcade over telnet, or raw TCP, for retro setups like BBSes:
+//! every connection plays its own instance of the arcade, from the welcome
+//! screen, enabled with the `serve` command.
+//!
+//! ```text
+//! $ terminal-arcade serve --port 2323
+//! $ telnet localhost 2323
+//! ```
+//!
+//! Each connection is played in its own process, running the hidden
+//! `serve-connection` command over its standard input and output, which the
+//! server pipes to and from the connection. Connections don't share the random
+//! number generator, the session's statistics or anything else kept for the
+//! whole process, and one crashing leaves the others playing.
+//!
+//! That process runs its own stack of screens in a [harness](Harness), on a
+//! [backend](TelnetBackend) drawing to the connection, with the keys it
+//! receives [decoded](InputDecoder) into events. Telnet clients are asked to
+//! let the server echo and to report their window's size, which the arcade
+//! is redrawn at. With `--raw`, nothing is negotiated, for clients like `nc`
+//! talking raw TCP, and the arcade is drawn at [`DEFAULT_SIZE`].
+//!
+//! Every connection saves its games' results in the same save directory, so
+//! the best scores and times kept by each game make up a leaderboard shared by
+//! every player. Connections start from a [served](WelcomeScreen::served)
+//! welcome screen, so players can't change the server's settings or manage
+//! its backups and save data.
+
+use std::{
+	env,
+	io::{
+		self,
+		BufWriter,
+		Read,
+		Write,
+	},
+	net::{
+		Shutdown,
+		SocketAddr,
+		TcpListener,
+		TcpStream,
+	},
+	process::{
+		Command,
+		Stdio,
+	},
+	sync::{
+		atomic::{
+			AtomicBool,
+			AtomicUsize,
+			Ordering,
+		},
+		Arc,
+	},
+	thread,
+	time::Duration,
+};
+
+use crossterm::{
+	cursor::{
+		Hide,
+		Show,
+	},
+	event::{
+		Event,
+		KeyCode,
+		KeyEvent,
+		KeyModifiers,
+	},
+	execute,
+	terminal::{
+		Clear,
+		ClearType,
+		EnterAlternateScreen,
+		LeaveAlternateScreen,
+	},
+};
+use ratatui::{
+	backend::{
+		Backend,
+		ClearType as BackendClearType,
+		CrosstermBackend,
+		WindowSize,
+	},
+	buffer::Cell,
+	layout::{
+		Rect,
+		Size,
+	},
+	Terminal,
+};
+
+use crate::{
+	core::{
+		files,
+		harness::{
+			Harness,
+			HarnessBackend,
+		},
+		panics,
+	},
+	t,
+	ui::WelcomeScreen,
+};
+
+/// Port listened on by default, the usual alternative telnet port.
+pub const DEFAULT_PORT: u16 = 2323;
+
+/// Size the arcade is drawn at until the client reports its window's, as
+/// `(width, height)`.
+pub const DEFAULT_SIZE: (u16, u16) = (80, 24);
+
+/// Time between two frames of a connection.
+const FRAME_TIME: Duration = Duration::from_millis(33);
+
+/// Telnet's "interpret as command" byte, starting every command.
+const IAC: u8 = 255;
+
+/// Telnet command refusing an option the client offered.
+const DONT: u8 = 254;
+
+/// Telnet command asking the client to enable an option.
+const DO: u8 = 253;
+
+/// Telnet command offering to enable an option.
+const WILL: u8 = 251;
+
+/// Telnet command starting an option's subnegotiation.
+const SB: u8 = 250;
+
+/// Telnet command ending an option's subnegotiation.
+const SE: u8 = 240;
+
+/// Telnet option for the server echoing what's typed, rather than the client.
+const ECHO: u8 = 1;
+
+/// Telnet option for sending keys without waiting for a line.
+const SUPPRESS_GO_AHEAD: u8 = 3;
+
+/// Telnet option for the client reporting its window's size.
+const NAWS: u8 = 31;
+
+/// Commands sent to telnet clients once connected: the server echoes, keys
+/// are sent as they're typed, and the client reports its window's size.
+const NEGOTIATION: [u8; 9] = [IAC, WILL, ECHO, IAC, WILL, SUPPRESS_GO_AHEAD, IAC, DO, NAWS];
+
+/// Decodes the bytes received from a connection into key and resize events.
+/// Telnet commands are answered by nothing but the window sizes they report,
+/// and dropped.
+#[derive(Debug, Clone, Default)]
+#[must_use]
+pub struct InputDecoder {
+	/// Whether the connection talks raw TCP, without telnet commands.
+	raw: bool,
+
+	/// Bytes received that don't make up a whole key or command yet.
+	pending: Vec<u8>,
+
+	/// Whether the last key was a carriage return, which clients follow with a
+	/// line feed or a null byte that isn't another key.
+	after_carriage_return: bool,
+}
+
+impl InputDecoder {
+	/// Creates a decoder, for raw TCP or for telnet.
+	pub fn new(raw: bool) -> Self {
+		Self {
+			raw,
+			..Self::default()
+		}
+	}
+
+	/// Decodes bytes received, returning the events they complete. An escape
+	/// byte ending them is taken as \[Esc\], since clients send every other
+	/// sequence starting with it at once.
+	pub fn feed(&mut self, bytes: &[u8]) -> Vec<Event> {
+		self.pending.extend_from_slice(bytes);
+		let mut events = Vec::new();
+		let mut start = 0;
+		while start < self.pending.len() {
+			let rest = &self.pending[start..];
+			let Some((length, event)) = self.decode(rest).or_else(|| {
+				(rest == [0x1b]).then(|| (1, Some(key(KeyCode::Esc, KeyModifiers::NONE))))
+			}) else {
+				break;
+			};
+			let line_feed = matches!(rest[0], b'\n' | 0) && self.after_carriage_return;
+			self.after_carriage_return = rest[0] == b'\r';
+			if !line_feed {
+				events.extend(event);
+			}
+			start += length;
+		}
+		self.pending.drain(..start);
+		events
+	}
+
+	/// Decodes the first key or command of some bytes, returning how many
+	/// bytes it took and its event, if any, or [`None`] if it isn't whole.
+	fn decode(&self, bytes: &[u8]) -> Option<(usize, Option<Event>)> {
+		let none = KeyModifiers::NONE;
+		let decoded = match bytes[0] {
+			IAC if !self.raw => return decode_command(bytes),
+			b'\r' | b'\n' => (1, Some(key(KeyCode::Enter, none))),
+			0x7f | 0x08 => (1, Some(key(KeyCode::Backspace, none))),
+			b'\t' => (1, Some(key(KeyCode::Tab, none))),
+			0x1b => return decode_escape(bytes),
+			byte @ 0x01..=0x1a => {
+				let letter = char::from(byte - 1 + b'a');
+				(1, Some(key(KeyCode::Char(letter), KeyModifiers::CONTROL)))
+			},
+			byte @ 0x20..=0x7e => (1, Some(key(KeyCode::Char(char::from(byte)), none))),
+			byte => {
+				let length = match byte {
+					0xc0..=0xdf => 2,
+					0xe0..=0xef => 3,
+					0xf0..=0xf7 => 4,
+					_ => return Some((1, None)),
+				};
+				let char = std::str::from_utf8(bytes.get(..length)?)
+					.ok()
+					.and_then(|text| text.chars().next());
+				(length, char.map(|char| key(KeyCode::Char(char), none)))
+			},
+		};
+		Some(decoded)
+	}
+}
+
+/// Returns the event of a key pressed.
+fn key(code: KeyCode, modifiers: KeyModifiers) -> Event {
+	Event::Key(KeyEvent::new(code, modifiers))
+}
+
+/// Decodes a telnet command, returning the window size it reports, if any.
+fn decode_command(bytes: &[u8]) -> Option<(usize, Option<Event>)> {
+	match *bytes.get(1)? {
+		WILL..=DONT => {
+			bytes.get(2)?;
+			Some((3, None))
+		},
+		SB => {
+			let end = bytes.windows(2).position(|window| window == [IAC, SE])?;
+			let size = &bytes[2..end];
+			let event = (size.len() == 5 && size[0] == NAWS).then(|| {
+				let width = u16::from_be_bytes([size[1], size[2]]);
+				let height = u16::from_be_bytes([size[3], size[4]]);
+				Event::Resize(width, height)
+			});
+			Some((end + 2, event))
+		},
+		_ => Some((2, None)),
+	}
+}
+
+/// Decodes an escape sequence, like the arrow keys' or the function keys'.
+/// Sequences that aren't keys are dropped.
+fn decode_escape(bytes: &[u8]) -> Option<(usize, Option<Event>)> {
+	let none = KeyModifiers::NONE;
+	let code = match *bytes.get(1)? {
+		b'[' => {
+			let end = bytes[2..].iter().position(|byte| (0x40..=0x7e).contains(byte))? + 2;
+			let parameter = std::str::from_utf8(&bytes[2..end])
+				.ok()
+				.and_then(|parameter| parameter.parse::<u8>().ok());
+			let code = match (bytes[end], parameter) {
+				(b'A', _) => Some(KeyCode::Up),
+				(b'B', _) => Some(KeyCode::Down),
+				(b'C', _) => Some(KeyCode::Right),
+				(b'D', _) => Some(KeyCode::Left),
+				(b'H', _) | (b'~', Some(1 | 7)) => Some(KeyCode::Home),
+				(b'F', _) | (b'~', Some(4 | 8)) => Some(KeyCode::End),
+				(b'Z', _) => Some(KeyCode::BackTab),
+				(b'~', Some(2)) => Some(KeyCode::Insert),
+				(b'~', Some(3)) => Some(KeyCode::Delete),
+				(b'~', Some(5)) => Some(KeyCode::PageUp),
+				(b'~', Some(6)) => Some(KeyCode::PageDown),
+				(b'~', Some(number @ 11..=15)) => Some(KeyCode::F(number - 10)),
+				(b'~', Some(number @ 17..=21)) => Some(KeyCode::F(number - 11)),
+				(b'~', Some(number @ 23..=24)) => Some(KeyCode::F(number - 12)),
+				_ => None,
+			};
+			return Some((end + 1, code.map(|code| key(code, none))));
+		},
+		b'O' => match *bytes.get(2)? {
+			letter @ b'P'..=b'S' => KeyCode::F(letter - b'P' + 1),
+			b'A' => KeyCode::Up,
+			b'B' => KeyCode::Down,
+			b'C' => KeyCode::Right,
+			b'D' => KeyCode::Left,
+			b'H' => KeyCode::Home,
+			b'F' => KeyCode::End,
+			_ => return Some((3, None)),
+		},
+		_ => return Some((1, Some(key(KeyCode::Esc, none)))),
+	};
+	Some((3, Some(key(code, none))))
+}
+
+/// A backend drawing to a connection, sized by the window size the client
+/// reports rather than by the server's terminal.
+#[must_use]
+pub struct TelnetBackend<W: Write> {
+	/// Backend writing the frames' escape sequences to the connection.
+	inner: CrosstermBackend<W>,
+
+	/// Size of the client's window.
+	size: Rect,
+}
+
+impl<W: Write> TelnetBackend<W> {
+	/// Creates a backend writing to a connection, with a window of a size.
+	pub fn new(writer: W, (width, height): (u16, u16)) -> Self {
+		Self {
+			inner: CrosstermBackend::new(writer),
+			size: Rect::new(0, 0, width, height),
+		}
+	}
+}
+
+impl<W: Write> Backend for TelnetBackend<W> {
+	fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+	where
+		I: Iterator<Item = (u16, u16, &'a Cell)>,
+	{
+		self.inner.draw(content)
+	}
+
+	fn hide_cursor(&mut self) -> io::Result<()> {
+		self.inner.hide_cursor()
+	}
+
+	fn show_cursor(&mut self) -> io::Result<()> {
+		self.inner.show_cursor()
+	}
+
+	/// The client can't be asked where its cursor is, and the arcade only
+	/// draws on the whole window anyway.
+	fn get_cursor(&mut self) -> io::Result<(u16, u16)> {
+		Ok((0, 0))
+	}
+
+	fn set_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
+		self.inner.set_cursor(x, y)
+	}
+
+	fn clear(&mut self) -> io::Result<()> {
+		self.inner.clear()
+	}
+
+	fn clear_region(&mut self, clear_type: BackendClearType) -> io::Result<()> {
+		self.inner.clear_region(clear_type)
+	}
+
+	fn size(&self) -> io::Result<Rect> {
+		Ok(self.size)
+	}
+
+	fn window_size(&mut self) -> io::Result<WindowSize> {
+		Ok(WindowSize {
+			columns_rows: Size::new(self.size.width, self.size.height),
+			pixels: Size::default(),
+		})
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		Backend::flush(&mut self.inner)
+	}
+}
+
+impl<W: Write> HarnessBackend for TelnetBackend<W> {
+	fn resize(&mut self, width: u16, height: u16) {
+		self.size = Rect::new(0, 0, width, height);
+	}
+}
+
+/// A connection counted towards the most allowed at once, until dropped.
+struct ConnectionSlot(Arc<AtomicUsize>);
+
+impl Drop for ConnectionSlot {
+	fn drop(&mut self) {
+		self.0.fetch_sub(1, Ordering::Relaxed);
+	}
+}
+
+/// The telnet server. See the [module](self) documentation for more
+/// information.
+#[must_use]
+pub struct TelnetServer {
+	/// Listener accepting connections.
+	listener: TcpListener,
+
+	/// Whether connections talk raw TCP, without telnet commands.
+	raw: bool,
+
+	/// Most connections played at once.
+	max_connections: usize,
+
+	/// Connections being played.
+	connections: Arc<AtomicUsize>,
+}
+
+impl TelnetServer {
+	/// Listens on an address, for telnet clients or for raw TCP ones.
+	pub fn bind(address: SocketAddr, raw: bool, max_connections: usize) -> anyhow::Result<Self> {
+		Ok(Self {
+			listener: TcpListener::bind(address)?,
+			raw,
+			max_connections,
+			connections: Arc::default(),
+		})
+	}
+
+	/// Returns the address listened on.
+	pub fn local_addr(&self) -> io::Result<SocketAddr> {
+		self.listener.local_addr()
+	}
+
+	/// Accepts connections until the process ends, playing each in its own
+	/// process. Connections over the most allowed at once are told so and
+	/// closed.
+	pub fn serve(&self) {
+		for mut stream in self.listener.incoming().flatten() {
+			if self.connections.load(Ordering::Relaxed) >= self.max_connections {
+				let _ = write!(stream, "{}\r\n", t!("serve-full"));
+				continue;
+			}
+			self.connections.fetch_add(1, Ordering::Relaxed);
+			let slot = ConnectionSlot(Arc::clone(&self.connections));
+			let raw = self.raw;
+			thread::spawn(move || {
+				let _slot = slot;
+				let _ = serve_connection(&stream, raw);
+				let _ = stream.shutdown(Shutdown::Both);
+			});
+		}
+	}
+}
+
+/// Plays the arcade on a connection in a new process, running the hidden
+/// `serve-connection` command with the server's files, until it ends.
+fn serve_connection(stream: &TcpStream, raw: bool) -> anyhow::Result<()> {
+	stream.set_nodelay(true)?;
+	let app_files = files::app_files();
+	let mut command = Command::new(env::current_exe()?);
+	command
+		.arg("--data-dir")
+		.arg(&app_files.data_dir)
+		.arg("--config-dir")
+		.arg(&app_files.config_dir)
+		.arg("serve-connection");
+	if raw {
+		command.arg("--raw");
+	}
+	let mut child =
+		command.stdin(Stdio::piped()).stdout(Stdio::piped()).stderr(Stdio::null()).spawn()?;
+
+	// Closing the process's input once the client disconnects ends it.
+	let mut input = child.stdin.take().expect("the input is piped");
+	let mut reader = stream.try_clone()?;
+	thread::spawn(move || io::copy(&mut reader, &mut input));
+	let mut output = child.stdout.take().expect("the output is piped");
+	let _ = io::copy(&mut output, &mut stream.try_clone()?);
+	child.wait()?;
+	Ok(())
+}
+
+/// Plays the arcade for a connection over the standard input and output, in
+/// the process the [server](TelnetServer) runs for it, from the welcome screen
+/// until every screen is closed or the client disconnects. Screens' panics
+/// are [recorded](panics) rather than printed to the connection.
+pub fn play_stdio(raw: bool) -> anyhow::Result<()> {
+	panics::set_hook();
+	let mut output = BufWriter::new(io::stdout());
+	if !raw {
+		output.write_all(&NEGOTIATION)?;
+	}
+	execute!(output, EnterAlternateScreen, Hide, Clear(ClearType::All))?;
+	let backend = TelnetBackend::new(BufWriter::new(io::stdout()), DEFAULT_SIZE);
+	let mut harness =
+		Harness::with_terminal(WelcomeScreen::served().into(), Terminal::new(backend)?)?;
+
+	let connected = Arc::new(AtomicBool::new(true));
+	let events = harness.event_sender();
+	let mut reader = io::stdin();
+	let reader_connected = Arc::clone(&connected);
+	thread::spawn(move || {
+		let mut decoder = InputDecoder::new(raw);
+		let mut buffer = [0; 512];
+		while let Ok(read @ 1..) = reader.read(&mut buffer) {
+			for event in decoder.feed(&buffer[..read]) {
+				if events.send(event).is_err() {
+					return;
+				}
+			}
+		}
+		reader_connected.store(false, Ordering::Relaxed);
+	});
+
+	while connected.load(Ordering::Relaxed) && !harness.is_closed() {
+		harness.try_run_until_idle()?;
+		thread::sleep(FRAME_TIME);
+	}
+	execute!(output, Show, LeaveAlternateScreen)?;
+	write!(output, "{}\r\n", t!("goodbye"))?;
+	output.flush()?;
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use crossterm::event::{
+		Event,
+		KeyCode,
+		KeyEvent,
+		KeyModifiers,
+	};
+
+	use super::InputDecoder;
+
+	/// Returns the event of a key pressed without modifiers.
+	fn key(code: KeyCode) -> Event {
+		Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+	}
+
+	#[test]
+	fn keys_and_window_sizes_are_decoded() {
+		let mut decoder = InputDecoder::new(false);
+		let events =
+			decoder.feed(b"a\r\n\x1b[A\x1b[15~\xff\xfd\x01\xff\xfa\x1f\x00\x78\x00\x28\xff\xf0");
+		assert_eq!(events, [
+			key(KeyCode::Char('a')),
+			key(KeyCode::Enter),
+			key(KeyCode::Up),
+			key(KeyCode::F(5)),
+			Event::Resize(120, 40),
+		]);
+
+		assert_eq!(decoder.feed("é\x1b[".as_bytes()), [key(KeyCode::Char(
+			'é'
+		))]);
+		assert_eq!(decoder.feed(b"B\x1b"), [
+			key(KeyCode::Down),
+			key(KeyCode::Esc)
+		]);
+		assert_eq!(decoder.feed(b"\x08\x03"), [
+			key(KeyCode::Backspace),
+			Event::Key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+		]);
+
+		let mut raw = InputDecoder::new(true);
+		let events = raw.feed(b"\xff\xfa\x1f\x00\x78\x00\x28\xff\xf0");
+		assert!(!events.contains(&Event::Resize(120, 40)));
+	}
+}
@@ -0,0 +1,210 @@
+//! Undoing and redoing moves in puzzle games, shared between them instead of
+//! each game keeping its own history.
+//!
+//! Games describe their moves as [commands](Command) that can be applied to
+//! and reverted from their state, or as [snapshots](Snapshot) of their state
+//! when that's simpler, and keep an [`UndoStack`] of them. The stack
+//! forgets its oldest moves past its capacity, and can be
+//! [autosaved](crate::services::autosave) along with the game when its moves
+//! can be serialized.
+
+use std::collections::VecDeque;
+
+use serde_derive::{
+	Deserialize,
+	Serialize,
+};
+
+/// How many moves a stack remembers, unless created with another capacity.
+pub const DEFAULT_CAPACITY: usize = 100;
+
+/// A move in a game, which can be applied to the game's state and reverted
+/// from it.
+pub trait Command {
+	/// The game state the move is made on.
+	type Target: ?Sized;
+
+	/// Makes the move, or makes it again after it was undone.
+	fn apply(&self, target: &mut Self::Target);
+
+	/// Takes the move back.
+	fn revert(&self, target: &mut Self::Target);
+}
+
+/// A move remembered as the game state before and after it, for games whose
+/// moves are simpler to take back by restoring their whole state, like 2048's
+/// slides with their merges and new tiles.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot<T> {
+	/// The state before the move.
+	pub before: T,
+
+	/// The state after the move.
+	pub after: T,
+}
+
+impl<T: Clone> Command for Snapshot<T> {
+	type Target = T;
+
+	fn apply(&self, target: &mut T) {
+		target.clone_from(&self.after);
+	}
+
+	fn revert(&self, target: &mut T) {
+		target.clone_from(&self.before);
+	}
+}
+
+/// Moves made in a game, to undo, and moves undone, to redo. Making a new move
+/// forgets the moves undone.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[must_use]
+pub struct UndoStack<C> {
+	/// Moves made, from the oldest one remembered to the latest.
+	done: VecDeque<C>,
+
+	/// Moves undone, from the first one undone to the latest.
+	undone: Vec<C>,
+
+	/// How many moves are remembered.
+	capacity: usize,
+}
+
+impl<C: Command> Default for UndoStack<C> {
+	fn default() -> Self {
+		Self::new(DEFAULT_CAPACITY)
+	}
+}
+
+impl<C: Command> UndoStack<C> {
+	/// Creates an empty stack, remembering at most a number of moves.
+	pub fn new(capacity: usize) -> Self {
+		Self {
+			done: VecDeque::new(),
+			undone: Vec::new(),
+			capacity: capacity.max(1),
+		}
+	}
+
+	/// Makes a move, remembering it.
+	pub fn execute(&mut self, command: C, target: &mut C::Target) {
+		command.apply(target);
+		self.push(command);
+	}
+
+	/// Remembers a move the game already made, forgetting the oldest move if
+	/// the stack is full and the moves undone.
+	pub fn push(&mut self, command: C) {
+		self.undone.clear();
+		if self.done.len() == self.capacity {
+			self.done.pop_front();
+		}
+		self.done.push_back(command);
+	}
+
+	/// Takes the latest move back, returning whether there was one.
+	pub fn undo(&mut self, target: &mut C::Target) -> bool {
+		let Some(command) = self.done.pop_back() else {
+			return false;
+		};
+		command.revert(target);
+		self.undone.push(command);
+		true
+	}
+
+	/// Makes the latest move undone again, returning whether there was one.
+	pub fn redo(&mut self, target: &mut C::Target) -> bool {
+		let Some(command) = self.undone.pop() else {
+			return false;
+		};
+		command.apply(target);
+		self.done.push_back(command);
+		true
+	}
+
+	/// Returns whether there's a move to undo.
+	#[must_use]
+	pub fn can_undo(&self) -> bool {
+		!self.done.is_empty()
+	}
+
+	/// Returns whether there's a move to redo.
+	#[must_use]
+	pub fn can_redo(&self) -> bool {
+		!self.undone.is_empty()
+	}
+
+	/// Forgets every move, like when a game starts over.
+	pub fn clear(&mut self) {
+		self.done.clear();
+		self.undone.clear();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serde_derive::{
+		Deserialize,
+		Serialize,
+	};
+
+	use super::{
+		Command,
+		Snapshot,
+		UndoStack,
+	};
+
+	/// Adds to a number.
+	#[derive(Debug, Clone, Serialize, Deserialize)]
+	struct Add(i32);
+
+	impl Command for Add {
+		type Target = i32;
+
+		fn apply(&self, target: &mut i32) {
+			*target += self.0;
+		}
+
+		fn revert(&self, target: &mut i32) {
+			*target -= self.0;
+		}
+	}
+
+	#[test]
+	fn undoes_and_redoes_within_capacity() {
+		let mut total = 0;
+		let mut stack = UndoStack::new(2);
+		for number in 1..=3 {
+			stack.execute(Add(number), &mut total);
+		}
+		assert_eq!(total, 6);
+		assert!(stack.undo(&mut total));
+		assert!(stack.undo(&mut total));
+		assert!(!stack.undo(&mut total));
+		assert_eq!(total, 1);
+		assert!(stack.redo(&mut total));
+		assert_eq!(total, 3);
+		let json = serde_json::to_string(&stack).unwrap();
+		let mut stack: UndoStack<Add> = serde_json::from_str(&json).unwrap();
+		stack.execute(Add(10), &mut total);
+		assert!(!stack.can_redo());
+		assert!(stack.undo(&mut total));
+		assert!(stack.undo(&mut total));
+		assert_eq!(total, 1);
+	}
+
+	#[test]
+	fn restores_snapshots() {
+		let mut state = vec![1];
+		let mut stack = UndoStack::default();
+		stack.push(Snapshot {
+			before: state.clone(),
+			after: vec![1, 2],
+		});
+		state.push(2);
+		assert!(stack.undo(&mut state));
+		assert_eq!(state, [1]);
+		assert!(stack.redo(&mut state));
+		assert_eq!(state, [1, 2]);
+	}
+}
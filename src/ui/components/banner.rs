@@ -0,0 +1,96 @@
+//! Large banner text, rendered with the [bundled fonts](BUNDLED_FONTS) and
+//! scaled to fit the available width.
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Rect,
+	},
+	text::{
+		Line,
+		Text,
+	},
+	widgets::{
+		Block,
+		Paragraph,
+	},
+	Frame,
+};
+
+use crate::ui::widgets::figlet::{
+	FigletFont,
+	BUNDLED_FONTS,
+};
+
+/// Returns the largest bundled font that fits every line of some text within
+/// a width, or [`None`] if none of them fit.
+#[must_use]
+pub fn get_fitting_font(text: &str, width: u16) -> Option<&'static FigletFont> {
+	BUNDLED_FONTS
+		.iter()
+		.find(|font| text.lines().all(|line| font.width(line) <= usize::from(width)))
+}
+
+/// Renders lines of text in a font, separated by an empty line.
+fn render_lines<'a>(font: &FigletFont, lines: impl IntoIterator<Item = &'a str>) -> Text<'static> {
+	let mut banner = Text::default();
+	for (index, line) in lines.into_iter().enumerate() {
+		if index > 0 {
+			banner.lines.push(Line::default());
+		}
+		banner.lines.extend(font.render(line).into_iter().map(Line::from));
+	}
+	banner
+}
+
+/// Returns the text of a banner that fits within a width. Lines of the text are
+/// separated by an empty line, and the text is left as-is if no font fits.
+#[must_use]
+pub fn get_banner_text(text: &str, width: u16) -> Text<'static> {
+	let Some(font) = get_fitting_font(text, width) else {
+		return Text::from(text.to_string());
+	};
+	render_lines(font, text.lines())
+}
+
+/// Returns the height of the tallest bundled font, which is as tall as a line
+/// of [large text](get_large_text) gets.
+#[must_use]
+pub fn get_large_text_height() -> u16 {
+	BUNDLED_FONTS.iter().map(FigletFont::height).max().unwrap_or_default() as u16
+}
+
+/// Returns some text in the largest bundled font that fits within a size, for
+/// the large text mode. Symbols the font can't draw, like emojis, are left out,
+/// but [`None`] is returned if it can't draw a letter or digit, or if no font
+/// fits.
+#[must_use]
+pub fn get_large_text(text: &str, width: u16, height: u16) -> Option<Text<'static>> {
+	BUNDLED_FONTS.iter().find_map(|font| {
+		if text.chars().any(|char| char.is_alphanumeric() && !font.has_glyph(char)) {
+			return None;
+		}
+		let lines: Vec<_> = text
+			.lines()
+			.map(|line| line.trim_matches(|char: char| char == ' ' || !font.has_glyph(char)))
+			.collect();
+		let text_height = (lines.len() * (font.height() + 1)).saturating_sub(1);
+		let fits = text_height <= usize::from(height)
+			&& lines.iter().all(|line| font.width(line) <= usize::from(width));
+		fits.then(|| render_lines(font, lines))
+	})
+}
+
+/// Returns the height of a banner that fits within a width, without borders.
+#[must_use]
+pub fn get_banner_height(text: &str, width: u16) -> u16 {
+	get_banner_text(text, width).height() as u16
+}
+
+/// Renders a banner in a block, centered and scaled to fit the block's width.
+pub fn render_banner(frame: &mut Frame<'_>, area: Rect, text: &str, block: Block<'_>) {
+	let width = block.inner(area).width;
+	let banner =
+		Paragraph::new(get_banner_text(text, width)).alignment(Alignment::Center).block(block);
+	frame.render_widget(banner, area);
+}
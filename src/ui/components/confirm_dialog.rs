@@ -0,0 +1,87 @@
+//! A dialog drawn over a screen to confirm something that can't be undone,
+//! like deleting save data. Screens open one with
+//! [`ScreenState::ask_confirmation`] and get the player's answer in
+//! [`Screen::answer_confirmation`](crate::ui::Screen::answer_confirmation).
+//! Events go to the dialog while it's open.
+//!
+//! [`ScreenState::ask_confirmation`]: crate::ui::screens::ScreenState::ask_confirmation
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Constraint,
+		Direction,
+		Layout,
+		Rect,
+	},
+	widgets::{
+		Clear,
+		Paragraph,
+		Wrap,
+	},
+	Frame,
+};
+
+use crate::{
+	t,
+	ui::{
+		components::presets::{
+			highlight_block,
+			titled_ui_block,
+		},
+		text,
+	},
+};
+
+/// Width of the dialog, in columns.
+const DIALOG_WIDTH: u16 = 56;
+
+/// See the [module](self) documentation for more information.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct ConfirmDialog {
+	/// What's being confirmed.
+	message: String,
+}
+
+impl ConfirmDialog {
+	/// Creates a dialog confirming something.
+	pub fn new(message: impl Into<String>) -> Self {
+		Self {
+			message: message.into(),
+		}
+	}
+
+	/// Renders the dialog in the middle of an area.
+	pub fn render(&self, frame: &mut Frame<'_>, area: Rect) {
+		let width = DIALOG_WIDTH.min(area.width);
+		let lines = text::wrap(&self.message, usize::from(width.saturating_sub(4))).len();
+		let message_height = u16::try_from(lines).unwrap_or(u16::MAX);
+		let height = message_height.saturating_add(4).min(area.height);
+		let dialog = Rect::new(
+			area.x + (area.width - width) / 2,
+			area.y + (area.height - height) / 2,
+			width,
+			height,
+		);
+		let block = highlight_block(titled_ui_block(t!("confirm-title")));
+		let inner = block.inner(dialog);
+		frame.render_widget(Clear, dialog);
+		frame.render_widget(block, dialog);
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.horizontal_margin(1)
+			.constraints([
+				Constraint::Min(0),
+				Constraint::Length(1),
+				Constraint::Length(1),
+			])
+			.split(inner);
+		let message = Paragraph::new(self.message.as_str())
+			.alignment(Alignment::Center)
+			.wrap(Wrap { trim: true });
+		frame.render_widget(message, chunks[0]);
+		let answers = Paragraph::new(t!("confirm-answers")).alignment(Alignment::Center);
+		frame.render_widget(answers, chunks[2]);
+	}
+}
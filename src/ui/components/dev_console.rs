@@ -0,0 +1,126 @@
+//! The developer console, drawn at the bottom of a game's screen with the
+//! `dev-console` feature. Commands typed in it, like `score 100`, change the
+//! game's state through [`Screen::dev_command`], to try out parts of a game
+//! without playing up to them. It's opened and closed with
+//! [`Action::DevConsole`] (\[F10\]) on screens whose game has
+//! [commands](Screen::dev_commands), and keeps the game paused while it's
+//! open.
+//!
+//! [`Screen::dev_command`]: crate::ui::Screen::dev_command
+//! [`Screen::dev_commands`]: crate::ui::Screen::dev_commands
+//! [`Action::DevConsole`]: crate::core::actions::Action::DevConsole
+
+use std::str::FromStr;
+
+use anyhow::anyhow;
+use ratatui::{
+	layout::Rect,
+	style::{
+		Color,
+		Style,
+	},
+	text::Line,
+	widgets::{
+		Clear,
+		Paragraph,
+	},
+	Frame,
+};
+
+use crate::{
+	t,
+	ui::components::presets::titled_ui_block,
+};
+
+/// How many lines of output the console shows, the latest last.
+const OUTPUT_LINES: usize = 6;
+
+/// A developer console command, as its usage and what it does, like
+/// `("score <points>", "Adds points to the score")`.
+pub type DevCommand = (&'static str, &'static str);
+
+/// Splits a command line into the command's name and its arguments, unless
+/// it's blank.
+#[must_use]
+pub fn parse(line: &str) -> Option<(&str, Vec<&str>)> {
+	let mut words = line.split_whitespace();
+	Some((words.next()?, words.collect()))
+}
+
+/// Parses a command's argument, failing with the command's usage if it's
+/// missing or malformed.
+pub fn arg<T: FromStr>(args: &[&str], index: usize, usage: &str) -> anyhow::Result<T> {
+	args.get(index)
+		.and_then(|arg| arg.parse().ok())
+		.ok_or_else(|| anyhow!(t!("dev-console-usage", usage = usage)))
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[must_use]
+pub struct DevConsole {
+	/// The command line being typed.
+	pub input: String,
+
+	/// The output of the commands run, and whether each line is an error.
+	output: Vec<(String, bool)>,
+}
+
+impl DevConsole {
+	/// Prints text in the console, one line at a time, keeping the last
+	/// [lines](OUTPUT_LINES).
+	pub fn print(&mut self, text: &str, error: bool) {
+		self.output.extend(text.lines().map(|line| (line.to_string(), error)));
+		let extra = self.output.len().saturating_sub(OUTPUT_LINES);
+		self.output.drain(..extra);
+	}
+
+	/// Renders the console along the bottom of an area.
+	pub fn render(&self, frame: &mut Frame<'_>, area: Rect) {
+		let height = (OUTPUT_LINES as u16 + 3).min(area.height);
+		let console = Rect::new(area.x, area.bottom() - height, area.width, height);
+		let mut lines: Vec<_> = self
+			.output
+			.iter()
+			.map(|(line, error)| {
+				let color = if *error { Color::Red } else { Color::Gray };
+				Line::styled(line.as_str(), Style::new().fg(color))
+			})
+			.collect();
+		lines.resize(OUTPUT_LINES, Line::default());
+		lines.push(Line::styled(
+			format!("> {}▏", self.input),
+			Style::new().fg(Color::White),
+		));
+		let block = titled_ui_block(t!("dev-console-title"));
+		frame.render_widget(Clear, console);
+		frame.render_widget(Paragraph::new(lines).block(block), console);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		parse,
+		DevConsole,
+		OUTPUT_LINES,
+	};
+
+	#[test]
+	fn commands_are_split_into_words() {
+		assert_eq!(
+			parse("  tile 1  2 2048 "),
+			Some(("tile", vec!["1", "2", "2048"]))
+		);
+		assert_eq!(parse("   "), None);
+
+		let mut console = DevConsole::default();
+		console.print(&"line\n".repeat(OUTPUT_LINES), false);
+		console.print("unknown command", true);
+		assert_eq!(console.output.len(), OUTPUT_LINES);
+		assert_eq!(
+			console.output.last(),
+			Some(&("unknown command".to_string(), true))
+		);
+	}
+}
@@ -1,7 +1,6 @@
 //! A search bar with a back "button" (in actuality it's just help text)
 //! and another row with the help text for the random selection function.
 
-use crossterm::style::Attribute;
 use ratatui::{
 	layout::{
 		Alignment,
@@ -15,9 +14,12 @@ use ratatui::{
 	Frame,
 };
 
-use crate::ui::components::presets::{
-	titled_ui_block,
-	untitled_ui_block,
+use crate::{
+	t,
+	ui::components::presets::{
+		titled_ui_block,
+		untitled_ui_block,
+	},
 };
 
 #[must_use]
@@ -32,8 +34,16 @@ fn search_section_layout() -> Layout {
 		])
 }
 
-/// Renders the top row of the search bar section.
-pub fn render_search_bar_top_row(frame: &mut Frame<'_>, size: Rect, search_term: Option<&str>) {
+/// Renders the top row of the search bar section, with the difficulty filter
+/// and sort after the search bar. An empty search bar tells about the most
+/// recent search, if any.
+pub fn render_search_bar_top_row(
+	frame: &mut Frame<'_>,
+	size: Rect,
+	search_term: Option<&str>,
+	recent_search: Option<&str>,
+	difficulty: &str,
+) {
 	let chunks = Layout::default()
 		.direction(Direction::Horizontal)
 		.margin(0)
@@ -41,24 +51,44 @@ pub fn render_search_bar_top_row(frame: &mut Frame<'_>, size: Rect, search_term:
 			Constraint::Max(13),   // Back button (does nothing)
 			Constraint::Length(1), // Space between widgets
 			Constraint::Min(1),    // Search area
+			Constraint::Length(1), // Space between widgets
+			Constraint::Max(30),   // Difficulty filter and sort
 		])
 		.horizontal_margin(1)
 		.split(size);
 
 	let back_button =
-		Paragraph::new("⏪ Back").alignment(Alignment::Center).block(untitled_ui_block());
+		Paragraph::new(t!("search-back")).alignment(Alignment::Center).block(untitled_ui_block());
 	frame.render_widget(back_button, chunks[0]);
 
-	let search_bar_text = format!(
-		"🔎︎ {}",
-		search_term.map_or_else(|| "Search...".to_string(), |term| format!("{term}█"),)
-	);
+	let search_bar_text = format!("🔎︎ {}", match (search_term, recent_search) {
+		(Some(term), _) => format!("{term}█"),
+		(None, Some(recent)) => t!("search-placeholder-recent", term = recent),
+		(None, None) => t!("search-placeholder").to_string(),
+	});
 	let search_bar =
 		Paragraph::new(search_bar_text).alignment(Alignment::Left).block(untitled_ui_block());
 	frame.render_widget(search_bar, chunks[2]);
+
+	let difficulty_box = Paragraph::new(difficulty)
+		.alignment(Alignment::Center)
+		.block(titled_ui_block(t!("search-difficulty")));
+	frame.render_widget(difficulty_box, chunks[4]);
 }
 
 /// Renders the search section.
-pub fn render_search_section(frame: &mut Frame<'_>, size: Rect, search_term: Option<&str>) {
-	render_search_bar_top_row(frame, search_section_layout().split(size)[0], search_term);
+pub fn render_search_section(
+	frame: &mut Frame<'_>,
+	size: Rect,
+	search_term: Option<&str>,
+	recent_search: Option<&str>,
+	difficulty: &str,
+) {
+	render_search_bar_top_row(
+		frame,
+		search_section_layout().split(size)[0],
+		search_term,
+		recent_search,
+		difficulty,
+	);
 }
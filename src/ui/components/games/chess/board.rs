@@ -0,0 +1,150 @@
+//! Renders a chess [board](Board), White's side at the bottom, with a
+//! cursor, the piece picked up and where it can move, and the last move
+//! played highlighted.
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Rect,
+	},
+	style::{
+		Color,
+		Modifier,
+		Style,
+	},
+	text::{
+		Line,
+		Span,
+	},
+	widgets::{
+		Padding,
+		Paragraph,
+	},
+	Frame,
+};
+
+use crate::{
+	games::chess::board::{
+		Board,
+		Move,
+		Square,
+		SIZE,
+	},
+	ui::{
+		color_scheme::{
+			FRENCH_VIOLET,
+			GHOST_WHITE,
+			SUNGLOW,
+			THISTLE,
+			TROPICAL_INDIGO,
+		},
+		components::presets::untitled_ui_block,
+	},
+};
+
+/// Width of a square, in columns.
+const SQUARE_WIDTH: usize = 3;
+
+/// Width of the rank labels left of the board, in columns.
+const LABEL_WIDTH: usize = 2;
+
+/// Width of the board on the terminal, labels and borders included.
+const BOARD_WIDTH: u16 = (LABEL_WIDTH + SIZE * SQUARE_WIDTH + 2) as u16;
+
+/// Height of the board on the terminal, labels and borders included.
+const BOARD_HEIGHT: u16 = (SIZE + 1 + 2) as u16;
+
+/// Style of the rank and file labels.
+const LABEL_STYLE: Style = Style::new().fg(Color::DarkGray);
+
+/// What to highlight on a board besides its pieces.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BoardHighlights<'a> {
+	/// The square under the cursor.
+	pub cursor: Square,
+
+	/// The square of the piece picked up, if any.
+	pub selected: Option<Square>,
+
+	/// The moves the piece picked up can play.
+	pub targets: &'a [Move],
+
+	/// The last move played, if any.
+	pub last_move: Option<Move>,
+}
+
+/// Returns the text and style of a square.
+fn square_span(board: &Board, square: Square, highlights: &BoardHighlights<'_>) -> Span<'static> {
+	let light = (square.0 + square.1) % 2 == 1;
+	let mut style =
+		Style::new().fg(Color::Black).bg(if light { GHOST_WHITE } else { TROPICAL_INDIGO });
+	if highlights.last_move.is_some_and(|mv| mv.from == square || mv.to == square) {
+		style = style.bg(THISTLE);
+	}
+	if highlights.selected == Some(square) {
+		style = style.bg(SUNGLOW);
+	}
+	let target = highlights.targets.iter().any(|mv| mv.to == square);
+	let text = match board.get(square) {
+		Some(piece) => {
+			if target {
+				style = style.bg(Color::LightRed);
+			}
+			piece.symbol().to_string()
+		},
+		None if target => {
+			style = style.fg(FRENCH_VIOLET).add_modifier(Modifier::BOLD);
+			"•".to_string()
+		},
+		None => " ".to_string(),
+	};
+	if highlights.cursor == square {
+		style = style.add_modifier(Modifier::REVERSED);
+	}
+	Span::styled(format!("{text:^SQUARE_WIDTH$}"), style)
+}
+
+/// Returns the area that a board takes up on the terminal when centered in an
+/// area, labels and borders included.
+#[must_use]
+pub fn board_area(bounds: Rect) -> Rect {
+	let width = BOARD_WIDTH.min(bounds.width);
+	let height = BOARD_HEIGHT.min(bounds.height);
+	Rect::new(
+		bounds.x + (bounds.width - width) / 2,
+		bounds.y + (bounds.height - height) / 2,
+		width,
+		height,
+	)
+}
+
+/// Renders a board centered in an area, with its highlights. The square under
+/// the cursor is displayed reversed.
+pub fn render_board(
+	frame: &mut Frame<'_>,
+	bounds: Rect,
+	board: &Board,
+	highlights: &BoardHighlights<'_>,
+) {
+	let mut lines = Vec::new();
+	for rank in (0..SIZE).rev() {
+		let mut spans = vec![Span::styled(
+			format!("{:<LABEL_WIDTH$}", rank + 1),
+			LABEL_STYLE,
+		)];
+		spans.extend((0..SIZE).map(|file| square_span(board, (file, rank), highlights)));
+		lines.push(Line::from(spans));
+	}
+	let files = (b'a'..b'a' + SIZE as u8)
+		.map(|file| format!("{:^SQUARE_WIDTH$}", char::from(file)))
+		.collect::<Vec<_>>()
+		.concat();
+	lines.push(Line::styled(
+		format!("{:LABEL_WIDTH$}{files}", ""),
+		LABEL_STYLE,
+	));
+	let board_widget = Paragraph::new(lines)
+		.alignment(Alignment::Left)
+		.block(untitled_ui_block().padding(Padding::zero()));
+	frame.render_widget(board_widget, board_area(bounds));
+}
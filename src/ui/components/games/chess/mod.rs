@@ -0,0 +1,3 @@
+//! Components for the Chess game UI.
+
+pub mod board;
@@ -0,0 +1,3 @@
+//! Components for the Klondike game UI.
+
+pub mod table;
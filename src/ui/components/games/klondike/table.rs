@@ -0,0 +1,177 @@
+//! Renders a game of Klondike laid out on the [table](Table): the stock,
+//! waste and foundations in a row at the top, and the tableau columns below,
+//! with the cards under the cursor and the cards picked up highlighted.
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Rect,
+	},
+	style::{
+		Color,
+		Modifier,
+		Style,
+	},
+	text::{
+		Line,
+		Span,
+	},
+	widgets::{
+		Padding,
+		Paragraph,
+	},
+	Frame,
+};
+
+use crate::{
+	games::klondike::{
+		cards::Card,
+		table::{
+			Pile,
+			Table,
+			COLUMNS,
+			FOUNDATIONS,
+		},
+	},
+	ui::{
+		color_scheme::{
+			GHOST_WHITE,
+			SUNGLOW,
+			TROPICAL_INDIGO,
+		},
+		components::presets::untitled_ui_block,
+	},
+};
+
+/// Width of a card, in columns.
+const CARD_WIDTH: usize = 5;
+
+/// Width of the table on the terminal, gaps and borders included.
+const TABLE_WIDTH: u16 = (COLUMNS * (CARD_WIDTH + 1) - 1 + 2) as u16;
+
+/// Style of the place of an empty pile.
+const EMPTY_STYLE: Style = Style::new().fg(Color::DarkGray);
+
+/// What to highlight on a table besides its cards.
+#[derive(Debug, Clone, Copy)]
+pub struct TableHighlights {
+	/// The pile under the cursor, and how many of its top cards.
+	pub cursor: (Pile, usize),
+
+	/// The pile cards were picked up from, and how many, if any.
+	pub held: Option<(Pile, usize)>,
+}
+
+impl TableHighlights {
+	/// Returns the style of the card at an index of a pile that has a number
+	/// of cards, on top of its own style.
+	fn style(&self, pile: Pile, index: usize, len: usize, style: Style) -> Style {
+		let from_top = len.saturating_sub(index);
+		let mut style = style;
+		if self.held.is_some_and(|(held, count)| held == pile && from_top <= count) {
+			style = style.bg(SUNGLOW);
+		}
+		let (cursor, depth) = self.cursor;
+		if cursor == pile && from_top <= depth.max(1) {
+			style = style.add_modifier(Modifier::REVERSED);
+		}
+		style
+	}
+}
+
+/// Returns the text and style of a card, or of the place of an empty pile.
+fn card_span(card: Option<Card>) -> Span<'static> {
+	match card {
+		Some(card) if card.face_up => {
+			let color = if card.is_red() { Color::Red } else { Color::Black };
+			Span::styled(
+				format!("{:^CARD_WIDTH$}", card.name()),
+				Style::new().fg(color).bg(GHOST_WHITE).add_modifier(Modifier::BOLD),
+			)
+		},
+		Some(_) => Span::styled("░".repeat(CARD_WIDTH), Style::new().fg(TROPICAL_INDIGO)),
+		None => Span::styled(format!("{:^CARD_WIDTH$}", "·"), EMPTY_STYLE),
+	}
+}
+
+/// Returns the span of the top card of a pile, highlighted.
+fn top_span(table: &Table, pile: Pile, highlights: &TableHighlights) -> Span<'static> {
+	let cards = table.pile(pile);
+	let span = card_span(cards.last().copied());
+	let style = highlights.style(
+		pile,
+		cards.len().saturating_sub(1),
+		cards.len().max(1),
+		span.style,
+	);
+	span.style(style)
+}
+
+/// Returns the area that a table takes up on the terminal when centered
+/// horizontally at the top of an area, gaps and borders included.
+#[must_use]
+pub fn table_area(bounds: Rect, table: &Table) -> Rect {
+	let rows = (0..COLUMNS)
+		.map(|column| table.pile(Pile::Tableau(column)).len())
+		.max()
+		.unwrap_or(0)
+		.max(1);
+	let width = TABLE_WIDTH.min(bounds.width);
+	let height = (rows as u16 + 2 + 2).min(bounds.height);
+	Rect::new(
+		bounds.x + (bounds.width - width) / 2,
+		bounds.y,
+		width,
+		height,
+	)
+}
+
+/// Renders a table centered horizontally at the top of an area, with its
+/// highlights. The cards under the cursor are displayed reversed.
+pub fn render_table(
+	frame: &mut Frame<'_>,
+	bounds: Rect,
+	table: &Table,
+	highlights: &TableHighlights,
+) {
+	let gap = || Span::raw(" ");
+	let blank = || Span::raw(" ".repeat(CARD_WIDTH));
+	let mut top_row = vec![
+		top_span(table, Pile::Stock, highlights),
+		gap(),
+		top_span(table, Pile::Waste, highlights),
+		gap(),
+		blank(),
+	];
+	for index in 0..FOUNDATIONS {
+		top_row.push(gap());
+		top_row.push(top_span(table, Pile::Foundation(index), highlights));
+	}
+	let mut lines = vec![Line::from(top_row), Line::default()];
+
+	let rows = table_area(bounds, table).height.saturating_sub(4) as usize;
+	for row in 0..rows {
+		let mut spans = Vec::new();
+		for column in 0..COLUMNS {
+			if column > 0 {
+				spans.push(gap());
+			}
+			let pile = Pile::Tableau(column);
+			let cards = table.pile(pile);
+			spans.push(match cards.get(row) {
+				Some(&card) => {
+					let span = card_span(Some(card));
+					let style = highlights.style(pile, row, cards.len(), span.style);
+					span.style(style)
+				},
+				None if row == 0 => top_span(table, pile, highlights),
+				None => blank(),
+			});
+		}
+		lines.push(Line::from(spans));
+	}
+	let table_widget = Paragraph::new(lines)
+		.alignment(Alignment::Left)
+		.block(untitled_ui_block().padding(Padding::zero()));
+	frame.render_widget(table_widget, table_area(bounds, table));
+}
@@ -0,0 +1,126 @@
+//! Renders a Minesweeper [board](Board), with a cursor and an optionally
+//! highlighted region.
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Rect,
+	},
+	style::{
+		Color,
+		Modifier,
+		Style,
+	},
+	text::{
+		Line,
+		Span,
+	},
+	widgets::{
+		Padding,
+		Paragraph,
+	},
+	Frame,
+};
+
+use crate::{
+	games::minesweeper::board::{
+		Board,
+		Position,
+		TileState,
+	},
+	ui::{
+		color_scheme::FRENCH_VIOLET,
+		components::presets::untitled_ui_block,
+	},
+};
+
+/// Width of a tile on the terminal, in columns.
+pub const TILE_WIDTH: u16 = 3;
+
+/// Returns the color of a neighboring mines count.
+fn count_color(count: u8) -> Color {
+	match count {
+		1 => Color::LightBlue,
+		2 => Color::LightGreen,
+		3 => Color::LightRed,
+		4 => Color::LightMagenta,
+		5 => Color::Yellow,
+		6 => Color::LightCyan,
+		7 => Color::White,
+		_ => Color::Gray,
+	}
+}
+
+/// Returns the text and style of the tile at a position.
+fn tile_span(board: &Board, position: Position) -> (String, Style) {
+	let tile = board.get(position);
+	let reveal_mine = tile.mine && board.is_exploded();
+	match tile.state {
+		TileState::Flagged => (" ⚑ ".to_string(), Style::new().fg(Color::LightRed)),
+		TileState::Covered if reveal_mine => (" ✸ ".to_string(), Style::new().fg(Color::Red)),
+		TileState::Covered => (" ■ ".to_string(), Style::new().fg(Color::DarkGray)),
+		TileState::Uncovered if tile.mine => (
+			" ✸ ".to_string(),
+			Style::new().fg(Color::White).bg(Color::Red),
+		),
+		TileState::Uncovered if tile.neighboring_mines == 0 => ("   ".to_string(), Style::new()),
+		TileState::Uncovered => (
+			format!(" {} ", tile.neighboring_mines),
+			Style::new().fg(count_color(tile.neighboring_mines)).add_modifier(Modifier::BOLD),
+		),
+	}
+}
+
+/// Returns whether a position is inside a region, in tiles.
+fn is_in_region((x, y): Position, region: Rect) -> bool {
+	let (x, y) = (x as u16, y as u16);
+	x >= region.x && x < region.right() && y >= region.y && y < region.bottom()
+}
+
+/// Returns the area that a board takes up on the terminal when centered in
+/// an area, including its borders.
+#[must_use]
+pub fn board_area(board: &Board, area: Rect) -> Rect {
+	let width = (board.width() as u16 * TILE_WIDTH + 2).min(area.width);
+	let height = (board.height() as u16 + 2).min(area.height);
+	Rect {
+		x: area.x + (area.width - width) / 2,
+		y: area.y + (area.height - height) / 2,
+		width,
+		height,
+	}
+}
+
+/// Renders a board centered in an area. The tile under the cursor is
+/// displayed reversed, and tiles inside the highlighted region (in tiles) are
+/// displayed with a colored background.
+pub fn render_board(
+	frame: &mut Frame<'_>,
+	area: Rect,
+	board: &Board,
+	cursor: Option<Position>,
+	highlight: Option<Rect>,
+) {
+	let lines: Vec<Line<'_>> = (0..board.height())
+		.map(|y| {
+			Line::from(
+				(0..board.width())
+					.map(|x| {
+						let (text, mut style) = tile_span(board, (x, y));
+						if highlight.is_some_and(|region| is_in_region((x, y), region)) {
+							style = style.bg(FRENCH_VIOLET);
+						}
+						if cursor == Some((x, y)) {
+							style = style.add_modifier(Modifier::REVERSED);
+						}
+						Span::styled(text, style)
+					})
+					.collect::<Vec<_>>(),
+			)
+		})
+		.collect();
+	let board_paragraph = Paragraph::new(lines)
+		.alignment(Alignment::Left)
+		.block(untitled_ui_block().padding(Padding::zero()));
+	frame.render_widget(board_paragraph, board_area(board, area));
+}
@@ -1 +1,3 @@
 //! Components for the Minesweeper game UI.
+
+pub mod board;
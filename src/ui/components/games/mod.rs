@@ -1,4 +1,17 @@
 //! All game-specific UI widgets and components, such as setup screens, dice
 //! displays, cards, etc.
 
+#[cfg(feature = "chess")]
+pub mod chess;
+#[cfg(feature = "klondike")]
+pub mod klondike;
+#[cfg(feature = "minesweeper")]
 pub mod minesweeper;
+pub mod setup;
+#[cfg(feature = "sudoku")]
+pub mod sudoku;
+#[cfg(feature = "tron")]
+pub mod tron;
+#[cfg(feature = "twenty-forty-eight")]
+pub mod twenty_forty_eight;
+pub mod zoom;
@@ -0,0 +1,138 @@
+//! Questions of games' setup screens, like the size of a board, answered with
+//! numbers picked with the arrow keys.
+
+use std::fmt::Display;
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Direction,
+		Rect,
+	},
+	Frame,
+};
+use strum::IntoEnumIterator;
+
+use crate::{
+	core::{
+		actions::Action,
+		config::Config,
+	},
+	ui::widgets::scrollable_list::{
+		ListItem,
+		ScrollableList,
+	},
+};
+
+/// Answers to a game's setup questions.
+pub trait SetupAnswers: Clone {
+	/// A question, listed in the order of its variants.
+	type Question: Copy + Display + IntoEnumIterator;
+
+	/// Returns a question's label.
+	fn label(question: Self::Question) -> &'static str;
+
+	/// Returns the answer to a question. Questions answered with one of a few
+	/// options, like a difficulty, answer with the option's index.
+	fn answer(&self, question: Self::Question) -> usize;
+
+	/// Returns the answer to a question as shown, like the name of the option
+	/// picked. Defaults to the [number answered](Self::answer).
+	fn answer_text(&self, question: Self::Question) -> String {
+		self.answer(question).to_string()
+	}
+
+	/// Changes the answer to a question by a step, keeping every answer in
+	/// its bounds.
+	fn adjust(&mut self, question: Self::Question, step: isize);
+}
+
+/// The list of a game's setup questions, with their answers. \[Up\] and
+/// \[Down\] select a question, and \[Left\] and \[Right\] change its answer.
+#[derive(Clone)]
+#[must_use]
+pub struct SetupQuestions<A: SetupAnswers> {
+	/// The answers given.
+	answers: A,
+
+	/// Scrollable list widget for the questions.
+	questions_list: ScrollableList<A::Question>,
+}
+
+impl<A: SetupAnswers> SetupQuestions<A> {
+	/// Lists the questions, with answers to start from.
+	pub fn new(answers: A) -> Self {
+		let questions_list = ScrollableList::new(
+			A::Question::iter().map(|question| Self::entry(&answers, question)).collect(),
+			None,
+			1,
+			Direction::Vertical,
+			Alignment::Center,
+			Some((1, 1)),
+			None,
+		)
+		.with_large_text(Config::load_or_default().large_text);
+		Self {
+			answers,
+			questions_list,
+		}
+	}
+
+	/// Returns the entry of a question in the list, with its answer.
+	fn entry(answers: &A, question: A::Question) -> ListItem<A::Question> {
+		let text = format!(
+			"{}: ◀ {} ▶",
+			A::label(question),
+			answers.answer_text(question)
+		);
+		ListItem::new(None, question, Some(text))
+	}
+
+	/// Returns the answers given.
+	pub fn answers(&self) -> &A {
+		&self.answers
+	}
+
+	/// Replaces the answers, like with a preset's.
+	pub fn set_answers(&mut self, answers: A) {
+		self.answers = answers;
+		for (index, question) in A::Question::iter().enumerate() {
+			self.questions_list.set_item(index, Self::entry(&self.answers, question));
+		}
+	}
+
+	/// Selects a question or changes its answer, returning whether the action
+	/// was handled.
+	pub fn handle_action(&mut self, action: Action) -> bool {
+		let step = match action {
+			Action::Up => {
+				self.questions_list.scroll_forward();
+				return true;
+			},
+			Action::Down => {
+				self.questions_list.scroll_backward();
+				return true;
+			},
+			Action::Left => -1,
+			Action::Right => 1,
+			_ => return false,
+		};
+		if let Some((_, item)) = self.questions_list.get_selected() {
+			let mut answers = self.answers.clone();
+			answers.adjust(item.data, step);
+			self.set_answers(answers);
+		}
+		true
+	}
+
+	/// Returns the height the questions take up.
+	#[must_use]
+	pub fn get_height(&self) -> u16 {
+		self.questions_list.get_height()
+	}
+
+	/// Renders the questions.
+	pub fn render(&self, frame: &mut Frame<'_>, area: Rect) {
+		self.questions_list.render(frame, area);
+	}
+}
@@ -0,0 +1,118 @@
+//! Renders a Sudoku [puzzle](Puzzle), with its boxes outlined, a cursor, and
+//! the digits that conflict with another highlighted.
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Rect,
+	},
+	style::{
+		Color,
+		Modifier,
+		Style,
+	},
+	text::{
+		Line,
+		Span,
+	},
+	widgets::{
+		Padding,
+		Paragraph,
+	},
+	Frame,
+};
+
+use crate::{
+	games::sudoku::puzzle::{
+		Puzzle,
+		BOX_SIZE,
+		SIZE,
+	},
+	ui::{
+		color_scheme::TROPICAL_INDIGO,
+		components::presets::untitled_ui_block,
+	},
+};
+
+/// Width of a cell, in columns.
+const CELL_WIDTH: usize = 3;
+
+/// Width of the grid on the terminal, borders included.
+const GRID_WIDTH: u16 = (SIZE * CELL_WIDTH + SIZE / BOX_SIZE - 1 + 2) as u16;
+
+/// Height of the grid on the terminal, borders included.
+const GRID_HEIGHT: u16 = (SIZE + SIZE / BOX_SIZE - 1 + 2) as u16;
+
+/// Style of the lines between boxes.
+const BOX_LINE_STYLE: Style = Style::new().fg(Color::DarkGray);
+
+/// Returns the text and style of a cell. Empty cells show their first pencil
+/// marks, dimmed.
+fn cell_span(puzzle: &Puzzle, x: usize, y: usize) -> (String, Style) {
+	let digit = puzzle.get(x, y);
+	if digit == 0 {
+		let marks = puzzle.marks(x, y);
+		let text = match marks.len() {
+			0 => "·".to_string(),
+			1..=CELL_WIDTH => marks.iter().map(u8::to_string).collect(),
+			_ => format!("{}{}…", marks[0], marks[1]),
+		};
+		let style = Style::new().fg(Color::DarkGray).add_modifier(Modifier::ITALIC);
+		return (format!("{text:^CELL_WIDTH$}"), style);
+	}
+	let style = if puzzle.is_conflicting(x, y) {
+		Style::new().fg(Color::Red).add_modifier(Modifier::BOLD)
+	} else if puzzle.is_given(x, y) {
+		Style::new().fg(Color::White).add_modifier(Modifier::BOLD)
+	} else {
+		Style::new().fg(TROPICAL_INDIGO)
+	};
+	(format!("{digit:^CELL_WIDTH$}"), style)
+}
+
+/// Returns the line between two bands of boxes.
+fn box_line() -> Line<'static> {
+	let band = "─".repeat(BOX_SIZE * CELL_WIDTH);
+	Line::styled(vec![band; SIZE / BOX_SIZE].join("┼"), BOX_LINE_STYLE)
+}
+
+/// Returns the area that a puzzle takes up on the terminal when centered in
+/// an area, borders included.
+#[must_use]
+pub fn grid_area(bounds: Rect) -> Rect {
+	let width = GRID_WIDTH.min(bounds.width);
+	let height = GRID_HEIGHT.min(bounds.height);
+	Rect::new(
+		bounds.x + (bounds.width - width) / 2,
+		bounds.y + (bounds.height - height) / 2,
+		width,
+		height,
+	)
+}
+
+/// Renders a puzzle centered in an area. The cell under the cursor is
+/// displayed reversed.
+pub fn render_puzzle(frame: &mut Frame<'_>, bounds: Rect, puzzle: &Puzzle, cursor: (usize, usize)) {
+	let mut lines = Vec::new();
+	for y in 0..SIZE {
+		if y > 0 && y % BOX_SIZE == 0 {
+			lines.push(box_line());
+		}
+		let mut spans = Vec::new();
+		for x in 0..SIZE {
+			if x > 0 && x % BOX_SIZE == 0 {
+				spans.push(Span::styled("│", BOX_LINE_STYLE));
+			}
+			let (text, mut style) = cell_span(puzzle, x, y);
+			if cursor == (x, y) {
+				style = style.add_modifier(Modifier::REVERSED);
+			}
+			spans.push(Span::styled(text, style));
+		}
+		lines.push(Line::from(spans));
+	}
+	let grid_widget = Paragraph::new(lines)
+		.alignment(Alignment::Left)
+		.block(untitled_ui_block().padding(Padding::zero()));
+	frame.render_widget(grid_widget, grid_area(bounds));
+}
@@ -0,0 +1,3 @@
+//! Components for the Sudoku game UI.
+
+pub mod grid;
@@ -0,0 +1,72 @@
+//! Renders a Tron [arena](Arena), with each player's cycle and trail in their
+//! color.
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Rect,
+	},
+	style::{
+		Color,
+		Style,
+	},
+	widgets::Paragraph,
+	Frame,
+};
+
+use crate::{
+	games::tron::arena::Arena,
+	ui::{
+		color_scheme::{
+			SUNGLOW,
+			TROPICAL_INDIGO,
+		},
+		components::{
+			games::zoom::{
+				GridCell,
+				Zoom,
+			},
+			presets::untitled_ui_block,
+		},
+	},
+};
+
+/// Colors of the players, by player.
+pub const PLAYER_COLORS: [Color; 2] = [SUNGLOW, TROPICAL_INDIGO];
+
+/// Returns a cell, given the player whose trail is on it and whether a cycle
+/// is on it.
+fn cell(trail: Option<usize>, cycle: bool) -> GridCell {
+	match trail {
+		Some(player) if cycle => GridCell::Filled('█', Style::new().fg(PLAYER_COLORS[player])),
+		Some(player) => GridCell::Filled('▒', Style::new().fg(PLAYER_COLORS[player])),
+		None => GridCell::Marked('·', Style::new().fg(Color::DarkGray)),
+	}
+}
+
+/// Returns the area that an arena takes up on the terminal at a zoom when
+/// centered in an area, borders included.
+#[must_use]
+pub fn arena_area(bounds: Rect, arena: &Arena, zoom: Zoom) -> Rect {
+	let (width, height) = zoom.grid_size(arena.width(), arena.height());
+	let width = (width + 2).min(bounds.width);
+	let height = (height + 2).min(bounds.height);
+	Rect::new(
+		bounds.x + (bounds.width - width) / 2,
+		bounds.y + (bounds.height - height) / 2,
+		width,
+		height,
+	)
+}
+
+/// Renders an arena at a zoom, centered in an area.
+pub fn render_arena(frame: &mut Frame<'_>, bounds: Rect, arena: &Arena, zoom: Zoom) {
+	let lines = zoom.lines((0..arena.height()).map(|y| {
+		(0..arena.width()).map(move |x| {
+			let cycle = arena.cycles().iter().any(|cycle| cycle.alive && cycle.position == (x, y));
+			cell(arena.trail((x, y)), cycle)
+		})
+	}));
+	let arena_widget = Paragraph::new(lines).alignment(Alignment::Left).block(untitled_ui_block());
+	frame.render_widget(arena_widget, arena_area(bounds, arena, zoom));
+}
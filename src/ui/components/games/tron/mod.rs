@@ -0,0 +1,3 @@
+//! Components for the Tron game UI.
+
+pub mod arena;
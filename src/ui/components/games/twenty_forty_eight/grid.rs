@@ -0,0 +1,88 @@
+//! Renders a 2048 [grid](Grid), with each tile colored after its value.
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Rect,
+	},
+	style::{
+		Color,
+		Style,
+		Stylize,
+	},
+	text::{
+		Line,
+		Span,
+	},
+	widgets::Paragraph,
+	Frame,
+};
+
+use crate::{
+	games::twenty_forty_eight::grid::Grid,
+	ui::{
+		color_scheme::{
+			FRENCH_VIOLET,
+			GHOST_WHITE,
+			MEDIUM_SLATE_BLUE,
+			SUNGLOW,
+			THISTLE,
+			TROPICAL_INDIGO,
+		},
+		components::presets::untitled_ui_block,
+	},
+};
+
+/// Width of a tile, in columns.
+pub const TILE_WIDTH: u16 = 6;
+
+/// Colors of the tiles, from the 2 tile up, repeating for the largest ones.
+const TILE_COLORS: [Color; 6] = [
+	GHOST_WHITE,
+	THISTLE,
+	TROPICAL_INDIGO,
+	MEDIUM_SLATE_BLUE,
+	FRENCH_VIOLET,
+	SUNGLOW,
+];
+
+/// Returns the span of a tile, empty if its value is 0.
+fn tile(value: u32) -> Span<'static> {
+	let width = TILE_WIDTH as usize;
+	if value == 0 {
+		return Span::styled(format!("{:^width$}", "·"), Style::new().fg(Color::DarkGray));
+	}
+	let color = TILE_COLORS[(value.ilog2() as usize - 1) % TILE_COLORS.len()];
+	Span::styled(
+		format!("{value:^width$}"),
+		Style::new().fg(Color::Black).bg(color).bold(),
+	)
+}
+
+/// Returns the area that a grid takes up on the terminal when centered in an
+/// area, borders included. Each row of tiles is followed by a blank line.
+#[must_use]
+pub fn grid_area(bounds: Rect, grid: &Grid) -> Rect {
+	let size = grid.size() as u16;
+	let width = (size * TILE_WIDTH + 2).min(bounds.width);
+	let height = (size * 2 + 1).min(bounds.height);
+	Rect::new(
+		bounds.x + (bounds.width - width) / 2,
+		bounds.y + (bounds.height - height) / 2,
+		width,
+		height,
+	)
+}
+
+/// Renders a grid, centered in an area.
+pub fn render_grid(frame: &mut Frame<'_>, bounds: Rect, grid: &Grid) {
+	let lines: Vec<_> = (0..grid.size())
+		.flat_map(|y| {
+			let tiles: Vec<_> = (0..grid.size()).map(|x| tile(grid.get(x, y))).collect();
+			[Line::from(tiles), Line::default()]
+		})
+		.take(grid.size() * 2 - 1)
+		.collect();
+	let grid_widget = Paragraph::new(lines).alignment(Alignment::Left).block(untitled_ui_block());
+	frame.render_widget(grid_widget, grid_area(bounds, grid));
+}
@@ -0,0 +1,3 @@
+//! Components for the 2048 game UI.
+
+pub mod grid;
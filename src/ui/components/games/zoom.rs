@@ -0,0 +1,134 @@
+//! Zoom levels of grid games, drawing each cell of the grid over more or fewer
+//! characters of the terminal, so that dense grids fit on small terminals and
+//! get readable on large ones.
+
+use ratatui::{
+	style::Style,
+	text::{
+		Line,
+		Span,
+	},
+};
+
+/// How many characters of the terminal a cell of a grid is drawn over.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Zoom {
+	/// A character per cell.
+	Small,
+
+	/// Two columns per cell, which look about square on most terminals.
+	#[default]
+	Medium,
+
+	/// Three columns and two rows per cell.
+	Large,
+}
+
+impl Zoom {
+	/// Returns the size of a cell at this zoom, in columns and rows.
+	#[must_use]
+	pub fn cell_size(self) -> (u16, u16) {
+		match self {
+			Self::Small => (1, 1),
+			Self::Medium => (2, 1),
+			Self::Large => (3, 2),
+		}
+	}
+
+	/// Returns the size of a grid of cells at this zoom, in columns and rows.
+	#[must_use]
+	pub fn grid_size(self, width: usize, height: usize) -> (u16, u16) {
+		let (cell_width, cell_height) = self.cell_size();
+		(
+			u16::try_from(width).unwrap_or(u16::MAX).saturating_mul(cell_width),
+			u16::try_from(height).unwrap_or(u16::MAX).saturating_mul(cell_height),
+		)
+	}
+
+	/// Returns the next zoom level, or the smallest after the largest.
+	#[must_use]
+	pub fn next(self) -> Self {
+		match self {
+			Self::Small => Self::Medium,
+			Self::Medium => Self::Large,
+			Self::Large => Self::Small,
+		}
+	}
+
+	/// Returns the lines of a grid of cells at this zoom, a row of cells at a
+	/// time.
+	#[must_use]
+	pub fn lines<R>(self, rows: R) -> Vec<Line<'static>>
+	where
+		R: IntoIterator,
+		R::Item: IntoIterator<Item = GridCell>,
+	{
+		let (width, height) = self.cell_size();
+		let (width, height) = (usize::from(width), usize::from(height));
+		rows.into_iter()
+			.flat_map(|row| {
+				let row: Vec<_> = row.into_iter().collect();
+				(0..height)
+					.map(|line| {
+						Line::from(
+							row.iter().map(|cell| cell.span(width, line)).collect::<Vec<_>>(),
+						)
+					})
+					.collect::<Vec<_>>()
+			})
+			.collect()
+	}
+}
+
+/// A cell of a grid, as drawn at any [zoom](Zoom).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridCell {
+	/// A symbol repeated over the whole cell, like a wall.
+	Filled(char, Style),
+
+	/// A symbol drawn once in the top right corner of the cell, like a dot on
+	/// an empty tile.
+	Marked(char, Style),
+}
+
+impl GridCell {
+	/// Returns the span of a line of the cell, given how wide it is.
+	fn span(self, width: usize, line: usize) -> Span<'static> {
+		match self {
+			Self::Filled(symbol, style) => Span::styled(symbol.to_string().repeat(width), style),
+			Self::Marked(symbol, style) if line == 0 => {
+				Span::styled(format!("{symbol:>width$}"), style)
+			},
+			Self::Marked(_, style) => Span::styled(" ".repeat(width), style),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use ratatui::style::Style;
+
+	use super::{
+		GridCell,
+		Zoom,
+	};
+
+	#[test]
+	fn cells_stretch_to_the_zoom() {
+		let rows = [[
+			GridCell::Filled('█', Style::new()),
+			GridCell::Marked('·', Style::new()),
+		]];
+		let text = |zoom: Zoom| {
+			zoom.lines(rows)
+				.into_iter()
+				.map(|line| line.spans.iter().map(|span| span.content.to_string()).collect())
+				.collect::<Vec<String>>()
+		};
+		assert_eq!(text(Zoom::Small), ["█·"]);
+		assert_eq!(text(Zoom::Medium), ["██ ·"]);
+		assert_eq!(text(Zoom::Large), ["███  ·", "███   "]);
+		assert_eq!(Zoom::Large.grid_size(40, 20), (120, 40));
+		assert_eq!(Zoom::Large.next(), Zoom::Small);
+	}
+}
@@ -6,9 +6,17 @@
 
 use ratatui::layout::Layout;
 
+pub mod banner;
+pub mod confirm_dialog;
+#[cfg(feature = "dev-console")]
+pub mod dev_console;
 pub mod game_select;
 pub mod games;
+pub mod pause_menu;
 pub mod presets;
+pub mod results_banner;
 pub mod screen_base_block;
+pub mod start_countdown;
+pub mod tutorial;
 pub mod under_construction;
 pub mod welcome;
@@ -0,0 +1,210 @@
+//! The pause menu, drawn over a game paused with [`Action::Pause`]: it
+//! resumes the game, gives a [hint](Hint), opens the game's manual or leaves
+//! the game. Games opt into it with [`ScreenState::with_pause_menu`], and into
+//! hints with [`ScreenState::with_hints`], giving them from
+//! [`Screen::hint`](crate::ui::Screen::hint). Sudoku, Klondike, 2048 and
+//! scripted games give hints.
+//!
+//! [`Action::Pause`]: crate::core::actions::Action::Pause
+//! [`ScreenState::with_pause_menu`]: crate::ui::screens::ScreenState::with_pause_menu
+//! [`ScreenState::with_hints`]: crate::ui::screens::ScreenState::with_hints
+
+use std::time::Duration;
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Constraint,
+		Direction,
+		Layout,
+		Rect,
+	},
+	widgets::{
+		Clear,
+		Paragraph,
+		Wrap,
+	},
+	Frame,
+};
+use strum::Display;
+
+use crate::{
+	t,
+	ui::{
+		components::presets::{
+			highlight_block,
+			titled_ui_block,
+		},
+		widgets::scrollable_list::{
+			ListItem,
+			ScrollableList,
+		},
+	},
+};
+
+/// Width of the pause menu, in columns.
+const MENU_WIDTH: u16 = 48;
+
+/// Lines kept for a hint's text under the menu's entries.
+const HINT_LINES: u16 = 4;
+
+/// Time added to the clock of games played against it for every hint given,
+/// which is how they count hints against the player.
+pub const HINT_TIME_PENALTY: Duration = Duration::from_secs(30);
+
+/// A hint for the player, given by a game from its pause menu. Games count
+/// the hints they give against the player's score, or add
+/// [`HINT_TIME_PENALTY`] to their time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Hint {
+	/// Advice, shown in the pause menu.
+	Text(String),
+
+	/// A position on the game's board, as `(x, y)`, pointed at by the game
+	/// once the pause menu closes.
+	Position(usize, usize),
+}
+
+/// An entry of the pause menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+pub enum PauseMenuItem {
+	/// Closes the menu, resuming the game.
+	Resume,
+
+	/// Asks the game for a [hint](Hint).
+	Hint,
+
+	/// Opens the game's manual.
+	Help,
+
+	/// Leaves the game.
+	Leave,
+}
+
+impl PauseMenuItem {
+	/// Returns the entry's label.
+	#[must_use]
+	fn label(self) -> &'static str {
+		match self {
+			Self::Resume => t!("pause-menu-resume"),
+			Self::Hint => t!("pause-menu-hint"),
+			Self::Help => t!("pause-menu-help"),
+			Self::Leave => t!("pause-menu-leave"),
+		}
+	}
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct PauseMenu {
+	/// Scrollable list widget for the entries.
+	items: ScrollableList<PauseMenuItem>,
+
+	/// Text of the last hint given, or telling that there was none.
+	hint_text: Option<String>,
+}
+
+impl PauseMenu {
+	/// Creates a pause menu, with entries for hints and the game's manual if
+	/// the game has them. Resuming is selected.
+	pub fn new(hints: bool, help: bool) -> Self {
+		let items = [
+			Some(PauseMenuItem::Resume),
+			hints.then_some(PauseMenuItem::Hint),
+			help.then_some(PauseMenuItem::Help),
+			Some(PauseMenuItem::Leave),
+		]
+		.into_iter()
+		.flatten()
+		.map(|item| ListItem::new(None, item, Some(item.label().to_string())))
+		.collect();
+		let mut items = ScrollableList::new(
+			items,
+			None,
+			1,
+			Direction::Vertical,
+			Alignment::Center,
+			Some((1, 2)),
+			None,
+		);
+		items.select(0);
+		Self {
+			items,
+			hint_text: None,
+		}
+	}
+
+	/// Selects the entry above the selected one.
+	pub fn select_previous(&mut self) {
+		self.items.scroll_forward();
+	}
+
+	/// Selects the entry below the selected one.
+	pub fn select_next(&mut self) {
+		self.items.scroll_backward();
+	}
+
+	/// Returns the selected entry.
+	#[must_use]
+	pub fn selected(&self) -> Option<PauseMenuItem> {
+		self.items.get_selected().map(|(_, item)| item.data)
+	}
+
+	/// Shows a hint's text under the entries, or tells that the game had no
+	/// hint to give.
+	pub fn show_hint(&mut self, text: Option<String>) {
+		self.hint_text = Some(text.unwrap_or_else(|| t!("pause-menu-no-hint").to_string()));
+	}
+
+	/// Renders the menu in the middle of an area.
+	pub fn render(&self, frame: &mut Frame<'_>, area: Rect) {
+		let hint_height = if self.hint_text.is_some() { HINT_LINES } else { 0 };
+		let width = MENU_WIDTH.min(area.width);
+		let height = (self.items.get_height() + hint_height + 2).min(area.height);
+		let menu = Rect::new(
+			area.x + (area.width - width) / 2,
+			area.y + (area.height - height) / 2,
+			width,
+			height,
+		);
+		let block = highlight_block(titled_ui_block(t!("pause-menu-title")));
+		let inner = block.inner(menu);
+		frame.render_widget(Clear, menu);
+		frame.render_widget(block, menu);
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.constraints([Constraint::Min(0), Constraint::Length(hint_height)])
+			.split(inner);
+		self.items.render(frame, chunks[0]);
+		if let Some(hint_text) = &self.hint_text {
+			let hint = Paragraph::new(hint_text.as_str())
+				.alignment(Alignment::Center)
+				.wrap(Wrap { trim: true });
+			frame.render_widget(hint, chunks[1]);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		PauseMenu,
+		PauseMenuItem,
+	};
+
+	#[test]
+	fn lists_the_entries_the_game_has() {
+		let mut menu = PauseMenu::new(false, true);
+		assert_eq!(menu.selected(), Some(PauseMenuItem::Resume));
+		menu.select_next();
+		assert_eq!(menu.selected(), Some(PauseMenuItem::Help));
+		menu.select_next();
+		assert_eq!(menu.selected(), Some(PauseMenuItem::Leave));
+		let mut menu = PauseMenu::new(true, false);
+		menu.select_previous();
+		assert_eq!(menu.selected(), Some(PauseMenuItem::Leave));
+		menu.select_previous();
+		assert_eq!(menu.selected(), Some(PauseMenuItem::Hint));
+	}
+}
@@ -3,7 +3,10 @@
 #![allow(clippy::needless_pass_by_value)]
 
 use ratatui::{
-	layout::Alignment,
+	layout::{
+		Alignment,
+		Constraint,
+	},
 	style::{
 		Color,
 		Modifier,
@@ -16,6 +19,8 @@ use ratatui::{
 		BorderType,
 		Borders,
 		Padding,
+		Row,
+		Table,
 	},
 };
 
@@ -46,3 +51,26 @@ pub fn untitled_ui_block<'a>() -> Block<'a> {
 pub fn highlight_block(block: Block<'_>) -> Block<'_> {
 	block.style(HIGHLIGHTED).title_style(HIGHLIGHTED).border_style(Style::new().fg(Color::White))
 }
+
+/// The [Style] of every other row in [striped tables](striped_table), a
+/// slightly lighter background than the terminal's.
+pub const STRIPED_ROW: Style = Style::new().bg(Color::Rgb(38, 38, 46));
+
+/// Styles every other row with [`STRIPED_ROW`], starting from the second one,
+/// so that rows are easier to follow across wide tables.
+pub fn striped_rows<'a>(rows: impl IntoIterator<Item = Row<'a>>) -> impl Iterator<Item = Row<'a>> {
+	rows.into_iter()
+		.enumerate()
+		.map(|(index, row)| if index % 2 == 1 { row.style(STRIPED_ROW) } else { row })
+}
+
+/// A [Table] with [striped rows](striped_rows). The header, if any, isn't
+/// striped.
+#[must_use]
+pub fn striped_table<'a, C>(rows: impl IntoIterator<Item = Row<'a>>, widths: C) -> Table<'a>
+where
+	C: IntoIterator,
+	C::Item: Into<Constraint>,
+{
+	Table::new(striped_rows(rows), widths)
+}
@@ -0,0 +1,297 @@
+//! A banner announcing the end of a game, drawn over it: "YOU WIN" or "GAME
+//! OVER" in large text when it fits, the final score rolling up from zero,
+//! and details like how the result compares to the player's bests.
+//!
+//! Games drawing from the shared generator show the
+//! [seed](ResultsBanner::with_seed) they started from, to copy it or play the
+//! same game again.
+//!
+//! Games can ask for [confetti](ResultsBanner::with_confetti) falling around
+//! the banner, like on wins and new bests. With [reduced
+//! motion](Config::reduced_motion) on, the score is shown at once and there's
+//! no confetti.
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Rect,
+	},
+	style::{
+		Color,
+		Style,
+	},
+	text::{
+		Line,
+		Text,
+	},
+	widgets::{
+		Clear,
+		Paragraph,
+	},
+	Frame,
+};
+
+use crate::{
+	core::config::Config,
+	t,
+	ui::{
+		color_scheme::{
+			FRENCH_VIOLET,
+			GHOST_WHITE,
+			MEDIUM_SLATE_BLUE,
+			SUNGLOW,
+			TROPICAL_INDIGO,
+		},
+		components::{
+			banner::{
+				get_large_text,
+				get_large_text_height,
+			},
+			presets::{
+				highlight_block,
+				untitled_ui_block,
+			},
+		},
+		text,
+	},
+};
+
+/// Pieces of confetti, drawn at random.
+const CONFETTI: [char; 5] = ['*', '•', '✦', '·', '+'];
+
+/// Colors of the confetti, picked at random.
+const CONFETTI_COLORS: [Color; 5] = [
+	SUNGLOW,
+	GHOST_WHITE,
+	TROPICAL_INDIGO,
+	MEDIUM_SLATE_BLUE,
+	FRENCH_VIOLET,
+];
+
+/// One cell in this many gets a piece of confetti.
+const CONFETTI_DENSITY: u64 = 14;
+
+/// How many ticks the confetti takes to fall by a row.
+const TICKS_PER_ROW: u64 = 4;
+
+/// How the game ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+	/// The player won.
+	Won,
+
+	/// The game ended without a win.
+	Over,
+}
+
+impl Verdict {
+	/// Returns the verdict's title, like `YOU WIN`.
+	#[must_use]
+	pub fn title(self) -> &'static str {
+		match self {
+			Self::Won => t!("results-won"),
+			Self::Over => t!("results-over"),
+		}
+	}
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct ResultsBanner {
+	/// How the game ended.
+	verdict: Verdict,
+
+	/// The final score, if the game keeps one.
+	score: Option<i64>,
+
+	/// The score shown, rolling up to the final one.
+	shown_score: i64,
+
+	/// Lines shown below the score.
+	details: Vec<String>,
+
+	/// The seed the game started from, if it drew from the shared generator.
+	seed: Option<u64>,
+
+	/// Whether the seed was copied.
+	seed_copied: bool,
+
+	/// Whether confetti falls around the banner.
+	confetti: bool,
+
+	/// How many ticks the banner has been shown for.
+	ticks: u64,
+
+	/// Whether the score is shown whole right away, without confetti.
+	reduced_motion: bool,
+}
+
+impl ResultsBanner {
+	/// Creates a banner for a game that ended, with its final score if it
+	/// keeps one. With [reduced motion](Config::reduced_motion) on, the score
+	/// is shown whole right away.
+	pub fn new(verdict: Verdict, score: Option<i64>) -> Self {
+		Self::with_reduced_motion(verdict, score, Config::load_or_default().reduced_motion)
+	}
+
+	/// Creates a banner for a game that ended, with its final score if it
+	/// keeps one, shown whole right away if motion is reduced.
+	pub fn with_reduced_motion(verdict: Verdict, score: Option<i64>, reduced_motion: bool) -> Self {
+		Self {
+			verdict,
+			score,
+			shown_score: if reduced_motion { score.unwrap_or(0) } else { 0 },
+			details: Vec::new(),
+			seed: None,
+			seed_copied: false,
+			confetti: false,
+			ticks: 0,
+			reduced_motion,
+		}
+	}
+
+	/// Adds lines shown below the score, skipping empty ones.
+	pub fn with_details(mut self, details: impl IntoIterator<Item = String>) -> Self {
+		self.details.extend(details.into_iter().filter(|detail| !detail.is_empty()));
+		self
+	}
+
+	/// Shows the seed the game started from, with the keys to copy it and to
+	/// play the same game again.
+	pub fn with_seed(mut self, seed: Option<u64>) -> Self {
+		self.seed = seed;
+		self
+	}
+
+	/// Returns the seed the game started from, if shown.
+	#[must_use]
+	pub fn seed(&self) -> Option<u64> {
+		self.seed
+	}
+
+	/// Tells that the seed was copied, in place of the key to copy it.
+	pub fn mark_seed_copied(&mut self) {
+		self.seed_copied = true;
+	}
+
+	/// Makes confetti fall around the banner, unless motion is reduced.
+	pub fn with_confetti(mut self, confetti: bool) -> Self {
+		self.confetti = confetti && !self.reduced_motion;
+		self
+	}
+
+	/// Returns the score shown, which rolls up to the final score.
+	#[must_use]
+	pub fn shown_score(&self) -> i64 {
+		self.shown_score
+	}
+
+	/// Rolls the score up, quickly at first and slowing down as it gets to
+	/// the final score, and lets the confetti fall.
+	pub fn tick(&mut self) {
+		self.ticks = self.ticks.saturating_add(1);
+		let remaining = self.score.unwrap_or(0) - self.shown_score;
+		let step = (remaining / 8).abs().max(1).min(remaining.abs());
+		self.shown_score += step * remaining.signum();
+	}
+
+	/// Returns the piece of confetti falling through a cell, if any.
+	fn confetti_at(&self, x: u16, y: u16) -> Option<(char, Color)> {
+		let row = u64::from(y).wrapping_sub(self.ticks / TICKS_PER_ROW);
+		let hash = (u64::from(x).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+			^ row.wrapping_mul(0xC2B2_AE3D_27D4_EB4F))
+		.rotate_left(31)
+		.wrapping_mul(0x1656_67B1_9E37_79F9);
+		let hash = hash >> 32;
+		(hash % CONFETTI_DENSITY == 0).then(|| {
+			let hash = hash / CONFETTI_DENSITY;
+			(
+				CONFETTI[(hash % CONFETTI.len() as u64) as usize],
+				CONFETTI_COLORS[(hash / 8 % CONFETTI_COLORS.len() as u64) as usize],
+			)
+		})
+	}
+
+	/// Renders the banner in the middle of an area, with confetti on the
+	/// blank cells around it if asked for.
+	pub fn render(&self, frame: &mut Frame<'_>, area: Rect) {
+		let title = self.verdict.title();
+		let large_title =
+			get_large_text(title, area.width.saturating_sub(6), get_large_text_height());
+		let mut lines = large_title.map_or_else(
+			|| vec![Line::from(title).style(Style::new().fg(SUNGLOW))],
+			|text| text.lines,
+		);
+		lines.push(Line::default());
+		if self.score.is_some() {
+			lines.push(Line::from(t!("results-score", score = self.shown_score)));
+		}
+		lines.extend(self.details.iter().map(|detail| Line::from(detail.clone())));
+		if let Some(seed) = self.seed {
+			lines.push(Line::from(t!("results-seed", seed = seed.to_string())));
+			let controls =
+				if self.seed_copied { t!("results-seed-copied") } else { t!("results-seed-copy") };
+			lines.push(Line::from(controls).style(Style::new().fg(TROPICAL_INDIGO)));
+		}
+		let content_width = lines
+			.iter()
+			.map(|line| line.spans.iter().map(|span| text::width(&span.content)).sum::<usize>())
+			.max()
+			.unwrap_or_default();
+		let width = (u16::try_from(content_width).unwrap_or(u16::MAX) + 4).min(area.width);
+		let height = (u16::try_from(lines.len()).unwrap_or(u16::MAX) + 2).min(area.height);
+		let banner = Rect::new(
+			area.x + (area.width - width) / 2,
+			area.y + (area.height - height) / 2,
+			width,
+			height,
+		);
+		if self.confetti {
+			let buffer = frame.buffer_mut();
+			for y in area.top()..area.bottom() {
+				for x in area.left()..area.right() {
+					let cell = buffer.get_mut(x, y);
+					if cell.symbol() != " " || banner.intersects(Rect::new(x, y, 1, 1)) {
+						continue;
+					}
+					if let Some((piece, color)) = self.confetti_at(x, y) {
+						cell.set_char(piece).set_fg(color);
+					}
+				}
+			}
+		}
+		let paragraph = Paragraph::new(Text::from(lines))
+			.alignment(Alignment::Center)
+			.block(highlight_block(untitled_ui_block()));
+		frame.render_widget(Clear, banner);
+		frame.render_widget(paragraph, banner);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		ResultsBanner,
+		Verdict,
+	};
+
+	#[test]
+	fn score_rolls_up_to_the_final_one() {
+		let mut banner = ResultsBanner::with_reduced_motion(Verdict::Won, Some(100), false);
+		banner.tick();
+		assert_eq!(banner.shown_score(), 12);
+		for _ in 0..100 {
+			banner.tick();
+		}
+		assert_eq!(banner.shown_score(), 100);
+		let mut banner = ResultsBanner::with_reduced_motion(Verdict::Over, Some(-3), false);
+		banner.tick();
+		banner.tick();
+		assert_eq!(banner.shown_score(), -2);
+		let banner =
+			ResultsBanner::with_reduced_motion(Verdict::Over, Some(42), true).with_confetti(true);
+		assert_eq!(banner.shown_score(), 42);
+		assert!(!banner.confetti);
+	}
+}
@@ -0,0 +1,143 @@
+//! A "3, 2, 1, Go!" countdown drawn over a game before it starts, so action
+//! games don't start the moment their screen opens. Games ask for one with
+//! [`ScreenState::start_countdown`], and the [screen
+//! handler](crate::core::handler::ScreenHandler) keeps them
+//! [paused](crate::ui::Screen::pause) until "Go!".
+//!
+//! [`ScreenState::start_countdown`]: crate::ui::screens::ScreenState::start_countdown
+
+use std::time::Duration;
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Rect,
+	},
+	text::Text,
+	widgets::{
+		Clear,
+		Paragraph,
+	},
+	Frame,
+};
+
+use crate::{
+	core::time::Instant,
+	t,
+	ui::components::{
+		banner::{
+			get_large_text,
+			get_large_text_height,
+		},
+		presets::{
+			highlight_block,
+			untitled_ui_block,
+		},
+	},
+};
+
+/// Number counted down from.
+const COUNT_FROM: u32 = 3;
+
+/// How long each number is shown.
+const STEP_DURATION: Duration = Duration::from_secs(1);
+
+/// How long "Go!" is shown once the game started.
+const GO_DURATION: Duration = Duration::from_millis(600);
+
+/// See the [module](self) documentation for more information.
+#[derive(Debug, Clone, Copy)]
+#[must_use]
+pub struct StartCountdown {
+	/// When the countdown started.
+	started: Instant,
+
+	/// Whether the game was told to start.
+	went: bool,
+}
+
+impl StartCountdown {
+	/// Starts a countdown.
+	pub fn start() -> Self {
+		Self {
+			started: Instant::now(),
+			went: false,
+		}
+	}
+
+	/// Returns what's shown a while into a countdown: a number, "Go!", or
+	/// [`None`] once it's over.
+	#[must_use]
+	fn label_at(elapsed: Duration) -> Option<String> {
+		let counting = STEP_DURATION * COUNT_FROM;
+		if elapsed < counting {
+			let step = (elapsed.as_millis() / STEP_DURATION.as_millis()) as u32;
+			Some((COUNT_FROM - step).to_string())
+		} else if elapsed < counting + GO_DURATION {
+			Some(t!("start-countdown-go").to_string())
+		} else {
+			None
+		}
+	}
+
+	/// Returns whether numbers are still being counted down, before "Go!".
+	#[must_use]
+	pub fn is_counting(&self) -> bool {
+		self.started.elapsed() < STEP_DURATION * COUNT_FROM
+	}
+
+	/// Marks the game as started once numbers are no longer counted down,
+	/// returning whether it just did.
+	pub fn go(&mut self) -> bool {
+		let went = !self.went && !self.is_counting();
+		self.went |= went;
+		went
+	}
+
+	/// Returns whether the countdown is still shown, "Go!" included.
+	#[must_use]
+	pub fn is_shown(&self) -> bool {
+		Self::label_at(self.started.elapsed()).is_some()
+	}
+
+	/// Renders the countdown in the middle of an area, in large text if it
+	/// fits.
+	pub fn render(&self, frame: &mut Frame<'_>, area: Rect) {
+		let Some(label) = Self::label_at(self.started.elapsed()) else {
+			return;
+		};
+		let width = (area.width / 2).clamp(12.min(area.width), 40.min(area.width));
+		let large_height = get_large_text_height();
+		let large_text = get_large_text(&label, width.saturating_sub(4), large_height);
+		let height = if large_text.is_some() { large_height + 2 } else { 3 };
+		let height = height.min(area.height);
+		let overlay = Rect::new(
+			area.x + (area.width - width) / 2,
+			area.y + (area.height - height) / 2,
+			width,
+			height,
+		);
+		let paragraph = Paragraph::new(large_text.unwrap_or_else(|| Text::from(label)))
+			.alignment(Alignment::Center)
+			.block(highlight_block(untitled_ui_block()));
+		frame.render_widget(Clear, overlay);
+		frame.render_widget(paragraph, overlay);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::time::Duration;
+
+	use super::StartCountdown;
+
+	#[test]
+	fn counts_down_to_go() {
+		let label = |millis| StartCountdown::label_at(Duration::from_millis(millis));
+		assert_eq!(label(0).as_deref(), Some("3"));
+		assert_eq!(label(1_999).as_deref(), Some("2"));
+		assert_eq!(label(2_000).as_deref(), Some("1"));
+		assert_eq!(label(3_000).as_deref(), Some("Go!"));
+		assert_eq!(label(3_600), None);
+	}
+}
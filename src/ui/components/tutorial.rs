@@ -0,0 +1,207 @@
+//! A framework for interactive tutorials, where a game scripts guided steps.
+//!
+//! Each [step](TutorialStep) shows explanatory text, can highlight a region of
+//! the game (in the game's own units, such as tiles), and has a
+//! [goal](TutorialGoal) that must be met before moving on. While a step is
+//! active, only the inputs it allows are passed on to the game, so that the
+//! player can't wander off the script.
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Rect,
+	},
+	style::Stylize,
+	text::{
+		Line,
+		Text,
+	},
+	widgets::{
+		Paragraph,
+		Wrap,
+	},
+	Frame,
+};
+
+use crate::{
+	core::events::KeyCode,
+	t,
+	ui::{
+		components::presets::{
+			highlight_block,
+			titled_ui_block,
+		},
+		widgets::typewriter::Typewriter,
+	},
+};
+
+/// What a player needs to do to complete a [tutorial step](TutorialStep).
+#[derive(Clone, Copy)]
+pub enum TutorialGoal<G> {
+	/// Press \[Enter\] to continue.
+	Continue,
+
+	/// Press a specific key, which is also passed on to the game.
+	Input(KeyCode),
+
+	/// Bring the game into a state, checked after every input passed on to the
+	/// game.
+	State(fn(&G) -> bool),
+}
+
+/// A guided step of a [Tutorial].
+#[derive(Clone)]
+pub struct TutorialStep<G> {
+	/// Explanatory text of the step.
+	pub text: &'static str,
+
+	/// Region of the game to highlight, in the game's own units.
+	pub highlight: Option<Rect>,
+
+	/// Inputs passed on to the game while this step is active, in addition to
+	/// the input required by the step's goal.
+	pub allowed_inputs: Vec<KeyCode>,
+
+	/// What the player needs to do to complete the step.
+	pub goal: TutorialGoal<G>,
+}
+
+impl<G> TutorialStep<G> {
+	/// Creates a step that is completed by pressing \[Enter\].
+	#[must_use]
+	pub fn new(text: &'static str, highlight: Option<Rect>) -> Self {
+		Self {
+			text,
+			highlight,
+			allowed_inputs: Vec::new(),
+			goal: TutorialGoal::Continue,
+		}
+	}
+
+	/// Sets the goal of the step. This is a fluent setter method.
+	#[must_use]
+	pub fn goal(mut self, goal: TutorialGoal<G>) -> Self {
+		self.goal = goal;
+		self
+	}
+
+	/// Sets the inputs passed on to the game while this step is active. This
+	/// is a fluent setter method.
+	#[must_use]
+	pub fn allow(mut self, inputs: &[KeyCode]) -> Self {
+		self.allowed_inputs = inputs.to_vec();
+		self
+	}
+
+	/// Returns whether an input should be passed on to the game.
+	#[must_use]
+	pub fn accepts_input(&self, input: KeyCode) -> bool {
+		self.allowed_inputs.contains(&input)
+			|| matches!(self.goal, TutorialGoal::Input(expected) if expected == input)
+	}
+}
+
+/// An interactive tutorial, made of a sequence of [steps](TutorialStep) for a
+/// game of type `G`.
+#[derive(Clone)]
+pub struct Tutorial<G> {
+	/// The steps of the tutorial.
+	steps: Vec<TutorialStep<G>>,
+
+	/// Index of the current step.
+	current: usize,
+
+	/// Types out the current step's text.
+	typewriter: Typewriter,
+}
+
+impl<G> Tutorial<G> {
+	/// Creates a new tutorial, starting at its first step.
+	#[must_use]
+	pub fn new(steps: Vec<TutorialStep<G>>) -> Self {
+		let typewriter = Typewriter::new(steps.first().map_or("", |step| step.text));
+		Self {
+			steps,
+			current: 0,
+			typewriter,
+		}
+	}
+
+	/// Returns the current step, or [`None`] if the tutorial is finished.
+	#[must_use]
+	pub fn current_step(&self) -> Option<&TutorialStep<G>> {
+		self.steps.get(self.current)
+	}
+
+	/// Returns the region to be highlighted in the current step.
+	#[must_use]
+	pub fn highlight(&self) -> Option<Rect> {
+		self.current_step()?.highlight
+	}
+
+	/// Returns whether an input should be passed on to the game.
+	#[must_use]
+	pub fn accepts_input(&self, input: KeyCode) -> bool {
+		self.current_step().is_some_and(|step| step.accepts_input(input))
+	}
+
+	/// Types out more of the current step's text. This should be called every
+	/// time the screen [updates](crate::ui::Screen::update).
+	pub fn tick(&mut self) {
+		self.typewriter.tick();
+	}
+
+	/// Advances the tutorial if the current step's goal was met by an input.
+	/// This should be called after the input was passed on to the game (if it
+	/// was [accepted](Self::accepts_input)). \[Enter\] shows the rest of a
+	/// step's text at once before moving on from it.
+	pub fn update(&mut self, input: KeyCode, game: &G) {
+		let Some(step) = self.current_step() else {
+			return;
+		};
+		if input == KeyCode::Enter
+			&& matches!(step.goal, TutorialGoal::Continue)
+			&& !self.typewriter.is_done()
+		{
+			self.typewriter.skip();
+			return;
+		}
+		let completed = match step.goal {
+			TutorialGoal::Continue => input == KeyCode::Enter,
+			TutorialGoal::Input(expected) => input == expected,
+			TutorialGoal::State(is_reached) => step.accepts_input(input) && is_reached(game),
+		};
+		if completed {
+			self.current += 1;
+			self.typewriter.set_text(self.current_step().map_or("", |step| step.text));
+		}
+	}
+
+	/// Returns whether every step has been completed.
+	#[must_use]
+	pub fn is_finished(&self) -> bool {
+		self.current >= self.steps.len()
+	}
+
+	/// Renders the current step's text in a box.
+	pub fn render(&self, frame: &mut Frame<'_>, area: Rect) {
+		let Some(step) = self.current_step() else {
+			return;
+		};
+		let mut text = Text::from(self.typewriter.visible());
+		if matches!(step.goal, TutorialGoal::Continue) && self.typewriter.is_done() {
+			text.lines.push(Line::default());
+			text.lines.push(Line::from(t!("tutorial-continue")).italic());
+		}
+		let title = t!(
+			"tutorial-step",
+			step = self.current + 1,
+			steps = self.steps.len()
+		);
+		let paragraph = Paragraph::new(text)
+			.block(highlight_block(titled_ui_block(title)))
+			.alignment(Alignment::Center)
+			.wrap(Wrap { trim: true });
+		frame.render_widget(paragraph, area);
+	}
+}
@@ -5,7 +5,10 @@ use ratatui::{
 		Alignment,
 		Rect,
 	},
-	text::Text,
+	text::{
+		Line,
+		Text,
+	},
 	widgets::{
 		Paragraph,
 		Wrap,
@@ -13,40 +16,70 @@ use ratatui::{
 	Frame,
 };
 
-use crate::ui::{
-	components::presets::untitled_ui_block,
-	util::get_crate_version,
+use crate::{
+	t,
+	ui::{
+		components::presets::untitled_ui_block,
+		layout::LayoutSize,
+		util::get_crate_version,
+	},
 };
 
 #[must_use]
 fn git_info_string() -> String {
-	let git_info = git_info::get();
-	let current_branch =
-		git_info.current_branch.unwrap_or_else(|| "of an unknown tree".to_string());
+	#[cfg(not(target_arch = "wasm32"))]
+	let (current_branch, commit_hash) = {
+		let git_info = git_info::get();
+		(
+			git_info.current_branch,
+			git_info.head.last_commit_hash_short,
+		)
+	};
+	// Browsers can't run git.
+	#[cfg(target_arch = "wasm32")]
+	let (current_branch, commit_hash) = (None, None);
+	let current_branch = current_branch.unwrap_or_else(|| t!("welcome-unknown-branch").to_string());
 	let version = get_crate_version();
-	let commit_hash = git_info.head.last_commit_hash_short.unwrap_or_else(|| "browns".to_string());
+	let commit_hash = commit_hash.unwrap_or_else(|| "browns".to_string());
 	let remote_link = "https://github.com/developer-ramen/terminal-arcade";
 
-	format!(
-		"🎮 Terminal Arcade {version}, on 🎋 {current_branch}, commit hash {commit_hash}; at \
-		 remote {remote_link}",
+	t!(
+		"welcome-version",
+		version = version,
+		branch = current_branch,
+		commit = commit_hash,
+		remote = remote_link,
 	)
 }
 
 #[must_use]
 fn bottom_bar_text() -> String {
 	format!(
-		"⏰ Time: {}\n{}\n🏗️ Terminal Arcade is a work-in-progress! If you would like to \
-		 contribute, please do!
-        ",
-		chrono::Local::now().format("%d/%m/%Y %H:%M:%S"),
+		"{}\n{}\n{}",
+		t!(
+			"welcome-time",
+			time = chrono::Local::now().format("%d/%m/%Y %H:%M:%S").to_string()
+		),
 		git_info_string(),
+		t!("welcome-work-in-progress"),
 	)
 }
 
-/// Renders the bottom bar at the welcome screen.
-pub fn render_welcome_bottom_bar(frame: &mut Frame<'_>, size: Rect) {
-	let bottom_bar_paragraph = Paragraph::new(bottom_bar_text())
+/// Renders the bottom bar at the welcome screen, with an optional notice of a
+/// new version being available and an optional tip. On
+/// [compact](LayoutSize::Compact) terminals, the time and version are left out.
+pub fn render_welcome_bottom_bar(
+	frame: &mut Frame<'_>,
+	size: Rect,
+	layout_size: LayoutSize,
+	update_notice: Option<&str>,
+	tip: Option<Line<'static>>,
+) {
+	let mut text =
+		if layout_size.is_compact() { Text::default() } else { Text::from(bottom_bar_text()) };
+	text.lines.extend(tip);
+	text.lines.extend(update_notice.map(|notice| Line::from(notice.to_string())));
+	let bottom_bar_paragraph = Paragraph::new(text)
 		.alignment(Alignment::Center)
 		.wrap(Wrap { trim: true })
 		.block(untitled_ui_block());
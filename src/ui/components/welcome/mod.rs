@@ -1,3 +1,4 @@
 //! A list of components for the welcome page.
 
 pub mod footer;
+pub mod tips;
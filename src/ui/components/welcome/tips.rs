@@ -0,0 +1,71 @@
+//! Tips of the day, displayed in the welcome screen's footer. Tips are bundled
+//! from `assets/tips.txt`, one tip per line, and may contain inline markdown.
+
+use std::time::Duration;
+
+use ratatui::text::Line;
+
+use crate::{
+	core::time::SystemTime,
+	games::get_unix_time_as_secs,
+	t,
+	ui::widgets::markdown::parse_markdown,
+};
+
+/// Every tip, one per line.
+pub const TIPS: &str = include_str!("../../../../assets/tips.txt");
+
+/// How long a tip is displayed before rotating to the next one.
+pub const TIP_ROTATION_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Returns the list of tips.
+#[must_use]
+pub fn get_tips() -> Vec<&'static str> {
+	TIPS.lines().map(str::trim).filter(|tip| !tip.is_empty()).collect()
+}
+
+/// Rotates through the tips, starting with the tip of the day.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct TipRotator {
+	/// When the rotation started.
+	begin_time: SystemTime,
+
+	/// Index of the first tip displayed.
+	first_index: usize,
+
+	/// Whether the tips have been dismissed.
+	dismissed: bool,
+}
+
+impl Default for TipRotator {
+	fn default() -> Self {
+		let days_since_epoch = get_unix_time_as_secs() / (24 * 60 * 60);
+		Self {
+			begin_time: SystemTime::now(),
+			first_index: (days_since_epoch % get_tips().len() as u64) as usize,
+			dismissed: false,
+		}
+	}
+}
+
+impl TipRotator {
+	/// Dismisses the tips, hiding them.
+	pub fn dismiss(&mut self) {
+		self.dismissed = true;
+	}
+
+	/// Returns the tip to be displayed, or [`None`] if the tips were
+	/// dismissed.
+	#[must_use]
+	pub fn get_tip(&self) -> Option<Line<'static>> {
+		if self.dismissed {
+			return None;
+		}
+		let tips = get_tips();
+		let elapsed = self.begin_time.elapsed().unwrap_or_default();
+		let rotations = (elapsed.as_secs() / TIP_ROTATION_INTERVAL.as_secs()) as usize;
+		let tip = tips[(self.first_index + rotations) % tips.len()];
+		parse_markdown(&t!("welcome-tip", tip = tip)).lines.pop()
+	}
+}
@@ -0,0 +1,124 @@
+//! Breakpoints for laying screens out by the size of the terminal, so that
+//! they drop or rearrange their less important parts on small terminals
+//! instead of cutting everything off.
+//!
+//! Screens pick a layout from the [`LayoutSize`] of the area they're drawn in,
+//! like a smaller bottom bar on compact terminals:
+//!
+//! ```ignore
+//! let bottom_bar_height = LayoutSize::of(area).pick(4, 7, 7);
+//! ```
+//!
+//! Side panes, like a chat next to a game, are [sized](split_side_pane) from
+//! the room the rest of the screen needs.
+
+use ratatui::layout::Rect;
+
+/// Terminals narrower than this many columns are
+/// [compact](LayoutSize::Compact).
+pub const COMPACT_WIDTH: u16 = 80;
+
+/// Terminals shorter than this many rows are [compact](LayoutSize::Compact).
+pub const COMPACT_HEIGHT: u16 = 30;
+
+/// Terminals at least this many columns wide are [wide](LayoutSize::Wide).
+pub const WIDE_WIDTH: u16 = 160;
+
+/// How much room there is to lay a screen out in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LayoutSize {
+	/// Narrower than [`COMPACT_WIDTH`] or shorter than [`COMPACT_HEIGHT`]:
+	/// only what's needed to use the screen is shown.
+	Compact,
+
+	/// The layout screens are designed for.
+	Normal,
+
+	/// At least [`WIDE_WIDTH`] wide, and not compact: there's room to put
+	/// things side by side.
+	Wide,
+}
+
+impl LayoutSize {
+	/// Returns the layout size of an area.
+	#[must_use]
+	pub fn of(area: Rect) -> Self {
+		if area.width < COMPACT_WIDTH || area.height < COMPACT_HEIGHT {
+			Self::Compact
+		} else if area.width >= WIDE_WIDTH {
+			Self::Wide
+		} else {
+			Self::Normal
+		}
+	}
+
+	/// Returns whether this is the [compact](Self::Compact) layout size.
+	#[must_use]
+	pub fn is_compact(self) -> bool {
+		self == Self::Compact
+	}
+
+	/// Returns the value for this layout size.
+	pub fn pick<T>(self, compact: T, normal: T, wide: T) -> T {
+		match self {
+			Self::Compact => compact,
+			Self::Normal => normal,
+			Self::Wide => wide,
+		}
+	}
+}
+
+/// Splits an area into a main pane and a side pane on its right, returned in
+/// that order. The side pane takes its width from the main pane down to the
+/// main pane's smallest width, then shrinks, though never below half its
+/// width so that it stays usable.
+#[must_use]
+pub fn split_side_pane(area: Rect, main_min_width: u16, side_width: u16) -> (Rect, Rect) {
+	let side_width = side_width
+		.min(area.width.saturating_sub(main_min_width))
+		.max(side_width / 2)
+		.min(area.width);
+	let main = Rect {
+		width: area.width - side_width,
+		..area
+	};
+	let side = Rect {
+		x: area.x + main.width,
+		width: side_width,
+		..area
+	};
+	(main, side)
+}
+
+#[cfg(test)]
+mod tests {
+	use ratatui::layout::Rect;
+
+	use super::{
+		split_side_pane,
+		LayoutSize,
+	};
+
+	#[test]
+	fn areas_are_sized_by_breakpoints() {
+		let size = |width, height| LayoutSize::of(Rect::new(0, 0, width, height));
+		assert_eq!(size(79, 40), LayoutSize::Compact);
+		assert_eq!(size(200, 29), LayoutSize::Compact);
+		assert_eq!(size(120, 40), LayoutSize::Normal);
+		assert_eq!(size(160, 30), LayoutSize::Wide);
+	}
+
+	#[test]
+	fn side_panes_leave_room_for_the_main_pane() {
+		let area = Rect::new(2, 1, 100, 30);
+		let (main, side) = split_side_pane(area, 60, 32);
+		assert_eq!(
+			(main, side),
+			(Rect::new(2, 1, 68, 30), Rect::new(70, 1, 32, 30))
+		);
+		let (main, side) = split_side_pane(area, 80, 32);
+		assert_eq!((main.width, side.width), (80, 20));
+		let (main, side) = split_side_pane(area, 95, 32);
+		assert_eq!((main.width, side.width), (84, 16));
+	}
+}
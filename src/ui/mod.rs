@@ -5,7 +5,10 @@
 
 pub mod color_scheme;
 pub mod components;
+pub mod layout;
 pub mod screens;
+pub mod search;
+pub mod text;
 pub mod util;
 pub mod widgets;
 
@@ -0,0 +1,191 @@
+//! The screen displaying information about Terminal Arcade itself: its
+//! version, repository, license, credits, the credits of each game and the
+//! changelog.
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Constraint,
+		Direction,
+		Layout,
+	},
+	widgets::{
+		Paragraph,
+		Wrap,
+	},
+	Frame,
+};
+
+use crate::{
+	core::{
+		actions::Action,
+		events::{
+			Event,
+			KeyCode,
+		},
+	},
+	games::{
+		Game,
+		Games,
+	},
+	t,
+	ui::{
+		components::presets::titled_ui_block,
+		screens::{
+			DiagnosticsScreen,
+			QrCodePopup,
+			ScreenKind,
+			ScreenState,
+		},
+		util::{
+			get_crate_authors,
+			get_crate_version,
+		},
+		widgets::markdown::Markdown,
+		Screen,
+	},
+};
+
+/// Terminal Arcade's changelog, bundled at compile time.
+pub const CHANGELOG: &str = include_str!("../../../CHANGELOG.md");
+
+/// Where bugs in Terminal Arcade are reported.
+pub const BUG_REPORT_URL: &str = concat!(env!("CARGO_PKG_REPOSITORY"), "/issues/new");
+
+/// Most lines the game credits take, borders included, leaving the rest of the
+/// screen to the changelog.
+const MAX_GAME_CREDITS_HEIGHT: u16 = 10;
+
+/// Number of lines scrolled at once with \[Page Up\] and \[Page Down\].
+const PAGE_SCROLL_LINES: u16 = 10;
+
+/// Returns the text listing Terminal Arcade's information and credits.
+#[must_use]
+fn about_text() -> String {
+	let authors = get_crate_authors().join(", ");
+	t!(
+		"about-text",
+		version = get_crate_version(),
+		description = env!("CARGO_PKG_DESCRIPTION"),
+		repository = env!("CARGO_PKG_REPOSITORY"),
+		license = env!("CARGO_PKG_LICENSE"),
+		authors = authors,
+	)
+}
+
+/// Returns the text crediting the authors of each game, including third-party
+/// ones.
+#[must_use]
+fn game_credits_text() -> String {
+	Games::all()
+		.into_iter()
+		.map(|game| {
+			let info = game.data().metadata.static_info;
+			let authors = info.authors_string();
+			let authors =
+				if authors.is_empty() { t!("about-unknown-authors").to_string() } else { authors };
+			t!("about-game-credits", name = info.name, authors = authors)
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct AboutScreen {
+	/// The authors of each game.
+	game_credits: String,
+
+	/// The rendered changelog.
+	changelog: Markdown,
+}
+
+impl Default for AboutScreen {
+	fn default() -> Self {
+		Self {
+			game_credits: game_credits_text(),
+			changelog: Markdown::new(CHANGELOG),
+		}
+	}
+}
+
+impl Screen for AboutScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(
+			t!("about-title"),
+			ScreenKind::Normal,
+			Some(vec![
+				("R", t!("about-repository-control")),
+				("B", t!("about-bug-control")),
+				("D", t!("about-diagnostics-control")),
+			]),
+		)
+		.with_actions(vec![
+			(&[Action::Up, Action::Down], t!("about-scroll")),
+			(&[Action::PageUp, Action::PageDown], t!("about-page-scroll")),
+		])
+		.with_static_frames()
+	}
+
+	fn handle_event(&mut self, event: &Event, state: &mut ScreenState) -> anyhow::Result<()> {
+		if let Event::Key(key) = event {
+			match key.code {
+				KeyCode::Char('r' | 'R') => state.create_screen(
+					QrCodePopup::new(
+						t!("about-repository"),
+						env!("CARGO_PKG_REPOSITORY").to_string(),
+					)
+					.into(),
+				),
+				KeyCode::Char('b' | 'B') => state.create_screen(
+					QrCodePopup::new(t!("about-report-bug"), BUG_REPORT_URL.to_string())
+						.with_message(t!("bug-report-hint").to_string())
+						.into(),
+				),
+				KeyCode::Char('d' | 'D') => {
+					state.create_screen(DiagnosticsScreen::default().into());
+				},
+				_ => {},
+			}
+		}
+		Ok(())
+	}
+
+	fn handle_action(&mut self, action: Action, _state: &mut ScreenState) -> anyhow::Result<bool> {
+		match action {
+			Action::Up => self.changelog.scroll_up(1),
+			Action::Down => self.changelog.scroll_down(1),
+			Action::PageUp => self.changelog.scroll_up(PAGE_SCROLL_LINES),
+			Action::PageDown => self.changelog.scroll_down(PAGE_SCROLL_LINES),
+			_ => return Ok(false),
+		}
+		Ok(true)
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let game_credits_height =
+			(self.game_credits.lines().count() as u16 + 2).min(MAX_GAME_CREDITS_HEIGHT);
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.vertical_margin(1)
+			.horizontal_margin(2)
+			.constraints([
+				Constraint::Length(6),                   // About text + borders
+				Constraint::Length(game_credits_height), // Game credits + borders
+				Constraint::Min(0),                      // Changelog
+			])
+			.split(frame.size());
+		let about = Paragraph::new(about_text())
+			.block(titled_ui_block(t!("about-block")))
+			.alignment(Alignment::Center)
+			.wrap(Wrap { trim: true });
+		frame.render_widget(about, chunks[0]);
+		let game_credits = Paragraph::new(self.game_credits.as_str())
+			.block(titled_ui_block(t!("about-game-credits-block")))
+			.alignment(Alignment::Center)
+			.wrap(Wrap { trim: true });
+		frame.render_widget(game_credits, chunks[1]);
+		self.changelog.render(frame, chunks[2], titled_ui_block(t!("about-changelog")));
+	}
+}
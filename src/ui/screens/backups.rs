@@ -0,0 +1,164 @@
+//! The screen listing the [backups](crate::services::backup) of the save
+//! directory, opened from the settings, where they can be restored.
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Constraint,
+		Direction,
+		Layout,
+	},
+	widgets::{
+		Paragraph,
+		Wrap,
+	},
+	Frame,
+};
+
+use crate::{
+	core::{
+		actions::Action,
+		events::{
+			Event,
+			KeyCode,
+		},
+	},
+	services::backup::Backup,
+	t,
+	ui::{
+		components::presets::untitled_ui_block,
+		screens::{
+			ScreenKind,
+			ScreenState,
+		},
+		widgets::scrollable_list::{
+			ListItem,
+			ScrollableList,
+		},
+		Screen,
+	},
+};
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct BackupsScreen {
+	/// The backups, newest first.
+	backups: Vec<Backup>,
+
+	/// Scrollable list widget for the backups, if there are any.
+	backups_list: Option<ScrollableList<u64>>,
+
+	/// What happened after the last action, shown below the list.
+	status: String,
+}
+
+impl Default for BackupsScreen {
+	fn default() -> Self {
+		let mut screen = Self {
+			backups: Vec::new(),
+			backups_list: None,
+			status: t!("backups-hint").to_string(),
+		};
+		screen.refresh();
+		screen
+	}
+}
+
+impl BackupsScreen {
+	/// Lists the backups again.
+	fn refresh(&mut self) {
+		match Backup::list() {
+			Ok(backups) => self.backups = backups,
+			Err(error) => self.status = t!("backups-list-failed", error = error.to_string()),
+		}
+		self.backups_list = (!self.backups.is_empty()).then(|| {
+			ScrollableList::new(
+				self.backups
+					.iter()
+					.map(|backup| ListItem::new(None, backup.created, Some(backup.label())))
+					.collect(),
+				Some(8),
+				1,
+				Direction::Vertical,
+				Alignment::Center,
+				Some((1, 3)),
+				None,
+			)
+		});
+	}
+
+	/// Restores the selected backup.
+	fn restore_selected(&mut self) {
+		let Some((index, _)) = self.backups_list.as_ref().and_then(ScrollableList::get_selected)
+		else {
+			return;
+		};
+		let backup = &self.backups[index];
+		self.status = match backup.restore() {
+			Ok(()) => t!("backups-restored", date = backup.label()),
+			Err(error) => t!("backups-restore-failed", error = error.to_string()),
+		};
+		self.refresh();
+	}
+
+	/// Backs up the save directory now.
+	fn back_up(&mut self) {
+		self.status = match Backup::create() {
+			Ok(backup) => t!("backups-created", date = backup.label()),
+			Err(error) => t!("backups-create-failed", error = error.to_string()),
+		};
+		self.refresh();
+	}
+}
+
+impl Screen for BackupsScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(
+			t!("backups-title"),
+			ScreenKind::Normal,
+			Some(vec![("N", t!("backups-create-control"))]),
+		)
+		.with_actions(vec![
+			(&[Action::Up, Action::Down], t!("backups-select")),
+			(&[Action::Confirm], t!("backups-restore-control")),
+		])
+	}
+
+	fn handle_event(&mut self, event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		if let Event::Key(key) = event {
+			if let KeyCode::Char('n' | 'N') = key.code {
+				self.back_up();
+			}
+		}
+		Ok(())
+	}
+
+	fn handle_action(&mut self, action: Action, _state: &mut ScreenState) -> anyhow::Result<bool> {
+		match action {
+			Action::Up => self.backups_list.iter_mut().for_each(ScrollableList::scroll_forward),
+			Action::Down => self.backups_list.iter_mut().for_each(ScrollableList::scroll_backward),
+			Action::Confirm => self.restore_selected(),
+			_ => return Ok(false),
+		}
+		Ok(true)
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.margin(1)
+			.constraints([Constraint::Min(0), Constraint::Length(4)])
+			.split(frame.size());
+		if let Some(list) = &self.backups_list {
+			list.render(frame, chunks[0]);
+		} else {
+			let empty = Paragraph::new(t!("backups-none")).alignment(Alignment::Center);
+			frame.render_widget(empty, chunks[0]);
+		}
+		let status = Paragraph::new(self.status.clone())
+			.block(untitled_ui_block())
+			.wrap(Wrap { trim: true });
+		frame.render_widget(status, chunks[1]);
+	}
+}
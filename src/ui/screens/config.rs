@@ -1,10 +1,6 @@
 //! The screen for viewing and modifying the configuration for Terminal Arcade.
 
-use crossterm::event::{
-	Event,
-	KeyCode,
-	KeyModifiers,
-};
+use clap::ValueEnum;
 use ratatui::{
 	layout::{
 		Alignment,
@@ -12,42 +8,368 @@ use ratatui::{
 		Direction,
 		Layout,
 	},
-	widgets::{
-		Borders,
-		Paragraph,
-	},
+	widgets::Paragraph,
 	Frame,
 };
+use strum::{
+	Display,
+	EnumIter,
+	IntoEnumIterator,
+};
 
-use crate::ui::{
-	components::{
-		presets::{
-			titled_ui_block,
-			untitled_ui_block,
+#[cfg(not(target_arch = "wasm32"))]
+use crate::services::sync;
+#[cfg(feature = "encryption")]
+use crate::{
+	core::encryption,
+	ui::screens::passphrase::{
+		PassphrasePurpose,
+		PassphraseScreen,
+	},
+};
+use crate::{
+	core::{
+		actions::Action,
+		cheat_sheet::{
+			self,
+			CheatSheet,
+			CheatSheetFormat,
+		},
+		config::Config,
+		events::{
+			Event,
+			KeyCode,
 		},
-		under_construction::render_under_construction_block,
+		export::{
+			self,
+			ExportFormat,
+		},
+		files::app_files,
 	},
-	screens::{
-		ScreenKind,
-		ScreenState,
+	t,
+	ui::{
+		components::presets::titled_ui_block,
+		screens::{
+			BackupsScreen,
+			GameDataScreen,
+			MacrosScreen,
+			ProfileScreen,
+			ResetScreen,
+			ScreenKind,
+			ScreenState,
+		},
+		widgets::scrollable_list::{
+			ListItem,
+			ScrollableList,
+		},
+		Screen,
 	},
-	Screen,
 };
 
+/// A toggleable setting in the configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, EnumIter)]
+#[allow(missing_docs)]
+pub enum Setting {
+	CheckForUpdates,
+	ShowTips,
+	LargeText,
+	ReducedMotion,
+	AttractMode,
+	AutosaveOnLeave,
+	ResumeGames,
+	CoalesceInputs,
+	ExitSummary,
+	ConfirmQuit,
+	ScreenshotAnsi,
+}
+
+impl Setting {
+	/// Returns the setting's description.
+	#[must_use]
+	pub fn label(self) -> &'static str {
+		match self {
+			Setting::CheckForUpdates => {
+				if cfg!(feature = "update-check") {
+					t!("settings-check-for-updates")
+				} else {
+					t!("settings-check-for-updates-unavailable")
+				}
+			},
+			Setting::ShowTips => t!("settings-show-tips"),
+			Setting::LargeText => t!("settings-large-text"),
+			Setting::ReducedMotion => t!("settings-reduced-motion"),
+			Setting::AttractMode => t!("settings-attract-mode"),
+			Setting::AutosaveOnLeave => t!("settings-autosave-on-leave"),
+			Setting::ResumeGames => t!("settings-resume-games"),
+			Setting::CoalesceInputs => t!("settings-coalesce-inputs"),
+			Setting::ExitSummary => t!("settings-exit-summary"),
+			Setting::ConfirmQuit => t!("settings-confirm-quit"),
+			Setting::ScreenshotAnsi => t!("settings-screenshot-ansi"),
+		}
+	}
+
+	/// Returns a mutable reference to the setting's value in a configuration.
+	fn value_mut(self, config: &mut Config) -> &mut bool {
+		match self {
+			Setting::CheckForUpdates => &mut config.check_for_updates,
+			Setting::ShowTips => &mut config.show_tips,
+			Setting::LargeText => &mut config.large_text,
+			Setting::ReducedMotion => &mut config.reduced_motion,
+			Setting::AttractMode => &mut config.attract_mode,
+			Setting::AutosaveOnLeave => &mut config.autosave_on_leave,
+			Setting::ResumeGames => &mut config.resume_games,
+			Setting::CoalesceInputs => &mut config.coalesce_inputs,
+			Setting::ExitSummary => &mut config.exit_summary,
+			Setting::ConfirmQuit => &mut config.confirm_quit,
+			Setting::ScreenshotAnsi => &mut config.screenshot_ansi,
+		}
+	}
+
+	/// Returns a list item displaying the setting and its value.
+	fn get_list_entry(self, config: &mut Config) -> ListItem<Setting> {
+		let value = if *self.value_mut(config) { t!("settings-on") } else { t!("settings-off") };
+		ListItem::new(None, self, Some(format!("{}: {value}", self.label())))
+	}
+}
+
+/// Returns the text showing where Terminal Arcade's files are and where their
+/// locations come from.
+fn files_text() -> String {
+	let files = app_files();
+	#[cfg(not(target_arch = "wasm32"))]
+	let sync_status = sync::status().unwrap_or_else(|| t!("settings-sync-off").to_string());
+	// Browsers can't run git, so there's nothing synced there.
+	#[cfg(target_arch = "wasm32")]
+	let sync_status = t!("settings-sync-off").to_string();
+	[
+		t!(
+			"settings-data-dir",
+			path = files.data_dir.display().to_string(),
+			source = files.data_source.label()
+		),
+		t!(
+			"settings-config-dir",
+			path = files.config_dir.display().to_string(),
+			source = files.config_source.label()
+		),
+		sync_status,
+	]
+	.join("\n")
+}
+
 /// See the [module](self) documentation for more information.
-#[derive(Default, Clone)]
-pub struct ConfigScreen;
+#[derive(Clone)]
+pub struct ConfigScreen {
+	/// The configuration being edited.
+	config: Config,
+
+	/// Scrollable list widget for the settings.
+	settings_list: ScrollableList<Setting>,
+
+	/// Outcome of the last export of statistics or of the keybindings cheat
+	/// sheet, if any.
+	export_status: Option<String>,
+
+	/// Whether the screen is played by a connection of the [telnet
+	/// server](crate::services::telnet). The settings are then only shown,
+	/// and the screens managing the server's files are left out.
+	served: bool,
+}
+
+impl Default for ConfigScreen {
+	fn default() -> Self {
+		let mut config = Config::load_or_default();
+		let settings_list = ScrollableList::new(
+			Setting::iter().map(|setting| setting.get_list_entry(&mut config)).collect(),
+			None,
+			1,
+			Direction::Vertical,
+			Alignment::Center,
+			Some((1, 3)),
+			None,
+		)
+		.with_large_text(config.large_text);
+		Self {
+			config,
+			settings_list,
+			export_status: None,
+			served: false,
+		}
+	}
+}
 
 impl Screen for ConfigScreen {
 	fn initial_state(&self) -> ScreenState {
-		ScreenState::new("Under construction!", ScreenKind::Normal, None)
+		if self.served {
+			return ScreenState::new(t!("settings-title"), ScreenKind::Normal, None)
+				.with_actions(vec![(&[Action::Up, Action::Down], t!("settings-select"))]);
+		}
+		ScreenState::new(
+			t!("settings-title"),
+			ScreenKind::Normal,
+			Some(vec![
+				("B", t!("settings-backups")),
+				("D", t!("settings-data")),
+				("M", t!("settings-macros")),
+				("P", t!("settings-profile")),
+				("R", t!("settings-reset")),
+				("X", t!("settings-export")),
+				("K", t!("settings-cheat-sheet")),
+				#[cfg(feature = "encryption")]
+				("E", t!("settings-encryption")),
+			]),
+		)
+		.with_actions(vec![
+			(&[Action::Up, Action::Down], t!("settings-select")),
+			(&[Action::Confirm], t!("settings-toggle")),
+		])
 	}
 
-	fn handle_event(&mut self, _event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+	fn handle_event(&mut self, event: &Event, state: &mut ScreenState) -> anyhow::Result<()> {
+		if self.served {
+			return Ok(());
+		}
+		if let Event::Key(key) = event {
+			match key.code {
+				KeyCode::Char('b' | 'B') => {
+					state.create_screen(BackupsScreen::default().into());
+				},
+				KeyCode::Char('d' | 'D') => {
+					state.create_screen(GameDataScreen::default().into());
+				},
+				KeyCode::Char('m' | 'M') => {
+					state.create_screen(MacrosScreen::default().into());
+				},
+				KeyCode::Char('p' | 'P') => {
+					state.create_screen(ProfileScreen::default().into());
+				},
+				KeyCode::Char('r' | 'R') => {
+					state.create_screen(ResetScreen::default().into());
+				},
+				KeyCode::Char('x' | 'X') => self.export_stats(),
+				KeyCode::Char('k' | 'K') => self.export_cheat_sheet(),
+				#[cfg(feature = "encryption")]
+				KeyCode::Char('e' | 'E') => {
+					let purpose = if encryption::is_enabled() {
+						PassphrasePurpose::Disable
+					} else {
+						PassphrasePurpose::Enable
+					};
+					state.create_screen(PassphraseScreen::new(purpose).into());
+				},
+				_ => {},
+			}
+		}
 		Ok(())
 	}
 
+	fn handle_action(&mut self, action: Action, _state: &mut ScreenState) -> anyhow::Result<bool> {
+		match action {
+			Action::Up => self.settings_list.scroll_forward(),
+			Action::Down => self.settings_list.scroll_backward(),
+			Action::Confirm if !self.served => self.toggle_selected()?,
+			_ => return Ok(false),
+		}
+		Ok(true)
+	}
+
+	fn resume(&mut self) {
+		self.config = Config::load_or_default();
+		for (index, setting) in Setting::iter().enumerate() {
+			self.settings_list.set_item(index, setting.get_list_entry(&mut self.config));
+		}
+		self.settings_list = self.settings_list.clone().with_large_text(self.config.large_text);
+	}
+
 	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
-		render_under_construction_block(frame);
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.margin(1)
+			.constraints([Constraint::Min(0), Constraint::Length(6)])
+			.split(frame.size());
+		self.settings_list.render(frame, chunks[0]);
+		let mut text = if self.served { t!("settings-served").to_string() } else { files_text() };
+		if let Some(status) = &self.export_status {
+			text = format!("{text}\n{status}");
+		}
+		frame.render_widget(
+			Paragraph::new(text).block(titled_ui_block(t!("settings-files"))),
+			chunks[1],
+		);
+	}
+}
+
+impl ConfigScreen {
+	/// Creates the settings screen for a connection of the [telnet
+	/// server](crate::services::telnet), showing the server's settings without
+	/// changing them or opening the screens that manage its files.
+	#[must_use]
+	pub fn served() -> Self {
+		Self {
+			served: true,
+			..Self::default()
+		}
+	}
+
+	/// Creates the settings screen with a setting selected.
+	#[must_use]
+	pub fn with_selected(setting: Setting) -> Self {
+		let mut screen = Self::default();
+		if let Some(index) = Setting::iter().position(|other| other == setting) {
+			screen.settings_list.select(index);
+		}
+		screen
+	}
+
+	/// Exports the player's statistics in every format to the save directory,
+	/// and shows where they were written.
+	fn export_stats(&mut self) {
+		let result = ExportFormat::value_variants()
+			.iter()
+			.try_for_each(|&format| export::export(&export::default_export_path(format), format));
+		self.export_status = Some(match result {
+			Ok(()) => t!(
+				"settings-export-done",
+				path = export::default_export_path(ExportFormat::Csv)
+					.parent()
+					.map(|dir| dir.display().to_string())
+					.unwrap_or_default()
+			),
+			Err(error) => t!("settings-export-failed", error = error.to_string()),
+		});
+	}
+
+	/// Writes a cheat sheet of the keybindings in every format to the save
+	/// directory, and shows where it was written.
+	fn export_cheat_sheet(&mut self) {
+		let cheat_sheet = CheatSheet::collect();
+		let path =
+			|format: CheatSheetFormat| export::export_path("keybindings", format.extension());
+		let result = CheatSheetFormat::ALL
+			.iter()
+			.try_for_each(|&format| cheat_sheet::export(&cheat_sheet, &path(format), format));
+		self.export_status = Some(match result {
+			Ok(()) => t!(
+				"settings-cheat-sheet-done",
+				path = path(CheatSheetFormat::Markdown).display().to_string()
+			),
+			Err(error) => t!("settings-cheat-sheet-failed", error = error.to_string()),
+		});
+	}
+
+	/// Toggles the selected setting and saves the configuration.
+	fn toggle_selected(&mut self) -> anyhow::Result<()> {
+		if let Some((index, item)) = self.settings_list.get_selected() {
+			let setting = item.data;
+			let value = setting.value_mut(&mut self.config);
+			*value = !*value;
+			self.settings_list.set_item(index, setting.get_list_entry(&mut self.config));
+			if setting == Setting::LargeText {
+				self.settings_list =
+					self.settings_list.clone().with_large_text(self.config.large_text);
+			}
+			self.config.save()?;
+		}
+		Ok(())
 	}
 }
@@ -1,6 +1,5 @@
 //! Module for the controls popup.
 
-use crossterm::event::Event;
 use derive_new::new;
 use ratatui::{
 	layout::{
@@ -19,18 +18,31 @@ use ratatui::{
 	Frame,
 };
 
-use crate::ui::{
-	components::presets::{
-		highlight_block,
-		titled_ui_block,
-		HIGHLIGHTED,
+use crate::{
+	core::{
+		actions::{
+			self,
+			Action,
+			ActionContext,
+		},
+		events::Event,
 	},
-	screens::{
-		ControlsEntry,
-		ScreenKind,
-		ScreenState,
+	t,
+	ui::{
+		components::presets::{
+			highlight_block,
+			striped_table,
+			titled_ui_block,
+			HIGHLIGHTED,
+		},
+		screens::{
+			ControlsEntry,
+			ScreenKind,
+			ScreenState,
+		},
+		text,
+		Screen,
 	},
-	Screen,
 };
 
 /// A controls popup, consisting of only a [Table] listing out each controls
@@ -43,10 +55,11 @@ pub struct ControlsPopup {
 impl Screen for ControlsPopup {
 	fn initial_state(&self) -> ScreenState {
 		ScreenState::new(
-			"Controls",
+			t!("controls-title"),
 			ScreenKind::Popup,
 			self.extra_controls_entries.clone(),
 		)
+		.with_static_frames()
 	}
 
 	fn handle_event(&mut self, _event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
@@ -75,23 +88,59 @@ impl ControlsPopup {
 	#[must_use]
 	fn get_controls_table<'a>(extra_entries: Option<Vec<ControlsEntry>>) -> Table<'a> {
 		let mut entries = extra_entries.unwrap_or_default();
-		let mut default_shortcuts = vec![
-			("Esc", "Closes this screen and returns to the previous one"),
-			("Ctrl-Q", "Quits the application"),
+		let default_shortcuts = [
+			(
+				ActionContext::Navigation,
+				Action::Back,
+				t!("controls-close"),
+			),
+			(ActionContext::Global, Action::Quit, t!("controls-quit")),
+			(
+				ActionContext::Global,
+				Action::RecordMacro,
+				t!("controls-record-macro"),
+			),
+			(
+				ActionContext::Global,
+				Action::ReportBug,
+				t!("controls-report-bug"),
+			),
+			(
+				ActionContext::Global,
+				Action::Screenshot,
+				t!("controls-screenshot"),
+			),
+			(
+				ActionContext::Global,
+				Action::RecordSession,
+				t!("controls-record-session"),
+			),
 		];
-		entries.append(&mut default_shortcuts);
-		Table::new(
+		entries.extend(
+			default_shortcuts.into_iter().filter_map(|(context, action, function)| {
+				Some((actions::keys_label(&[action], context)?, function))
+			}),
+		);
+		let shortcut_width = entries
+			.iter()
+			.map(|entry| entry.0)
+			.chain([t!("controls-shortcut")])
+			.map(text::width)
+			.max()
+			.unwrap_or_default();
+		striped_table(
 			entries.into_iter().map(|entry| Row::new([Cell::new(entry.0), Cell::new(entry.1)])),
-			&[
-				Constraint::Ratio(1, 6), // shortcut
-				Constraint::Ratio(5, 6), // function
+			[
+				// Shortcuts are as wide as the widest one, functions take the rest.
+				Constraint::Length(u16::try_from(shortcut_width).unwrap_or(u16::MAX)),
+				Constraint::Min(0),
 			],
 		)
-		.block(highlight_block(titled_ui_block("Controls")))
+		.block(highlight_block(titled_ui_block(t!("controls-title"))))
 		.highlight_spacing(HighlightSpacing::Always)
 		.column_spacing(3)
 		.header(
-			Row::new(["Shortcut", "Function"])
+			Row::new([t!("controls-shortcut"), t!("controls-function")])
 				.style(HIGHLIGHTED.add_modifier(Modifier::UNDERLINED)),
 		)
 	}
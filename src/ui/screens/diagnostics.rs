@@ -0,0 +1,100 @@
+//! The screen showing the [diagnostics](crate::core::doctor) of the terminal
+//! and the player's files, as printed by the `doctor` command, opened from the
+//! about screen.
+
+use ratatui::{
+	layout::Margin,
+	style::{
+		Modifier,
+		Style,
+	},
+	text::{
+		Line,
+		Span,
+	},
+	widgets::{
+		Paragraph,
+		Wrap,
+	},
+	Frame,
+};
+
+use crate::{
+	core::{
+		doctor::Report,
+		events::{
+			Event,
+			KeyCode,
+		},
+	},
+	t,
+	ui::{
+		components::presets::titled_ui_block,
+		screens::{
+			ScreenKind,
+			ScreenState,
+		},
+		Screen,
+	},
+};
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+pub struct DiagnosticsScreen {
+	/// The outcomes of the checks.
+	report: Report,
+}
+
+impl Default for DiagnosticsScreen {
+	fn default() -> Self {
+		Self {
+			report: Report::run(false),
+		}
+	}
+}
+
+impl Screen for DiagnosticsScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(
+			t!("diagnostics-title"),
+			ScreenKind::Normal,
+			Some(vec![("R", t!("diagnostics-rerun"))]),
+		)
+		.with_static_frames()
+	}
+
+	fn handle_event(&mut self, event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		if let Event::Key(key) = event {
+			if let KeyCode::Char('r' | 'R') = key.code {
+				self.report = Report::run(false);
+			}
+		}
+		Ok(())
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let lines: Vec<_> = self
+			.report
+			.checks
+			.iter()
+			.map(|check| {
+				Line::from(vec![
+					Span::raw(format!("{} ", check.status.symbol())),
+					Span::styled(
+						format!("{}: ", check.name),
+						Style::new().add_modifier(Modifier::BOLD),
+					),
+					Span::raw(check.details.clone()),
+				])
+			})
+			.collect();
+		let checks = Paragraph::new(lines)
+			.wrap(Wrap { trim: false })
+			.block(titled_ui_block(t!("diagnostics-checks")));
+		let area = frame.size().inner(&Margin {
+			vertical: 1,
+			horizontal: 1,
+		});
+		frame.render_widget(checks, area);
+	}
+}
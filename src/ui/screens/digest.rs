@@ -0,0 +1,137 @@
+//! The screen showing the player's [week in the
+//! arcade](crate::services::stats::WeeklyDigest), which can be exported as a
+//! Markdown file.
+
+use std::fs;
+
+use ratatui::{
+	layout::{
+		Constraint,
+		Direction,
+		Layout,
+	},
+	widgets::{
+		Paragraph,
+		Row,
+	},
+	Frame,
+};
+
+use crate::{
+	core::{
+		events::{
+			Event,
+			KeyCode,
+		},
+		export::export_path,
+	},
+	services::stats::WeeklyDigest,
+	t,
+	ui::{
+		components::presets::{
+			striped_table,
+			titled_ui_block,
+		},
+		layout::LayoutSize,
+		screens::{
+			ScreenKind,
+			ScreenState,
+		},
+		Screen,
+	},
+};
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+pub struct DigestScreen {
+	/// The digest of the week.
+	digest: WeeklyDigest,
+
+	/// Outcome of the last export, if any.
+	export_status: Option<String>,
+}
+
+impl Default for DigestScreen {
+	fn default() -> Self {
+		Self {
+			digest: WeeklyDigest::compute(),
+			export_status: None,
+		}
+	}
+}
+
+impl DigestScreen {
+	/// Exports the digest to the save directory's `exports` folder.
+	fn export(&mut self) {
+		let path = export_path("week", "md");
+		let result = path
+			.parent()
+			.map_or(Ok(()), fs::create_dir_all)
+			.and_then(|()| fs::write(&path, self.digest.to_markdown()));
+		self.export_status = Some(match result {
+			Ok(()) => t!("digest-exported", path = path.display().to_string()),
+			Err(error) => t!("digest-export-failed", error = error.to_string()),
+		});
+	}
+}
+
+impl Screen for DigestScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(
+			t!("digest-title"),
+			ScreenKind::Normal,
+			Some(vec![("M", t!("digest-export"))]),
+		)
+		.with_static_frames()
+	}
+
+	fn handle_event(&mut self, event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		if let Event::Key(key) = event {
+			if let KeyCode::Char('m' | 'M') = key.code {
+				self.export();
+			}
+		}
+		Ok(())
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let mut summary = self.digest.summary_lines();
+		summary.extend(self.export_status.clone());
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.margin(1)
+			.constraints([
+				Constraint::Length(summary.len() as u16 + 2),
+				Constraint::Min(0),
+			])
+			.split(frame.size());
+		frame.render_widget(
+			Paragraph::new(summary.join("\n")).block(titled_ui_block(t!("digest-summary"))),
+			chunks[0],
+		);
+		// The games and records are stacked when there's no room side by side.
+		let direction = if LayoutSize::of(frame.size()).is_compact() {
+			Direction::Vertical
+		} else {
+			Direction::Horizontal
+		};
+		let columns = Layout::default()
+			.direction(direction)
+			.constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+			.split(chunks[1]);
+		let stats_table = |lines: Vec<String>, title| {
+			striped_table(lines.into_iter().map(|line| Row::new([line])), [
+				Constraint::Fill(1),
+			])
+			.block(titled_ui_block(title))
+		};
+		frame.render_widget(
+			stats_table(self.digest.play_lines(), t!("digest-games")),
+			columns[0],
+		);
+		frame.render_widget(
+			stats_table(self.digest.record_lines(), t!("digest-records")),
+			columns[1],
+		);
+	}
+}
@@ -0,0 +1,92 @@
+//! A popup telling the player about an [error](crate::core::error) a screen
+//! ran into, with its code and how to recover from it, instead of quitting.
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Rect,
+	},
+	widgets::{
+		Clear,
+		Paragraph,
+		Widget,
+		Wrap,
+	},
+	Frame,
+};
+
+use crate::{
+	core::{
+		error::ErrorReport,
+		events::{
+			Event,
+			KeyCode,
+		},
+	},
+	t,
+	ui::{
+		components::presets::{
+			highlight_block,
+			titled_ui_block,
+		},
+		screens::{
+			OpenStatus,
+			ScreenKind,
+			ScreenState,
+		},
+		Screen,
+	},
+};
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct ErrorPopup {
+	/// What went wrong.
+	report: ErrorReport,
+}
+
+impl ErrorPopup {
+	/// Creates a popup showing an error's report.
+	pub fn new(report: ErrorReport) -> Self {
+		Self { report }
+	}
+}
+
+impl Screen for ErrorPopup {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(t!("error-title"), ScreenKind::Popup, None).with_static_frames()
+	}
+
+	fn handle_event(&mut self, event: &Event, state: &mut ScreenState) -> anyhow::Result<()> {
+		if let Event::Key(key) = event {
+			if key.code == KeyCode::Enter {
+				state.open_status = OpenStatus::Closed;
+			}
+		}
+		Ok(())
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let frame_area = frame.size();
+		let area = Rect {
+			x: frame_area.width / 5,
+			y: frame_area.height / 4,
+			width: frame_area.width / 5 * 3,
+			height: frame_area.height / 2,
+		};
+		Clear.render(area, frame.buffer_mut());
+		let text = format!(
+			"{}\n\n{}\n\n{}",
+			self.report.message,
+			self.report.hint,
+			t!("error-close")
+		);
+		let title = t!("error-title-code", code = self.report.code);
+		let popup = Paragraph::new(text)
+			.alignment(Alignment::Center)
+			.wrap(Wrap { trim: true })
+			.block(highlight_block(titled_ui_block(title)));
+		frame.render_widget(popup, area);
+	}
+}
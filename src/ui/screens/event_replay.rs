@@ -0,0 +1,272 @@
+//! A developer screen, opened with \[F11\] when Terminal Arcade runs with
+//! `--dev-tools`, that steps back through the [last events
+//! played](crate::core::event_history) and replays a range of them against a
+//! fresh screen, in a [harness](Harness), to see what they led to.
+//!
+//! The range replayed goes from the event marked with \[Space\], or from the
+//! oldest one, to the selected one.
+
+use ratatui::{
+	layout::{
+		Constraint,
+		Direction,
+		Layout,
+		Rect,
+	},
+	style::{
+		Modifier,
+		Style,
+	},
+	text::Line,
+	widgets::{
+		List,
+		ListState,
+		Paragraph,
+		Wrap,
+	},
+	Frame,
+};
+
+use crate::{
+	core::{
+		actions::Action,
+		event_history::{
+			EventHistory,
+			RecordedEvent,
+		},
+		events::{
+			Event,
+			KeyCode,
+		},
+		harness::Harness,
+		time::Instant,
+	},
+	games::{
+		Game,
+		Games,
+	},
+	t,
+	ui::{
+		components::presets::{
+			titled_ui_block,
+			untitled_ui_block,
+		},
+		screens::{
+			ScreenKind,
+			ScreenState,
+			Screens,
+		},
+		GameSearchScreen,
+		Screen,
+		WelcomeScreen,
+	},
+};
+
+/// What a replay led to.
+#[derive(Clone)]
+struct Replay {
+	/// Titles of the screens open after the replay, the active one last.
+	titles: Vec<&'static str>,
+
+	/// Text of the last frame drawn, line by line.
+	lines: Vec<String>,
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct EventReplayScreen {
+	/// The events that can be replayed, oldest first.
+	events: Vec<RecordedEvent>,
+
+	/// Index of the selected event, where the range replayed ends.
+	selected: usize,
+
+	/// Index of the event marked as the start of the range replayed, if any.
+	range_start: Option<usize>,
+
+	/// The screens the events can be replayed against, fresh.
+	targets: Vec<Screens>,
+
+	/// Index of the screen the events are replayed against.
+	target: usize,
+
+	/// Size of the terminal the events are replayed on.
+	size: (u16, u16),
+
+	/// What the last replay led to, if the events were replayed.
+	replay: Option<Replay>,
+
+	/// What happened after the last action, shown below the events.
+	status: String,
+}
+
+impl EventReplayScreen {
+	/// Creates a screen replaying events on a terminal of a size.
+	pub fn new(history: &EventHistory, width: u16, height: u16) -> Self {
+		let events: Vec<_> = history.events().cloned().collect();
+		let targets = [
+			WelcomeScreen::default().into(),
+			GameSearchScreen::default().into(),
+		]
+		.into_iter()
+		.chain(Games::all().into_iter().filter_map(|game| game.data().created_screen))
+		.collect();
+		Self {
+			selected: events.len().saturating_sub(1),
+			events,
+			range_start: None,
+			targets,
+			target: 0,
+			size: (width, height),
+			replay: None,
+			status: t!("event-replay-hint").to_string(),
+		}
+	}
+
+	/// Returns the first and last index of the range of events replayed.
+	fn range(&self) -> (usize, usize) {
+		let start = self.range_start.unwrap_or(0);
+		(start.min(self.selected), start.max(self.selected))
+	}
+
+	/// Returns the title of the screen the events are replayed against.
+	fn target_title(&self) -> &'static str {
+		self.targets[self.target].initial_state().title
+	}
+
+	/// Replays the range of events against a fresh screen.
+	fn replay(&mut self) {
+		if self.events.is_empty() {
+			return;
+		}
+		let (start, end) = self.range();
+		let (width, height) = self.size;
+		let mut harness = Harness::new(self.targets[self.target].clone(), width, height);
+		for event in &self.events[start..=end] {
+			harness.send(event.event.clone());
+		}
+		self.status = match harness.try_run_until_idle() {
+			Ok(()) => t!(
+				"event-replay-done",
+				count = end - start + 1,
+				screen = self.target_title()
+			),
+			Err(error) => t!("event-replay-failed", error = error.to_string()),
+		};
+		self.replay = Some(Replay {
+			titles: harness.titles(),
+			lines: harness.lines(),
+		});
+	}
+
+	/// Renders the events, the selected one highlighted and the ones in the
+	/// range replayed marked.
+	fn render_events(&self, frame: &mut Frame<'_>, area: Rect) {
+		let (start, end) = self.range();
+		let mut last_at: Option<Instant> = None;
+		let items: Vec<_> = self
+			.events
+			.iter()
+			.enumerate()
+			.map(|(index, event)| {
+				let delay = last_at.map_or(0, |last_at| (event.at - last_at).as_millis());
+				last_at = Some(event.at);
+				let marker = if (start..=end).contains(&index) { "▌" } else { " " };
+				format!("{marker}{index:>3} +{delay}ms {}", event.describe())
+			})
+			.collect();
+		let list = List::new(items)
+			.block(titled_ui_block(t!("event-replay-events")))
+			.highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+		let mut state = ListState::default().with_selected(Some(self.selected));
+		frame.render_stateful_widget(list, area, &mut state);
+	}
+
+	/// Renders what the last replay led to.
+	fn render_replay(&self, frame: &mut Frame<'_>, area: Rect) {
+		let Some(replay) = &self.replay else {
+			let empty = Paragraph::new(t!("event-replay-none"))
+				.block(titled_ui_block(t!("event-replay-preview")))
+				.wrap(Wrap { trim: true });
+			frame.render_widget(empty, area);
+			return;
+		};
+		let title = if replay.titles.is_empty() {
+			t!("event-replay-closed").to_string()
+		} else {
+			replay.titles.join(" › ")
+		};
+		let preview =
+			Paragraph::new(replay.lines.iter().cloned().map(Line::from).collect::<Vec<_>>())
+				.block(titled_ui_block(title));
+		frame.render_widget(preview, area);
+	}
+}
+
+impl Screen for EventReplayScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(
+			t!("event-replay-title"),
+			ScreenKind::Normal,
+			Some(vec![
+				("Space", t!("event-replay-mark")),
+				("Tab", t!("event-replay-target")),
+			]),
+		)
+		.with_actions(vec![
+			(&[Action::Up, Action::Down], t!("event-replay-select")),
+			(&[Action::First, Action::Last], t!("event-replay-jump")),
+			(&[Action::Confirm], t!("event-replay-control")),
+		])
+	}
+
+	fn handle_action(&mut self, action: Action, _state: &mut ScreenState) -> anyhow::Result<bool> {
+		let last = self.events.len().saturating_sub(1);
+		match action {
+			Action::Up => self.selected = self.selected.saturating_sub(1),
+			Action::Down => self.selected = (self.selected + 1).min(last),
+			Action::First => self.selected = 0,
+			Action::Last => self.selected = last,
+			Action::Confirm => self.replay(),
+			_ => return Ok(false),
+		}
+		Ok(true)
+	}
+
+	fn handle_event(&mut self, event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		let Event::Key(key) = event else {
+			return Ok(());
+		};
+		match key.code {
+			KeyCode::Char(' ') => {
+				self.range_start =
+					(self.range_start != Some(self.selected)).then_some(self.selected);
+			},
+			KeyCode::Tab => {
+				self.target = (self.target + 1) % self.targets.len();
+				self.status = t!("event-replay-target-picked", screen = self.target_title());
+			},
+			_ => {},
+		}
+		Ok(())
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let columns = Layout::default()
+			.direction(Direction::Horizontal)
+			.margin(1)
+			.constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+			.split(frame.size());
+		let rows = Layout::default()
+			.direction(Direction::Vertical)
+			.constraints([Constraint::Min(0), Constraint::Length(5)])
+			.split(columns[0]);
+		self.render_events(frame, rows[0]);
+		let status = Paragraph::new(self.status.clone())
+			.block(untitled_ui_block())
+			.wrap(Wrap { trim: true });
+		frame.render_widget(status, rows[1]);
+		self.render_replay(frame, columns[1]);
+	}
+}
@@ -0,0 +1,311 @@
+//! The screen listing each game's [save data](crate::services::game_data),
+//! opened from the settings. Games are marked with \[Space\] to act on several
+//! at once, or else the selected one is acted on: their data can be deleted,
+//! their statistics reset, or their files exported. Deleting and resetting are
+//! confirmed first, and the save data is [backed up](crate::services::backup)
+//! before.
+
+use std::collections::BTreeSet;
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Constraint,
+		Direction,
+		Layout,
+	},
+	widgets::{
+		Paragraph,
+		Wrap,
+	},
+	Frame,
+};
+
+use crate::{
+	core::{
+		actions::Action,
+		events::{
+			Event,
+			KeyCode,
+		},
+		get_save_dir,
+	},
+	services::{
+		backup::Backup,
+		game_data::{
+			open_folder,
+			size_text,
+			GameData,
+		},
+	},
+	t,
+	ui::{
+		components::presets::untitled_ui_block,
+		screens::{
+			ScreenKind,
+			ScreenState,
+		},
+		widgets::data_table::{
+			Column,
+			DataRow,
+			DataTable,
+		},
+		Screen,
+	},
+};
+
+/// An action on games' data that's confirmed first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PendingAction {
+	/// Deletes the games' save files.
+	Delete,
+
+	/// Resets the games' statistics.
+	ResetStats,
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct GameDataScreen {
+	/// Each game's save data.
+	games: Vec<GameData>,
+
+	/// Table of the games, holding their name.
+	games_table: Option<DataTable<String>>,
+
+	/// Names of the games marked to be acted on together.
+	marked: BTreeSet<String>,
+
+	/// The action waiting to be confirmed, if any.
+	pending: Option<PendingAction>,
+
+	/// What happened after the last action, shown below the table.
+	status: String,
+}
+
+impl Default for GameDataScreen {
+	fn default() -> Self {
+		let mut screen = Self {
+			games: Vec::new(),
+			games_table: None,
+			marked: BTreeSet::new(),
+			pending: None,
+			status: t!("data-hint").to_string(),
+		};
+		screen.refresh();
+		screen
+	}
+}
+
+impl GameDataScreen {
+	/// Looks up the games' save data again, keeping the sort and the
+	/// selection.
+	fn refresh(&mut self) {
+		self.games = GameData::list();
+		let rows: Vec<_> = self
+			.games
+			.iter()
+			.map(|game| {
+				let mark = if self.marked.contains(&game.name) { "✓" } else { "" };
+				DataRow::new(
+					vec![
+						mark.to_string(),
+						game.name.clone(),
+						game.files.len().to_string(),
+						size_text(game.size),
+						game.modified_text(),
+					],
+					game.name.clone(),
+				)
+			})
+			.collect();
+		if rows.is_empty() {
+			self.games_table = None;
+		} else if let Some(table) = &mut self.games_table {
+			table.update_rows(rows);
+		} else {
+			let columns = vec![
+				Column::new(String::new(), 1),
+				Column::new(t!("data-game").to_string(), 8),
+				Column::new(t!("data-files").to_string(), 5),
+				Column::new(t!("data-size").to_string(), 8),
+				Column::new(t!("data-modified").to_string(), 10),
+			];
+			self.games_table = Some(DataTable::new(columns, rows));
+		}
+	}
+
+	/// Returns the games acted on: the marked ones, or else the selected one.
+	fn targets(&self) -> Vec<&GameData> {
+		let selected = self.games_table.as_ref().and_then(DataTable::get_selected);
+		self.games
+			.iter()
+			.filter(|game| {
+				if self.marked.is_empty() {
+					selected.is_some_and(|row| row.data == game.name)
+				} else {
+					self.marked.contains(&game.name)
+				}
+			})
+			.collect()
+	}
+
+	/// Returns the names of the games acted on, separated by commas.
+	fn targets_text(&self) -> String {
+		self.targets().iter().map(|game| game.name.as_str()).collect::<Vec<_>>().join(", ")
+	}
+
+	/// Marks the selected game, or unmarks it if it's marked.
+	fn toggle_mark(&mut self) {
+		let Some(row) = self.games_table.as_ref().and_then(DataTable::get_selected) else {
+			return;
+		};
+		let name = row.data.clone();
+		if !self.marked.remove(&name) {
+			self.marked.insert(name);
+		}
+		self.refresh();
+	}
+
+	/// Asks to confirm an action on the games acted on, if there are any.
+	fn ask(&mut self, action: PendingAction, state: &mut ScreenState) {
+		let games = self.targets_text();
+		if games.is_empty() {
+			return;
+		}
+		let message = match action {
+			PendingAction::Delete => t!("data-confirm-delete", games = games),
+			PendingAction::ResetStats => t!("data-confirm-reset", games = games),
+		};
+		self.pending = Some(action);
+		state.ask_confirmation(message);
+	}
+
+	/// Does an action on each game acted on, then tells how it went and
+	/// unmarks the games.
+	fn run(
+		&mut self,
+		action: impl Fn(&GameData) -> anyhow::Result<()>,
+		done: impl FnOnce(String) -> String,
+	) {
+		let games = self.targets_text();
+		if games.is_empty() {
+			return;
+		}
+		let result = self.targets().into_iter().try_for_each(action);
+		self.status = match result {
+			Ok(()) => done(games),
+			Err(error) => t!("data-failed", error = error.to_string()),
+		};
+		self.marked.clear();
+		self.refresh();
+	}
+
+	/// Exports the files of each game acted on.
+	fn export(&mut self) {
+		self.run(
+			|game| game.export().map(|_| ()),
+			|games| t!("data-exported", games = games),
+		);
+	}
+
+	/// Opens the save directory in the system's file manager.
+	fn open_folder(&mut self) {
+		if let Err(error) = open_folder(&get_save_dir()) {
+			self.status = t!("data-open-failed", error = error.to_string());
+		}
+	}
+}
+
+impl Screen for GameDataScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(
+			t!("data-title"),
+			ScreenKind::Normal,
+			Some(vec![
+				("Space", t!("data-mark-control")),
+				("Delete", t!("data-delete-control")),
+				("R", t!("data-reset-control")),
+				("X", t!("data-export-control")),
+				("O", t!("data-open-control")),
+				("S", t!("data-sort-control")),
+			]),
+		)
+		.with_actions(vec![(&[Action::Up, Action::Down], t!("data-select"))])
+		.with_static_frames()
+	}
+
+	fn handle_event(&mut self, event: &Event, state: &mut ScreenState) -> anyhow::Result<()> {
+		if self.games_table.as_mut().is_some_and(|table| table.handle_event(event)) {
+			return Ok(());
+		}
+		let Event::Key(key) = event else {
+			return Ok(());
+		};
+		match key.code {
+			KeyCode::Char(' ') => self.toggle_mark(),
+			KeyCode::Delete => self.ask(PendingAction::Delete, state),
+			KeyCode::Char('r' | 'R') => self.ask(PendingAction::ResetStats, state),
+			KeyCode::Char('x' | 'X') => self.export(),
+			KeyCode::Char('o' | 'O') => self.open_folder(),
+			KeyCode::Char('s' | 'S') => {
+				self.games_table.iter_mut().for_each(DataTable::cycle_sort);
+			},
+			_ => {},
+		}
+		Ok(())
+	}
+
+	fn handle_action(&mut self, action: Action, _state: &mut ScreenState) -> anyhow::Result<bool> {
+		match action {
+			Action::Up => self.games_table.iter_mut().for_each(DataTable::select_previous),
+			Action::Down => self.games_table.iter_mut().for_each(DataTable::select_next),
+			_ => return Ok(false),
+		}
+		Ok(true)
+	}
+
+	fn answer_confirmation(
+		&mut self,
+		confirmed: bool,
+		_state: &mut ScreenState,
+	) -> anyhow::Result<()> {
+		let Some(action) = self.pending.take().filter(|_| confirmed) else {
+			return Ok(());
+		};
+		if let Err(error) = Backup::create() {
+			self.status = t!("data-backup-failed", error = error.to_string());
+			return Ok(());
+		}
+		match action {
+			PendingAction::Delete => {
+				self.run(GameData::delete, |games| t!("data-deleted", games = games));
+			},
+			PendingAction::ResetStats => {
+				self.run(GameData::reset_stats, |games| {
+					t!("data-reset", games = games)
+				});
+			},
+		}
+		Ok(())
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.margin(1)
+			.constraints([Constraint::Min(0), Constraint::Length(4)])
+			.split(frame.size());
+		if let Some(table) = &self.games_table {
+			table.render(frame, chunks[0], untitled_ui_block());
+		} else {
+			let empty = Paragraph::new(t!("data-none")).alignment(Alignment::Center);
+			frame.render_widget(empty, chunks[0]);
+		}
+		let status = Paragraph::new(self.status.clone())
+			.block(untitled_ui_block())
+			.wrap(Wrap { trim: true });
+		frame.render_widget(status, chunks[1]);
+	}
+}
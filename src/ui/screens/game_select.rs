@@ -1,17 +1,26 @@
 //! A game-selection screen.
 //! Users can scroll through the list with arrows to look for a game they want,
-//! search a game by its name, or pick a game at random.
+//! search a game by its name, or pick a game at random. Searches also find
+//! [settings and help topics](crate::ui::search), listed after the games.
+//! Pressing a number from 1 to 9 opens the result with that number, if it's
+//! shown. Before any result is selected, the up arrow recalls [recent
+//! searches](crate::core::search_history).
+//!
+//! The selected game's [preview](GamePreview) is shown next to the results.
+//! On [wide](LayoutSize::Wide) terminals, the results only show the games'
+//! descriptions, and everything else about the selected game, including the
+//! player's best scores, is shown in a column of its own.
 
-use std::cmp::{
-	max,
-	min,
+use std::{
+	cell::RefCell,
+	cmp::{
+		max,
+		min,
+	},
+	collections::HashMap,
+	time::Duration,
 };
 
-use crossterm::event::{
-	Event,
-	KeyCode,
-	KeyModifiers,
-};
 use rand::Rng;
 use ratatui::{
 	layout::{
@@ -19,18 +28,45 @@ use ratatui::{
 		Constraint,
 		Direction,
 		Layout,
+		Margin,
 		Rect,
 	},
-	widgets::Paragraph,
+	widgets::{
+		Paragraph,
+		Wrap,
+	},
 	Frame,
 };
 use strum::IntoEnumIterator;
 
+#[cfg(feature = "images")]
+use crate::ui::widgets::image::TerminalImage;
 use crate::{
+	core::{
+		actions::Action,
+		config::Config,
+		events::{
+			Event,
+			KeyCode,
+			KeyModifiers,
+		},
+		search_history::SearchHistory,
+		time::Instant,
+	},
 	games::{
+		clock_text,
+		difficulty::{
+			DifficultyRating,
+			DifficultySort,
+		},
+		preview::GamePreview,
+		registry::GameId,
 		Game,
+		GameDynamicInfo,
+		GameStaticInfo,
 		Games,
 	},
+	t,
 	ui::{
 		components::{
 			game_select::{
@@ -42,10 +78,13 @@ use crate::{
 				untitled_ui_block,
 			},
 		},
+		layout::LayoutSize,
 		screens::{
 			ScreenKind,
 			ScreenState,
 		},
+		search::SearchResult,
+		text,
 		widgets::scrollable_list::ScrollableList,
 		Screen,
 	},
@@ -65,78 +104,180 @@ pub struct GameSearchScreen {
 	/// Search term, inputted by the user.
 	search_term: Option<String>,
 
-	/// Search results.
-	search_results: Vec<Games>,
+	/// Recent searches, recalled with the up arrow.
+	search_history: SearchHistory,
+
+	/// Index of the recent search in the search bar, if it was recalled and not
+	/// edited since.
+	recalled_search: Option<usize>,
+
+	/// Search results: the games, then anything else found.
+	search_results: Vec<SearchResult>,
 
 	/// Scrollable list widget for display.
-	game_results_list: ScrollableList<Games>,
+	game_results_list: ScrollableList<SearchResult>,
+
+	/// The only difficulty shown, if the results are filtered by difficulty.
+	difficulty_filter: Option<DifficultyRating>,
+
+	/// How the results are sorted by difficulty.
+	difficulty_sort: DifficultySort,
 
 	/// Time spent to search and filter the results, in seconds.
 	time_to_search_secs: f64,
+
+	/// Decoded [thumbnails](Game::thumbnail) of the games, by name.
+	#[cfg(feature = "images")]
+	thumbnails: HashMap<String, TerminalImage>,
+
+	/// Static info of the games, by name, for their descriptions and
+	/// [previews](GamePreview).
+	static_infos: HashMap<String, GameStaticInfo>,
+
+	/// Dynamic info of the selected game, by its ID, loaded when shown in
+	/// the [wide](LayoutSize::Wide) layout and reloaded once back from a game.
+	selected_info: RefCell<Option<(GameId, GameDynamicInfo)>>,
+
+	/// When the screen was opened, to animate previews.
+	opened: Instant,
+
+	/// Whether previews are kept still, with [reduced
+	/// motion](Config::reduced_motion) on.
+	reduced_motion: bool,
+
+	/// Whether the screen is played by a connection of the [telnet
+	/// server](crate::services::telnet), which leaves settings out of the
+	/// results.
+	served: bool,
 }
 
 impl Default for GameSearchScreen {
 	fn default() -> Self {
-		let all_games: Vec<_> = Games::iter().collect();
+		let all_games: Vec<_> = Games::all().into_iter().map(SearchResult::Game).collect();
 		Self {
 			search_term: None,
-			search_results: all_games.clone(),
+			search_history: SearchHistory::load_or_default(),
+			recalled_search: None,
 			game_results_list: ScrollableList::new(
-				all_games.into_iter().map(|game| game.data().metadata.get_list_entry()).collect(),
+				all_games.iter().map(SearchResult::get_list_entry).collect(),
 				Some(5),
 				3,
 				Direction::Vertical,
 				Alignment::Center,
 				Some((1, 3)),
 				None,
-			),
+			)
+			.with_expanded_selection(true),
+			search_results: all_games,
+			difficulty_filter: None,
+			difficulty_sort: DifficultySort::default(),
 			time_to_search_secs: 0.0,
+			#[cfg(feature = "images")]
+			thumbnails: Games::all()
+				.into_iter()
+				.filter_map(|game| {
+					let image = TerminalImage::from_png(game.thumbnail()?).ok()?;
+					Some((game.data().metadata.static_info.name, image))
+				})
+				.collect(),
+			static_infos: Games::all()
+				.into_iter()
+				.map(|game| {
+					let info = game.data().metadata.static_info;
+					(info.name.clone(), info)
+				})
+				.collect(),
+			selected_info: RefCell::default(),
+			opened: Instant::now(),
+			reduced_motion: Config::load_or_default().reduced_motion,
+			served: false,
 		}
 	}
 }
 
 impl Screen for GameSearchScreen {
 	fn initial_state(&self) -> ScreenState {
-		ScreenState::new("Search for a game!", ScreenKind::Normal, None)
+		ScreenState::new(
+			t!("search-title"),
+			ScreenKind::Normal,
+			Some(vec![
+				("Ctrl + F", t!("search-filter-control")),
+				("Ctrl + S", t!("search-sort-control")),
+				("1-9", t!("search-launch-control")),
+			]),
+		)
 	}
 
 	fn handle_event(&mut self, event: &Event, state: &mut ScreenState) -> anyhow::Result<()> {
 		if let Event::Key(key) = event {
 			match key.code {
+				// Numbers launch results rather than being searched for.
+				KeyCode::Char(digit @ '1'..='9') if key.modifiers == KeyModifiers::NONE => {
+					self.launch_numbered(digit, state)?;
+				},
 				KeyCode::Char('r') if key.modifiers == KeyModifiers::CONTROL => {
 					self.game_results_list.scroll_to_random();
 				},
 				KeyCode::Char('d') if key.modifiers == KeyModifiers::CONTROL => {
 					self.clear_search_term();
 				},
+				KeyCode::Char('f') if key.modifiers == KeyModifiers::CONTROL => {
+					self.difficulty_filter = self
+						.difficulty_filter
+						.map_or(Some(DifficultyRating::Easy), DifficultyRating::harder);
+					self.update_search_results();
+				},
+				KeyCode::Char('s') if key.modifiers == KeyModifiers::CONTROL => {
+					self.difficulty_sort = self.difficulty_sort.next();
+					self.update_search_results();
+				},
 				KeyCode::Backspace => self.pop_one_character(),
 				KeyCode::Char(character)
 					if [KeyModifiers::SHIFT, KeyModifiers::NONE].contains(&key.modifiers) =>
 				{
 					self.add_character_to_term(character, key.modifiers);
 				},
-				KeyCode::Up => {
-					self.game_results_list.scroll_forward();
-				},
-				KeyCode::Down => {
-					self.game_results_list.scroll_backward();
-				},
-				KeyCode::Left => self.decrease_searches_shown(),
-				KeyCode::Right => self.increase_searches_shown(),
-				KeyCode::Enter if self.game_results_list.get_selected().is_some() => {
-					self.select_game(state);
-				},
 				_ => {},
 			}
 		}
 		Ok(())
 	}
 
+	fn handle_action(&mut self, action: Action, state: &mut ScreenState) -> anyhow::Result<bool> {
+		match action {
+			Action::Up if self.can_recall_search() => self.recall_search(),
+			Action::Up => self.game_results_list.scroll_forward(),
+			Action::Down => self.game_results_list.scroll_backward(),
+			Action::Left => self.decrease_searches_shown(),
+			Action::Right => self.increase_searches_shown(),
+			Action::Confirm if self.game_results_list.get_selected().is_some() => {
+				self.select_game(state)?;
+			},
+			_ => return Ok(false),
+		}
+		Ok(true)
+	}
+
+	fn resume(&mut self) {
+		// The game played since may have new play counts and best scores.
+		self.selected_info.take();
+		self.update_results_list();
+	}
+
 	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
 		let size = frame.size();
 		let chunks = Self::game_selection_layout(size).split(size);
-		render_search_section(frame, chunks[0], self.search_term.as_deref());
-		self.game_results_list.render(frame, chunks[1]);
+		render_search_section(
+			frame,
+			chunks[0],
+			self.search_term.as_deref(),
+			self.search_history.get(0),
+			&self.difficulty_text(),
+		);
+		self.render_results(frame, chunks[1]);
+		if LayoutSize::of(size).is_compact() {
+			return;
+		}
 		render_search_bottom_bar(
 			frame,
 			chunks[2],
@@ -148,18 +289,30 @@ impl Screen for GameSearchScreen {
 }
 
 impl GameSearchScreen {
-	/// Returns the layout for the game selection screen.
+	/// Creates the search screen for a connection of the [telnet
+	/// server](crate::services::telnet), finding no settings to change.
+	#[must_use]
+	pub fn served() -> Self {
+		Self {
+			served: true,
+			..Self::default()
+		}
+	}
+
+	/// Returns the layout for the game selection screen. The bottom info row
+	/// makes way for the results on [compact](LayoutSize::Compact) terminals.
 	#[must_use]
 	fn game_selection_layout(size: Rect) -> Layout {
 		let search_section_height = 3;
-		let used_ui_height = search_section_height + 3 + 2;
+		let bottom_row_height = if LayoutSize::of(size).is_compact() { 0 } else { 3 };
+		let used_ui_height = search_section_height + bottom_row_height + 2;
 		let search_results_height =
 			if used_ui_height >= size.height { 10 } else { size.height - used_ui_height };
 
 		let constraints = vec![
 			Constraint::Max(search_section_height), // Search bar/section
 			Constraint::Max(search_results_height), // Search results
-			Constraint::Max(3),                     // Search bottom info row
+			Constraint::Max(bottom_row_height),     // Search bottom info row
 			Constraint::Max(0),                     /* Prevents elements from taking all
 			                                         * remaining space. */
 		];
@@ -170,17 +323,254 @@ impl GameSearchScreen {
 			.constraints(constraints)
 	}
 
-	/// Selects a game.
-	fn select_game(&mut self, state: &mut ScreenState) {
-		if let Some(selection) = self.game_results_list.get_selected() {
-			state.screen_created = selection.1.data.data().created_screen.take();
+	/// Returns whether a game has a thumbnail or a [preview](GamePreview) to
+	/// show.
+	fn has_preview(&self, name: &str) -> bool {
+		#[cfg(feature = "images")]
+		if self.thumbnails.contains_key(name) {
+			return true;
 		}
+		self.static_infos.get(name).is_some_and(|info| info.preview.is_some())
+	}
+
+	/// Returns the name of the selected result, if it's a game.
+	fn selected_game(&self) -> Option<&str> {
+		self.game_results_list
+			.get_selected()
+			.filter(|(_, item)| matches!(item.data, SearchResult::Game(_)))
+			.and_then(|(_, item)| item.name.as_deref())
 	}
 
-	/// Updates the search results.
+	/// Renders the search results, next to the selected game's details on
+	/// [wide](LayoutSize::Wide) terminals, or else next to its thumbnail or
+	/// [preview](GamePreview) if it has one and the terminal isn't
+	/// [compact](LayoutSize::Compact).
+	fn render_results(&self, frame: &mut Frame<'_>, area: Rect) {
+		if LayoutSize::of(frame.size()) == LayoutSize::Wide {
+			self.render_columns(frame, area);
+			return;
+		}
+		let Some(name) = self
+			.selected_game()
+			.filter(|name| self.has_preview(name))
+			.filter(|_| !LayoutSize::of(frame.size()).is_compact())
+		else {
+			self.game_results_list.render(frame, area);
+			return;
+		};
+		let chunks = Layout::default()
+			.direction(Direction::Horizontal)
+			.constraints([Constraint::Min(0), Constraint::Length(32)])
+			.split(area);
+		self.game_results_list.render(frame, chunks[0]);
+		self.render_preview(frame, chunks[1], name);
+	}
+
+	/// Renders the results as a column of descriptions, next to a column with
+	/// everything about the selected result.
+	fn render_columns(&self, frame: &mut Frame<'_>, area: Rect) {
+		let chunks = Layout::default()
+			.direction(Direction::Horizontal)
+			.constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+			.split(area);
+		// The details column already shows all of the selected game's metadata.
+		let list = self.game_results_list.clone().with_expanded_selection(false);
+		list.render_processed(frame, chunks[0], |item| {
+			let text = match &item.data {
+				SearchResult::Game(_) => item
+					.name
+					.as_ref()
+					.and_then(|name| self.static_infos.get(name))
+					.map_or_else(String::new, |info| info.description.clone()),
+				_ => item.get_displayed_data(),
+			};
+			Paragraph::new(text).wrap(Wrap { trim: true })
+		});
+		self.render_details(frame, chunks[1]);
+	}
+
+	/// Renders everything about the selected result: a game's preview, its
+	/// metadata and the player's best scores, or what a setting or a help topic
+	/// is about.
+	fn render_details(&self, frame: &mut Frame<'_>, area: Rect) {
+		let Some(info) = self.selected_game().and_then(|name| self.static_infos.get(name)) else {
+			let Some((_, item)) = self.game_results_list.get_selected() else {
+				frame.render_widget(untitled_ui_block(), area);
+				return;
+			};
+			let block = titled_ui_block(item.name.clone().unwrap_or_default());
+			let details =
+				Paragraph::new(item.get_displayed_data()).wrap(Wrap { trim: true }).block(block);
+			frame.render_widget(details, area);
+			return;
+		};
+		let block = titled_ui_block(info.name.clone());
+		let inner = block.inner(area).inner(&Margin::new(2, 1));
+		frame.render_widget(block, area);
+		let preview_height = if self.has_preview(&info.name) {
+			info.preview.as_ref().map_or(inner.height / 2, |preview| {
+				let lines = preview.frame_at(Duration::ZERO).lines().count();
+				u16::try_from(lines).unwrap_or(u16::MAX).saturating_add(2)
+			})
+		} else {
+			0
+		};
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.constraints([
+				Constraint::Length(preview_height),
+				Constraint::Length(u16::from(preview_height > 0)),
+				Constraint::Min(0),
+			])
+			.split(inner);
+		if preview_height > 0 {
+			self.render_preview(frame, chunks[0], &info.name);
+		}
+		let mut selected_info = self.selected_info.borrow_mut();
+		if selected_info.as_ref().is_none_or(|(id, _)| *id != info.id) {
+			let dynamic_info = GameDynamicInfo::load_or_default(&info.id).unwrap_or_default();
+			*selected_info = Some((info.id.clone(), dynamic_info));
+		}
+		let Some((_, dynamic_info)) = selected_info.as_ref() else {
+			return;
+		};
+		let details =
+			Paragraph::new(Self::details_text(info, dynamic_info)).wrap(Wrap { trim: false });
+		frame.render_widget(details, chunks[2]);
+	}
+
+	/// Returns a game's metadata, followed by the player's best score in each
+	/// of its modes and their best time.
+	fn details_text(info: &GameStaticInfo, dynamic_info: &GameDynamicInfo) -> String {
+		let mut lines = vec![
+			info.description.clone(),
+			String::new(),
+			t!("search-created", created = info.created_text()),
+			t!(
+				"search-difficulty-stars",
+				difficulty = info.difficulty.stars_text()
+			),
+			dynamic_info.play_text(),
+			String::new(),
+			t!("search-best-scores").to_string(),
+		];
+		lines.extend(info.game.modes().into_iter().map(|mode| {
+			dynamic_info.high_score_in(mode).map_or_else(
+				|| t!("search-no-best-score", mode = mode.label()),
+				|score| t!("search-best-score", mode = mode.label(), score = score),
+			)
+		}));
+		if let Some(secs) = dynamic_info.best_time_secs {
+			lines.push(t!("search-best-time", time = clock_text(secs)));
+		}
+		lines.join("\n")
+	}
+
+	/// Renders a game's thumbnail or [preview](GamePreview), centered in a
+	/// block.
+	fn render_preview(&self, frame: &mut Frame<'_>, area: Rect, name: &str) {
+		let block = titled_ui_block(t!("search-preview"));
+		let preview_area = block.inner(area);
+		frame.render_widget(block, area);
+		#[cfg(feature = "images")]
+		if let Some(thumbnail) = self.thumbnails.get(name) {
+			thumbnail.render(frame, preview_area);
+			return;
+		}
+		if let Some(preview) = self.static_infos.get(name).and_then(|info| info.preview.as_ref()) {
+			let elapsed = if self.reduced_motion { Duration::ZERO } else { self.opened.elapsed() };
+			let art = preview.frame_at(elapsed);
+			// Centers the art as a whole, keeping its lines aligned.
+			let width = art.lines().map(text::width).max().unwrap_or_default();
+			let width = u16::try_from(width).unwrap_or(u16::MAX);
+			let height = u16::try_from(art.lines().count()).unwrap_or(u16::MAX);
+			let left = preview_area.width.saturating_sub(width) / 2;
+			let top = preview_area.height.saturating_sub(height) / 2;
+			let art_area = Rect {
+				x: preview_area.x + left,
+				y: preview_area.y + top,
+				width: preview_area.width - left,
+				height: preview_area.height - top,
+			};
+			frame.render_widget(Paragraph::new(art), art_area);
+		}
+	}
+
+	/// Opens the selected result. Games are launched right away, or from the
+	/// mode selector if they have several [modes](Game::modes). The search
+	/// that found the result is remembered.
+	fn select_game(&mut self, state: &mut ScreenState) -> anyhow::Result<()> {
+		let Some((_, item)) = self.game_results_list.get_selected() else {
+			return Ok(());
+		};
+		state.create_screens(item.data.screen());
+		if let Some(term) = &self.search_term {
+			self.search_history.record(term);
+			self.search_history.save()?;
+		}
+		Ok(())
+	}
+
+	/// Opens the result numbered with a digit, if it's shown.
+	fn launch_numbered(&mut self, digit: char, state: &mut ScreenState) -> anyhow::Result<()> {
+		let Some(index) = digit.to_digit(10).and_then(|number| number.checked_sub(1)) else {
+			return Ok(());
+		};
+		let index = index as usize;
+		if self.game_results_list.is_displayed(index) {
+			self.game_results_list.select(index);
+			self.select_game(state)?;
+		}
+		Ok(())
+	}
+
+	/// Returns whether the up arrow recalls a recent search: before any result
+	/// is selected, with the search bar empty or holding a recalled search, and
+	/// an older search to recall.
+	fn can_recall_search(&self) -> bool {
+		let next = self.recalled_search.map_or(0, |index| index + 1);
+		self.game_results_list.get_selected().is_none()
+			&& (self.search_term.is_none() || self.recalled_search.is_some())
+			&& self.search_history.get(next).is_some()
+	}
+
+	/// Puts the recent search older than the one in the search bar in it.
+	fn recall_search(&mut self) {
+		let index = self.recalled_search.map_or(0, |index| index + 1);
+		let Some(term) = self.search_history.get(index) else {
+			return;
+		};
+		self.search_term = Some(term.to_string());
+		self.recalled_search = Some(index);
+		self.update_search_results();
+	}
+
+	/// Returns the difficulty filter and sort, as shown next to the search bar.
+	fn difficulty_text(&self) -> String {
+		let filter = self.difficulty_filter.map_or_else(
+			|| t!("difficulty-all").to_string(),
+			DifficultyRating::stars_text,
+		);
+		format!("{filter} · {}", self.difficulty_sort.label())
+	}
+
+	/// Updates the search results, filtered and sorted by difficulty.
 	fn update_search_results(&mut self) {
-		let timer = std::time::Instant::now();
-		self.search_results = Games::get_by_search_term(&self.search_term);
+		let timer = crate::core::time::Instant::now();
+		let mut games = Games::get_by_search_term(&self.search_term);
+		if let Some(difficulty) = self.difficulty_filter {
+			games.retain(|game| game.data().metadata.static_info.difficulty == difficulty);
+		}
+		self.difficulty_sort.sort(&mut games);
+		self.search_results = games.into_iter().map(SearchResult::Game).collect();
+		// Only games have a difficulty, so filtering by it leaves the rest out.
+		if let (Some(term), None) = (&self.search_term, self.difficulty_filter) {
+			self.search_results.extend(
+				SearchResult::search_everything_else(term)
+					.into_iter()
+					.filter(|result| !self.served || !matches!(result, SearchResult::Setting(_))),
+			);
+		}
 		self.update_results_list();
 		self.time_to_search_secs = timer.elapsed().as_secs_f64();
 	}
@@ -188,9 +578,8 @@ impl GameSearchScreen {
 	/// Updates the [`Self::game_results_list`] property from the
 	/// [`Self::search_results`] property.
 	fn update_results_list(&mut self) {
-		self.game_results_list.update_items(
-			self.search_results.iter().map(|game| game.data().metadata.get_list_entry()).collect(),
-		);
+		self.game_results_list
+			.update_items(self.search_results.iter().map(SearchResult::get_list_entry).collect());
 	}
 
 	/// Adds the character to the search term object, capping out at 256
@@ -203,12 +592,14 @@ impl GameSearchScreen {
 			Some(ref mut term) if term.len() < 100 => term.push(character),
 			Some(_) => panic!("Logic went flying all around the plane of existence"),
 		}
+		self.recalled_search = None;
 		self.update_search_results();
 	}
 
 	/// Clears the search term.
 	fn clear_search_term(&mut self) {
 		self.search_term = None;
+		self.recalled_search = None;
 		self.update_search_results();
 	}
 
@@ -221,6 +612,7 @@ impl GameSearchScreen {
 				self.search_term = None;
 			}
 		}
+		self.recalled_search = None;
 		self.update_search_results();
 	}
 
@@ -240,3 +632,39 @@ impl GameSearchScreen {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use insta::{
+		assert_snapshot,
+		with_settings,
+	};
+
+	use super::GameSearchScreen;
+	use crate::core::{
+		files,
+		harness::render_snapshot,
+	};
+
+	#[test]
+	#[cfg(all(
+		feature = "minesweeper",
+		feature = "tron",
+		feature = "twenty-forty-eight",
+		feature = "sudoku",
+		feature = "chess",
+		feature = "klondike"
+	))]
+	fn game_search_screen_snapshot() {
+		files::isolate_test_files();
+		with_settings!({
+			filters => vec![
+				(r".*(🆕 Never played before!|🕹️ Played ).*", "[play history]"),
+				(r".*Finished in \d+ seconds?.*", "[search statistics]"),
+				(r".*╭─+\d+ ─ .*", "[entry title]"),
+			]
+		}, {
+			assert_snapshot!(render_snapshot(GameSearchScreen::default().into(), 120, 40));
+		});
+	}
+}
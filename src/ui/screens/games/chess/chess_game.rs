@@ -0,0 +1,462 @@
+//! The screen of a game of Chess, picking pieces up and moving them with the
+//! cursor, against the computer or another player taking turns on the same
+//! keyboard, passing it over in [hotseat](crate::games::hotseat) after each
+//! move.
+
+use std::time::Duration;
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Constraint,
+		Direction,
+		Layout,
+	},
+	widgets::Paragraph,
+	Frame,
+};
+
+use crate::{
+	core::{
+		actions::Action,
+		events::Event,
+		time::Instant,
+	},
+	games::{
+		chess::{
+			ai::Search,
+			board::{
+				square_name,
+				Board,
+				Move,
+				PieceKind,
+				Side,
+				Square,
+				Status,
+				SIZE,
+			},
+			setup::{
+				ChessSetup,
+				Opponent,
+			},
+			GAME_ID,
+			HELP_PAGE,
+		},
+		hotseat::{
+			Hotseat,
+			HotseatPlayer,
+			PLAYER_COLORS,
+		},
+		modes::GameMode,
+		registry::GameId,
+		GameDynamicInfo,
+		GameResult,
+	},
+	services::rating::MatchResult,
+	t,
+	ui::{
+		components::{
+			games::chess::board::{
+				render_board,
+				BoardHighlights,
+			},
+			presets::untitled_ui_block,
+			results_banner::{
+				ResultsBanner,
+				Verdict,
+			},
+		},
+		screens::{
+			ScreenKind,
+			ScreenState,
+		},
+		Screen,
+	},
+};
+
+/// Moves after which a game counts as significant progress, asking to confirm
+/// before quickly restarting it.
+const SIGNIFICANT_MOVES: usize = 10;
+
+/// Returns a side's name.
+fn side_name(side: Side) -> &'static str {
+	match side {
+		Side::White => t!("chess-white"),
+		Side::Black => t!("chess-black"),
+	}
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct ChessGameScreen {
+	/// The answers to the setup screen.
+	setup: ChessSetup,
+
+	/// The board being played on.
+	board: Board,
+
+	/// The square under the cursor.
+	cursor: Square,
+
+	/// The square of the piece picked up, if any.
+	selected: Option<Square>,
+
+	/// The moves the piece picked up can play.
+	targets: Vec<Move>,
+
+	/// The last move played, if any.
+	last_move: Option<Move>,
+
+	/// Moves played by both sides so far.
+	moves: usize,
+
+	/// The computer's search for its move, while it thinks.
+	search: Option<Search>,
+
+	/// When the game was started, moved forward by the time spent paused.
+	started: Instant,
+
+	/// When the game was [paused](Screen::pause), if it is.
+	paused_at: Option<Instant>,
+
+	/// Banner announcing how the game ended, shown once it's over.
+	results: Option<ResultsBanner>,
+
+	/// White and Black taking turns on the keyboard, when playing against
+	/// another player.
+	hotseat: Option<Hotseat>,
+}
+
+impl ChessGameScreen {
+	/// Starts a game from the starting position, counting a play of the game.
+	pub fn new(setup: ChessSetup) -> Self {
+		let _ = GameDynamicInfo::update(&GameId::from(GAME_ID), GameDynamicInfo::play);
+		Self {
+			setup,
+			board: Board::default(),
+			cursor: (4, 1),
+			selected: None,
+			targets: Vec::new(),
+			last_move: None,
+			moves: 0,
+			search: None,
+			started: Instant::now(),
+			paused_at: None,
+			results: None,
+			hotseat: (setup.opponent == Opponent::Human).then(|| {
+				Hotseat::new(
+					[Side::White, Side::Black]
+						.into_iter()
+						.zip(PLAYER_COLORS)
+						.map(|(side, color)| HotseatPlayer {
+							name: side_name(side).to_string(),
+							color,
+						})
+						.collect(),
+				)
+			}),
+		}
+	}
+
+	/// Returns how long the game has been played for, leaving out the time
+	/// spent paused.
+	fn elapsed(&self) -> Duration {
+		self.paused_at.unwrap_or_else(Instant::now).duration_since(self.started)
+	}
+
+	/// Returns whether the side to move is played from the keyboard.
+	fn is_human_turn(&self) -> bool {
+		self.setup.opponent == Opponent::Human || self.board.turn() == Side::White
+	}
+
+	/// Moves the cursor by a step, staying on the board.
+	fn move_cursor(&mut self, files: isize, ranks: isize) {
+		let (file, rank) = self.cursor;
+		self.cursor = (
+			file.saturating_add_signed(files).min(SIZE - 1),
+			rank.saturating_add_signed(ranks).min(SIZE - 1),
+		);
+	}
+
+	/// Picks up the piece under the cursor, or moves the piece picked up
+	/// there. Pawns reaching the last rank are promoted to queens.
+	fn select(&mut self, state: &mut ScreenState) {
+		if !self.is_human_turn() {
+			return;
+		}
+		let target = self.targets.iter().copied().find(|mv| {
+			mv.to == self.cursor && mv.promotion.is_none_or(|kind| kind == PieceKind::Queen)
+		});
+		if let Some(mv) = target {
+			self.play(mv, state);
+			return;
+		}
+		let own = self.board.get(self.cursor).is_some_and(|piece| piece.side == self.board.turn());
+		if own && self.selected != Some(self.cursor) {
+			self.selected = Some(self.cursor);
+			self.targets = self.board.legal_moves_from(self.cursor);
+		} else {
+			self.selected = None;
+			self.targets.clear();
+		}
+	}
+
+	/// Plays a legal move, ending the game if it's over, or passing the
+	/// keyboard to the other player in hotseat games.
+	fn play(&mut self, mv: Move, state: &mut ScreenState) {
+		self.board.apply(mv);
+		self.last_move = Some(mv);
+		self.moves += 1;
+		self.selected = None;
+		self.targets.clear();
+		self.check_over();
+		if let Some(hotseat) = &mut self.hotseat {
+			if self.results.is_none() {
+				hotseat.end_turn(state);
+			}
+		}
+	}
+
+	/// Announces how the game ended if it's over, recording the time of wins
+	/// and updating the player's rating against the computer.
+	fn check_over(&mut self) {
+		let status = self.board.status();
+		let outcome = match status {
+			Status::Playing => return,
+			Status::Checkmate(winner) => t!("chess-checkmate", winner = side_name(winner)),
+			Status::Stalemate => t!("chess-stalemate").to_string(),
+			Status::FiftyMoves => t!("chess-fifty-moves").to_string(),
+			Status::InsufficientMaterial => t!("chess-insufficient-material").to_string(),
+		};
+		let mut details = vec![outcome];
+		let against_computer = self.setup.opponent == Opponent::Computer;
+		let won = against_computer && status == Status::Checkmate(Side::White);
+		if against_computer {
+			let result = match status {
+				Status::Checkmate(Side::White) => MatchResult::Win,
+				Status::Checkmate(Side::Black) => MatchResult::Loss,
+				_ => MatchResult::Draw,
+			};
+			let computer_rating = self.setup.computer_rating();
+			let time = GameResult::new(GameMode::Standard, None, Some(self.elapsed()));
+			let recorded = GameDynamicInfo::update(&GameId::from(GAME_ID), |info| {
+				let comparison = won.then(|| info.record_result(&time));
+				(comparison, info.record_match(computer_rating, result))
+			});
+			if let Ok((comparison, rating)) = recorded {
+				details.push(comparison.unwrap_or_default().text());
+				details.push(t!("chess-rating", rating = rating));
+			}
+		}
+		let verdict = if won { Verdict::Won } else { Verdict::Over };
+		self.results =
+			Some(ResultsBanner::new(verdict, None).with_details(details).with_confetti(won));
+	}
+
+	/// Returns the line below the board, with whose turn it is, whether
+	/// they're in check, and the last move.
+	fn status_line(&self) -> String {
+		let turn = self.board.turn();
+		let mut parts = vec![
+			if self.is_human_turn() {
+				t!("chess-turn", side = side_name(turn))
+			} else {
+				t!("chess-thinking").to_string()
+			},
+		];
+		if self.board.is_in_check(turn) {
+			parts.push(t!("chess-check").to_string());
+		}
+		if let Some(mv) = self.last_move {
+			parts.push(t!(
+				"chess-last-move",
+				from = square_name(mv.from),
+				to = square_name(mv.to)
+			));
+		}
+		parts.join(" | ")
+	}
+}
+
+impl Screen for ChessGameScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(
+			t!("chess-title"),
+			ScreenKind::Normal,
+			Some(vec![("Enter", t!("chess-new-control"))]),
+		)
+		.with_actions(vec![
+			(
+				&[Action::Up, Action::Down, Action::Left, Action::Right],
+				t!("chess-move"),
+			),
+			(&[Action::Confirm], t!("chess-select-control")),
+			(&[Action::Help], t!("chess-help-control")),
+		])
+		.with_pause_menu()
+		.with_quick_restart()
+	}
+
+	fn handle_event(&mut self, _event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		Ok(())
+	}
+
+	fn handle_action(&mut self, action: Action, state: &mut ScreenState) -> anyhow::Result<bool> {
+		if self.results.is_some() {
+			if action == Action::Confirm {
+				*self = Self::new(self.setup);
+				return Ok(true);
+			}
+			return Ok(false);
+		}
+		match action {
+			Action::Up => self.move_cursor(0, 1),
+			Action::Down => self.move_cursor(0, -1),
+			Action::Left => self.move_cursor(-1, 0),
+			Action::Right => self.move_cursor(1, 0),
+			Action::Confirm => self.select(state),
+			_ => return Ok(false),
+		}
+		Ok(true)
+	}
+
+	fn quick_restart(&mut self, _state: &mut ScreenState) -> anyhow::Result<()> {
+		*self = Self::new(self.setup);
+		Ok(())
+	}
+
+	fn has_significant_progress(&self) -> bool {
+		self.results.is_none() && self.moves >= SIGNIFICANT_MOVES
+	}
+
+	fn update(&mut self, state: &mut ScreenState) {
+		if let Some(results) = &mut self.results {
+			results.tick();
+			return;
+		}
+		if self.is_human_turn() || self.paused_at.is_some() {
+			return;
+		}
+		let search =
+			self.search.get_or_insert_with(|| Search::start(self.board.clone(), self.setup.level));
+		let Some(result) = search.poll() else {
+			return;
+		};
+		self.search = None;
+		if let Some(mv) = result {
+			self.play(mv, state);
+		}
+	}
+
+	fn pause(&mut self) {
+		self.paused_at.get_or_insert_with(Instant::now);
+	}
+
+	fn resume(&mut self) {
+		if let Some(paused_at) = self.paused_at.take() {
+			self.started += paused_at.elapsed();
+		}
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.vertical_margin(1)
+			.horizontal_margin(2)
+			.constraints([Constraint::Min(0), Constraint::Length(3)])
+			.split(frame.size());
+		let highlights = BoardHighlights {
+			cursor: self.cursor,
+			selected: self.selected,
+			targets: &self.targets,
+			last_move: self.last_move,
+		};
+		render_board(frame, chunks[0], &self.board, &highlights);
+		let status = Paragraph::new(self.status_line())
+			.alignment(Alignment::Center)
+			.block(untitled_ui_block());
+		frame.render_widget(status, chunks[1]);
+		if let Some(results) = &self.results {
+			results.render(frame, chunks[0]);
+		}
+	}
+
+	fn help_page(&self) -> Option<&'static str> {
+		Some(HELP_PAGE)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		core::files,
+		services::rating::{
+			updated_rating,
+			DEFAULT_RATING,
+		},
+		ui::screens::{
+			ScreenEvent,
+			Screens,
+		},
+	};
+
+	/// Plays 1. e4 from the keyboard, returning the screen events it sent.
+	fn play_e4(opponent: Opponent) -> Vec<ScreenEvent> {
+		let mut state = ScreenState::new("", ScreenKind::Normal, None);
+		let mut game = ChessGameScreen::new(ChessSetup {
+			opponent,
+			..ChessSetup::default()
+		});
+		game.handle_action(Action::Confirm, &mut state).unwrap();
+		game.handle_action(Action::Up, &mut state).unwrap();
+		game.handle_action(Action::Up, &mut state).unwrap();
+		game.handle_action(Action::Confirm, &mut state).unwrap();
+		assert_eq!(game.last_move.map(|mv| mv.to), Some((4, 3)));
+		state.screen_events
+	}
+
+	#[test]
+	fn passes_the_keyboard_after_moves_against_players() {
+		files::isolate_test_files();
+		let events = play_e4(Opponent::Human);
+		assert!(matches!(
+			events.as_slice(),
+			[ScreenEvent::Create(screen)] if matches!(**screen, Screens::PassKeyboardScreen(_))
+		));
+		assert!(play_e4(Opponent::Computer).is_empty());
+	}
+
+	#[test]
+	fn losses_to_the_computer_are_rated() {
+		files::isolate_test_files();
+		let mut game = ChessGameScreen::new(ChessSetup::default());
+		for (from, to) in [((5, 1), (5, 2)), ((4, 6), (4, 4)), ((6, 1), (6, 3))] {
+			game.board.apply(Move {
+				from,
+				to,
+				promotion: None,
+			});
+		}
+		game.check_over();
+		assert!(game.results.is_none());
+		game.board.apply(Move {
+			from: (3, 7),
+			to: (7, 3),
+			promotion: None,
+		});
+		game.check_over();
+		assert!(game.results.is_some());
+		let info = GameDynamicInfo::load_or_default(&GameId::from(GAME_ID)).unwrap();
+		assert_eq!(
+			info.rating,
+			Some(updated_rating(
+				DEFAULT_RATING,
+				ChessSetup::default().computer_rating(),
+				MatchResult::Loss
+			))
+		);
+		assert!(info.rating < Some(DEFAULT_RATING));
+	}
+}
@@ -0,0 +1,4 @@
+//! Screens used for Chess.
+
+pub mod chess_game;
+pub mod setup;
@@ -0,0 +1,91 @@
+//! Game setup screen for Chess, where the player picks their opponent and how
+//! far ahead the computer looks.
+
+use ratatui::{
+	layout::{
+		Constraint,
+		Direction,
+		Layout,
+	},
+	Frame,
+};
+
+use crate::{
+	core::{
+		actions::Action,
+		events::Event,
+	},
+	games::{
+		chess::{
+			setup::ChessSetup,
+			Chess,
+		},
+		Game,
+	},
+	t,
+	ui::{
+		components::games::setup::SetupQuestions,
+		screens::{
+			ScreenKind,
+			ScreenState,
+		},
+		ChessGameScreen,
+		Screen,
+	},
+};
+
+/// A setup screen for a game of Chess.
+#[derive(Clone)]
+#[must_use]
+pub struct ChessSetupScreen {
+	/// The questions, with the answers given.
+	questions: SetupQuestions<ChessSetup>,
+}
+
+impl Default for ChessSetupScreen {
+	fn default() -> Self {
+		Self {
+			questions: SetupQuestions::new(ChessSetup::default()),
+		}
+	}
+}
+
+impl Screen for ChessSetupScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(t!("chess-setup-title"), ScreenKind::Normal, None).with_actions(vec![
+			(&[Action::Up, Action::Down], t!("chess-setup-select")),
+			(&[Action::Left, Action::Right], t!("chess-setup-change")),
+			(&[Action::Confirm], t!("chess-start")),
+			(&[Action::Help], t!("chess-help-control")),
+		])
+	}
+
+	fn handle_event(&mut self, _event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		Ok(())
+	}
+
+	fn handle_action(&mut self, action: Action, state: &mut ScreenState) -> anyhow::Result<bool> {
+		if action == Action::Confirm {
+			state.create_screen(ChessGameScreen::new(*self.questions.answers()).into());
+			return Ok(true);
+		}
+		Ok(self.questions.handle_action(action))
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.vertical_margin(1)
+			.horizontal_margin(2)
+			.constraints([
+				Constraint::Length(self.questions.get_height()),
+				Constraint::Min(0),
+			])
+			.split(frame.size());
+		self.questions.render(frame, chunks[0]);
+	}
+
+	fn help_page(&self) -> Option<&'static str> {
+		Chess.help_page()
+	}
+}
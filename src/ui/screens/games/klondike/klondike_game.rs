@@ -0,0 +1,470 @@
+//! The screen of a game of Klondike, picking cards up and moving them between
+//! piles with the cursor.
+
+use std::time::Duration;
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Constraint,
+		Direction,
+		Layout,
+	},
+	widgets::Paragraph,
+	Frame,
+};
+
+use crate::{
+	core::{
+		actions::Action,
+		events::{
+			Event,
+			KeyCode,
+		},
+		time::Instant,
+	},
+	games::{
+		clock_text,
+		klondike::{
+			setup::KlondikeSetup,
+			table::{
+				Move,
+				Pile,
+				Table,
+				COLUMNS,
+			},
+			GAME_ID,
+			HELP_PAGE,
+		},
+		modes::GameMode,
+		registry::GameId,
+		GameDynamicInfo,
+		GameResult,
+	},
+	services::{
+		rng,
+		undo::{
+			Snapshot,
+			UndoStack,
+		},
+	},
+	t,
+	ui::{
+		components::{
+			games::klondike::table::{
+				render_table,
+				TableHighlights,
+			},
+			pause_menu::{
+				Hint,
+				HINT_TIME_PENALTY,
+			},
+			presets::untitled_ui_block,
+			results_banner::{
+				ResultsBanner,
+				Verdict,
+			},
+		},
+		screens::{
+			ScreenKind,
+			ScreenState,
+		},
+		Screen,
+	},
+};
+
+/// Moves after which a game counts as significant progress, asking to confirm
+/// before quickly restarting it.
+const SIGNIFICANT_MOVES: usize = 20;
+
+/// Column of the gap between the waste and the foundations, in the top row.
+const GAP_COLUMN: usize = 2;
+
+/// Returns the column of the table a pile is in.
+fn column_of(pile: Pile) -> usize {
+	match pile {
+		Pile::Stock => 0,
+		Pile::Waste => 1,
+		Pile::Foundation(index) => GAP_COLUMN + 1 + index,
+		Pile::Tableau(column) => column,
+	}
+}
+
+/// Returns the pile of the top row in a column, the gap standing for the
+/// waste.
+fn top_pile(column: usize) -> Pile {
+	match column {
+		0 => Pile::Stock,
+		1 | GAP_COLUMN => Pile::Waste,
+		column => Pile::Foundation(column - GAP_COLUMN - 1),
+	}
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct KlondikeGameScreen {
+	/// The answers to the setup screen.
+	setup: KlondikeSetup,
+
+	/// The seed the deal was shuffled from.
+	seed: u64,
+
+	/// The cards on the table.
+	table: Table,
+
+	/// The moves played, to take back.
+	undo: UndoStack<Snapshot<Table>>,
+
+	/// The pile under the cursor.
+	cursor: Pile,
+
+	/// How many of the top cards of the tableau column under the cursor are
+	/// under it, to pick a run of them up.
+	depth: usize,
+
+	/// The pile cards were picked up from, and how many, if any.
+	held: Option<(Pile, usize)>,
+
+	/// Moves played so far, turning the stock included.
+	moves: usize,
+
+	/// When the game was started, moved forward by the time spent paused.
+	started: Instant,
+
+	/// When the game was [paused](Screen::pause), if it is.
+	paused_at: Option<Instant>,
+
+	/// How many [hints](Screen::hint) were given, each adding
+	/// [`HINT_TIME_PENALTY`] to the time.
+	hints: u32,
+
+	/// Banner announcing the win, shown once every card is on the
+	/// foundations.
+	results: Option<ResultsBanner>,
+}
+
+impl KlondikeGameScreen {
+	/// Deals a new game from a new [seed](rng::seed_game), counting a play of
+	/// the game.
+	pub fn new(setup: KlondikeSetup) -> Self {
+		Self::with_seed(setup, rng::seed_game())
+	}
+
+	/// Deals a game from a seed, counting a play of the game.
+	fn with_seed(setup: KlondikeSetup, seed: u64) -> Self {
+		let _ = GameDynamicInfo::update(&GameId::from(GAME_ID), GameDynamicInfo::play);
+		let mut screen = Self {
+			setup,
+			seed,
+			table: Table::deal(setup.draw, &mut rng::seeded(seed)),
+			undo: UndoStack::default(),
+			cursor: Pile::Stock,
+			depth: 1,
+			held: None,
+			moves: 0,
+			started: Instant::now(),
+			paused_at: None,
+			hints: 0,
+			results: None,
+		};
+		if setup.auto_move {
+			screen.table.auto_move();
+		}
+		screen
+	}
+
+	/// Returns how long the game has been played for, leaving out the time
+	/// spent paused and adding the hints' penalty.
+	fn elapsed(&self) -> Duration {
+		self.paused_at.unwrap_or_else(Instant::now).duration_since(self.started)
+			+ HINT_TIME_PENALTY * self.hints
+	}
+
+	/// Moves the cursor to a pile, on its top card.
+	fn move_cursor_to(&mut self, pile: Pile) {
+		self.cursor = pile;
+		self.depth = 1;
+	}
+
+	/// Moves the cursor a column to the left or right, skipping the gap in the
+	/// top row.
+	fn move_cursor_across(&mut self, columns: isize) {
+		let mut column = column_of(self.cursor).saturating_add_signed(columns).min(COLUMNS - 1);
+		if let Pile::Tableau(_) = self.cursor {
+			self.move_cursor_to(Pile::Tableau(column));
+			return;
+		}
+		if column == GAP_COLUMN {
+			column = column.saturating_add_signed(columns);
+		}
+		self.move_cursor_to(top_pile(column));
+	}
+
+	/// Moves the cursor up a card of the tableau column under it, or up to the
+	/// top row past its last face-up card.
+	fn move_cursor_up(&mut self) {
+		let Pile::Tableau(column) = self.cursor else {
+			return;
+		};
+		if self.held.is_none() && self.depth < self.table.face_up(self.cursor) {
+			self.depth += 1;
+		} else {
+			self.move_cursor_to(top_pile(column));
+		}
+	}
+
+	/// Moves the cursor down a card of the tableau column under it, or down to
+	/// the tableau from the top row.
+	fn move_cursor_down(&mut self) {
+		match self.cursor {
+			Pile::Tableau(_) => self.depth = self.depth.saturating_sub(1).max(1),
+			pile => self.move_cursor_to(Pile::Tableau(column_of(pile))),
+		}
+	}
+
+	/// Turns the stock under the cursor, picks the cards under it up, or puts
+	/// the cards picked up on the pile under it. Picking them up from the same
+	/// pile again puts them back.
+	fn select(&mut self) {
+		let before = self.table.clone();
+		if let Some((from, count)) = self.held {
+			if from == self.cursor {
+				self.held = None;
+			} else if self.table.move_cards(from, count, self.cursor) {
+				self.held = None;
+				self.moved(before);
+			}
+			return;
+		}
+		if self.cursor == Pile::Stock {
+			if self.table.turn_stock() {
+				self.moved(before);
+			}
+		} else if self.table.can_pick_up(self.cursor, self.depth) {
+			self.held = Some((self.cursor, self.depth));
+		}
+	}
+
+	/// Moves the card picked up, or else the top card under the cursor, to a
+	/// foundation if it can go on one.
+	fn send_to_foundation(&mut self) {
+		let pile = match self.held {
+			Some((from, 1)) => from,
+			Some(_) => return,
+			None => self.cursor,
+		};
+		let before = self.table.clone();
+		if self.table.send_to_foundation(pile) {
+			self.held = None;
+			self.moved(before);
+		}
+	}
+
+	/// Counts a move from the table as it was before it, moving safe cards to
+	/// the foundations if set up to, and announces the win once every card is
+	/// on them.
+	fn moved(&mut self, before: Table) {
+		self.moves += 1;
+		if self.setup.auto_move {
+			self.table.auto_move();
+		}
+		self.undo.push(Snapshot {
+			before,
+			after: self.table.clone(),
+		});
+		self.depth = self.depth.min(self.table.face_up(self.cursor)).max(1);
+		if self.table.is_won() {
+			self.record_win();
+		}
+	}
+
+	/// Records the win and its time, and announces it.
+	fn record_win(&mut self) {
+		let time = self.elapsed();
+		let mut details = vec![t!("klondike-won-in", time = clock_text(time.as_secs()))];
+		let result = GameResult::new(GameMode::Standard, None, Some(time));
+		if let Ok((comparison, wins)) = GameDynamicInfo::update(&GameId::from(GAME_ID), |info| {
+			(info.record_result(&result), info.record_win())
+		}) {
+			details.push(comparison.text());
+			details.push(t!("klondike-wins", count = wins));
+		}
+		if self.hints > 0 {
+			details.push(t!(
+				"klondike-hints-used",
+				count = self.hints,
+				time = clock_text((HINT_TIME_PENALTY * self.hints).as_secs())
+			));
+		}
+		self.results =
+			Some(ResultsBanner::new(Verdict::Won, None).with_details(details).with_confetti(true));
+	}
+
+	/// Returns the line below the table, with the cards left in the stock,
+	/// the moves played, the time and the hints given.
+	fn status_line(&self) -> String {
+		let status = t!(
+			"klondike-status",
+			stock = self.table.pile(Pile::Stock).len(),
+			moves = self.moves,
+			time = clock_text(self.elapsed().as_secs())
+		);
+		if self.hints == 0 {
+			return status;
+		}
+		format!("{status} | {}", t!("klondike-hints", hints = self.hints))
+	}
+
+	/// Returns the text of a hint suggesting a move.
+	fn hint_text(&self, suggestion: Move) -> String {
+		let Move::Cards { from, count, to } = suggestion else {
+			return t!("klondike-hint-stock").to_string();
+		};
+		let cards = self.table.pile(from);
+		let card = cards[cards.len() - count].name();
+		match (to, self.table.pile(to).last()) {
+			(Pile::Foundation(_), _) => t!("klondike-hint-foundation", card = card),
+			(_, Some(target)) => t!("klondike-hint-move", card = card, target = target.name()),
+			(_, None) => t!("klondike-hint-empty", card = card),
+		}
+	}
+}
+
+impl Screen for KlondikeGameScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(
+			t!("klondike-title"),
+			ScreenKind::Normal,
+			Some(vec![
+				("F", t!("klondike-foundation-control")),
+				("Enter", t!("klondike-new-control")),
+			]),
+		)
+		.with_actions(vec![
+			(
+				&[Action::Up, Action::Down, Action::Left, Action::Right],
+				t!("klondike-move"),
+			),
+			(&[Action::Confirm], t!("klondike-select-control")),
+			(&[Action::Undo], t!("klondike-undo-control")),
+			(&[Action::Redo], t!("klondike-redo-control")),
+			(&[Action::Help], t!("klondike-help-control")),
+		])
+		.with_pause_menu()
+		.with_hints()
+		.with_quick_restart()
+	}
+
+	fn handle_event(&mut self, event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		if let Event::Key(key) = event {
+			if self.results.is_some() {
+				return Ok(());
+			}
+			if let KeyCode::Char('f' | 'F') = key.code {
+				self.send_to_foundation();
+			}
+		}
+		Ok(())
+	}
+
+	fn handle_action(&mut self, action: Action, _state: &mut ScreenState) -> anyhow::Result<bool> {
+		if self.results.is_some() {
+			if action == Action::Confirm {
+				*self = Self::new(self.setup);
+				return Ok(true);
+			}
+			return Ok(false);
+		}
+		match action {
+			Action::Up => self.move_cursor_up(),
+			Action::Down => self.move_cursor_down(),
+			Action::Left => self.move_cursor_across(-1),
+			Action::Right => self.move_cursor_across(1),
+			Action::Confirm => self.select(),
+			Action::Undo | Action::Redo => {
+				let changed = if action == Action::Undo {
+					self.undo.undo(&mut self.table)
+				} else {
+					self.undo.redo(&mut self.table)
+				};
+				if changed {
+					self.held = None;
+					self.depth = self.depth.min(self.table.face_up(self.cursor)).max(1);
+					if self.table.is_won() {
+						self.record_win();
+					}
+				}
+			},
+			_ => return Ok(false),
+		}
+		Ok(true)
+	}
+
+	fn quick_restart(&mut self, _state: &mut ScreenState) -> anyhow::Result<()> {
+		*self = Self::with_seed(self.setup, self.seed);
+		Ok(())
+	}
+
+	fn has_significant_progress(&self) -> bool {
+		self.results.is_none() && self.moves >= SIGNIFICANT_MOVES
+	}
+
+	fn hint(&mut self, _state: &mut ScreenState) -> Option<Hint> {
+		if self.results.is_some() {
+			return None;
+		}
+		let suggestion = self.table.suggest_move()?;
+		let text = self.hint_text(suggestion);
+		match suggestion {
+			Move::TurnStock => self.move_cursor_to(Pile::Stock),
+			Move::Cards { from, count, .. } => {
+				self.move_cursor_to(from);
+				self.depth = count;
+			},
+		}
+		self.hints += 1;
+		Some(Hint::Text(text))
+	}
+
+	fn update(&mut self, _state: &mut ScreenState) {
+		if let Some(results) = &mut self.results {
+			results.tick();
+		}
+	}
+
+	fn pause(&mut self) {
+		self.paused_at.get_or_insert_with(Instant::now);
+	}
+
+	fn resume(&mut self) {
+		if let Some(paused_at) = self.paused_at.take() {
+			self.started += paused_at.elapsed();
+		}
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.vertical_margin(1)
+			.horizontal_margin(2)
+			.constraints([Constraint::Min(0), Constraint::Length(3)])
+			.split(frame.size());
+		let highlights = TableHighlights {
+			cursor: (self.cursor, self.depth),
+			held: self.held,
+		};
+		render_table(frame, chunks[0], &self.table, &highlights);
+		let status = Paragraph::new(self.status_line())
+			.alignment(Alignment::Center)
+			.block(untitled_ui_block());
+		frame.render_widget(status, chunks[1]);
+		if let Some(results) = &self.results {
+			results.render(frame, chunks[0]);
+		}
+	}
+
+	fn help_page(&self) -> Option<&'static str> {
+		Some(HELP_PAGE)
+	}
+}
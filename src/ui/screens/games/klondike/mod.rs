@@ -0,0 +1,4 @@
+//! Screens used for Klondike.
+
+pub mod klondike_game;
+pub mod setup;
@@ -0,0 +1,92 @@
+//! Game setup screen for Klondike, where the player picks how many cards are
+//! turned from the stock at once, and whether cards move to the foundations
+//! on their own.
+
+use ratatui::{
+	layout::{
+		Constraint,
+		Direction,
+		Layout,
+	},
+	Frame,
+};
+
+use crate::{
+	core::{
+		actions::Action,
+		events::Event,
+	},
+	games::{
+		klondike::{
+			setup::KlondikeSetup,
+			Klondike,
+		},
+		Game,
+	},
+	t,
+	ui::{
+		components::games::setup::SetupQuestions,
+		screens::{
+			ScreenKind,
+			ScreenState,
+		},
+		KlondikeGameScreen,
+		Screen,
+	},
+};
+
+/// A setup screen for a game of Klondike.
+#[derive(Clone)]
+#[must_use]
+pub struct KlondikeSetupScreen {
+	/// The questions, with the answers given.
+	questions: SetupQuestions<KlondikeSetup>,
+}
+
+impl Default for KlondikeSetupScreen {
+	fn default() -> Self {
+		Self {
+			questions: SetupQuestions::new(KlondikeSetup::default()),
+		}
+	}
+}
+
+impl Screen for KlondikeSetupScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(t!("klondike-setup-title"), ScreenKind::Normal, None).with_actions(vec![
+			(&[Action::Up, Action::Down], t!("klondike-setup-select")),
+			(&[Action::Left, Action::Right], t!("klondike-setup-change")),
+			(&[Action::Confirm], t!("klondike-start")),
+			(&[Action::Help], t!("klondike-help-control")),
+		])
+	}
+
+	fn handle_event(&mut self, _event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		Ok(())
+	}
+
+	fn handle_action(&mut self, action: Action, state: &mut ScreenState) -> anyhow::Result<bool> {
+		if action == Action::Confirm {
+			state.create_screen(KlondikeGameScreen::new(*self.questions.answers()).into());
+			return Ok(true);
+		}
+		Ok(self.questions.handle_action(action))
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.vertical_margin(1)
+			.horizontal_margin(2)
+			.constraints([
+				Constraint::Length(self.questions.get_height()),
+				Constraint::Min(0),
+			])
+			.split(frame.size());
+		self.questions.render(frame, chunks[0]);
+	}
+
+	fn help_page(&self) -> Option<&'static str> {
+		Klondike.help_page()
+	}
+}
@@ -1,42 +1,261 @@
-//! Game setup screen for a Minesweeper board.
+//! Game setup screen for a Minesweeper board, where the player sizes the board
+//! and picks how many mines hide in it, or picks
+//! [presets](crate::core::presets) of those answers.
 
-use crossterm::event::{
-	Event,
-	KeyCode,
-};
-use derive_new::new;
 use ratatui::{
 	layout::{
+		Alignment,
 		Constraint,
 		Direction,
 		Layout,
-		Rect,
+	},
+	widgets::{
+		Paragraph,
+		Wrap,
 	},
 	Frame,
 };
 
-use crate::ui::{
-	components::presets::titled_ui_block,
-	screens::{
-		OpenStatus,
-		ScreenKind,
-		ScreenState,
+use crate::{
+	core::{
+		actions::Action,
+		events::{
+			Event,
+			KeyCode,
+			KeyModifiers,
+		},
+		presets::Presets,
+	},
+	games::{
+		minesweeper::{
+			setup::BoardSetup,
+			Minesweeper,
+			GAME_ID,
+		},
+		registry::GameId,
+		Game,
+	},
+	t,
+	ui::{
+		components::{
+			games::setup::SetupQuestions,
+			presets::untitled_ui_block,
+		},
+		screens::{
+			ScreenKind,
+			ScreenState,
+		},
+		MinesweeperTutorialScreen,
+		Screen,
 	},
-	Screen,
 };
 
 /// A setup screen for a board of Minesweeper.
-#[derive(new, Clone)]
-pub struct MinesweeperSetupScreen;
+#[derive(Clone)]
+#[must_use]
+pub struct MinesweeperSetupScreen {
+	/// The questions, with the answers given.
+	questions: SetupQuestions<BoardSetup>,
+
+	/// The presets saved.
+	presets: Presets<BoardSetup>,
+
+	/// Index of the preset picked last, if any.
+	preset: Option<usize>,
+
+	/// Name of the preset being saved, while it's being typed.
+	new_preset_name: Option<String>,
+
+	/// What happened after the last action with presets, if anything.
+	status: Option<String>,
+}
+
+impl Default for MinesweeperSetupScreen {
+	fn default() -> Self {
+		Self {
+			questions: SetupQuestions::new(BoardSetup::default()),
+			presets: Presets::load_or_default(&GameId::from(GAME_ID)),
+			preset: None,
+			new_preset_name: None,
+			status: None,
+		}
+	}
+}
+
+impl MinesweeperSetupScreen {
+	/// Saves the presets, showing what was done with them.
+	fn save_presets(&mut self, done: String) {
+		self.status = Some(match self.presets.save(&GameId::from(GAME_ID)) {
+			Ok(()) => done,
+			Err(error) => t!("presets-save-failed", error = error.to_string()),
+		});
+	}
+
+	/// Saves the answers as a preset under the name typed, unless it's blank.
+	fn save_preset(&mut self) {
+		let name = self.new_preset_name.take().unwrap_or_default();
+		let name = name.trim();
+		if name.is_empty() {
+			self.status = None;
+			return;
+		}
+		self.preset = Some(self.presets.insert(name, *self.questions.answers()));
+		self.save_presets(t!("presets-saved", name = name.to_string()));
+	}
+
+	/// Picks the preset after the one picked last, filling in its answers.
+	fn pick_next_preset(&mut self) {
+		if self.presets.presets.is_empty() {
+			return;
+		}
+		let index = self.preset.map_or(0, |index| (index + 1) % self.presets.presets.len());
+		let preset = &self.presets.presets[index];
+		self.questions.set_answers(preset.answers.clamped());
+		self.status = Some(t!("presets-picked", name = preset.name.as_str()));
+		self.preset = Some(index);
+	}
+
+	/// Removes the preset picked last.
+	fn remove_preset(&mut self) {
+		let Some(index) = self.preset.take() else {
+			return;
+		};
+		let removed = self.presets.presets.remove(index);
+		self.save_presets(t!("presets-removed", name = removed.name));
+	}
+
+	/// Returns the text listing the presets, and what happened last.
+	fn presets_text(&self) -> String {
+		let names = self.presets.names();
+		let presets = if names.is_empty() {
+			t!("presets-none").to_string()
+		} else {
+			t!("presets-list", presets = names.join(", "))
+		};
+		match (&self.new_preset_name, &self.status) {
+			(Some(name), _) => t!("presets-naming", name = name.as_str()),
+			(None, Some(status)) => format!("{presets}\n{status}"),
+			(None, None) => presets,
+		}
+	}
+}
 
 impl Screen for MinesweeperSetupScreen {
 	fn initial_state(&self) -> ScreenState {
-		ScreenState::new("Mine your field!", ScreenKind::Normal, None)
+		ScreenState::new(
+			t!("minesweeper-setup-title"),
+			ScreenKind::Normal,
+			Some(vec![
+				("T", t!("minesweeper-tutorial-control")),
+				("D", t!("minesweeper-demo-control")),
+				("S", t!("presets-save-control")),
+				("P", t!("presets-pick-control")),
+				("Delete", t!("presets-remove-control")),
+			]),
+		)
+		.with_actions(vec![
+			(&[Action::Up, Action::Down], t!("minesweeper-setup-select")),
+			(
+				&[Action::Left, Action::Right],
+				t!("minesweeper-setup-change"),
+			),
+			(&[Action::Help], t!("minesweeper-help-control")),
+		])
 	}
 
-	fn handle_event(&mut self, _event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+	fn handle_event(&mut self, event: &Event, state: &mut ScreenState) -> anyhow::Result<()> {
+		let Event::Key(key) = event else {
+			return Ok(());
+		};
+		if let Some(name) = &mut self.new_preset_name {
+			match key.code {
+				KeyCode::Backspace => {
+					name.pop();
+				},
+				KeyCode::Char(char)
+					if [KeyModifiers::NONE, KeyModifiers::SHIFT].contains(&key.modifiers) =>
+				{
+					name.push(char);
+				},
+				_ => {},
+			}
+			return Ok(());
+		}
+		match key.code {
+			KeyCode::Char('t' | 'T') => {
+				state.create_screen(MinesweeperTutorialScreen::default().into());
+			},
+			KeyCode::Char('d' | 'D') => {
+				if let Some(screen) = Minesweeper.demo_screen() {
+					state.create_screen(screen);
+				}
+			},
+			KeyCode::Char('s' | 'S') => self.new_preset_name = Some(String::new()),
+			KeyCode::Char('p' | 'P') => self.pick_next_preset(),
+			KeyCode::Delete => self.remove_preset(),
+			_ => {},
+		}
 		Ok(())
 	}
 
-	fn render_ui(&self, _frame: &mut Frame<'_>, _state: &ScreenState) {}
+	fn handle_action(&mut self, action: Action, _state: &mut ScreenState) -> anyhow::Result<bool> {
+		// Keys are typed into the preset's name while saving it.
+		if self.new_preset_name.is_some() && action != Action::Confirm {
+			return Ok(false);
+		}
+		if action == Action::Confirm && self.new_preset_name.is_some() {
+			self.save_preset();
+			return Ok(true);
+		}
+		Ok(self.questions.handle_action(action))
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.vertical_margin(1)
+			.horizontal_margin(2)
+			.constraints([
+				Constraint::Max(3),
+				Constraint::Length(self.questions.get_height()),
+				Constraint::Length(4),
+				Constraint::Min(0),
+			])
+			.split(frame.size());
+		let tutorial_hint = Paragraph::new(t!("minesweeper-tutorial-hint"))
+			.alignment(Alignment::Center)
+			.block(untitled_ui_block());
+		frame.render_widget(tutorial_hint, chunks[0]);
+		self.questions.render(frame, chunks[1]);
+		let presets = Paragraph::new(self.presets_text())
+			.alignment(Alignment::Center)
+			.block(untitled_ui_block())
+			.wrap(Wrap { trim: true });
+		frame.render_widget(presets, chunks[2]);
+	}
+
+	fn help_page(&self) -> Option<&'static str> {
+		Minesweeper.help_page()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use insta::assert_snapshot;
+
+	use super::MinesweeperSetupScreen;
+	use crate::core::{
+		files,
+		harness::render_snapshot,
+	};
+
+	#[test]
+	fn minesweeper_setup_screen_snapshot() {
+		files::isolate_test_files();
+		assert_snapshot!(render_snapshot(
+			MinesweeperSetupScreen::default().into(),
+			120,
+			40
+		));
+	}
 }
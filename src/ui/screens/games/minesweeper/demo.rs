@@ -0,0 +1,85 @@
+//! A game of Minesweeper playing itself, following a
+//! [demo](crate::games::demo::DemoPlayable) policy that flags and uncovers
+//! what it can deduce and guesses otherwise. Any key stops watching.
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Constraint,
+		Direction,
+		Layout,
+	},
+	widgets::Paragraph,
+	Frame,
+};
+
+use crate::{
+	core::events::Event,
+	games::{
+		demo::DemoPlayer,
+		minesweeper::round::Round,
+	},
+	t,
+	ui::{
+		components::{
+			games::minesweeper::board::render_board,
+			presets::untitled_ui_block,
+		},
+		screens::{
+			OpenStatus,
+			ScreenKind,
+			ScreenState,
+		},
+		Screen,
+	},
+};
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone, Default)]
+#[must_use]
+pub struct MinesweeperDemoScreen {
+	/// Plays the game.
+	player: DemoPlayer<Round>,
+}
+
+impl Screen for MinesweeperDemoScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(t!("minesweeper-demo-title"), ScreenKind::Normal, None)
+	}
+
+	fn event(&mut self, event: &Event, state: &mut ScreenState) -> anyhow::Result<()> {
+		if let Event::Key(_) = event {
+			state.open_status = OpenStatus::Closed;
+		}
+		Ok(())
+	}
+
+	fn handle_event(&mut self, _event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		Ok(())
+	}
+
+	fn update(&mut self, _state: &mut ScreenState) {
+		self.player.update();
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.vertical_margin(1)
+			.horizontal_margin(2)
+			.constraints([Constraint::Min(0), Constraint::Length(3)])
+			.split(frame.size());
+		let round = self.player.game();
+		let cursor = (!self.player.is_over()).then_some(round.cursor);
+		render_board(frame, chunks[0], &round.board, cursor, None);
+		let status = if round.board.is_cleared() {
+			t!("minesweeper-demo-cleared")
+		} else if round.board.is_exploded() {
+			t!("minesweeper-demo-exploded")
+		} else {
+			t!("demo-watching")
+		};
+		let status = Paragraph::new(status).alignment(Alignment::Center).block(untitled_ui_block());
+		frame.render_widget(status, chunks[1]);
+	}
+}
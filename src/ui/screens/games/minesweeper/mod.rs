@@ -1,4 +1,6 @@
 //! Screens used for Minesweeper.
 
 pub mod board_setup;
+pub mod demo;
 pub mod minesweeper_game;
+pub mod tutorial;
@@ -0,0 +1,152 @@
+//! An interactive tutorial teaching the basics of Minesweeper on a small,
+//! fixed board.
+
+use ratatui::{
+	layout::{
+		Constraint,
+		Direction,
+		Layout,
+		Rect,
+	},
+	Frame,
+};
+
+use crate::{
+	core::events::{
+		Event,
+		KeyCode,
+	},
+	games::{
+		minesweeper::{
+			board::Board,
+			round::Round,
+			Minesweeper,
+		},
+		Game,
+	},
+	t,
+	ui::{
+		components::{
+			games::minesweeper::board::render_board,
+			tutorial::{
+				Tutorial,
+				TutorialGoal,
+				TutorialStep,
+			},
+		},
+		screens::{
+			OpenStatus,
+			ScreenKind,
+			ScreenState,
+		},
+		Screen,
+	},
+};
+
+/// Width of the tutorial board.
+const BOARD_WIDTH: usize = 5;
+
+/// Height of the tutorial board.
+const BOARD_HEIGHT: usize = 3;
+
+/// Position of the tutorial board's only mine. Uncovering the top left tile
+/// uncovers everything but the mine and the tile to its right.
+const MINE: (usize, usize) = (3, 0);
+
+/// Arrow keys, for moving the cursor.
+const ARROW_KEYS: [KeyCode; 4] = [KeyCode::Up, KeyCode::Down, KeyCode::Left, KeyCode::Right];
+
+/// Returns a region of a single tile.
+fn tile_region(x: u16, y: u16) -> Rect {
+	Rect::new(x, y, 1, 1)
+}
+
+/// Returns the tutorial's steps.
+fn tutorial_steps() -> Vec<TutorialStep<Round>> {
+	let whole_board = Some(Rect::new(0, 0, BOARD_WIDTH as u16, BOARD_HEIGHT as u16));
+	vec![
+		TutorialStep::new(t!("minesweeper-tutorial-welcome"), None),
+		TutorialStep::new(t!("minesweeper-tutorial-board"), whole_board),
+		TutorialStep::new(t!("minesweeper-tutorial-uncover"), Some(tile_region(0, 0)))
+			.goal(TutorialGoal::Input(KeyCode::Char(' '))),
+		TutorialStep::new(t!("minesweeper-tutorial-numbers"), whole_board),
+		TutorialStep::new(t!("minesweeper-tutorial-deduce"), Some(tile_region(2, 0))),
+		TutorialStep::new(t!("minesweeper-tutorial-move"), Some(tile_region(3, 0)))
+			.allow(&ARROW_KEYS)
+			.goal(TutorialGoal::State(|round| round.cursor == MINE)),
+		TutorialStep::new(t!("minesweeper-tutorial-flag"), Some(tile_region(3, 0)))
+			.goal(TutorialGoal::Input(KeyCode::Char('f'))),
+		TutorialStep::new(t!("minesweeper-tutorial-safe"), Some(tile_region(4, 1))),
+		TutorialStep::new(t!("minesweeper-tutorial-finish"), Some(tile_region(4, 0)))
+			.allow(&[ARROW_KEYS.as_slice(), &[KeyCode::Char(' '), KeyCode::Enter]].concat())
+			.goal(TutorialGoal::State(|round| round.board.is_cleared())),
+		TutorialStep::new(t!("minesweeper-tutorial-done"), None),
+	]
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct MinesweeperTutorialScreen {
+	/// The round played during the tutorial.
+	round: Round,
+
+	/// The tutorial's progress.
+	tutorial: Tutorial<Round>,
+}
+
+impl Default for MinesweeperTutorialScreen {
+	fn default() -> Self {
+		Self {
+			round: Round::new(Board::with_mines(BOARD_WIDTH, BOARD_HEIGHT, &[MINE])),
+			tutorial: Tutorial::new(tutorial_steps()),
+		}
+	}
+}
+
+impl Screen for MinesweeperTutorialScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(t!("minesweeper-tutorial-title"), ScreenKind::Normal, None)
+	}
+
+	fn handle_event(&mut self, event: &Event, state: &mut ScreenState) -> anyhow::Result<()> {
+		if let Event::Key(key) = event {
+			if self.tutorial.accepts_input(key.code) {
+				self.round.handle_key(key.code);
+			}
+			self.tutorial.update(key.code, &self.round);
+			if self.tutorial.is_finished() {
+				state.open_status = OpenStatus::Closed;
+			}
+		}
+		Ok(())
+	}
+
+	fn update(&mut self, _state: &mut ScreenState) {
+		self.tutorial.tick();
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.vertical_margin(1)
+			.horizontal_margin(2)
+			.constraints([
+				Constraint::Min(BOARD_HEIGHT as u16 + 2), // Board
+				Constraint::Length(7),                    // Tutorial text
+			])
+			.split(frame.size());
+		render_board(
+			frame,
+			chunks[0],
+			&self.round.board,
+			Some(self.round.cursor),
+			self.tutorial.highlight(),
+		);
+		self.tutorial.render(frame, chunks[1]);
+	}
+
+	fn help_page(&self) -> Option<&'static str> {
+		Minesweeper.help_page()
+	}
+}
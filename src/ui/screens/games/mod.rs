@@ -1,6 +1,52 @@
 //! This module is specifically reserved for all game [`crate::ui::Screen`]
 //! implementations. See [`crate::game`] for more information.
 
+#[cfg(feature = "chess")]
+pub mod chess;
+#[cfg(feature = "klondike")]
+pub mod klondike;
+#[cfg(feature = "minesweeper")]
 pub mod minesweeper;
+#[cfg(feature = "scripting")]
+pub mod scripted;
+#[cfg(feature = "sudoku")]
+pub mod sudoku;
+#[cfg(feature = "tron")]
+pub mod tron;
+#[cfg(feature = "twenty-forty-eight")]
+pub mod twenty_forty_eight;
 
-pub use minesweeper::board_setup::MinesweeperSetupScreen;
+#[cfg(feature = "chess")]
+pub use chess::{
+	chess_game::ChessGameScreen,
+	setup::ChessSetupScreen,
+};
+#[cfg(feature = "klondike")]
+pub use klondike::{
+	klondike_game::KlondikeGameScreen,
+	setup::KlondikeSetupScreen,
+};
+#[cfg(feature = "minesweeper")]
+pub use minesweeper::{
+	board_setup::MinesweeperSetupScreen,
+	demo::MinesweeperDemoScreen,
+	tutorial::MinesweeperTutorialScreen,
+};
+#[cfg(feature = "scripting")]
+pub use scripted::ScriptedGameScreen;
+#[cfg(feature = "sudoku")]
+pub use sudoku::{
+	setup::SudokuSetupScreen,
+	sudoku_game::SudokuGameScreen,
+};
+#[cfg(feature = "tron")]
+pub use tron::{
+	demo::TronDemoScreen,
+	setup::TronSetupScreen,
+	tron_game::TronGameScreen,
+};
+#[cfg(feature = "twenty-forty-eight")]
+pub use twenty_forty_eight::{
+	setup::TwentyFortyEightSetupScreen,
+	twenty_forty_eight_game::TwentyFortyEightGameScreen,
+};
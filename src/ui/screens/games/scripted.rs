@@ -0,0 +1,618 @@
+//! The screen running a [scripted game](ScriptedGame), drawing its canvas and
+//! passing it key presses. Scripts with a `hint` function give hints from the
+//! [pause menu](crate::ui::components::pause_menu).
+
+use std::{
+	cell::RefCell,
+	rc::Rc,
+	time::Duration,
+};
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Constraint,
+		Direction,
+		Layout,
+	},
+	style::{
+		Color,
+		Style,
+	},
+	text::{
+		Line,
+		Span,
+		Text,
+	},
+	widgets::{
+		Paragraph,
+		Wrap,
+	},
+	Frame,
+};
+
+use crate::{
+	core::{
+		actions::Action,
+		config::Config,
+		events::{
+			Event,
+			KeyCode,
+			KeyModifiers,
+		},
+		time::Instant,
+	},
+	games::{
+		modes::{
+			Countdown,
+			GameMode,
+		},
+		scripted::{
+			runtime::ScriptRuntime,
+			ScriptedGame,
+			HELP_PAGE,
+		},
+		GameDynamicInfo,
+		GameResult,
+	},
+	services::{
+		autosave,
+		rng,
+	},
+	t,
+	ui::{
+		color_scheme::FRENCH_VIOLET,
+		components::{
+			banner::{
+				get_large_text,
+				get_large_text_height,
+			},
+			pause_menu::Hint,
+			presets::{
+				titled_ui_block,
+				untitled_ui_block,
+			},
+			results_banner::{
+				ResultsBanner,
+				Verdict,
+			},
+		},
+		screens::{
+			ScreenKind,
+			ScreenState,
+			SIGNIFICANT_PLAY_TIME,
+		},
+		Screen,
+	},
+};
+
+/// Background of the cell a hint points at.
+const HINT_HIGHLIGHT: Color = FRENCH_VIOLET;
+
+/// Returns the name scripts get for a key, like `up`, `enter` or `a`.
+fn key_name(code: KeyCode) -> Option<String> {
+	Some(match code {
+		KeyCode::Char(' ') => "space".to_string(),
+		KeyCode::Char(char) => char.to_string(),
+		KeyCode::Up => "up".to_string(),
+		KeyCode::Down => "down".to_string(),
+		KeyCode::Left => "left".to_string(),
+		KeyCode::Right => "right".to_string(),
+		KeyCode::Enter => "enter".to_string(),
+		KeyCode::Tab => "tab".to_string(),
+		KeyCode::Backspace => "backspace".to_string(),
+		KeyCode::Delete => "delete".to_string(),
+		KeyCode::Home => "home".to_string(),
+		KeyCode::End => "end".to_string(),
+		KeyCode::PageUp => "pageup".to_string(),
+		KeyCode::PageDown => "pagedown".to_string(),
+		_ => return None,
+	})
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+pub struct ScriptedGameScreen {
+	/// The game run.
+	game: ScriptedGame,
+
+	/// The running script, once loaded.
+	runtime: Option<Rc<RefCell<ScriptRuntime>>>,
+
+	/// The last error the script ran into. The script stops running after an
+	/// error, until it's reloaded.
+	error: Option<String>,
+
+	/// When the script's `on_tick` function was last called.
+	last_tick: Instant,
+
+	/// Whether the score is drawn in [large text](get_large_text).
+	large_text: bool,
+
+	/// The mode the game is played in.
+	mode: GameMode,
+
+	/// Countdown to the end of the game, in time trials.
+	countdown: Option<Countdown>,
+
+	/// The [seed](rng::seed_game) the game started from, shown once it's over
+	/// to play it again. Resumed games have none, since their randomness
+	/// isn't autosaved.
+	seed: Option<u64>,
+
+	/// When the game started, for timing [timed](ScriptedGame::timed) games.
+	/// Resumed games aren't timed, since their time isn't autosaved.
+	started: Option<Instant>,
+
+	/// Banner announcing how the last game ended and how it compares to the
+	/// player's previous bests, shown once it's over.
+	results: Option<ResultsBanner>,
+
+	/// When the game was [paused](Screen::pause), if it is.
+	paused_at: Option<Instant>,
+
+	/// The cell the last hint pointed at, as `(x, y)`, highlighted until the
+	/// next key press.
+	hint_position: Option<(usize, usize)>,
+}
+
+impl ScriptedGameScreen {
+	/// Creates the screen of a scripted game played in a mode. The script is
+	/// loaded once the screen is opened.
+	#[must_use]
+	pub fn new(game: ScriptedGame, mode: GameMode) -> Self {
+		Self {
+			game,
+			runtime: None,
+			error: None,
+			last_tick: Instant::now(),
+			large_text: Config::load_or_default().large_text,
+			mode,
+			countdown: None,
+			seed: None,
+			started: None,
+			results: None,
+			paused_at: None,
+			hint_position: None,
+		}
+	}
+
+	/// Returns the name the game in progress is autosaved under, which differs
+	/// between modes.
+	fn autosave_name(&self) -> String {
+		autosave::autosave_name(self.game.id().as_str(), self.mode)
+	}
+
+	/// Loads the script from its file, resuming the game's autosave if it has
+	/// one and its mode allows it, or else counting a play of the game.
+	fn load(&mut self) {
+		let snapshot =
+			self.mode.allows_saves().then(|| autosave::resume(&self.autosave_name())).flatten();
+		let resumed = snapshot.is_some();
+		self.start_clocks();
+		if resumed {
+			self.started = None;
+			self.seed = None;
+		} else {
+			self.seed = Some(rng::seed_game());
+		}
+		match ScriptRuntime::load(&self.game, self.mode, snapshot) {
+			Ok(runtime) => {
+				if !resumed {
+					let _ = GameDynamicInfo::update(&self.game.id(), GameDynamicInfo::play);
+				}
+				self.runtime = Some(Rc::new(RefCell::new(runtime)));
+				self.error = None;
+				self.last_tick = Instant::now();
+			},
+			Err(err) => self.error = Some(format!("{err:#}")),
+		}
+	}
+
+	/// Starts a [countdown](ScreenState::start_countdown) before the game
+	/// starts, if its script ticks. Games that only react to keys don't need
+	/// one.
+	fn start_countdown(&self, state: &mut ScreenState) {
+		let ticks = self
+			.runtime
+			.as_ref()
+			.is_some_and(|runtime| runtime.borrow().tick_interval_ms().is_some());
+		if ticks {
+			state.start_countdown();
+		}
+	}
+
+	/// Returns whether the script gives hints, having a `hint` function.
+	fn offers_hints(&self) -> bool {
+		self.runtime.as_ref().is_some_and(|runtime| runtime.borrow().has_function("hint"))
+	}
+
+	/// Starts the time trial countdown and the timer of timed games.
+	fn start_clocks(&mut self) {
+		self.countdown = self.mode.time_limit().map(Countdown::start);
+		self.started = Some(Instant::now());
+		self.results = None;
+	}
+
+	/// Runs a function with the script, keeping the error it runs into. When
+	/// the game ends, its result is recorded and announced, and in endless
+	/// games, a new round starts instead.
+	fn run(&mut self, function: impl FnOnce(&mut ScriptRuntime) -> anyhow::Result<()>) {
+		let Some(runtime) = &self.runtime else {
+			return;
+		};
+		if self.error.is_none() {
+			let was_over = runtime.borrow().canvas().over;
+			if let Err(err) = function(&mut runtime.borrow_mut()) {
+				self.error = Some(format!("{err:#}"));
+			}
+			let (over, won, score) = {
+				let canvas = runtime.borrow();
+				let canvas = canvas.canvas();
+				(canvas.over, canvas.won, canvas.score)
+			};
+			if over && !was_over {
+				autosave::discard(&self.autosave_name());
+				let time =
+					self.started.filter(|_| self.game.timed).map(|started| started.elapsed());
+				let result = GameResult::new(self.mode, Some(score), time).with_seed(self.seed);
+				let comparison =
+					GameDynamicInfo::update(&self.game.id(), |info| info.record_result(&result))
+						.ok();
+				if self.mode == GameMode::Endless {
+					if let Err(err) = runtime.borrow_mut().next_round() {
+						self.error = Some(format!("{err:#}"));
+					}
+				} else {
+					let comparison = comparison.unwrap_or_default();
+					let verdict = if won { Verdict::Won } else { Verdict::Over };
+					let new_best = comparison.new_best_score || comparison.new_best_time;
+					self.results = Some(
+						ResultsBanner::new(verdict, Some(score))
+							.with_details([comparison.text()])
+							.with_seed(self.seed)
+							.with_confetti(won || new_best),
+					);
+				}
+			}
+		}
+	}
+
+	/// Plays the game again, from the [seed](rng::seed_game) the generator was
+	/// just restarted from.
+	fn restart(&mut self, state: &mut ScreenState) {
+		self.run(ScriptRuntime::restart);
+		self.start_clocks();
+		self.start_countdown(state);
+	}
+
+	/// Saves the script's data for its next runs.
+	fn save_data(&self) -> anyhow::Result<()> {
+		match &self.runtime {
+			Some(runtime) => runtime.borrow().save(),
+			None => Ok(()),
+		}
+	}
+
+	/// Returns the lines of the canvas, highlighting the cell a hint points
+	/// at.
+	fn canvas_lines(&self, runtime: &ScriptRuntime) -> Vec<Line<'static>> {
+		runtime
+			.canvas()
+			.rows()
+			.enumerate()
+			.map(|(y, row)| {
+				Line::from(
+					row.iter()
+						.enumerate()
+						.map(|(x, cell)| {
+							let mut style = Style::new().fg(cell.color);
+							if self.hint_position == Some((x, y)) {
+								style = style.bg(HINT_HIGHLIGHT);
+							}
+							Span::styled(cell.symbol.to_string(), style)
+						})
+						.collect::<Vec<_>>(),
+				)
+			})
+			.collect()
+	}
+
+	/// Returns the height of the status below the canvas, which shows the
+	/// score in large text in the large text mode.
+	fn status_height(&self) -> u16 {
+		if self.large_text {
+			get_large_text_height() + 3
+		} else {
+			3
+		}
+	}
+
+	/// Returns the line below the canvas, with the mode, the countdown, the
+	/// score and the script's status. Pauses are left out while the game is
+	/// [counting down](ScreenState::is_counting_down) to its start.
+	fn status_line(&self, runtime: &ScriptRuntime, state: &ScreenState) -> Line<'static> {
+		let canvas = runtime.canvas();
+		let mut parts = Vec::new();
+		if self.mode != GameMode::Standard {
+			parts.push(self.mode.label().to_string());
+		}
+		if let Some(countdown) = &self.countdown {
+			parts.push(countdown.text());
+		}
+		parts.push(t!("scripted-score", score = canvas.score));
+		if canvas.hints > 0 {
+			parts.push(t!("scripted-hints", hints = canvas.hints));
+		}
+		if self.paused_at.is_some() && !state.is_counting_down() {
+			parts.push(t!("scripted-paused").to_string());
+		}
+		if !canvas.status.is_empty() {
+			parts.push(canvas.status.clone());
+		}
+		if canvas.over {
+			parts.push(t!("scripted-over").to_string());
+		}
+		Line::from(parts.join(" | "))
+	}
+}
+
+impl Screen for ScriptedGameScreen {
+	fn initial_state(&self) -> ScreenState {
+		let state = ScreenState::new(
+			t!("scripted-title"),
+			ScreenKind::Normal,
+			Some(vec![
+				(t!("scripted-any-key"), t!("scripted-key-control")),
+				("Enter", t!("scripted-restart-control")),
+				("Ctrl + R", t!("scripted-reload-control")),
+			]),
+		)
+		.with_actions(vec![
+			(&[Action::Undo], t!("scripted-undo-control")),
+			(&[Action::Redo], t!("scripted-redo-control")),
+		])
+		.with_pause_menu();
+		// Like reloading, restarting is a way out of a bad game.
+		if self.mode.allows_undo() {
+			state.with_quick_restart()
+		} else {
+			state
+		}
+	}
+
+	fn handle_action(&mut self, action: Action, state: &mut ScreenState) -> anyhow::Result<bool> {
+		// Keys wait for the game to resume, like after its start countdown.
+		if self.paused_at.is_some() {
+			return Ok(false);
+		}
+		let over = self.runtime.as_ref().is_some_and(|runtime| runtime.borrow().canvas().over);
+		let undoable = self.runtime.as_ref().filter(|runtime| runtime.borrow().undoable());
+		match (action, undoable) {
+			(Action::Confirm, _) if over => {
+				self.hint_position = None;
+				self.seed = Some(rng::seed_game());
+				self.restart(state);
+			},
+			(Action::Undo, Some(runtime)) => {
+				runtime.borrow_mut().undo();
+				self.hint_position = None;
+			},
+			(Action::Redo, Some(runtime)) => {
+				runtime.borrow_mut().redo();
+				self.hint_position = None;
+			},
+			// Other actions go to the script as keys.
+			_ => return Ok(false),
+		}
+		Ok(true)
+	}
+
+	fn handle_event(&mut self, event: &Event, state: &mut ScreenState) -> anyhow::Result<()> {
+		let Event::Key(key) = event else {
+			return Ok(());
+		};
+		// Keys wait for the game to resume, like after its start countdown.
+		if self.paused_at.is_some() {
+			return Ok(());
+		}
+		self.hint_position = None;
+		// Reloading is a way out of a bad game, so hardcore games only allow it
+		// to recover from an error.
+		if key.code == KeyCode::Char('r')
+			&& key.modifiers == KeyModifiers::CONTROL
+			&& (self.mode.allows_undo() || self.error.is_some())
+		{
+			self.save_data()?;
+			autosave::discard(&self.autosave_name());
+			self.load();
+			self.start_countdown(state);
+			state.offers_hints = self.offers_hints();
+			return Ok(());
+		}
+		let over = self.runtime.as_ref().is_some_and(|runtime| runtime.borrow().canvas().over);
+		let seed = self.results.as_ref().and_then(ResultsBanner::seed);
+		if let (true, Some(seed)) = (over, seed) {
+			match key.code {
+				KeyCode::Char('c') => {
+					state.copy(seed.to_string());
+					if let Some(results) = &mut self.results {
+						results.mark_seed_copied();
+					}
+				},
+				KeyCode::Char('s') => {
+					rng::seed(seed);
+					self.restart(state);
+				},
+				_ => {},
+			}
+		} else if let Some(name) = key_name(key.code) {
+			self.run(|runtime| runtime.key(&name));
+		}
+		Ok(())
+	}
+
+	fn quick_restart(&mut self, state: &mut ScreenState) -> anyhow::Result<()> {
+		if self.runtime.is_none() || self.error.is_some() {
+			return Ok(());
+		}
+		// Resumed games have no seed to play again from, so they start from a
+		// new one.
+		match self.seed {
+			Some(seed) => rng::seed(seed),
+			None => self.seed = Some(rng::seed_game()),
+		}
+		autosave::discard(&self.autosave_name());
+		self.restart(state);
+		Ok(())
+	}
+
+	fn has_significant_progress(&self) -> bool {
+		let over = self.runtime.as_ref().is_some_and(|runtime| runtime.borrow().canvas().over);
+		!over && self.started.is_none_or(|started| started.elapsed() >= SIGNIFICANT_PLAY_TIME)
+	}
+
+	fn update(&mut self, state: &mut ScreenState) {
+		if self.runtime.is_none() && self.error.is_none() {
+			self.load();
+			self.start_countdown(state);
+			state.offers_hints = self.offers_hints();
+		}
+		if self.paused_at.is_some() {
+			return;
+		}
+		if let Some(results) = &mut self.results {
+			results.tick();
+		}
+		if self.countdown.as_ref().is_some_and(Countdown::is_over) {
+			self.run(ScriptRuntime::end);
+		}
+		let interval =
+			self.runtime.as_ref().and_then(|runtime| runtime.borrow().tick_interval_ms());
+		if let Some(interval) = interval {
+			if self.last_tick.elapsed() >= Duration::from_millis(interval.max(1)) {
+				self.last_tick = Instant::now();
+				self.run(ScriptRuntime::tick);
+			}
+		}
+	}
+
+	fn hint(&mut self, _state: &mut ScreenState) -> Option<Hint> {
+		let runtime = self.runtime.as_ref()?;
+		if self.error.is_some() {
+			return None;
+		}
+		let hint = runtime.borrow_mut().hint();
+		match hint {
+			Ok(hint) => {
+				if let Some(Hint::Position(x, y)) = hint {
+					self.hint_position = Some((x, y));
+				}
+				hint
+			},
+			Err(err) => {
+				self.error = Some(format!("{err:#}"));
+				None
+			},
+		}
+	}
+
+	fn pause(&mut self) {
+		self.paused_at.get_or_insert_with(Instant::now);
+	}
+
+	fn resume(&mut self) {
+		let Some(paused_at) = self.paused_at.take() else {
+			return;
+		};
+		let paused = paused_at.elapsed();
+		self.last_tick += paused;
+		if let Some(started) = &mut self.started {
+			*started += paused;
+		}
+		if let Some(countdown) = &mut self.countdown {
+			countdown.delay(paused);
+		}
+	}
+
+	fn autosave(&mut self) -> anyhow::Result<()> {
+		let Some(runtime) = &self.runtime else {
+			return Ok(());
+		};
+		let runtime = runtime.borrow();
+		if self.error.is_some() || runtime.canvas().over || !self.mode.allows_saves() {
+			return Ok(());
+		}
+		runtime.save()?;
+		autosave::save(&self.autosave_name(), &runtime.snapshot())
+	}
+
+	fn close(&mut self) -> anyhow::Result<()> {
+		self.save_data()?;
+		if Config::load_or_default().autosave_on_leave {
+			self.autosave()?;
+		}
+		Ok(())
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, state: &ScreenState) {
+		let (width, height) = self.runtime.as_ref().map_or((0, 0), |runtime| {
+			let runtime = runtime.borrow();
+			(
+				runtime.canvas().width() as u16,
+				runtime.canvas().height() as u16,
+			)
+		});
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.vertical_margin(1)
+			.horizontal_margin(2)
+			.constraints([
+				Constraint::Min(height + 2),
+				Constraint::Length(self.status_height()),
+				Constraint::Length(if self.error.is_some() { 6 } else { 0 }),
+			])
+			.split(frame.size());
+
+		if let Some(runtime) = &self.runtime {
+			let runtime = runtime.borrow();
+			let canvas_area = Layout::default()
+				.direction(Direction::Horizontal)
+				.constraints([
+					Constraint::Min(0),
+					Constraint::Length(width + 2),
+					Constraint::Min(0),
+				])
+				.split(chunks[0])[1];
+			let canvas =
+				Paragraph::new(self.canvas_lines(&runtime)).block(titled_ui_block(&self.game.name));
+			frame.render_widget(canvas, canvas_area);
+			let mut status = Text::from(self.status_line(&runtime, state));
+			if self.large_text {
+				let score = get_large_text(
+					&runtime.canvas().score.to_string(),
+					chunks[1].width.saturating_sub(2),
+					get_large_text_height(),
+				);
+				status.lines.splice(0..0, score.unwrap_or_default().lines);
+			}
+			let status =
+				Paragraph::new(status).alignment(Alignment::Center).block(untitled_ui_block());
+			frame.render_widget(status, chunks[1]);
+			if let Some(results) = &self.results {
+				results.render(frame, chunks[0]);
+			}
+		}
+		if let Some(error) = &self.error {
+			let error = Paragraph::new(t!("scripted-error", error = error.as_str()))
+				.style(Style::new().fg(Color::Red))
+				.wrap(Wrap { trim: true })
+				.block(titled_ui_block(t!("scripted-error-title")));
+			frame.render_widget(error, chunks[2]);
+		}
+	}
+
+	fn help_page(&self) -> Option<&'static str> {
+		Some(HELP_PAGE)
+	}
+}
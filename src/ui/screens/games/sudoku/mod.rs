@@ -0,0 +1,4 @@
+//! Screens used for Sudoku.
+
+pub mod setup;
+pub mod sudoku_game;
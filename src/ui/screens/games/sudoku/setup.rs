@@ -0,0 +1,91 @@
+//! Game setup screen for Sudoku, where the player picks how hard the puzzle is.
+
+use ratatui::{
+	layout::{
+		Constraint,
+		Direction,
+		Layout,
+	},
+	Frame,
+};
+
+use crate::{
+	core::{
+		actions::Action,
+		events::Event,
+	},
+	games::{
+		sudoku::{
+			setup::PuzzleSetup,
+			Sudoku,
+		},
+		Game,
+	},
+	t,
+	ui::{
+		components::games::setup::SetupQuestions,
+		screens::{
+			ScreenKind,
+			ScreenState,
+		},
+		Screen,
+		SudokuGameScreen,
+	},
+};
+
+/// A setup screen for a Sudoku puzzle.
+#[derive(Clone)]
+#[must_use]
+pub struct SudokuSetupScreen {
+	/// The questions, with the answers given.
+	questions: SetupQuestions<PuzzleSetup>,
+}
+
+impl Default for SudokuSetupScreen {
+	fn default() -> Self {
+		Self {
+			questions: SetupQuestions::new(PuzzleSetup::default()),
+		}
+	}
+}
+
+impl Screen for SudokuSetupScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(t!("sudoku-setup-title"), ScreenKind::Normal, None).with_actions(vec![
+			(&[Action::Up, Action::Down], t!("sudoku-setup-select")),
+			(&[Action::Left, Action::Right], t!("sudoku-setup-change")),
+			(&[Action::Confirm], t!("sudoku-start")),
+			(&[Action::Help], t!("sudoku-help-control")),
+		])
+	}
+
+	fn handle_event(&mut self, _event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		Ok(())
+	}
+
+	fn handle_action(&mut self, action: Action, state: &mut ScreenState) -> anyhow::Result<bool> {
+		if action == Action::Confirm {
+			let difficulty = self.questions.answers().difficulty;
+			state.create_screen(SudokuGameScreen::new(difficulty).into());
+			return Ok(true);
+		}
+		Ok(self.questions.handle_action(action))
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.vertical_margin(1)
+			.horizontal_margin(2)
+			.constraints([
+				Constraint::Length(self.questions.get_height()),
+				Constraint::Min(0),
+			])
+			.split(frame.size());
+		self.questions.render(frame, chunks[0]);
+	}
+
+	fn help_page(&self) -> Option<&'static str> {
+		Sudoku.help_page()
+	}
+}
@@ -0,0 +1,439 @@
+//! The screen of a game of Sudoku, filling the puzzle's cells in, or pencilling
+//! candidates in them, until every cell is filled without a conflict.
+
+use std::time::Duration;
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Constraint,
+		Direction,
+		Layout,
+	},
+	widgets::Paragraph,
+	Frame,
+};
+
+#[cfg(feature = "dev-console")]
+use crate::ui::components::dev_console::{
+	self,
+	DevCommand,
+};
+use crate::{
+	core::{
+		actions::Action,
+		events::{
+			Event,
+			KeyCode,
+		},
+		time::Instant,
+	},
+	games::{
+		clock_text,
+		difficulty::DifficultyRating,
+		modes::GameMode,
+		registry::GameId,
+		sudoku::{
+			puzzle::{
+				Puzzle,
+				SIZE,
+			},
+			GAME_ID,
+			HELP_PAGE,
+		},
+		GameDynamicInfo,
+		GameResult,
+	},
+	services::undo::{
+		Snapshot,
+		UndoStack,
+	},
+	t,
+	ui::{
+		components::{
+			games::sudoku::grid::render_puzzle,
+			pause_menu::{
+				Hint,
+				HINT_TIME_PENALTY,
+			},
+			presets::untitled_ui_block,
+			results_banner::{
+				ResultsBanner,
+				Verdict,
+			},
+		},
+		screens::{
+			ScreenKind,
+			ScreenState,
+			SIGNIFICANT_PLAY_TIME,
+		},
+		Screen,
+	},
+};
+
+/// Usage of the [developer console](dev_console) command filling a cell in
+/// with its solution.
+#[cfg(feature = "dev-console")]
+const FILL_USAGE: &str = "fill <x> <y>";
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct SudokuGameScreen {
+	/// How hard the puzzle is.
+	difficulty: DifficultyRating,
+
+	/// The puzzle being solved.
+	puzzle: Puzzle,
+
+	/// The digits and pencil marks entered, to take back.
+	undo: UndoStack<Snapshot<Puzzle>>,
+
+	/// The cell under the cursor, as `(x, y)`.
+	cursor: (usize, usize),
+
+	/// Whether digits typed are pencilled in as candidates rather than filled
+	/// in.
+	pencil: bool,
+
+	/// When the puzzle was started, moved forward by the time spent paused.
+	started: Instant,
+
+	/// When the game was [paused](Screen::pause), if it is.
+	paused_at: Option<Instant>,
+
+	/// How many [hints](Screen::hint) were given, each adding
+	/// [`HINT_TIME_PENALTY`] to the time.
+	hints: u32,
+
+	/// How long the puzzle took to solve, once it's solved.
+	solved_in: Option<Duration>,
+
+	/// Banner announcing the puzzle solved and how its time compares to the
+	/// player's best, shown once it's solved.
+	results: Option<ResultsBanner>,
+}
+
+impl SudokuGameScreen {
+	/// Starts a game on a new puzzle of a difficulty, counting a play of the
+	/// game.
+	pub fn new(difficulty: DifficultyRating) -> Self {
+		let _ = GameDynamicInfo::update(&GameId::from(GAME_ID), GameDynamicInfo::play);
+		Self {
+			difficulty,
+			puzzle: Puzzle::generate(difficulty),
+			undo: UndoStack::default(),
+			cursor: (SIZE / 2, SIZE / 2),
+			pencil: false,
+			started: Instant::now(),
+			paused_at: None,
+			hints: 0,
+			solved_in: None,
+			results: None,
+		}
+	}
+
+	/// Returns how long the puzzle has been played for, leaving out the time
+	/// spent paused and adding the hints' penalty.
+	fn elapsed(&self) -> Duration {
+		if let Some(time) = self.solved_in {
+			return time;
+		}
+		self.paused_at.unwrap_or_else(Instant::now).duration_since(self.started)
+			+ HINT_TIME_PENALTY * self.hints
+	}
+
+	/// Moves the cursor by a step, staying in the grid.
+	fn move_cursor(&mut self, dx: isize, dy: isize) {
+		let (x, y) = self.cursor;
+		self.cursor = (
+			x.saturating_add_signed(dx).min(SIZE - 1),
+			y.saturating_add_signed(dy).min(SIZE - 1),
+		);
+	}
+
+	/// Fills the cell under the cursor in with a digit, or pencils it in,
+	/// recording the game's result and announcing it once the puzzle is
+	/// solved.
+	fn enter_digit(&mut self, digit: u8) {
+		let (x, y) = self.cursor;
+		let before = self.puzzle.clone();
+		if self.pencil && digit != 0 {
+			if self.puzzle.toggle_mark(x, y, digit) {
+				self.remember(before);
+			}
+			return;
+		}
+		if self.puzzle.set(x, y, digit) {
+			self.remember(before);
+			self.check_solved();
+		}
+	}
+
+	/// Fills a cell in with its solution and moves the cursor onto it,
+	/// returning the cell. That's the cell under the cursor if it's empty or
+	/// wrong, or else the first wrong cell, or else the first empty one.
+	fn reveal_cell(&mut self) -> Option<(usize, usize)> {
+		let solution = self.puzzle.solution();
+		let wrong = |&(x, y): &(usize, usize)| {
+			let digit = self.puzzle.get(x, y);
+			digit != 0 && digit != solution[y * SIZE + x]
+		};
+		let empty = |&(x, y): &(usize, usize)| self.puzzle.get(x, y) == 0;
+		let cells = (0..SIZE * SIZE).map(|index| (index % SIZE, index / SIZE));
+		let (x, y) = std::iter::once(self.cursor)
+			.filter(|cell| wrong(cell) || empty(cell))
+			.chain(cells.clone().filter(wrong))
+			.chain(cells.filter(empty))
+			.next()?;
+		let before = self.puzzle.clone();
+		self.puzzle.set(x, y, solution[y * SIZE + x]);
+		self.remember(before);
+		self.cursor = (x, y);
+		Some((x, y))
+	}
+
+	/// Remembers the puzzle as it was before a change, to take it back.
+	fn remember(&mut self, before: Puzzle) {
+		if before != self.puzzle {
+			self.undo.push(Snapshot {
+				before,
+				after: self.puzzle.clone(),
+			});
+		}
+	}
+
+	/// Records the game's result and announces it if the puzzle is solved.
+	fn check_solved(&mut self) {
+		if !self.puzzle.is_solved() {
+			return;
+		}
+		let time = self.elapsed();
+		self.solved_in = Some(time);
+		let result = GameResult::new(GameMode::Standard, None, Some(time));
+		let comparison =
+			GameDynamicInfo::update(&GameId::from(GAME_ID), |info| info.record_result(&result))
+				.ok();
+		let mut details = vec![
+			t!("sudoku-solved-in", time = clock_text(time.as_secs())),
+			comparison.unwrap_or_default().text(),
+		];
+		if self.hints > 0 {
+			details.push(t!(
+				"sudoku-hints-used",
+				count = self.hints,
+				time = clock_text((HINT_TIME_PENALTY * self.hints).as_secs())
+			));
+		}
+		self.results =
+			Some(ResultsBanner::new(Verdict::Won, None).with_details(details).with_confetti(true));
+	}
+
+	/// Returns the line below the grid, with the difficulty, the time, whether
+	/// digits are pencilled in, and the marks of the cell under the cursor.
+	fn status_line(&self) -> String {
+		let mut parts = vec![
+			self.difficulty.label().to_string(),
+			t!("sudoku-time", time = clock_text(self.elapsed().as_secs())),
+		];
+		if self.hints > 0 {
+			parts.push(t!("sudoku-hints", hints = self.hints));
+		}
+		if self.pencil {
+			parts.push(t!("sudoku-pencil-on").to_string());
+		}
+		let marks = self.puzzle.marks(self.cursor.0, self.cursor.1);
+		if !marks.is_empty() {
+			let marks: Vec<_> = marks.iter().map(u8::to_string).collect();
+			parts.push(t!("sudoku-marks", marks = marks.join(" ")));
+		}
+		parts.join(" | ")
+	}
+}
+
+impl Screen for SudokuGameScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(
+			t!("sudoku-title"),
+			ScreenKind::Normal,
+			Some(vec![
+				("1-9", t!("sudoku-digit-control")),
+				("0 / Backspace", t!("sudoku-clear-control")),
+				("P", t!("sudoku-pencil-control")),
+				("Enter", t!("sudoku-new-control")),
+			]),
+		)
+		.with_actions(vec![
+			(
+				&[Action::Up, Action::Down, Action::Left, Action::Right],
+				t!("sudoku-move"),
+			),
+			(&[Action::Undo], t!("sudoku-undo-control")),
+			(&[Action::Redo], t!("sudoku-redo-control")),
+			(&[Action::Help], t!("sudoku-help-control")),
+		])
+		.with_pause_menu()
+		.with_hints()
+		.with_quick_restart()
+	}
+
+	fn handle_event(&mut self, event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		let Event::Key(key) = event else {
+			return Ok(());
+		};
+		if self.results.is_some() {
+			return Ok(());
+		}
+		match key.code {
+			KeyCode::Char(char @ '0'..='9') => self.enter_digit(char as u8 - b'0'),
+			KeyCode::Backspace | KeyCode::Delete => self.enter_digit(0),
+			KeyCode::Char('p' | 'P') => self.pencil = !self.pencil,
+			_ => {},
+		}
+		Ok(())
+	}
+
+	fn handle_action(&mut self, action: Action, _state: &mut ScreenState) -> anyhow::Result<bool> {
+		match action {
+			Action::Confirm if self.results.is_some() => *self = Self::new(self.difficulty),
+			Action::Undo if self.results.is_none() => {
+				self.undo.undo(&mut self.puzzle);
+			},
+			Action::Redo if self.results.is_none() => {
+				if self.undo.redo(&mut self.puzzle) {
+					self.check_solved();
+				}
+			},
+			Action::Up => self.move_cursor(0, -1),
+			Action::Down => self.move_cursor(0, 1),
+			Action::Left => self.move_cursor(-1, 0),
+			Action::Right => self.move_cursor(1, 0),
+			_ => return Ok(false),
+		}
+		Ok(true)
+	}
+
+	fn quick_restart(&mut self, _state: &mut ScreenState) -> anyhow::Result<()> {
+		self.puzzle.reset();
+		self.undo = UndoStack::default();
+		self.started = Instant::now();
+		self.paused_at = None;
+		self.hints = 0;
+		self.solved_in = None;
+		self.results = None;
+		Ok(())
+	}
+
+	fn has_significant_progress(&self) -> bool {
+		self.results.is_none() && self.elapsed() >= SIGNIFICANT_PLAY_TIME
+	}
+
+	#[cfg(feature = "dev-console")]
+	fn dev_commands(&self) -> Vec<DevCommand> {
+		vec![
+			("reveal", t!("sudoku-command-reveal")),
+			(FILL_USAGE, t!("sudoku-command-fill")),
+		]
+	}
+
+	#[cfg(feature = "dev-console")]
+	fn dev_command(
+		&mut self,
+		name: &str,
+		args: &[&str],
+		_state: &mut ScreenState,
+	) -> anyhow::Result<String> {
+		let solution = self.puzzle.solution();
+		if name == "reveal" {
+			for (index, digit) in solution.into_iter().enumerate() {
+				self.puzzle.set(index % SIZE, index / SIZE, digit);
+			}
+		} else {
+			let x: usize = dev_console::arg(args, 0, FILL_USAGE)?;
+			let y: usize = dev_console::arg(args, 1, FILL_USAGE)?;
+			if x >= SIZE || y >= SIZE {
+				anyhow::bail!(t!("dev-console-usage", usage = FILL_USAGE));
+			}
+			self.puzzle.set(x, y, solution[y * SIZE + x]);
+		}
+		if self.results.is_none() {
+			self.check_solved();
+		}
+		Ok(String::new())
+	}
+
+	fn hint(&mut self, _state: &mut ScreenState) -> Option<Hint> {
+		if self.results.is_some() {
+			return None;
+		}
+		let (x, y) = self.reveal_cell()?;
+		self.hints += 1;
+		self.check_solved();
+		Some(Hint::Position(x, y))
+	}
+
+	fn update(&mut self, _state: &mut ScreenState) {
+		if let Some(results) = &mut self.results {
+			results.tick();
+		}
+	}
+
+	fn pause(&mut self) {
+		self.paused_at.get_or_insert_with(Instant::now);
+	}
+
+	fn resume(&mut self) {
+		if let Some(paused_at) = self.paused_at.take() {
+			self.started += paused_at.elapsed();
+		}
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.vertical_margin(1)
+			.horizontal_margin(2)
+			.constraints([Constraint::Min(0), Constraint::Length(3)])
+			.split(frame.size());
+		render_puzzle(frame, chunks[0], &self.puzzle, self.cursor);
+		let status = Paragraph::new(self.status_line())
+			.alignment(Alignment::Center)
+			.block(untitled_ui_block());
+		frame.render_widget(status, chunks[1]);
+		if let Some(results) = &self.results {
+			results.render(frame, chunks[0]);
+		}
+	}
+
+	fn help_page(&self) -> Option<&'static str> {
+		Some(HELP_PAGE)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::core::files;
+
+	#[test]
+	fn hints_fill_a_cell_in_and_cost_time() {
+		files::isolate_test_files();
+		let mut state = ScreenState::new("", ScreenKind::Normal, None);
+		let mut game = SudokuGameScreen::new(DifficultyRating::Easy);
+		let solution = game.puzzle.solution();
+		let index = (0..SIZE * SIZE)
+			.find(|&index| !game.puzzle.is_given(index % SIZE, index / SIZE))
+			.unwrap();
+		let (x, y) = (index % SIZE, index / SIZE);
+		game.cursor = (x, y);
+		game.enter_digit(solution[index] % 9 + 1);
+
+		assert_eq!(game.hint(&mut state), Some(Hint::Position(x, y)));
+		assert_eq!(game.puzzle.get(x, y), solution[index]);
+		assert_eq!(game.hints, 1);
+		assert!(game.elapsed() >= HINT_TIME_PENALTY);
+
+		game.handle_action(Action::Undo, &mut state).unwrap();
+		assert_ne!(game.puzzle.get(x, y), solution[index]);
+	}
+}
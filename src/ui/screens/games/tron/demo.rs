@@ -0,0 +1,106 @@
+//! A game of Tron playing itself, between two
+//! [demo](crate::games::demo::DemoPlayable) cycles. Any key
+//! stops watching.
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Constraint,
+		Direction,
+		Layout,
+		Rect,
+	},
+	widgets::Paragraph,
+	Frame,
+};
+
+use crate::{
+	core::events::Event,
+	games::{
+		demo::DemoPlayer,
+		tron::arena::{
+			Arena,
+			Outcome,
+		},
+	},
+	t,
+	ui::{
+		components::{
+			games::{
+				tron::arena::render_arena,
+				zoom::Zoom,
+			},
+			presets::untitled_ui_block,
+		},
+		screens::{
+			OpenStatus,
+			ScreenKind,
+			ScreenState,
+		},
+		Screen,
+	},
+};
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone, Default)]
+#[must_use]
+pub struct TronDemoScreen {
+	/// Plays the game.
+	player: DemoPlayer<Arena>,
+}
+
+impl TronDemoScreen {
+	/// Returns the largest zoom the whole arena fits in an area at, with its
+	/// borders.
+	fn zoom(&self, bounds: Rect) -> Zoom {
+		let arena = self.player.game();
+		[Zoom::Large, Zoom::Medium]
+			.into_iter()
+			.find(|zoom| {
+				let (width, height) = zoom.grid_size(arena.width(), arena.height());
+				width + 2 <= bounds.width && height + 2 <= bounds.height
+			})
+			.unwrap_or(Zoom::Small)
+	}
+}
+
+impl Screen for TronDemoScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(t!("tron-demo-title"), ScreenKind::Normal, None)
+	}
+
+	fn event(&mut self, event: &Event, state: &mut ScreenState) -> anyhow::Result<()> {
+		if let Event::Key(_) = event {
+			state.open_status = OpenStatus::Closed;
+		}
+		Ok(())
+	}
+
+	fn handle_event(&mut self, _event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		Ok(())
+	}
+
+	fn update(&mut self, _state: &mut ScreenState) {
+		self.player.update();
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.vertical_margin(1)
+			.horizontal_margin(2)
+			.constraints([Constraint::Min(0), Constraint::Length(3)])
+			.split(frame.size());
+		let arena = self.player.game();
+		render_arena(frame, chunks[0], arena, self.zoom(chunks[0]));
+		let status = match arena.outcome() {
+			Some(Outcome::Winner(player)) => {
+				t!("tron-demo-won", player = (player + 1) as u64)
+			},
+			Some(Outcome::Draw) => t!("tron-demo-draw").to_string(),
+			None => t!("demo-watching").to_string(),
+		};
+		let status = Paragraph::new(status).alignment(Alignment::Center).block(untitled_ui_block());
+		frame.render_widget(status, chunks[1]);
+	}
+}
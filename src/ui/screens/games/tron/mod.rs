@@ -0,0 +1,5 @@
+//! Screens used for Tron.
+
+pub mod demo;
+pub mod setup;
+pub mod tron_game;
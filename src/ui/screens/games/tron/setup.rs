@@ -0,0 +1,151 @@
+//! Game setup screen for Tron, where players choose to host or join a game.
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Constraint,
+		Direction,
+		Layout,
+	},
+	Frame,
+};
+use strum::Display;
+
+use crate::{
+	core::{
+		actions::Action,
+		config::Config,
+		events::{
+			Event,
+			KeyCode,
+		},
+	},
+	games::{
+		tron::{
+			Tron,
+			GAME_NAME,
+		},
+		Game,
+	},
+	t,
+	ui::{
+		screens::{
+			ScreenKind,
+			ScreenState,
+		},
+		widgets::scrollable_list::{
+			ListItem,
+			ScrollableList,
+		},
+		HostLobbyScreen,
+		JoinLobbyScreen,
+		LocalGamesScreen,
+		Screen,
+		TronGameScreen,
+	},
+};
+
+/// Ways to set up a game of Tron.
+#[derive(Clone, Copy, PartialEq, Eq, Display)]
+enum SetupOption {
+	Host,
+	JoinLocal,
+	Join,
+	WatchDemo,
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct TronSetupScreen {
+	/// Scrollable list widget for the setup options.
+	options_list: ScrollableList<SetupOption>,
+}
+
+impl Default for TronSetupScreen {
+	fn default() -> Self {
+		let options_list = ScrollableList::new(
+			vec![
+				ListItem::new(None, SetupOption::Host, Some(t!("tron-host").to_string())),
+				ListItem::new(
+					None,
+					SetupOption::JoinLocal,
+					Some(t!("tron-join-local").to_string()),
+				),
+				ListItem::new(None, SetupOption::Join, Some(t!("tron-join").to_string())),
+				ListItem::new(
+					None,
+					SetupOption::WatchDemo,
+					Some(t!("tron-watch-demo").to_string()),
+				),
+			],
+			None,
+			1,
+			Direction::Vertical,
+			Alignment::Center,
+			Some((1, 3)),
+			None,
+		)
+		.with_large_text(Config::load_or_default().large_text);
+		Self { options_list }
+	}
+}
+
+impl Screen for TronSetupScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(t!("tron-setup-title"), ScreenKind::Normal, None).with_actions(vec![
+			(&[Action::Up, Action::Down], t!("tron-select")),
+			(&[Action::Confirm], t!("tron-start")),
+			(&[Action::Help], t!("tron-help-control")),
+		])
+	}
+
+	fn handle_event(&mut self, _event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		Ok(())
+	}
+
+	fn handle_action(&mut self, action: Action, state: &mut ScreenState) -> anyhow::Result<bool> {
+		match action {
+			Action::Up => self.options_list.scroll_forward(),
+			Action::Down => self.options_list.scroll_backward(),
+			Action::Confirm => {
+				let start = |session| TronGameScreen::new(session).into();
+				match self.options_list.get_selected().map(|(_, item)| item.data) {
+					Some(SetupOption::Host) => {
+						state.create_screen(HostLobbyScreen::new(GAME_NAME, start).into());
+					},
+					Some(SetupOption::JoinLocal) => {
+						state.create_screen(LocalGamesScreen::new(GAME_NAME, start).into());
+					},
+					Some(SetupOption::Join) => {
+						state.create_screen(JoinLobbyScreen::new(GAME_NAME, start).into());
+					},
+					Some(SetupOption::WatchDemo) => {
+						if let Some(screen) = Tron.demo_screen() {
+							state.create_screen(screen);
+						}
+					},
+					None => {},
+				}
+			},
+			_ => return Ok(false),
+		}
+		Ok(true)
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.margin(1)
+			.constraints([
+				Constraint::Length(self.options_list.get_height()),
+				Constraint::Min(0),
+			])
+			.split(frame.size());
+		self.options_list.render(frame, chunks[0]);
+	}
+
+	fn help_page(&self) -> Option<&'static str> {
+		Tron.help_page()
+	}
+}
@@ -0,0 +1,573 @@
+//! The screen containing the Tron game itself, played over a network
+//! [session](Session). The host runs the simulation and sends every step to the
+//! guest, who only sends back where it turns, and to the spectators.
+
+use std::time::Duration;
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Constraint,
+		Direction,
+		Layout,
+	},
+	style::Style,
+	text::{
+		Line,
+		Span,
+		Text,
+	},
+	widgets::Paragraph,
+	Frame,
+};
+
+use crate::{
+	core::{
+		actions::Action,
+		config::Config,
+		events::{
+			Event,
+			KeyCode,
+		},
+		time::Instant,
+	},
+	games::{
+		registry::GameId,
+		tron::{
+			arena::{
+				Arena,
+				Heading,
+				Outcome,
+			},
+			Tron,
+			TronEvent,
+			GAME_ID,
+		},
+		Game,
+		GameDynamicInfo,
+	},
+	services::{
+		net::{
+			default_player_name,
+			Role,
+			Session,
+			SessionEvent,
+		},
+		rating::{
+			updated_rating,
+			MatchResult,
+			DEFAULT_RATING,
+		},
+	},
+	t,
+	ui::{
+		components::{
+			banner::{
+				get_large_text,
+				get_large_text_height,
+			},
+			games::{
+				tron::arena::{
+					render_arena,
+					PLAYER_COLORS,
+				},
+				zoom::Zoom,
+			},
+			presets::untitled_ui_block,
+		},
+		layout::split_side_pane,
+		screens::{
+			ActionEntry,
+			ScreenKind,
+			ScreenState,
+		},
+		widgets::{
+			chat::ChatPane,
+			utils::focus_ring::FocusRing,
+			Widget,
+			WidgetState,
+		},
+		Screen,
+	},
+};
+
+/// Width of the arena, in cells.
+pub const ARENA_WIDTH: usize = 40;
+
+/// Height of the arena, in cells.
+pub const ARENA_HEIGHT: usize = 20;
+
+/// Returns the smallest terminal size the whole arena fits in at a zoom, with
+/// its borders, the score, the status and the screen's margins.
+fn min_size(zoom: Zoom) -> (u16, u16) {
+	let (width, height) = zoom.grid_size(ARENA_WIDTH, ARENA_HEIGHT);
+	(width + 6, height + 10)
+}
+
+/// Returns the terminal size the arena fits in at a zoom with the chat
+/// expanded beside it.
+fn preferred_size(zoom: Zoom) -> (u16, u16) {
+	let (width, height) = min_size(zoom);
+	(width + CHAT_WIDTH, height)
+}
+
+/// Width of the chat pane, in columns.
+pub const CHAT_WIDTH: u16 = 32;
+
+/// How often the host steps the simulation.
+pub const TICK_INTERVAL: Duration = Duration::from_millis(120);
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct TronGameScreen {
+	/// The session with the other player.
+	session: Session,
+
+	/// The arena being raced in.
+	arena: Arena,
+
+	/// Names of the players, by player.
+	names: [String; 2],
+
+	/// Rounds won, by player.
+	wins: [u32; 2],
+
+	/// [Ratings](crate::services::rating), by player. Every round is a rated
+	/// match.
+	ratings: [u32; 2],
+
+	/// When the simulation was last stepped.
+	last_tick: Instant,
+
+	/// Whether this player paused the game.
+	paused: bool,
+
+	/// Whether the other player paused the game.
+	peer_paused: bool,
+
+	/// Chat with the other player and the spectators.
+	chat: ChatPane,
+
+	/// State of the chat pane.
+	chat_state: WidgetState,
+
+	/// Whether the arena or the chat gets the keys typed.
+	focus: FocusRing<TronPane>,
+
+	/// Whether the rounds won are drawn in [large text](get_large_text).
+	large_text: bool,
+
+	/// How large the arena's cells are drawn.
+	zoom: Zoom,
+}
+
+/// A part of the Tron screen that can be focused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TronPane {
+	/// The arena, steered in.
+	Arena,
+
+	/// The chat, expanded while focused.
+	Chat,
+}
+
+impl TronGameScreen {
+	/// Creates the game screen for a session. The host is the first player.
+	/// Spectators learn about the guest once the host catches them up.
+	pub fn new(mut session: Session) -> Self {
+		let (own_name, peer_name) = (default_player_name(), session.peer_name().to_string());
+		let names = match session.role() {
+			Role::Host => [own_name, peer_name],
+			Role::Guest => [peer_name, own_name],
+			Role::Spectator => [peer_name, t!("tron-guest").to_string()],
+		};
+		let mut ratings = [DEFAULT_RATING; 2];
+		if let Some(player) = Self::player_of(session.role()) {
+			ratings[player] = GameDynamicInfo::load_or_default(&GameId::from(GAME_ID))
+				.map_or(DEFAULT_RATING, |info| info.rating_or_default());
+			let _ = session.send(&TronEvent::Rating(ratings[player]));
+		}
+		Self {
+			session,
+			arena: Arena::new(ARENA_WIDTH, ARENA_HEIGHT),
+			names,
+			wins: [0; 2],
+			ratings,
+			last_tick: Instant::now(),
+			paused: false,
+			peer_paused: false,
+			chat_state: ChatPane::default().initial_state(),
+			chat: ChatPane::default(),
+			focus: FocusRing::new(vec![TronPane::Arena, TronPane::Chat]),
+			large_text: Config::load_or_default().large_text,
+			zoom: Zoom::default(),
+		}
+	}
+
+	/// Returns the index of the player on a side of the session, or [`None`]
+	/// for spectators.
+	fn player_of(role: Role) -> Option<usize> {
+		match role {
+			Role::Host => Some(0),
+			Role::Guest => Some(1),
+			Role::Spectator => None,
+		}
+	}
+
+	/// Returns this player's index, or [`None`] if spectating.
+	fn player(&self) -> Option<usize> {
+		Self::player_of(self.session.role())
+	}
+
+	/// Turns this player's cycle, sending the turn to the host if this player
+	/// is the guest.
+	fn turn(&mut self, heading: Heading) -> anyhow::Result<()> {
+		match self.session.role() {
+			Role::Host => self.arena.turn(0, heading),
+			Role::Guest => self.session.send(&TronEvent::Turn(heading))?,
+			Role::Spectator => {},
+		}
+		Ok(())
+	}
+
+	/// Starts a new round after a countdown, telling the guest to as well if
+	/// this player is the host.
+	fn restart(&mut self, state: &mut ScreenState) -> anyhow::Result<()> {
+		self.arena = Arena::new(ARENA_WIDTH, ARENA_HEIGHT);
+		self.last_tick = Instant::now();
+		state.start_countdown();
+		if self.session.role() == Role::Host {
+			self.session.send(&TronEvent::Restart)?;
+		}
+		Ok(())
+	}
+
+	/// Pauses or resumes the round, telling the other player so that it stops
+	/// or goes on for both. The round picks up where it was when resumed.
+	fn set_paused(&mut self, paused: bool) {
+		if self.paused == paused || self.player().is_none() {
+			return;
+		}
+		self.paused = paused;
+		self.last_tick = Instant::now();
+		let _ = self.session.send(&TronEvent::Paused(paused));
+	}
+
+	/// Steps the simulation and sends the result to the guest, if this player
+	/// is the host and it's time to.
+	fn tick(&mut self) -> anyhow::Result<()> {
+		if self.session.role() != Role::Host
+			|| self.session.is_disconnected()
+			|| self.paused
+			|| self.peer_paused
+			|| self.last_tick.elapsed() < TICK_INTERVAL
+			|| self.arena.outcome().is_some()
+		{
+			return Ok(());
+		}
+		self.last_tick = Instant::now();
+		self.arena.step();
+		self.session.send(&TronEvent::Tick(*self.arena.cycles()))?;
+		self.finish_round();
+		Ok(())
+	}
+
+	/// Counts the win of the round and updates the ratings, if it just ended.
+	/// This player's new rating is saved.
+	fn finish_round(&mut self) {
+		let Some(outcome) = self.arena.outcome() else {
+			return;
+		};
+		if let Outcome::Winner(winner) = outcome {
+			self.wins[winner] += 1;
+		}
+		let Some(player) = self.player() else {
+			return;
+		};
+		let result = match outcome {
+			Outcome::Winner(winner) if winner == player => MatchResult::Win,
+			Outcome::Winner(_) => MatchResult::Loss,
+			Outcome::Draw => MatchResult::Draw,
+		};
+		let opponent = 1 - player;
+		let (rating, opponent_rating) = (self.ratings[player], self.ratings[opponent]);
+		self.ratings[opponent] = updated_rating(opponent_rating, rating, result.opposite());
+		self.ratings[player] = GameDynamicInfo::update(&GameId::from(GAME_ID), |info| {
+			info.record_match(opponent_rating, result)
+		})
+		.unwrap_or_else(|_| updated_rating(rating, opponent_rating, result));
+	}
+
+	/// Handles an event from the other player, or from the host if
+	/// spectating.
+	fn handle_session_event(&mut self, event: &SessionEvent<TronEvent>, state: &mut ScreenState) {
+		let role = self.session.role();
+		match event {
+			&SessionEvent::Game(TronEvent::Turn(heading)) if role == Role::Host => {
+				self.arena.turn(1, heading);
+			},
+			&SessionEvent::Game(TronEvent::Tick(cycles)) if role != Role::Host => {
+				let was_over = self.arena.outcome().is_some();
+				self.arena.apply(cycles);
+				if !was_over {
+					self.finish_round();
+				}
+			},
+			SessionEvent::Game(TronEvent::Restart) if role != Role::Host => {
+				self.arena = Arena::new(ARENA_WIDTH, ARENA_HEIGHT);
+				if role == Role::Guest {
+					state.start_countdown();
+				}
+			},
+			&SessionEvent::Game(TronEvent::Paused(paused)) if role != Role::Spectator => {
+				self.peer_paused = paused;
+				self.last_tick = Instant::now();
+			},
+			&SessionEvent::Game(TronEvent::Rating(rating)) if role != Role::Spectator => {
+				self.ratings[usize::from(role == Role::Host)] = rating;
+			},
+			SessionEvent::Game(TronEvent::Sync {
+				arena,
+				names,
+				wins,
+				ratings,
+			}) if role == Role::Spectator => {
+				self.arena.clone_from(arena);
+				self.names.clone_from(names);
+				self.wins = *wins;
+				self.ratings = *ratings;
+			},
+			SessionEvent::Chat { author, text } => self.chat.receive(author, text),
+			SessionEvent::SpectatorJoined if role == Role::Host => {
+				let _ = self.session.send_to_spectators(&TronEvent::Sync {
+					arena: self.arena.clone(),
+					names: self.names.clone(),
+					wins: self.wins,
+					ratings: self.ratings,
+				});
+			},
+			_ => {},
+		}
+	}
+
+	/// Returns the height of the score above the arena, which shows the rounds
+	/// won in large text in the large text mode.
+	fn score_height(&self) -> u16 {
+		if self.large_text {
+			get_large_text_height() + 3
+		} else {
+			3
+		}
+	}
+
+	/// Returns the line showing the players and their score.
+	fn score_line(&self) -> Line<'static> {
+		let player_span = |player: usize| {
+			Span::styled(
+				format!(
+					"{} ({}) ⭐{}",
+					self.names[player], self.wins[player], self.ratings[player]
+				),
+				Style::new().fg(PLAYER_COLORS[player]),
+			)
+		};
+		Line::from(vec![player_span(0), Span::raw("  vs  "), player_span(1)])
+	}
+
+	/// Returns the line showing the round's status and the connection's.
+	fn status_line(&self) -> String {
+		let latency = self.session.latency().map_or_else(
+			|| t!("tron-ping-unknown").to_string(),
+			|latency| t!("tron-ping", ms = latency.as_millis() as u64),
+		);
+		let status = if self.session.is_disconnected() {
+			t!("tron-peer-left", name = self.session.peer_name())
+		} else if self.peer_paused {
+			t!("tron-peer-paused", name = self.session.peer_name())
+		} else {
+			let restart_hint = match self.session.role() {
+				Role::Host => t!("tron-restart-host"),
+				Role::Guest | Role::Spectator => t!("tron-restart-wait"),
+			};
+			match (self.arena.outcome(), self.player()) {
+				(Some(Outcome::Winner(winner)), Some(player)) if winner == player => {
+					t!("tron-won", hint = restart_hint)
+				},
+				(Some(Outcome::Winner(_)), Some(_)) => {
+					t!("tron-crashed", hint = restart_hint)
+				},
+				(Some(Outcome::Winner(winner)), None) => {
+					t!(
+						"tron-other-won",
+						name = self.names[winner].as_str(),
+						hint = restart_hint
+					)
+				},
+				(Some(Outcome::Draw), _) => t!("tron-draw", hint = restart_hint),
+				(None, Some(_)) => t!("tron-ride").to_string(),
+				(None, None) => t!("tron-spectating").to_string(),
+			}
+		};
+		match self.session.spectator_count() {
+			0 => format!("{status}    {latency}"),
+			count => format!(
+				"{status}    {latency}    {}",
+				t!("tron-watching", count = count)
+			),
+		}
+	}
+}
+
+impl Screen for TronGameScreen {
+	fn initial_state(&self) -> ScreenState {
+		let mut controls = vec![
+			("Z", t!("tron-zoom-control")),
+			("Tab", t!("tron-chat-control")),
+		];
+		let mut actions: Vec<ActionEntry> = vec![(&[Action::Help], t!("tron-help-control"))];
+		if self.session.role() != Role::Spectator {
+			controls.splice(0..0, [
+				("W A S D", t!("tron-turn")),
+				("R", t!("tron-restart-control")),
+			]);
+			let arrows = &[Action::Up, Action::Down, Action::Left, Action::Right];
+			actions.insert(0, (arrows, t!("tron-turn")));
+		}
+		let (min_width, min_height) = min_size(self.zoom);
+		let (preferred_width, preferred_height) = preferred_size(self.zoom);
+		let state = ScreenState::new("Tron", ScreenKind::Normal, Some(controls))
+			.with_actions(actions)
+			.with_min_size(min_width, min_height)
+			.with_preferred_size(preferred_width, preferred_height);
+		if self.session.role() == Role::Spectator {
+			state
+		} else {
+			state.with_start_countdown()
+		}
+	}
+
+	fn handle_event(&mut self, event: &Event, state: &mut ScreenState) -> anyhow::Result<()> {
+		let Event::Key(key) = event else {
+			return Ok(());
+		};
+		match self.focus.route(event) {
+			None => {
+				self.chat.set_expanded(self.focus.is_focused(TronPane::Chat));
+				self.chat_state.focus = self.focus.widget_focus(TronPane::Chat);
+				return Ok(());
+			},
+			Some(TronPane::Chat) => {
+				self.chat.handle_event(event)?;
+				for text in self.chat.take_sent() {
+					self.session.send_chat(&text);
+				}
+				return Ok(());
+			},
+			Some(TronPane::Arena) => {},
+		}
+		let heading = match key.code {
+			KeyCode::Char('w' | 'W') => Heading::Up,
+			KeyCode::Char('s' | 'S') => Heading::Down,
+			KeyCode::Char('a' | 'A') => Heading::Left,
+			KeyCode::Char('d' | 'D') => Heading::Right,
+			KeyCode::Char('z' | 'Z') => {
+				self.zoom = self.zoom.next();
+				state.min_size = Some(min_size(self.zoom));
+				state.preferred_size = Some(preferred_size(self.zoom));
+				return Ok(());
+			},
+			KeyCode::Char('r' | 'R')
+				if self.session.role() == Role::Host && self.arena.outcome().is_some() =>
+			{
+				return self.restart(state);
+			},
+			_ => return Ok(()),
+		};
+		self.turn(heading)
+	}
+
+	fn handle_action(&mut self, action: Action, _state: &mut ScreenState) -> anyhow::Result<bool> {
+		if !self.focus.is_focused(TronPane::Arena) {
+			return Ok(false);
+		}
+		let heading = match action {
+			Action::Up => Heading::Up,
+			Action::Down => Heading::Down,
+			Action::Left => Heading::Left,
+			Action::Right => Heading::Right,
+			_ => return Ok(false),
+		};
+		self.turn(heading)?;
+		Ok(true)
+	}
+
+	fn update(&mut self, state: &mut ScreenState) {
+		for event in self.session.poll() {
+			self.handle_session_event(&event, state);
+		}
+		// The host only steps the round once the countdown gets to "Go!".
+		if !state.is_counting_down() {
+			let _ = self.tick();
+		}
+	}
+
+	fn pause(&mut self) {
+		self.set_paused(true);
+	}
+
+	fn resume(&mut self) {
+		self.set_paused(false);
+	}
+
+	fn close(&mut self) -> anyhow::Result<()> {
+		self.session.leave();
+		Ok(())
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.vertical_margin(1)
+			.horizontal_margin(2)
+			.constraints([
+				Constraint::Length(self.score_height()), // Score
+				Constraint::Min(0),                      // Arena
+				Constraint::Length(3),                   // Status
+			])
+			.split(frame.size());
+		let mut score = Text::from(self.score_line());
+		if self.large_text {
+			let wins = get_large_text(
+				&format!("{} - {}", self.wins[0], self.wins[1]),
+				chunks[0].width.saturating_sub(2),
+				get_large_text_height(),
+			);
+			score.lines.splice(0..0, wins.unwrap_or_default().lines);
+		}
+		let score = Paragraph::new(score).alignment(Alignment::Center).block(untitled_ui_block());
+		frame.render_widget(score, chunks[0]);
+		let (arena_area, status_area, chat_area) = if self.chat.is_expanded() {
+			// The arena keeps its borders.
+			let arena_width = self.zoom.grid_size(ARENA_WIDTH, ARENA_HEIGHT).0 + 2;
+			let (arena_area, chat_area) = split_side_pane(chunks[1], arena_width, CHAT_WIDTH);
+			(arena_area, chunks[2], chat_area)
+		} else {
+			let status_chunks = Layout::default()
+				.direction(Direction::Horizontal)
+				.constraints([Constraint::Min(0), Constraint::Length(CHAT_WIDTH)])
+				.split(chunks[2]);
+			(chunks[1], status_chunks[0], status_chunks[1])
+		};
+		render_arena(frame, arena_area, &self.arena, self.zoom);
+		let status = Paragraph::new(self.status_line())
+			.alignment(Alignment::Center)
+			.block(untitled_ui_block());
+		frame.render_widget(status, status_area);
+		self.chat.render_ui(frame, chat_area, &self.chat_state);
+	}
+
+	fn help_page(&self) -> Option<&'static str> {
+		Tron.help_page()
+	}
+}
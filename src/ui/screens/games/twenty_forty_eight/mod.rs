@@ -0,0 +1,4 @@
+//! Screens used for 2048.
+
+pub mod setup;
+pub mod twenty_forty_eight_game;
@@ -0,0 +1,114 @@
+//! Game setup screen for 2048, where the player sizes the grid.
+
+use ratatui::{
+	layout::{
+		Constraint,
+		Direction,
+		Layout,
+	},
+	Frame,
+};
+
+use crate::{
+	core::{
+		actions::Action,
+		events::Event,
+	},
+	games::{
+		modes::GameMode,
+		twenty_forty_eight::{
+			setup::GridSetup,
+			TwentyFortyEight,
+		},
+		Game,
+	},
+	t,
+	ui::{
+		components::games::setup::SetupQuestions,
+		screens::{
+			ScreenKind,
+			ScreenState,
+		},
+		Screen,
+		TwentyFortyEightGameScreen,
+	},
+};
+
+/// A setup screen for a grid of 2048.
+#[derive(Clone)]
+#[must_use]
+pub struct TwentyFortyEightSetupScreen {
+	/// The questions, with the answers given.
+	questions: SetupQuestions<GridSetup>,
+
+	/// The mode the game will be played in.
+	mode: GameMode,
+}
+
+impl Default for TwentyFortyEightSetupScreen {
+	fn default() -> Self {
+		Self::new(GameMode::Standard)
+	}
+}
+
+impl TwentyFortyEightSetupScreen {
+	/// Creates a setup screen for a game in a mode.
+	pub fn new(mode: GameMode) -> Self {
+		Self {
+			questions: SetupQuestions::new(GridSetup::default()),
+			mode,
+		}
+	}
+}
+
+impl Screen for TwentyFortyEightSetupScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(
+			t!("twenty-forty-eight-setup-title"),
+			ScreenKind::Normal,
+			None,
+		)
+		.with_actions(vec![
+			(
+				&[Action::Up, Action::Down],
+				t!("twenty-forty-eight-setup-select"),
+			),
+			(
+				&[Action::Left, Action::Right],
+				t!("twenty-forty-eight-setup-change"),
+			),
+			(&[Action::Confirm], t!("twenty-forty-eight-start")),
+			(&[Action::Help], t!("twenty-forty-eight-help-control")),
+		])
+	}
+
+	fn handle_event(&mut self, _event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		Ok(())
+	}
+
+	fn handle_action(&mut self, action: Action, state: &mut ScreenState) -> anyhow::Result<bool> {
+		if action == Action::Confirm {
+			let size = self.questions.answers().size;
+			state.create_screen(TwentyFortyEightGameScreen::new(size, self.mode).into());
+			return Ok(true);
+		}
+		Ok(self.questions.handle_action(action))
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.vertical_margin(1)
+			.horizontal_margin(2)
+			.constraints([
+				Constraint::Length(self.questions.get_height()),
+				Constraint::Min(0),
+			])
+			.split(frame.size());
+		self.questions.render(frame, chunks[0]);
+	}
+
+	fn help_page(&self) -> Option<&'static str> {
+		TwentyFortyEight.help_page()
+	}
+}
@@ -0,0 +1,401 @@
+//! The screen of a game of 2048, sliding the grid's tiles with the arrow keys
+//! until a tile reaches 2048 or no slide moves any.
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Constraint,
+		Direction,
+		Layout,
+	},
+	widgets::Paragraph,
+	Frame,
+};
+
+#[cfg(feature = "dev-console")]
+use crate::ui::components::dev_console::{
+	self,
+	DevCommand,
+};
+use crate::{
+	core::{
+		actions::Action,
+		events::{
+			Event,
+			KeyCode,
+		},
+	},
+	games::{
+		modes::GameMode,
+		registry::GameId,
+		twenty_forty_eight::{
+			grid::{
+				Grid,
+				Slide,
+			},
+			GAME_ID,
+			HELP_PAGE,
+		},
+		GameDynamicInfo,
+		GameResult,
+	},
+	services::{
+		rng,
+		undo::{
+			Snapshot,
+			UndoStack,
+		},
+	},
+	t,
+	ui::{
+		components::{
+			games::twenty_forty_eight::grid::render_grid,
+			pause_menu::Hint,
+			presets::untitled_ui_block,
+			results_banner::{
+				ResultsBanner,
+				Verdict,
+			},
+		},
+		screens::{
+			ScreenKind,
+			ScreenState,
+		},
+		Screen,
+	},
+};
+
+/// How many slides a game takes before [quickly
+/// restarting](Screen::quick_restart) it takes confirming.
+const SIGNIFICANT_SLIDES: usize = 20;
+
+/// Points a [hint](Screen::hint) takes off the score.
+const HINT_COST: i64 = 100;
+
+/// Usage of the [developer console](dev_console) command adding points to the
+/// score.
+#[cfg(feature = "dev-console")]
+const SCORE_USAGE: &str = "score <points>";
+
+/// Usage of the [developer console](dev_console) command placing a tile.
+#[cfg(feature = "dev-console")]
+const TILE_USAGE: &str = "tile <x> <y> <value>";
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct TwentyFortyEightGameScreen {
+	/// The grid played on.
+	grid: Grid,
+
+	/// The [seed](rng::seed_game) the game started from.
+	seed: u64,
+
+	/// The mode the game is played in.
+	mode: GameMode,
+
+	/// The slides made, to take back, unless the mode doesn't
+	/// [allow it](GameMode::allows_undo).
+	undo: UndoStack<Snapshot<Grid>>,
+
+	/// How many slides moved tiles in this game.
+	slides: usize,
+
+	/// How many [hints](Screen::hint) were given, each taking [`HINT_COST`]
+	/// points off the score.
+	hints: u32,
+
+	/// The player's best score before this game, if they have one.
+	best_score: Option<i64>,
+
+	/// Banner announcing how the last game ended and how it compares to the
+	/// player's previous bests, shown once it's over.
+	results: Option<ResultsBanner>,
+}
+
+impl TwentyFortyEightGameScreen {
+	/// Starts a game in a mode on a grid of a size from a new
+	/// [seed](rng::seed_game), counting a play of the game.
+	pub fn new(size: usize, mode: GameMode) -> Self {
+		Self::with_seed(size, mode, rng::seed_game())
+	}
+
+	/// Starts a game in a mode on a grid of a size from a seed, counting a
+	/// play of the game.
+	fn with_seed(size: usize, mode: GameMode, seed: u64) -> Self {
+		rng::seed(seed);
+		let best_score = GameDynamicInfo::update(&GameId::from(GAME_ID), |info| {
+			info.play();
+			info.high_score_in(mode)
+		})
+		.ok()
+		.flatten();
+		Self {
+			grid: Grid::new(size),
+			seed,
+			mode,
+			undo: UndoStack::default(),
+			slides: 0,
+			hints: 0,
+			best_score,
+			results: None,
+		}
+	}
+
+	/// Returns the score: the points merged, less the hints' cost.
+	fn score(&self) -> i64 {
+		self.grid.score() - HINT_COST * i64::from(self.hints)
+	}
+
+	/// Slides the tiles, recording the game's result and announcing it once
+	/// a tile reaches 2048 or no slide moves any.
+	fn slide(&mut self, slide: Slide) {
+		if self.results.is_some() {
+			return;
+		}
+		let before = self.grid.clone();
+		if !self.grid.slide(slide) {
+			return;
+		}
+		if self.mode.allows_undo() {
+			self.undo.push(Snapshot {
+				before,
+				after: self.grid.clone(),
+			});
+		}
+		self.slides += 1;
+		let won = self.grid.is_won();
+		if !won && !self.grid.is_over() {
+			return;
+		}
+		let score = self.score();
+		let comparison = GameDynamicInfo::update(&GameId::from(GAME_ID), |info| {
+			info.record_result(&GameResult::new(self.mode, Some(score), None))
+		})
+		.unwrap_or_default();
+		let verdict = if won { Verdict::Won } else { Verdict::Over };
+		let mut details = vec![comparison.text()];
+		if self.hints > 0 {
+			details.push(t!(
+				"twenty-forty-eight-hints-used",
+				count = self.hints,
+				points = HINT_COST * i64::from(self.hints)
+			));
+		}
+		self.results = Some(
+			ResultsBanner::new(verdict, Some(score))
+				.with_details(details)
+				.with_confetti(won || comparison.new_best_score),
+		);
+	}
+
+	/// Returns the line below the grid, with the score, the best score and
+	/// the hints given.
+	fn status_line(&self) -> String {
+		let score = self.score();
+		let best_score = self.best_score.map_or(score, |best_score| best_score.max(score));
+		let mut status = t!(
+			"twenty-forty-eight-status",
+			score = score,
+			best = best_score
+		);
+		if self.hints > 0 {
+			status = format!(
+				"{status} | {}",
+				t!("twenty-forty-eight-hints", hints = self.hints)
+			);
+		}
+		status
+	}
+}
+
+impl Screen for TwentyFortyEightGameScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(
+			t!("twenty-forty-eight-title"),
+			ScreenKind::Normal,
+			Some(vec![("Enter", t!("twenty-forty-eight-restart-control"))]),
+		)
+		.with_actions(vec![
+			(
+				&[Action::Up, Action::Down, Action::Left, Action::Right],
+				t!("twenty-forty-eight-slide"),
+			),
+			(&[Action::Undo], t!("twenty-forty-eight-undo-control")),
+			(&[Action::Redo], t!("twenty-forty-eight-redo-control")),
+			(&[Action::Help], t!("twenty-forty-eight-help-control")),
+		])
+		.with_pause_menu()
+		.with_hints()
+		.with_quick_restart()
+	}
+
+	fn handle_event(&mut self, _event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		Ok(())
+	}
+
+	fn handle_action(&mut self, action: Action, _state: &mut ScreenState) -> anyhow::Result<bool> {
+		let slide = match action {
+			Action::Confirm if self.results.is_some() => {
+				*self = Self::new(self.grid.size(), self.mode);
+				return Ok(true);
+			},
+			Action::Undo if self.results.is_none() => {
+				self.undo.undo(&mut self.grid);
+				return Ok(true);
+			},
+			Action::Redo if self.results.is_none() => {
+				self.undo.redo(&mut self.grid);
+				return Ok(true);
+			},
+			Action::Up => Slide::Up,
+			Action::Down => Slide::Down,
+			Action::Left => Slide::Left,
+			Action::Right => Slide::Right,
+			_ => return Ok(false),
+		};
+		self.slide(slide);
+		Ok(true)
+	}
+
+	fn quick_restart(&mut self, _state: &mut ScreenState) -> anyhow::Result<()> {
+		*self = Self::with_seed(self.grid.size(), self.mode, self.seed);
+		Ok(())
+	}
+
+	fn has_significant_progress(&self) -> bool {
+		self.results.is_none() && self.slides >= SIGNIFICANT_SLIDES
+	}
+
+	#[cfg(feature = "dev-console")]
+	fn dev_commands(&self) -> Vec<DevCommand> {
+		vec![
+			(SCORE_USAGE, t!("twenty-forty-eight-command-score")),
+			(TILE_USAGE, t!("twenty-forty-eight-command-tile")),
+		]
+	}
+
+	#[cfg(feature = "dev-console")]
+	fn dev_command(
+		&mut self,
+		name: &str,
+		args: &[&str],
+		_state: &mut ScreenState,
+	) -> anyhow::Result<String> {
+		if name == "score" {
+			self.grid.add_score(dev_console::arg(args, 0, SCORE_USAGE)?);
+			return Ok(t!(
+				"twenty-forty-eight-command-score-done",
+				score = self.grid.score()
+			));
+		}
+		let x: usize = dev_console::arg(args, 0, TILE_USAGE)?;
+		let y: usize = dev_console::arg(args, 1, TILE_USAGE)?;
+		let value: u32 = dev_console::arg(args, 2, TILE_USAGE)?;
+		let size = self.grid.size();
+		if x >= size || y >= size || value == 1 || (value != 0 && !value.is_power_of_two()) {
+			anyhow::bail!(t!("dev-console-usage", usage = TILE_USAGE));
+		}
+		self.grid.set(x, y, value);
+		Ok(String::new())
+	}
+
+	fn hint(&mut self, _state: &mut ScreenState) -> Option<Hint> {
+		if self.results.is_some() {
+			return None;
+		}
+		let hint = match self.grid.best_slide()? {
+			Slide::Up => t!("twenty-forty-eight-hint-up"),
+			Slide::Down => t!("twenty-forty-eight-hint-down"),
+			Slide::Left => t!("twenty-forty-eight-hint-left"),
+			Slide::Right => t!("twenty-forty-eight-hint-right"),
+		};
+		self.hints += 1;
+		Some(Hint::Text(hint.to_string()))
+	}
+
+	fn update(&mut self, _state: &mut ScreenState) {
+		if let Some(results) = &mut self.results {
+			results.tick();
+		}
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.vertical_margin(1)
+			.horizontal_margin(2)
+			.constraints([Constraint::Min(0), Constraint::Length(3)])
+			.split(frame.size());
+		render_grid(frame, chunks[0], &self.grid);
+		let status = Paragraph::new(self.status_line())
+			.alignment(Alignment::Center)
+			.block(untitled_ui_block());
+		frame.render_widget(status, chunks[1]);
+		if let Some(results) = &self.results {
+			results.render(frame, chunks[0]);
+		}
+	}
+
+	fn help_page(&self) -> Option<&'static str> {
+		Some(HELP_PAGE)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::core::files;
+
+	/// Slides a new game until a slide moves tiles, returning the game and
+	/// its grid before that slide.
+	fn slid_game(mode: GameMode) -> (TwentyFortyEightGameScreen, Grid) {
+		let mut game = TwentyFortyEightGameScreen::with_seed(4, mode, 7);
+		let before = game.grid.clone();
+		for slide in [Slide::Up, Slide::Down, Slide::Left, Slide::Right] {
+			game.slide(slide);
+			if game.slides > 0 {
+				break;
+			}
+		}
+		(game, before)
+	}
+
+	#[test]
+	fn undoes_slides() {
+		files::isolate_test_files();
+		let mut state = ScreenState::new("", ScreenKind::Normal, None);
+		let (mut game, before) = slid_game(GameMode::Standard);
+		let after = game.grid.clone();
+
+		game.handle_action(Action::Undo, &mut state).unwrap();
+		assert_eq!(game.grid, before);
+		game.handle_action(Action::Redo, &mut state).unwrap();
+		assert_eq!(game.grid, after);
+	}
+
+	#[test]
+	fn hardcore_games_do_not_undo() {
+		files::isolate_test_files();
+		let mut state = ScreenState::new("", ScreenKind::Normal, None);
+		let (mut game, _) = slid_game(GameMode::Hardcore);
+		let after = game.grid.clone();
+
+		game.handle_action(Action::Undo, &mut state).unwrap();
+		assert_eq!(game.grid, after);
+	}
+
+	#[test]
+	fn hints_name_a_slide_and_cost_points() {
+		files::isolate_test_files();
+		let mut state = ScreenState::new("", ScreenKind::Normal, None);
+		let mut game = TwentyFortyEightGameScreen::with_seed(4, GameMode::Standard, 7);
+		let score = game.score();
+
+		assert!(matches!(game.hint(&mut state), Some(Hint::Text(_))));
+		assert_eq!(game.score(), score - HINT_COST);
+		game.grid = Grid::with_cells(2, vec![2, 4, 4, 2]);
+		assert_eq!(game.hint(&mut state), None);
+		assert_eq!(game.hints, 1);
+	}
+}
@@ -0,0 +1,87 @@
+//! A screen displaying a help page, such as a game's manual.
+//! Help pages are bundled in the `assets/help` directory at compile time.
+
+use ratatui::{
+	layout::{
+		Constraint,
+		Direction,
+		Layout,
+	},
+	Frame,
+};
+
+use crate::{
+	core::{
+		actions::Action,
+		events::Event,
+	},
+	t,
+	ui::{
+		components::presets::untitled_ui_block,
+		screens::{
+			ScreenKind,
+			ScreenState,
+		},
+		widgets::markdown::Markdown,
+		Screen,
+	},
+};
+
+/// Number of lines scrolled at once with \[Page Up\] and \[Page Down\].
+const PAGE_SCROLL_LINES: u16 = 10;
+
+/// A scrollable screen that displays a help page, written in markdown.
+#[derive(Clone)]
+#[must_use]
+pub struct HelpScreen {
+	/// The rendered help page.
+	page: Markdown,
+}
+
+impl Screen for HelpScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(t!("help-title"), ScreenKind::Normal, None)
+			.with_actions(vec![
+				(&[Action::Up, Action::Down], t!("help-scroll")),
+				(&[Action::PageUp, Action::PageDown], t!("help-page-scroll")),
+				(&[Action::First, Action::Last], t!("help-jump")),
+			])
+			.with_static_frames()
+	}
+
+	fn handle_event(&mut self, _event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		Ok(())
+	}
+
+	fn handle_action(&mut self, action: Action, _state: &mut ScreenState) -> anyhow::Result<bool> {
+		match action {
+			Action::Up => self.page.scroll_up(1),
+			Action::Down => self.page.scroll_down(1),
+			Action::PageUp => self.page.scroll_up(PAGE_SCROLL_LINES),
+			Action::PageDown => self.page.scroll_down(PAGE_SCROLL_LINES),
+			Action::First => self.page.scroll_to_top(),
+			Action::Last => self.page.scroll_to_bottom(),
+			_ => return Ok(false),
+		}
+		Ok(true)
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.vertical_margin(1)
+			.horizontal_margin(2)
+			.constraints([Constraint::Min(0)])
+			.split(frame.size());
+		self.page.render(frame, chunks[0], untitled_ui_block());
+	}
+}
+
+impl HelpScreen {
+	/// Creates a new help screen from a markdown help page.
+	pub fn new(page: &str) -> Self {
+		Self {
+			page: Markdown::new(page),
+		}
+	}
+}
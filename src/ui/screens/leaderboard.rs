@@ -0,0 +1,373 @@
+//! The leaderboard screen, opened from the welcome screen: the player's bests
+//! in a game on the "Local" tab, and the rankings of every player of a
+//! [leaderboard server](crate::services::leaderboard) in the game's standard
+//! mode on the "Global" tab.
+
+use std::{
+	collections::HashMap,
+	sync::{
+		Arc,
+		Mutex,
+	},
+	thread,
+};
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Constraint,
+		Direction,
+		Layout,
+		Rect,
+	},
+	style::{
+		Modifier,
+		Style,
+	},
+	widgets::{
+		Paragraph,
+		Row,
+		Tabs,
+		Wrap,
+	},
+	Frame,
+};
+use strum::IntoEnumIterator;
+
+use crate::{
+	core::{
+		actions::Action,
+		events::{
+			Event,
+			KeyCode,
+		},
+	},
+	games::{
+		clock_text,
+		modes::GameMode,
+		registry::GameId,
+		Game,
+		GameDynamicInfo,
+		Games,
+	},
+	services::leaderboard::{
+		self,
+		Ranking,
+	},
+	t,
+	ui::{
+		color_scheme::SUNGLOW,
+		components::presets::{
+			striped_table,
+			untitled_ui_block,
+		},
+		screens::{
+			ScreenKind,
+			ScreenState,
+		},
+		Screen,
+	},
+};
+
+/// A tab of the screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LeaderboardTab {
+	/// The player's own bests.
+	Local,
+
+	/// Every player's rankings, from the leaderboard server.
+	Global,
+}
+
+/// What's known of a game's global rankings.
+#[derive(Debug, Clone)]
+enum GlobalRankings {
+	/// They're being fetched.
+	Loading,
+
+	/// They were fetched.
+	Loaded(Vec<Ranking>),
+
+	/// They couldn't be fetched, for a reason.
+	Failed(String),
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct LeaderboardScreen {
+	/// The games listed, with their name.
+	games: Vec<(GameId, String)>,
+
+	/// Index of the game shown.
+	game: usize,
+
+	/// The player's bests in the game shown.
+	bests: GameDynamicInfo,
+
+	/// The tab shown.
+	tab: LeaderboardTab,
+
+	/// Global rankings of the games, filled in by background fetches.
+	global: Arc<Mutex<HashMap<GameId, GlobalRankings>>>,
+
+	/// Outcome of the last submission of the player's bests, if any.
+	submit_status: Arc<Mutex<Option<String>>>,
+}
+
+impl Default for LeaderboardScreen {
+	fn default() -> Self {
+		let mut screen = Self {
+			games: Games::all()
+				.iter()
+				.map(|game| {
+					let info = game.data().metadata.static_info;
+					(info.id, info.name)
+				})
+				.collect(),
+			game: 0,
+			bests: GameDynamicInfo::default(),
+			tab: LeaderboardTab::Local,
+			global: Arc::default(),
+			submit_status: Arc::default(),
+		};
+		screen.load_bests();
+		screen
+	}
+}
+
+impl LeaderboardScreen {
+	/// Returns the ID of the game shown, if there's any game.
+	fn game_id(&self) -> Option<&GameId> {
+		self.games.get(self.game).map(|(id, _)| id)
+	}
+
+	/// Loads the player's bests in the game shown.
+	fn load_bests(&mut self) {
+		self.bests = self
+			.game_id()
+			.and_then(|game| GameDynamicInfo::load_or_default(game).ok())
+			.unwrap_or_default();
+	}
+
+	/// Fetches the global rankings of the game shown in the background, unless
+	/// they were already.
+	fn fetch_rankings(&self) {
+		let Some(game) = self.game_id().cloned() else {
+			return;
+		};
+		let Ok(mut global) = self.global.lock() else {
+			return;
+		};
+		if global.contains_key(&game) || leaderboard::server_url().is_none() {
+			return;
+		}
+		global.insert(game.clone(), GlobalRankings::Loading);
+		let global = Arc::clone(&self.global);
+		thread::spawn(move || {
+			let rankings = match leaderboard::fetch_rankings(&game, GameMode::Standard) {
+				Ok(rankings) => GlobalRankings::Loaded(rankings),
+				Err(error) => GlobalRankings::Failed(error.to_string()),
+			};
+			if let Ok(mut global) = global.lock() {
+				global.insert(game, rankings);
+			}
+		});
+	}
+
+	/// Submits the player's bests to the leaderboard server in the background,
+	/// fetching the rankings again once they're submitted.
+	fn submit_scores(&self) {
+		if let Ok(mut status) = self.submit_status.lock() {
+			*status = Some(t!("leaderboard-submitting").to_string());
+		}
+		let status = Arc::clone(&self.submit_status);
+		let global = Arc::clone(&self.global);
+		thread::spawn(move || {
+			let outcome = match leaderboard::submit_scores() {
+				Ok(games) => {
+					if let Ok(mut global) = global.lock() {
+						global.clear();
+					}
+					t!("leaderboard-submitted", games = games)
+				},
+				Err(error) => t!("leaderboard-submit-failed", error = error.to_string()),
+			};
+			if let Ok(mut status) = status.lock() {
+				*status = Some(outcome);
+			}
+		});
+	}
+
+	/// Renders the player's bests in the game shown.
+	fn render_local(&self, frame: &mut Frame<'_>, area: Rect) {
+		let info = &self.bests;
+		let mut rows: Vec<_> = GameMode::iter()
+			.filter_map(|mode| {
+				let score = info.high_score_in(mode)?;
+				Some(Row::new([mode.label().to_string(), score.to_string()]))
+			})
+			.collect();
+		if let Some(secs) = info.best_time_secs {
+			rows.push(Row::new([
+				t!("leaderboard-best-time").to_string(),
+				clock_text(secs),
+			]));
+		}
+		if rows.is_empty() {
+			render_message(frame, area, t!("leaderboard-no-scores"));
+			return;
+		}
+		let table = striped_table(rows, [Constraint::Fill(1), Constraint::Length(12)])
+			.block(untitled_ui_block());
+		frame.render_widget(table, area);
+	}
+
+	/// Renders every player's rankings in the game shown.
+	fn render_global(&self, frame: &mut Frame<'_>, area: Rect, game: &GameId) {
+		if leaderboard::server_url().is_none() {
+			render_message(frame, area, t!("leaderboard-no-server"));
+			return;
+		}
+		let rankings = self.global.lock().ok().and_then(|global| global.get(game).cloned());
+		let rankings = match rankings {
+			None | Some(GlobalRankings::Loading) => {
+				render_message(frame, area, t!("leaderboard-loading"));
+				return;
+			},
+			Some(GlobalRankings::Failed(error)) => {
+				render_message(frame, area, &t!("leaderboard-failed", error = error));
+				return;
+			},
+			Some(GlobalRankings::Loaded(rankings)) if rankings.is_empty() => {
+				render_message(frame, area, t!("leaderboard-no-rankings"));
+				return;
+			},
+			Some(GlobalRankings::Loaded(rankings)) => rankings,
+		};
+		let rows = rankings.into_iter().enumerate().map(|(index, ranking)| {
+			Row::new([
+				(index + 1).to_string(),
+				ranking.player,
+				ranking.score.map(|score| score.to_string()).unwrap_or_default(),
+				ranking.time_secs.map(clock_text).unwrap_or_default(),
+			])
+		});
+		let header = Row::new([
+			t!("leaderboard-rank"),
+			t!("leaderboard-player"),
+			t!("leaderboard-score"),
+			t!("leaderboard-time"),
+		])
+		.style(Style::new().add_modifier(Modifier::BOLD));
+		let table = striped_table(rows, [
+			Constraint::Length(4),
+			Constraint::Fill(1),
+			Constraint::Length(12),
+			Constraint::Length(8),
+		])
+		.header(header)
+		.block(untitled_ui_block());
+		frame.render_widget(table, area);
+	}
+}
+
+/// Renders a message in a bordered area, in place of a table.
+fn render_message(frame: &mut Frame<'_>, area: Rect, message: &str) {
+	let paragraph = Paragraph::new(message)
+		.alignment(Alignment::Center)
+		.wrap(Wrap { trim: true })
+		.block(untitled_ui_block());
+	frame.render_widget(paragraph, area);
+}
+
+impl Screen for LeaderboardScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(
+			t!("leaderboard-title"),
+			ScreenKind::Normal,
+			Some(vec![
+				("Tab", t!("leaderboard-tab-control")),
+				("S", t!("leaderboard-submit-control")),
+				("R", t!("leaderboard-refresh-control")),
+			]),
+		)
+		.with_actions(vec![(
+			&[Action::Left, Action::Right],
+			t!("leaderboard-game-control"),
+		)])
+	}
+
+	fn handle_event(&mut self, event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		let Event::Key(key) = event else {
+			return Ok(());
+		};
+		match key.code {
+			KeyCode::Tab | KeyCode::BackTab => {
+				self.tab = match self.tab {
+					LeaderboardTab::Local => LeaderboardTab::Global,
+					LeaderboardTab::Global => LeaderboardTab::Local,
+				};
+			},
+			KeyCode::Char('s' | 'S') => self.submit_scores(),
+			KeyCode::Char('r' | 'R') => {
+				if let (Some(game), Ok(mut global)) = (self.game_id(), self.global.lock()) {
+					global.remove(game);
+				}
+			},
+			_ => {},
+		}
+		Ok(())
+	}
+
+	fn handle_action(&mut self, action: Action, _state: &mut ScreenState) -> anyhow::Result<bool> {
+		let count = self.games.len().max(1);
+		match action {
+			Action::Left => self.game = (self.game + count - 1) % count,
+			Action::Right => self.game = (self.game + 1) % count,
+			_ => return Ok(false),
+		}
+		self.load_bests();
+		Ok(true)
+	}
+
+	fn update(&mut self, _state: &mut ScreenState) {
+		if self.tab == LeaderboardTab::Global {
+			self.fetch_rankings();
+		}
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.margin(1)
+			.constraints([
+				Constraint::Length(1),
+				Constraint::Length(1),
+				Constraint::Min(0),
+				Constraint::Length(1),
+			])
+			.split(frame.size());
+		let tabs = Tabs::new(vec![t!("leaderboard-local"), t!("leaderboard-global")])
+			.select(self.tab as usize)
+			.highlight_style(Style::new().fg(SUNGLOW).add_modifier(Modifier::BOLD));
+		frame.render_widget(tabs, chunks[0]);
+		let Some((game, name)) = self.games.get(self.game) else {
+			render_message(frame, chunks[2], t!("leaderboard-no-games"));
+			return;
+		};
+		frame.render_widget(
+			Paragraph::new(format!("◀ {name} ▶")).alignment(Alignment::Center),
+			chunks[1],
+		);
+		match self.tab {
+			LeaderboardTab::Local => self.render_local(frame, chunks[2]),
+			LeaderboardTab::Global => self.render_global(frame, chunks[2], game),
+		}
+		let status = self.submit_status.lock().ok().and_then(|status| status.clone());
+		frame.render_widget(
+			Paragraph::new(status.unwrap_or_default()).alignment(Alignment::Center),
+			chunks[3],
+		);
+	}
+}
@@ -0,0 +1,231 @@
+//! The screen listing the [input macros](crate::core::macros), opened from the
+//! settings, where they can be renamed and removed.
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Constraint,
+		Direction,
+		Layout,
+	},
+	widgets::{
+		Paragraph,
+		Wrap,
+	},
+	Frame,
+};
+
+use crate::{
+	core::{
+		actions::Action,
+		events::{
+			Event,
+			KeyCode,
+			KeyModifiers,
+		},
+		macros::{
+			hotkey_label,
+			Macros,
+		},
+	},
+	t,
+	ui::{
+		components::presets::untitled_ui_block,
+		screens::{
+			ScreenKind,
+			ScreenState,
+		},
+		widgets::data_table::{
+			Column,
+			DataRow,
+			DataTable,
+		},
+		Screen,
+	},
+};
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct MacrosScreen {
+	/// The macros, in the order of their hotkeys.
+	macros: Macros,
+
+	/// Table of the macros, if there are any, holding their index.
+	macros_table: Option<DataTable<usize>>,
+
+	/// New name of the selected macro, while it's being renamed.
+	new_name: Option<String>,
+
+	/// What happened after the last action, shown below the list.
+	status: String,
+}
+
+impl Default for MacrosScreen {
+	fn default() -> Self {
+		let mut screen = Self {
+			macros: Macros::load_or_default(),
+			macros_table: None,
+			new_name: None,
+			status: t!("macros-hint").to_string(),
+		};
+		screen.refresh();
+		screen
+	}
+}
+
+impl MacrosScreen {
+	/// Lists the macros again, keeping the sort and the selection.
+	fn refresh(&mut self) {
+		if self.macros.macros.is_empty() {
+			self.macros_table = None;
+			return;
+		}
+		let rows = self
+			.macros
+			.macros
+			.iter()
+			.enumerate()
+			.map(|(index, input_macro)| {
+				DataRow::new(
+					vec![
+						hotkey_label(index),
+						input_macro.name.clone(),
+						input_macro.inputs.len().to_string(),
+					],
+					index,
+				)
+			})
+			.collect();
+		if let Some(table) = &mut self.macros_table {
+			table.update_rows(rows);
+		} else {
+			let columns = vec![
+				Column::new(t!("macros-hotkey").to_string(), 6),
+				Column::new(t!("macros-name").to_string(), 8),
+				Column::new(t!("macros-inputs").to_string(), 6),
+			];
+			self.macros_table = Some(DataTable::new(columns, rows));
+		}
+	}
+
+	/// Returns the index of the selected macro, if any.
+	fn selected(&self) -> Option<usize> {
+		self.macros_table.as_ref()?.get_selected().map(|row| row.data)
+	}
+
+	/// Saves the macros, then lists them again.
+	fn save(&mut self, done: String) {
+		self.status = match self.macros.save() {
+			Ok(()) => done,
+			Err(error) => t!("macros-save-failed", error = error.to_string()),
+		};
+		self.refresh();
+	}
+
+	/// Removes the selected macro. The macros after it move up a hotkey.
+	fn remove_selected(&mut self) {
+		let Some(index) = self.selected() else {
+			return;
+		};
+		let removed = self.macros.macros.remove(index);
+		self.save(t!("macros-removed", name = removed.name));
+	}
+
+	/// Renames the selected macro to the name typed, unless it's blank.
+	fn rename_selected(&mut self) {
+		let new_name = self.new_name.take().unwrap_or_default();
+		let new_name = new_name.trim();
+		let Some(index) = self.selected().filter(|_| !new_name.is_empty()) else {
+			self.status = t!("macros-hint").to_string();
+			return;
+		};
+		self.macros.macros[index].name = new_name.to_string();
+		self.save(t!("macros-renamed", name = new_name.to_string()));
+	}
+}
+
+impl Screen for MacrosScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(
+			t!("macros-title"),
+			ScreenKind::Normal,
+			Some(vec![
+				("S", t!("macros-sort-control")),
+				("R", t!("macros-rename-control")),
+				("Delete", t!("macros-remove-control")),
+				("F9", t!("macros-record-control")),
+			]),
+		)
+		.with_actions(vec![(&[Action::Up, Action::Down], t!("macros-select"))])
+		.with_static_frames()
+	}
+
+	fn handle_event(&mut self, event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		if self.macros_table.as_mut().is_some_and(|table| table.handle_event(event)) {
+			return Ok(());
+		}
+		let Event::Key(key) = event else {
+			return Ok(());
+		};
+		if let Some(new_name) = &mut self.new_name {
+			match key.code {
+				KeyCode::Backspace => {
+					new_name.pop();
+				},
+				KeyCode::Char(char)
+					if [KeyModifiers::NONE, KeyModifiers::SHIFT].contains(&key.modifiers) =>
+				{
+					new_name.push(char);
+				},
+				_ => {},
+			}
+			self.status = t!("macros-renaming", name = new_name.clone());
+			return Ok(());
+		}
+		match key.code {
+			KeyCode::Char('r' | 'R') if self.selected().is_some() => {
+				self.new_name = Some(String::new());
+				self.status = t!("macros-renaming", name = String::new());
+			},
+			KeyCode::Char('s' | 'S') => {
+				self.macros_table.iter_mut().for_each(DataTable::cycle_sort);
+			},
+			KeyCode::Delete => self.remove_selected(),
+			_ => {},
+		}
+		Ok(())
+	}
+
+	fn handle_action(&mut self, action: Action, _state: &mut ScreenState) -> anyhow::Result<bool> {
+		// Keys are typed into the new name while renaming.
+		if self.new_name.is_some() && action != Action::Confirm {
+			return Ok(false);
+		}
+		match action {
+			Action::Up => self.macros_table.iter_mut().for_each(DataTable::select_previous),
+			Action::Down => self.macros_table.iter_mut().for_each(DataTable::select_next),
+			Action::Confirm if self.new_name.is_some() => self.rename_selected(),
+			_ => return Ok(false),
+		}
+		Ok(true)
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.margin(1)
+			.constraints([Constraint::Min(0), Constraint::Length(4)])
+			.split(frame.size());
+		if let Some(table) = &self.macros_table {
+			table.render(frame, chunks[0], untitled_ui_block());
+		} else {
+			let empty = Paragraph::new(t!("macros-none")).alignment(Alignment::Center);
+			frame.render_widget(empty, chunks[0]);
+		}
+		let status = Paragraph::new(self.status.clone())
+			.block(untitled_ui_block())
+			.wrap(Wrap { trim: true });
+		frame.render_widget(status, chunks[1]);
+	}
+}
@@ -0,0 +1,145 @@
+//! The screen offering to [move save data](Migration) from the legacy save
+//! directory, shown on startup when there's data to move.
+
+use ratatui::{
+	layout::{
+		Constraint,
+		Direction,
+		Layout,
+	},
+	text::{
+		Line,
+		Text,
+	},
+	widgets::{
+		Paragraph,
+		Wrap,
+	},
+	Frame,
+};
+
+use crate::{
+	core::{
+		events::{
+			Event,
+			KeyCode,
+		},
+		migration::Migration,
+	},
+	t,
+	ui::{
+		components::presets::{
+			titled_ui_block,
+			untitled_ui_block,
+		},
+		screens::{
+			OpenStatus,
+			ScreenKind,
+			ScreenState,
+		},
+		Screen,
+	},
+};
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct MigrationScreen {
+	/// The migration offered.
+	migration: Migration,
+
+	/// Files to move, relative to the old directory, or why they couldn't be
+	/// listed.
+	files: Result<Vec<String>, String>,
+
+	/// How many files were moved, or why they couldn't be, once the migration
+	/// was run.
+	outcome: Option<Result<usize, String>>,
+}
+
+impl MigrationScreen {
+	/// Creates the screen offering a migration.
+	pub fn new(migration: Migration) -> Self {
+		let files = migration
+			.files()
+			.map(|files| files.iter().map(|file| file.display().to_string()).collect())
+			.map_err(|error| error.to_string());
+		Self {
+			migration,
+			files,
+			outcome: None,
+		}
+	}
+
+	/// Returns the text explaining the migration, or its outcome.
+	fn message(&self) -> Text<'static> {
+		let mut lines = vec![
+			Line::from(t!(
+				"migration-intro",
+				from = self.migration.from.display().to_string()
+			)),
+			Line::default(),
+			Line::from(t!(
+				"migration-data-dir",
+				path = self.migration.data_dir.display().to_string()
+			)),
+			Line::from(t!(
+				"migration-config-dir",
+				path = self.migration.config_dir.display().to_string()
+			)),
+			Line::default(),
+		];
+		lines.push(Line::from(match &self.outcome {
+			None => t!("migration-prompt").to_string(),
+			Some(Ok(count)) => t!("migration-done", count = *count),
+			Some(Err(error)) => t!("migration-failed", error = error.clone()),
+		}));
+		Text::from(lines)
+	}
+
+	/// Returns the text listing the files to move.
+	fn file_list(&self) -> Text<'static> {
+		match &self.files {
+			Ok(files) => files.iter().map(|file| Line::from(format!("📄 {file}"))).collect(),
+			Err(error) => Text::from(t!("migration-list-failed", error = error.clone())),
+		}
+	}
+}
+
+impl Screen for MigrationScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(
+			t!("migration-title"),
+			ScreenKind::Normal,
+			Some(vec![("Enter", t!("migration-control"))]),
+		)
+	}
+
+	fn handle_event(&mut self, event: &Event, state: &mut ScreenState) -> anyhow::Result<()> {
+		if let Event::Key(key) = event {
+			if key.code == KeyCode::Enter {
+				if self.outcome.is_some() {
+					state.open_status = OpenStatus::Closed;
+				} else {
+					self.outcome = Some(self.migration.run().map_err(|error| error.to_string()));
+				}
+			}
+		}
+		Ok(())
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.margin(1)
+			.constraints([Constraint::Length(9), Constraint::Min(0)])
+			.split(frame.size());
+		let message =
+			Paragraph::new(self.message()).block(untitled_ui_block()).wrap(Wrap { trim: true });
+		frame.render_widget(message, chunks[0]);
+		let files = Paragraph::new(self.file_list())
+			.block(titled_ui_block(t!("migration-files")))
+			.wrap(Wrap { trim: true });
+		frame.render_widget(files, chunks[1]);
+	}
+}
@@ -1,52 +1,142 @@
 //! Module for screens used in Terminal Arcade. See [Screen] to get started.
 
+pub mod about;
+pub mod backups;
 pub mod config;
 pub mod controls_popup;
+pub mod diagnostics;
+pub mod digest;
+pub mod error_popup;
+pub mod event_replay;
+pub mod game_data;
 pub mod game_select;
 pub mod games;
+pub mod help;
+pub mod leaderboard;
+pub mod macros;
+pub mod migration;
+pub mod mode_select;
+pub mod multiplayer;
+#[cfg(feature = "encryption")]
+pub mod passphrase;
+pub mod profile;
+pub mod qr_code;
+pub mod reset;
+pub mod roulette;
+#[cfg(feature = "images")]
+pub mod splash;
+pub mod sync_conflict;
 pub mod welcome;
 
+use std::time::Duration;
+
+pub use about::AboutScreen;
+pub use backups::BackupsScreen;
 pub use config::ConfigScreen;
 pub use controls_popup::ControlsPopup;
-use crossterm::event::{
-	Event,
-	KeyCode,
-	KeyEvent,
-	KeyModifiers,
-};
+pub use diagnostics::DiagnosticsScreen;
+pub use digest::DigestScreen;
 use enum_dispatch::enum_dispatch;
+pub use error_popup::ErrorPopup;
+pub use event_replay::EventReplayScreen;
+pub use game_data::GameDataScreen;
 pub use game_select::GameSearchScreen;
 pub use games::*;
+pub use help::HelpScreen;
+pub use leaderboard::LeaderboardScreen;
+pub use macros::MacrosScreen;
+pub use migration::MigrationScreen;
+pub use mode_select::ModeSelectPopup;
+pub use multiplayer::PassKeyboardScreen;
+#[cfg(feature = "networking")]
+pub use multiplayer::{
+	HostLobbyScreen,
+	JoinLobbyScreen,
+	LocalGamesScreen,
+};
+#[cfg(feature = "encryption")]
+pub use passphrase::PassphraseScreen;
+pub use profile::ProfileScreen;
+pub use qr_code::QrCodePopup;
 use ratatui::{
 	buffer::Buffer,
 	layout::{
+		Alignment,
 		Constraint,
+		Margin,
 		Rect,
 	},
 	style::{
 		Modifier,
 		Style,
 	},
-	text::Text,
+	text::{
+		Line,
+		Text,
+	},
 	widgets::{
 		Cell,
 		Clear,
 		HighlightSpacing,
+		Paragraph,
 		Row,
 		Table,
 		Widget,
+		Wrap,
 	},
 	Frame,
 };
+pub use reset::ResetScreen;
+pub use roulette::RoulettePopup;
+#[cfg(feature = "images")]
+pub use splash::SplashScreen;
+pub use sync_conflict::SyncConflictPopup;
 pub use welcome::WelcomeScreen;
 
-use crate::ui::components::{
-	presets::{
-		highlight_block,
-		titled_ui_block,
-		HIGHLIGHTED,
+#[cfg(feature = "dev-console")]
+use crate::ui::components::dev_console::{
+	self,
+	DevCommand,
+	DevConsole,
+};
+use crate::{
+	core::{
+		actions::{
+			self,
+			Action,
+			ActionContext,
+		},
+		events::{
+			Event,
+			KeyCode,
+			KeyEvent,
+			KeyModifiers,
+		},
+		render_stats,
+	},
+	services::{
+		autosave,
+		stats,
+	},
+	t,
+	ui::{
+		components::{
+			confirm_dialog::ConfirmDialog,
+			pause_menu::{
+				Hint,
+				PauseMenu,
+				PauseMenuItem,
+			},
+			presets::{
+				highlight_block,
+				titled_ui_block,
+				HIGHLIGHTED,
+			},
+			screen_base_block::screen_base_block,
+			start_countdown::StartCountdown,
+		},
+		text,
 	},
-	screen_base_block::screen_base_block,
 };
 
 /// A controls entry. The first element of the tuple is the key shortcut, while
@@ -54,6 +144,17 @@ use crate::ui::components::{
 /// screen).
 pub type ControlsEntry = (&'static str, &'static str);
 
+/// An action entry. The first element of the tuple is the
+/// [navigation](ActionContext::Navigation) actions the screen handles, like
+/// moving up and down, while the second element is what they do on the screen.
+/// They're listed in the controls popup with the keys bound to them.
+pub type ActionEntry = (&'static [Action], &'static str);
+
+/// How long a game is played for before [quickly
+/// restarting](Screen::quick_restart) it takes confirming, for games that
+/// measure their progress by time played.
+pub const SIGNIFICANT_PLAY_TIME: Duration = Duration::from_secs(30);
+
 /// Open status of the screen.
 #[derive(Clone, Copy, PartialEq, Eq)]
 #[must_use]
@@ -81,10 +182,27 @@ pub enum ScreenKind {
 	Popup,
 }
 
+/// Something a screen asks of the [screen
+/// handler](crate::core::handler::ScreenHandler), queued in its
+/// [state](ScreenState::screen_events) and handled after the screen handled an
+/// event or updated.
+#[derive(Clone)]
+#[must_use]
+pub enum ScreenEvent {
+	/// Spawns a screen over this one. If this screen is closed at the same
+	/// time, the new screen takes its place, like a game's setup making way
+	/// for its board.
+	Create(Box<Screens>),
+
+	/// Copies text to the terminal's [clipboard](crate::core::clipboard).
+	Copy(String),
+}
+
 /// State of a screen. Preferably, this struct is handled and mutated by an
 /// implementor of [Screen] itself, and not an overlying structure.
 #[derive(Clone)]
 #[must_use]
+#[allow(clippy::struct_excessive_bools)]
 pub struct ScreenState {
 	/// Title of the screen, displayed on top by a surrounding block.
 	pub title: &'static str,
@@ -99,8 +217,62 @@ pub struct ScreenState {
 	/// popup.
 	pub controls_entries: Option<Vec<ControlsEntry>>,
 
-	/// Screen to be created and to be spawned.
-	pub screen_created: Option<Screens>,
+	/// Actions handled by this page, to be displayed in the controls popup
+	/// before [its other controls](Self::controls_entries).
+	pub actions: Vec<ActionEntry>,
+
+	/// Events to be handled by the screen handler, oldest first.
+	pub screen_events: Vec<ScreenEvent>,
+
+	/// Smallest terminal size the screen can be drawn in, in columns and rows,
+	/// if it needs one. A smaller terminal shows a notice instead of the
+	/// screen.
+	pub min_size: Option<(u16, u16)>,
+
+	/// Terminal size the screen is laid out best in, in columns and rows, if
+	/// it has one, like room for side panes. A smaller terminal still draws
+	/// the screen, with a hint telling how big it's best at.
+	pub preferred_size: Option<(u16, u16)>,
+
+	/// How long the screen should take to draw at most. Slower frames are
+	/// counted in the [render stats](crate::core::render_stats).
+	pub frame_budget: Duration,
+
+	/// Countdown drawn over the screen before its game starts, if one was
+	/// [started](Self::start_countdown) and is still shown.
+	pub countdown: Option<StartCountdown>,
+
+	/// Whether [`Action::Pause`] opens a [pause menu](PauseMenu) over the
+	/// screen's game.
+	pub pausable: bool,
+
+	/// Whether the pause menu offers [hints](Screen::hint).
+	pub offers_hints: bool,
+
+	/// Whether [`Action::Restart`] [quickly restarts](Screen::quick_restart)
+	/// the screen's game.
+	pub restartable: bool,
+
+	/// Whether the [confirmation dialog](Self::confirm_dialog) open asks
+	/// whether to [quickly restart](Screen::quick_restart) the game.
+	pub restart_pending: bool,
+
+	/// The pause menu, while it's open. The screen stays
+	/// [paused](Screen::pause) until it closes.
+	pub pause_menu: Option<PauseMenu>,
+
+	/// The [confirmation dialog](ConfirmDialog), while it's open.
+	pub confirm_dialog: Option<ConfirmDialog>,
+
+	/// The [developer console](DevConsole), while it's open. The screen
+	/// stays [paused](Screen::pause) until it closes.
+	#[cfg(feature = "dev-console")]
+	pub dev_console: Option<DevConsole>,
+
+	/// Whether the screen only changes after something happened, like input,
+	/// rather than being animated. Static screens are only [drawn
+	/// again](crate::core::render_stats::FramePacer) when needed.
+	pub static_frames: bool,
 }
 
 impl ScreenState {
@@ -116,13 +288,166 @@ impl ScreenState {
 			kind,
 			open_status: OpenStatus::Open,
 			controls_entries,
-			screen_created: None,
+			actions: Vec::new(),
+			screen_events: Vec::new(),
+			min_size: None,
+			preferred_size: None,
+			frame_budget: render_stats::FRAME_BUDGET,
+			countdown: None,
+			pausable: false,
+			offers_hints: false,
+			restartable: false,
+			restart_pending: false,
+			pause_menu: None,
+			confirm_dialog: None,
+			#[cfg(feature = "dev-console")]
+			dev_console: None,
+			static_frames: false,
 		}
 	}
 
-	/// Sets the [`Self::screen_created`] property, given a screen.
-	pub fn set_screen_created(&mut self, screen: Screens) {
-		self.screen_created = Some(screen);
+	/// Sets the [smallest terminal size](Self::min_size) the screen can be
+	/// drawn in.
+	pub fn with_min_size(mut self, width: u16, height: u16) -> Self {
+		self.min_size = Some((width, height));
+		self
+	}
+
+	/// Sets the [terminal size](Self::preferred_size) the screen is laid out
+	/// best in.
+	pub fn with_preferred_size(mut self, width: u16, height: u16) -> Self {
+		self.preferred_size = Some((width, height));
+		self
+	}
+
+	/// Sets the [frame budget](Self::frame_budget) of the screen.
+	pub fn with_frame_budget(mut self, frame_budget: Duration) -> Self {
+		self.frame_budget = frame_budget;
+		self
+	}
+
+	/// Sets the [actions](Self::actions) the screen handles.
+	pub fn with_actions(mut self, actions: Vec<ActionEntry>) -> Self {
+		self.actions = actions;
+		self
+	}
+
+	/// Returns the controls of this page for the controls popup: its
+	/// [actions](Self::actions) with the keys bound to them, leaving out the
+	/// unbound ones, then [its other controls](Self::controls_entries).
+	#[must_use]
+	pub fn controls_entries_with_actions(&self) -> Option<Vec<ControlsEntry>> {
+		let mut entries = self
+			.actions
+			.iter()
+			.filter_map(|&(actions, function)| {
+				Some((
+					actions::keys_label(actions, ActionContext::Navigation)?,
+					function,
+				))
+			})
+			.collect::<Vec<_>>();
+		entries.extend(self.controls_entries.iter().flatten().copied());
+		(!entries.is_empty()).then_some(entries)
+	}
+
+	/// Starts a [countdown](StartCountdown) before the screen's game starts,
+	/// or starts it over. The screen is [paused](Screen::pause) until it's
+	/// over.
+	pub fn start_countdown(&mut self) {
+		self.countdown = Some(StartCountdown::start());
+	}
+
+	/// Starts a [countdown](Self::start_countdown) as soon as the screen
+	/// opens.
+	pub fn with_start_countdown(mut self) -> Self {
+		self.start_countdown();
+		self
+	}
+
+	/// Returns whether the screen's game is waiting for its
+	/// [countdown](Self::start_countdown) to get to "Go!".
+	#[must_use]
+	pub fn is_counting_down(&self) -> bool {
+		self.countdown.as_ref().is_some_and(StartCountdown::is_counting)
+	}
+
+	/// Marks the screen as [static](Self::static_frames), so that it's only
+	/// drawn again when needed.
+	pub fn with_static_frames(mut self) -> Self {
+		self.static_frames = true;
+		self
+	}
+
+	/// Lets [`Action::Pause`] open a [pause menu](PauseMenu) over the screen's
+	/// game, listing the action in the controls popup.
+	pub fn with_pause_menu(mut self) -> Self {
+		if !self.pausable {
+			self.pausable = true;
+			self.actions.push((&[Action::Pause], t!("pause-menu-control")));
+		}
+		self
+	}
+
+	/// Opens a [pause menu](Self::with_pause_menu) offering
+	/// [hints](Screen::hint) on [`Action::Pause`].
+	pub fn with_hints(mut self) -> Self {
+		self.offers_hints = true;
+		self.with_pause_menu()
+	}
+
+	/// Lets [`Action::Restart`] [quickly restart](Screen::quick_restart) the
+	/// screen's game, listing the action in the controls popup.
+	pub fn with_quick_restart(mut self) -> Self {
+		if !self.restartable {
+			self.restartable = true;
+			self.actions.push((&[Action::Restart], t!("quick-restart-control")));
+		}
+		self
+	}
+
+	/// Opens a [confirmation dialog](ConfirmDialog) over the screen, whose
+	/// answer is given to [`Screen::answer_confirmation`].
+	pub fn ask_confirmation(&mut self, message: impl Into<String>) {
+		self.confirm_dialog = Some(ConfirmDialog::new(message));
+	}
+
+	/// Returns whether an area is too small for the screen to be drawn in.
+	#[must_use]
+	pub fn is_too_small(&self, area: Rect) -> bool {
+		self.min_size.is_some_and(|(width, height)| area.width < width || area.height < height)
+	}
+
+	/// Returns whether an area is smaller than the screen's [preferred
+	/// size](Self::preferred_size), though big enough to draw it in.
+	#[must_use]
+	pub fn is_cramped(&self, area: Rect) -> bool {
+		!self.is_too_small(area)
+			&& self
+				.preferred_size
+				.is_some_and(|(width, height)| area.width < width || area.height < height)
+	}
+
+	/// Queues a [`ScreenEvent`] for the screen handler.
+	pub fn send(&mut self, event: ScreenEvent) {
+		self.screen_events.push(event);
+	}
+
+	/// Asks for a screen to be spawned over this one. See
+	/// [`ScreenEvent::Create`].
+	pub fn create_screen(&mut self, screen: Screens) {
+		self.send(ScreenEvent::Create(Box::new(screen)));
+	}
+
+	/// Asks for text to be copied to the clipboard. See [`ScreenEvent::Copy`].
+	pub fn copy(&mut self, text: impl Into<String>) {
+		self.send(ScreenEvent::Copy(text.into()));
+	}
+
+	/// Asks for screens to be spawned over this one, in order, if any.
+	pub fn create_screens(&mut self, screens: impl IntoIterator<Item = Screens>) {
+		self.screen_events
+			.extend(screens.into_iter().map(|screen| ScreenEvent::Create(Box::new(screen))));
 	}
 }
 
@@ -144,32 +469,170 @@ pub trait Screen {
 	/// default shortcuts for every screen as well.
 	fn handle_event(&mut self, event: &Event, state: &mut ScreenState) -> anyhow::Result<()>;
 
+	/// Handles an [action](Action) resolved from an input event against the
+	/// [navigation](ActionContext::Navigation) keybindings, returning whether
+	/// it was handled. Events whose action isn't handled are passed to
+	/// [`Self::handle_event`] instead, so that screens can move to actions one
+	/// key at a time.
+	fn handle_action(&mut self, _action: Action, _state: &mut ScreenState) -> anyhow::Result<bool> {
+		Ok(false)
+	}
+
 	/// Called when an input event is received.
-	/// In addition to the events that [`Self::event_screen`] handles, this
-	/// method also handles two extra events:
-	/// - On \[Esc\], closes this screen.
-	/// - On \[Ctrl\]+\[H\], displays the controls popup only when the screen is
-	///   of [`ScreenKind::Normal`] kind.
+	/// In addition to the events that [`Self::handle_action`] and
+	/// [`Self::handle_event`] handle, this method also handles a few actions:
+	/// - On [`Action::Back`] (\[Esc\]), closes this screen.
+	/// - On [`Action::Controls`] (\[Ctrl\]+\[H\]), displays the controls popup
+	///   only when the screen is of [`ScreenKind::Normal`] kind.
+	/// - On [`Action::Help`] (\[F1\]), opens the screen's [help
+	///   page](Self::help_page), if it has one.
+	/// - On [`Action::Pause`] (\[F2\]), opens the screen's [pause
+	///   menu](ScreenState::with_pause_menu), if it has one. Events go to the
+	///   menu while it's open.
+	/// - On [`Action::Restart`] (\[F5\]), [quickly
+	///   restarts](Self::quick_restart) the screen's game, if it
+	///   [can](ScreenState::with_quick_restart), once confirmed if the game
+	///   [made significant progress](Self::has_significant_progress).
+	///
+	/// While a [confirmation dialog](ScreenState::ask_confirmation) is open,
+	/// events only answer it.
 	fn event(&mut self, event: &Event, state: &mut ScreenState) -> anyhow::Result<()> {
-		if let Event::Key(ref key) = event {
-			match key.code {
-				KeyCode::Char('h')
-					if key.modifiers == KeyModifiers::CONTROL
-						&& state.kind == ScreenKind::Normal =>
-				{
-					state.set_screen_created(
-						ControlsPopup::new(state.controls_entries.clone()).into(),
-					);
+		let action = actions::resolve(event, ActionContext::Navigation);
+		if state.confirm_dialog.is_some() {
+			let answer = match (action, event) {
+				(Some(Action::Confirm), _) => Some(true),
+				(Some(Action::Back), _) => Some(false),
+				(_, Event::Key(key)) => match key.code {
+					KeyCode::Char('y' | 'Y') => Some(true),
+					KeyCode::Char('n' | 'N') => Some(false),
+					_ => None,
 				},
-				KeyCode::Esc => {
-					state.open_status = OpenStatus::Closed;
-				},
-				_ => {},
+				_ => None,
+			};
+			if let Some(confirmed) = answer {
+				state.confirm_dialog = None;
+				if std::mem::take(&mut state.restart_pending) {
+					if confirmed {
+						self.quick_restart(state)?;
+					}
+				} else {
+					self.answer_confirmation(confirmed, state)?;
+				}
+			}
+			return Ok(());
+		}
+		#[cfg(feature = "dev-console")]
+		if state.dev_console.is_some() {
+			handle_dev_console_event(self, action, event, state);
+			return Ok(());
+		}
+		if state.pause_menu.is_some() {
+			if let Some(action) = action {
+				handle_pause_menu_action(self, action, state);
+			}
+			return Ok(());
+		}
+		match action {
+			#[cfg(feature = "dev-console")]
+			Some(Action::DevConsole) if !self.dev_commands().is_empty() => {
+				state.dev_console = Some(DevConsole::default());
+				return Ok(());
+			},
+			Some(Action::Pause) if state.pausable && !state.is_counting_down() => {
+				let help = self.help_page().is_some();
+				state.pause_menu = Some(PauseMenu::new(state.offers_hints, help));
+				return Ok(());
+			},
+			Some(Action::Restart) if state.restartable && !state.is_counting_down() => {
+				if self.has_significant_progress() {
+					state.restart_pending = true;
+					state.ask_confirmation(t!("quick-restart-confirm"));
+					return Ok(());
+				}
+				return self.quick_restart(state);
+			},
+			Some(Action::Help) => {
+				if let Some(page) = self.help_page() {
+					state.create_screen(HelpScreen::new(page).into());
+				}
+			},
+			Some(Action::Controls) if state.kind == ScreenKind::Normal => {
+				state.create_screen(
+					ControlsPopup::new(state.controls_entries_with_actions()).into(),
+				);
+			},
+			Some(Action::Back) => {
+				state.open_status = OpenStatus::Closed;
+			},
+			_ => {},
+		}
+		if let Some(action) = action {
+			if self.handle_action(action, state)? {
+				return Ok(());
 			}
 		}
 		self.handle_event(event, state)
 	}
 
+	/// Handles the player's answer to the screen's [confirmation
+	/// dialog](ScreenState::ask_confirmation).
+	fn answer_confirmation(
+		&mut self,
+		_confirmed: bool,
+		_state: &mut ScreenState,
+	) -> anyhow::Result<()> {
+		Ok(())
+	}
+
+	/// Plays the screen's game again from the start, with the same settings
+	/// and [seed](crate::services::rng), on [`Action::Restart`] for screens
+	/// that [allow it](ScreenState::with_quick_restart).
+	fn quick_restart(&mut self, _state: &mut ScreenState) -> anyhow::Result<()> {
+		Ok(())
+	}
+
+	/// Returns whether the game in progress went far enough that [quickly
+	/// restarting](Self::quick_restart) it takes confirming, like after
+	/// [`SIGNIFICANT_PLAY_TIME`] of playing.
+	fn has_significant_progress(&self) -> bool {
+		false
+	}
+
+	/// Returns the [developer console](DevConsole) commands of the screen's
+	/// game. The console only opens on screens with commands, which are run
+	/// with [`Self::dev_command`].
+	#[cfg(feature = "dev-console")]
+	fn dev_commands(&self) -> Vec<DevCommand> {
+		Vec::new()
+	}
+
+	/// Runs one of the screen's [developer console
+	/// commands](Self::dev_commands) with its arguments, returning what it
+	/// prints in the console.
+	#[cfg(feature = "dev-console")]
+	fn dev_command(
+		&mut self,
+		_name: &str,
+		_args: &[&str],
+		_state: &mut ScreenState,
+	) -> anyhow::Result<String> {
+		Ok(String::new())
+	}
+
+	/// Returns the help page for this screen, opened with \[F1\]. Game screens
+	/// should return their game's [manual](crate::games::Game::help_page).
+	fn help_page(&self) -> Option<&'static str> {
+		None
+	}
+
+	/// Returns a [hint](Hint) for the player, asked for from the [pause
+	/// menu](ScreenState::with_hints), or [`None`] if the game has none to
+	/// give right now. Every hint given should count against the player's
+	/// score.
+	fn hint(&mut self, _state: &mut ScreenState) -> Option<Hint> {
+		None
+	}
+
 	/// Called when the screen is being closed.
 	/// This can be called when the entire application is being quit (in the
 	/// proper manner, of course, not through a crash or a panic).
@@ -177,6 +640,29 @@ pub trait Screen {
 		Ok(())
 	}
 
+	/// Saves the game in progress on this screen, for screens of games that
+	/// support [autosaving](crate::services::autosave). Called on every open
+	/// screen every
+	/// [`Config::autosave_interval_seconds`](crate::core::config::Config::autosave_interval_seconds).
+	fn autosave(&mut self) -> anyhow::Result<()> {
+		Ok(())
+	}
+
+	/// Updates the screen on a frame-by-frame basis, whether or not an event
+	/// was received. Only the active screen is updated.
+	fn update(&mut self, _state: &mut ScreenState) {}
+
+	/// Pauses what runs on its own on this screen, like a game's ticks and
+	/// timers. Called when another screen opens over this one, or when the
+	/// terminal loses focus. Time spent paused shouldn't count towards the
+	/// player's times. Games played over the network tell the other players,
+	/// so that the game stops for them too.
+	fn pause(&mut self) {}
+
+	/// Resumes what was [paused](Self::pause) on this screen. Called when the
+	/// screens over this one close, or when the terminal gets focus back.
+	fn resume(&mut self) {}
+
 	/// Renders ***this*** screen's UI.
 	/// Using this method directly is discouraged - [`Self::render`] handles
 	/// rendering its popups as well.
@@ -186,16 +672,189 @@ pub trait Screen {
 	/// screen-sized base block with a provided title by the trait.
 	fn render(&mut self, frame: &mut Frame<'_>, state: &mut ScreenState, focused: bool) {
 		if state.kind == ScreenKind::Normal {
-			let mut base_block = screen_base_block(state.title);
+			// The title is truncated so it never runs into the corners.
+			let title_width = usize::from(frame.size().width.saturating_sub(4));
+			let mut base_block = screen_base_block(text::truncate(state.title, title_width));
+			if let Some(indicator) = crate::core::profile::indicator() {
+				base_block = base_block.title(Line::from(indicator).alignment(Alignment::Right));
+			}
+			if let Some(indicator) = stats::today_indicator() {
+				base_block = base_block.title(Line::from(indicator).alignment(Alignment::Left));
+			}
 			if !focused {
 				base_block = base_block.style(Style::new().add_modifier(Modifier::DIM));
 			}
+			if let Some(indicator) = autosave::indicator() {
+				base_block =
+					base_block.title_bottom(Line::from(indicator).alignment(Alignment::Right));
+			}
+			if let Some(indicator) = crate::core::recording::indicator() {
+				base_block =
+					base_block.title_bottom(Line::from(indicator).alignment(Alignment::Left));
+			}
+			if let Some(indicator) = crate::core::macros::indicator() {
+				base_block =
+					base_block.title_bottom(Line::from(indicator).alignment(Alignment::Left));
+			}
+			if let Some(indicator) = crate::core::screenshot::indicator() {
+				base_block =
+					base_block.title_bottom(Line::from(indicator).alignment(Alignment::Center));
+			}
+			if let Some((width, height)) =
+				state.preferred_size.filter(|_| state.is_cramped(frame.size()))
+			{
+				let hint = t!("terminal-cramped", width = width, height = height);
+				base_block = base_block.title_bottom(Line::from(hint).alignment(Alignment::Center));
+			}
+			if let Some(indicator) = crate::core::actions::quit_indicator() {
+				base_block =
+					base_block.title_bottom(Line::from(indicator).alignment(Alignment::Center));
+			}
+			if let Some(indicator) = render_stats::indicator() {
+				base_block =
+					base_block.title_bottom(Line::from(indicator).alignment(Alignment::Center));
+			}
 			frame.render_widget(base_block, frame.size());
 		}
-		self.render_ui(frame, state);
+		if state.is_too_small(frame.size()) {
+			render_too_small(frame, state);
+		} else {
+			self.render_ui(frame, state);
+			if let Some(countdown) = &state.countdown {
+				countdown.render(frame, frame.size());
+			}
+			if let Some(pause_menu) = &state.pause_menu {
+				pause_menu.render(frame, frame.size());
+			}
+			if let Some(confirm_dialog) = &state.confirm_dialog {
+				confirm_dialog.render(frame, frame.size());
+			}
+			#[cfg(feature = "dev-console")]
+			if let Some(dev_console) = &state.dev_console {
+				dev_console.render(frame, frame.size());
+			}
+		}
 	}
 }
 
+/// Handles an action while a screen's [pause menu](PauseMenu) is open. Text
+/// hints are shown in the menu, while the menu closes for the screen to point
+/// at positions.
+fn handle_pause_menu_action<S: Screen + ?Sized>(
+	screen: &mut S,
+	action: Action,
+	state: &mut ScreenState,
+) {
+	let Some(pause_menu) = &mut state.pause_menu else {
+		return;
+	};
+	match action {
+		Action::Up => pause_menu.select_previous(),
+		Action::Down => pause_menu.select_next(),
+		Action::Back | Action::Pause => state.pause_menu = None,
+		Action::Confirm => match pause_menu.selected() {
+			Some(PauseMenuItem::Resume) => state.pause_menu = None,
+			Some(PauseMenuItem::Hint) => match screen.hint(state) {
+				Some(Hint::Position(..)) => state.pause_menu = None,
+				hint => {
+					let text = match hint {
+						Some(Hint::Text(text)) => Some(text),
+						_ => None,
+					};
+					if let Some(pause_menu) = &mut state.pause_menu {
+						pause_menu.show_hint(text);
+					}
+				},
+			},
+			Some(PauseMenuItem::Help) => {
+				if let Some(page) = screen.help_page() {
+					state.create_screen(HelpScreen::new(page).into());
+				}
+			},
+			Some(PauseMenuItem::Leave) => state.open_status = OpenStatus::Closed,
+			None => {},
+		},
+		_ => {},
+	}
+}
+
+/// Handles an event while a screen's [developer console](DevConsole) is open:
+/// keys are typed into the command line, run with \[Enter\], and
+/// [`Action::DevConsole`] or [`Action::Back`] close the console.
+#[cfg(feature = "dev-console")]
+fn handle_dev_console_event<S: Screen + ?Sized>(
+	screen: &mut S,
+	action: Option<Action>,
+	event: &Event,
+	state: &mut ScreenState,
+) {
+	let Some(dev_console) = &mut state.dev_console else {
+		return;
+	};
+	if matches!(action, Some(Action::DevConsole | Action::Back)) {
+		state.dev_console = None;
+		return;
+	}
+	let Event::Key(key) = event else {
+		return;
+	};
+	match key.code {
+		KeyCode::Char(char) => dev_console.input.push(char),
+		KeyCode::Backspace => {
+			dev_console.input.pop();
+		},
+		KeyCode::Enter => {
+			let line = std::mem::take(&mut dev_console.input);
+			dev_console.print(&format!("> {line}"), false);
+			let Some((name, args)) = dev_console::parse(&line) else {
+				return;
+			};
+			let commands = screen.dev_commands();
+			let result = if name == "help" {
+				Ok(std::iter::once(("help", t!("dev-console-help")))
+					.chain(commands)
+					.map(|(usage, description)| format!("{usage}: {description}"))
+					.collect::<Vec<_>>()
+					.join("\n"))
+			} else if commands.iter().any(|(usage, _)| usage.split(' ').next() == Some(name)) {
+				screen.dev_command(name, &args, state)
+			} else {
+				Err(anyhow::anyhow!(t!("dev-console-unknown", name = name)))
+			};
+			if let Some(dev_console) = &mut state.dev_console {
+				match result {
+					Ok(output) => dev_console.print(&output, false),
+					Err(err) => dev_console.print(&format!("{err:#}"), true),
+				}
+			}
+		},
+		_ => {},
+	}
+}
+
+/// Renders a notice in place of a screen the terminal is [too
+/// small](ScreenState::is_too_small) for, telling how big it needs to be.
+fn render_too_small(frame: &mut Frame<'_>, state: &ScreenState) {
+	let size = frame.size();
+	let (min_width, min_height) = state.min_size.unwrap_or_default();
+	let notice = t!(
+		"terminal-too-small",
+		width = size.width,
+		height = size.height,
+		min_width = min_width,
+		min_height = min_height
+	);
+	let area = Rect {
+		y: size.height / 2,
+		height: size.height - size.height / 2,
+		..size.inner(&Margin::new(2, 1))
+	};
+	frame.render_widget(
+		Paragraph::new(notice).alignment(Alignment::Center).wrap(Wrap { trim: true }),
+		area,
+	);
+}
+
 /// A wrapper struct for a screen and its state.
 #[derive(Clone)]
 #[must_use]
@@ -205,13 +864,97 @@ pub struct ScreenAndState {
 
 	/// State associated with the screen.
 	pub state: ScreenState,
+
+	/// Whether the screen is [paused](Screen::pause).
+	paused: bool,
+
+	/// Whether the screen was paused for its [pause
+	/// menu](ScreenState::pause_menu).
+	paused_by_menu: bool,
 }
 
 impl ScreenAndState {
 	/// Creates a new screen and state object.
 	pub fn new(screen: Screens) -> Self {
 		let state = screen.initial_state();
-		Self { screen, state }
+		Self {
+			screen,
+			state,
+			paused: false,
+			paused_by_menu: false,
+		}
+	}
+
+	/// [Pauses](Screen::pause) the screen, unless it already is.
+	pub fn pause(&mut self) {
+		if !self.paused {
+			self.paused = true;
+			self.screen.pause();
+		}
+	}
+
+	/// [Resumes](Screen::resume) the screen, if it's paused and neither its
+	/// [pause menu](ScreenState::pause_menu) nor its [developer
+	/// console](ScreenState::dev_console) is open.
+	pub fn resume(&mut self) {
+		if self.paused && !self.paused_by_menu && !self.is_menu_open() {
+			self.paused = false;
+			self.screen.resume();
+		}
+	}
+
+	/// Keeps the screen [paused](Self::pause) while its [start
+	/// countdown](ScreenState::start_countdown) counts down, resuming it at
+	/// "Go!" and dropping the countdown once it's no longer shown.
+	pub fn update_countdown(&mut self) {
+		let Some(countdown) = &mut self.state.countdown else {
+			return;
+		};
+		if countdown.is_counting() {
+			self.pause();
+			return;
+		}
+		let went = countdown.go();
+		if !countdown.is_shown() {
+			self.state.countdown = None;
+		}
+		if went {
+			self.resume();
+		}
+	}
+
+	/// Returns whether a game is being played on the screen: it's a game's
+	/// screen, and it's neither paused nor counting down to the start.
+	#[must_use]
+	pub fn is_playing(&self) -> bool {
+		self.state.pausable && !self.paused && self.state.countdown.is_none()
+	}
+
+	/// Returns whether the screen's [pause menu](ScreenState::pause_menu) or
+	/// its [developer console](ScreenState::dev_console) is open.
+	fn is_menu_open(&self) -> bool {
+		#[allow(unused_mut)]
+		let mut open = self.state.pause_menu.is_some();
+		#[cfg(feature = "dev-console")]
+		{
+			open |= self.state.dev_console.is_some();
+		}
+		open
+	}
+
+	/// Keeps the screen [paused](Self::pause) while its [pause
+	/// menu](ScreenState::pause_menu) or its [developer
+	/// console](ScreenState::dev_console) is open, resuming it once it closes.
+	pub fn update_pause_menu(&mut self) {
+		let open = self.is_menu_open();
+		if open != self.paused_by_menu {
+			self.paused_by_menu = open;
+			if open {
+				self.pause();
+			} else {
+				self.resume();
+			}
+		}
 	}
 
 	/// Closes the screen.
@@ -221,16 +964,74 @@ impl ScreenAndState {
 	}
 }
 
-/// All screens implemented in Terminal Arcade.
+/// All screens implemented in Terminal Arcade. Only a handful are open at a
+/// time, so game screens holding a whole game aren't boxed.
 #[enum_dispatch(Screen)]
 #[derive(Clone)]
-#[allow(missing_docs)]
+#[allow(missing_docs, clippy::large_enum_variant)]
 pub enum Screens {
 	ControlsPopup(ControlsPopup),
 	WelcomeScreen(WelcomeScreen),
 	ConfigScreen(ConfigScreen),
+	AboutScreen(AboutScreen),
+	BackupsScreen(BackupsScreen),
+	DiagnosticsScreen(DiagnosticsScreen),
+	DigestScreen(DigestScreen),
+	ErrorPopup(ErrorPopup),
+	EventReplayScreen(EventReplayScreen),
+	GameDataScreen(GameDataScreen),
 	GameSearchScreen(GameSearchScreen),
+	HelpScreen(HelpScreen),
+	LeaderboardScreen(LeaderboardScreen),
+	MacrosScreen(MacrosScreen),
+	ProfileScreen(ProfileScreen),
+	QrCodePopup(QrCodePopup),
+	ResetScreen(ResetScreen),
+	SyncConflictPopup(SyncConflictPopup),
+	MigrationScreen(MigrationScreen),
+	ModeSelectPopup(ModeSelectPopup),
+	RoulettePopup(RoulettePopup),
+	#[cfg(feature = "minesweeper")]
 	MinesweeperSetupScreen(MinesweeperSetupScreen),
+	#[cfg(feature = "minesweeper")]
+	MinesweeperDemoScreen(MinesweeperDemoScreen),
+	#[cfg(feature = "minesweeper")]
+	MinesweeperTutorialScreen(MinesweeperTutorialScreen),
+	#[cfg(feature = "networking")]
+	HostLobbyScreen(HostLobbyScreen),
+	#[cfg(feature = "networking")]
+	JoinLobbyScreen(JoinLobbyScreen),
+	#[cfg(feature = "networking")]
+	LocalGamesScreen(LocalGamesScreen),
+	PassKeyboardScreen(PassKeyboardScreen),
+	#[cfg(feature = "tron")]
+	TronSetupScreen(TronSetupScreen),
+	#[cfg(feature = "tron")]
+	TronDemoScreen(TronDemoScreen),
+	#[cfg(feature = "tron")]
+	TronGameScreen(TronGameScreen),
+	#[cfg(feature = "twenty-forty-eight")]
+	TwentyFortyEightSetupScreen(TwentyFortyEightSetupScreen),
+	#[cfg(feature = "twenty-forty-eight")]
+	TwentyFortyEightGameScreen(TwentyFortyEightGameScreen),
+	#[cfg(feature = "sudoku")]
+	SudokuSetupScreen(SudokuSetupScreen),
+	#[cfg(feature = "sudoku")]
+	SudokuGameScreen(SudokuGameScreen),
+	#[cfg(feature = "chess")]
+	ChessSetupScreen(ChessSetupScreen),
+	#[cfg(feature = "chess")]
+	ChessGameScreen(ChessGameScreen),
+	#[cfg(feature = "klondike")]
+	KlondikeSetupScreen(KlondikeSetupScreen),
+	#[cfg(feature = "klondike")]
+	KlondikeGameScreen(KlondikeGameScreen),
+	#[cfg(feature = "scripting")]
+	ScriptedGameScreen(ScriptedGameScreen),
+	#[cfg(feature = "images")]
+	SplashScreen(SplashScreen),
+	#[cfg(feature = "encryption")]
+	PassphraseScreen(PassphraseScreen),
 }
 
 impl From<Screens> for ScreenAndState {
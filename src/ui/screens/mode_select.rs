@@ -0,0 +1,138 @@
+//! The popup for choosing the [mode](GameMode) a game is played in, with the
+//! player's best score in each, opened when launching a game that has more than
+//! the standard mode.
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Direction,
+		Rect,
+	},
+	widgets::{
+		Clear,
+		Widget,
+	},
+	Frame,
+};
+
+use crate::{
+	core::{
+		actions::Action,
+		events::{
+			Event,
+			KeyCode,
+		},
+	},
+	games::{
+		modes::GameMode,
+		Game,
+		GameDynamicInfo,
+		Games,
+	},
+	t,
+	ui::{
+		components::presets::{
+			highlight_block,
+			titled_ui_block,
+		},
+		screens::{
+			OpenStatus,
+			ScreenKind,
+			ScreenState,
+		},
+		widgets::scrollable_list::{
+			ListItem,
+			ScrollableList,
+		},
+		Screen,
+	},
+};
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct ModeSelectPopup {
+	/// The game being launched.
+	game: Games,
+
+	/// Scrollable list widget for the game's modes.
+	modes_list: ScrollableList<GameMode>,
+}
+
+impl ModeSelectPopup {
+	/// Creates the popup for choosing one of a game's modes.
+	pub fn new(game: Games) -> Self {
+		let metadata = game.data().metadata;
+		let info = GameDynamicInfo::load_or_default(&metadata.static_info.id).unwrap_or_default();
+		let items = game
+			.modes()
+			.into_iter()
+			.map(|mode| {
+				let best = info
+					.high_score_in(mode)
+					.map(|score| t!("game-high-scores", scores = score))
+					.unwrap_or_default();
+				let text = format!("{}{best}\n{}", mode.label(), mode.description());
+				ListItem::new(None, mode, Some(text))
+			})
+			.collect();
+		Self {
+			game,
+			modes_list: ScrollableList::new(
+				items,
+				None,
+				2,
+				Direction::Vertical,
+				Alignment::Center,
+				Some((1, 1)),
+				None,
+			),
+		}
+	}
+}
+
+impl Screen for ModeSelectPopup {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(
+			t!("mode-select-title"),
+			ScreenKind::Popup,
+			Some(vec![("Enter", t!("mode-select-control"))]),
+		)
+	}
+
+	fn handle_event(&mut self, _event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		Ok(())
+	}
+
+	fn handle_action(&mut self, action: Action, state: &mut ScreenState) -> anyhow::Result<bool> {
+		match action {
+			Action::Up => self.modes_list.scroll_forward(),
+			Action::Down => self.modes_list.scroll_backward(),
+			Action::Confirm => {
+				if let Some((_, item)) = self.modes_list.get_selected() {
+					state.create_screens(self.game.mode_screen(item.data));
+					state.open_status = OpenStatus::Closed;
+				}
+			},
+			_ => return Ok(false),
+		}
+		Ok(true)
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let frame_area = frame.size();
+		let width = (frame_area.width / 5 * 4).max(50).min(frame_area.width);
+		let height = (self.modes_list.get_height() + 2).min(frame_area.height);
+		let area = Rect {
+			x: (frame_area.width - width) / 2,
+			y: (frame_area.height - height) / 2,
+			width,
+			height,
+		};
+		Clear.render(area, frame.buffer_mut());
+		let block = highlight_block(titled_ui_block(t!("mode-select-title")));
+		let inner = block.inner(area);
+		frame.render_widget(block, area);
+		self.modes_list.render(frame, inner);
+	}
+}
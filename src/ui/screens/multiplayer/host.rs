@@ -0,0 +1,160 @@
+//! The lobby where a host waits for another player to join its game.
+
+use std::net::IpAddr;
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Constraint,
+		Direction,
+		Layout,
+	},
+	widgets::{
+		Paragraph,
+		Wrap,
+	},
+	Frame,
+};
+
+use crate::{
+	core::events::Event,
+	services::net::{
+		default_player_name,
+		discovery::Advertisement,
+		local_address,
+		PendingSession,
+		Session,
+		DEFAULT_PORT,
+	},
+	t,
+	ui::{
+		components::presets::titled_ui_block,
+		screens::{
+			OpenStatus,
+			ScreenKind,
+			ScreenState,
+			Screens,
+		},
+		Screen,
+	},
+};
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct HostLobbyScreen {
+	/// Name of the game hosted.
+	game: &'static str,
+
+	/// Creates the game's screen once a player joined.
+	start: fn(Session) -> Screens,
+
+	/// The session waiting for a guest, or why the game couldn't be hosted.
+	pending: Result<PendingSession, String>,
+
+	/// Address of this computer on the local network, shown to the host.
+	local_address: Option<IpAddr>,
+
+	/// The game's advertisement on the local network, if it could be
+	/// advertised.
+	advertisement: Option<Advertisement>,
+}
+
+impl HostLobbyScreen {
+	/// Hosts a game on the [default port](DEFAULT_PORT), starting it with a
+	/// screen created by `start` once a player joined.
+	pub fn new(game: &'static str, start: fn(Session) -> Screens) -> Self {
+		let name = default_player_name();
+		let pending = PendingSession::host(game, name.clone(), DEFAULT_PORT)
+			.map_err(|err| t!("host-failed", error = err.to_string()));
+		let advertisement = pending
+			.as_ref()
+			.ok()
+			.and_then(|_| Advertisement::start(game, &name, DEFAULT_PORT).ok());
+		Self {
+			game,
+			start,
+			pending,
+			local_address: local_address(),
+			advertisement,
+		}
+	}
+
+	/// Stops advertising the game on the local network.
+	fn stop_advertising(&mut self) {
+		if let Some(advertisement) = self.advertisement.take() {
+			advertisement.stop();
+		}
+	}
+
+	/// Returns the text describing the lobby's status.
+	fn status_text(&self) -> String {
+		match &self.pending {
+			Ok(_) => {
+				let address = self.local_address.map_or_else(
+					|| t!("host-unknown-address").to_string(),
+					|ip| ip.to_string(),
+				);
+				let discoverable = if self.advertisement.is_some() { "yes" } else { "no" };
+				t!(
+					"host-waiting",
+					game = self.game,
+					port = DEFAULT_PORT,
+					address = address,
+					discoverable = discoverable,
+				)
+			},
+			Err(err) => err.clone(),
+		}
+	}
+}
+
+impl Screen for HostLobbyScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(t!("host-title"), ScreenKind::Normal, None)
+	}
+
+	fn handle_event(&mut self, _event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		Ok(())
+	}
+
+	fn update(&mut self, state: &mut ScreenState) {
+		let Ok(pending) = &self.pending else {
+			return;
+		};
+		match pending.poll() {
+			Some(Ok(session)) => {
+				self.stop_advertising();
+				state.create_screen((self.start)(session));
+				state.open_status = OpenStatus::Closed;
+			},
+			Some(Err(err)) => {
+				self.stop_advertising();
+				self.pending = Err(t!("host-failed", error = err.to_string()));
+			},
+			None => {},
+		}
+	}
+
+	fn close(&mut self) -> anyhow::Result<()> {
+		if let Ok(pending) = &self.pending {
+			pending.cancel();
+		}
+		self.stop_advertising();
+		Ok(())
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.vertical_margin(1)
+			.horizontal_margin(2)
+			.constraints([Constraint::Length(9), Constraint::Min(0)])
+			.split(frame.size());
+		let status = Paragraph::new(self.status_text())
+			.alignment(Alignment::Center)
+			.wrap(Wrap { trim: true })
+			.block(titled_ui_block(t!("host-lobby")));
+		frame.render_widget(status, chunks[0]);
+	}
+}
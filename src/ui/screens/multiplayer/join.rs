@@ -0,0 +1,200 @@
+//! The lobby where a player types in the address of a game to join or watch.
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Constraint,
+		Direction,
+		Layout,
+	},
+	widgets::{
+		Paragraph,
+		Wrap,
+	},
+	Frame,
+};
+
+use crate::{
+	core::events::{
+		Event,
+		KeyCode,
+	},
+	services::net::{
+		default_player_name,
+		PendingSession,
+		Session,
+		DEFAULT_PORT,
+	},
+	t,
+	ui::{
+		components::presets::{
+			titled_ui_block,
+			untitled_ui_block,
+		},
+		screens::{
+			OpenStatus,
+			ScreenKind,
+			ScreenState,
+			Screens,
+		},
+		widgets::utils::focus_ring::FocusRing,
+		Screen,
+	},
+};
+
+/// Maximum length of an address, in characters.
+const MAX_ADDRESS_LENGTH: usize = 64;
+
+/// A field of the lobby's form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JoinField {
+	/// The address typed in.
+	Address,
+
+	/// Whether to play or watch.
+	Mode,
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct JoinLobbyScreen {
+	/// Name of the game joined.
+	game: &'static str,
+
+	/// Creates the game's screen once joined.
+	start: fn(Session) -> Screens,
+
+	/// Address of the host, typed in by the player.
+	address: String,
+
+	/// Whether to watch the game as a spectator instead of playing it.
+	spectate: bool,
+
+	/// The field getting the keys typed.
+	focus: FocusRing<JoinField>,
+
+	/// The session being joined, if any.
+	pending: Option<PendingSession>,
+
+	/// Why the last attempt to join failed, if it did.
+	error: Option<String>,
+}
+
+impl JoinLobbyScreen {
+	/// Creates a lobby to join a game, starting it with a screen created by
+	/// `start` once joined.
+	pub fn new(game: &'static str, start: fn(Session) -> Screens) -> Self {
+		Self {
+			game,
+			start,
+			address: String::new(),
+			spectate: false,
+			focus: FocusRing::new(vec![JoinField::Address, JoinField::Mode]),
+			pending: None,
+			error: None,
+		}
+	}
+
+	/// Returns the text describing the lobby's status.
+	fn status_text(&self) -> String {
+		let mode = if self.spectate { "watch" } else { "play" };
+		if self.pending.is_some() {
+			t!("join-connecting", address = self.address.as_str())
+		} else if let Some(err) = &self.error {
+			t!("join-failed", mode = mode, error = err.as_str())
+		} else {
+			t!(
+				"join-instructions",
+				mode = mode,
+				game = self.game,
+				port = DEFAULT_PORT
+			)
+		}
+	}
+}
+
+impl Screen for JoinLobbyScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(
+			t!("join-title"),
+			ScreenKind::Normal,
+			Some(vec![
+				("Enter", t!("join-control")),
+				("Tab", t!("join-focus-control")),
+				("Space", t!("join-switch-control")),
+			]),
+		)
+	}
+
+	fn handle_event(&mut self, event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		let Event::Key(key) = event else {
+			return Ok(());
+		};
+		if self.pending.is_some() {
+			return Ok(());
+		}
+		let Some(field) = self.focus.route(event) else {
+			return Ok(());
+		};
+		match (field, key.code) {
+			(JoinField::Address, KeyCode::Char(character))
+				if !character.is_whitespace() && self.address.len() < MAX_ADDRESS_LENGTH =>
+			{
+				self.address.push(character);
+			},
+			(JoinField::Address, KeyCode::Backspace) => {
+				self.address.pop();
+			},
+			(JoinField::Mode, KeyCode::Char(' ')) => self.spectate = !self.spectate,
+			(_, KeyCode::Enter) if !self.address.is_empty() => {
+				self.error = None;
+				let connect =
+					if self.spectate { PendingSession::spectate } else { PendingSession::join };
+				self.pending = Some(connect(self.game, default_player_name(), &self.address));
+			},
+			_ => {},
+		}
+		Ok(())
+	}
+
+	fn update(&mut self, state: &mut ScreenState) {
+		let Some(result) = self.pending.as_ref().and_then(PendingSession::poll) else {
+			return;
+		};
+		self.pending = None;
+		match result {
+			Ok(session) => {
+				state.create_screen((self.start)(session));
+				state.open_status = OpenStatus::Closed;
+			},
+			Err(err) => self.error = Some(err.to_string()),
+		}
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.vertical_margin(1)
+			.horizontal_margin(2)
+			.constraints([
+				Constraint::Length(3), // Address
+				Constraint::Length(3), // Mode
+				Constraint::Length(5), // Status
+				Constraint::Min(0),
+			])
+			.split(frame.size());
+		let address = Paragraph::new(format!("🔌 {}", self.address))
+			.block(self.focus.block(JoinField::Address, titled_ui_block(t!("join-address"))));
+		frame.render_widget(address, chunks[0]);
+		let mode = if self.spectate { t!("join-mode-watching") } else { t!("join-mode-playing") };
+		let mode = Paragraph::new(mode)
+			.block(self.focus.block(JoinField::Mode, titled_ui_block(t!("join-mode"))));
+		frame.render_widget(mode, chunks[1]);
+		let status = Paragraph::new(self.status_text())
+			.alignment(Alignment::Center)
+			.wrap(Wrap { trim: true })
+			.block(untitled_ui_block());
+		frame.render_widget(status, chunks[2]);
+	}
+}
@@ -0,0 +1,212 @@
+//! The lobby listing games hosted on the local network, found over
+//! [mDNS](crate::services::net::discovery), for a player to join one without
+//! typing in its address.
+
+use std::net::SocketAddr;
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Constraint,
+		Direction,
+		Layout,
+	},
+	widgets::{
+		Paragraph,
+		Wrap,
+	},
+	Frame,
+};
+
+use crate::{
+	core::{
+		actions::Action,
+		events::{
+			Event,
+			KeyCode,
+		},
+	},
+	services::net::{
+		default_player_name,
+		discovery::LocalGameBrowser,
+		PendingSession,
+		Session,
+	},
+	t,
+	ui::{
+		components::presets::untitled_ui_block,
+		screens::{
+			OpenStatus,
+			ScreenKind,
+			ScreenState,
+			Screens,
+		},
+		widgets::scrollable_list::{
+			ListItem,
+			ScrollableList,
+		},
+		Screen,
+	},
+};
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct LocalGamesScreen {
+	/// Name of the game joined.
+	game: &'static str,
+
+	/// Creates the game's screen once joined.
+	start: fn(Session) -> Screens,
+
+	/// Browser for the games on the local network, or why the network couldn't
+	/// be browsed.
+	browser: Result<LocalGameBrowser, String>,
+
+	/// Scrollable list widget for the games found.
+	games_list: ScrollableList<SocketAddr>,
+
+	/// The session being joined, if any.
+	pending: Option<PendingSession>,
+
+	/// Why the last attempt to join failed, if it did.
+	error: Option<String>,
+}
+
+impl LocalGamesScreen {
+	/// Creates a lobby to join a game on the local network, starting it with a
+	/// screen created by `start` once joined.
+	pub fn new(game: &'static str, start: fn(Session) -> Screens) -> Self {
+		Self {
+			game,
+			start,
+			browser: LocalGameBrowser::browse(game)
+				.map_err(|err| t!("local-browse-failed", error = err.to_string())),
+			games_list: ScrollableList::new(
+				Vec::new(),
+				Some(5),
+				1,
+				Direction::Vertical,
+				Alignment::Center,
+				Some((1, 3)),
+				None,
+			),
+			pending: None,
+			error: None,
+		}
+	}
+
+	/// Returns the text describing the lobby's status.
+	fn status_text(&self) -> String {
+		match &self.browser {
+			Err(err) => err.clone(),
+			Ok(_) if self.pending.is_some() => t!("local-joining").to_string(),
+			Ok(_) => {
+				if let Some(err) = &self.error {
+					t!("local-join-failed", error = err.as_str())
+				} else if self.games_list.get_selected().is_none() {
+					t!("local-looking", game = self.game)
+				} else {
+					t!("local-press-enter").to_string()
+				}
+			},
+		}
+	}
+
+	/// Joins the selected game.
+	fn join_selected(&mut self) {
+		let Some((_, item)) = self.games_list.get_selected() else {
+			return;
+		};
+		self.error = None;
+		self.pending = Some(PendingSession::join(
+			self.game,
+			default_player_name(),
+			&item.data.to_string(),
+		));
+	}
+}
+
+impl Screen for LocalGamesScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(t!("local-title"), ScreenKind::Normal, None).with_actions(vec![
+			(&[Action::Up, Action::Down], t!("local-select")),
+			(&[Action::Confirm], t!("local-join-control")),
+		])
+	}
+
+	fn handle_event(&mut self, _event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		Ok(())
+	}
+
+	fn handle_action(&mut self, action: Action, _state: &mut ScreenState) -> anyhow::Result<bool> {
+		if self.pending.is_some() {
+			return Ok(false);
+		}
+		match action {
+			Action::Up => self.games_list.scroll_forward(),
+			Action::Down => self.games_list.scroll_backward(),
+			Action::Confirm => self.join_selected(),
+			_ => return Ok(false),
+		}
+		Ok(true)
+	}
+
+	fn update(&mut self, state: &mut ScreenState) {
+		if let Ok(browser) = &mut self.browser {
+			if browser.poll() {
+				let items = browser
+					.games()
+					.iter()
+					.map(|game| {
+						ListItem::new(
+							None,
+							game.address,
+							Some(format!("🖥️ {} ({})", game.host_name, game.address)),
+						)
+					})
+					.collect();
+				self.games_list.update_items(items);
+			}
+		}
+		let Some(result) = self.pending.as_ref().and_then(PendingSession::poll) else {
+			return;
+		};
+		self.pending = None;
+		match result {
+			Ok(session) => {
+				state.create_screen((self.start)(session));
+				state.open_status = OpenStatus::Closed;
+			},
+			Err(err) => self.error = Some(err.to_string()),
+		}
+	}
+
+	fn close(&mut self) -> anyhow::Result<()> {
+		if let Ok(browser) = &self.browser {
+			browser.stop();
+		}
+		if let Some(pending) = &self.pending {
+			pending.cancel();
+		}
+		Ok(())
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.vertical_margin(1)
+			.horizontal_margin(2)
+			.constraints([
+				Constraint::Length(5), // Status
+				Constraint::Min(0),    // Games
+			])
+			.split(frame.size());
+		let status = Paragraph::new(self.status_text())
+			.alignment(Alignment::Center)
+			.wrap(Wrap { trim: true })
+			.block(untitled_ui_block());
+		frame.render_widget(status, chunks[0]);
+		self.games_list.render(frame, chunks[1]);
+	}
+}
@@ -0,0 +1,20 @@
+//! Screens for multiplayer games: lobbies for networked games, where players
+//! host or join a [session](crate::services::net::Session) before the game
+//! starts, and the interstitial between [hotseat](crate::games::hotseat)
+//! turns.
+
+#[cfg(feature = "networking")]
+pub mod host;
+#[cfg(feature = "networking")]
+pub mod join;
+#[cfg(feature = "networking")]
+pub mod local;
+pub mod pass_keyboard;
+
+#[cfg(feature = "networking")]
+pub use host::HostLobbyScreen;
+#[cfg(feature = "networking")]
+pub use join::JoinLobbyScreen;
+#[cfg(feature = "networking")]
+pub use local::LocalGamesScreen;
+pub use pass_keyboard::PassKeyboardScreen;
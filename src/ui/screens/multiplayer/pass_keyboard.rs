@@ -0,0 +1,96 @@
+//! The screen shown between [hotseat](crate::games::hotseat) turns, hiding the
+//! game until the next player has the keyboard.
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Constraint,
+		Direction,
+		Layout,
+	},
+	style::{
+		Modifier,
+		Style,
+	},
+	text::{
+		Line,
+		Span,
+		Text,
+	},
+	widgets::Paragraph,
+	Frame,
+};
+
+use crate::{
+	core::events::{
+		Event,
+		KeyCode,
+	},
+	games::hotseat::HotseatPlayer,
+	t,
+	ui::{
+		components::presets::untitled_ui_block,
+		screens::{
+			OpenStatus,
+			ScreenKind,
+			ScreenState,
+		},
+		Screen,
+	},
+};
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct PassKeyboardScreen {
+	/// The player taking the keyboard.
+	player: HotseatPlayer,
+}
+
+impl PassKeyboardScreen {
+	/// Creates the screen asking to pass the keyboard to a player.
+	pub fn new(player: HotseatPlayer) -> Self {
+		Self { player }
+	}
+}
+
+impl Screen for PassKeyboardScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(
+			t!("pass-title"),
+			ScreenKind::Normal,
+			Some(vec![("Enter / Space", t!("pass-start-control"))]),
+		)
+	}
+
+	fn handle_event(&mut self, event: &Event, state: &mut ScreenState) -> anyhow::Result<()> {
+		if let Event::Key(key) = event {
+			if matches!(key.code, KeyCode::Enter | KeyCode::Char(' ')) {
+				state.open_status = OpenStatus::Closed;
+			}
+		}
+		Ok(())
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let size = frame.size();
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.constraints([
+				Constraint::Min(0),
+				Constraint::Length(6),
+				Constraint::Min(0),
+			])
+			.horizontal_margin(4)
+			.split(size);
+		let name_style = Style::new().fg(self.player.color).add_modifier(Modifier::BOLD);
+		let text = Text::from(vec![
+			Line::from(t!("pass-keyboard")),
+			Line::from(Span::styled(self.player.name.clone(), name_style)),
+			Line::default(),
+			Line::from(t!("pass-ready")),
+		]);
+		let message = Paragraph::new(text).alignment(Alignment::Center).block(untitled_ui_block());
+		frame.render_widget(message, chunks[1]);
+	}
+}
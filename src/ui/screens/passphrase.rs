@@ -0,0 +1,174 @@
+//! The screen asking for the passphrase of the [encrypted save
+//! data](crate::core::encryption), to unlock it on startup or to set up or
+//! remove its encryption from the settings.
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Constraint,
+		Direction,
+		Layout,
+	},
+	widgets::{
+		Paragraph,
+		Wrap,
+	},
+	Frame,
+};
+
+use crate::{
+	core::{
+		encryption,
+		events::{
+			Event,
+			KeyCode,
+		},
+	},
+	t,
+	ui::{
+		components::presets::{
+			highlight_block,
+			titled_ui_block,
+			untitled_ui_block,
+		},
+		screens::{
+			OpenStatus,
+			ScreenKind,
+			ScreenState,
+		},
+		Screen,
+	},
+};
+
+/// Maximum length of a passphrase, in characters.
+const MAX_PASSPHRASE_LENGTH: usize = 128;
+
+/// What the passphrase is asked for.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PassphrasePurpose {
+	/// Unlocking the save data.
+	Unlock,
+
+	/// Encrypting the save data with a new passphrase.
+	Enable,
+
+	/// Decrypting the save data for good.
+	Disable,
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct PassphraseScreen {
+	/// What the passphrase is asked for.
+	purpose: PassphrasePurpose,
+
+	/// The passphrase typed in.
+	passphrase: String,
+
+	/// The new passphrase typed in the first time, when it's being confirmed.
+	first_entry: Option<String>,
+
+	/// Why the last passphrase didn't do, if it didn't.
+	error: Option<String>,
+}
+
+impl PassphraseScreen {
+	/// Creates the screen asking for the passphrase for a purpose.
+	pub fn new(purpose: PassphrasePurpose) -> Self {
+		Self {
+			purpose,
+			passphrase: String::new(),
+			first_entry: None,
+			error: None,
+		}
+	}
+
+	/// Returns the text explaining what the passphrase is for.
+	fn instructions(&self) -> &'static str {
+		match self.purpose {
+			PassphrasePurpose::Unlock => t!("passphrase-unlock"),
+			PassphrasePurpose::Enable if self.first_entry.is_some() => t!("passphrase-confirm"),
+			PassphrasePurpose::Enable => t!("passphrase-enable"),
+			PassphrasePurpose::Disable => t!("passphrase-disable"),
+		}
+	}
+
+	/// Acts on the passphrase typed in, returning whether it's done.
+	fn submit(&mut self) -> anyhow::Result<bool> {
+		let passphrase = std::mem::take(&mut self.passphrase);
+		match self.purpose {
+			PassphrasePurpose::Unlock => encryption::unlock(&passphrase)?,
+			PassphrasePurpose::Enable => match self.first_entry.take() {
+				None => {
+					self.first_entry = Some(passphrase);
+					return Ok(false);
+				},
+				Some(first_entry) if first_entry == passphrase => encryption::enable(&passphrase)?,
+				Some(_) => anyhow::bail!(t!("passphrase-mismatch")),
+			},
+			PassphrasePurpose::Disable => {
+				encryption::unlock(&passphrase)?;
+				encryption::disable()?;
+			},
+		}
+		Ok(true)
+	}
+}
+
+impl Screen for PassphraseScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(
+			t!("passphrase-title"),
+			ScreenKind::Normal,
+			Some(vec![("Enter", t!("passphrase-control"))]),
+		)
+	}
+
+	fn handle_event(&mut self, event: &Event, state: &mut ScreenState) -> anyhow::Result<()> {
+		let Event::Key(key) = event else {
+			return Ok(());
+		};
+		match key.code {
+			KeyCode::Char(character) if self.passphrase.len() < MAX_PASSPHRASE_LENGTH => {
+				self.passphrase.push(character);
+			},
+			KeyCode::Backspace => {
+				self.passphrase.pop();
+			},
+			KeyCode::Enter if !self.passphrase.is_empty() => match self.submit() {
+				Ok(true) => state.open_status = OpenStatus::Closed,
+				Ok(false) => self.error = None,
+				Err(error) => self.error = Some(error.to_string()),
+			},
+			_ => {},
+		}
+		Ok(())
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.vertical_margin(1)
+			.horizontal_margin(2)
+			.constraints([
+				Constraint::Length(3), // Passphrase
+				Constraint::Length(5), // Instructions
+				Constraint::Min(0),
+			])
+			.split(frame.size());
+		let hidden = "•".repeat(self.passphrase.chars().count());
+		let passphrase = Paragraph::new(format!("🔑 {hidden}"))
+			.block(highlight_block(titled_ui_block(t!("passphrase-field"))));
+		frame.render_widget(passphrase, chunks[0]);
+		let text = match &self.error {
+			Some(error) => t!("passphrase-failed", error = error.as_str()),
+			None => self.instructions().to_string(),
+		};
+		let instructions = Paragraph::new(text)
+			.alignment(Alignment::Center)
+			.wrap(Wrap { trim: true })
+			.block(untitled_ui_block());
+		frame.render_widget(instructions, chunks[1]);
+	}
+}
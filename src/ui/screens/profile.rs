@@ -0,0 +1,161 @@
+//! The screen editing the player's [profile](crate::core::profile), opened from
+//! the settings. The name is typed in, and the avatar picked with the arrow
+//! keys.
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Constraint,
+		Direction,
+		Layout,
+		Margin,
+	},
+	widgets::{
+		Paragraph,
+		Wrap,
+	},
+	Frame,
+};
+
+use crate::{
+	core::{
+		actions::Action,
+		events::{
+			Event,
+			KeyCode,
+			KeyModifiers,
+		},
+		profile::{
+			clean_name,
+			Profile,
+			MAX_NAME_LENGTH,
+		},
+	},
+	t,
+	ui::{
+		components::presets::{
+			titled_ui_block,
+			untitled_ui_block,
+		},
+		screens::{
+			ScreenKind,
+			ScreenState,
+		},
+		Screen,
+	},
+};
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct ProfileScreen {
+	/// The profile being edited.
+	profile: Profile,
+
+	/// The name typed in, saved once it's not blank.
+	name: String,
+
+	/// What happened after the last save, shown below the profile.
+	status: String,
+}
+
+impl Default for ProfileScreen {
+	fn default() -> Self {
+		let profile = Profile::current();
+		Self {
+			name: profile.name.clone(),
+			profile,
+			status: t!("profile-hint").to_string(),
+		}
+	}
+}
+
+impl ProfileScreen {
+	/// Saves the profile with the name typed in, unless it's blank.
+	fn save(&mut self) {
+		let Some(name) = clean_name(&self.name) else {
+			self.status = t!("profile-name-blank").to_string();
+			return;
+		};
+		self.profile.name = name;
+		self.name.clone_from(&self.profile.name);
+		self.status = match self.profile.save() {
+			Ok(()) => t!("profile-saved", badge = self.profile.badge()),
+			Err(error) => t!("profile-save-failed", error = error.to_string()),
+		};
+	}
+}
+
+impl Screen for ProfileScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(t!("profile-title"), ScreenKind::Normal, None)
+			.with_actions(vec![
+				(&[Action::Left, Action::Right], t!("profile-avatar-control")),
+				(&[Action::Confirm], t!("profile-save-control")),
+			])
+			.with_static_frames()
+	}
+
+	fn handle_event(&mut self, event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		let Event::Key(key) = event else {
+			return Ok(());
+		};
+		match key.code {
+			KeyCode::Backspace => {
+				self.name.pop();
+			},
+			KeyCode::Char(char)
+				if [KeyModifiers::NONE, KeyModifiers::SHIFT].contains(&key.modifiers)
+					&& self.name.chars().count() < MAX_NAME_LENGTH =>
+			{
+				self.name.push(char);
+			},
+			_ => {},
+		}
+		Ok(())
+	}
+
+	fn handle_action(&mut self, action: Action, _state: &mut ScreenState) -> anyhow::Result<bool> {
+		match action {
+			Action::Left => self.profile.cycle_avatar(false),
+			Action::Right => self.profile.cycle_avatar(true),
+			Action::Confirm => self.save(),
+			_ => return Ok(false),
+		}
+		Ok(true)
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.margin(1)
+			.constraints([
+				Constraint::Length(3),
+				Constraint::Length(3),
+				Constraint::Min(0),
+				Constraint::Length(4),
+			])
+			.split(frame.size());
+		let name = Paragraph::new(format!("{}▏", self.name))
+			.alignment(Alignment::Center)
+			.block(titled_ui_block(t!("profile-name")));
+		frame.render_widget(name, chunks[0]);
+		let avatar = Paragraph::new(format!("◀ {} ▶", self.profile.avatar))
+			.alignment(Alignment::Center)
+			.block(titled_ui_block(t!("profile-avatar")));
+		frame.render_widget(avatar, chunks[1]);
+		let badge = Profile {
+			name: clean_name(&self.name).unwrap_or_default(),
+			..self.profile.clone()
+		}
+		.badge();
+		let preview = Paragraph::new(t!("profile-preview", badge = badge))
+			.alignment(Alignment::Center)
+			.wrap(Wrap { trim: true });
+		frame.render_widget(preview, chunks[2].inner(&Margin::new(0, 1)));
+		let status = Paragraph::new(self.status.clone())
+			.block(untitled_ui_block())
+			.wrap(Wrap { trim: true });
+		frame.render_widget(status, chunks[3]);
+	}
+}
@@ -0,0 +1,128 @@
+//! A popup showing some data, like a URL, as a QR code. Useful for players on
+//! remote sessions, who can scan the code with their phone instead of copying
+//! the data out of the terminal.
+
+use qrcode::{
+	render::unicode::Dense1x2,
+	QrCode,
+};
+use ratatui::{
+	layout::{
+		Alignment,
+		Rect,
+	},
+	text::{
+		Line,
+		Text,
+	},
+	widgets::{
+		Clear,
+		Paragraph,
+		Widget,
+	},
+	Frame,
+};
+
+use crate::{
+	core::events::Event,
+	t,
+	ui::{
+		components::presets::{
+			highlight_block,
+			titled_ui_block,
+		},
+		screens::{
+			ScreenKind,
+			ScreenState,
+		},
+		Screen,
+	},
+};
+
+/// Renders data as a QR code made of half blocks, or returns [`None`] if the
+/// data is too long to fit in one. Colors are inverted, since most terminals
+/// have a dark background and scanners expect dark modules on light.
+#[must_use]
+pub fn render_qr_code(data: &str) -> Option<String> {
+	let code = QrCode::new(data).ok()?;
+	Some(
+		code.render::<Dense1x2>()
+			.dark_color(Dense1x2::Light)
+			.light_color(Dense1x2::Dark)
+			.quiet_zone(true)
+			.build(),
+	)
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct QrCodePopup {
+	/// What the data is, displayed as the popup's title.
+	label: &'static str,
+
+	/// The data encoded in the QR code, also displayed below it.
+	data: String,
+
+	/// Text displayed above the QR code, if any.
+	message: Option<String>,
+
+	/// The rendered QR code.
+	code: Option<String>,
+}
+
+impl QrCodePopup {
+	/// Creates a new popup for some data.
+	pub fn new(label: &'static str, data: String) -> Self {
+		let code = render_qr_code(&data);
+		Self {
+			label,
+			data,
+			message: None,
+			code,
+		}
+	}
+
+	/// Displays some text above the QR code.
+	pub fn with_message(mut self, message: String) -> Self {
+		self.message = Some(message);
+		self
+	}
+}
+
+impl Screen for QrCodePopup {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(t!("qr-title"), ScreenKind::Popup, None).with_static_frames()
+	}
+
+	fn handle_event(&mut self, _event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		Ok(())
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let mut text =
+			Text::from(self.code.clone().unwrap_or_else(|| t!("qr-too-long").to_string()));
+		text.lines.push(Line::from(self.data.clone()));
+		if let Some(message) = &self.message {
+			let mut message = Text::from(message.clone());
+			message.lines.push(Line::default());
+			message.lines.append(&mut text.lines);
+			text = message;
+		}
+
+		let frame_area = frame.size();
+		let width = (text.width() as u16 + 4).min(frame_area.width);
+		let height = (text.height() as u16 + 2).min(frame_area.height);
+		let area = Rect {
+			x: (frame_area.width - width) / 2,
+			y: (frame_area.height - height) / 2,
+			width,
+			height,
+		};
+		Clear.render(area, frame.buffer_mut());
+		let popup = Paragraph::new(text)
+			.alignment(Alignment::Center)
+			.block(highlight_block(titled_ui_block(self.label)));
+		frame.render_widget(popup, area);
+	}
+}
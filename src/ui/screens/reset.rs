@@ -0,0 +1,137 @@
+//! The screen resetting parts of Terminal Arcade to their
+//! [defaults](crate::services::reset), opened from the settings. Each reset is
+//! confirmed first, and backs up what it wipes.
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Constraint,
+		Direction,
+		Layout,
+	},
+	widgets::{
+		Paragraph,
+		Wrap,
+	},
+	Frame,
+};
+
+use crate::{
+	core::{
+		actions::Action,
+		events::Event,
+	},
+	services::reset::ResetScope,
+	t,
+	ui::{
+		components::presets::untitled_ui_block,
+		screens::{
+			ScreenKind,
+			ScreenState,
+		},
+		widgets::scrollable_list::{
+			ListItem,
+			ScrollableList,
+		},
+		Screen,
+	},
+};
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct ResetScreen {
+	/// Scrollable list widget for what can be reset.
+	scopes_list: ScrollableList<ResetScope>,
+
+	/// What happened after the last reset, shown below the list.
+	status: String,
+}
+
+impl Default for ResetScreen {
+	fn default() -> Self {
+		let scopes_list = ScrollableList::new(
+			ResetScope::all()
+				.into_iter()
+				.map(|scope| ListItem::new(None, scope.clone(), Some(scope.label())))
+				.collect(),
+			Some(8),
+			1,
+			Direction::Vertical,
+			Alignment::Center,
+			Some((1, 3)),
+			None,
+		);
+		Self {
+			scopes_list,
+			status: t!("reset-hint").to_string(),
+		}
+	}
+}
+
+impl ResetScreen {
+	/// Returns what's selected to be reset.
+	fn selected(&self) -> Option<&ResetScope> {
+		self.scopes_list.get_selected().map(|(_, item)| &item.data)
+	}
+}
+
+impl Screen for ResetScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(t!("reset-title"), ScreenKind::Normal, None).with_actions(vec![
+			(&[Action::Up, Action::Down], t!("reset-select")),
+			(&[Action::Confirm], t!("reset-control")),
+		])
+	}
+
+	fn handle_event(&mut self, _event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		Ok(())
+	}
+
+	fn handle_action(&mut self, action: Action, state: &mut ScreenState) -> anyhow::Result<bool> {
+		match action {
+			Action::Up => self.scopes_list.scroll_forward(),
+			Action::Down => self.scopes_list.scroll_backward(),
+			Action::Confirm => {
+				if let Some(scope) = self.selected() {
+					state.ask_confirmation(scope.confirmation());
+				}
+			},
+			_ => return Ok(false),
+		}
+		Ok(true)
+	}
+
+	fn answer_confirmation(
+		&mut self,
+		confirmed: bool,
+		_state: &mut ScreenState,
+	) -> anyhow::Result<()> {
+		let Some(scope) = self.selected().filter(|_| confirmed).cloned() else {
+			return Ok(());
+		};
+		self.status = match scope.reset() {
+			Ok(backups) if backups.is_empty() => t!("reset-done", scope = scope.label()),
+			Ok(backups) => t!(
+				"reset-done-backed-up",
+				scope = scope.label(),
+				count = backups.len()
+			),
+			Err(error) => t!("reset-failed", error = error.to_string()),
+		};
+		Ok(())
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.margin(1)
+			.constraints([Constraint::Min(0), Constraint::Length(4)])
+			.split(frame.size());
+		self.scopes_list.render(frame, chunks[0]);
+		let status = Paragraph::new(self.status.clone())
+			.block(untitled_ui_block())
+			.wrap(Wrap { trim: true });
+		frame.render_widget(status, chunks[1]);
+	}
+}
@@ -0,0 +1,160 @@
+//! The "Surprise me" popup, which spins through the games' names like a slot
+//! machine, slowing down until it stops on a random one and launches it.
+
+use std::time::Duration;
+
+use rand::Rng;
+use ratatui::{
+	layout::{
+		Alignment,
+		Rect,
+	},
+	style::{
+		Modifier,
+		Style,
+	},
+	text::Line,
+	widgets::{
+		Clear,
+		Paragraph,
+		Widget,
+	},
+	Frame,
+};
+
+use crate::{
+	core::{
+		events::Event,
+		time::Instant,
+	},
+	games::{
+		Game,
+		Games,
+	},
+	services::rng,
+	t,
+	ui::{
+		components::presets::{
+			highlight_block,
+			titled_ui_block,
+		},
+		screens::{
+			OpenStatus,
+			ScreenKind,
+			ScreenState,
+		},
+		Screen,
+	},
+};
+
+/// How many names the roulette spins through before stopping.
+const SPINS: usize = 20;
+
+/// Time between the first spins.
+const FIRST_SPIN_INTERVAL: Duration = Duration::from_millis(40);
+
+/// How much longer each spin takes than the one before, so that the roulette
+/// slows down.
+const SLOWDOWN: f64 = 1.08;
+
+/// How long the roulette shows the picked game before launching it.
+const PAUSE_BEFORE_LAUNCH: Duration = Duration::from_millis(700);
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct RoulettePopup {
+	/// The games spun through.
+	games: Vec<Games>,
+
+	/// Names of the games.
+	names: Vec<String>,
+
+	/// Index of the game shown.
+	position: usize,
+
+	/// Spins left before stopping.
+	spins_left: usize,
+
+	/// Time between the last spin and the next.
+	interval: Duration,
+
+	/// When the last spin happened.
+	last_spin: Instant,
+}
+
+impl Default for RoulettePopup {
+	fn default() -> Self {
+		let games = Games::all();
+		let names = games.iter().map(|game| game.data().metadata.static_info.name).collect();
+		// Starts far enough behind the picked game to stop on it after every spin.
+		let picked = rng::with_rng(|rng| rng.gen_range(0..games.len()));
+		let position = (picked + games.len() * SPINS - SPINS) % games.len();
+		Self {
+			games,
+			names,
+			position,
+			spins_left: SPINS,
+			interval: FIRST_SPIN_INTERVAL,
+			last_spin: Instant::now(),
+		}
+	}
+}
+
+impl RoulettePopup {
+	/// Returns the name of the game some positions after the one shown,
+	/// wrapping around.
+	fn name_after(&self, offset: usize) -> &str {
+		&self.names[(self.position + offset) % self.names.len()]
+	}
+}
+
+impl Screen for RoulettePopup {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(t!("roulette-title"), ScreenKind::Popup, None)
+	}
+
+	fn handle_event(&mut self, _event: &Event, _state: &mut ScreenState) -> anyhow::Result<()> {
+		Ok(())
+	}
+
+	fn update(&mut self, state: &mut ScreenState) {
+		if self.spins_left == 0 {
+			if self.last_spin.elapsed() >= PAUSE_BEFORE_LAUNCH {
+				state.create_screens(self.games[self.position].launch_screen());
+				state.open_status = OpenStatus::Closed;
+			}
+		} else if self.last_spin.elapsed() >= self.interval {
+			self.position = (self.position + 1) % self.games.len();
+			self.spins_left -= 1;
+			self.interval = self.interval.mul_f64(SLOWDOWN);
+			self.last_spin = Instant::now();
+		}
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let frame_area = frame.size();
+		let width = 40.min(frame_area.width);
+		let height = 5.min(frame_area.height);
+		let area = Rect {
+			x: (frame_area.width - width) / 2,
+			y: (frame_area.height - height) / 2,
+			width,
+			height,
+		};
+		Clear.render(area, frame.buffer_mut());
+		let dim = Style::new().add_modifier(Modifier::DIM);
+		let lines = vec![
+			Line::styled(self.name_after(self.names.len() - 1).to_string(), dim),
+			Line::styled(
+				format!("▶ {} ◀", self.name_after(0)),
+				Style::new().add_modifier(Modifier::BOLD),
+			),
+			Line::styled(self.name_after(1).to_string(), dim),
+		];
+		let roulette = Paragraph::new(lines)
+			.alignment(Alignment::Center)
+			.block(highlight_block(titled_ui_block(t!("roulette-title"))));
+		frame.render_widget(roulette, area);
+	}
+}
@@ -0,0 +1,105 @@
+//! A splash screen showing Terminal Arcade's logo on startup. Only available
+//! with the `images` feature.
+
+use std::time::Duration;
+
+use ratatui::{
+	layout::{
+		Constraint,
+		Direction,
+		Layout,
+	},
+	widgets::Borders,
+	Frame,
+};
+
+use crate::{
+	core::{
+		events::Event,
+		time::Instant,
+	},
+	ui::{
+		components::{
+			banner::{
+				get_banner_height,
+				render_banner,
+			},
+			presets::untitled_ui_block,
+		},
+		screens::{
+			OpenStatus,
+			ScreenKind,
+			ScreenState,
+		},
+		widgets::image::TerminalImage,
+		Screen,
+	},
+};
+
+/// Terminal Arcade's logo.
+pub const LOGO: &[u8] = include_bytes!("../../../assets/images/logo.png");
+
+/// How long the splash screen is shown for, unless a key is pressed.
+pub const SPLASH_DURATION: Duration = Duration::from_millis(1500);
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct SplashScreen {
+	/// When the splash screen was opened.
+	opened_at: Instant,
+
+	/// The logo, or [`None`] if it couldn't be decoded.
+	logo: Option<TerminalImage>,
+}
+
+impl Default for SplashScreen {
+	fn default() -> Self {
+		Self {
+			opened_at: Instant::now(),
+			logo: TerminalImage::from_png(LOGO).ok(),
+		}
+	}
+}
+
+impl Screen for SplashScreen {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new("Terminal Arcade", ScreenKind::Normal, None)
+	}
+
+	fn handle_event(&mut self, event: &Event, state: &mut ScreenState) -> anyhow::Result<()> {
+		if let Event::Key(_) = event {
+			state.open_status = OpenStatus::Closed;
+		}
+		Ok(())
+	}
+
+	fn update(&mut self, state: &mut ScreenState) {
+		if self.opened_at.elapsed() >= SPLASH_DURATION {
+			state.open_status = OpenStatus::Closed;
+		}
+	}
+
+	fn render(&mut self, frame: &mut Frame<'_>, state: &mut ScreenState, _focused: bool) {
+		self.render_ui(frame, state);
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let size = frame.size();
+		let banner_height = get_banner_height("Terminal Arcade", size.width.saturating_sub(4));
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.margin(2)
+			.constraints([Constraint::Min(0), Constraint::Length(banner_height)])
+			.split(size);
+		if let Some(logo) = &self.logo {
+			logo.render(frame, chunks[0]);
+		}
+		render_banner(
+			frame,
+			chunks[1],
+			"Terminal Arcade",
+			untitled_ui_block().borders(Borders::NONE),
+		);
+	}
+}
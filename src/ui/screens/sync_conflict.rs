@@ -0,0 +1,82 @@
+//! A popup telling the player about a [sync](crate::services::sync) conflict,
+//! and which side's save data was kept.
+
+use ratatui::{
+	layout::{
+		Alignment,
+		Rect,
+	},
+	widgets::{
+		Clear,
+		Paragraph,
+		Widget,
+		Wrap,
+	},
+	Frame,
+};
+
+use crate::{
+	core::events::{
+		Event,
+		KeyCode,
+	},
+	t,
+	ui::{
+		components::presets::{
+			highlight_block,
+			titled_ui_block,
+		},
+		screens::{
+			OpenStatus,
+			ScreenKind,
+			ScreenState,
+		},
+		Screen,
+	},
+};
+
+/// See the [module](self) documentation for more information.
+#[derive(Clone)]
+#[must_use]
+pub struct SyncConflictPopup {
+	/// What happened, and which side was kept.
+	message: String,
+}
+
+impl SyncConflictPopup {
+	/// Creates a popup showing a conflict.
+	pub fn new(message: String) -> Self {
+		Self { message }
+	}
+}
+
+impl Screen for SyncConflictPopup {
+	fn initial_state(&self) -> ScreenState {
+		ScreenState::new(t!("sync-conflict-title"), ScreenKind::Popup, None).with_static_frames()
+	}
+
+	fn handle_event(&mut self, event: &Event, state: &mut ScreenState) -> anyhow::Result<()> {
+		if let Event::Key(key) = event {
+			if key.code == KeyCode::Enter {
+				state.open_status = OpenStatus::Closed;
+			}
+		}
+		Ok(())
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
+		let frame_area = frame.size();
+		let area = Rect {
+			x: frame_area.width / 5,
+			y: frame_area.height / 3,
+			width: frame_area.width / 5 * 3,
+			height: frame_area.height / 3,
+		};
+		Clear.render(area, frame.buffer_mut());
+		let popup = Paragraph::new(format!("{}\n\n{}", self.message, t!("sync-conflict-close")))
+			.alignment(Alignment::Center)
+			.wrap(Wrap { trim: true })
+			.block(highlight_block(titled_ui_block(t!("sync-conflict-title"))));
+		frame.render_widget(popup, area);
+	}
+}
@@ -1,12 +1,11 @@
 //! A module for containing the welcome screen in Terminal Arcade.
 
-use std::cmp::max;
-
-use crossterm::event::{
-	Event,
-	KeyCode,
-	KeyModifiers,
+use std::{
+	cmp::max,
+	time::Duration,
 };
+
+use rand::seq::IteratorRandom;
 use ratatui::{
 	layout::{
 		Alignment,
@@ -14,6 +13,7 @@ use ratatui::{
 		Direction,
 		Layout,
 	},
+	text::Line,
 	widgets::{
 		Padding,
 		Paragraph,
@@ -25,52 +25,79 @@ use strum::{
 	EnumString,
 };
 
-use crate::ui::{
-	components::{
-		presets::{
-			titled_ui_block,
-			untitled_ui_block,
+use crate::{
+	core::{
+		actions::Action,
+		config::Config,
+		events::{
+			Event,
+			KeyCode,
+			KeyModifiers,
 		},
-		welcome::footer::render_welcome_bottom_bar,
+		time::Instant,
+		updates::UpdateNotice,
 	},
-	screens::{
-		config::ConfigScreen,
-		game_select::GameSearchScreen,
-		OpenStatus,
-		ScreenAndState,
-		ScreenKind,
-		ScreenState,
-		Screens,
+	games::{
+		Game,
+		Games,
 	},
-	util::get_crate_version,
-	widgets::scrollable_list::{
-		ListItem,
-		ScrollableList,
+	services::rng,
+	t,
+	ui::{
+		components::{
+			banner::{
+				get_banner_height,
+				render_banner,
+			},
+			presets::{
+				titled_ui_block,
+				untitled_ui_block,
+			},
+			welcome::{
+				footer::render_welcome_bottom_bar,
+				tips::TipRotator,
+			},
+		},
+		layout::LayoutSize,
+		screens::{
+			about::AboutScreen,
+			config::ConfigScreen,
+			digest::DigestScreen,
+			game_select::GameSearchScreen,
+			leaderboard::LeaderboardScreen,
+			OpenStatus,
+			RoulettePopup,
+			ScreenAndState,
+			ScreenKind,
+			ScreenState,
+			Screens,
+		},
+		util::get_crate_version,
+		widgets::{
+			scrollable_list::{
+				ListItem,
+				ScrollableList,
+			},
+			typewriter::Typewriter,
+		},
+		Screen,
 	},
-	Screen,
 };
 
-/// Terminal Arcade's ASCII banner.
-pub const BANNER: &str = r"/‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾/‾‾/‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾\            
-‾‾‾‾‾/  /‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾ ‾‾ ‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾\  \           
-    /  /  /‾‾‾‾‾/  /‾‾‾‾‾‾‾/  /‾‾‾‾‾‾‾‾‾/  /‾‾/  /‾‾‾‾‾‾/  /‾‾‾‾\  \  \          
-   /  /  /  /‾‾‾  /  /‾/  /  / /‾/ /‾/ /  /  /  /  /‾/ /  /  /\  \  \  \         
-  /  /  /  ‾‾‾/  /  / /  /  / / / / / /  /  /  /  / / /  /   ‾‾   \  \  \        
- /  /  /  /‾‾‾  /  /  \  \  \ \ \ \ \ \  \  \  \  \ \ \  \  \‾‾‾\  \  \  \       
-/  /  /  ‾‾‾/  /  /    \  \  \ \ \ \ \ \  \  \  \  \ \ \  \  \   \  \  \  ‾‾‾‾‾\ 
-‾‾‾   ‾‾‾‾‾‾   ‾‾‾      ‾‾‾   ‾‾  ‾‾  ‾‾   ‾‾‾   ‾‾‾  ‾‾   ‾‾‾    ‾‾‾   ‾‾‾‾‾‾‾‾ 
-    /‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾/  /‾‾\ \‾‾‾‾‾\ \‾‾‾‾‾‾‾‾/ /\ \‾‾‾‾‾\ \‾‾‾‾‾‾\ 
-   /      /‾‾                    /  / /\ \ \ \‾\ \ \ \‾‾‾‾  /  \ \ \‾\ \ \  \‾‾‾ 
-  /  /‾‾     /‾‾  /‾‾‾‾  /‾‾‾‾  /  / / / / / /‾/ / / /     / /\ \ \ \ \ \ \  ‾‾/ 
- /      /‾‾      /      /      /  / / / / / / / / / /     / /  \ \ \ \/ / / /‾‾  
-/                             /  / /‾/ / / / / / /  ‾‾‾/ / /‾‾‾‾\ \ \  / /  ‾‾/  
-‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾‾   ‾‾  ‾‾  ‾‾  ‾‾  ‾‾‾‾‾‾  ‾‾      ‾‾  ‾‾  ‾‾‾‾‾   ";
+/// Terminal Arcade's banner text.
+pub const BANNER: &str = "Terminal\nArcade";
+
+/// How long the welcome screen is left idle before it plays a demo of a
+/// random game, in [attract mode](Config::attract_mode).
+pub const ATTRACT_MODE_DELAY: Duration = Duration::from_mins(2);
 
 /// Control options available at the welcome screen.
 #[derive(Clone, Copy, PartialEq, Eq, Display)]
 enum ControlOptions {
 	SearchGames,
+	SurpriseMe,
 	ViewConfigs,
+	ViewAbout,
 	QuitApplication,
 }
 
@@ -80,6 +107,26 @@ enum ControlOptions {
 pub struct WelcomeScreen {
 	/// Scrollable list widget for options.
 	controls_list: ScrollableList<ControlOptions>,
+
+	/// Notice of a new version of Terminal Arcade, if one is available.
+	update_notice: UpdateNotice,
+
+	/// Rotator for the tips displayed in the footer, if tips are enabled.
+	tips: Option<TipRotator>,
+
+	/// Types out the tagline under the banner.
+	tagline: Typewriter,
+
+	/// Whether a demo plays once the screen is left idle for a while.
+	attract_mode: bool,
+
+	/// When a key was last pressed on the screen, or when it was last shown.
+	idle_since: Instant,
+
+	/// Whether the screen is played by a connection of the [telnet
+	/// server](crate::services::telnet), opening the settings and the search
+	/// [served](ConfigScreen::served) too.
+	served: bool,
 }
 
 impl Default for WelcomeScreen {
@@ -89,17 +136,27 @@ impl Default for WelcomeScreen {
 				ListItem::new(
 					None,
 					ControlOptions::SearchGames,
-					Some("🎮 Hop into a game and play!".to_string()),
+					Some(t!("welcome-play").to_string()),
+				),
+				ListItem::new(
+					None,
+					ControlOptions::SurpriseMe,
+					Some(t!("welcome-surprise").to_string()),
 				),
 				ListItem::new(
 					None,
 					ControlOptions::ViewConfigs,
-					Some("🗜️ View your settings...".to_string()),
+					Some(t!("welcome-settings").to_string()),
+				),
+				ListItem::new(
+					None,
+					ControlOptions::ViewAbout,
+					Some(t!("welcome-about").to_string()),
 				),
 				ListItem::new(
 					None,
 					ControlOptions::QuitApplication,
-					Some("🛑 Quit the application...".to_string()),
+					Some(t!("welcome-quit").to_string()),
 				),
 			],
 			None,
@@ -108,63 +165,223 @@ impl Default for WelcomeScreen {
 			Alignment::Center,
 			Some((1, 3)),
 			None,
-		);
-		Self { controls_list }
+		)
+		.with_large_text(Config::load_or_default().large_text);
+		Self {
+			controls_list,
+			update_notice: UpdateNotice::spawn_check(),
+			tips: Config::load_or_default().show_tips.then(TipRotator::default),
+			tagline: Typewriter::new(t!("welcome-tagline")),
+			attract_mode: Config::load_or_default().attract_mode,
+			idle_since: Instant::now(),
+			served: false,
+		}
 	}
 }
 
 impl Screen for WelcomeScreen {
 	fn initial_state(&self) -> ScreenState {
-		ScreenState::new("Terminal Arcade", ScreenKind::Normal, None)
+		ScreenState::new(
+			"Terminal Arcade",
+			ScreenKind::Normal,
+			Some(vec![
+				("D", t!("welcome-dismiss-tips")),
+				("W", t!("welcome-digest")),
+				("L", t!("welcome-leaderboard")),
+			]),
+		)
 	}
 
 	fn handle_event(&mut self, event: &Event, state: &mut ScreenState) -> anyhow::Result<()> {
 		if let Event::Key(key) = event {
+			self.idle_since = Instant::now();
 			match key.code {
-				KeyCode::Up => self.controls_list.scroll_forward(),
-				KeyCode::Down => self.controls_list.scroll_backward(),
-				KeyCode::Enter => self.handle_enter_shortcut(state),
+				KeyCode::Char('w' | 'W') => {
+					state.create_screen(DigestScreen::default().into());
+				},
+				KeyCode::Char('l' | 'L') => {
+					state.create_screen(LeaderboardScreen::default().into());
+				},
+				KeyCode::Char('d' | 'D') => {
+					if let Some(tips) = self.tips.as_mut() {
+						tips.dismiss();
+					}
+				},
 				_ => {},
 			}
 		}
 		Ok(())
 	}
 
+	fn handle_action(&mut self, action: Action, state: &mut ScreenState) -> anyhow::Result<bool> {
+		self.idle_since = Instant::now();
+		match action {
+			Action::Up => self.controls_list.scroll_forward(),
+			Action::Down => self.controls_list.scroll_backward(),
+			Action::Confirm => self.handle_enter_shortcut(state),
+			_ => return Ok(false),
+		}
+		Ok(true)
+	}
+
+	fn update(&mut self, state: &mut ScreenState) {
+		self.tagline.tick();
+		if self.attract_mode && self.idle_since.elapsed() >= ATTRACT_MODE_DELAY {
+			self.idle_since = Instant::now();
+			let demos: Vec<_> = Games::all().iter().filter_map(Game::demo_screen).collect();
+			if let Some(demo) = rng::with_rng(|rng| demos.into_iter().choose(rng)) {
+				state.create_screen(demo);
+			}
+		}
+	}
+
+	fn resume(&mut self) {
+		self.idle_since = Instant::now();
+	}
+
 	fn render_ui(&self, frame: &mut Frame<'_>, _state: &ScreenState) {
 		let size = frame.size();
-		let used_ui_height = 16 + 11 + 5 + 6;
-		let empty_space_height =
-			if size.height <= used_ui_height { 0 } else { size.height - used_ui_height };
+		let layout_size = LayoutSize::of(size);
+		// The screen's title already says what the banner does on small terminals.
+		let banner_height = if layout_size.is_compact() {
+			0
+		} else {
+			get_banner_height(BANNER, size.width.saturating_sub(6)) + 2
+		};
+		let list_height = self.controls_list.get_height();
+		let update_notice = self.update_notice.get_text();
+		let tip = self.tips.as_ref().and_then(TipRotator::get_tip);
+		let bottom_bar_height = match layout_size {
+			LayoutSize::Compact if update_notice.is_none() && tip.is_none() => 0,
+			LayoutSize::Compact => 4,
+			LayoutSize::Normal | LayoutSize::Wide => 7,
+		};
+		let used_ui_height = banner_height + list_height + 5 + bottom_bar_height;
+		let empty_space_height = size.height.saturating_sub(used_ui_height);
 		let chunks = Layout::default()
 			.direction(Direction::Vertical)
 			.margin(1)
 			.constraints([
-				Constraint::Max(16), // Banner's height + borders
-				Constraint::Max(11), // Controls list block's height
+				Constraint::Max(banner_height),  // Banner's height + borders
+				Constraint::Length(list_height), // Controls list block's height
 				Constraint::Min(empty_space_height),
-				Constraint::Max(6), // Bottom bar
+				Constraint::Max(bottom_bar_height), // Bottom bar
 			])
 			.horizontal_margin(2)
 			.split(size);
-		let banner = Paragraph::new(BANNER).block(untitled_ui_block()).alignment(Alignment::Center);
-		frame.render_widget(banner, chunks[0]);
+		if !layout_size.is_compact() {
+			let tagline = Line::from(self.tagline.visible()).alignment(Alignment::Center);
+			render_banner(
+				frame,
+				chunks[0],
+				BANNER,
+				untitled_ui_block().title_bottom(tagline),
+			);
+		}
 		self.controls_list.render(frame, chunks[1]);
-		render_welcome_bottom_bar(frame, chunks[3]);
+		if bottom_bar_height > 0 {
+			render_welcome_bottom_bar(frame, chunks[3], layout_size, update_notice.as_deref(), tip);
+		}
 	}
 }
 
 impl WelcomeScreen {
+	/// Creates the welcome screen for a connection of the [telnet
+	/// server](crate::services::telnet), which can't change the server's
+	/// settings or files.
+	#[must_use]
+	pub fn served() -> Self {
+		Self {
+			served: true,
+			..Self::default()
+		}
+	}
+
 	/// Handles the ENTER shortcut, which executes the function that the UI
 	/// selector is pointing at.
 	fn handle_enter_shortcut(&mut self, state: &mut ScreenState) {
 		if let Some((_, item)) = self.controls_list.get_selected() {
 			match item.data {
+				ControlOptions::SearchGames if self.served => {
+					state.create_screen(GameSearchScreen::served().into());
+				},
 				ControlOptions::SearchGames => {
-					state.set_screen_created(GameSearchScreen::default().into());
+					state.create_screen(GameSearchScreen::default().into());
+				},
+				// Builds without games and no scripted ones have nothing to pick.
+				ControlOptions::SurpriseMe if !Games::all().is_empty() => {
+					state.create_screen(RoulettePopup::default().into());
+				},
+				ControlOptions::SurpriseMe => {},
+				ControlOptions::ViewConfigs if self.served => {
+					state.create_screen(ConfigScreen::served().into());
+				},
+				ControlOptions::ViewConfigs => {
+					state.create_screen(ConfigScreen::default().into());
+				},
+				ControlOptions::ViewAbout => {
+					state.create_screen(AboutScreen::default().into());
 				},
-				ControlOptions::ViewConfigs => state.set_screen_created(ConfigScreen.into()),
 				ControlOptions::QuitApplication => state.open_status = OpenStatus::Closed,
 			}
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use insta::{
+		assert_snapshot,
+		with_settings,
+	};
+
+	use super::WelcomeScreen;
+	use crate::core::{
+		config::config_file_path,
+		events::KeyCode,
+		files,
+		harness::Harness,
+	};
+
+	#[test]
+	fn welcome_screen_snapshot() {
+		files::isolate_test_files();
+		let mut harness = Harness::new(WelcomeScreen::default().into(), 120, 40);
+		harness.press(KeyCode::Char('d'));
+		harness.run_frames(60);
+		with_settings!({
+			filters => vec![
+				(r".*⏰ Time: .*", "[time]"),
+				(r".*, commit hash .*", "[version, branch and commit hash]"),
+			]
+		}, {
+			assert_snapshot!(harness.text());
+		});
+	}
+
+	#[test]
+	fn welcome_screen_compact_snapshot() {
+		files::isolate_test_files();
+		let mut harness = Harness::new(WelcomeScreen::default().into(), 60, 20);
+		harness.press(KeyCode::Char('d'));
+		assert_snapshot!(harness.text());
+	}
+
+	#[test]
+	fn served_settings_are_only_shown() {
+		files::isolate_test_files();
+		let mut harness = Harness::new(WelcomeScreen::served().into(), 120, 40);
+		for _ in 0..3 {
+			harness.press(KeyCode::Down);
+		}
+		harness.press(KeyCode::Enter);
+		assert_eq!(harness.active_title(), Some("Settings"));
+		assert!(harness.contains("These settings are the server's"));
+
+		harness.press(KeyCode::Down);
+		harness.press(KeyCode::Enter);
+		harness.press(KeyCode::Char('r'));
+		assert_eq!(harness.active_title(), Some("Settings"));
+		assert!(!config_file_path().exists());
+	}
+}
@@ -0,0 +1,219 @@
+//! Search across Terminal Arcade: games, settings and the topics of help
+//! pages. Results are typed, and know which screen they open when selected.
+
+use std::fmt::{
+	Display,
+	Formatter,
+};
+
+use strum::IntoEnumIterator;
+
+use crate::{
+	games::{
+		Game,
+		Games,
+	},
+	t,
+	ui::{
+		screens::{
+			config::Setting,
+			ConfigScreen,
+			HelpScreen,
+			Screens,
+		},
+		widgets::scrollable_list::ListItem,
+	},
+};
+
+/// A section of a help page, from one of its `##` headings to the next.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HelpTopic {
+	/// Title of the page the topic is in.
+	pub page_title: String,
+
+	/// Title of the topic.
+	pub title: String,
+
+	/// Markdown source of the topic, heading included.
+	pub source: String,
+}
+
+impl HelpTopic {
+	/// Returns whether the topic's title or text contains a keyword.
+	#[must_use]
+	pub fn matches_keyword(&self, keyword: &str) -> bool {
+		let keyword = keyword.trim().to_lowercase();
+		self.source.to_lowercase().contains(&keyword)
+	}
+
+	/// Returns the topic's first line of text, to preview it.
+	#[must_use]
+	pub fn preview(&self) -> &str {
+		self.source
+			.lines()
+			.skip(1)
+			.map(str::trim)
+			.find(|line| !line.is_empty() && !line.starts_with("```"))
+			.unwrap_or_default()
+	}
+}
+
+/// Returns the bundled help pages: every game's, then the scripting manual
+/// with the `scripting` feature.
+#[must_use]
+pub fn help_pages() -> Vec<&'static str> {
+	let pages = Games::all().into_iter().filter_map(|game| game.help_page());
+	#[cfg(feature = "scripting")]
+	let pages = pages.chain([crate::games::scripted::HELP_PAGE]);
+	let mut help_pages = Vec::new();
+	for page in pages {
+		if !help_pages.contains(&page) {
+			help_pages.push(page);
+		}
+	}
+	help_pages
+}
+
+/// Splits a help page into its topics, skipping headings in code blocks.
+#[must_use]
+pub fn help_topics(page: &str) -> Vec<HelpTopic> {
+	let page_title = page
+		.lines()
+		.find_map(|line| line.strip_prefix("# "))
+		.unwrap_or_default()
+		.trim()
+		.to_string();
+	let mut topics: Vec<HelpTopic> = Vec::new();
+	let mut in_code_block = false;
+	for line in page.lines() {
+		if line.trim_start().starts_with("```") {
+			in_code_block = !in_code_block;
+		}
+		if let Some(title) = line.strip_prefix("## ").filter(|_| !in_code_block) {
+			topics.push(HelpTopic {
+				page_title: page_title.clone(),
+				title: title.trim().to_string(),
+				source: String::new(),
+			});
+		}
+		if let Some(topic) = topics.last_mut() {
+			topic.source.push_str(line);
+			topic.source.push('\n');
+		}
+	}
+	topics
+}
+
+/// Something found by a search.
+#[derive(Clone)]
+pub enum SearchResult {
+	/// A game, launched when selected.
+	Game(Games),
+
+	/// A setting, selected in the settings screen when selected.
+	Setting(Setting),
+
+	/// A topic of a help page, shown when selected.
+	HelpTopic(HelpTopic),
+}
+
+impl SearchResult {
+	/// Returns the settings and help topics matching a keyword. Games are
+	/// searched with [`Games::get_by_keyword`].
+	#[must_use]
+	pub fn search_everything_else(keyword: &str) -> Vec<Self> {
+		let keyword_lowercase = keyword.trim().to_lowercase();
+		let settings = Setting::iter()
+			.filter(|setting| setting.label().to_lowercase().contains(&keyword_lowercase))
+			.map(Self::Setting);
+		let topics = help_pages()
+			.into_iter()
+			.flat_map(help_topics)
+			.filter(|topic| topic.matches_keyword(keyword))
+			.map(Self::HelpTopic);
+		settings.chain(topics).collect()
+	}
+
+	/// Returns the result as an entry of a list.
+	pub fn get_list_entry(&self) -> ListItem<Self> {
+		match self {
+			Self::Game(game) => {
+				let entry = game.data().metadata.get_list_entry();
+				ListItem::new(entry.name, self.clone(), entry.displayed_content)
+					.with_badge(entry.badge)
+			},
+			Self::Setting(setting) => ListItem::new(
+				Some(t!("search-kind-setting").to_string()),
+				self.clone(),
+				Some(format!(
+					"{}\n{}",
+					setting.label(),
+					t!("search-setting-hint")
+				)),
+			),
+			Self::HelpTopic(topic) => ListItem::new(
+				Some(t!("search-kind-help").to_string()),
+				self.clone(),
+				Some(format!(
+					"{} › {}\n{}",
+					topic.page_title,
+					topic.title,
+					topic.preview()
+				)),
+			),
+		}
+	}
+
+	/// Returns the screen opened when the result is selected.
+	#[must_use]
+	pub fn screen(&self) -> Option<Screens> {
+		match self {
+			Self::Game(game) => game.launch_screen(),
+			Self::Setting(setting) => Some(ConfigScreen::with_selected(*setting).into()),
+			Self::HelpTopic(topic) => Some(HelpScreen::new(&topic.source).into()),
+		}
+	}
+}
+
+impl Display for SearchResult {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Game(game) => game.fmt(f),
+			Self::Setting(setting) => f.write_str(setting.label()),
+			Self::HelpTopic(topic) => f.write_str(&topic.title),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::help_topics;
+
+	#[test]
+	fn help_pages_split_into_topics() {
+		let page = [
+			"# Game",
+			"",
+			"Intro.",
+			"",
+			"## Rules",
+			"",
+			"Win.",
+			"",
+			"```md",
+			"## Not a topic",
+			"```",
+			"",
+			"## Controls",
+			"",
+			"- Keys",
+		]
+		.join("\n");
+		let topics = help_topics(&page);
+		assert_eq!(topics.len(), 2);
+		assert_eq!(topics[0].page_title, "Game");
+		assert_eq!(topics[0].title, "Rules");
+		assert!(topics[0].source.contains("## Not a topic"));
+		assert_eq!(topics[1].preview(), "- Keys");
+	}
+}
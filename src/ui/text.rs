@@ -0,0 +1,157 @@
+//! Text measured in terminal columns rather than in characters, so that
+//! strings mixing emoji or CJK characters, which take up two columns each,
+//! are truncated, centered and wrapped without pushing borders out of line.
+
+use unicode_width::{
+	UnicodeWidthChar,
+	UnicodeWidthStr,
+};
+
+/// Ellipsis ending truncated text.
+pub const ELLIPSIS: char = '…';
+
+/// Returns how many columns a string takes up on the terminal.
+#[must_use]
+pub fn width(text: &str) -> usize {
+	text.width()
+}
+
+/// Returns how many columns a string takes up on the terminal, as a terminal
+/// size.
+#[must_use]
+pub fn width_u16(text: &str) -> u16 {
+	u16::try_from(width(text)).unwrap_or(u16::MAX)
+}
+
+/// Returns the longest start of a string that fits in a width, without
+/// splitting a wide character.
+fn fitting(text: &str, width: usize) -> &str {
+	let mut used = 0;
+	for (index, char) in text.char_indices() {
+		used += char.width().unwrap_or(0);
+		if used > width {
+			return &text[..index];
+		}
+	}
+	text
+}
+
+/// Truncates a string to a width, ending it with an [ellipsis](ELLIPSIS) if
+/// it didn't fit.
+#[must_use]
+pub fn truncate(text: &str, width: usize) -> String {
+	if self::width(text) <= width {
+		return text.to_string();
+	}
+	if width == 0 {
+		return String::new();
+	}
+	format!("{}{ELLIPSIS}", fitting(text, width - 1).trim_end())
+}
+
+/// Pads a string with spaces to a width, or truncates it if it's wider.
+#[must_use]
+pub fn pad(text: &str, width: usize) -> String {
+	let text = truncate(text, width);
+	let padding = width - self::width(&text);
+	format!("{text}{}", " ".repeat(padding))
+}
+
+/// Centers a string in a width with spaces on both sides, or truncates it if
+/// it's wider. Odd padding leaves the extra space on the right.
+#[must_use]
+pub fn center(text: &str, width: usize) -> String {
+	let text = truncate(text, width);
+	let padding = width - self::width(&text);
+	format!(
+		"{}{text}{}",
+		" ".repeat(padding / 2),
+		" ".repeat(padding - padding / 2)
+	)
+}
+
+/// Wraps a string to lines of a width, between words where possible. Words
+/// wider than a line are split across lines, and line breaks are kept.
+#[must_use]
+pub fn wrap(text: &str, width: usize) -> Vec<String> {
+	let width = width.max(1);
+	let mut lines = Vec::new();
+	for paragraph in text.lines() {
+		let mut line = String::new();
+		for word in paragraph.split_whitespace() {
+			let separator = usize::from(!line.is_empty());
+			if self::width(&line) + separator + self::width(word) <= width {
+				if separator == 1 {
+					line.push(' ');
+				}
+				line.push_str(word);
+				continue;
+			}
+			if !line.is_empty() {
+				lines.push(std::mem::take(&mut line));
+			}
+			let mut rest = word;
+			while self::width(rest) > width {
+				// A character wider than a line still gets one of its own.
+				let head = match fitting(rest, width) {
+					"" => &rest[..rest.chars().next().map_or(0, char::len_utf8)],
+					head => head,
+				};
+				lines.push(head.to_string());
+				rest = &rest[head.len()..];
+			}
+			line.push_str(rest);
+		}
+		lines.push(line);
+	}
+	lines
+}
+
+/// Wraps a string like [`wrap`], keeping at most a number of lines. The last
+/// line kept ends with an [ellipsis](ELLIPSIS) if lines were left out.
+#[must_use]
+pub fn wrap_clamped(text: &str, width: usize, max_lines: usize) -> Vec<String> {
+	let mut lines = wrap(text, width);
+	if lines.len() > max_lines {
+		lines.truncate(max_lines);
+		if let Some(last) = lines.last_mut() {
+			let kept = fitting(last, width.max(1) - 1).trim_end();
+			*last = format!("{kept}{ELLIPSIS}");
+		}
+	}
+	lines
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		center,
+		pad,
+		truncate,
+		width,
+		wrap,
+		wrap_clamped,
+	};
+
+	#[test]
+	fn wide_characters_take_two_columns() {
+		assert_eq!(width("🎮 Games"), 8);
+		assert_eq!(truncate("🎮 Games", 8), "🎮 Games");
+		assert_eq!(truncate("🎮 Games", 4), "🎮…");
+		assert_eq!(truncate("日本語", 4), "日…");
+		assert_eq!(truncate("日本語", 3), "日…");
+		assert_eq!(truncate("日本語", 2), "…");
+		assert_eq!(pad("日本", 6), "日本  ");
+		assert_eq!(center("🎮", 5), " 🎮  ");
+		assert_eq!(wrap("🏆 You won the round!", 10), [
+			"🏆 You won",
+			"the round!"
+		]);
+		assert_eq!(wrap("日本語日本語", 5), ["日本", "語日", "本語"]);
+		assert_eq!(wrap("one\n\ntwo", 10), ["one", "", "two"]);
+		assert_eq!(wrap_clamped("a race of light cycles", 6, 2), [
+			"a race", "of…"
+		]);
+		assert_eq!(wrap_clamped("light cycles", 6, 2), ["light", "cycles"]);
+	}
+}
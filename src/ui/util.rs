@@ -2,23 +2,22 @@
 
 #![allow(clippy::needless_pass_by_value)]
 
-use std::time::{
-	SystemTime,
-	UNIX_EPOCH,
-};
-
-use crossterm::{
-	execute,
-	terminal::{
-		Clear,
-		ClearType,
-	},
-};
 use ratatui::text::{
 	Line,
 	Text,
 };
 
+use crate::core::time::{
+	SystemTime,
+	UNIX_EPOCH,
+};
+
+/// Gets the authors of the crate, as listed in its manifest.
+#[must_use]
+pub fn get_crate_authors() -> Vec<String> {
+	env!("CARGO_PKG_AUTHORS").split(':').map(str::to_string).collect()
+}
+
 /// Gets the version of the crate, or returns "NOT.FOUND" if one
 /// was unable to be retrieved.
 /// TODO: Move to footer where this information is used
@@ -26,6 +25,6 @@ use ratatui::text::{
 pub fn get_crate_version() -> String {
 	format!(
 		"v{}",
-		std::env::var("CARGO_PKG_VERSION").unwrap_or_else(|_| "NOT.FOUND".to_string())
+		option_env!("CARGO_PKG_VERSION").unwrap_or("NOT.FOUND")
 	)
 }
@@ -0,0 +1,330 @@
+//! A chat pane for multiplayer sessions. See [`ChatPane`] for more.
+
+use ratatui::{
+	layout::{
+		Constraint,
+		Direction,
+		Layout,
+		Rect,
+	},
+	style::{
+		Color,
+		Modifier,
+		Style,
+	},
+	text::{
+		Line,
+		Span,
+	},
+	widgets::{
+		Paragraph,
+		Wrap,
+	},
+	Frame,
+};
+
+use crate::{
+	core::{
+		events::{
+			Event,
+			KeyCode,
+			KeyModifiers,
+		},
+		profile::{
+			Profile,
+			MAX_NAME_LENGTH,
+		},
+	},
+	t,
+	ui::{
+		color_scheme::{
+			SUNGLOW,
+			TROPICAL_INDIGO,
+		},
+		components::presets::{
+			highlight_block,
+			titled_ui_block,
+			untitled_ui_block,
+		},
+		text,
+		widgets::{
+			utils::controls_table::{
+				Control,
+				ControlsEntries,
+				KeyControl,
+			},
+			Widget,
+			WidgetFocus,
+			WidgetState,
+		},
+	},
+};
+
+/// Maximum length of a message, in characters.
+pub const MAX_MESSAGE_LENGTH: usize = 200;
+
+/// Widest a message's author is shown, in terminal columns: a
+/// [profile](crate::core::profile)'s avatar and name.
+pub const MAX_AUTHOR_WIDTH: usize = MAX_NAME_LENGTH + 3;
+
+/// Number of messages kept in the scrollback.
+pub const SCROLLBACK_LENGTH: usize = 100;
+
+/// A message in the chat.
+#[derive(Debug, Clone)]
+pub struct ChatMessage {
+	/// Name of the player who sent the message.
+	pub author: String,
+
+	/// What the message says.
+	pub text: String,
+
+	/// Whether this player sent the message.
+	pub own: bool,
+}
+
+/// A chat pane, either collapsed into a single line telling how many messages
+/// arrived since it was last expanded, or expanded into the scrollback and an
+/// input line. Messages typed in are [taken](Self::take_sent) by the screen to
+/// be sent over the session.
+#[derive(Clone, Default)]
+#[must_use]
+pub struct ChatPane {
+	/// Messages in the scrollback, oldest first.
+	messages: Vec<ChatMessage>,
+
+	/// Message being typed in.
+	input: String,
+
+	/// Whether the pane is expanded.
+	expanded: bool,
+
+	/// Messages that arrived while the pane was collapsed.
+	unread: usize,
+
+	/// Number of messages scrolled back from the latest one.
+	scroll: usize,
+
+	/// Messages typed in and not yet taken by the screen.
+	sent: Vec<String>,
+}
+
+impl ChatPane {
+	/// Returns whether the pane is expanded.
+	#[must_use]
+	pub fn is_expanded(&self) -> bool {
+		self.expanded
+	}
+
+	/// Expands or collapses the pane, like when it gains or loses focus.
+	/// Expanding it marks every message as read.
+	pub fn set_expanded(&mut self, expanded: bool) {
+		self.expanded = expanded;
+		if self.expanded {
+			self.unread = 0;
+		}
+	}
+
+	/// Returns the number of messages that arrived while the pane was
+	/// collapsed.
+	#[must_use]
+	pub fn unread(&self) -> usize {
+		self.unread
+	}
+
+	/// Adds a message received from another player, cutting its author's name
+	/// to [`MAX_AUTHOR_WIDTH`] and its text to [`MAX_MESSAGE_LENGTH`].
+	pub fn receive(&mut self, author: &str, text: &str) {
+		self.push(ChatMessage {
+			author: text::truncate(author, MAX_AUTHOR_WIDTH),
+			text: text.chars().take(MAX_MESSAGE_LENGTH).collect(),
+			own: false,
+		});
+		if !self.expanded {
+			self.unread += 1;
+		}
+	}
+
+	/// Takes the messages typed in since last taken, to be sent.
+	pub fn take_sent(&mut self) -> Vec<String> {
+		std::mem::take(&mut self.sent)
+	}
+
+	/// Adds a message to the scrollback, dropping the oldest ones past
+	/// [`SCROLLBACK_LENGTH`].
+	fn push(&mut self, message: ChatMessage) {
+		self.messages.push(message);
+		if self.messages.len() > SCROLLBACK_LENGTH {
+			self.messages.remove(0);
+		}
+	}
+
+	/// Sends the message typed in.
+	fn send_input(&mut self) {
+		let text = std::mem::take(&mut self.input).trim().to_string();
+		if text.is_empty() {
+			return;
+		}
+		self.push(ChatMessage {
+			author: Profile::current().badge(),
+			text: text.clone(),
+			own: true,
+		});
+		self.sent.push(text);
+		self.scroll = 0;
+	}
+
+	/// Returns the line showing a message.
+	fn message_line(message: &ChatMessage) -> Line<'static> {
+		let author_color = if message.own { SUNGLOW } else { TROPICAL_INDIGO };
+		Line::from(vec![
+			Span::styled(
+				format!("{}: ", message.author),
+				Style::new().fg(author_color).add_modifier(Modifier::BOLD),
+			),
+			Span::raw(message.text.clone()),
+		])
+	}
+
+	/// Renders the collapsed pane.
+	fn render_collapsed(&self, frame: &mut Frame<'_>, area: Rect) {
+		let text = t!("chat-collapsed", unread = self.unread);
+		let mut block = untitled_ui_block();
+		if self.unread > 0 {
+			block = highlight_block(block);
+		}
+		frame.render_widget(Paragraph::new(text).block(block), area);
+	}
+
+	/// Renders the expanded pane.
+	fn render_expanded(&self, frame: &mut Frame<'_>, area: Rect) {
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.constraints([Constraint::Min(0), Constraint::Length(3)])
+			.split(area);
+		let scrollback_height = chunks[0].height.saturating_sub(2) as usize;
+		let end = self.messages.len().saturating_sub(self.scroll);
+		// Every message takes a line at least, so older ones can't be shown.
+		let lines: Vec<_> = self.messages[..end]
+			.iter()
+			.rev()
+			.take(scrollback_height)
+			.rev()
+			.map(Self::message_line)
+			.collect();
+		let scrollback = Paragraph::new(lines).wrap(Wrap { trim: false });
+		// Messages wrapping push the latest ones down, so the oldest lines are
+		// scrolled past instead.
+		let overflow = scrollback
+			.line_count(chunks[0].width.saturating_sub(2))
+			.saturating_sub(scrollback_height);
+		let scrollback = scrollback
+			.scroll((u16::try_from(overflow).unwrap_or(u16::MAX), 0))
+			.style(Style::new().fg(Color::White))
+			.block(titled_ui_block(t!("chat-title")));
+		frame.render_widget(scrollback, chunks[0]);
+		let input = Paragraph::new(format!("> {}▏", self.input))
+			.block(highlight_block(untitled_ui_block()));
+		frame.render_widget(input, chunks[1]);
+	}
+}
+
+impl Widget for ChatPane {
+	fn initial_state(&self) -> WidgetState {
+		WidgetState::new(
+			WidgetFocus::Unfocused,
+			ControlsEntries::default()
+				.add(
+					Control::new(None, KeyControl::new_custom("Tab")),
+					t!("chat-toggle"),
+				)
+				.add(
+					Control::new(None, KeyControl::new_custom("Enter")),
+					t!("chat-send"),
+				)
+				.add(
+					Control::new(None, KeyControl::new_custom("PgUp PgDn")),
+					t!("chat-scroll"),
+				),
+		)
+	}
+
+	fn handle_event(&mut self, event: &Event) -> anyhow::Result<()> {
+		let Event::Key(key) = event else {
+			return Ok(());
+		};
+		if !self.expanded {
+			return Ok(());
+		}
+		match key.code {
+			KeyCode::Char(character)
+				if [KeyModifiers::NONE, KeyModifiers::SHIFT].contains(&key.modifiers)
+					&& self.input.chars().count() < MAX_MESSAGE_LENGTH =>
+			{
+				self.input.push(character);
+			},
+			KeyCode::Backspace => {
+				self.input.pop();
+			},
+			KeyCode::Enter => self.send_input(),
+			KeyCode::PageUp => {
+				self.scroll = (self.scroll + 1).min(self.messages.len().saturating_sub(1));
+			},
+			KeyCode::PageDown => self.scroll = self.scroll.saturating_sub(1),
+			_ => {},
+		}
+		Ok(())
+	}
+
+	fn render_ui(&self, frame: &mut Frame<'_>, area: Rect, _state: &WidgetState) {
+		if self.expanded {
+			self.render_expanded(frame, area);
+		} else {
+			self.render_collapsed(frame, area);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use ratatui::{
+		backend::TestBackend,
+		Terminal,
+	};
+
+	use super::{
+		ChatPane,
+		MAX_AUTHOR_WIDTH,
+		MAX_MESSAGE_LENGTH,
+	};
+	use crate::{
+		core::screenshot,
+		ui::text,
+	};
+
+	#[test]
+	fn the_latest_message_shows_when_older_ones_wrap() {
+		let mut chat = ChatPane::default();
+		chat.set_expanded(true);
+		for index in 0..3 {
+			chat.receive("Peer", &format!("message {index} {}", "long ".repeat(10)));
+		}
+		chat.receive("Peer", "latest");
+
+		let mut terminal = Terminal::new(TestBackend::new(30, 10)).unwrap();
+		terminal.draw(|frame| chat.render_expanded(frame, frame.size())).unwrap();
+		let lines = screenshot::buffer_lines(terminal.backend().buffer());
+		assert!(
+			lines.iter().any(|line| line.contains("Peer: latest")),
+			"{lines:#?}"
+		);
+	}
+
+	#[test]
+	fn received_messages_are_cut() {
+		let mut chat = ChatPane::default();
+		chat.receive(&"a".repeat(100), &"b".repeat(1000));
+		assert!(text::width(&chat.messages[0].author) <= MAX_AUTHOR_WIDTH);
+		assert_eq!(chat.messages[0].text.chars().count(), MAX_MESSAGE_LENGTH);
+	}
+}
@@ -0,0 +1,391 @@
+//! A table of data with a header, whose rows can be selected and sorted by any
+//! column, from the keyboard or by clicking the column's header. See
+//! [`DataTable`] for the struct this module exports.
+//!
+//! Columns are given the width of their widest cell, and the widest columns
+//! are narrowed down to their minimum width when the table doesn't fit. Cells
+//! too wide for their column end with an ellipsis.
+
+use std::{
+	cell::Cell as StdCell,
+	cmp::Ordering,
+};
+
+use derive_new::new;
+use ratatui::{
+	layout::{
+		Constraint,
+		Rect,
+	},
+	style::{
+		Modifier,
+		Style,
+	},
+	widgets::{
+		Block,
+		Cell,
+		Row,
+		TableState,
+	},
+	Frame,
+};
+
+use crate::{
+	core::events::{
+		Event,
+		MouseButton,
+		MouseEventKind,
+	},
+	ui::{
+		components::presets::{
+			striped_table,
+			HIGHLIGHTED,
+		},
+		text,
+	},
+};
+
+/// Spacing between columns, in columns.
+const COLUMN_SPACING: u16 = 2;
+
+/// A column of a [`DataTable`].
+#[derive(Debug, Clone, new)]
+#[must_use]
+pub struct Column {
+	/// Header of the column.
+	pub header: String,
+
+	/// Narrowest the column gets when the table doesn't fit.
+	pub min_width: u16,
+
+	/// Widest the column gets, if limited.
+	#[new(default)]
+	pub max_width: Option<u16>,
+}
+
+impl Column {
+	/// Limits how wide the column gets.
+	pub fn with_max_width(mut self, max_width: u16) -> Self {
+		self.max_width = Some(max_width);
+		self
+	}
+}
+
+/// A row of a [`DataTable`]: a cell per column, and data to tell which row it
+/// is once sorted.
+#[derive(Debug, Clone, new)]
+#[must_use]
+pub struct DataRow<D> {
+	/// Text of the row's cells, by column.
+	pub cells: Vec<String>,
+
+	/// Data associated with this row.
+	pub data: D,
+}
+
+/// The order rows are sorted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+	/// Smallest first.
+	Ascending,
+
+	/// Largest first.
+	Descending,
+}
+
+/// Compares cells as numbers if both are, or else as text.
+fn compare_cells(a: &str, b: &str) -> Ordering {
+	match (a.parse::<f64>(), b.parse::<f64>()) {
+		(Ok(a), Ok(b)) => a.total_cmp(&b),
+		_ => a.to_lowercase().cmp(&b.to_lowercase()),
+	}
+}
+
+/// See the [module](self) documentation for more information.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct DataTable<D> {
+	/// The table's columns.
+	columns: Vec<Column>,
+
+	/// The table's rows, in the order they were given.
+	rows: Vec<DataRow<D>>,
+
+	/// Indices of the rows, in the order they're shown.
+	order: Vec<usize>,
+
+	/// The column the rows are sorted by and in what order, if sorted.
+	sort: Option<(usize, SortOrder)>,
+
+	/// Position of the selected row in the order shown, if any.
+	selected: Option<usize>,
+
+	/// Area of the header when last rendered, to tell which column is
+	/// clicked.
+	header_area: StdCell<Rect>,
+}
+
+impl<D: Clone + PartialEq> DataTable<D> {
+	/// Creates a table of rows, unsorted and with nothing selected.
+	pub fn new(columns: Vec<Column>, rows: Vec<DataRow<D>>) -> Self {
+		let mut table = Self {
+			columns,
+			rows: Vec::new(),
+			order: Vec::new(),
+			sort: None,
+			selected: None,
+			header_area: StdCell::default(),
+		};
+		table.update_rows(rows);
+		table
+	}
+
+	/// Replaces the rows, keeping the sort and the selected row if it's still
+	/// there.
+	pub fn update_rows(&mut self, rows: Vec<DataRow<D>>) {
+		let selected = self.get_selected().map(|row| row.data.clone());
+		self.rows = rows;
+		self.sort_rows();
+		let last = self.rows.len().saturating_sub(1);
+		self.selected = selected
+			.and_then(|data| {
+				self.position_of(&data).or_else(|| self.selected.map(|selected| selected.min(last)))
+			})
+			.filter(|_| !self.rows.is_empty());
+	}
+
+	/// Returns the selected row, if any.
+	#[must_use]
+	pub fn get_selected(&self) -> Option<&DataRow<D>> {
+		Some(&self.rows[*self.order.get(self.selected?)?])
+	}
+
+	/// Selects the row holding some data, if there's one.
+	pub fn select(&mut self, data: &D) {
+		if let Some(position) = self.position_of(data) {
+			self.selected = Some(position);
+		}
+	}
+
+	/// Selects the row above the selected one, or the last one if none is.
+	pub fn select_previous(&mut self) {
+		self.selected = match self.selected {
+			Some(selected) => Some(selected.saturating_sub(1)),
+			None => self.rows.len().checked_sub(1),
+		};
+	}
+
+	/// Selects the row below the selected one, or the first one if none is.
+	pub fn select_next(&mut self) {
+		let last = self.rows.len().checked_sub(1);
+		self.selected =
+			last.map(|last| self.selected.map_or(0, |selected| (selected + 1).min(last)));
+	}
+
+	/// Returns the column the rows are sorted by and in what order, if sorted.
+	#[must_use]
+	pub fn sort(&self) -> Option<(usize, SortOrder)> {
+		self.sort
+	}
+
+	/// Sorts the rows by a column, in ascending order, or in descending order
+	/// if they already are in ascending order by it.
+	pub fn sort_by(&mut self, column: usize) {
+		let order = match self.sort {
+			Some((sorted, SortOrder::Ascending)) if sorted == column => SortOrder::Descending,
+			_ => SortOrder::Ascending,
+		};
+		self.set_sort(Some((column, order)));
+	}
+
+	/// Moves on to the next way of sorting the rows: by each column in
+	/// ascending then descending order, then unsorted.
+	pub fn cycle_sort(&mut self) {
+		let sort = match self.sort {
+			None => Some((0, SortOrder::Ascending)),
+			Some((column, SortOrder::Ascending)) => Some((column, SortOrder::Descending)),
+			Some((column, SortOrder::Descending)) => {
+				(column + 1 < self.columns.len()).then_some((column + 1, SortOrder::Ascending))
+			},
+		};
+		self.set_sort(sort);
+	}
+
+	/// Sorts the rows a way, keeping the selected row.
+	fn set_sort(&mut self, sort: Option<(usize, SortOrder)>) {
+		let selected = self.get_selected().map(|row| row.data.clone());
+		self.sort = sort.filter(|(column, _)| *column < self.columns.len());
+		self.sort_rows();
+		if let Some(data) = selected {
+			self.select(&data);
+		}
+	}
+
+	/// Sorts [`Self::order`] by the sort set.
+	fn sort_rows(&mut self) {
+		self.order = (0..self.rows.len()).collect();
+		let Some((column, order)) = self.sort else {
+			return;
+		};
+		let rows = &self.rows;
+		self.order.sort_by(|&a, &b| {
+			let ordering = compare_cells(&rows[a].cells[column], &rows[b].cells[column]);
+			match order {
+				SortOrder::Ascending => ordering,
+				SortOrder::Descending => ordering.reverse(),
+			}
+		});
+	}
+
+	/// Returns the position in the order shown of the row holding some data.
+	fn position_of(&self, data: &D) -> Option<usize> {
+		self.order.iter().position(|&index| self.rows[index].data == *data)
+	}
+
+	/// Sorts the rows by the column whose header was clicked, if one was,
+	/// returning whether one was.
+	pub fn handle_event(&mut self, event: &Event) -> bool {
+		let Event::Mouse(mouse) = event else {
+			return false;
+		};
+		let header_area = self.header_area.get();
+		if mouse.kind != MouseEventKind::Down(MouseButton::Left)
+			|| mouse.row != header_area.y
+			|| !(header_area.left()..header_area.right()).contains(&mouse.column)
+		{
+			return false;
+		}
+		let mut right = header_area.x;
+		for (column, width) in self.column_widths(header_area.width).into_iter().enumerate() {
+			right += width + COLUMN_SPACING;
+			if mouse.column < right {
+				self.sort_by(column);
+				return true;
+			}
+		}
+		false
+	}
+
+	/// Returns the width of each column in a table of a width, negotiated
+	/// from the width of their cells.
+	#[must_use]
+	pub fn column_widths(&self, width: u16) -> Vec<u16> {
+		let mut widths: Vec<u16> = self
+			.columns
+			.iter()
+			.enumerate()
+			.map(|(index, column)| {
+				let widest = self
+					.rows
+					.iter()
+					.map(|row| row.cells.get(index).map_or(0, |cell| text::width(cell)))
+					.chain([text::width(&column.header) + 2])
+					.max()
+					.unwrap_or_default();
+				let widest = u16::try_from(widest).unwrap_or(u16::MAX);
+				widest.min(column.max_width.unwrap_or(u16::MAX)).max(column.min_width)
+			})
+			.collect();
+		let spacing = COLUMN_SPACING * u16::try_from(widths.len().saturating_sub(1)).unwrap_or(0);
+		let mut total = widths.iter().sum::<u16>() + spacing;
+		while total > width {
+			let Some((index, _)) = widths
+				.iter()
+				.enumerate()
+				.filter(|(index, &width)| width > self.columns[*index].min_width)
+				.max_by_key(|(_, &width)| width)
+			else {
+				break;
+			};
+			widths[index] -= 1;
+			total -= 1;
+		}
+		widths
+	}
+
+	/// Renders the table in a block, the selected row highlighted.
+	pub fn render(&self, frame: &mut Frame<'_>, area: Rect, block: Block<'_>) {
+		let inner = block.inner(area);
+		self.header_area.set(Rect { height: 1, ..inner });
+		let header = self
+			.columns
+			.iter()
+			.enumerate()
+			.map(|(index, column)| {
+				let arrow = match self.sort {
+					Some((sorted, SortOrder::Ascending)) if sorted == index => " ▲",
+					Some((sorted, SortOrder::Descending)) if sorted == index => " ▼",
+					_ => "",
+				};
+				Cell::new(format!("{}{arrow}", column.header))
+			})
+			.collect::<Row<'_>>()
+			.style(HIGHLIGHTED.add_modifier(Modifier::UNDERLINED));
+		let widths = self.column_widths(inner.width);
+		let rows = self.order.iter().map(|&index| {
+			self.rows[index]
+				.cells
+				.iter()
+				.zip(&widths)
+				.map(|(cell, width)| Cell::new(text::truncate(cell, usize::from(*width))))
+				.collect::<Row<'_>>()
+		});
+		let constraints = widths.iter().copied().map(Constraint::Length);
+		let table = striped_table(rows, constraints)
+			.header(header)
+			.block(block)
+			.column_spacing(COLUMN_SPACING)
+			.highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+		let mut state = TableState::default().with_selected(self.selected);
+		frame.render_stateful_widget(table, area, &mut state);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{
+		Column,
+		DataRow,
+		DataTable,
+	};
+
+	fn table() -> DataTable<u8> {
+		DataTable::new(
+			vec![
+				Column::new("Name".to_string(), 4),
+				Column::new("Score".to_string(), 5),
+			],
+			[("Tron", "9"), ("minesweeper", "10"), ("Chess", "2")]
+				.into_iter()
+				.zip(0..)
+				.map(|((name, score), data)| {
+					DataRow::new(vec![name.to_string(), score.to_string()], data)
+				})
+				.collect(),
+		)
+	}
+
+	#[test]
+	fn rows_sort_by_columns_keeping_the_selection() {
+		let mut table = table();
+		table.select_next();
+		assert_eq!(table.get_selected().map(|row| row.data), Some(0));
+		table.sort_by(1);
+		assert_eq!(table.order, [2, 0, 1]);
+		table.sort_by(1);
+		assert_eq!(table.order, [1, 0, 2]);
+		table.cycle_sort();
+		assert_eq!(table.order, [0, 1, 2]);
+		table.sort_by(0);
+		assert_eq!(table.order, [2, 1, 0]);
+		assert_eq!(table.get_selected().map(|row| row.data), Some(0));
+	}
+
+	#[test]
+	fn widest_columns_narrow_down_first() {
+		let table = table();
+		assert_eq!(table.column_widths(100), [11, 7]);
+		assert_eq!(table.column_widths(15), [7, 6]);
+		assert_eq!(table.column_widths(5), [4, 5]);
+	}
+}
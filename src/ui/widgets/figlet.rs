@@ -0,0 +1,142 @@
+//! Module for rendering large text with [figlet fonts](FigletFont).
+//!
+//! Only the parts of the figlet font format needed for the
+//! [bundled fonts](BUNDLED_FONTS) are supported: characters are always laid out
+//! at full width, without kerning or smushing.
+
+use std::{
+	collections::HashMap,
+	sync::LazyLock,
+};
+
+use anyhow::{
+	anyhow,
+	bail,
+};
+
+/// Fonts bundled with Terminal Arcade, from the largest to the smallest.
+pub static BUNDLED_FONTS: LazyLock<Vec<FigletFont>> = LazyLock::new(|| {
+	[
+		include_str!("../../../assets/fonts/wide.flf"),
+		include_str!("../../../assets/fonts/block.flf"),
+		include_str!("../../../assets/fonts/mini.flf"),
+	]
+	.into_iter()
+	.map(|source| FigletFont::parse(source).expect("bundled fonts should be valid"))
+	.collect()
+});
+
+/// Signature at the start of every figlet font's header.
+const SIGNATURE: &str = "flf2a";
+
+/// A figlet font, mapping characters to multi-line glyphs.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct FigletFont {
+	/// Height of every glyph, in lines.
+	height: usize,
+
+	/// Glyphs of the font, each made of [`height`](Self::height) lines.
+	glyphs: HashMap<char, Vec<String>>,
+}
+
+impl FigletFont {
+	/// Parses a font from the contents of a `.flf` file.
+	pub fn parse(source: &str) -> anyhow::Result<Self> {
+		let mut lines = source.lines();
+		let header = lines.next().ok_or_else(|| anyhow!("Font is empty"))?;
+		let Some(hardblank) = header.strip_prefix(SIGNATURE).and_then(|rest| rest.chars().next())
+		else {
+			bail!("Font header does not start with {SIGNATURE}");
+		};
+		let mut parameters = header.split_whitespace().skip(1).map(str::parse::<i64>);
+		let mut next_parameter = |name: &str| -> anyhow::Result<i64> {
+			parameters
+				.next()
+				.ok_or_else(|| anyhow!("Font header is missing the {name}"))?
+				.map_err(|err| anyhow!("Font header has an invalid {name}: {err}"))
+		};
+		let height = usize::try_from(next_parameter("height")?)?;
+		let _baseline = next_parameter("baseline")?;
+		let _max_length = next_parameter("max length")?;
+		let _old_layout = next_parameter("old layout")?;
+		let comment_lines = usize::try_from(next_parameter("comment line count")?)?;
+
+		let mut lines = lines.skip(comment_lines);
+		let mut glyphs = HashMap::new();
+		for code in ' '..='~' {
+			glyphs.insert(code, Self::parse_glyph(&mut lines, height, hardblank)?);
+		}
+		while let Some(tag) = lines.next() {
+			let Some(code) = Self::parse_code_tag(tag) else {
+				break;
+			};
+			glyphs.insert(code, Self::parse_glyph(&mut lines, height, hardblank)?);
+		}
+		Ok(Self { height, glyphs })
+	}
+
+	/// Parses the lines of a single glyph, stripping their endmarks.
+	fn parse_glyph<'a>(
+		lines: &mut impl Iterator<Item = &'a str>,
+		height: usize,
+		hardblank: char,
+	) -> anyhow::Result<Vec<String>> {
+		(0..height)
+			.map(|_| {
+				let line =
+					lines.next().ok_or_else(|| anyhow!("Font ends in the middle of a glyph"))?;
+				let endmark = line.chars().last().ok_or_else(|| anyhow!("Glyph line is empty"))?;
+				Ok(line.trim_end_matches(endmark).replace(hardblank, " "))
+			})
+			.collect()
+	}
+
+	/// Parses the character code at the start of a code-tagged glyph, in
+	/// decimal, octal (`0` prefix) or hexadecimal (`0x` prefix).
+	fn parse_code_tag(tag: &str) -> Option<char> {
+		let code = tag.split_whitespace().next()?;
+		let code = if let Some(hex) = code.strip_prefix("0x").or(code.strip_prefix("0X")) {
+			u32::from_str_radix(hex, 16).ok()?
+		} else if code.len() > 1 && code.starts_with('0') {
+			u32::from_str_radix(&code[1..], 8).ok()?
+		} else {
+			code.parse().ok()?
+		};
+		char::from_u32(code)
+	}
+
+	/// Returns the height of the font, in lines.
+	#[must_use]
+	pub fn height(&self) -> usize {
+		self.height
+	}
+
+	/// Returns whether the font has a glyph for a character.
+	#[must_use]
+	pub fn has_glyph(&self, char: char) -> bool {
+		self.glyphs.contains_key(&char)
+	}
+
+	/// Renders a line of text, returning [`height`](Self::height) lines.
+	/// Characters missing from the font are skipped.
+	#[must_use]
+	pub fn render(&self, text: &str) -> Vec<String> {
+		let mut rendered = vec![String::new(); self.height];
+		for glyph in text.chars().filter_map(|char| self.glyphs.get(&char)) {
+			for (line, glyph_line) in rendered.iter_mut().zip(glyph) {
+				line.push_str(glyph_line);
+			}
+		}
+		rendered
+	}
+
+	/// Returns the width of a line of text once rendered, in characters.
+	#[must_use]
+	pub fn width(&self, text: &str) -> usize {
+		text.chars()
+			.filter_map(|char| self.glyphs.get(&char))
+			.map(|glyph| glyph.iter().map(|line| line.chars().count()).max().unwrap_or_default())
+			.sum()
+	}
+}
@@ -0,0 +1,331 @@
+//! Module for rendering [images](TerminalImage) in the terminal.
+//!
+//! Images are drawn with the terminal's graphics protocol when one is
+//! [detected](GraphicsProtocol::detect), and with colored half blocks
+//! otherwise. Only available with the `images` feature.
+
+use std::{
+	cell::RefCell,
+	env,
+	fmt::Write as _,
+	io::Write as _,
+	sync::LazyLock,
+};
+
+use base64::{
+	engine::general_purpose::STANDARD,
+	Engine,
+};
+use image::{
+	imageops::{
+		resize,
+		FilterType,
+	},
+	RgbaImage,
+};
+use ratatui::{
+	buffer::Buffer,
+	layout::Rect,
+	style::Color,
+	Frame,
+};
+
+use crate::core::handler::Terminal;
+
+/// Assumed size of a terminal cell in pixels, for protocols that draw images
+/// pixel by pixel.
+const CELL_SIZE_PX: (u32, u32) = (10, 20);
+
+/// Maximum length of a single chunk of image data sent with the kitty
+/// protocol.
+const KITTY_CHUNK_SIZE: usize = 4096;
+
+/// Levels per color channel in the palette used for sixel images.
+const SIXEL_LEVELS: u32 = 6;
+
+/// The graphics protocol detected for the current terminal.
+pub static GRAPHICS_PROTOCOL: LazyLock<GraphicsProtocol> = LazyLock::new(GraphicsProtocol::detect);
+
+/// A way to draw images in the terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphicsProtocol {
+	/// The kitty graphics protocol, also supported by `WezTerm` and Ghostty.
+	Kitty,
+
+	/// iTerm2's inline images protocol.
+	Iterm2,
+
+	/// Sixel graphics, supported by terminals like foot, mlterm and xterm.
+	Sixel,
+
+	/// Colored half blocks, one pixel per half of a cell. Works everywhere.
+	HalfBlocks,
+}
+
+impl GraphicsProtocol {
+	/// Detects the graphics protocol supported by the terminal from the
+	/// environment, falling back to [half blocks](Self::HalfBlocks).
+	#[must_use]
+	pub fn detect() -> Self {
+		let var = |name: &str| env::var(name).unwrap_or_default().to_lowercase();
+		let (term, term_program) = (var("TERM"), var("TERM_PROGRAM"));
+		if env::var_os("KITTY_WINDOW_ID").is_some()
+			|| term.contains("kitty")
+			|| term.contains("ghostty")
+			|| term_program == "ghostty"
+		{
+			Self::Kitty
+		} else if term_program == "iterm.app"
+			|| term_program == "wezterm"
+			|| var("LC_TERMINAL") == "iterm2"
+		{
+			Self::Iterm2
+		} else if term.contains("sixel")
+			|| term.starts_with("foot")
+			|| term.starts_with("mlterm")
+			|| term_program == "contour"
+		{
+			Self::Sixel
+		} else {
+			Self::HalfBlocks
+		}
+	}
+}
+
+/// Clears images drawn with a graphics protocol, which could otherwise linger
+/// after the screen they were drawn on is gone.
+pub fn clear_images(terminal: &mut Terminal) -> anyhow::Result<()> {
+	if *GRAPHICS_PROTOCOL == GraphicsProtocol::HalfBlocks {
+		return Ok(());
+	}
+	if *GRAPHICS_PROTOCOL == GraphicsProtocol::Kitty {
+		let backend = terminal.backend_mut();
+		backend.write_all(b"\x1b_Ga=d,q=2\x1b\\")?;
+		backend.flush()?;
+	}
+	terminal.clear()?;
+	Ok(())
+}
+
+/// A PNG image that can be rendered in the terminal.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct TerminalImage {
+	/// The image's PNG data, sent as-is to protocols that support PNG.
+	png: &'static [u8],
+
+	/// The decoded image.
+	image: RgbaImage,
+
+	/// The last escape sequence encoded and the area it was encoded for.
+	encoded: RefCell<Option<(Rect, String)>>,
+}
+
+impl TerminalImage {
+	/// Decodes an image from PNG data.
+	pub fn from_png(png: &'static [u8]) -> anyhow::Result<Self> {
+		Ok(Self {
+			png,
+			image: image::load_from_memory(png)?.to_rgba8(),
+			encoded: RefCell::new(None),
+		})
+	}
+
+	/// Returns the largest area fitting in the given one while keeping the
+	/// image's aspect ratio, centered in it. Cells are assumed to be twice as
+	/// high as they are wide.
+	#[must_use]
+	#[allow(clippy::cast_sign_loss)]
+	pub fn fit(&self, area: Rect) -> Rect {
+		let (width, height) = (
+			f64::from(self.image.width()),
+			f64::from(self.image.height()),
+		);
+		let scale = (f64::from(area.width) / width).min(f64::from(area.height) * 2.0 / height);
+		let fitted_width = ((width * scale) as u16).clamp(1, area.width.max(1));
+		let fitted_height = ((height * scale / 2.0) as u16).clamp(1, area.height.max(1));
+		Rect::new(
+			area.x + (area.width - fitted_width) / 2,
+			area.y + (area.height - fitted_height) / 2,
+			fitted_width,
+			fitted_height,
+		)
+	}
+
+	/// Renders the image, fitted and centered in an area.
+	pub fn render(&self, frame: &mut Frame<'_>, area: Rect) {
+		if area.is_empty() {
+			return;
+		}
+		let area = self.fit(area);
+		let buffer = frame.buffer_mut();
+		if *GRAPHICS_PROTOCOL == GraphicsProtocol::HalfBlocks {
+			self.render_half_blocks(buffer, area);
+			return;
+		}
+		let mut encoded = self.encoded.borrow_mut();
+		if encoded.as_ref().is_none_or(|(encoded_area, _)| *encoded_area != area) {
+			*encoded = Some((area, self.encode(area)));
+		}
+		let (_, sequence) = encoded.as_ref().expect("the image should have been encoded");
+		for y in area.top()..area.bottom() {
+			for x in area.left()..area.right() {
+				buffer.get_mut(x, y).set_skip(true);
+			}
+		}
+		buffer.get_mut(area.x, area.y).set_symbol(sequence).set_skip(false);
+	}
+
+	/// Renders the image with colored half blocks.
+	fn render_half_blocks(&self, buffer: &mut Buffer, area: Rect) {
+		let resized = resize(
+			&self.image,
+			u32::from(area.width),
+			u32::from(area.height) * 2,
+			FilterType::Nearest,
+		);
+		let color = |x: u16, y: u32| {
+			let [r, g, b, a] = resized.get_pixel(u32::from(x), y).0;
+			if a < u8::MAX / 2 {
+				Color::Reset
+			} else {
+				Color::Rgb(r, g, b)
+			}
+		};
+		for row in 0..area.height {
+			for column in 0..area.width {
+				buffer
+					.get_mut(area.x + column, area.y + row)
+					.set_symbol("▀")
+					.set_fg(color(column, u32::from(row) * 2))
+					.set_bg(color(column, u32::from(row) * 2 + 1));
+			}
+		}
+	}
+
+	/// Encodes the escape sequence drawing the image over an area with the
+	/// detected graphics protocol.
+	fn encode(&self, area: Rect) -> String {
+		match *GRAPHICS_PROTOCOL {
+			GraphicsProtocol::Kitty => self.encode_kitty(area),
+			GraphicsProtocol::Iterm2 => self.encode_iterm2(area),
+			GraphicsProtocol::Sixel => self.encode_sixel(area),
+			GraphicsProtocol::HalfBlocks => String::new(),
+		}
+	}
+
+	/// Encodes the image with the kitty graphics protocol, drawn below text so
+	/// that popups stay readable.
+	fn encode_kitty(&self, area: Rect) -> String {
+		let data = STANDARD.encode(self.png);
+		let chunks: Vec<_> = data.as_bytes().chunks(KITTY_CHUNK_SIZE).collect();
+		let mut sequence = String::new();
+		for (index, chunk) in chunks.iter().enumerate() {
+			let more = u8::from(index + 1 < chunks.len());
+			let chunk = String::from_utf8_lossy(chunk);
+			if index == 0 {
+				let _ = write!(
+					sequence,
+					"\x1b_Ga=T,f=100,t=d,q=2,C=1,z=-1,c={},r={},m={more};{chunk}\x1b\\",
+					area.width, area.height,
+				);
+			} else {
+				let _ = write!(sequence, "\x1b_Gm={more};{chunk}\x1b\\");
+			}
+		}
+		sequence
+	}
+
+	/// Encodes the image with iTerm2's inline images protocol.
+	fn encode_iterm2(&self, area: Rect) -> String {
+		format!(
+			"\x1b]1337;File=inline=1;size={};width={};height={};preserveAspectRatio=0:{}\x07",
+			self.png.len(),
+			area.width,
+			area.height,
+			STANDARD.encode(self.png),
+		)
+	}
+
+	/// Encodes the image as sixels, with a palette of [`SIXEL_LEVELS`] levels
+	/// per channel. Transparent pixels are left untouched.
+	fn encode_sixel(&self, area: Rect) -> String {
+		let resized = resize(
+			&self.image,
+			u32::from(area.width) * CELL_SIZE_PX.0,
+			u32::from(area.height) * CELL_SIZE_PX.1,
+			FilterType::Nearest,
+		);
+		let quantize = |channel: u8| u32::from(channel) * (SIXEL_LEVELS - 1) / 255;
+		let palette_index = |x: u32, y: u32| {
+			let [r, g, b, a] = resized.get_pixel(x, y).0;
+			(a >= u8::MAX / 2)
+				.then(|| (quantize(r) * SIXEL_LEVELS + quantize(g)) * SIXEL_LEVELS + quantize(b))
+		};
+
+		let (width, height) = resized.dimensions();
+		let mut sequence = format!("\x1bP0;1;0q\"1;1;{width};{height}");
+		for index in 0..SIXEL_LEVELS.pow(3) {
+			let percent = |level: u32| level * 100 / (SIXEL_LEVELS - 1);
+			let (r, g, b) = (
+				index / SIXEL_LEVELS / SIXEL_LEVELS,
+				index / SIXEL_LEVELS % SIXEL_LEVELS,
+				index % SIXEL_LEVELS,
+			);
+			let _ = write!(
+				sequence,
+				"#{index};2;{};{};{}",
+				percent(r),
+				percent(g),
+				percent(b)
+			);
+		}
+		for band in (0..height).step_by(6) {
+			let band_height = (height - band).min(6);
+			let mut colors: Vec<_> = (0..width)
+				.flat_map(|x| (0..band_height).filter_map(move |dy| palette_index(x, band + dy)))
+				.collect();
+			colors.sort_unstable();
+			colors.dedup();
+			for color in colors {
+				let _ = write!(sequence, "#{color}");
+				let sixels = (0..width).map(|x| {
+					let bits = (0..band_height)
+						.filter(|&dy| palette_index(x, band + dy) == Some(color))
+						.fold(0_u8, |bits, dy| bits | (1 << dy));
+					char::from(0x3F + bits)
+				});
+				push_run_length_encoded(&mut sequence, sixels);
+				sequence.push('$');
+			}
+			sequence.push('-');
+		}
+		sequence.push_str("\x1b\\");
+		sequence
+	}
+}
+
+/// Pushes sixels to a sequence, compressing runs of the same sixel.
+fn push_run_length_encoded(sequence: &mut String, sixels: impl Iterator<Item = char>) {
+	let mut push_run = |sixel: char, count: usize| {
+		if count > 3 {
+			let _ = write!(sequence, "!{count}{sixel}");
+		} else {
+			sequence.extend(std::iter::repeat_n(sixel, count));
+		}
+	};
+	let mut run: Option<(char, usize)> = None;
+	for sixel in sixels {
+		run = match run {
+			Some((current, count)) if current == sixel => Some((current, count + 1)),
+			Some((current, count)) => {
+				push_run(current, count);
+				Some((sixel, 1))
+			},
+			None => Some((sixel, 1)),
+		};
+	}
+	if let Some((sixel, count)) = run {
+		push_run(sixel, count);
+	}
+}
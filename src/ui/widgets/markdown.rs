@@ -0,0 +1,415 @@
+//! A lightweight markdown renderer. See [`Markdown`] for the struct this
+//! module exports.
+//!
+//! Only a small subset of markdown is supported:
+//! - Headings, from `#` to `######`
+//! - Bold (`**bold**`) and italic (`*italic*` or `_italic_`) text
+//! - Unordered (`-`, `*` or `+`) and ordered (`1.`) list items
+//! - Code spans (`` `code` ``) and fenced code blocks
+//! - Block quotes (`>`)
+//!
+//! Consecutive lines of text are joined into paragraphs, like markdown does.
+
+use std::cell::Cell;
+
+use ratatui::{
+	layout::Rect,
+	style::{
+		Modifier,
+		Style,
+	},
+	text::{
+		Line,
+		Span,
+		Text,
+	},
+	widgets::{
+		Block,
+		Paragraph,
+		Wrap,
+	},
+	Frame,
+};
+
+use crate::ui::color_scheme::{
+	MEDIUM_SLATE_BLUE,
+	SUNGLOW,
+	THISTLE,
+	TROPICAL_INDIGO,
+};
+
+/// Style of inline code spans and code blocks.
+const CODE_STYLE: Style = Style::new().fg(THISTLE);
+
+/// Style of block quotes.
+const QUOTE_STYLE: Style = Style::new().fg(TROPICAL_INDIGO).add_modifier(Modifier::ITALIC);
+
+/// Returns the style of a heading of a certain level (the number of `#`s).
+fn heading_style(level: usize) -> Style {
+	let style = Style::new().add_modifier(Modifier::BOLD);
+	match level {
+		1 => style.fg(SUNGLOW).add_modifier(Modifier::UNDERLINED),
+		2 => style.fg(TROPICAL_INDIGO),
+		_ => style.fg(MEDIUM_SLATE_BLUE),
+	}
+}
+
+/// A block of text being built from one or more source lines.
+struct PendingBlock {
+	/// Text prepended to the block, such as a list bullet.
+	prefix: Span<'static>,
+
+	/// Source text of the block, with lines joined by spaces.
+	text: String,
+
+	/// Base style of the block.
+	style: Style,
+}
+
+impl PendingBlock {
+	/// Turns the block into a rendered line.
+	fn into_line(self) -> Line<'static> {
+		let mut spans = vec![self.prefix];
+		spans.extend(parse_inline(&self.text, self.style));
+		Line::from(spans)
+	}
+}
+
+/// An inline markdown marker that toggles a style.
+enum InlineMarker {
+	Bold,
+	Italic,
+	Code,
+}
+
+/// Returns whether the character at an index is next to a non-alphanumeric
+/// character, so that underscores in `snake_case` aren't mistaken for italics.
+fn is_at_word_boundary(chars: &[char], index: usize) -> bool {
+	let is_alphanumeric = |index: Option<usize>| {
+		index.and_then(|index| chars.get(index)).is_some_and(|c| c.is_alphanumeric())
+	};
+	!is_alphanumeric(index.checked_sub(1)) || !is_alphanumeric(Some(index + 1))
+}
+
+/// Returns whether the character at an index has whitespace, or nothing, on
+/// both sides, like the `*` of `2 * 3`, in which case it isn't a marker.
+fn is_between_spaces(chars: &[char], index: usize) -> bool {
+	let is_space = |index: Option<usize>| {
+		index.and_then(|index| chars.get(index)).is_none_or(|c| c.is_whitespace())
+	};
+	is_space(index.checked_sub(1)) && is_space(Some(index + 1))
+}
+
+/// Parses inline markdown (bold, italic, code spans) into styled spans.
+fn parse_inline(text: &str, base_style: Style) -> Vec<Span<'static>> {
+	let chars: Vec<char> = text.chars().collect();
+	let mut spans = Vec::new();
+	let mut current = String::new();
+	let (mut bold, mut italic, mut code) = (false, false, false);
+
+	let style_of = |bold: bool, italic: bool, code: bool| {
+		let mut style = base_style;
+		if code {
+			style = style.patch(CODE_STYLE);
+		}
+		if bold {
+			style = style.add_modifier(Modifier::BOLD);
+		}
+		if italic {
+			style = style.add_modifier(Modifier::ITALIC);
+		}
+		style
+	};
+
+	let mut index = 0;
+	while index < chars.len() {
+		let character = chars[index];
+		let next = chars.get(index + 1).copied();
+		let marker = match character {
+			'\\' if !code => {
+				current.extend(next);
+				index += 2;
+				continue;
+			},
+			'`' => Some(InlineMarker::Code),
+			'*' if !code && next == Some('*') => Some(InlineMarker::Bold),
+			'*' | '_'
+				if !code
+					&& is_at_word_boundary(&chars, index)
+					&& !is_between_spaces(&chars, index) =>
+			{
+				Some(InlineMarker::Italic)
+			},
+			_ => None,
+		};
+
+		if let Some(marker) = marker {
+			if !current.is_empty() {
+				spans.push(Span::styled(
+					std::mem::take(&mut current),
+					style_of(bold, italic, code),
+				));
+			}
+			match marker {
+				InlineMarker::Bold => {
+					bold = !bold;
+					index += 1;
+				},
+				InlineMarker::Italic => italic = !italic,
+				InlineMarker::Code => code = !code,
+			}
+		} else {
+			current.push(character);
+		}
+		index += 1;
+	}
+	if !current.is_empty() {
+		spans.push(Span::styled(current, style_of(bold, italic, code)));
+	}
+	spans
+}
+
+/// Returns the list item prefix and the rest of the line if the line is a list
+/// item.
+fn parse_list_item(line: &str) -> Option<(String, &str)> {
+	let trimmed = line.trim_start();
+	let indent = " ".repeat(line.len() - trimmed.len());
+	for bullet in ["- ", "* ", "+ "] {
+		if let Some(rest) = trimmed.strip_prefix(bullet) {
+			return Some((format!("{indent}• "), rest));
+		}
+	}
+	let (number, rest) = trimmed.split_once(". ")?;
+	if !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()) {
+		Some((format!("{indent}{number}. "), rest))
+	} else {
+		None
+	}
+}
+
+/// Parses markdown source into styled [Text].
+pub fn parse_markdown(source: &str) -> Text<'static> {
+	let mut lines = Vec::new();
+	let mut pending: Option<PendingBlock> = None;
+	let mut in_code_block = false;
+
+	for line in source.lines() {
+		if line.trim_start().starts_with("```") {
+			lines.extend(pending.take().map(PendingBlock::into_line));
+			in_code_block = !in_code_block;
+			continue;
+		}
+		if in_code_block {
+			lines.push(Line::styled(format!("  {line}"), CODE_STYLE));
+			continue;
+		}
+
+		let trimmed = line.trim();
+		if trimmed.is_empty() {
+			lines.extend(pending.take().map(PendingBlock::into_line));
+			lines.push(Line::default());
+			continue;
+		}
+
+		let heading_level = trimmed.chars().take_while(|c| *c == '#').count();
+		if (1..=6).contains(&heading_level) && trimmed[heading_level..].starts_with(' ') {
+			lines.extend(pending.take().map(PendingBlock::into_line));
+			let style = heading_style(heading_level);
+			lines.push(Line::from(parse_inline(
+				trimmed[heading_level..].trim(),
+				style,
+			)));
+			continue;
+		}
+
+		let new_block = if let Some((prefix, rest)) = parse_list_item(line) {
+			Some(PendingBlock {
+				prefix: Span::raw(prefix),
+				text: rest.to_string(),
+				style: Style::new(),
+			})
+		} else {
+			trimmed.strip_prefix('>').map(|rest| PendingBlock {
+				prefix: Span::styled("│ ", QUOTE_STYLE),
+				text: rest.trim().to_string(),
+				style: QUOTE_STYLE,
+			})
+		};
+
+		match (new_block, pending.as_mut()) {
+			(Some(block), _) => {
+				lines.extend(pending.replace(block).map(PendingBlock::into_line));
+			},
+			(None, Some(block)) => {
+				block.text.push(' ');
+				block.text.push_str(trimmed);
+			},
+			(None, None) => {
+				pending = Some(PendingBlock {
+					prefix: Span::raw(""),
+					text: trimmed.to_string(),
+					style: Style::new(),
+				});
+			},
+		}
+	}
+	lines.extend(pending.map(PendingBlock::into_line));
+	Text::from(lines)
+}
+
+/// A scrollable view of rendered markdown.
+#[derive(Clone)]
+#[must_use]
+pub struct Markdown {
+	/// The rendered markdown text.
+	text: Text<'static>,
+
+	/// Number of lines scrolled down from the top.
+	scroll: u16,
+
+	/// Width the text was last wrapped to, once rendered.
+	width: Cell<Option<u16>>,
+}
+
+impl Markdown {
+	/// Renders markdown source into a new markdown view.
+	pub fn new(source: &str) -> Self {
+		Self {
+			text: parse_markdown(source),
+			scroll: 0,
+			width: Cell::new(None),
+		}
+	}
+
+	/// Returns the text as a paragraph, wrapped.
+	fn paragraph(&self) -> Paragraph<'static> {
+		Paragraph::new(self.text.clone()).wrap(Wrap { trim: false })
+	}
+
+	/// Renders this view into an area, wrapped in a block.
+	pub fn render(&self, frame: &mut Frame<'_>, area: Rect, block: Block<'_>) {
+		self.width.set(Some(block.inner(area).width));
+		let paragraph = self.paragraph().block(block).scroll((self.scroll, 0));
+		frame.render_widget(paragraph, area);
+	}
+
+	/// Returns the maximum number of lines that can be scrolled down, counting
+	/// the lines text is wrapped into once rendered.
+	#[must_use]
+	pub fn max_scroll(&self) -> u16 {
+		let lines = match self.width.get() {
+			Some(width) => self.paragraph().line_count(width),
+			None => self.text.lines.len(),
+		};
+		u16::try_from(lines).unwrap_or(u16::MAX).saturating_sub(1)
+	}
+
+	/// Scrolls the view up by a number of lines.
+	pub fn scroll_up(&mut self, lines: u16) {
+		self.scroll = self.scroll.saturating_sub(lines);
+	}
+
+	/// Scrolls the view down by a number of lines, stopping at the last line.
+	pub fn scroll_down(&mut self, lines: u16) {
+		self.scroll = self.scroll.saturating_add(lines).min(self.max_scroll());
+	}
+
+	/// Scrolls to the top of the view.
+	pub fn scroll_to_top(&mut self) {
+		self.scroll = 0;
+	}
+
+	/// Scrolls to the bottom of the view.
+	pub fn scroll_to_bottom(&mut self) {
+		self.scroll = self.max_scroll();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::cell::Cell;
+
+	use ratatui::style::Modifier;
+
+	use super::{
+		parse_inline,
+		parse_markdown,
+		Markdown,
+	};
+
+	/// Returns the text of each rendered line.
+	fn line_texts(source: &str) -> Vec<String> {
+		parse_markdown(source).lines.iter().map(ToString::to_string).collect()
+	}
+
+	/// Returns the text of each span of a line, with whether it's italic.
+	fn italics(text: &str) -> Vec<(String, bool)> {
+		parse_inline(text, ratatui::style::Style::new())
+			.into_iter()
+			.map(|span| {
+				let italic = span.style.add_modifier.contains(Modifier::ITALIC);
+				(span.content.into_owned(), italic)
+			})
+			.collect()
+	}
+
+	#[test]
+	fn parses_blocks() {
+		assert_eq!(
+			line_texts(
+				"# Title
+Some
+text
+
+- One
+2. Two
+> Quote
+```
+# Not a title
+```"
+			),
+			[
+				"Title",
+				"Some text",
+				"",
+				"• One",
+				"2. Two",
+				"│ Quote",
+				"  # Not a title"
+			]
+		);
+		assert_eq!(
+			line_texts(
+				"###### Six
+####### Seven"
+			),
+			["Six", "####### Seven"]
+		);
+	}
+
+	#[test]
+	fn parses_inline_markers() {
+		assert_eq!(italics("*a* **b** `c*d`"), [
+			("a".to_string(), true),
+			(" ".to_string(), false),
+			("b".to_string(), false),
+			(" ".to_string(), false),
+			("c*d".to_string(), false),
+		]);
+		assert_eq!(italics("snake_case_name"), [(
+			"snake_case_name".to_string(),
+			false
+		)]);
+		assert_eq!(italics("2 * 3 * 4"), [("2 * 3 * 4".to_string(), false)]);
+	}
+
+	#[test]
+	fn scrolls_through_wrapped_lines() {
+		let mut markdown = Markdown::new("one two three four");
+		assert_eq!(markdown.max_scroll(), 0);
+		markdown.width = Cell::new(Some(5));
+		assert_eq!(markdown.max_scroll(), 3);
+		markdown.scroll_to_bottom();
+		assert_eq!(markdown.scroll, 3);
+	}
+}
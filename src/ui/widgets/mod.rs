@@ -1,6 +1,5 @@
 //! Module for widgets to display units of data. See [Widget] to get started.
 
-use crossterm::event::Event;
 use derive_new::new;
 use enum_dispatch::enum_dispatch;
 use ratatui::{
@@ -12,9 +11,20 @@ use ratatui::{
 	Frame,
 };
 
-use crate::ui::widgets::utils::controls_table::ControlsEntries;
+use crate::{
+	core::events::Event,
+	ui::widgets::utils::controls_table::ControlsEntries,
+};
 
+#[cfg(feature = "networking")]
+pub mod chat;
+pub mod data_table;
+pub mod figlet;
+#[cfg(feature = "images")]
+pub mod image;
+pub mod markdown;
 pub mod scrollable_list;
+pub mod typewriter;
 pub mod utils;
 
 /// No state.
@@ -29,11 +29,18 @@ use ratatui::{
 };
 
 use crate::ui::{
-	components::presets::{
-		highlight_block,
-		titled_ui_block,
-		HIGHLIGHTED,
+	components::{
+		banner::{
+			get_large_text,
+			get_large_text_height,
+		},
+		presets::{
+			highlight_block,
+			titled_ui_block,
+			HIGHLIGHTED,
+		},
 	},
+	text,
 	widgets::utils::{
 		flicker_counter::FlickerCounter,
 		scroll_tracker::ScrollTracker,
@@ -52,9 +59,19 @@ pub struct ListItem<D: ToString> {
 
 	/// Content to be displayed on screen instead of the data.
 	pub displayed_content: Option<String>,
+
+	/// Badge shown after the item's name, like `New!`.
+	#[new(default)]
+	pub badge: Option<String>,
 }
 
 impl<D: ToString> ListItem<D> {
+	/// Adds a badge after the item's name.
+	pub fn with_badge(mut self, badge: Option<String>) -> Self {
+		self.badge = badge;
+		self
+	}
+
 	/// Returns data to be displayed for this list item.
 	pub fn get_displayed_data(&self) -> String {
 		self.displayed_content.clone().unwrap_or(self.data.to_string())
@@ -90,6 +107,13 @@ pub struct ScrollableList<D: ToString + Clone> {
 
 	/// Flicker counter for the list.
 	flicker_counter: FlickerCounter,
+
+	/// Whether items are drawn in [large text](get_large_text), when they fit.
+	large_text: bool,
+
+	/// Whether the selected item grows to show all of its content, when
+	/// there's room.
+	expand_selected: bool,
 }
 
 impl<D: ToString + Clone> ScrollableList<D> {
@@ -115,9 +139,43 @@ impl<D: ToString + Clone> ScrollableList<D> {
 			text_alignment,
 			margins,
 			flicker_counter,
+			large_text: false,
+			expand_selected: false,
 		}
 	}
 
+	/// Draws the items in [large text](get_large_text) when they fit, making
+	/// them as tall as the tallest bundled font.
+	pub fn with_large_text(mut self, large_text: bool) -> Self {
+		self.large_text = large_text;
+		self
+	}
+
+	/// Makes the selected item grow to show all of its content, taking up the
+	/// room left in the list. Other items are cut off after their maximum
+	/// number of lines either way.
+	pub fn with_expanded_selection(mut self, expand_selected: bool) -> Self {
+		self.expand_selected = expand_selected;
+		self
+	}
+
+	/// Returns the number of lines an item takes, without its borders.
+	fn get_item_lines(&self) -> u16 {
+		if self.large_text {
+			self.max_item_lines.max(get_large_text_height())
+		} else {
+			self.max_item_lines
+		}
+	}
+
+	/// Returns the height the list needs to display its items, margins
+	/// included.
+	#[must_use]
+	pub fn get_height(&self) -> u16 {
+		let displayed = self.get_display_count().unwrap_or(self.items.len()) as u16;
+		displayed * (self.get_item_lines() + 2) + self.margins.0 * 2
+	}
+
 	/// Returns the selected item in the list.
 	/// The first element in the returned tuple is the index where the element
 	/// was found.
@@ -128,19 +186,27 @@ impl<D: ToString + Clone> ScrollableList<D> {
 		Some((selected_index, item))
 	}
 
+	/// Returns whether the item at an index is currently displayed.
+	#[must_use]
+	pub fn is_displayed(&self, index: usize) -> bool {
+		self.scroll_tracker.get_displayed_range().contains(&index)
+	}
+
 	/// Renders this list.
 	pub fn render(&self, frame: &mut Frame<'_>, area: Rect) {
-		let chunks = self.get_layout().split(area);
+		let chunks = self.get_layout(area).split(area);
 		for (position, index) in self.scroll_tracker.get_displayed_range().enumerate() {
 			self.render_raw_item(frame, chunks[position], index, None);
 		}
 	}
 
-	/// Creates a block for the list item to be displayed in.
+	/// Creates a block of a width for the list item to be displayed in, its
+	/// title truncated to fit and its content wrapped to it.
 	fn get_list_item_paragraph<'a>(
 		&'a self,
 		index: usize,
 		custom_paragraph: Option<Paragraph<'a>>,
+		width: u16,
 	) -> Paragraph<'a> {
 		let item = self.items.get(index).unwrap_or_else(|| {
 			panic!(
@@ -149,14 +215,17 @@ impl<D: ToString + Clone> ScrollableList<D> {
 			)
 		});
 
-		let mut item_block = titled_ui_block(format!(
-			"{}{}",
+		let title = format!(
+			"{}{}{}",
 			index + 1,
-			item.name.as_ref().map_or(String::new(), |s| format!(" ─ {s}"))
-		))
-		.title_alignment(self.text_alignment);
-
-		if self.get_selected().map_or(false, |(selected_index, _)| index == selected_index) {
+			item.name.as_ref().map_or(String::new(), |s| format!(" ─ {s}")),
+			item.badge.as_ref().map_or(String::new(), |s| format!(" ─ {s}"))
+		);
+		let mut item_block =
+			titled_ui_block(text::truncate(&title, usize::from(width.saturating_sub(2))))
+				.title_alignment(self.text_alignment);
+
+		if self.get_selected().is_some_and(|(selected_index, _)| index == selected_index) {
 			let mut style = HIGHLIGHTED;
 			if self.flicker_counter.is_off() {
 				style = style.add_modifier(Modifier::DIM);
@@ -165,7 +234,19 @@ impl<D: ToString + Clone> ScrollableList<D> {
 		}
 
 		custom_paragraph
-			.unwrap_or_else(|| Paragraph::new(item.get_displayed_data()))
+			.unwrap_or_else(|| {
+				let max_lines = if self.is_expanded(index) {
+					usize::MAX
+				} else {
+					usize::from(self.get_item_lines())
+				};
+				let lines = text::wrap_clamped(
+					&item.get_displayed_data(),
+					usize::from(width.saturating_sub(4)),
+					max_lines,
+				);
+				Paragraph::new(lines.join("\n"))
+			})
 			.alignment(self.text_alignment)
 			.block(item_block)
 	}
@@ -182,7 +263,16 @@ impl<D: ToString + Clone> ScrollableList<D> {
 		index: usize,
 		custom_paragraph: Option<Paragraph<'_>>,
 	) {
-		let item_paragraph = self.get_list_item_paragraph(index, custom_paragraph);
+		let custom_paragraph = custom_paragraph.or_else(|| {
+			let item = self.items.get(index).filter(|_| self.large_text)?;
+			let text = get_large_text(
+				&item.get_displayed_data(),
+				area.width.saturating_sub(2),
+				area.height.saturating_sub(2),
+			)?;
+			Some(Paragraph::new(text))
+		});
+		let item_paragraph = self.get_list_item_paragraph(index, custom_paragraph, area.width);
 		frame.render_widget(item_paragraph, area);
 	}
 
@@ -211,7 +301,7 @@ impl<D: ToString + Clone> ScrollableList<D> {
 	where
 		P: Fn(&ListItem<D>) -> Paragraph<'_>,
 	{
-		let chunks = self.get_layout().split(area);
+		let chunks = self.get_layout(area).split(area);
 		let items = self.items.clone();
 		for (position, index) in self.scroll_tracker.get_displayed_range().enumerate() {
 			let item = items.get(index).unwrap_or_else(|| {
@@ -224,11 +314,34 @@ impl<D: ToString + Clone> ScrollableList<D> {
 		}
 	}
 
-	/// Returns the layout for this list. Put simply, the layout is only a list
-	/// of vertically-scrolling boxes.
+	/// Returns whether the item at an index is the selected one and
+	/// [expanded](Self::with_expanded_selection).
+	fn is_expanded(&self, index: usize) -> bool {
+		self.expand_selected
+			&& self.direction == Direction::Vertical
+			&& !self.large_text
+			&& self.scroll_tracker.selected == Some(index)
+	}
+
+	/// Returns the layout for this list in an area. Put simply, the layout is
+	/// only a list of vertically-scrolling boxes.
 	#[must_use]
-	pub fn get_layout(&self) -> Layout {
-		let mut constraints = vec![Constraint::Max(self.max_item_lines + 2); self.items.len()];
+	pub fn get_layout(&self, area: Rect) -> Layout {
+		let item_height = self.get_item_lines() + 2;
+		let mut constraints = vec![Constraint::Max(item_height); self.items.len()];
+		let displayed = self.scroll_tracker.get_displayed_range();
+		if let Some(selected) = displayed.clone().find(|index| self.is_expanded(*index)) {
+			let width = area.width.saturating_sub(self.margins.1 * 2 + 4);
+			let lines = text::wrap(
+				&self.items[selected].get_displayed_data(),
+				usize::from(width),
+			);
+			// The other items keep their room, the selected one gets what's left.
+			let others = (displayed.len() as u16).saturating_sub(1) * item_height;
+			let room = area.height.saturating_sub(self.margins.0 * 2 + others);
+			let height = (u16::try_from(lines.len()).unwrap_or(u16::MAX) + 2).min(room);
+			constraints[selected - displayed.start] = Constraint::Max(height.max(item_height));
+		}
 		constraints.push(Constraint::Max(0));
 
 		Layout::default()
@@ -238,8 +351,6 @@ impl<D: ToString + Clone> ScrollableList<D> {
 			.constraints(constraints)
 	}
 
-	/// Returns
-
 	/// Updates items this list displays as well as the length of the underlying
 	/// scroll tracker.
 	pub fn update_items(&mut self, items: Vec<ListItem<D>>) {
@@ -247,6 +358,15 @@ impl<D: ToString + Clone> ScrollableList<D> {
 		self.scroll_tracker.set_length(self.items.len());
 	}
 
+	/// Replaces the item at an index, keeping the current selection.
+	///
+	/// # Panics
+	///
+	/// This function panics when the index is outside of the list's items.
+	pub fn set_item(&mut self, index: usize, item: ListItem<D>) {
+		self.items[index] = item;
+	}
+
 	/// Scrolls the list forward, or back to start if the list is at the end.
 	pub fn scroll_forward(&mut self) {
 		self.scroll_tracker.scroll_forward();
@@ -259,6 +379,12 @@ impl<D: ToString + Clone> ScrollableList<D> {
 		self.flicker_counter.reset();
 	}
 
+	/// Selects an item, scrolling the list to it.
+	pub fn select(&mut self, index: usize) {
+		self.scroll_tracker.select(index);
+		self.flicker_counter.reset();
+	}
+
 	/// Scrolls the list to a random position.
 	pub fn scroll_to_random(&mut self) {
 		self.scroll_tracker.scroll_to_random();
@@ -0,0 +1,105 @@
+//! Text revealed a few characters at a time, like on a typewriter. See
+//! [`Typewriter`] for the struct this module exports.
+//!
+//! The text is revealed as the screen showing it [ticks](Typewriter::tick),
+//! usually once per [update](crate::ui::Screen::update), so it types out at
+//! the frame rate. With [reduced motion](Config::reduced_motion) on, it's
+//! shown whole right away.
+
+use crate::core::config::Config;
+
+/// How many characters are revealed each tick.
+pub const CHARS_PER_TICK: usize = 1;
+
+/// See the [module](self) documentation for more information.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct Typewriter {
+	/// The whole text.
+	text: String,
+
+	/// Length of the revealed part of the text, in bytes.
+	revealed: usize,
+
+	/// Whether texts are shown whole right away.
+	reduced_motion: bool,
+}
+
+impl Typewriter {
+	/// Creates a typewriter about to type out a text, or having typed it if
+	/// [reduced motion](Config::reduced_motion) is on.
+	pub fn new(text: impl Into<String>) -> Self {
+		Self::with_reduced_motion(text, Config::load_or_default().reduced_motion)
+	}
+
+	/// Creates a typewriter about to type out a text, or having typed it if
+	/// motion is reduced.
+	pub fn with_reduced_motion(text: impl Into<String>, reduced_motion: bool) -> Self {
+		let mut typewriter = Self {
+			text: String::new(),
+			revealed: 0,
+			reduced_motion,
+		};
+		typewriter.set_text(text);
+		typewriter
+	}
+
+	/// Starts typing out another text.
+	pub fn set_text(&mut self, text: impl Into<String>) {
+		self.text = text.into();
+		self.revealed = 0;
+		if self.reduced_motion {
+			self.skip();
+		}
+	}
+
+	/// Reveals the next few characters.
+	pub fn tick(&mut self) {
+		self.revealed += self.text[self.revealed..]
+			.chars()
+			.take(CHARS_PER_TICK)
+			.map(char::len_utf8)
+			.sum::<usize>();
+	}
+
+	/// Reveals the rest of the text at once.
+	pub fn skip(&mut self) {
+		self.revealed = self.text.len();
+	}
+
+	/// Returns whether the whole text is revealed.
+	#[must_use]
+	pub fn is_done(&self) -> bool {
+		self.revealed == self.text.len()
+	}
+
+	/// Returns the revealed part of the text.
+	#[must_use]
+	pub fn visible(&self) -> &str {
+		&self.text[..self.revealed]
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Typewriter;
+
+	#[test]
+	fn text_types_out_a_character_per_tick() {
+		let mut typewriter = Typewriter::with_reduced_motion("🎮 Go", false);
+		assert_eq!(typewriter.visible(), "");
+		typewriter.tick();
+		assert_eq!(typewriter.visible(), "🎮");
+		typewriter.tick();
+		typewriter.tick();
+		assert!(!typewriter.is_done());
+		typewriter.skip();
+		assert_eq!(typewriter.visible(), "🎮 Go");
+		typewriter.tick();
+		assert!(typewriter.is_done());
+		typewriter.set_text("Again");
+		assert_eq!(typewriter.visible(), "");
+		let typewriter = Typewriter::with_reduced_motion("At once", true);
+		assert_eq!(typewriter.visible(), "At once");
+	}
+}
@@ -3,12 +3,6 @@
 use std::fmt::Display;
 
 use bitflags::bitflags;
-use crossterm::event::{
-	Event,
-	KeyCode,
-	KeyEvent,
-	KeyModifiers,
-};
 use derive_builder::Builder;
 use derive_new::new;
 use indexmap::IndexMap;
@@ -37,13 +31,26 @@ use ratatui::{
 	},
 };
 
-use crate::ui::{
-	components::presets::HIGHLIGHTED,
-	widgets::{
-		utils::scroll_tracker::ScrollTracker,
-		Widget,
-		WidgetFocus,
-		WidgetState,
+use crate::{
+	core::events::{
+		Event,
+		KeyCode,
+		KeyEvent,
+		KeyModifiers,
+	},
+	t,
+	ui::{
+		components::presets::{
+			striped_table,
+			HIGHLIGHTED,
+		},
+		text,
+		widgets::{
+			utils::scroll_tracker::ScrollTracker,
+			Widget,
+			WidgetFocus,
+			WidgetState,
+		},
 	},
 };
 
@@ -62,8 +69,8 @@ pub enum KeyControl {
 
 impl KeyControl {
 	/// Creates a new custom key control.
-	pub fn new_custom<S: ToString>(s: S) -> Self {
-		Self::Custom(s.to_string())
+	pub fn new_custom<S: Into<String>>(s: S) -> Self {
+		Self::Custom(s.into())
 	}
 }
 
@@ -82,7 +89,7 @@ impl Display for KeyControl {
 		write!(f, "{}", match self {
 			KeyControl::Char(c) => c.to_string().to_uppercase(),
 			KeyControl::F(n) => format!("F{n}"),
-			KeyControl::Custom(ref s) => s.to_string(),
+			KeyControl::Custom(ref s) => s.clone(),
 		})
 	}
 }
@@ -99,7 +106,7 @@ pub struct Control {
 
 impl Display for Control {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		let mut keys = self.modifiers.clone().unwrap_or_else(|| Vec::new());
+		let mut keys = self.modifiers.clone().unwrap_or_default();
 		keys.push(self.control.to_string());
 		let result = keys.into_iter().map(|key| format!("[{key}]")).collect::<Vec<_>>().join(" ");
 		write!(f, "{result}")
@@ -114,7 +121,7 @@ impl Display for Control {
 #[derive(Clone, Default)]
 pub struct ControlsEntries(pub IndexMap<Control, Vec<String>>);
 
-impl<'a> ControlsEntries {
+impl ControlsEntries {
 	/// Creates a new controls entries table.
 	pub fn new<E>(entries: E) -> Self
 	where
@@ -125,26 +132,25 @@ impl<'a> ControlsEntries {
 
 	/// Adds an entry into the controls entries table. This is a fluent setter
 	/// method.
-	pub fn add<S: ToString>(mut self, control: Control, entry: S) -> Self {
-		self.register(control, entry.to_string());
+	#[must_use]
+	pub fn add<S: Into<String>>(mut self, control: Control, entry: S) -> Self {
+		self.register(control, entry.into());
 		self
 	}
 
 	/// Adds an entry of multiple functions in to the controls entries table.
 	/// This is a fluent setter method.
+	#[must_use]
 	pub fn add_multi(mut self, control: Control, entries: Vec<String>) -> Self {
-		for entry in entries {
-			self = self.add(control.clone(), entry);
+		if !entries.is_empty() {
+			self.0.entry(control).or_default().extend(entries);
 		}
 		self
 	}
 
 	/// Registers an entry, merging into an exact control if it exists.
 	fn register(&mut self, control: Control, entry: String) {
-		self.0
-			.entry(control.clone())
-			.and_modify(|entries| entries.push(entry.clone()))
-			.or_insert(vec![entry]);
+		self.0.entry(control).or_default().push(entry);
 	}
 
 	/// Creates a new controls entries table, with reference to other controls
@@ -172,19 +178,24 @@ impl<'a> ControlsEntries {
 	}
 
 	/// Gets the longest control string's length.
+	#[must_use]
 	pub fn get_longest_control_str_len(&self) -> Option<usize> {
-		self.0.iter().map(|(control, _)| control.to_string().len()).fold(None, |acc, item| {
-			if item > acc.unwrap_or(0) {
-				Some(item)
-			} else {
-				acc
-			}
-		})
+		self.0.iter().map(|(control, _)| text::width(&control.to_string())).fold(
+			None,
+			|acc, item| {
+				if item > acc.unwrap_or(0) {
+					Some(item)
+				} else {
+					acc
+				}
+			},
+		)
 	}
 
 	/// Gets the longest entry string's length.
+	#[must_use]
 	pub fn get_longest_entry_str_len(&self, index: usize) -> Option<usize> {
-		self.0.get_index(index)?.1.iter().map(String::len).fold(None, |acc, item| {
+		self.0.get_index(index)?.1.iter().map(|entry| text::width(entry)).fold(None, |acc, item| {
 			if item > acc.unwrap_or(0) {
 				Some(item)
 			} else {
@@ -211,7 +222,7 @@ impl Widget for ControlsTable {
 			WidgetFocus::Unfocused,
 			ControlsEntries::default().add(
 				Control::new(None, KeyControl::new_custom("[↑ ↓]")),
-				"Navigate this controls list",
+				t!("controls-navigate"),
 			),
 		)
 	}
@@ -236,38 +247,30 @@ impl Widget for ControlsTable {
 		let mut table_state = TableState::from(self.scroll_tracker);
 		let controls_entries = &self.controls_entries;
 
-		let header = ["Control", "Function"]
+		let header = [t!("controls-control"), t!("controls-function")]
 			.into_iter()
 			.map(Cell::from)
 			.collect::<Row<'_>>()
 			.style(HIGHLIGHTED.add_modifier(Modifier::UNDERLINED))
 			.height(1);
 
-		let entry_rows = {
-			let mut rows = controls_entries
-				.0
-				.iter()
-				.map(|(control, entries)| {
-					let entry_length = entries.len();
-					let entry_height = entry_length
-						.try_into()
-						.expect(format!("Too many lines: {entry_length} > {}", u16::MAX).as_str());
+		let entry_rows = controls_entries.0.iter().map(|(control, entries)| {
+			let entry_length = entries.len();
+			let entry_height = entry_length
+				.try_into()
+				.unwrap_or_else(|_| panic!("Too many lines: {entry_length} > {}", u16::MAX));
 
-					Row::new([
-						Cell::new(control.to_string()).italic(),
-						Cell::new(entries.join("\n")),
-					])
-					.height(entry_height)
-				})
-				.collect::<Vec<_>>();
-			rows.insert(0, header);
-			rows
-		};
+			Row::new([
+				Cell::new(control.to_string()).italic(),
+				Cell::new(entries.join("\n")),
+			])
+			.height(entry_height)
+		});
 
 		let table_widths = [Constraint::Length(
 			self.controls_entries.get_longest_control_str_len().unwrap_or(0) as u16,
 		)];
-		let table = Table::new(entry_rows, table_widths);
+		let table = striped_table(entry_rows, table_widths).header(header);
 		frame.render_stateful_widget(table, area, &mut table_state);
 	}
 }
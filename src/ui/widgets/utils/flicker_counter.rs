@@ -2,14 +2,13 @@
 
 use std::{
 	sync::Mutex,
-	time::{
-		Duration,
-		SystemTime,
-	},
+	time::Duration,
 };
 
 use lazy_static::lazy_static;
 
+use crate::core::time::SystemTime;
+
 /// A flicker state.
 #[allow(missing_docs)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -68,7 +67,7 @@ impl FlickerCounter {
 	/// Gets the current [flicker state](FlickerState).
 	pub fn get_state(&self) -> FlickerState {
 		let elapsed = self.begin_time.elapsed().expect("Time is not making sense").as_nanos();
-		if elapsed / self.interval.as_nanos() % 2 == 0 {
+		if (elapsed / self.interval.as_nanos()).is_multiple_of(2) {
 			FlickerState::On
 		} else {
 			FlickerState::Off
@@ -0,0 +1,149 @@
+//! A ring of the parts of a screen that can be focused, like a form's fields
+//! or a game's board and chat. \[Tab\] moves the focus to the next part and
+//! \[Shift\]+\[Tab\] to the previous one, wrapping around, and other events are
+//! routed to the focused part.
+
+use ratatui::widgets::Block;
+
+use crate::{
+	core::events::{
+		Event,
+		KeyCode,
+		KeyEventKind,
+	},
+	ui::{
+		components::presets::highlight_block,
+		widgets::WidgetFocus,
+	},
+};
+
+/// See the [module](self) documentation for more information.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct FocusRing<T> {
+	/// The parts that can be focused, in the order \[Tab\] goes through them.
+	targets: Vec<T>,
+
+	/// Index of the focused part.
+	focused: usize,
+}
+
+impl<T: Copy + PartialEq> FocusRing<T> {
+	/// Creates a ring going through parts in order, focusing the first one.
+	///
+	/// # Panics
+	///
+	/// This function panics if there are no parts to focus.
+	pub fn new(targets: Vec<T>) -> Self {
+		assert!(!targets.is_empty(), "A focus ring needs something to focus");
+		Self {
+			targets,
+			focused: 0,
+		}
+	}
+
+	/// Returns the focused part.
+	#[must_use]
+	pub fn focused(&self) -> T {
+		self.targets[self.focused]
+	}
+
+	/// Returns whether a part is focused.
+	#[must_use]
+	pub fn is_focused(&self, target: T) -> bool {
+		self.focused() == target
+	}
+
+	/// Focuses a part, if it's in the ring.
+	pub fn focus(&mut self, target: T) {
+		if let Some(index) = self.targets.iter().position(|other| *other == target) {
+			self.focused = index;
+		}
+	}
+
+	/// Focuses the next part, or the first one after the last.
+	pub fn focus_next(&mut self) {
+		self.focused = (self.focused + 1) % self.targets.len();
+	}
+
+	/// Focuses the previous part, or the last one before the first.
+	pub fn focus_previous(&mut self) {
+		self.focused = self.focused.checked_sub(1).unwrap_or(self.targets.len() - 1);
+	}
+
+	/// Returns the [focus state](WidgetFocus) of a part.
+	pub fn widget_focus(&self, target: T) -> WidgetFocus {
+		if !self.targets.contains(&target) {
+			WidgetFocus::Unfocusable
+		} else if self.is_focused(target) {
+			WidgetFocus::Focused
+		} else {
+			WidgetFocus::Unfocused
+		}
+	}
+
+	/// Highlights a part's block if it's focused.
+	pub fn block<'a>(&self, target: T, block: Block<'a>) -> Block<'a> {
+		if self.is_focused(target) {
+			highlight_block(block)
+		} else {
+			block
+		}
+	}
+
+	/// Moves the focus on \[Tab\] and \[Shift\]+\[Tab\], returning [`None`],
+	/// or else returns the focused part, which the event should be passed to.
+	pub fn route(&mut self, event: &Event) -> Option<T> {
+		if let Event::Key(key) = event {
+			if key.kind == KeyEventKind::Press {
+				match key.code {
+					KeyCode::Tab => {
+						self.focus_next();
+						return None;
+					},
+					KeyCode::BackTab => {
+						self.focus_previous();
+						return None;
+					},
+					_ => {},
+				}
+			}
+		}
+		Some(self.focused())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::FocusRing;
+	use crate::{
+		core::events::{
+			Event,
+			KeyCode,
+			KeyEvent,
+			KeyModifiers,
+		},
+		ui::widgets::WidgetFocus,
+	};
+
+	#[test]
+	fn tab_cycles_through_the_ring() {
+		let key = |code, modifiers| Event::Key(KeyEvent::new(code, modifiers));
+		let mut ring = FocusRing::new(vec!['a', 'b', 'c']);
+		assert_eq!(
+			ring.route(&key(KeyCode::Char('x'), KeyModifiers::NONE)),
+			Some('a')
+		);
+		assert_eq!(
+			ring.route(&key(KeyCode::BackTab, KeyModifiers::SHIFT)),
+			None
+		);
+		assert_eq!(ring.focused(), 'c');
+		assert_eq!(ring.route(&key(KeyCode::Tab, KeyModifiers::NONE)), None);
+		assert_eq!(ring.focused(), 'a');
+		ring.focus('b');
+		assert!(ring.widget_focus('b') == WidgetFocus::Focused);
+		assert!(ring.widget_focus('a') == WidgetFocus::Unfocused);
+		assert!(ring.widget_focus('z') == WidgetFocus::Unfocusable);
+	}
+}
@@ -2,4 +2,5 @@
 
 pub mod controls_table;
 pub mod flicker_counter;
+pub mod focus_ring;
 pub mod scroll_tracker;
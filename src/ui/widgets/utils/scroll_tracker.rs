@@ -11,6 +11,8 @@ use anyhow::bail;
 use rand::Rng;
 use ratatui::widgets::TableState;
 
+use crate::services::rng;
+
 /// Keeps track of scroll position.
 #[derive(Debug, Clone, Copy)]
 pub struct ScrollTracker {
@@ -37,7 +39,7 @@ impl ScrollTracker {
 		Self {
 			selected: None,
 			start: 0,
-			end: length - 1,
+			end: length.saturating_sub(1),
 			display_count: Some(min(range.unwrap_or(length), length)),
 			length,
 		}
@@ -81,11 +83,9 @@ impl ScrollTracker {
 			self.selected = Some(self.length - 1);
 		} else {
 			self.selected = Some(selected - 1);
-			if self.display_count.is_some() && selected == self.start {
-				self.start = if selected < self.display_count.unwrap() {
-					0
-				} else {
-					selected - self.display_count.unwrap()
+			if let Some(count) = self.display_count {
+				if selected == self.start {
+					self.start = selected.saturating_sub(count);
 				}
 			}
 		}
@@ -105,18 +105,33 @@ impl ScrollTracker {
 			self.selected = Some(0);
 		} else {
 			self.selected = Some(selected + 1);
-			if self.display_count.is_some()
-				&& selected == self.start + self.display_count.unwrap() - 1
-			{
-				self.start = min(self.start + self.display_count.unwrap(), self.end);
+			if let Some(count) = self.display_count {
+				if selected == self.start + count - 1 {
+					self.start = min(self.start + count, self.end);
+				}
 			}
 		}
 	}
 
-	/// Scrolls to a random spot in the scroll tracker.
+	/// Selects an index, moving the displayed range to show it if needed.
+	pub fn select(&mut self, index: usize) {
+		if index >= self.length {
+			return;
+		}
+		self.selected = Some(index);
+		if let Some(count) = self.display_count {
+			if index < self.start || index >= self.start + count {
+				self.start = min(index, self.length.saturating_sub(count));
+			}
+		}
+	}
+
+	/// Scrolls to a random spot in the scroll tracker, if it isn't empty.
 	pub fn scroll_to_random(&mut self) {
-		let mut rng = rand::thread_rng();
-		self.start = rng.gen_range(0..self.length);
+		if self.length == 0 {
+			return;
+		}
+		self.start = rng::with_rng(|rng| rng.gen_range(0..self.length));
 		self.selected = Some(self.start);
 		self.end = std::cmp::min(self.end + self.display_count.unwrap_or(0), self.length - 1);
 	}
@@ -0,0 +1,45 @@
+// Runs the arcade, built into `pkg` as described in `src/core/web.rs`, on an
+// xterm.js terminal filling the page.
+import init, { WebArcade } from "./pkg/terminal_arcade.js";
+
+await init();
+
+const terminal = new Terminal({ cursorBlink: false });
+const fitAddon = new FitAddon.FitAddon();
+terminal.loadAddon(fitAddon);
+terminal.open(document.getElementById("terminal"));
+fitAddon.fit();
+terminal.focus();
+
+const arcade = new WebArcade(terminal.cols, terminal.rows, navigator.language);
+
+// Keys go to the arcade rather than to the terminal, which would type them.
+// Ctrl+V is left to the browser, so that it pastes.
+terminal.attachCustomKeyEventHandler((event) => {
+	if (event.type !== "keydown" || (event.ctrlKey && event.key === "v")) {
+		return false;
+	}
+	if (arcade.key(event.key, event.ctrlKey, event.altKey, event.shiftKey)) {
+		event.preventDefault();
+	}
+	return false;
+});
+// With keys going to the arcade, the terminal's data is only what's pasted.
+terminal.onData((text) => arcade.paste(text));
+terminal.onResize(({ cols, rows }) => arcade.resize(cols, rows));
+window.addEventListener("resize", () => fitAddon.fit());
+
+function frame() {
+	try {
+		terminal.write(arcade.tick());
+	} catch (error) {
+		console.error(error);
+		return;
+	}
+	if (arcade.isClosed()) {
+		terminal.reset();
+		return;
+	}
+	requestAnimationFrame(frame);
+}
+requestAnimationFrame(frame);